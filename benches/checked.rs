@@ -0,0 +1,34 @@
+use criterion::{criterion_group, criterion_main, Criterion, PlottingBackend};
+use libnoise::prelude::*;
+
+const SCALE: f64 = 0.033;
+const SHAPE_2D: &[usize] = &[1000, 1000];
+
+macro_rules! impl_generator {
+    () => {
+        Source::simplex(42).fbm(3, 0.013, 2.0, 0.5)
+    };
+}
+
+// without the strict-range feature, the check is compiled out of release builds, such that both
+// benchmarks are expected to perform the same, while with the feature enabled, the difference is
+// the cost of the check, as measured by `cargo bench --bench checked --features strict-range`
+fn bench(c: &mut Criterion) {
+    devtools::benchtools::bench_noise2d(c, "unchecked_2d", SHAPE_2D, SCALE, &impl_generator!());
+    devtools::benchtools::bench_noise2d(
+        c,
+        "checked_2d",
+        SHAPE_2D,
+        SCALE,
+        &impl_generator!().checked(-1.0, 1.0),
+    );
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default()
+        .plotting_backend(PlottingBackend::Plotters);
+    targets = bench
+}
+
+criterion_main!(benches);
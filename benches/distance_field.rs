@@ -0,0 +1,41 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, PlottingBackend};
+use libnoise::prelude::*;
+
+const QUERIES: usize = 2000;
+const EXTENT: f64 = 100.0;
+
+// deterministic pseudo-random points within [0, EXTENT) along each axis
+fn random_points(seed: u64, count: usize) -> Vec<[f64; 2]> {
+    let mut state = seed;
+    let mut random = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 11) as f64 / (1_u64 << 53) as f64 * EXTENT
+    };
+    (0..count).map(|_| [random(), random()]).collect()
+}
+
+fn bench(c: &mut Criterion) {
+    let queries = random_points(7, QUERIES);
+    for count in [1000, 100_000] {
+        let generator = Source::distance_field(random_points(count as u64, count)).unwrap();
+        c.bench_function(&format!("distance_field_2d_{count}"), |b| {
+            b.iter(|| {
+                queries
+                    .iter()
+                    .map(|&query| generator.sample(black_box(query)))
+                    .sum::<f64>()
+            });
+        });
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default()
+        .plotting_backend(PlottingBackend::Plotters);
+    targets = bench
+}
+
+criterion_main!(benches);
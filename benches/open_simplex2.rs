@@ -0,0 +1,26 @@
+use criterion::{criterion_group, criterion_main, Criterion, PlottingBackend};
+use libnoise::prelude::*;
+
+const SCALE: f64 = 0.033;
+const SHAPE_2D: &[usize] = &[1000, 1000];
+const SHAPE_3D: &[usize] = &[100, 100, 100];
+
+macro_rules! impl_generator {
+    () => {
+        Source::open_simplex2(42)
+    };
+}
+
+fn bench(c: &mut Criterion) {
+    devtools::benchtools::bench_noise2d(c, "open_simplex2_2d", SHAPE_2D, SCALE, &impl_generator!());
+    devtools::benchtools::bench_noise3d(c, "open_simplex2_3d", SHAPE_3D, SCALE, &impl_generator!());
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default()
+        .plotting_backend(PlottingBackend::Plotters);
+    targets = bench
+}
+
+criterion_main!(benches);
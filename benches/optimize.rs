@@ -0,0 +1,50 @@
+use criterion::{criterion_group, criterion_main, Criterion, PlottingBackend};
+use libnoise::prelude::*;
+
+const SCALE: f64 = 0.033;
+const SHAPE_2D: &[usize] = &[1000, 1000];
+
+// a chain as assembled step by step in an editor, with identity adapters, split transforms,
+// and constant subtrees
+macro_rules! impl_generator {
+    () => {
+        Source::<2>::simplex(42)
+            .scale([1.0, 1.0])
+            .translate([0.5, 0.5])
+            .scale([0.5, 0.5])
+            .translate([10.0, 20.0])
+            .scale([2.0, 2.0])
+            .mul(1.0)
+            .neg()
+            .neg()
+            .sum(Source::constant(0.25).fbm(3, 0.013, 2.0, 0.5))
+            .product(Source::constant(2.0).abs().add(-1.0))
+    };
+}
+
+fn bench(c: &mut Criterion) {
+    let recipe = impl_generator!().to_recipe().unwrap();
+    devtools::benchtools::bench_noise2d(
+        c,
+        "unoptimized_2d",
+        SHAPE_2D,
+        SCALE,
+        &recipe.build::<2>().unwrap(),
+    );
+    devtools::benchtools::bench_noise2d(
+        c,
+        "optimized_2d",
+        SHAPE_2D,
+        SCALE,
+        &recipe.optimize::<2>().unwrap().build::<2>().unwrap(),
+    );
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default()
+        .plotting_backend(PlottingBackend::Plotters);
+    targets = bench
+}
+
+criterion_main!(benches);
@@ -0,0 +1,71 @@
+use criterion::{criterion_group, criterion_main, Criterion, PlottingBackend};
+use libnoise::pipeline::{Pipeline, PipelineOp};
+use libnoise::prelude::*;
+use libnoise::recipe::NoiseRecipe;
+
+const SCALE: f64 = 0.033;
+const SHAPE_2D: &[usize] = &[1000, 1000];
+
+fn simplex() -> Pipeline<2> {
+    Pipeline::new(NoiseRecipe::Simplex {
+        seed: 42,
+        large_coordinates: false,
+        r_squared: None,
+    })
+    .unwrap()
+}
+
+// a fractal chain, dominated by the cost of the source
+fn bench_fractal(c: &mut Criterion) {
+    let generator = Source::<2>::simplex(42)
+        .fbm(6, 0.013, 2.0, 0.5)
+        .clamp(-0.5, 0.5);
+    let mut pipeline = simplex();
+    pipeline.push_fbm(6, 0.013, 2.0, 0.5).unwrap();
+    pipeline
+        .push(PipelineOp::Clamp {
+            min: -0.5,
+            max: 0.5,
+        })
+        .unwrap();
+    devtools::benchtools::bench_noise2d(c, "fractal_static_2d", SHAPE_2D, SCALE, &generator);
+    devtools::benchtools::bench_noise2d(c, "fractal_pipeline_2d", SHAPE_2D, SCALE, &pipeline);
+}
+
+// a chain of cheap arithmetic adapters, dominated by the cost of calling them
+fn bench_arithmetic(c: &mut Criterion) {
+    let generator = Source::<2>::simplex(42)
+        .scale([0.5, 2.0])
+        .translate([10.0, 20.0])
+        .abs()
+        .mul(-2.0)
+        .add(1.0)
+        .neg()
+        .clamp(-0.5, 0.5);
+    let mut pipeline = simplex();
+    for op in [
+        PipelineOp::Scale([0.5, 2.0]),
+        PipelineOp::Translate([10.0, 20.0]),
+        PipelineOp::Abs,
+        PipelineOp::Mul(-2.0),
+        PipelineOp::Add(1.0),
+        PipelineOp::Neg,
+        PipelineOp::Clamp {
+            min: -0.5,
+            max: 0.5,
+        },
+    ] {
+        pipeline.push(op).unwrap();
+    }
+    devtools::benchtools::bench_noise2d(c, "arithmetic_static_2d", SHAPE_2D, SCALE, &generator);
+    devtools::benchtools::bench_noise2d(c, "arithmetic_pipeline_2d", SHAPE_2D, SCALE, &pipeline);
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default()
+        .plotting_backend(PlottingBackend::Plotters);
+    targets = bench_fractal, bench_arithmetic
+}
+
+criterion_main!(benches);
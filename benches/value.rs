@@ -1,4 +1,4 @@
-use criterion::{criterion_group, criterion_main, Criterion, PlottingBackend};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, PlottingBackend};
 use libnoise::prelude::*;
 
 const SCALE: f64 = 0.033;
@@ -18,6 +18,14 @@ fn bench(c: &mut Criterion) {
     devtools::benchtools::bench_noise2d(c, "value_2d", SHAPE_2D, SCALE, &impl_generator!());
     devtools::benchtools::bench_noise3d(c, "value_3d", SHAPE_3D, SCALE, &impl_generator!());
     devtools::benchtools::bench_noise4d(c, "value_4d", SHAPE_4D, SCALE, &impl_generator!());
+    // sampling at integer coordinates, as done for voxel grids
+    let generator: Value<3> = impl_generator!();
+    c.bench_function("value_3d_grid", |b| {
+        b.iter(|| NoiseBuffer::<3>::new(black_box([100, 100, 100]), &generator));
+    });
+    c.bench_function("value_3d_lattice", |b| {
+        b.iter(|| NoiseBuffer::<3>::fill_lattice(&generator, [0; 3], black_box([100, 100, 100])));
+    });
 }
 
 criterion_group! {
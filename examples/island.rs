@@ -0,0 +1,24 @@
+use libnoise::prelude::*;
+
+fn main() {
+    example_island_round();
+    example_island_rect();
+}
+
+fn example_island_round() {
+    let mask = Source::island([500.0, 500.0], 450.0, FalloffCurve::Smoothstep).unwrap();
+    let terrain = Source::simplex(42).fbm(5, 0.006, 2.0, 0.5).add(0.2);
+    let generator = mask.min(terrain);
+    Visualizer::<2>::new([1000, 1000], &generator)
+        .write_to_file("island_2d.png")
+        .unwrap();
+}
+
+fn example_island_rect() {
+    let mask = Source::island_rect([0.0, 0.0], [1000.0, 1000.0], FalloffCurve::SquareBump).unwrap();
+    let terrain = Source::simplex(42).fbm(5, 0.006, 2.0, 0.5).add(0.2);
+    let generator = mask.min(terrain);
+    Visualizer::<2>::new([1000, 1000], &generator)
+        .write_to_file("island_rect_2d.png")
+        .unwrap();
+}
@@ -0,0 +1,39 @@
+use libnoise::prelude::*;
+
+fn main() {
+    example_preset_mountains();
+    example_preset_rolling_hills();
+    example_preset_archipelago();
+    example_preset_badlands();
+    example_preset_caves3d();
+}
+
+fn example_preset_mountains() {
+    Visualizer::<2>::new([1000, 1000], &presets::mountains(42))
+        .write_to_file("preset_mountains.png")
+        .unwrap();
+}
+
+fn example_preset_rolling_hills() {
+    Visualizer::<2>::new([1000, 1000], &presets::rolling_hills(42))
+        .write_to_file("preset_rolling_hills.png")
+        .unwrap();
+}
+
+fn example_preset_archipelago() {
+    Visualizer::<2>::new([1000, 1000], &presets::archipelago(42))
+        .write_to_file("preset_archipelago.png")
+        .unwrap();
+}
+
+fn example_preset_badlands() {
+    Visualizer::<2>::new([1000, 1000], &presets::badlands(42))
+        .write_to_file("preset_badlands.png")
+        .unwrap();
+}
+
+fn example_preset_caves3d() {
+    Visualizer::<3>::new([200, 200, 200], &presets::caves3d(42))
+        .write_to_file("preset_caves3d.png")
+        .unwrap();
+}
@@ -0,0 +1,41 @@
+use libnoise::prelude::*;
+
+fn main() {
+    example_spot_noise_gaussian();
+    example_spot_noise_cone();
+    example_spot_noise_ring();
+}
+
+fn example_spot_noise_gaussian() {
+    let generator = Source::spot(42)
+        .with_kernel(SpotKernel::Gaussian)
+        .with_radius(0.6)
+        .with_density(2)
+        .with_amplitude_jitter(0.6)
+        .scale([0.02; 2]);
+    Visualizer::<2>::new([1000, 1000], &generator)
+        .write_to_file("spot_gaussian_2d.png")
+        .unwrap();
+}
+
+fn example_spot_noise_cone() {
+    let generator = Source::spot(42)
+        .with_kernel(SpotKernel::Cone)
+        .with_radius(0.6)
+        .with_amplitude_jitter(0.4)
+        .scale([0.02; 2]);
+    Visualizer::<2>::new([1000, 1000], &generator)
+        .write_to_file("spot_cone_2d.png")
+        .unwrap();
+}
+
+fn example_spot_noise_ring() {
+    let generator = Source::spot(42)
+        .with_kernel(SpotKernel::Ring)
+        .with_radius(0.7)
+        .with_amplitude_jitter(0.5)
+        .scale([0.02; 2]);
+    Visualizer::<2>::new([1000, 1000], &generator)
+        .write_to_file("spot_ring_2d.png")
+        .unwrap();
+}
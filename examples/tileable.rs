@@ -0,0 +1,26 @@
+use libnoise::prelude::*;
+
+fn main() {
+    example_tileable_noise2d();
+    example_repeated_noise2d();
+}
+
+// four copies of a tile of 4-dimensional noise sampled on a torus, which line up seamlessly
+fn example_tileable_noise2d() {
+    let generator = Source::simplex(42)
+        .fbm(5, 0.013, 2.0, 0.5)
+        .tileable_2d([500.0, 500.0]);
+    Visualizer::<2>::new([1000, 1000], &generator)
+        .write_to_file("tileable_2d.png")
+        .unwrap();
+}
+
+// four copies of a tile of 2-dimensional noise, which show seams at the edges of the tiles
+fn example_repeated_noise2d() {
+    let tile = Source::simplex(42).fbm(5, 0.013, 2.0, 0.5);
+    let generator =
+        Source::custom(|[x, y]: [f64; 2]| tile.sample([x.rem_euclid(500.0), y.rem_euclid(500.0)]));
+    Visualizer::<2>::new([1000, 1000], &generator)
+        .write_to_file("tileable_2d_repeated.png")
+        .unwrap();
+}
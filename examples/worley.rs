@@ -5,6 +5,7 @@ fn main() {
     example_worley_noise2d();
     example_worley_noise3d();
     example_worley_noise4d();
+    example_worley_edge_distance2d();
 }
 
 fn example_worley_noise1d() {
@@ -34,3 +35,12 @@ fn example_worley_noise4d() {
         .write_to_file("worley_4d.gif")
         .unwrap();
 }
+
+fn example_worley_edge_distance2d() {
+    let generator = Source::worley(42)
+        .with_return_type(WorleyReturnType::EdgeDistance)
+        .scale([0.013; 2]);
+    Visualizer::<2>::new([1000, 1000], &generator)
+        .write_to_file("worley_edge_2d.png")
+        .unwrap();
+}
@@ -1,4 +1,11 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+    },
+};
 
 /// A generator adding `offset` to results of the underlying generator.
 ///
@@ -8,6 +15,7 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 ///
 /// [`add()`]: Generator::add
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Add<const D: usize, G> {
     generator: G,
     offset: f64,
@@ -36,4 +44,24 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator.sample(point) + self.offset
     }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        interval::offset(self.generator.sample_interval(min, max), self.offset)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Add")
+            .with_param("offset", self.offset)
+            .with_child("source", self.generator.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Add {
+            source: recipe::child("source", &self.generator)?,
+            offset: self.offset,
+        })
+    }
 }
+
+impl_display!([const D: usize, G] Add<D, G>);
@@ -0,0 +1,100 @@
+use crate::core::{
+    generator::{Generator, Generator2D},
+    utils::describe::Description,
+};
+use core::fmt;
+
+/// A generator animating a 3-dimensional generator in 2-dimensional space, by using the third
+/// dimension as time.
+///
+/// For details, see the documentation of [`animated_2d()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`animated_2d()`] implemented by [`Generator3D`], should be used
+/// to create [`Animated2D`].
+///
+/// [`animated_2d()`]: crate::Generator3D::animated_2d
+/// [`Generator3D`]: crate::Generator3D
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Animated2D<G> {
+    generator: G,
+    time_scale: f64,
+    time: f64,
+}
+
+impl<G: Generator<3>> Generator2D for Animated2D<G> {}
+
+impl<G: Generator<3>> Animated2D<G> {
+    /// Create a new generator at time 0, advancing through the third dimension of the
+    /// underlying generator by `time_scale` per unit of time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `time_scale` is not finite.
+    #[inline]
+    pub fn new(generator: G, time_scale: f64) -> Self {
+        assert!(
+            time_scale.is_finite(),
+            "time_scale must be finite, got {}",
+            time_scale
+        );
+        Self {
+            generator,
+            time_scale,
+            time: 0.0,
+        }
+    }
+
+    /// Sets the time of the frame provided by [`sample()`](Generator::sample).
+    #[inline]
+    pub fn set_time(&mut self, time: f64) {
+        self.time = time;
+    }
+
+    /// Returns the generator with the time of its frame set to `time`.
+    #[inline]
+    pub fn at_time(mut self, time: f64) -> Self {
+        self.set_time(time);
+        self
+    }
+
+    /// Returns the time of the frame provided by [`sample()`](Generator::sample).
+    #[inline]
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// Samples the generator at a given `point` of the frame at the given `time`, regardless of
+    /// the time set for the generator.
+    #[inline]
+    pub fn sample_at(&self, point: [f64; 2], time: f64) -> f64 {
+        self.generator
+            .sample([point[0], point[1], time * self.time_scale])
+    }
+}
+
+impl<G: Generator<3>> Generator<2> for Animated2D<G> {
+    #[inline]
+    fn sample(&self, point: [f64; 2]) -> f64 {
+        self.sample_at(point, self.time)
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; 2], max: [f64; 2]) -> (f64, f64) {
+        let t = self.time * self.time_scale;
+        self.generator
+            .sample_interval([min[0], min[1], t], [max[0], max[1], t])
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Animated2D")
+            .with_param("time_scale", self.time_scale)
+            .with_param("time", self.time)
+            .with_child("source", self.generator.describe())
+    }
+}
+
+impl<G: Generator<3>> fmt::Display for Animated2D<G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.describe(), f)
+    }
+}
@@ -1,4 +1,14 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    adapters::fractal::{self, Decay},
+    error::Error,
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+        param::Parameter,
+    },
+};
 
 /// Create a generator applying an `fbm()`-like effect on the underlying generator.
 ///
@@ -8,33 +18,112 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 ///
 /// [`billow()`]: Generator::billow
 #[derive(Clone, Copy, Debug)]
-pub struct Billow<const D: usize, G> {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "BillowParams<D, G, P>",
+        bound(deserialize = "G: serde::Deserialize<'de>, P: Parameter + serde::Deserialize<'de>")
+    )
+)]
+pub struct Billow<const D: usize, G, P = f64> {
     generator: G,
     octaves: u32,
-    frequency: f64,
-    lacunarity: f64,
-    persistence: f64,
+    frequency: P,
+    lacunarity: P,
+    persistence: P,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     normalization_factor: f64,
 }
 
-impl<G: Generator<1>> Generator1D for Billow<1, G> {}
-impl<G: Generator<2>> Generator2D for Billow<2, G> {}
-impl<G: Generator<3>> Generator3D for Billow<3, G> {}
-impl<G: Generator<4>> Generator4D for Billow<4, G> {}
+// the normalization factor is derived from the other parameters rather than deserialized, which
+// are validated like try_new() on deserialization
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct BillowParams<const D: usize, G, P> {
+    generator: G,
+    octaves: u32,
+    frequency: P,
+    lacunarity: P,
+    persistence: P,
+}
+
+#[cfg(feature = "serde")]
+impl<const D: usize, G, P: Parameter> TryFrom<BillowParams<D, G, P>> for Billow<D, G, P> {
+    type Error = Error;
+
+    fn try_from(params: BillowParams<D, G, P>) -> Result<Self, Self::Error> {
+        fractal::check(
+            params.octaves,
+            params.frequency.get(),
+            params.lacunarity.get(),
+            Decay::Persistence(params.persistence.get()),
+        )?;
+        let normalization_factor =
+            compute_normalization_factor(params.octaves, params.persistence.get());
+        Ok(Self {
+            generator: params.generator,
+            octaves: params.octaves,
+            frequency: params.frequency,
+            lacunarity: params.lacunarity,
+            persistence: params.persistence,
+            normalization_factor,
+        })
+    }
+}
+
+impl<G: Generator<1>, P: Parameter> Generator1D for Billow<1, G, P> {}
+impl<G: Generator<2>, P: Parameter> Generator2D for Billow<2, G, P> {}
+impl<G: Generator<3>, P: Parameter> Generator3D for Billow<3, G, P> {}
+impl<G: Generator<4>, P: Parameter> Generator4D for Billow<4, G, P> {}
 
-impl<const D: usize, G> Billow<D, G>
+impl<const D: usize, G, P> Billow<D, G, P>
 where
     G: Generator<D>,
+    P: Parameter,
 {
-    #[inline]
-    pub fn new(
+    /// Create a new [`Billow`], validating the parameters.
+    ///
+    /// This is the fallible counterpart of [`billow()`], rejecting the parameters for which it
+    /// panics in debug builds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] naming the first invalid parameter and its value.
+    ///
+    /// [`billow()`]: Generator::billow
+    pub fn try_new(
         generator: G,
         octaves: u32,
-        frequency: f64,
-        lacunarity: f64,
-        persistence: f64,
-    ) -> Self {
-        let normalization_factor = compute_normalization_factor(octaves, persistence);
+        frequency: P,
+        lacunarity: P,
+        persistence: P,
+    ) -> Result<Self, Error> {
+        fractal::check(
+            octaves,
+            frequency.get(),
+            lacunarity.get(),
+            Decay::Persistence(persistence.get()),
+        )?;
+        Ok(Self::new(
+            generator,
+            octaves,
+            frequency,
+            lacunarity,
+            persistence,
+        ))
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn new(generator: G, octaves: u32, frequency: P, lacunarity: P, persistence: P) -> Self {
+        fractal::debug_check(
+            octaves,
+            frequency.get(),
+            lacunarity.get(),
+            Decay::Persistence(persistence.get()),
+        );
+        let normalization_factor = compute_normalization_factor(octaves, persistence.get());
         Self {
             generator,
             octaves,
@@ -46,24 +135,71 @@ where
     }
 }
 
+impl<const D: usize, G, P: Parameter> Billow<D, G, P> {
+    // the precomputed normalization factor, or the factor for the current persistence if it is
+    // tweakable
+    #[inline]
+    fn current_normalization_factor(&self, persistence: f64) -> f64 {
+        if P::FIXED {
+            self.normalization_factor
+        } else {
+            compute_normalization_factor(self.octaves, persistence)
+        }
+    }
+}
+
 macro_rules! impl_generator {
     ($dim:literal) => {
-        impl<G: Generator<$dim>> Generator<$dim> for Billow<$dim, G> {
+        impl<G: Generator<$dim>, P: Parameter> Generator<$dim> for Billow<$dim, G, P> {
             fn sample(&self, point: [f64; $dim]) -> f64 {
                 let mut noise = 0.0;
                 let mut amp = 1.0;
-                let mut freq = self.frequency;
+                let lacunarity = self.lacunarity.get();
+                let persistence = self.persistence.get();
+                let mut freq = self.frequency.get();
                 for _ in 0..self.octaves {
-                    noise += amp
-                        * self
-                            .generator
-                            .sample(point.map(|x| x * freq))
-                            .abs()
-                            .mul_add(2.0, -1.0);
-                    freq *= self.lacunarity;
-                    amp *= self.persistence;
+                    noise +=
+                        amp * (self.generator.sample(point.map(|x| x * freq)).abs() * 2.0 - 1.0);
+                    freq *= lacunarity;
+                    amp *= persistence;
                 }
-                noise * self.normalization_factor
+                noise * self.current_normalization_factor(persistence)
+            }
+
+            fn sample_interval(&self, min: [f64; $dim], max: [f64; $dim]) -> (f64, f64) {
+                let mut noise = (0.0, 0.0);
+                let mut amp = 1.0;
+                let lacunarity = self.lacunarity.get();
+                let persistence = self.persistence.get();
+                let mut freq = self.frequency.get();
+                for _ in 0..self.octaves {
+                    let (min, max) = interval::scale_box(min, max, [freq; $dim]);
+                    let octave = interval::abs(self.generator.sample_interval(min, max));
+                    let octave = interval::offset(interval::scale(octave, 2.0), -1.0);
+                    noise = interval::add(noise, interval::scale(octave, amp));
+                    freq *= lacunarity;
+                    amp *= persistence;
+                }
+                interval::scale(noise, self.current_normalization_factor(persistence))
+            }
+
+            fn describe(&self) -> Description {
+                Description::new("Billow")
+                    .with_param("octaves", self.octaves)
+                    .with_param("frequency", self.frequency.get())
+                    .with_param("lacunarity", self.lacunarity.get())
+                    .with_param("persistence", self.persistence.get())
+                    .with_child("source", self.generator.describe())
+            }
+
+            fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+                Ok(NoiseRecipe::Billow {
+                    source: recipe::child("source", &self.generator)?,
+                    octaves: self.octaves,
+                    frequency: self.frequency.get(),
+                    lacunarity: self.lacunarity.get(),
+                    persistence: self.persistence.get(),
+                })
             }
         }
     };
@@ -76,5 +212,14 @@ impl_generator!(4);
 
 #[inline]
 fn compute_normalization_factor(octaves: u32, persistence: f64) -> f64 {
-    1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32))
+    // accumulate amplitudes the same way as when sampling, rather than using powi, which is not
+    // guaranteed to produce identical results across platforms
+    let mut amp = 1.0;
+    1.0 / (0..octaves).fold(0.0, |acc, _| {
+        let acc = acc + amp;
+        amp *= persistence;
+        acc
+    })
 }
+
+impl_display!([const D: usize, G, P] Billow<D, G, P>);
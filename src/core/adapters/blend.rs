@@ -1,4 +1,11 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+    },
+};
 
 /// A generator blending the underlying generator with a given other generator based on the
 /// value supplied by a control-generator.
@@ -9,6 +16,7 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 ///
 /// [`blend()`]: Generator::blend
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Blend<const D: usize, GA, GB, GC> {
     generator_a: GA,
     generator_b: GB,
@@ -49,4 +57,32 @@ where
         let t = self.generator_control.sample(point) * 0.5 + 0.5;
         a + t * (b - a)
     }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        let a = self.generator_a.sample_interval(min, max);
+        let b = self.generator_b.sample_interval(min, max);
+        let t = interval::offset(
+            interval::scale(self.generator_control.sample_interval(min, max), 0.5),
+            0.5,
+        );
+        interval::add(a, interval::mul(t, interval::sub(b, a)))
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Blend")
+            .with_child("a", self.generator_a.describe())
+            .with_child("b", self.generator_b.describe())
+            .with_child("control", self.generator_control.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Blend {
+            a: recipe::child("a", &self.generator_a)?,
+            b: recipe::child("b", &self.generator_b)?,
+            control: recipe::child("control", &self.generator_control)?,
+        })
+    }
 }
+
+impl_display!([const D: usize, GA, GB, GC] Blend<D, GA, GB, GC>);
@@ -0,0 +1,91 @@
+use crate::core::{
+    generator::{Generator, VectorGenerator},
+    utils::describe::Description,
+};
+use core::fmt;
+
+/// A vector generator bundling scalar generators, such that each of them provides one component
+/// of the resulting vectors.
+///
+/// The bundled generators are sampled individually, such that no work is shared among them. For
+/// details, see the documentation of [`VectorGenerator`]. Generators of different types can be
+/// bundled by boxing them as [`SampleGenerator<D>`] trait objects.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Bundle, Source, Generator, SampleGenerator, VectorGenerator};
+/// let generator = Bundle::new([Source::simplex(42), Source::simplex(43)]);
+/// let [x, y] = generator.sample([0.2, 0.5]);
+///
+/// assert_eq!(x, Source::simplex(42).sample([0.2, 0.5]));
+/// assert_eq!(y, Source::simplex(43).sample([0.2, 0.5]));
+///
+/// // bundle generators of different types
+/// let generators: [Box<dyn SampleGenerator<2>>; 2] = [
+///     Box::new(Source::simplex(42)),
+///     Box::new(Source::worley(43).scale([0.5, 0.5])),
+/// ];
+/// let generator = Bundle::new(generators);
+/// let [x, y] = generator.sample([0.2, 0.5]);
+/// ```
+///
+/// [`SampleGenerator<D>`]: crate::SampleGenerator
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "G: serde::Serialize",
+        deserialize = "G: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Bundle<const D: usize, const M: usize, G> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::core::utils::serde_array"))]
+    generators: [G; M],
+}
+
+impl<const D: usize, const M: usize, G> Bundle<D, M, G>
+where
+    G: Generator<D>,
+{
+    /// Create a new vector generator, whose components are the results of `generators`.
+    #[inline]
+    pub fn new(generators: [G; M]) -> Self {
+        Self { generators }
+    }
+}
+
+impl<const D: usize, const M: usize, G> VectorGenerator<D, M> for Bundle<D, M, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> [f64; M] {
+        core::array::from_fn(|i| self.generators[i].sample(point))
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> [(f64, f64); M] {
+        core::array::from_fn(|i| self.generators[i].sample_interval(min, max))
+    }
+
+    fn describe(&self) -> Description {
+        self.generators
+            .iter()
+            .fold(Description::new("Bundle"), |description, generator| {
+                description.with_child("component", generator.describe())
+            })
+    }
+}
+
+impl<const D: usize, const M: usize, G> fmt::Display for Bundle<D, M, G>
+where
+    G: Generator<D>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&VectorGenerator::describe(self), f)
+    }
+}
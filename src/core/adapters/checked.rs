@@ -0,0 +1,97 @@
+use crate::core::{
+    error::Error,
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::describe::{impl_display, Description},
+};
+
+/// A generator asserting that results of the underlying generator lie within a given interval.
+///
+/// For details, see the documentation of [`checked()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`checked()`] implemented by [`Generator`], should be used
+/// to create [`Checked`].
+///
+/// [`checked()`]: Generator::checked
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Checked<const D: usize, G> {
+    generator: G,
+    min: f64,
+    max: f64,
+}
+
+impl<G: Generator<1>> Generator1D for Checked<1, G> {}
+impl<G: Generator<2>> Generator2D for Checked<2, G> {}
+impl<G: Generator<3>> Generator3D for Checked<3, G> {}
+impl<G: Generator<4>> Generator4D for Checked<4, G> {}
+
+impl<const D: usize, G> Checked<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    pub fn new(generator: G, min: f64, max: f64) -> Self
+    where
+        G: Generator<D>,
+    {
+        Self {
+            generator,
+            min,
+            max,
+        }
+    }
+
+    /// Samples the underlying generator at a given `point` and returns the resulting value, or an
+    /// error describing the offending point and value if the value lies outside the interval.
+    #[inline]
+    pub fn try_sample(&self, point: [f64; D]) -> Result<f64, Error> {
+        let value = self.generator.sample(point);
+        if self.min <= value && value <= self.max {
+            Ok(value)
+        } else {
+            Err(Error::OutOfRange {
+                point: point.to_vec(),
+                value,
+                min: self.min,
+                max: self.max,
+            })
+        }
+    }
+}
+
+impl<const D: usize, G> Generator<D> for Checked<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        if cfg!(any(debug_assertions, feature = "strict-range")) {
+            self.try_sample(point)
+                .unwrap_or_else(|err| panic!("{}", err))
+        } else {
+            self.generator.sample(point)
+        }
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        self.generator.sample_interval(min, max)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Checked")
+            .with_param("min", self.min)
+            .with_param("max", self.max)
+            .with_child("source", self.generator.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Checked {
+            source: recipe::child("source", &self.generator)?,
+            min: self.min,
+            max: self.max,
+        })
+    }
+}
+
+impl_display!([const D: usize, G] Checked<D, G>);
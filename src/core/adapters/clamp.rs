@@ -1,4 +1,12 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+        param::Parameter,
+    },
+};
 
 /// A generator clamping results of the underlying generator to a given interval.
 ///
@@ -8,23 +16,25 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 ///
 /// [`clamp()`]: Generator::clamp
 #[derive(Clone, Copy, Debug)]
-pub struct Clamp<const D: usize, G> {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Clamp<const D: usize, G, P = f64> {
     generator: G,
-    min: f64,
-    max: f64,
+    min: P,
+    max: P,
 }
 
-impl<G: Generator<1>> Generator1D for Clamp<1, G> {}
-impl<G: Generator<2>> Generator2D for Clamp<2, G> {}
-impl<G: Generator<3>> Generator3D for Clamp<3, G> {}
-impl<G: Generator<4>> Generator4D for Clamp<4, G> {}
+impl<G: Generator<1>, P: Parameter> Generator1D for Clamp<1, G, P> {}
+impl<G: Generator<2>, P: Parameter> Generator2D for Clamp<2, G, P> {}
+impl<G: Generator<3>, P: Parameter> Generator3D for Clamp<3, G, P> {}
+impl<G: Generator<4>, P: Parameter> Generator4D for Clamp<4, G, P> {}
 
-impl<const D: usize, G> Clamp<D, G>
+impl<const D: usize, G, P> Clamp<D, G, P>
 where
     G: Generator<D>,
+    P: Parameter,
 {
     #[inline]
-    pub fn new(generator: G, min: f64, max: f64) -> Self
+    pub fn new(generator: G, min: P, max: P) -> Self
     where
         G: Generator<D>,
     {
@@ -36,12 +46,41 @@ where
     }
 }
 
-impl<const D: usize, G> Generator<D> for Clamp<D, G>
+impl<const D: usize, G, P> Generator<D> for Clamp<D, G, P>
 where
     G: Generator<D>,
+    P: Parameter,
 {
     #[inline]
     fn sample(&self, point: [f64; D]) -> f64 {
-        self.generator.sample(point).clamp(self.min, self.max)
+        self.generator
+            .sample(point)
+            .clamp(self.min.get(), self.max.get())
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        interval::clamp(
+            self.generator.sample_interval(min, max),
+            self.min.get(),
+            self.max.get(),
+        )
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Clamp")
+            .with_param("min", self.min.get())
+            .with_param("max", self.max.get())
+            .with_child("source", self.generator.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Clamp {
+            source: recipe::child("source", &self.generator)?,
+            min: self.min.get(),
+            max: self.max.get(),
+        })
     }
 }
+
+impl_display!([const D: usize, G, P] Clamp<D, G, P>);
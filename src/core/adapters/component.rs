@@ -0,0 +1,68 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D, VectorGenerator},
+    utils::describe::{impl_display, Description},
+};
+
+/// A generator providing a single component of the results of the underlying vector generator.
+///
+/// For details, see the documentation of [`component()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`component()`] implemented by [`VectorGenerator`], should be
+/// used to create [`Component`].
+///
+/// [`component()`]: VectorGenerator::component
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Component<const D: usize, const M: usize, V> {
+    generator: V,
+    index: usize,
+}
+
+impl<const M: usize, V: VectorGenerator<1, M>> Generator1D for Component<1, M, V> {}
+impl<const M: usize, V: VectorGenerator<2, M>> Generator2D for Component<2, M, V> {}
+impl<const M: usize, V: VectorGenerator<3, M>> Generator3D for Component<3, M, V> {}
+impl<const M: usize, V: VectorGenerator<4, M>> Generator4D for Component<4, M, V> {}
+
+impl<const D: usize, const M: usize, V> Component<D, M, V>
+where
+    V: VectorGenerator<D, M>,
+{
+    /// Create a new generator providing the component at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `M`.
+    #[inline]
+    #[track_caller]
+    pub fn new(generator: V, index: usize) -> Self {
+        assert!(
+            index < M,
+            "index must be less than the number of components {}, got {}",
+            M,
+            index
+        );
+        Self { generator, index }
+    }
+}
+
+impl<const D: usize, const M: usize, V> Generator<D> for Component<D, M, V>
+where
+    V: VectorGenerator<D, M>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.generator.sample(point)[self.index]
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        self.generator.sample_interval(min, max)[self.index]
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Component")
+            .with_param("index", self.index)
+            .with_child("source", self.generator.describe())
+    }
+}
+
+impl_display!([const D: usize, const M: usize, V] Component<D, M, V>);
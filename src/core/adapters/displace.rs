@@ -1,4 +1,8 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::describe::{impl_display, Description},
+};
 
 /// A generator producing the maximum of results of the underlying generator and results of
 /// a given other generator.
@@ -9,6 +13,7 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 ///
 /// [`displace_x()`]: Generator2D::displace_x
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Displace<const D: usize, const A: usize, G, GA> {
     generator: G,
     displacement_generator: GA,
@@ -43,4 +48,30 @@ where
         point[A] += self.displacement_generator.sample(point);
         self.generator.sample(point)
     }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        let (lower, upper) = self.displacement_generator.sample_interval(min, max);
+        let (mut min, mut max) = (min, max);
+        min[A] += lower;
+        max[A] += upper;
+        self.generator.sample_interval(min, max)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Displace")
+            .with_param("axis", A)
+            .with_child("source", self.generator.describe())
+            .with_child("displacement", self.displacement_generator.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Displace {
+            source: recipe::child("source", &self.generator)?,
+            displacement: recipe::child("displacement", &self.displacement_generator)?,
+            axis: A,
+        })
+    }
 }
+
+impl_display!([const D: usize, const A: usize, G, GA] Displace<D, A, G, GA>);
@@ -1,4 +1,13 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+    },
+};
 
 /// A generator applying the exponential function on results of the underlying generator.
 ///
@@ -8,6 +17,7 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 ///
 /// [`exp()`]: Generator::exp
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Exp<const D: usize, G> {
     generator: G,
 }
@@ -35,4 +45,21 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator.sample(point).exp()
     }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        interval::exp(self.generator.sample_interval(min, max))
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Exp").with_child("source", self.generator.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Exp {
+            source: recipe::child("source", &self.generator)?,
+        })
+    }
 }
+
+impl_display!([const D: usize, G] Exp<D, G>);
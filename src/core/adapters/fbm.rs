@@ -1,4 +1,15 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    adapters::fractal::{self, Decay},
+    error::Error,
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+        param::Parameter,
+    },
+};
+use alloc::{format, vec::Vec};
 
 /// A generator applying fractal brownian motion on the underlying generator.
 ///
@@ -8,33 +19,112 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 ///
 /// [`fbm()`]: Generator::fbm
 #[derive(Clone, Copy, Debug)]
-pub struct Fbm<const D: usize, G> {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "FbmParams<D, G, P>",
+        bound(deserialize = "G: serde::Deserialize<'de>, P: Parameter + serde::Deserialize<'de>")
+    )
+)]
+pub struct Fbm<const D: usize, G, P = f64> {
     generator: G,
     octaves: u32,
-    frequency: f64,
-    lacunarity: f64,
-    persistence: f64,
+    frequency: P,
+    lacunarity: P,
+    persistence: P,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     normalization_factor: f64,
 }
 
-impl<G: Generator<1>> Generator1D for Fbm<1, G> {}
-impl<G: Generator<2>> Generator2D for Fbm<2, G> {}
-impl<G: Generator<3>> Generator3D for Fbm<3, G> {}
-impl<G: Generator<4>> Generator4D for Fbm<4, G> {}
+// the normalization factor is derived from the other parameters rather than deserialized, which
+// are validated like try_new() on deserialization
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct FbmParams<const D: usize, G, P> {
+    generator: G,
+    octaves: u32,
+    frequency: P,
+    lacunarity: P,
+    persistence: P,
+}
+
+#[cfg(feature = "serde")]
+impl<const D: usize, G, P: Parameter> TryFrom<FbmParams<D, G, P>> for Fbm<D, G, P> {
+    type Error = Error;
+
+    fn try_from(params: FbmParams<D, G, P>) -> Result<Self, Self::Error> {
+        fractal::check(
+            params.octaves,
+            params.frequency.get(),
+            params.lacunarity.get(),
+            Decay::Persistence(params.persistence.get()),
+        )?;
+        let normalization_factor =
+            compute_normalization_factor(params.octaves, params.persistence.get());
+        Ok(Self {
+            generator: params.generator,
+            octaves: params.octaves,
+            frequency: params.frequency,
+            lacunarity: params.lacunarity,
+            persistence: params.persistence,
+            normalization_factor,
+        })
+    }
+}
 
-impl<const D: usize, G> Fbm<D, G>
+impl<G: Generator<1>, P: Parameter> Generator1D for Fbm<1, G, P> {}
+impl<G: Generator<2>, P: Parameter> Generator2D for Fbm<2, G, P> {}
+impl<G: Generator<3>, P: Parameter> Generator3D for Fbm<3, G, P> {}
+impl<G: Generator<4>, P: Parameter> Generator4D for Fbm<4, G, P> {}
+
+impl<const D: usize, G, P> Fbm<D, G, P>
 where
     G: Generator<D>,
+    P: Parameter,
 {
-    #[inline]
-    pub fn new(
+    /// Create a new [`Fbm`], validating the parameters.
+    ///
+    /// This is the fallible counterpart of [`fbm()`], rejecting the parameters for which it
+    /// panics in debug builds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] naming the first invalid parameter and its value.
+    ///
+    /// [`fbm()`]: Generator::fbm
+    pub fn try_new(
         generator: G,
         octaves: u32,
-        frequency: f64,
-        lacunarity: f64,
-        persistence: f64,
-    ) -> Self {
-        let normalization_factor = compute_normalization_factor(octaves, persistence);
+        frequency: P,
+        lacunarity: P,
+        persistence: P,
+    ) -> Result<Self, Error> {
+        fractal::check(
+            octaves,
+            frequency.get(),
+            lacunarity.get(),
+            Decay::Persistence(persistence.get()),
+        )?;
+        Ok(Self::new(
+            generator,
+            octaves,
+            frequency,
+            lacunarity,
+            persistence,
+        ))
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn new(generator: G, octaves: u32, frequency: P, lacunarity: P, persistence: P) -> Self {
+        fractal::debug_check(
+            octaves,
+            frequency.get(),
+            lacunarity.get(),
+            Decay::Persistence(persistence.get()),
+        );
+        let normalization_factor = compute_normalization_factor(octaves, persistence.get());
         Self {
             generator,
             octaves,
@@ -46,19 +136,118 @@ where
     }
 }
 
+impl<const D: usize, G, P: Parameter> Fbm<D, G, P> {
+    /// Returns a generator sampling only the given `octave` of this generator.
+    ///
+    /// Octaves are numbered from 0, starting with the one of the lowest frequency. The returned
+    /// generator applies the frequency and amplitude of the octave, including the normalization
+    /// of the fbm, such that the views of all octaves sum up to the output of this generator.
+    /// This is useful for debugging, for example by rendering each octave to its own image.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if `octave` is not less than the number of octaves.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::simplex(42).fbm(3, 0.013, 2.0, 0.5);
+    /// let point = [12.0, 34.0];
+    ///
+    /// let sum: f64 = generator
+    ///     .octave_views()
+    ///     .iter()
+    ///     .map(|octave| octave.sample(point))
+    ///     .sum();
+    /// assert!((sum - generator.sample(point)).abs() < 1e-12);
+    /// assert!(generator.octave_view(3).is_err());
+    /// ```
+    pub fn octave_view(&self, octave: usize) -> Result<FbmOctave<'_, D, G, P>, Error> {
+        if octave >= self.octaves as usize {
+            return Err(Error::InvalidParameter {
+                name: "octave",
+                reason: format!(
+                    "must be less than the number of octaves {}, got {}",
+                    self.octaves, octave
+                ),
+            });
+        }
+        Ok(FbmOctave { fbm: self, octave })
+    }
+
+    /// Returns generators sampling each octave of this generator, as done by
+    /// [`octave_view()`](Fbm::octave_view), ordered from the lowest to the highest frequency.
+    pub fn octave_views(&self) -> Vec<FbmOctave<'_, D, G, P>> {
+        (0..self.octaves as usize)
+            .map(|octave| FbmOctave { fbm: self, octave })
+            .collect()
+    }
+
+    // the precomputed normalization factor, or the factor for the current persistence if it is
+    // tweakable
+    #[inline]
+    fn current_normalization_factor(&self, persistence: f64) -> f64 {
+        if P::FIXED {
+            self.normalization_factor
+        } else {
+            compute_normalization_factor(self.octaves, persistence)
+        }
+    }
+}
+
 macro_rules! impl_generator {
     ($dim:literal) => {
-        impl<G: Generator<$dim>> Generator<$dim> for Fbm<$dim, G> {
+        impl<G: Generator<$dim>, P: Parameter> Generator<$dim> for Fbm<$dim, G, P> {
             fn sample(&self, point: [f64; $dim]) -> f64 {
                 let mut noise = 0.0;
                 let mut amp = 1.0;
-                let mut freq = self.frequency;
+                let lacunarity = self.lacunarity.get();
+                let persistence = self.persistence.get();
+                let mut freq = self.frequency.get();
                 for _ in 0..self.octaves {
                     noise += amp * self.generator.sample(point.map(|x| x * freq));
-                    freq *= self.lacunarity;
-                    amp *= self.persistence;
+                    freq *= lacunarity;
+                    amp *= persistence;
                 }
-                noise * self.normalization_factor
+                noise * self.current_normalization_factor(persistence)
+            }
+
+            fn sample_interval(&self, min: [f64; $dim], max: [f64; $dim]) -> (f64, f64) {
+                let mut noise = (0.0, 0.0);
+                let mut amp = 1.0;
+                let lacunarity = self.lacunarity.get();
+                let persistence = self.persistence.get();
+                let mut freq = self.frequency.get();
+                for _ in 0..self.octaves {
+                    let (min, max) = interval::scale_box(min, max, [freq; $dim]);
+                    let octave = self.generator.sample_interval(min, max);
+                    noise = interval::add(noise, interval::scale(octave, amp));
+                    freq *= lacunarity;
+                    amp *= persistence;
+                }
+                interval::scale(noise, self.current_normalization_factor(persistence))
+            }
+
+            fn describe(&self) -> Description {
+                Description::new("Fbm")
+                    .with_param("octaves", self.octaves)
+                    .with_param("frequency", self.frequency.get())
+                    .with_param("lacunarity", self.lacunarity.get())
+                    .with_param("persistence", self.persistence.get())
+                    .with_child("source", self.generator.describe())
+            }
+
+            fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+                Ok(NoiseRecipe::Fbm {
+                    source: recipe::child("source", &self.generator)?,
+                    octaves: self.octaves,
+                    frequency: self.frequency.get(),
+                    lacunarity: self.lacunarity.get(),
+                    persistence: self.persistence.get(),
+                })
             }
         }
     };
@@ -71,5 +260,77 @@ impl_generator!(4);
 
 #[inline]
 fn compute_normalization_factor(octaves: u32, persistence: f64) -> f64 {
-    1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32))
+    // accumulate amplitudes the same way as when sampling, rather than using powi, which is not
+    // guaranteed to produce identical results across platforms
+    let mut amp = 1.0;
+    1.0 / (0..octaves).fold(0.0, |acc, _| {
+        let acc = acc + amp;
+        amp *= persistence;
+        acc
+    })
+}
+
+impl_display!([const D: usize, G, P] Fbm<D, G, P>);
+
+/// A generator sampling a single octave of an [`Fbm`].
+///
+/// This struct is created by [`Fbm::octave_view()`] and [`Fbm::octave_views()`]. For details,
+/// see their documentation.
+#[derive(Clone, Copy, Debug)]
+pub struct FbmOctave<'a, const D: usize, G, P = f64> {
+    fbm: &'a Fbm<D, G, P>,
+    octave: usize,
+}
+
+impl<G: Generator<1>, P: Parameter> Generator1D for FbmOctave<'_, 1, G, P> {}
+impl<G: Generator<2>, P: Parameter> Generator2D for FbmOctave<'_, 2, G, P> {}
+impl<G: Generator<3>, P: Parameter> Generator3D for FbmOctave<'_, 3, G, P> {}
+impl<G: Generator<4>, P: Parameter> Generator4D for FbmOctave<'_, 4, G, P> {}
+
+impl<const D: usize, G, P: Parameter> FbmOctave<'_, D, G, P> {
+    // the frequency and normalized amplitude of the octave, accumulated the same way as when
+    // sampling the fbm
+    fn frequency_and_amplitude(&self) -> (f64, f64) {
+        let lacunarity = self.fbm.lacunarity.get();
+        let persistence = self.fbm.persistence.get();
+        let mut freq = self.fbm.frequency.get();
+        let mut amp = 1.0;
+        for _ in 0..self.octave {
+            freq *= lacunarity;
+            amp *= persistence;
+        }
+        (
+            freq,
+            amp * self.fbm.current_normalization_factor(persistence),
+        )
+    }
 }
+
+impl<const D: usize, G, P> Generator<D> for FbmOctave<'_, D, G, P>
+where
+    G: Generator<D>,
+    P: Parameter,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        let (freq, amp) = self.frequency_and_amplitude();
+        amp * self.fbm.generator.sample(point.map(|x| x * freq))
+    }
+
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        let (freq, amp) = self.frequency_and_amplitude();
+        let (min, max) = interval::scale_box(min, max, [freq; D]);
+        interval::scale(self.fbm.generator.sample_interval(min, max), amp)
+    }
+
+    fn describe(&self) -> Description {
+        let (freq, amp) = self.frequency_and_amplitude();
+        Description::new("FbmOctave")
+            .with_param("octave", self.octave)
+            .with_param("frequency", freq)
+            .with_param("amplitude", amp)
+            .with_child("source", self.fbm.generator.describe())
+    }
+}
+
+impl_display!(['a, const D: usize, G, P] FbmOctave<'a, D, G, P>);
@@ -0,0 +1,338 @@
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::{
+    adapters::fractal::{self, Decay},
+    error::Error,
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+    },
+};
+
+/// A generator applying fractal brownian motion on the underlying generator, with support for
+/// reducing the level of detail.
+///
+/// For details, see the documentation of [`fbm_lod()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`fbm_lod()`] implemented by [`Generator`], should be used
+/// to create [`FbmLod`].
+///
+/// [`fbm_lod()`]: Generator::fbm_lod
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "FbmLodParams<D, G>"))]
+pub struct FbmLod<const D: usize, G> {
+    generator: G,
+    octaves: u32,
+    frequency: f64,
+    lacunarity: f64,
+    persistence: f64,
+}
+
+// the parameters are validated like try_new() on deserialization
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct FbmLodParams<const D: usize, G> {
+    generator: G,
+    octaves: u32,
+    frequency: f64,
+    lacunarity: f64,
+    persistence: f64,
+}
+
+#[cfg(feature = "serde")]
+impl<const D: usize, G> TryFrom<FbmLodParams<D, G>> for FbmLod<D, G> {
+    type Error = Error;
+
+    fn try_from(params: FbmLodParams<D, G>) -> Result<Self, Self::Error> {
+        fractal::check(
+            params.octaves,
+            params.frequency,
+            params.lacunarity,
+            Decay::Persistence(params.persistence),
+        )?;
+        Ok(Self {
+            generator: params.generator,
+            octaves: params.octaves,
+            frequency: params.frequency,
+            lacunarity: params.lacunarity,
+            persistence: params.persistence,
+        })
+    }
+}
+
+impl<G: Generator<1>> Generator1D for FbmLod<1, G> {}
+impl<G: Generator<2>> Generator2D for FbmLod<2, G> {}
+impl<G: Generator<3>> Generator3D for FbmLod<3, G> {}
+impl<G: Generator<4>> Generator4D for FbmLod<4, G> {}
+
+impl<const D: usize, G> FbmLod<D, G>
+where
+    G: Generator<D>,
+{
+    /// Create a new [`FbmLod`], validating the parameters.
+    ///
+    /// This is the fallible counterpart of [`fbm_lod()`], accepting the same parameters as
+    /// [`Fbm::try_new()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] naming the first invalid parameter and its value.
+    ///
+    /// [`fbm_lod()`]: Generator::fbm_lod
+    /// [`Fbm::try_new()`]: crate::Fbm::try_new
+    pub fn try_new(
+        generator: G,
+        octaves: u32,
+        frequency: f64,
+        lacunarity: f64,
+        persistence: f64,
+    ) -> Result<Self, Error> {
+        fractal::check(
+            octaves,
+            frequency,
+            lacunarity,
+            Decay::Persistence(persistence),
+        )?;
+        Ok(Self::new(
+            generator,
+            octaves,
+            frequency,
+            lacunarity,
+            persistence,
+        ))
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn new(
+        generator: G,
+        octaves: u32,
+        frequency: f64,
+        lacunarity: f64,
+        persistence: f64,
+    ) -> Self {
+        fractal::debug_check(
+            octaves,
+            frequency,
+            lacunarity,
+            Decay::Persistence(persistence),
+        );
+        Self {
+            generator,
+            octaves,
+            frequency,
+            lacunarity,
+            persistence,
+        }
+    }
+
+    /// Samples the generator at a given `point` with a reduced level of detail.
+    ///
+    /// The level of detail `lod` specifies how many of the highest-frequency octaves are faded
+    /// out. Octaves are removed one at a time, starting with the one of the highest frequency,
+    /// and a fractional `lod` scales the amplitude of the last remaining octave by the fraction
+    /// which is left of it, such that the result changes continuously with `lod`. The result is
+    /// normalized by the sum of the remaining amplitudes. At least one octave is always kept.
+    ///
+    /// A `lod` of 0 or less yields exactly the result of [`sample()`], and a `lod` of
+    /// `octaves - 1` or more yields exactly the result of a single octave, as produced by
+    /// [`fbm()`] with 1 octave.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::simplex(42).fbm_lod(6, 0.013, 2.0, 0.5);
+    /// let point = [1.0, 2.0];
+    ///
+    /// assert_eq!(generator.sample_lod(point, 0.0), generator.sample(point));
+    /// assert_eq!(
+    ///     generator.sample_lod(point, 5.0),
+    ///     Source::simplex(42).fbm(1, 0.013, 2.0, 0.5).sample(point),
+    /// );
+    /// ```
+    ///
+    /// [`sample()`]: Generator::sample
+    /// [`fbm()`]: Generator::fbm
+    pub fn sample_lod(&self, point: [f64; D], lod: f64) -> f64 {
+        let mut noise = 0.0;
+        let mut amp_sum = 0.0;
+        let mut amp = 1.0;
+        let mut freq = self.frequency;
+        // the first octave is always kept, such that the weights never sum to zero
+        let remaining = (self.octaves as f64 - lod.max(0.0)).max(1.0);
+        for octave in 0..self.octaves {
+            let weight = (remaining - octave as f64).min(1.0);
+            if weight <= 0.0 {
+                break;
+            }
+            let weighted_amp = amp * weight;
+            noise += weighted_amp * self.generator.sample(point.map(|x| x * freq));
+            amp_sum += weighted_amp;
+            freq *= self.lacunarity;
+            amp *= self.persistence;
+        }
+        noise * (1.0 / amp_sum)
+    }
+
+    /// Create a generator which reduces the level of detail of this generator with increasing
+    /// distance from a `focus` point.
+    ///
+    /// Within a distance of `near` around the focus point, the result is sampled with full
+    /// detail. Beyond that, one octave is faded out for each doubling of the distance, such
+    /// that a point at distance `near * 2^lod` is sampled as by [`sample_lod()`] with the
+    /// respective `lod`. Distances are measured in the input space of this generator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let fbm = Source::simplex(42).fbm_lod(6, 0.013, 2.0, 0.5);
+    /// let generator = fbm.clone().distance_lod([0.0, 0.0], 100.0);
+    ///
+    /// // full detail near the focus point
+    /// assert_eq!(generator.sample([30.0, 40.0]), fbm.sample([30.0, 40.0]));
+    /// // two octaves less at four times the distance
+    /// assert_eq!(generator.sample([0.0, 400.0]), fbm.sample_lod([0.0, 400.0], 2.0));
+    /// ```
+    ///
+    /// [`sample_lod()`]: FbmLod::sample_lod
+    #[inline]
+    pub fn distance_lod(self, focus: [f64; D], near: f64) -> DistanceLod<D, G> {
+        DistanceLod::new(self, focus, near)
+    }
+
+    // computes the hull of the intervals of all octaves, which contains every weighted average
+    // of the octaves with non-negative weights, widened to account for rounding errors
+    fn octave_hull(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        let mut hull = (f64::INFINITY, f64::NEG_INFINITY);
+        let mut freq = self.frequency;
+        for _ in 0..self.octaves {
+            let (min, max) = interval::scale_box(min, max, [freq; D]);
+            hull = interval::hull(hull, self.generator.sample_interval(min, max));
+            freq *= self.lacunarity;
+        }
+        interval::widen(hull)
+    }
+}
+
+impl<const D: usize, G> Generator<D> for FbmLod<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.sample_lod(point, 0.0)
+    }
+
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        if self.persistence >= 0.0 {
+            self.octave_hull(min, max)
+        } else {
+            interval::UNBOUNDED
+        }
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("FbmLod")
+            .with_param("octaves", self.octaves)
+            .with_param("frequency", self.frequency)
+            .with_param("lacunarity", self.lacunarity)
+            .with_param("persistence", self.persistence)
+            .with_child("source", self.generator.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::FbmLod {
+            source: recipe::child("source", &self.generator)?,
+            octaves: self.octaves,
+            frequency: self.frequency,
+            lacunarity: self.lacunarity,
+            persistence: self.persistence,
+        })
+    }
+}
+
+/// A generator reducing the level of detail of an [`FbmLod`] generator with increasing distance
+/// from a focus point.
+///
+/// For details, see the documentation of [`distance_lod()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`distance_lod()`] implemented by [`FbmLod`], should be used
+/// to create [`DistanceLod`].
+///
+/// [`distance_lod()`]: FbmLod::distance_lod
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistanceLod<const D: usize, G> {
+    generator: FbmLod<D, G>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::core::utils::serde_array"))]
+    focus: [f64; D],
+    near: f64,
+}
+
+impl<G: Generator<1>> Generator1D for DistanceLod<1, G> {}
+impl<G: Generator<2>> Generator2D for DistanceLod<2, G> {}
+impl<G: Generator<3>> Generator3D for DistanceLod<3, G> {}
+impl<G: Generator<4>> Generator4D for DistanceLod<4, G> {}
+
+impl<const D: usize, G> DistanceLod<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    pub fn new(generator: FbmLod<D, G>, focus: [f64; D], near: f64) -> Self {
+        Self {
+            generator,
+            focus,
+            near,
+        }
+    }
+}
+
+impl<const D: usize, G> Generator<D> for DistanceLod<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        let distance = (0..D)
+            .map(|i| (point[i] - self.focus[i]) * (point[i] - self.focus[i]))
+            .sum::<f64>()
+            .sqrt();
+        let lod = (distance / self.near).log2().max(0.0);
+        self.generator.sample_lod(point, lod)
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        self.generator.sample_interval(min, max)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("DistanceLod")
+            .with_array_param("focus", &self.focus)
+            .with_param("near", self.near)
+            .with_child("source", self.generator.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::DistanceLod {
+            source: recipe::child("source", &self.generator.generator)?,
+            octaves: self.generator.octaves,
+            frequency: self.generator.frequency,
+            lacunarity: self.generator.lacunarity,
+            persistence: self.generator.persistence,
+            focus: self.focus.to_vec(),
+            near: self.near,
+        })
+    }
+}
+
+impl_display!([const D: usize, G] FbmLod<D, G>);
+impl_display!([const D: usize, G] DistanceLod<D, G>);
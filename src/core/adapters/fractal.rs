@@ -0,0 +1,96 @@
+use crate::core::error::Error;
+use alloc::format;
+
+// the largest persistence accepted by fractal adapters
+pub(crate) const MAX_PERSISTENCE: f64 = 1e9;
+
+// how the amplitude of successive octaves of a fractal adapter evolves
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Decay {
+    // amplitudes are multiplied by the persistence with every octave
+    Persistence(f64),
+    // weights are divided by the attenuation with every octave
+    Attenuation(f64),
+}
+
+// validates the parameters shared by fractal adapters, returning the name of the offending
+// parameter, the reason for rejecting it, and the value it was given
+pub(crate) fn validate(
+    octaves: u32,
+    frequency: f64,
+    lacunarity: f64,
+    decay: Decay,
+) -> Result<(), (&'static str, &'static str, f64)> {
+    if octaves == 0 {
+        return Err(("octaves", "must be at least 1", 0.0));
+    }
+    if !(frequency > 0.0 && frequency.is_finite()) {
+        return Err(("frequency", "must be positive and finite", frequency));
+    }
+    if !(lacunarity > 0.0 && lacunarity.is_finite()) {
+        return Err(("lacunarity", "must be positive and finite", lacunarity));
+    }
+    match decay {
+        Decay::Persistence(persistence) => {
+            if !(0.0..=MAX_PERSISTENCE).contains(&persistence) {
+                return Err(("persistence", "must be within [0, 1e9]", persistence));
+            }
+            if !total_weight(octaves, |amp| amp * persistence).is_finite() {
+                return Err((
+                    "persistence",
+                    "must not let the octave amplitudes overflow",
+                    persistence,
+                ));
+            }
+        }
+        Decay::Attenuation(attenuation) => {
+            if !(attenuation > 0.0 && attenuation.is_finite()) {
+                return Err(("attenuation", "must be positive and finite", attenuation));
+            }
+            if !total_weight(octaves, |weight| weight / attenuation).is_finite() {
+                return Err((
+                    "attenuation",
+                    "must not let the octave weights overflow",
+                    attenuation,
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+// like validate(), but reports the offending parameter and its value as an error
+pub(crate) fn check(
+    octaves: u32,
+    frequency: f64,
+    lacunarity: f64,
+    decay: Decay,
+) -> Result<(), Error> {
+    validate(octaves, frequency, lacunarity, decay).map_err(|(name, reason, value)| {
+        Error::InvalidParameter {
+            name,
+            reason: format!("{}, got {}", reason, value),
+        }
+    })
+}
+
+// like check(), but panics on invalid parameters in debug builds, and does nothing otherwise
+#[inline]
+#[track_caller]
+pub(crate) fn debug_check(octaves: u32, frequency: f64, lacunarity: f64, decay: Decay) {
+    if cfg!(debug_assertions) {
+        if let Err(error) = check(octaves, frequency, lacunarity, decay) {
+            panic!("{}", error);
+        }
+    }
+}
+
+// accumulates the octave weights the same way as when sampling
+fn total_weight(octaves: u32, next: impl Fn(f64) -> f64) -> f64 {
+    let mut weight = 1.0;
+    (0..octaves).fold(0.0, |acc, _| {
+        let acc = acc + weight;
+        weight = next(weight);
+        acc
+    })
+}
@@ -1,4 +1,7 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    utils::describe::{impl_display, Description},
+};
 
 /// A generator applying the supplied closure to results of the underlying generator.
 ///
@@ -38,4 +41,12 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         (self.lambda)(self.generator.sample(point))
     }
+
+    fn describe(&self) -> Description {
+        Description::new("Lambda")
+            .with_param("lambda", "<closure>")
+            .with_child("source", self.generator.describe())
+    }
 }
+
+impl_display!([const D: usize, G, L] Lambda<D, G, L>);
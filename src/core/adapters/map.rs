@@ -0,0 +1,62 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    utils::describe::{impl_display, Description},
+};
+use core::fmt;
+
+/// A generator applying the supplied closure to results of the underlying generator.
+///
+/// For details, see the documentation of [`map()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`map()`] implemented by [`Generator`], should be used
+/// to create [`Map`].
+///
+/// [`map()`]: Generator::map
+#[derive(Clone, Copy)]
+pub struct Map<const D: usize, G, F> {
+    generator: G,
+    map: F,
+}
+
+impl<G: Generator<1>, F: Fn(f64) -> f64> Generator1D for Map<1, G, F> {}
+impl<G: Generator<2>, F: Fn(f64) -> f64> Generator2D for Map<2, G, F> {}
+impl<G: Generator<3>, F: Fn(f64) -> f64> Generator3D for Map<3, G, F> {}
+impl<G: Generator<4>, F: Fn(f64) -> f64> Generator4D for Map<4, G, F> {}
+
+impl<const D: usize, G, F> Map<D, G, F>
+where
+    G: Generator<D>,
+    F: Fn(f64) -> f64,
+{
+    #[inline]
+    pub fn new(generator: G, map: F) -> Self {
+        Self { generator, map }
+    }
+}
+
+impl<const D: usize, G, F> Generator<D> for Map<D, G, F>
+where
+    G: Generator<D>,
+    F: Fn(f64) -> f64,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        (self.map)(self.generator.sample(point))
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Map")
+            .with_param("map", "<closure>")
+            .with_child("source", self.generator.describe())
+    }
+}
+
+impl<const D: usize, G: fmt::Debug, F> fmt::Debug for Map<D, G, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Map")
+            .field("generator", &self.generator)
+            .field("map", &format_args!("<closure>"))
+            .finish()
+    }
+}
+
+impl_display!([const D: usize, G, F] Map<D, G, F>);
@@ -1,4 +1,11 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+    },
+};
 
 /// A generator producing the maximum of results of the underlying generator and results of
 /// a given other generator.
@@ -9,6 +16,7 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 ///
 /// [`max()`]: Generator::max
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Max<const D: usize, GA, GB> {
     generator_a: GA,
     generator_b: GB,
@@ -40,8 +48,35 @@ where
 {
     #[inline]
     fn sample(&self, point: [f64; D]) -> f64 {
-        self.generator_a
-            .sample(point)
-            .max(self.generator_b.sample(point))
+        let a = self.generator_a.sample(point);
+        let b = self.generator_b.sample(point);
+        // unlike f64::max, propagate NaN from either generator
+        if a.is_nan() || b.is_nan() {
+            f64::NAN
+        } else {
+            a.max(b)
+        }
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        let a = self.generator_a.sample_interval(min, max);
+        let b = self.generator_b.sample_interval(min, max);
+        interval::max(a, b)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Max")
+            .with_child("a", self.generator_a.describe())
+            .with_child("b", self.generator_b.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Max {
+            a: recipe::child("a", &self.generator_a)?,
+            b: recipe::child("b", &self.generator_b)?,
+        })
     }
 }
+
+impl_display!([const D: usize, GA, GB] Max<D, GA, GB>);
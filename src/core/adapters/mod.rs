@@ -1,12 +1,19 @@
 mod abs;
 mod add;
+mod animated;
 mod billow;
 mod blend;
+mod bundle;
+mod checked;
 mod clamp;
+mod component;
 mod displace;
 mod exp;
 mod fbm;
+mod fbm_lod;
+pub(crate) mod fractal;
 mod lambda;
+mod map;
 mod max;
 mod min;
 mod mul;
@@ -19,16 +26,26 @@ mod rotate;
 mod scale;
 mod select;
 mod sum;
+mod tileable;
 mod translate;
+mod warp;
+mod zip3_with;
+mod zip_with;
 pub use abs::Abs;
 pub use add::Add;
+pub use animated::Animated2D;
 pub use billow::Billow;
 pub use blend::Blend;
+pub use bundle::Bundle;
+pub use checked::Checked;
 pub use clamp::Clamp;
+pub use component::Component;
 pub use displace::Displace;
 pub use exp::Exp;
-pub use fbm::Fbm;
+pub use fbm::{Fbm, FbmOctave};
+pub use fbm_lod::{DistanceLod, FbmLod};
 pub use lambda::Lambda;
+pub use map::Map;
 pub use max::Max;
 pub use min::Min;
 pub use mul::Mul;
@@ -41,4 +58,8 @@ pub use rotate::Rotate;
 pub use scale::Scale;
 pub use select::Select;
 pub use sum::Sum;
+pub use tileable::Tileable2D;
 pub use translate::Translate;
+pub use warp::Warp;
+pub use zip3_with::Zip3With;
+pub use zip_with::ZipWith;
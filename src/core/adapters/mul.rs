@@ -1,4 +1,11 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+    },
+};
 
 /// A generator multiplying `scale` to results of the underlying generator.
 ///
@@ -8,6 +15,7 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 ///
 /// [`mul()`]: Generator::mul
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Mul<const D: usize, G> {
     generator: G,
     scale: f64,
@@ -36,4 +44,24 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator.sample(point) * self.scale
     }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        interval::scale(self.generator.sample_interval(min, max), self.scale)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Mul")
+            .with_param("scale", self.scale)
+            .with_child("source", self.generator.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Mul {
+            source: recipe::child("source", &self.generator)?,
+            scale: self.scale,
+        })
+    }
 }
+
+impl_display!([const D: usize, G] Mul<D, G>);
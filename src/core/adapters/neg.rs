@@ -1,4 +1,11 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+    },
+};
 
 /// A generator which negates the results of the underlying generator.
 ///
@@ -8,6 +15,7 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 ///
 /// [`neg()`]: Generator::neg
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Neg<const D: usize, G> {
     generator: G,
 }
@@ -35,4 +43,21 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         -self.generator.sample(point)
     }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        interval::neg(self.generator.sample_interval(min, max))
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Neg").with_child("source", self.generator.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Neg {
+            source: recipe::child("source", &self.generator)?,
+        })
+    }
 }
+
+impl_display!([const D: usize, G] Neg<D, G>);
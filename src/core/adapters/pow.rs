@@ -1,4 +1,13 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+    },
+};
 
 /// A generator raising results of the underlying generator to the power of `exponent`.
 ///
@@ -9,6 +18,7 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 /// [`powi()`]: Generator::powi
 /// [`powf()`]: Generator::powf
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pow<const D: usize, G, T> {
     generator: G,
     exponent: T,
@@ -45,6 +55,24 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator.sample(point).powi(self.exponent)
     }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        interval::powi(self.generator.sample_interval(min, max), self.exponent)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Pow")
+            .with_param("exponent", self.exponent)
+            .with_child("source", self.generator.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Powi {
+            source: recipe::child("source", &self.generator)?,
+            exponent: self.exponent,
+        })
+    }
 }
 
 impl<const D: usize, G: Generator<D>> Generator<D> for Pow<D, G, f64>
@@ -55,4 +83,24 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator.sample(point).powf(self.exponent)
     }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        interval::powf(self.generator.sample_interval(min, max), self.exponent)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Pow")
+            .with_param("exponent", self.exponent)
+            .with_child("source", self.generator.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Powf {
+            source: recipe::child("source", &self.generator)?,
+            exponent: self.exponent,
+        })
+    }
 }
+
+impl_display!([const D: usize, G, T] Pow<D, G, T>);
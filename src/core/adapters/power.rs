@@ -1,4 +1,13 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+    },
+};
 
 /// A generator raising results of the underlying generator to the power of results of a
 /// given other generator.
@@ -9,6 +18,7 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 ///
 /// [`power()`]: Generator::power
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Power<const D: usize, GA, GB> {
     generator_a: GA,
     generator_b: GB,
@@ -44,4 +54,26 @@ where
             .sample(point)
             .powf(self.generator_b.sample(point))
     }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        let base = self.generator_a.sample_interval(min, max);
+        let exponent = self.generator_b.sample_interval(min, max);
+        interval::pow(base, exponent)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Power")
+            .with_child("a", self.generator_a.describe())
+            .with_child("b", self.generator_b.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Power {
+            a: recipe::child("a", &self.generator_a)?,
+            b: recipe::child("b", &self.generator_b)?,
+        })
+    }
 }
+
+impl_display!([const D: usize, GA, GB] Power<D, GA, GB>);
@@ -1,4 +1,11 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+    },
+};
 
 /// A generator multiplying results of the underlying generator to results of a given other
 /// generator.
@@ -9,6 +16,7 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 ///
 /// [`product()`]: Generator::product
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Product<const D: usize, GA, GB> {
     generator_a: GA,
     generator_b: GB,
@@ -42,4 +50,26 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator_a.sample(point) * self.generator_b.sample(point)
     }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        let a = self.generator_a.sample_interval(min, max);
+        let b = self.generator_b.sample_interval(min, max);
+        interval::mul(a, b)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Product")
+            .with_child("a", self.generator_a.describe())
+            .with_child("b", self.generator_b.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Product {
+            a: recipe::child("a", &self.generator_a)?,
+            b: recipe::child("b", &self.generator_b)?,
+        })
+    }
 }
+
+impl_display!([const D: usize, GA, GB] Product<D, GA, GB>);
@@ -1,4 +1,14 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    adapters::fractal::{self, Decay},
+    error::Error,
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+        param::Parameter,
+    },
+};
 
 /// Create a generator applying an `fbm()`-like effect on the underlying generator.
 ///
@@ -9,33 +19,112 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 /// [`fbm()`]: Generator:fbm
 /// [`ridgedmulti()`]: Generator::ridgedmulti
 #[derive(Clone, Copy, Debug)]
-pub struct RidgedMulti<const D: usize, G> {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "RidgedMultiParams<D, G, P>",
+        bound(deserialize = "G: serde::Deserialize<'de>, P: Parameter + serde::Deserialize<'de>")
+    )
+)]
+pub struct RidgedMulti<const D: usize, G, P = f64> {
     generator: G,
     octaves: u32,
-    frequency: f64,
-    lacunarity: f64,
-    attenuation: f64,
+    frequency: P,
+    lacunarity: P,
+    attenuation: P,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     normalization_factor: f64,
 }
 
-impl<G: Generator<1>> Generator1D for RidgedMulti<1, G> {}
-impl<G: Generator<2>> Generator2D for RidgedMulti<2, G> {}
-impl<G: Generator<3>> Generator3D for RidgedMulti<3, G> {}
-impl<G: Generator<4>> Generator4D for RidgedMulti<4, G> {}
+// the normalization factor is derived from the other parameters rather than deserialized, which
+// are validated like try_new() on deserialization
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RidgedMultiParams<const D: usize, G, P> {
+    generator: G,
+    octaves: u32,
+    frequency: P,
+    lacunarity: P,
+    attenuation: P,
+}
+
+#[cfg(feature = "serde")]
+impl<const D: usize, G, P: Parameter> TryFrom<RidgedMultiParams<D, G, P>> for RidgedMulti<D, G, P> {
+    type Error = Error;
+
+    fn try_from(params: RidgedMultiParams<D, G, P>) -> Result<Self, Self::Error> {
+        fractal::check(
+            params.octaves,
+            params.frequency.get(),
+            params.lacunarity.get(),
+            Decay::Attenuation(params.attenuation.get()),
+        )?;
+        let normalization_factor =
+            compute_normalization_factor(params.octaves, params.attenuation.get());
+        Ok(Self {
+            generator: params.generator,
+            octaves: params.octaves,
+            frequency: params.frequency,
+            lacunarity: params.lacunarity,
+            attenuation: params.attenuation,
+            normalization_factor,
+        })
+    }
+}
+
+impl<G: Generator<1>, P: Parameter> Generator1D for RidgedMulti<1, G, P> {}
+impl<G: Generator<2>, P: Parameter> Generator2D for RidgedMulti<2, G, P> {}
+impl<G: Generator<3>, P: Parameter> Generator3D for RidgedMulti<3, G, P> {}
+impl<G: Generator<4>, P: Parameter> Generator4D for RidgedMulti<4, G, P> {}
 
-impl<const D: usize, G> RidgedMulti<D, G>
+impl<const D: usize, G, P> RidgedMulti<D, G, P>
 where
     G: Generator<D>,
+    P: Parameter,
 {
-    #[inline]
-    pub fn new(
+    /// Create a new [`RidgedMulti`], validating the parameters.
+    ///
+    /// This is the fallible counterpart of [`ridgedmulti()`], rejecting the parameters for which
+    /// it panics in debug builds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] naming the first invalid parameter and its value.
+    ///
+    /// [`ridgedmulti()`]: Generator::ridgedmulti
+    pub fn try_new(
         generator: G,
         octaves: u32,
-        frequency: f64,
-        lacunarity: f64,
-        attenuation: f64,
-    ) -> Self {
-        let normalization_factor = compute_normalization_factor(octaves, attenuation);
+        frequency: P,
+        lacunarity: P,
+        attenuation: P,
+    ) -> Result<Self, Error> {
+        fractal::check(
+            octaves,
+            frequency.get(),
+            lacunarity.get(),
+            Decay::Attenuation(attenuation.get()),
+        )?;
+        Ok(Self::new(
+            generator,
+            octaves,
+            frequency,
+            lacunarity,
+            attenuation,
+        ))
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn new(generator: G, octaves: u32, frequency: P, lacunarity: P, attenuation: P) -> Self {
+        fractal::debug_check(
+            octaves,
+            frequency.get(),
+            lacunarity.get(),
+            Decay::Attenuation(attenuation.get()),
+        );
+        let normalization_factor = compute_normalization_factor(octaves, attenuation.get());
         Self {
             generator,
             octaves,
@@ -47,22 +136,77 @@ where
     }
 }
 
+impl<const D: usize, G, P: Parameter> RidgedMulti<D, G, P> {
+    // the precomputed normalization factor, or the factor for the current attenuation if it is
+    // tweakable
+    #[inline]
+    fn current_normalization_factor(&self, attenuation: f64) -> f64 {
+        if P::FIXED {
+            self.normalization_factor
+        } else {
+            compute_normalization_factor(self.octaves, attenuation)
+        }
+    }
+}
+
 macro_rules! impl_generator {
     ($dim:literal) => {
-        impl<G: Generator<$dim>> Generator<$dim> for RidgedMulti<$dim, G> {
+        impl<G: Generator<$dim>, P: Parameter> Generator<$dim> for RidgedMulti<$dim, G, P> {
             fn sample(&self, point: [f64; $dim]) -> f64 {
                 let mut noise = 0.0;
                 let mut amp = 1.0;
-                let mut freq = self.frequency;
+                let lacunarity = self.lacunarity.get();
+                let attenuation = self.attenuation.get();
+                let mut freq = self.frequency.get();
                 for _ in 0..self.octaves {
                     let mut layer = 1.0 - self.generator.sample(point.map(|x| x * freq)).abs();
                     layer *= layer;
                     layer *= amp;
                     noise += layer;
-                    freq *= self.lacunarity;
-                    amp = (layer / self.attenuation).clamp(0.0, 1.0);
+                    freq *= lacunarity;
+                    amp = (layer / attenuation).clamp(0.0, 1.0);
                 }
-                (noise * self.normalization_factor).mul_add(2.0, -1.0)
+                noise * self.current_normalization_factor(attenuation) * 2.0 - 1.0
+            }
+
+            fn sample_interval(&self, min: [f64; $dim], max: [f64; $dim]) -> (f64, f64) {
+                let mut noise = (0.0, 0.0);
+                let mut amp = (1.0, 1.0);
+                let lacunarity = self.lacunarity.get();
+                let attenuation = self.attenuation.get();
+                let mut freq = self.frequency.get();
+                for _ in 0..self.octaves {
+                    let (min, max) = interval::scale_box(min, max, [freq; $dim]);
+                    let octave = interval::abs(self.generator.sample_interval(min, max));
+                    let mut layer = interval::offset(interval::neg(octave), 1.0);
+                    layer = interval::powi(layer, 2);
+                    layer = interval::mul(layer, amp);
+                    noise = interval::add(noise, layer);
+                    freq *= lacunarity;
+                    amp = interval::hull_of([layer.0 / attenuation, layer.1 / attenuation]);
+                    amp = interval::clamp(amp, 0.0, 1.0);
+                }
+                let noise = interval::scale(noise, self.current_normalization_factor(attenuation));
+                interval::offset(interval::scale(noise, 2.0), -1.0)
+            }
+
+            fn describe(&self) -> Description {
+                Description::new("RidgedMulti")
+                    .with_param("octaves", self.octaves)
+                    .with_param("frequency", self.frequency.get())
+                    .with_param("lacunarity", self.lacunarity.get())
+                    .with_param("attenuation", self.attenuation.get())
+                    .with_child("source", self.generator.describe())
+            }
+
+            fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+                Ok(NoiseRecipe::RidgedMulti {
+                    source: recipe::child("source", &self.generator)?,
+                    octaves: self.octaves,
+                    frequency: self.frequency.get(),
+                    lacunarity: self.lacunarity.get(),
+                    attenuation: self.attenuation.get(),
+                })
             }
         }
     };
@@ -75,7 +219,14 @@ impl_generator!(4);
 
 #[inline]
 fn compute_normalization_factor(octaves: u32, attenuation: f64) -> f64 {
-    1.0 / (0..octaves).fold(0.0, |acc, octave| {
-        acc + (1.0 / attenuation).powi(octave as i32)
+    // accumulate weights iteratively rather than using powi, which is not guaranteed to produce
+    // identical results across platforms
+    let mut weight = 1.0;
+    1.0 / (0..octaves).fold(0.0, |acc, _| {
+        let acc = acc + weight;
+        weight /= attenuation;
+        acc
     })
 }
+
+impl_display!([const D: usize, G, P] RidgedMulti<D, G, P>);
@@ -1,4 +1,13 @@
-use crate::core::generator::{Generator, Generator2D, Generator3D, Generator4D};
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::{
+    generator::{Generator, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+    },
+};
 
 /// A generator which rotates input points before passing them to the underlying generator.
 ///
@@ -8,8 +17,10 @@ use crate::core::generator::{Generator, Generator2D, Generator3D, Generator4D};
 ///
 /// [`rotate()`]: Generator2D::rotate
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rotate<const D: usize, const P: usize, G> {
     generator: G,
+    #[cfg_attr(feature = "serde", serde(with = "crate::core::utils::serde_array"))]
     rotation: [f64; P],
 }
 
@@ -30,8 +41,9 @@ where
     }
 }
 
-impl<G: Generator<2>> Generator<2> for Rotate<2, 1, G> {
-    fn sample(&self, point: [f64; 2]) -> f64 {
+impl<G: Generator<2>> Rotate<2, 1, G> {
+    #[inline]
+    pub(crate) fn rotate(&self, point: [f64; 2]) -> [f64; 2] {
         let x = point[0];
         let y = point[1];
 
@@ -41,12 +53,37 @@ impl<G: Generator<2>> Generator<2> for Rotate<2, 1, G> {
         let xr = x * cos_theta - y * sin_theta;
         let yr = x * sin_theta + y * cos_theta;
 
-        self.generator.sample([xr, yr])
+        [xr, yr]
     }
 }
 
-impl<G: Generator<3>> Generator<3> for Rotate<3, 3, G> {
-    fn sample(&self, point: [f64; 3]) -> f64 {
+impl<G: Generator<2>> Generator<2> for Rotate<2, 1, G> {
+    fn sample(&self, point: [f64; 2]) -> f64 {
+        self.generator.sample(self.rotate(point))
+    }
+
+    fn sample_interval(&self, min: [f64; 2], max: [f64; 2]) -> (f64, f64) {
+        let (min, max) = interval::transform_box(min, max, |point| self.rotate(point));
+        self.generator.sample_interval(min, max)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Rotate")
+            .with_array_param("rotation", &self.rotation)
+            .with_child("source", self.generator.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Rotate {
+            source: recipe::child("source", &self.generator)?,
+            rotation: self.rotation.to_vec(),
+        })
+    }
+}
+
+impl<G: Generator<3>> Rotate<3, 3, G> {
+    #[inline]
+    pub(crate) fn rotate(&self, point: [f64; 3]) -> [f64; 3] {
         let x = point[0];
         let y = point[1];
         let z = point[2];
@@ -66,12 +103,37 @@ impl<G: Generator<3>> Generator<3> for Rotate<3, 3, G> {
         let zr =
             cos_a * (sin_b * (x * cos_g + y * sin_g) + z * cos_b) + sin_a * (x * sin_g - y * cos_g);
 
-        self.generator.sample([xr, yr, zr])
+        [xr, yr, zr]
     }
 }
 
-impl<G: Generator<4>> Generator<4> for Rotate<4, 6, G> {
-    fn sample(&self, point: [f64; 4]) -> f64 {
+impl<G: Generator<3>> Generator<3> for Rotate<3, 3, G> {
+    fn sample(&self, point: [f64; 3]) -> f64 {
+        self.generator.sample(self.rotate(point))
+    }
+
+    fn sample_interval(&self, min: [f64; 3], max: [f64; 3]) -> (f64, f64) {
+        let (min, max) = interval::transform_box(min, max, |point| self.rotate(point));
+        self.generator.sample_interval(min, max)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Rotate")
+            .with_array_param("rotation", &self.rotation)
+            .with_child("source", self.generator.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Rotate {
+            source: recipe::child("source", &self.generator)?,
+            rotation: self.rotation.to_vec(),
+        })
+    }
+}
+
+impl<G: Generator<4>> Rotate<4, 6, G> {
+    #[inline]
+    fn rotate(&self, point: [f64; 4]) -> [f64; 4] {
         let x = point[0];
         let y = point[1];
         let z = point[2];
@@ -129,6 +191,32 @@ impl<G: Generator<4>> Generator<4> for Rotate<4, 6, G> {
         let wr = cos_gamma * (cos_epsilon * (z * sin_digamma + w * cos_digamma) + y * sin_epsilon)
             + x * sin_gamma;
 
-        self.generator.sample([xr, yr, zr, wr])
+        [xr, yr, zr, wr]
     }
 }
+
+impl<G: Generator<4>> Generator<4> for Rotate<4, 6, G> {
+    fn sample(&self, point: [f64; 4]) -> f64 {
+        self.generator.sample(self.rotate(point))
+    }
+
+    fn sample_interval(&self, min: [f64; 4], max: [f64; 4]) -> (f64, f64) {
+        let (min, max) = interval::transform_box(min, max, |point| self.rotate(point));
+        self.generator.sample_interval(min, max)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Rotate")
+            .with_array_param("rotation", &self.rotation)
+            .with_child("source", self.generator.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Rotate {
+            source: recipe::child("source", &self.generator)?,
+            rotation: self.rotation.to_vec(),
+        })
+    }
+}
+
+impl_display!([const D: usize, const P: usize, G] Rotate<D, P, G>);
@@ -1,4 +1,12 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+        param::{self, Parameter},
+    },
+};
 
 /// A generator which scales input points before passing them to the underlying generator.
 ///
@@ -8,33 +16,65 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 ///
 /// [`scale()`]: Generator::scale
 #[derive(Clone, Copy, Debug)]
-pub struct Scale<const D: usize, G> {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "G: serde::Serialize, P: serde::Serialize",
+        deserialize = "G: serde::Deserialize<'de>, P: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Scale<const D: usize, G, P = f64> {
     generator: G,
-    scale: [f64; D],
+    #[cfg_attr(feature = "serde", serde(with = "crate::core::utils::serde_array"))]
+    scale: [P; D],
 }
 
-impl<G: Generator<1>> Generator1D for Scale<1, G> {}
-impl<G: Generator<2>> Generator2D for Scale<2, G> {}
-impl<G: Generator<3>> Generator3D for Scale<3, G> {}
-impl<G: Generator<4>> Generator4D for Scale<4, G> {}
+impl<G: Generator<1>, P: Parameter> Generator1D for Scale<1, G, P> {}
+impl<G: Generator<2>, P: Parameter> Generator2D for Scale<2, G, P> {}
+impl<G: Generator<3>, P: Parameter> Generator3D for Scale<3, G, P> {}
+impl<G: Generator<4>, P: Parameter> Generator4D for Scale<4, G, P> {}
 
-impl<const D: usize, G> Scale<D, G>
+impl<const D: usize, G, P> Scale<D, G, P>
 where
     G: Generator<D>,
+    P: Parameter,
 {
     #[inline]
-    pub fn new(generator: G, scale: [f64; D]) -> Self {
+    pub fn new(generator: G, scale: [P; D]) -> Self {
         Self { generator, scale }
     }
 }
 
-impl<const D: usize, G> Generator<D> for Scale<D, G>
+impl<const D: usize, G, P> Generator<D> for Scale<D, G, P>
 where
     G: Generator<D>,
+    P: Parameter,
 {
     #[inline]
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator
-            .sample(std::array::from_fn(|i| point[i] * self.scale[i]))
+            .sample(core::array::from_fn(|i| point[i] * self.scale[i].get()))
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        let (min, max) = interval::scale_box(min, max, param::values(&self.scale));
+        self.generator.sample_interval(min, max)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Scale")
+            .with_array_param("scale", &param::values(&self.scale))
+            .with_child("source", self.generator.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Scale {
+            source: recipe::child("source", &self.generator)?,
+            scale: param::values(&self.scale).to_vec(),
+        })
     }
 }
+
+impl_display!([const D: usize, G, P] Scale<D, G, P>);
@@ -1,4 +1,12 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+        param::Parameter,
+    },
+};
 
 /// Create a generator selecting the result of either the underlying generator or that of a given
 /// other generator based on whether the value supplied by a control-generator lies within the
@@ -10,32 +18,62 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 ///
 /// [`select()`]: Generator::select
 #[derive(Clone, Copy, Debug)]
-pub struct Select<const D: usize, GA, GB, GC> {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Select<const D: usize, GA, GB, GC, P = f64> {
     generator_a: GA,
     generator_b: GB,
     generator_control: GC,
-    selection_min: f64,
-    selection_max: f64,
+    selection_min: P,
+    selection_max: P,
 }
 
-impl<GA: Generator<1>, GB: Generator<1>, GC: Generator<1>> Generator1D for Select<1, GA, GB, GC> {}
-impl<GA: Generator<2>, GB: Generator<2>, GC: Generator<2>> Generator2D for Select<2, GA, GB, GC> {}
-impl<GA: Generator<3>, GB: Generator<3>, GC: Generator<3>> Generator3D for Select<3, GA, GB, GC> {}
-impl<GA: Generator<4>, GB: Generator<4>, GC: Generator<4>> Generator4D for Select<4, GA, GB, GC> {}
+impl<GA, GB, GC, P> Generator1D for Select<1, GA, GB, GC, P>
+where
+    GA: Generator<1>,
+    GB: Generator<1>,
+    GC: Generator<1>,
+    P: Parameter,
+{
+}
+impl<GA, GB, GC, P> Generator2D for Select<2, GA, GB, GC, P>
+where
+    GA: Generator<2>,
+    GB: Generator<2>,
+    GC: Generator<2>,
+    P: Parameter,
+{
+}
+impl<GA, GB, GC, P> Generator3D for Select<3, GA, GB, GC, P>
+where
+    GA: Generator<3>,
+    GB: Generator<3>,
+    GC: Generator<3>,
+    P: Parameter,
+{
+}
+impl<GA, GB, GC, P> Generator4D for Select<4, GA, GB, GC, P>
+where
+    GA: Generator<4>,
+    GB: Generator<4>,
+    GC: Generator<4>,
+    P: Parameter,
+{
+}
 
-impl<const D: usize, GA, GB, GC> Select<D, GA, GB, GC>
+impl<const D: usize, GA, GB, GC, P> Select<D, GA, GB, GC, P>
 where
     GA: Generator<D>,
     GB: Generator<D>,
     GC: Generator<D>,
+    P: Parameter,
 {
     #[inline]
     pub fn new(
         generator_a: GA,
         generator_b: GB,
         generator_control: GC,
-        selection_min: f64,
-        selection_max: f64,
+        selection_min: P,
+        selection_max: P,
     ) -> Self {
         Self {
             generator_a,
@@ -47,19 +85,57 @@ where
     }
 }
 
-impl<const D: usize, GA, GB, GC> Generator<D> for Select<D, GA, GB, GC>
+impl<const D: usize, GA, GB, GC, P> Generator<D> for Select<D, GA, GB, GC, P>
 where
     GA: Generator<D>,
     GB: Generator<D>,
     GC: Generator<D>,
+    P: Parameter,
 {
     #[inline]
     fn sample(&self, point: [f64; D]) -> f64 {
+        let (selection_min, selection_max) = (self.selection_min.get(), self.selection_max.get());
         match self.generator_control.sample(point) {
-            t if self.selection_min <= t && t <= self.selection_max => {
-                self.generator_a.sample(point)
-            }
+            t if selection_min <= t && t <= selection_max => self.generator_a.sample(point),
+            t if t.is_nan() => t,
             _ => self.generator_b.sample(point),
         }
     }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        let (selection_min, selection_max) = (self.selection_min.get(), self.selection_max.get());
+        let (lower, upper) = self.generator_control.sample_interval(min, max);
+        if selection_min <= lower && upper <= selection_max {
+            self.generator_a.sample_interval(min, max)
+        } else if upper < selection_min || selection_max < lower {
+            self.generator_b.sample_interval(min, max)
+        } else {
+            interval::hull(
+                self.generator_a.sample_interval(min, max),
+                self.generator_b.sample_interval(min, max),
+            )
+        }
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Select")
+            .with_param("selection_min", self.selection_min.get())
+            .with_param("selection_max", self.selection_max.get())
+            .with_child("a", self.generator_a.describe())
+            .with_child("b", self.generator_b.describe())
+            .with_child("control", self.generator_control.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Select {
+            a: recipe::child("a", &self.generator_a)?,
+            b: recipe::child("b", &self.generator_b)?,
+            control: recipe::child("control", &self.generator_control)?,
+            selection_min: self.selection_min.get(),
+            selection_max: self.selection_max.get(),
+        })
+    }
 }
+
+impl_display!([const D: usize, GA, GB, GC, P] Select<D, GA, GB, GC, P>);
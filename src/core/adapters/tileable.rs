@@ -0,0 +1,92 @@
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::{
+    generator::{Generator, Generator2D},
+    utils::describe::Description,
+};
+use core::{f64::consts::TAU, fmt};
+
+/// A generator which maps 2-dimensional input points onto a torus in 4-dimensional space before
+/// passing them to the underlying generator.
+///
+/// For details, see the documentation of [`tileable_2d()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`tileable_2d()`] implemented by [`Generator4D`], should be used
+/// to create [`Tileable2D`].
+///
+/// [`tileable_2d()`]: crate::Generator4D::tileable_2d
+/// [`Generator4D`]: crate::Generator4D
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tileable2D<G> {
+    generator: G,
+    period: [f64; 2],
+}
+
+impl<G: Generator<4>> Generator2D for Tileable2D<G> {}
+
+impl<G: Generator<4>> Tileable2D<G> {
+    /// Create a new generator tiling with the given `period` along each axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any component of `period` is not positive and finite.
+    #[inline]
+    pub fn new(generator: G, period: [f64; 2]) -> Self {
+        assert!(
+            period.iter().all(|p| p.is_finite() && *p > 0.0),
+            "period must be positive and finite along every axis, got {:?}",
+            period
+        );
+        Self { generator, period }
+    }
+
+    // maps each coordinate onto a circle with a circumference of the respective period. the
+    // coordinate is reduced to the period first, such that points an integer multiple of the
+    // period apart map to the same angle.
+    #[inline]
+    fn to_torus(&self, point: [f64; 2]) -> [f64; 4] {
+        let [(sin_x, cos_x), (sin_y, cos_y)] = core::array::from_fn(|i| {
+            let angle = point[i].rem_euclid(self.period[i]) / self.period[i] * TAU;
+            (angle.sin(), angle.cos())
+        });
+        let [radius_x, radius_y] = self.radii();
+        [
+            radius_x * cos_x,
+            radius_x * sin_x,
+            radius_y * cos_y,
+            radius_y * sin_y,
+        ]
+    }
+
+    #[inline]
+    fn radii(&self) -> [f64; 2] {
+        self.period.map(|p| p / TAU)
+    }
+}
+
+impl<G: Generator<4>> Generator<2> for Tileable2D<G> {
+    #[inline]
+    fn sample(&self, point: [f64; 2]) -> f64 {
+        self.generator.sample(self.to_torus(point))
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; 2], _max: [f64; 2]) -> (f64, f64) {
+        // every point maps onto the torus, which is contained in this box
+        let [radius_x, radius_y] = self.radii();
+        let extent = [radius_x, radius_x, radius_y, radius_y];
+        self.generator.sample_interval(extent.map(|r| -r), extent)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Tileable2D")
+            .with_array_param("period", &self.period)
+            .with_child("source", self.generator.describe())
+    }
+}
+
+impl<G: Generator<4>> fmt::Display for Tileable2D<G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.describe(), f)
+    }
+}
@@ -1,4 +1,11 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{self, NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        param::{self, Parameter},
+    },
+};
 
 /// A generator which translates input points before passing them to the underlying generator.
 ///
@@ -8,22 +15,32 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 ///
 /// [`translate()`]: Generator::translate
 #[derive(Clone, Copy, Debug)]
-pub struct Translate<const D: usize, G> {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "G: serde::Serialize, P: serde::Serialize",
+        deserialize = "G: serde::Deserialize<'de>, P: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Translate<const D: usize, G, P = f64> {
     generator: G,
-    translation: [f64; D],
+    #[cfg_attr(feature = "serde", serde(with = "crate::core::utils::serde_array"))]
+    translation: [P; D],
 }
 
-impl<G: Generator<1>> Generator1D for Translate<1, G> {}
-impl<G: Generator<2>> Generator2D for Translate<2, G> {}
-impl<G: Generator<3>> Generator3D for Translate<3, G> {}
-impl<G: Generator<4>> Generator4D for Translate<4, G> {}
+impl<G: Generator<1>, P: Parameter> Generator1D for Translate<1, G, P> {}
+impl<G: Generator<2>, P: Parameter> Generator2D for Translate<2, G, P> {}
+impl<G: Generator<3>, P: Parameter> Generator3D for Translate<3, G, P> {}
+impl<G: Generator<4>, P: Parameter> Generator4D for Translate<4, G, P> {}
 
-impl<const D: usize, G> Translate<D, G>
+impl<const D: usize, G, P> Translate<D, G, P>
 where
     G: Generator<D>,
+    P: Parameter,
 {
     #[inline]
-    pub fn new(generator: G, translation: [f64; D]) -> Self {
+    pub fn new(generator: G, translation: [P; D]) -> Self {
         Self {
             generator,
             translation,
@@ -31,13 +48,39 @@ where
     }
 }
 
-impl<const D: usize, G> Generator<D> for Translate<D, G>
+impl<const D: usize, G, P> Generator<D> for Translate<D, G, P>
 where
     G: Generator<D>,
+    P: Parameter,
 {
     #[inline]
     fn sample(&self, point: [f64; D]) -> f64 {
-        self.generator
-            .sample(std::array::from_fn(|i| point[i] + self.translation[i]))
+        self.generator.sample(core::array::from_fn(|i| {
+            point[i] + self.translation[i].get()
+        }))
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        let translation = param::values(&self.translation);
+        self.generator.sample_interval(
+            core::array::from_fn(|i| min[i] + translation[i]),
+            core::array::from_fn(|i| max[i] + translation[i]),
+        )
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Translate")
+            .with_array_param("translation", &param::values(&self.translation))
+            .with_child("source", self.generator.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Translate {
+            source: recipe::child("source", &self.generator)?,
+            translation: param::values(&self.translation).to_vec(),
+        })
     }
 }
+
+impl_display!([const D: usize, G, P] Translate<D, G, P>);
@@ -0,0 +1,68 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D, VectorGenerator},
+    utils::describe::{impl_display, Description},
+};
+
+/// A generator displacing input points by the results of a vector generator before passing them
+/// to the underlying generator.
+///
+/// For details, see the documentation of [`warp()`]. Typically, this struct is not meant to be
+/// used directly. Instead, [`warp()`] implemented by [`Generator`], should be used to create
+/// [`Warp`].
+///
+/// [`warp()`]: Generator::warp
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Warp<const D: usize, G, V> {
+    generator: G,
+    offset_generator: V,
+}
+
+impl<G: Generator<1>, V: VectorGenerator<1, 1>> Generator1D for Warp<1, G, V> {}
+impl<G: Generator<2>, V: VectorGenerator<2, 2>> Generator2D for Warp<2, G, V> {}
+impl<G: Generator<3>, V: VectorGenerator<3, 3>> Generator3D for Warp<3, G, V> {}
+impl<G: Generator<4>, V: VectorGenerator<4, 4>> Generator4D for Warp<4, G, V> {}
+
+impl<const D: usize, G, V> Warp<D, G, V>
+where
+    G: Generator<D>,
+    V: VectorGenerator<D, D>,
+{
+    #[inline]
+    pub fn new(generator: G, offset_generator: V) -> Self {
+        Self {
+            generator,
+            offset_generator,
+        }
+    }
+}
+
+impl<const D: usize, G, V> Generator<D> for Warp<D, G, V>
+where
+    G: Generator<D>,
+    V: VectorGenerator<D, D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        let offsets = self.offset_generator.sample(point);
+        self.generator
+            .sample(core::array::from_fn(|i| point[i] + offsets[i]))
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        let offsets = self.offset_generator.sample_interval(min, max);
+        self.generator.sample_interval(
+            core::array::from_fn(|i| min[i] + offsets[i].0),
+            core::array::from_fn(|i| max[i] + offsets[i].1),
+        )
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Warp")
+            .with_child("source", self.generator.describe())
+            .with_child("offsets", self.offset_generator.describe())
+    }
+}
+
+impl_display!([const D: usize, G, V] Warp<D, G, V>);
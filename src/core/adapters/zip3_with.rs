@@ -0,0 +1,118 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    utils::describe::{impl_display, Description},
+};
+use core::fmt;
+
+/// A generator combining results of the underlying generator and two given other generators
+/// using the supplied closure.
+///
+/// For details, see the documentation of [`zip3_with()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`zip3_with()`] implemented by [`Generator`], should be used
+/// to create [`Zip3With`].
+///
+/// [`zip3_with()`]: Generator::zip3_with
+#[derive(Clone, Copy)]
+pub struct Zip3With<const D: usize, GA, GB, GC, F> {
+    generator_a: GA,
+    generator_b: GB,
+    generator_c: GC,
+    zip: F,
+}
+
+impl<GA, GB, GC, F> Generator1D for Zip3With<1, GA, GB, GC, F>
+where
+    GA: Generator<1>,
+    GB: Generator<1>,
+    GC: Generator<1>,
+    F: Fn(f64, f64, f64) -> f64,
+{
+}
+
+impl<GA, GB, GC, F> Generator2D for Zip3With<2, GA, GB, GC, F>
+where
+    GA: Generator<2>,
+    GB: Generator<2>,
+    GC: Generator<2>,
+    F: Fn(f64, f64, f64) -> f64,
+{
+}
+
+impl<GA, GB, GC, F> Generator3D for Zip3With<3, GA, GB, GC, F>
+where
+    GA: Generator<3>,
+    GB: Generator<3>,
+    GC: Generator<3>,
+    F: Fn(f64, f64, f64) -> f64,
+{
+}
+
+impl<GA, GB, GC, F> Generator4D for Zip3With<4, GA, GB, GC, F>
+where
+    GA: Generator<4>,
+    GB: Generator<4>,
+    GC: Generator<4>,
+    F: Fn(f64, f64, f64) -> f64,
+{
+}
+
+impl<const D: usize, GA, GB, GC, F> Zip3With<D, GA, GB, GC, F>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+    GC: Generator<D>,
+    F: Fn(f64, f64, f64) -> f64,
+{
+    #[inline]
+    pub fn new(generator_a: GA, generator_b: GB, generator_c: GC, zip: F) -> Self {
+        Self {
+            generator_a,
+            generator_b,
+            generator_c,
+            zip,
+        }
+    }
+}
+
+impl<const D: usize, GA, GB, GC, F> Generator<D> for Zip3With<D, GA, GB, GC, F>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+    GC: Generator<D>,
+    F: Fn(f64, f64, f64) -> f64,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        (self.zip)(
+            self.generator_a.sample(point),
+            self.generator_b.sample(point),
+            self.generator_c.sample(point),
+        )
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Zip3With")
+            .with_param("zip", "<closure>")
+            .with_child("a", self.generator_a.describe())
+            .with_child("b", self.generator_b.describe())
+            .with_child("c", self.generator_c.describe())
+    }
+}
+
+impl<const D: usize, GA, GB, GC, F> fmt::Debug for Zip3With<D, GA, GB, GC, F>
+where
+    GA: fmt::Debug,
+    GB: fmt::Debug,
+    GC: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Zip3With")
+            .field("generator_a", &self.generator_a)
+            .field("generator_b", &self.generator_b)
+            .field("generator_c", &self.generator_c)
+            .field("zip", &format_args!("<closure>"))
+            .finish()
+    }
+}
+
+impl_display!([const D: usize, GA, GB, GC, F] Zip3With<D, GA, GB, GC, F>);
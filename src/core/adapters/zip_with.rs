@@ -0,0 +1,102 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    utils::describe::{impl_display, Description},
+};
+use core::fmt;
+
+/// A generator combining results of the underlying generator and a given other generator using
+/// the supplied closure.
+///
+/// For details, see the documentation of [`zip_with()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`zip_with()`] implemented by [`Generator`], should be used
+/// to create [`ZipWith`].
+///
+/// [`zip_with()`]: Generator::zip_with
+#[derive(Clone, Copy)]
+pub struct ZipWith<const D: usize, GA, GB, F> {
+    generator_a: GA,
+    generator_b: GB,
+    zip: F,
+}
+
+impl<GA, GB, F> Generator1D for ZipWith<1, GA, GB, F>
+where
+    GA: Generator<1>,
+    GB: Generator<1>,
+    F: Fn(f64, f64) -> f64,
+{
+}
+
+impl<GA, GB, F> Generator2D for ZipWith<2, GA, GB, F>
+where
+    GA: Generator<2>,
+    GB: Generator<2>,
+    F: Fn(f64, f64) -> f64,
+{
+}
+
+impl<GA, GB, F> Generator3D for ZipWith<3, GA, GB, F>
+where
+    GA: Generator<3>,
+    GB: Generator<3>,
+    F: Fn(f64, f64) -> f64,
+{
+}
+
+impl<GA, GB, F> Generator4D for ZipWith<4, GA, GB, F>
+where
+    GA: Generator<4>,
+    GB: Generator<4>,
+    F: Fn(f64, f64) -> f64,
+{
+}
+
+impl<const D: usize, GA, GB, F> ZipWith<D, GA, GB, F>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+    F: Fn(f64, f64) -> f64,
+{
+    #[inline]
+    pub fn new(generator_a: GA, generator_b: GB, zip: F) -> Self {
+        Self {
+            generator_a,
+            generator_b,
+            zip,
+        }
+    }
+}
+
+impl<const D: usize, GA, GB, F> Generator<D> for ZipWith<D, GA, GB, F>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+    F: Fn(f64, f64) -> f64,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        (self.zip)(
+            self.generator_a.sample(point),
+            self.generator_b.sample(point),
+        )
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("ZipWith")
+            .with_param("zip", "<closure>")
+            .with_child("a", self.generator_a.describe())
+            .with_child("b", self.generator_b.describe())
+    }
+}
+
+impl<const D: usize, GA: fmt::Debug, GB: fmt::Debug, F> fmt::Debug for ZipWith<D, GA, GB, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ZipWith")
+            .field("generator_a", &self.generator_a)
+            .field("generator_b", &self.generator_b)
+            .field("zip", &format_args!("<closure>"))
+            .finish()
+    }
+}
+
+impl_display!([const D: usize, GA, GB, F] ZipWith<D, GA, GB, F>);
@@ -0,0 +1,840 @@
+use super::{float, Binary, Lattice, Node, Octave, Program, Unary};
+use crate::core::{
+    sources::functional::constants::{
+        CORNERPOINT_GRADIENT_LUT_2D, CORNERPOINT_GRADIENT_LUT_3D, MIDPOINT_GRADIENT_LUT_2D,
+        MIDPOINT_GRADIENT_LUT_3D, PERMUTATION_TABLE_SIZE, SIMPLEX_NORMALIZATION_FACTOR_2D,
+        SIMPLEX_NORMALIZATION_FACTOR_3D, SIMPLEX_SKEW_FACTOR_2D, SIMPLEX_SKEW_FACTOR_3D,
+        SIMPLEX_TRAVERSAL_LUT_3D, SIMPLEX_UNSKEW_FACTOR_2D, SIMPLEX_UNSKEW_FACTOR_3D,
+    },
+    utils::ptable::PermutationTable,
+};
+use std::fmt::Write;
+
+/// A version of GLSL to generate code for.
+///
+/// The generated code does not contain a `#version` directive, as it is meant to be included in
+/// a shader declaring the version itself. The directive matching each version is returned by
+/// [`directive()`].
+///
+/// [`directive()`]: GlslVersion::directive
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GlslVersion {
+    /// GLSL 3.30, as used by OpenGL 3.3 core profiles.
+    Glsl330,
+    /// GLSL 4.50, as used by OpenGL 4.5 and, compiled to SPIR-V, by Vulkan.
+    Glsl450,
+    /// GLSL ES 3.00, as used by OpenGL ES 3.0 and WebGL 2. Fragment shaders including the
+    /// generated code must declare `precision highp float;`.
+    Es300,
+}
+
+impl GlslVersion {
+    /// Returns the `#version` directive of the version.
+    pub fn directive(&self) -> &'static str {
+        match self {
+            Self::Glsl330 => "#version 330 core",
+            Self::Glsl450 => "#version 450",
+            Self::Es300 => "#version 300 es",
+        }
+    }
+}
+
+/// How the permutation tables of the sources are provided to the generated code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PermutationStorage {
+    /// The tables are embedded in the generated code as a constant array. This requires no setup,
+    /// but large constant arrays compile slowly on some drivers.
+    ConstArray,
+    /// The tables are read from a texture, which the generated code declares as a uniform
+    /// `usampler2D` named after the function with a `_PERM` suffix. The texture contents are
+    /// returned by [`permutation_texture()`], and must be uploaded with a single unsigned
+    /// integer channel, such as `GL_R8UI`, with a width of 512 texels and one row per table. The
+    /// binding is declared for [`GlslVersion::Glsl450`] only, as other versions bind samplers
+    /// through the API.
+    ///
+    /// [`permutation_texture()`]: crate::codegen::permutation_texture
+    Texture {
+        /// The binding of the texture.
+        binding: u32,
+    },
+}
+
+/// Options for generating GLSL code using [`Generator::to_glsl()`].
+///
+/// A [`GlslVersion`] converts into options storing the permutation tables as a constant array.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{codegen::{GlslOptions, GlslVersion}, Source, Generator};
+/// let options = GlslOptions::new(GlslVersion::Glsl450).with_permutation_texture(1);
+/// let glsl = Source::<2>::simplex(42).to_glsl(options, "noise").unwrap();
+///
+/// assert!(glsl.contains("layout(binding = 1) uniform usampler2D noise_PERM;"));
+/// ```
+///
+/// [`Generator::to_glsl()`]: crate::Generator::to_glsl
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GlslOptions {
+    /// The version to generate code for.
+    pub version: GlslVersion,
+    /// How the permutation tables are provided.
+    pub permutation: PermutationStorage,
+}
+
+impl GlslOptions {
+    /// Create options for the given `version`, storing the permutation tables as a constant array.
+    pub fn new(version: GlslVersion) -> Self {
+        Self {
+            version,
+            permutation: PermutationStorage::ConstArray,
+        }
+    }
+
+    /// Read the permutation tables from a texture with the given `binding`. For details, see
+    /// [`PermutationStorage::Texture`].
+    pub fn with_permutation_texture(mut self, binding: u32) -> Self {
+        self.permutation = PermutationStorage::Texture { binding };
+        self
+    }
+}
+
+impl From<GlslVersion> for GlslOptions {
+    fn from(version: GlslVersion) -> Self {
+        Self::new(version)
+    }
+}
+
+// keywords, words reserved for future use, types, and the builtin functions called by the
+// generated code, none of which may be used as function name
+const RESERVED: &[&str] = &[
+    "abs",
+    "active",
+    "asm",
+    "attribute",
+    "bool",
+    "break",
+    "buffer",
+    "bvec2",
+    "bvec3",
+    "bvec4",
+    "case",
+    "cast",
+    "centroid",
+    "clamp",
+    "class",
+    "coherent",
+    "common",
+    "const",
+    "continue",
+    "default",
+    "discard",
+    "distance",
+    "dmat2",
+    "dmat3",
+    "dmat4",
+    "do",
+    "dot",
+    "double",
+    "dvec2",
+    "dvec3",
+    "dvec4",
+    "else",
+    "enum",
+    "exp",
+    "extern",
+    "external",
+    "false",
+    "filter",
+    "fixed",
+    "flat",
+    "float",
+    "floor",
+    "for",
+    "fvec2",
+    "fvec3",
+    "fvec4",
+    "goto",
+    "half",
+    "highp",
+    "hvec2",
+    "hvec3",
+    "hvec4",
+    "if",
+    "in",
+    "inline",
+    "inout",
+    "input",
+    "int",
+    "interface",
+    "invariant",
+    "isampler2D",
+    "ivec2",
+    "ivec3",
+    "ivec4",
+    "layout",
+    "log2",
+    "long",
+    "lowp",
+    "mat2",
+    "mat3",
+    "mat4",
+    "max",
+    "mediump",
+    "min",
+    "namespace",
+    "noinline",
+    "noperspective",
+    "out",
+    "output",
+    "partition",
+    "patch",
+    "pow",
+    "precise",
+    "precision",
+    "public",
+    "readonly",
+    "resource",
+    "restrict",
+    "return",
+    "sample",
+    "sampler2D",
+    "shared",
+    "short",
+    "sizeof",
+    "smooth",
+    "static",
+    "struct",
+    "subroutine",
+    "superp",
+    "switch",
+    "template",
+    "texelFetch",
+    "this",
+    "true",
+    "typedef",
+    "uint",
+    "uniform",
+    "union",
+    "unsigned",
+    "usampler2D",
+    "using",
+    "uvec2",
+    "uvec3",
+    "uvec4",
+    "varying",
+    "vec2",
+    "vec3",
+    "vec4",
+    "void",
+    "volatile",
+    "while",
+    "writeonly",
+];
+
+// checks whether a name is reserved in glsl, including names with the prefix of builtin variables
+pub(crate) fn is_reserved(name: &str) -> bool {
+    RESERVED.contains(&name) || name.starts_with("gl_")
+}
+
+// helpers shared by all lattice sources
+const LATTICE: &str = "
+$uvec $name_wrap($vec x) {
+    return $uvec($ivec(x) & $ivec(255));
+}
+
+float $name_lerp(float a, float b, float t) {
+    return a + t * (b - a);
+}
+
+$vec $name_smoothstep($vec t) {
+    return t * t * (t * -2.0 + 3.0);
+}
+";
+
+const SIMPLEX_CONTRIBUTION: &str = "
+float $name_simplex_contribution($vec x, uint gradient) {
+    float t = 0.5 - dot(x, x);
+    if (t <= 0.0) {
+        return 0.0;
+    }
+    t = t * t;
+    return t * t * dot($name_SIMPLEX_GRADIENTS[gradient], x);
+}
+";
+
+const SIMPLEX_2D: &str = "
+float $name_simplex(vec2 x, uint table) {
+    vec2 cell = floor(x + (x.x + x.y) * $skew);
+    vec2 x0 = x - cell + (cell.x + cell.y) * $unskew;
+    uvec2 i = $name_wrap(cell);
+    uvec2 i1 = x0.x < x0.y ? uvec2(0u, 1u) : uvec2(1u, 0u);
+    vec2 x1 = x0 - vec2(i1) + $unskew;
+    vec2 x2 = x0 - 1.0 + 2.0 * $unskew;
+    float n0 = $name_simplex_contribution(x0, $name_hash(table, i) % 4u);
+    float n1 = $name_simplex_contribution(x1, $name_hash(table, i + i1) % 4u);
+    float n2 = $name_simplex_contribution(x2, $name_hash(table, i + 1u) % 4u);
+    return (n0 + n1 + n2) * $normalization;
+}
+";
+
+const SIMPLEX_3D: &str = "
+float $name_simplex(vec3 x, uint table) {
+    vec3 cell = floor(x + (x.x + x.y + x.z) * $skew);
+    vec3 x0 = x - cell + (cell.x + cell.y + cell.z) * $unskew;
+    uvec3 i = $name_wrap(cell);
+    uint traversal = uint(x0.x > x0.y) * 4u + uint(x0.y > x0.z) * 2u + uint(x0.x > x0.z);
+    uvec3 i1 = $name_SIMPLEX_TRAVERSAL[traversal * 2u];
+    uvec3 i2 = $name_SIMPLEX_TRAVERSAL[traversal * 2u + 1u];
+    vec3 x1 = x0 - vec3(i1) + $unskew;
+    vec3 x2 = x0 - vec3(i2) + 2.0 * $unskew;
+    vec3 x3 = x0 - 1.0 + 3.0 * $unskew;
+    float n0 = $name_simplex_contribution(x0, $name_hash(table, i) % 16u);
+    float n1 = $name_simplex_contribution(x1, $name_hash(table, i + i1) % 16u);
+    float n2 = $name_simplex_contribution(x2, $name_hash(table, i + i2) % 16u);
+    float n3 = $name_simplex_contribution(x3, $name_hash(table, i + 1u) % 16u);
+    return (n0 + n1 + n2 + n3) * $normalization;
+}
+";
+
+const PERLIN_CONTRIBUTION: &str = "
+float $name_perlin_contribution(uint table, $uvec i, $vec dx, $uvec corner) {
+    $vec gradient = $name_PERLIN_GRADIENTS[$name_hash(table, i + corner) % $gradients];
+    return dot(gradient, dx - $vec(corner));
+}
+";
+
+const PERLIN_2D: &str = "
+float $name_perlin(vec2 x, uint table) {
+    vec2 cell = floor(x);
+    vec2 dx = x - cell;
+    vec2 dxs = $name_smoothstep(dx);
+    uvec2 i = $name_wrap(cell);
+    float n00 = $name_perlin_contribution(table, i, dx, uvec2(0u, 0u));
+    float n01 = $name_perlin_contribution(table, i, dx, uvec2(0u, 1u));
+    float n10 = $name_perlin_contribution(table, i, dx, uvec2(1u, 0u));
+    float n11 = $name_perlin_contribution(table, i, dx, uvec2(1u, 1u));
+    float xn0 = $name_lerp(n00, n10, dxs.x);
+    float xn1 = $name_lerp(n01, n11, dxs.x);
+    return $name_lerp(xn0, xn1, dxs.y);
+}
+";
+
+const PERLIN_3D: &str = "
+float $name_perlin(vec3 x, uint table) {
+    vec3 cell = floor(x);
+    vec3 dx = x - cell;
+    vec3 dxs = $name_smoothstep(dx);
+    uvec3 i = $name_wrap(cell);
+    float n000 = $name_perlin_contribution(table, i, dx, uvec3(0u, 0u, 0u));
+    float n001 = $name_perlin_contribution(table, i, dx, uvec3(0u, 0u, 1u));
+    float n010 = $name_perlin_contribution(table, i, dx, uvec3(0u, 1u, 0u));
+    float n011 = $name_perlin_contribution(table, i, dx, uvec3(0u, 1u, 1u));
+    float n100 = $name_perlin_contribution(table, i, dx, uvec3(1u, 0u, 0u));
+    float n101 = $name_perlin_contribution(table, i, dx, uvec3(1u, 0u, 1u));
+    float n110 = $name_perlin_contribution(table, i, dx, uvec3(1u, 1u, 0u));
+    float n111 = $name_perlin_contribution(table, i, dx, uvec3(1u, 1u, 1u));
+    float xn00 = $name_lerp(n000, n100, dxs.x);
+    float xn01 = $name_lerp(n001, n101, dxs.x);
+    float xn10 = $name_lerp(n010, n110, dxs.x);
+    float xn11 = $name_lerp(n011, n111, dxs.x);
+    float yn0 = $name_lerp(xn00, xn10, dxs.y);
+    float yn1 = $name_lerp(xn01, xn11, dxs.y);
+    return $name_lerp(yn0, yn1, dxs.z) * 0.6666666666666666;
+}
+";
+
+const VALUE_2D: &str = "
+float $name_value(vec2 x, uint table) {
+    vec2 cell = floor(x);
+    vec2 dxs = $name_smoothstep(x - cell);
+    uvec2 i = $name_wrap(cell);
+    float f00 = float($name_hash(table, i));
+    float f01 = float($name_hash(table, i + uvec2(0u, 1u)));
+    float f10 = float($name_hash(table, i + uvec2(1u, 0u)));
+    float f11 = float($name_hash(table, i + 1u));
+    float xf0 = $name_lerp(f00, f10, dxs.x);
+    float xf1 = $name_lerp(f01, f11, dxs.x);
+    return 2.0 / 256.0 * $name_lerp(xf0, xf1, dxs.y) - 1.0;
+}
+";
+
+const VALUE_3D: &str = "
+float $name_value(vec3 x, uint table) {
+    vec3 cell = floor(x);
+    vec3 dxs = $name_smoothstep(x - cell);
+    uvec3 i = $name_wrap(cell);
+    float f000 = float($name_hash(table, i));
+    float f001 = float($name_hash(table, i + uvec3(0u, 0u, 1u)));
+    float f010 = float($name_hash(table, i + uvec3(0u, 1u, 0u)));
+    float f011 = float($name_hash(table, i + uvec3(0u, 1u, 1u)));
+    float f100 = float($name_hash(table, i + uvec3(1u, 0u, 0u)));
+    float f101 = float($name_hash(table, i + uvec3(1u, 0u, 1u)));
+    float f110 = float($name_hash(table, i + uvec3(1u, 1u, 0u)));
+    float f111 = float($name_hash(table, i + 1u));
+    float xf00 = $name_lerp(f000, f100, dxs.x);
+    float xf01 = $name_lerp(f001, f101, dxs.x);
+    float xf10 = $name_lerp(f010, f110, dxs.x);
+    float xf11 = $name_lerp(f011, f111, dxs.x);
+    float yf0 = $name_lerp(xf00, xf10, dxs.y);
+    float yf1 = $name_lerp(xf01, xf11, dxs.y);
+    return 2.0 / 256.0 * $name_lerp(yf0, yf1, dxs.z) - 1.0;
+}
+";
+
+const POWI: &str = "
+float $name_powi(float x, int exponent) {
+    float base = exponent < 0 ? 1.0 / x : x;
+    uint remaining = uint(abs(exponent));
+    float result = 1.0;
+    while (remaining > 0u) {
+        if ((remaining & 1u) == 1u) {
+            result *= base;
+        }
+        base *= base;
+        remaining >>= 1u;
+    }
+    return result;
+}
+";
+
+// generates glsl code for a lowered program, with the root node named after the program. as
+// glsl requires functions to be declared before they are called, helpers precede their callers
+pub(crate) fn generate(program: &Program, options: &GlslOptions, name: &str) -> String {
+    let dimension = program.dimension;
+    let vec = format!("vec{}", dimension);
+    let mut library = String::new();
+    let lattices = |lattice| {
+        program
+            .nodes
+            .iter()
+            .any(|node| matches!(node, Node::Lattice { lattice: used, .. } if *used == lattice))
+    };
+
+    if !program.tables.is_empty() {
+        library += &permutation_tables(name, &program.tables, options);
+        library += &hash(dimension);
+        library += LATTICE;
+    }
+    if lattices(Lattice::Simplex) {
+        let (skew, unskew, normalization, gradients) = if dimension == 2 {
+            (
+                SIMPLEX_SKEW_FACTOR_2D,
+                SIMPLEX_UNSKEW_FACTOR_2D,
+                SIMPLEX_NORMALIZATION_FACTOR_2D,
+                array(&MIDPOINT_GRADIENT_LUT_2D),
+            )
+        } else {
+            library += &traversal(name);
+            (
+                SIMPLEX_SKEW_FACTOR_3D,
+                SIMPLEX_UNSKEW_FACTOR_3D,
+                SIMPLEX_NORMALIZATION_FACTOR_3D,
+                array(&MIDPOINT_GRADIENT_LUT_3D),
+            )
+        };
+        library += &gradient_table(name, "SIMPLEX", &vec, &gradients);
+        library += SIMPLEX_CONTRIBUTION;
+        library += &if dimension == 2 {
+            SIMPLEX_2D
+        } else {
+            SIMPLEX_3D
+        }
+        .replace("$skew", &float(skew as f32))
+        .replace("$unskew", &float(unskew as f32))
+        .replace("$normalization", &float(normalization as f32));
+    }
+    if lattices(Lattice::Perlin) {
+        let gradients = if dimension == 2 {
+            array(&CORNERPOINT_GRADIENT_LUT_2D)
+        } else {
+            array(&CORNERPOINT_GRADIENT_LUT_3D)
+        };
+        library += &gradient_table(name, "PERLIN", &vec, &gradients);
+        library += &PERLIN_CONTRIBUTION.replace("$gradients", &format!("{}u", gradients.len()));
+        library += if dimension == 2 { PERLIN_2D } else { PERLIN_3D };
+    }
+    if lattices(Lattice::Value) {
+        library += if dimension == 2 { VALUE_2D } else { VALUE_3D };
+    }
+    if program
+        .nodes
+        .iter()
+        .any(|node| matches!(node, Node::Checkerboard { smooth: false, .. }))
+    {
+        library += &checkerboard(dimension);
+    }
+    if program
+        .nodes
+        .iter()
+        .any(|node| matches!(node, Node::Checkerboard { smooth: true, .. }))
+    {
+        library += &checkerboard_smooth(dimension);
+    }
+    if program
+        .nodes
+        .iter()
+        .any(|node| matches!(node, Node::Powi { .. }))
+    {
+        library += POWI;
+    }
+
+    let mut code = format!("// float {}({} p), generated by libnoise\n", name, vec);
+    code += &library
+        .replace("$name", name)
+        .replace("$vec", &vec)
+        .replace("$uvec", &format!("uvec{}", dimension))
+        .replace("$ivec", &format!("ivec{}", dimension));
+    for (index, node) in program.nodes.iter().enumerate() {
+        let function = if index + 1 == program.nodes.len() {
+            name.to_string()
+        } else {
+            node_name(name, index)
+        };
+        let body = body(node, name, &vec);
+        let _ = write!(code, "\nfloat {}({} p) {{\n{}}}\n", function, vec, body);
+    }
+    code
+}
+
+fn node_name(name: &str, index: usize) -> String {
+    format!("{}_n{}", name, index)
+}
+
+// generates the statements of the function evaluating a node
+fn body(node: &Node, name: &str, vec: &str) -> String {
+    let call = |index: usize, point: &str| format!("{}({})", node_name(name, index), point);
+    let vector = |values: &[f32]| {
+        let values = values.iter().map(|&x| float(x)).collect::<Vec<_>>();
+        format!("{}({})", vec, values.join(", "))
+    };
+    let ret = |expression: String| format!("    return {};\n", expression);
+    match node {
+        Node::Constant(value) => ret(float(*value)),
+        Node::Checkerboard { period, smooth } => {
+            let function = if *smooth {
+                "checkerboard_smooth"
+            } else {
+                "checkerboard"
+            };
+            if period.iter().all(|&x| x == 1.0) {
+                ret(format!("{}_{}(p)", name, function))
+            } else {
+                ret(format!("{}_{}(p / {})", name, function, vector(period)))
+            }
+        }
+        Node::Lattice { lattice, table } => {
+            let function = match lattice {
+                Lattice::Simplex => "simplex",
+                Lattice::Perlin => "perlin",
+                Lattice::Value => "value",
+            };
+            ret(format!("{}_{}(p, {}u)", name, function, table))
+        }
+        Node::Scale { source, scale } => ret(call(*source, &format!("p * {}", vector(scale)))),
+        Node::Translate {
+            source,
+            translation,
+        } => ret(call(*source, &format!("p + {}", vector(translation)))),
+        Node::Rotate { source, matrix } => {
+            let dimension = (matrix.len() as f64).sqrt() as usize;
+            let matrix = matrix.iter().map(|&x| float(x)).collect::<Vec<_>>();
+            let matrix = format!("mat{}({})", dimension, matrix.join(", "));
+            ret(call(*source, &format!("{} * p", matrix)))
+        }
+        Node::Displace {
+            source,
+            displacement,
+            axis,
+        } => format!(
+            "    {} q = p;\n    q.{} += {};\n    return {};\n",
+            vec,
+            ["x", "y", "z"][*axis],
+            call(*displacement, "p"),
+            call(*source, "q")
+        ),
+        Node::Unary { op, source } => {
+            let value = call(*source, "p");
+            ret(match op {
+                Unary::Neg => format!("-{}", value),
+                Unary::Abs => format!("abs({})", value),
+                Unary::Exp => format!("exp({})", value),
+            })
+        }
+        Node::Add { source, offset } => ret(format!("{} + {}", call(*source, "p"), float(*offset))),
+        Node::Mul { source, scale } => ret(format!("{} * {}", call(*source, "p"), float(*scale))),
+        Node::Powi { source, exponent } => ret(format!(
+            "{}_powi({}, {})",
+            name,
+            call(*source, "p"),
+            exponent
+        )),
+        Node::Powf { source, exponent } => ret(format!(
+            "pow({}, {})",
+            call(*source, "p"),
+            float(*exponent)
+        )),
+        Node::Clamp { source, min, max } => {
+            let value = call(*source, "p");
+            ret(match (min, max) {
+                (Some(min), Some(max)) => format!("clamp({}, {}, {})", value, float(*min), float(*max)),
+                (Some(min), None) => format!("max({}, {})", value, float(*min)),
+                (None, Some(max)) => format!("min({}, {})", value, float(*max)),
+                (None, None) => value,
+            })
+        }
+        Node::Binary { op, a, b } => {
+            let (a, b) = (call(*a, "p"), call(*b, "p"));
+            ret(match op {
+                Binary::Sum => format!("{} + {}", a, b),
+                Binary::Product => format!("{} * {}", a, b),
+                Binary::Min => format!("min({}, {})", a, b),
+                Binary::Max => format!("max({}, {})", a, b),
+                Binary::Power => format!("pow({}, {})", a, b),
+            })
+        }
+        Node::Blend { a, b, control } => format!(
+            "    float a = {};\n    float b = {};\n    float t = {} * 0.5 + 0.5;\n    return a + t * (b - a);\n",
+            call(*a, "p"),
+            call(*b, "p"),
+            call(*control, "p")
+        ),
+        Node::Select {
+            a,
+            b,
+            control,
+            min,
+            max,
+        } => {
+            let condition = match (min, max) {
+                (Some(min), Some(max)) => {
+                    format!("control >= {} && control <= {}", float(*min), float(*max))
+                }
+                (Some(min), None) => format!("control >= {}", float(*min)),
+                (None, Some(max)) => format!("control <= {}", float(*max)),
+                (None, None) => return ret(call(*a, "p")),
+            };
+            format!(
+                "    float control = {};\n    if ({}) {{\n        return {};\n    }}\n    return {};\n",
+                call(*control, "p"),
+                condition,
+                call(*a, "p"),
+                call(*b, "p")
+            )
+        }
+        Node::Fbm {
+            source,
+            octaves,
+            normalization,
+        } => fractal(octaves, *normalization, |octave| {
+            format!(
+                "{} * {}",
+                float(octave.amplitude),
+                call(*source, &format!("p * {}", float(octave.frequency)))
+            )
+        }),
+        Node::Billow {
+            source,
+            octaves,
+            normalization,
+        } => fractal(octaves, *normalization, |octave| {
+            format!(
+                "{} * (abs({}) * 2.0 - 1.0)",
+                float(octave.amplitude),
+                call(*source, &format!("p * {}", float(octave.frequency)))
+            )
+        }),
+        Node::RidgedMulti {
+            source,
+            frequencies,
+            attenuation,
+            normalization,
+        } => {
+            let mut body =
+                "    float value = 0.0;\n    float amplitude = 1.0;\n    float layer = 0.0;\n"
+                    .to_string();
+            for frequency in frequencies {
+                let _ = write!(
+                    body,
+                    "    layer = 1.0 - abs({});\n    layer = layer * layer * amplitude;\n    value += layer;\n    amplitude = clamp(layer / {}, 0.0, 1.0);\n",
+                    call(*source, &format!("p * {}", float(*frequency))),
+                    float(*attenuation)
+                );
+            }
+            body + &format!("    return value * {} * 2.0 - 1.0;\n", float(*normalization))
+        }
+        Node::DistanceLod {
+            source,
+            octaves,
+            focus,
+            near,
+        } => {
+            let mut body = format!(
+                "    float lod = max(log2(distance(p, {}) / {}), 0.0);\n    float remaining = max({} - lod, 1.0);\n    float value = 0.0;\n    float total = 0.0;\n    float weight = 0.0;\n",
+                vector(focus),
+                float(*near),
+                float(octaves.len() as f32)
+            );
+            for (index, octave) in octaves.iter().enumerate() {
+                let _ = write!(
+                    body,
+                    "    weight = min(remaining - {}, 1.0);\n    if (weight > 0.0) {{\n        value += {} * weight * {};\n        total += {} * weight;\n    }}\n",
+                    float(index as f32),
+                    float(octave.amplitude),
+                    call(*source, &format!("p * {}", float(octave.frequency))),
+                    float(octave.amplitude)
+                );
+            }
+            body + "    return value * (1.0 / total);\n"
+        }
+    }
+}
+
+// unrolls the octaves of a fractal, whose frequencies and amplitudes are known in advance
+fn fractal(octaves: &[Octave], normalization: f32, octave: impl Fn(&Octave) -> String) -> String {
+    let mut body = "    float value = 0.0;\n".to_string();
+    for o in octaves {
+        let _ = writeln!(body, "    value += {};", octave(o));
+    }
+    body + &format!("    return value * {};\n", float(normalization))
+}
+
+// declares the permutation tables of all seeds and a function looking up an entry of a table,
+// where each table is doubled up like on the cpu such that hashes never need to wrap
+fn permutation_tables(name: &str, seeds: &[u64], options: &GlslOptions) -> String {
+    let size = 2 * PERMUTATION_TABLE_SIZE;
+    let (declaration, lookup) = match options.permutation {
+        PermutationStorage::ConstArray => {
+            let entries = entries(seeds);
+            let rows = entries
+                .chunks(16)
+                .map(|row| {
+                    let row = row.iter().map(|x| format!("{}u", x)).collect::<Vec<_>>();
+                    format!("    {}", row.join(", "))
+                })
+                .collect::<Vec<_>>();
+            (
+                format!(
+                    "const uint {}_PERM[{}] = uint[{}](\n{}\n);",
+                    name,
+                    entries.len(),
+                    entries.len(),
+                    rows.join(",\n")
+                ),
+                format!("{}_PERM[table * {}u + i]", name, size),
+            )
+        }
+        PermutationStorage::Texture { binding } => {
+            let uniform = match options.version {
+                GlslVersion::Glsl330 => "uniform usampler2D".to_string(),
+                GlslVersion::Glsl450 => format!("layout(binding = {}) uniform usampler2D", binding),
+                GlslVersion::Es300 => "uniform highp usampler2D".to_string(),
+            };
+            (
+                format!(
+                    "// permutation tables with {} texels per row, one row per table\n{} {}_PERM;",
+                    size, uniform, name
+                ),
+                format!("texelFetch({}_PERM, ivec2(int(i), int(table)), 0).r", name),
+            )
+        }
+    };
+    format!(
+        "\n{}\n\nuint $name_perm(uint table, uint i) {{\n    return {};\n}}\n",
+        declaration, lookup
+    )
+}
+
+// the permutation tables of all seeds, laid out one after another
+fn entries(seeds: &[u64]) -> Vec<usize> {
+    seeds
+        .iter()
+        .flat_map(|&seed| PermutationTable::new(seed, PERMUTATION_TABLE_SIZE, true).table)
+        .collect()
+}
+
+// permutation table entries are less than the table size, and thereby fit into a byte
+pub(crate) fn permutation_texture(program: &Program) -> Vec<u8> {
+    entries(&program.tables)
+        .into_iter()
+        .map(|entry| entry as u8)
+        .collect()
+}
+
+fn hash(dimension: usize) -> String {
+    let lookup =
+        ["i.x", "i.y", "i.z"][..dimension]
+            .iter()
+            .fold(String::new(), |inner, component| {
+                if inner.is_empty() {
+                    format!("$name_perm(table, {})", component)
+                } else {
+                    format!("$name_perm(table, {} + {})", component, inner)
+                }
+            });
+    format!(
+        "\nuint $name_hash(uint table, $uvec i) {{\n    return {};\n}}\n",
+        lookup
+    )
+}
+
+fn traversal(name: &str) -> String {
+    let rows = SIMPLEX_TRAVERSAL_LUT_3D
+        .iter()
+        .flat_map(|row| [&row[..3], &row[3..]])
+        .map(|i| format!("    uvec3({}u, {}u, {}u)", i[0], i[1], i[2]))
+        .collect::<Vec<_>>();
+    format!(
+        "\nconst uvec3 {}_SIMPLEX_TRAVERSAL[{}] = uvec3[{}](\n{}\n);\n",
+        name,
+        rows.len(),
+        rows.len(),
+        rows.join(",\n")
+    )
+}
+
+fn gradient_table(name: &str, kind: &str, vec: &str, gradients: &[Vec<f64>]) -> String {
+    let rows = gradients
+        .iter()
+        .map(|gradient| {
+            let gradient = gradient
+                .iter()
+                .map(|&x| float(x as f32))
+                .collect::<Vec<_>>();
+            format!("    {}({})", vec, gradient.join(", "))
+        })
+        .collect::<Vec<_>>();
+    format!(
+        "\nconst {} {}_{}_GRADIENTS[{}] = {}[{}](\n{}\n);\n",
+        vec,
+        name,
+        kind,
+        rows.len(),
+        vec,
+        rows.len(),
+        rows.join(",\n")
+    )
+}
+
+fn checkerboard(dimension: usize) -> String {
+    let parity = ["c.x", "c.y", "c.z"][..dimension].join(" ^ ");
+    format!(
+        "\nfloat $name_checkerboard($vec x) {{\n    $ivec c = $ivec(x) & $ivec(1);\n    return float({}) * 2.0 - 1.0;\n}}\n",
+        parity
+    )
+}
+
+fn checkerboard_smooth(dimension: usize) -> String {
+    let product = ["s.x", "s.y", "s.z"][..dimension].join(" * ");
+    format!(
+        "\nfloat $name_checkerboard_smooth($vec x) {{\n    $vec s = sin(3.14159265 * x);\n    return -({});\n}}\n",
+        product
+    )
+}
+
+fn array<const N: usize>(rows: &[[f64; N]]) -> Vec<Vec<f64>> {
+    rows.iter().map(|row| row.to_vec()).collect()
+}
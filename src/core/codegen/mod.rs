@@ -0,0 +1,859 @@
+//! Translation of generators into shader code.
+//!
+//! With the `codegen` feature enabled, a generator composed of supported sources and adapters
+//! can be translated into a self-contained shader function using [`Generator::to_wgsl()`] or
+//! [`Generator::to_glsl()`]. The generated code evaluates the same chain on the GPU, using
+//! permutation tables seeded exactly like the sources of this library, such that the shader
+//! reproduces the noise sampled on the CPU. The chain is first converted into a [`NoiseRecipe`],
+//! so generators built from recipes and [`Pipeline`]s are supported as well. Both shading
+//! languages share the same translation of the chain, so they support the same generators and
+//! report the same errors.
+//!
+//! # Supported generators
+//!
+//! Shader code can be generated for 2 and 3 dimensions, and for the following sources:
+//!
+//! - [`constant()`], [`checkerboard()`], [`simplex()`], [`perlin()`], and [`value()`].
+//!
+//! All adapters except [`lambda()`] are supported, where [`checked()`] has no effect, as shaders
+//! cannot report errors, and [`fbm_lod()`] behaves like [`fbm()`]. The large coordinate mode of
+//! sources is ignored. Unsupported nodes, including closures, [`worley()`], and
+//! [`improved_perlin()`], result in a [`CodegenError`] naming the offending node.
+//!
+//! # Precision
+//!
+//! Shaders compute in single precision. Parameters are rounded to [`f32`] once, after deriving
+//! values such as the frequencies and amplitudes of fractal octaves in double precision, so the
+//! deviation from the CPU is dominated by the precision of the input point. For input
+//! coordinates of magnitude up to 1000, the generated sources deviate from the CPU by less than
+//! `1e-3`, and fractal adapters add at most the deviation of their sources, as their octaves are
+//! normalized. Larger coordinates lose precision proportionally. Additionally, the results of
+//! [`exp()`], [`powf()`], and [`power()`] depend on the precision of the GPU, and negative bases
+//! of the latter two are undefined in shaders. Where the CPU propagates NaN, such as for the
+//! control value of [`select()`], the shader behavior is unspecified.
+//!
+//! # Layout of the generated code
+//!
+//! The requested function name is used as entry point, taking the input point as `vec2<f32>` or
+//! `vec3<f32>` and returning an `f32` in WGSL, or the equivalent `vec2` or `vec3` and `float` in
+//! GLSL. All other items are prefixed by the function name, such
+//! that multiple generated generators can be used in the same shader. Each node is compiled into
+//! its own function, and sources sharing a seed share the same permutation table. In GLSL, the
+//! permutation tables can be read from a texture instead of a constant array, as configured by
+//! [`GlslOptions`].
+//!
+//! [`Generator::to_wgsl()`]: crate::Generator::to_wgsl
+//! [`Generator::to_glsl()`]: crate::Generator::to_glsl
+//! [`NoiseRecipe`]: crate::recipe::NoiseRecipe
+//! [`Pipeline`]: crate::pipeline::Pipeline
+//! [`constant()`]: crate::Source::constant
+//! [`checkerboard()`]: crate::Source::checkerboard
+//! [`simplex()`]: crate::Source::simplex
+//! [`perlin()`]: crate::Source::perlin
+//! [`value()`]: crate::Source::value
+//! [`worley()`]: crate::Source::worley
+//! [`improved_perlin()`]: crate::Source::improved_perlin
+//! [`lambda()`]: crate::Generator::lambda
+//! [`checked()`]: crate::Generator::checked
+//! [`fbm_lod()`]: crate::Generator::fbm_lod
+//! [`fbm()`]: crate::Generator::fbm
+//! [`exp()`]: crate::Generator::exp
+//! [`powf()`]: crate::Generator::powf
+//! [`power()`]: crate::Generator::power
+//! [`select()`]: crate::Generator::select
+
+pub(crate) mod glsl;
+pub(crate) mod wgsl;
+
+pub use glsl::{GlslOptions, GlslVersion, PermutationStorage};
+
+use crate::core::{
+    adapters::Rotate,
+    error::Error,
+    generator::Generator,
+    recipe::{NoiseRecipe, RecipeError, TransformStep},
+    sources::{functional, Constant},
+};
+use std::{collections::HashMap, fmt};
+
+/// Returns the contents of the texture holding the permutation tables used by the GLSL code of a
+/// generator, when generated with [`PermutationStorage::Texture`].
+///
+/// The texture consists of one row of 512 texels for each table, in the order expected by the
+/// generated code. It is empty if the generator contains no lattice sources, in which case the
+/// generated code declares no texture either.
+///
+/// # Errors
+///
+/// Returns the same error as [`Generator::to_glsl()`] for generators which cannot be translated.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{codegen, Source, Generator};
+/// let generator = Source::<2>::simplex(42).blend(
+///     Source::perlin(42),
+///     Source::value(43),
+/// );
+/// let texture = codegen::permutation_texture(&generator).unwrap();
+///
+/// // sources sharing a seed share a table
+/// assert_eq!(texture.len(), 2 * 512);
+/// ```
+///
+/// [`Generator::to_glsl()`]: crate::Generator::to_glsl
+pub fn permutation_texture<const D: usize, G: Generator<D>>(
+    generator: &G,
+) -> Result<Vec<u8>, CodegenError> {
+    let program = Program::lower(&generator.to_recipe()?, D)?;
+    Ok(glsl::permutation_texture(&program))
+}
+
+/// The error type for generating shader code.
+///
+/// Variants concerning a node name it by its type and its path in the generator chain, as for
+/// [`RecipeError`]. The path consists of the field names leading from the root to the node,
+/// separated by dots, and is empty for the root node.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum CodegenError {
+    /// The node cannot be translated into shader code.
+    Unsupported {
+        /// The path of the offending node.
+        path: String,
+        /// The type of the offending node.
+        node: &'static str,
+    },
+    /// A parameter of the node cannot be represented in shader code.
+    InvalidParameter {
+        /// The path of the offending node.
+        path: String,
+        /// The type of the offending node.
+        node: &'static str,
+        /// The name of the offending parameter.
+        parameter: &'static str,
+        /// The reason why the value cannot be represented.
+        reason: &'static str,
+    },
+    /// Shader code cannot be generated for the dimensionality.
+    UnsupportedDimension {
+        /// The requested dimensionality.
+        dimension: usize,
+    },
+    /// The requested function name is not a valid identifier.
+    InvalidName {
+        /// The offending name.
+        name: String,
+    },
+}
+
+impl fmt::Display for CodegenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = |path: &str| match path {
+            "" => "root".to_string(),
+            path => path.to_string(),
+        };
+        match self {
+            Self::Unsupported { path, node } => write!(
+                f,
+                "{} node at {} cannot be translated into shader code",
+                node,
+                location(path)
+            ),
+            Self::InvalidParameter {
+                path,
+                node,
+                parameter,
+                reason,
+            } => write!(
+                f,
+                "invalid {} node at {}: {} {}",
+                node,
+                location(path),
+                parameter,
+                reason
+            ),
+            Self::UnsupportedDimension { dimension } => write!(
+                f,
+                "shader code cannot be generated for {} dimensions",
+                dimension
+            ),
+            Self::InvalidName { name } => write!(f, "{:?} is not a valid function name", name),
+        }
+    }
+}
+
+impl std::error::Error for CodegenError {}
+
+impl From<CodegenError> for Error {
+    fn from(error: CodegenError) -> Self {
+        match error {
+            CodegenError::InvalidParameter {
+                ref path,
+                node,
+                parameter,
+                reason,
+            } => Self::InvalidParameter {
+                name: parameter,
+                reason: match path.as_str() {
+                    "" => format!("{}, in {} node at root", reason, node),
+                    path => format!("{}, in {} node at {}", reason, node, path),
+                },
+            },
+            CodegenError::InvalidName { name } => Self::InvalidParameter {
+                name: "fn_name",
+                reason: format!("must be a valid identifier, got {:?}", name),
+            },
+            _ => Self::Unsupported {
+                reason: error.to_string(),
+            },
+        }
+    }
+}
+
+impl From<RecipeError> for CodegenError {
+    fn from(error: RecipeError) -> Self {
+        Self::Unsupported {
+            path: error.path().to_string(),
+            node: error.node(),
+        }
+    }
+}
+
+// a generator chain lowered into a flat list of nodes with parameters in single precision, which
+// is shared by all shading languages
+pub(crate) struct Program {
+    pub(crate) dimension: usize,
+    // the seeds of the permutation tables, in the order they are laid out
+    pub(crate) tables: Vec<u64>,
+    // nodes refer to their children by index, such that children precede their parents, and the
+    // last node is the root
+    pub(crate) nodes: Vec<Node>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Lattice {
+    Simplex,
+    Perlin,
+    Value,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Unary {
+    Neg,
+    Abs,
+    Exp,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Binary {
+    Sum,
+    Product,
+    Min,
+    Max,
+    Power,
+}
+
+pub(crate) enum Node {
+    Constant(f32),
+    Checkerboard {
+        period: Vec<f32>,
+        smooth: bool,
+    },
+    Lattice {
+        lattice: Lattice,
+        table: usize,
+    },
+    Scale {
+        source: usize,
+        scale: Vec<f32>,
+    },
+    Translate {
+        source: usize,
+        translation: Vec<f32>,
+    },
+    Rotate {
+        source: usize,
+        // column-major
+        matrix: Vec<f32>,
+    },
+    Displace {
+        source: usize,
+        displacement: usize,
+        axis: usize,
+    },
+    Unary {
+        op: Unary,
+        source: usize,
+    },
+    Add {
+        source: usize,
+        offset: f32,
+    },
+    Mul {
+        source: usize,
+        scale: f32,
+    },
+    Powi {
+        source: usize,
+        exponent: i32,
+    },
+    Powf {
+        source: usize,
+        exponent: f32,
+    },
+    // unbounded sides are omitted
+    Clamp {
+        source: usize,
+        min: Option<f32>,
+        max: Option<f32>,
+    },
+    Binary {
+        op: Binary,
+        a: usize,
+        b: usize,
+    },
+    Blend {
+        a: usize,
+        b: usize,
+        control: usize,
+    },
+    // unbounded sides are omitted
+    Select {
+        a: usize,
+        b: usize,
+        control: usize,
+        min: Option<f32>,
+        max: Option<f32>,
+    },
+    Fbm {
+        source: usize,
+        octaves: Vec<Octave>,
+        normalization: f32,
+    },
+    Billow {
+        source: usize,
+        octaves: Vec<Octave>,
+        normalization: f32,
+    },
+    RidgedMulti {
+        source: usize,
+        frequencies: Vec<f32>,
+        attenuation: f32,
+        normalization: f32,
+    },
+    DistanceLod {
+        source: usize,
+        octaves: Vec<Octave>,
+        focus: Vec<f32>,
+        near: f32,
+    },
+}
+
+pub(crate) struct Octave {
+    pub(crate) frequency: f32,
+    pub(crate) amplitude: f32,
+}
+
+impl Program {
+    // lowers a recipe for the given dimensionality
+    pub(crate) fn lower(recipe: &NoiseRecipe, dimension: usize) -> Result<Self, CodegenError> {
+        validate_dimension(dimension)?;
+        let mut lowering = Lowering {
+            program: Self {
+                dimension,
+                tables: Vec::new(),
+                nodes: Vec::new(),
+            },
+            lattices: HashMap::new(),
+        };
+        lowering.node(recipe, String::new())?;
+        Ok(lowering.program)
+    }
+}
+
+struct Lowering {
+    program: Program,
+    // deduplicates sources sharing a kind and seed
+    lattices: HashMap<(Lattice, u64), usize>,
+}
+
+impl Lowering {
+    fn node(&mut self, recipe: &NoiseRecipe, path: String) -> Result<usize, CodegenError> {
+        let child = |this: &mut Self, field: &str, recipe: &NoiseRecipe| {
+            if path.is_empty() {
+                this.node(recipe, field.to_string())
+            } else {
+                this.node(recipe, format!("{}.{}", path, field))
+            }
+        };
+        let single = |parameter, value: f64| single(recipe, &path, parameter, value);
+        let node = match recipe {
+            NoiseRecipe::Constant { value } => Node::Constant(single("value", *value)?),
+            NoiseRecipe::Checkerboard { period, smooth } => Node::Checkerboard {
+                period: match period {
+                    Some(period) => {
+                        if let Err((parameter, reason)) =
+                            functional::checkerboard::validate_period(period)
+                        {
+                            return Err(invalid(recipe, &path, parameter, reason));
+                        }
+                        self.vector(recipe, &path, "period", period)?
+                    }
+                    None => vec![1.0; self.program.dimension],
+                },
+                smooth: *smooth,
+            },
+            NoiseRecipe::Simplex {
+                r_squared: Some(_), ..
+            } => {
+                return Err(invalid(
+                    recipe,
+                    &path,
+                    "r_squared",
+                    "is only supported at its default of 0.5",
+                ))
+            }
+            NoiseRecipe::Simplex { seed, .. } => return Ok(self.lattice(Lattice::Simplex, *seed)),
+            NoiseRecipe::Perlin { seed, .. } => return Ok(self.lattice(Lattice::Perlin, *seed)),
+            NoiseRecipe::Value { seed, .. } => return Ok(self.lattice(Lattice::Value, *seed)),
+            NoiseRecipe::ImprovedPerlin { .. }
+            | NoiseRecipe::OpenSimplex2 { .. }
+            | NoiseRecipe::OpenSimplex2S { .. }
+            | NoiseRecipe::Worley { .. }
+            | NoiseRecipe::Spot { .. }
+            | NoiseRecipe::Wavelet { .. }
+            | NoiseRecipe::White { .. }
+            | NoiseRecipe::Blue { .. }
+            | NoiseRecipe::DistanceField { .. }
+            | NoiseRecipe::LinearGradient { .. }
+            | NoiseRecipe::RadialGradient { .. }
+            | NoiseRecipe::Island { .. }
+            | NoiseRecipe::IslandRect { .. } => {
+                return Err(CodegenError::Unsupported {
+                    path: path.clone(),
+                    node: recipe.node(),
+                })
+            }
+            NoiseRecipe::Scale { source, scale } => Node::Scale {
+                scale: self.vector(recipe, &path, "scale", scale)?,
+                source: child(self, "source", source)?,
+            },
+            NoiseRecipe::Translate {
+                source,
+                translation,
+            } => Node::Translate {
+                translation: self.vector(recipe, &path, "translation", translation)?,
+                source: child(self, "source", source)?,
+            },
+            NoiseRecipe::Transform { source, steps } => {
+                // lowered into the equivalent chain of nodes, from the innermost step outwards
+                let mut index = child(self, "source", source)?;
+                for step in steps.iter().rev() {
+                    let node = match step {
+                        TransformStep::Scale(scale) => Node::Scale {
+                            scale: self.vector(recipe, &path, "steps", scale)?,
+                            source: index,
+                        },
+                        TransformStep::Translate(translation) => Node::Translate {
+                            translation: self.vector(recipe, &path, "steps", translation)?,
+                            source: index,
+                        },
+                    };
+                    self.program.nodes.push(node);
+                    index = self.program.nodes.len() - 1;
+                }
+                return Ok(index);
+            }
+            NoiseRecipe::Rotate { source, rotation } => Node::Rotate {
+                matrix: self.rotation(recipe, &path, rotation)?,
+                source: child(self, "source", source)?,
+            },
+            NoiseRecipe::Displace {
+                source,
+                displacement,
+                axis,
+            } => {
+                if *axis >= self.program.dimension {
+                    return Err(invalid(
+                        recipe,
+                        &path,
+                        "axis",
+                        "must be less than the dimensionality",
+                    ));
+                }
+                Node::Displace {
+                    source: child(self, "source", source)?,
+                    displacement: child(self, "displacement", displacement)?,
+                    axis: *axis,
+                }
+            }
+            NoiseRecipe::Neg { source } => Node::Unary {
+                op: Unary::Neg,
+                source: child(self, "source", source)?,
+            },
+            NoiseRecipe::Abs { source } => Node::Unary {
+                op: Unary::Abs,
+                source: child(self, "source", source)?,
+            },
+            NoiseRecipe::Exp { source } => Node::Unary {
+                op: Unary::Exp,
+                source: child(self, "source", source)?,
+            },
+            NoiseRecipe::Add { source, offset } => Node::Add {
+                offset: single("offset", *offset)?,
+                source: child(self, "source", source)?,
+            },
+            NoiseRecipe::Mul { source, scale } => Node::Mul {
+                scale: single("scale", *scale)?,
+                source: child(self, "source", source)?,
+            },
+            NoiseRecipe::Powi { source, exponent } => Node::Powi {
+                exponent: *exponent,
+                source: child(self, "source", source)?,
+            },
+            NoiseRecipe::Powf { source, exponent } => Node::Powf {
+                exponent: single("exponent", *exponent)?,
+                source: child(self, "source", source)?,
+            },
+            NoiseRecipe::Clamp { source, min, max } => Node::Clamp {
+                min: lower_bound(recipe, &path, "min", *min)?,
+                max: upper_bound(recipe, &path, "max", *max)?,
+                source: child(self, "source", source)?,
+            },
+            // shaders cannot report errors, so checks are dropped
+            NoiseRecipe::Checked { source, .. } => return child(self, "source", source),
+            NoiseRecipe::Sum { a, b } => Node::Binary {
+                op: Binary::Sum,
+                a: child(self, "a", a)?,
+                b: child(self, "b", b)?,
+            },
+            NoiseRecipe::Product { a, b } => Node::Binary {
+                op: Binary::Product,
+                a: child(self, "a", a)?,
+                b: child(self, "b", b)?,
+            },
+            NoiseRecipe::Min { a, b } => Node::Binary {
+                op: Binary::Min,
+                a: child(self, "a", a)?,
+                b: child(self, "b", b)?,
+            },
+            NoiseRecipe::Max { a, b } => Node::Binary {
+                op: Binary::Max,
+                a: child(self, "a", a)?,
+                b: child(self, "b", b)?,
+            },
+            NoiseRecipe::Power { a, b } => Node::Binary {
+                op: Binary::Power,
+                a: child(self, "a", a)?,
+                b: child(self, "b", b)?,
+            },
+            NoiseRecipe::Blend { a, b, control } => Node::Blend {
+                a: child(self, "a", a)?,
+                b: child(self, "b", b)?,
+                control: child(self, "control", control)?,
+            },
+            NoiseRecipe::Select {
+                a,
+                b,
+                control,
+                selection_min,
+                selection_max,
+            } => Node::Select {
+                min: lower_bound(recipe, &path, "selection_min", *selection_min)?,
+                max: upper_bound(recipe, &path, "selection_max", *selection_max)?,
+                a: child(self, "a", a)?,
+                b: child(self, "b", b)?,
+                control: child(self, "control", control)?,
+            },
+            NoiseRecipe::Fbm {
+                source,
+                octaves,
+                frequency,
+                lacunarity,
+                persistence,
+            }
+            | NoiseRecipe::FbmLod {
+                source,
+                octaves,
+                frequency,
+                lacunarity,
+                persistence,
+            } => {
+                let (octaves, normalization) = octaves_of(
+                    recipe,
+                    &path,
+                    *octaves,
+                    *frequency,
+                    *lacunarity,
+                    *persistence,
+                )?;
+                Node::Fbm {
+                    source: child(self, "source", source)?,
+                    octaves,
+                    normalization,
+                }
+            }
+            NoiseRecipe::Billow {
+                source,
+                octaves,
+                frequency,
+                lacunarity,
+                persistence,
+            } => {
+                let (octaves, normalization) = octaves_of(
+                    recipe,
+                    &path,
+                    *octaves,
+                    *frequency,
+                    *lacunarity,
+                    *persistence,
+                )?;
+                Node::Billow {
+                    source: child(self, "source", source)?,
+                    octaves,
+                    normalization,
+                }
+            }
+            NoiseRecipe::RidgedMulti {
+                source,
+                octaves,
+                frequency,
+                lacunarity,
+                attenuation,
+            } => {
+                let (octaves, _) =
+                    octaves_of(recipe, &path, *octaves, *frequency, *lacunarity, 1.0)?;
+                // the weights of the octaves decay by the attenuation, like on the cpu
+                let mut weight = 1.0;
+                let sum = octaves.iter().fold(0.0, |acc, _| {
+                    let acc = acc + weight;
+                    weight /= attenuation;
+                    acc
+                });
+                Node::RidgedMulti {
+                    source: child(self, "source", source)?,
+                    frequencies: octaves.iter().map(|octave| octave.frequency).collect(),
+                    attenuation: single("attenuation", *attenuation)?,
+                    normalization: single("attenuation", 1.0 / sum)?,
+                }
+            }
+            NoiseRecipe::DistanceLod {
+                source,
+                octaves,
+                frequency,
+                lacunarity,
+                persistence,
+                focus,
+                near,
+            } => {
+                let (octaves, _) = octaves_of(
+                    recipe,
+                    &path,
+                    *octaves,
+                    *frequency,
+                    *lacunarity,
+                    *persistence,
+                )?;
+                Node::DistanceLod {
+                    focus: self.vector(recipe, &path, "focus", focus)?,
+                    near: single("near", *near)?,
+                    source: child(self, "source", source)?,
+                    octaves,
+                }
+            }
+        };
+        self.program.nodes.push(node);
+        Ok(self.program.nodes.len() - 1)
+    }
+
+    fn lattice(&mut self, lattice: Lattice, seed: u64) -> usize {
+        if let Some(&index) = self.lattices.get(&(lattice, seed)) {
+            return index;
+        }
+        let table = match self.program.tables.iter().position(|&s| s == seed) {
+            Some(table) => table,
+            None => {
+                self.program.tables.push(seed);
+                self.program.tables.len() - 1
+            }
+        };
+        self.program.nodes.push(Node::Lattice { lattice, table });
+        let index = self.program.nodes.len() - 1;
+        self.lattices.insert((lattice, seed), index);
+        index
+    }
+
+    fn vector(
+        &self,
+        recipe: &NoiseRecipe,
+        path: &str,
+        parameter: &'static str,
+        values: &[f64],
+    ) -> Result<Vec<f32>, CodegenError> {
+        if values.len() != self.program.dimension {
+            return Err(invalid(
+                recipe,
+                path,
+                parameter,
+                "must have one component per dimension",
+            ));
+        }
+        values
+            .iter()
+            .map(|&value| single(recipe, path, parameter, value))
+            .collect()
+    }
+
+    // computes the rotation matrix by rotating the basis vectors like the cpu implementation
+    fn rotation(
+        &self,
+        recipe: &NoiseRecipe,
+        path: &str,
+        rotation: &[f64],
+    ) -> Result<Vec<f32>, CodegenError> {
+        let columns: Vec<Vec<f64>> = match (self.program.dimension, rotation) {
+            (2, &[angle]) => {
+                let rotate = Rotate::<2, 1, _>::new(Constant::new(0.0), [angle]);
+                [[1.0, 0.0], [0.0, 1.0]]
+                    .map(|basis| rotate.rotate(basis).to_vec())
+                    .to_vec()
+            }
+            (3, &[a, b, c]) => {
+                let rotate = Rotate::<3, 3, _>::new(Constant::new(0.0), [a, b, c]);
+                [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+                    .map(|basis| rotate.rotate(basis).to_vec())
+                    .to_vec()
+            }
+            _ => {
+                return Err(invalid(
+                    recipe,
+                    path,
+                    "rotation",
+                    "must have one angle per plane of rotation",
+                ))
+            }
+        };
+        columns
+            .into_iter()
+            .flatten()
+            .map(|value| single(recipe, path, "rotation", value))
+            .collect()
+    }
+}
+
+// computes the octaves of a fractal and its normalization factor in double precision, the same
+// way as the cpu implementations
+fn octaves_of(
+    recipe: &NoiseRecipe,
+    path: &str,
+    octaves: u32,
+    frequency: f64,
+    lacunarity: f64,
+    persistence: f64,
+) -> Result<(Vec<Octave>, f32), CodegenError> {
+    if octaves == 0 {
+        return Err(invalid(recipe, path, "octaves", "must be at least 1"));
+    }
+    let mut lowered = Vec::new();
+    let (mut freq, mut amp, mut sum) = (frequency, 1.0, 0.0);
+    for _ in 0..octaves {
+        lowered.push(Octave {
+            frequency: single(recipe, path, "frequency", freq)?,
+            amplitude: single(recipe, path, "persistence", amp)?,
+        });
+        sum += amp;
+        freq *= lacunarity;
+        amp *= persistence;
+    }
+    let normalization = single(recipe, path, "persistence", 1.0 / sum)?;
+    Ok((lowered, normalization))
+}
+
+fn single(
+    recipe: &NoiseRecipe,
+    path: &str,
+    parameter: &'static str,
+    value: f64,
+) -> Result<f32, CodegenError> {
+    let value = value as f32;
+    if !value.is_finite() {
+        return Err(invalid(
+            recipe,
+            path,
+            parameter,
+            "is not finite in single precision",
+        ));
+    }
+    Ok(value)
+}
+
+fn lower_bound(
+    recipe: &NoiseRecipe,
+    path: &str,
+    parameter: &'static str,
+    value: f64,
+) -> Result<Option<f32>, CodegenError> {
+    match value {
+        f64::NEG_INFINITY => Ok(None),
+        value => single(recipe, path, parameter, value).map(Some),
+    }
+}
+
+fn upper_bound(
+    recipe: &NoiseRecipe,
+    path: &str,
+    parameter: &'static str,
+    value: f64,
+) -> Result<Option<f32>, CodegenError> {
+    match value {
+        f64::INFINITY => Ok(None),
+        value => single(recipe, path, parameter, value).map(Some),
+    }
+}
+
+fn invalid(
+    recipe: &NoiseRecipe,
+    path: &str,
+    parameter: &'static str,
+    reason: &'static str,
+) -> CodegenError {
+    CodegenError::InvalidParameter {
+        path: path.to_string(),
+        node: recipe.node(),
+        parameter,
+        reason,
+    }
+}
+
+pub(crate) fn validate_dimension(dimension: usize) -> Result<(), CodegenError> {
+    match dimension {
+        2 | 3 => Ok(()),
+        dimension => Err(CodegenError::UnsupportedDimension { dimension }),
+    }
+}
+
+// checks whether a name is an identifier in all supported shading languages, which excludes
+// names reserved by a leading double underscore, and whether the language does not reserve it
+pub(crate) fn validate_name(name: &str, reserved: fn(&str) -> bool) -> Result<(), CodegenError> {
+    let mut chars = name.chars();
+    let valid = matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && name != "_"
+        && !name.starts_with("__")
+        && !reserved(name);
+    if valid {
+        Ok(())
+    } else {
+        Err(CodegenError::InvalidName {
+            name: name.to_string(),
+        })
+    }
+}
+
+// formats a literal, which is valid in all supported shading languages
+pub(crate) fn float(value: f32) -> String {
+    format!("{:?}", value)
+}
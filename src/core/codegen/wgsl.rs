@@ -0,0 +1,778 @@
+use super::{float, Binary, Lattice, Node, Octave, Program, Unary};
+use crate::core::{
+    sources::functional::constants::{
+        CORNERPOINT_GRADIENT_LUT_2D, CORNERPOINT_GRADIENT_LUT_3D, MIDPOINT_GRADIENT_LUT_2D,
+        MIDPOINT_GRADIENT_LUT_3D, PERMUTATION_TABLE_SIZE, SIMPLEX_NORMALIZATION_FACTOR_2D,
+        SIMPLEX_NORMALIZATION_FACTOR_3D, SIMPLEX_SKEW_FACTOR_2D, SIMPLEX_SKEW_FACTOR_3D,
+        SIMPLEX_TRAVERSAL_LUT_3D, SIMPLEX_UNSKEW_FACTOR_2D, SIMPLEX_UNSKEW_FACTOR_3D,
+    },
+    utils::ptable::PermutationTable,
+};
+use std::fmt::Write;
+
+// keywords, reserved words, and the predeclared types and builtin functions used by the generated
+// code, none of which may be used as function name
+const RESERVED: &[&str] = &[
+    "NULL",
+    "Self",
+    "abs",
+    "abstract",
+    "active",
+    "alias",
+    "alignas",
+    "alignof",
+    "array",
+    "as",
+    "asm",
+    "asm_fragment",
+    "async",
+    "attribute",
+    "auto",
+    "await",
+    "become",
+    "bool",
+    "break",
+    "case",
+    "cast",
+    "catch",
+    "clamp",
+    "class",
+    "co_await",
+    "co_return",
+    "co_yield",
+    "coherent",
+    "column_major",
+    "common",
+    "compile",
+    "compile_fragment",
+    "concept",
+    "const",
+    "const_assert",
+    "const_cast",
+    "consteval",
+    "constexpr",
+    "constinit",
+    "continue",
+    "continuing",
+    "crate",
+    "debugger",
+    "decltype",
+    "default",
+    "delete",
+    "demote",
+    "demote_to_helper",
+    "diagnostic",
+    "discard",
+    "distance",
+    "do",
+    "dot",
+    "dynamic_cast",
+    "else",
+    "enable",
+    "enum",
+    "exp",
+    "explicit",
+    "export",
+    "extends",
+    "extern",
+    "external",
+    "f32",
+    "fallthrough",
+    "false",
+    "filter",
+    "final",
+    "finally",
+    "floor",
+    "fn",
+    "for",
+    "friend",
+    "from",
+    "fxgroup",
+    "get",
+    "goto",
+    "groupshared",
+    "highp",
+    "i32",
+    "if",
+    "impl",
+    "implements",
+    "import",
+    "inline",
+    "instanceof",
+    "interface",
+    "layout",
+    "let",
+    "log2",
+    "loop",
+    "lowp",
+    "macro",
+    "macro_rules",
+    "mat2x2",
+    "mat3x3",
+    "match",
+    "max",
+    "mediump",
+    "meta",
+    "min",
+    "mod",
+    "module",
+    "move",
+    "mut",
+    "mutable",
+    "namespace",
+    "new",
+    "nil",
+    "noexcept",
+    "noinline",
+    "nointerpolation",
+    "noperspective",
+    "null",
+    "nullptr",
+    "of",
+    "operator",
+    "override",
+    "package",
+    "packoffset",
+    "partition",
+    "pass",
+    "patch",
+    "pixelfragment",
+    "pow",
+    "precise",
+    "precision",
+    "premerge",
+    "priv",
+    "protected",
+    "pub",
+    "public",
+    "readonly",
+    "ref",
+    "regardless",
+    "register",
+    "reinterpret_cast",
+    "require",
+    "requires",
+    "resource",
+    "restrict",
+    "return",
+    "self",
+    "set",
+    "shared",
+    "sizeof",
+    "smooth",
+    "snorm",
+    "static",
+    "static_assert",
+    "static_cast",
+    "std",
+    "struct",
+    "subroutine",
+    "super",
+    "switch",
+    "target",
+    "template",
+    "this",
+    "thread_local",
+    "throw",
+    "trait",
+    "true",
+    "try",
+    "type",
+    "typedef",
+    "typeid",
+    "typename",
+    "typeof",
+    "u32",
+    "union",
+    "unless",
+    "unorm",
+    "unsafe",
+    "unsized",
+    "use",
+    "using",
+    "var",
+    "varying",
+    "vec2",
+    "vec3",
+    "virtual",
+    "volatile",
+    "wgsl",
+    "where",
+    "while",
+    "with",
+    "writeonly",
+    "yield",
+];
+
+pub(crate) fn is_reserved(name: &str) -> bool {
+    RESERVED.contains(&name)
+}
+
+// helpers shared by all lattice sources
+const LATTICE: &str = "
+fn $name_wrap(x: $vec) -> $uvec {
+    return $uvec($ivec(x) & $ivec(255));
+}
+
+fn $name_lerp(a: f32, b: f32, t: f32) -> f32 {
+    return a + t * (b - a);
+}
+
+fn $name_smoothstep(t: $vec) -> $vec {
+    return t * t * (t * -2.0 + 3.0);
+}
+";
+
+const SIMPLEX_2D: &str = "
+fn $name_simplex(x: vec2<f32>, table: u32) -> f32 {
+    let cell = floor(x + (x.x + x.y) * $skew);
+    let x0 = x - cell + (cell.x + cell.y) * $unskew;
+    let i = $name_wrap(cell);
+    var i1 = vec2<u32>(1u, 0u);
+    if x0.x < x0.y {
+        i1 = vec2<u32>(0u, 1u);
+    }
+    let x1 = x0 - vec2<f32>(i1) + $unskew;
+    let x2 = x0 - 1.0 + 2.0 * $unskew;
+    let n0 = $name_simplex_contribution(x0, $name_hash(table, i) % 4u);
+    let n1 = $name_simplex_contribution(x1, $name_hash(table, i + i1) % 4u);
+    let n2 = $name_simplex_contribution(x2, $name_hash(table, i + 1u) % 4u);
+    return (n0 + n1 + n2) * $normalization;
+}
+";
+
+const SIMPLEX_3D: &str = "
+fn $name_simplex(x: vec3<f32>, table: u32) -> f32 {
+    let cell = floor(x + (x.x + x.y + x.z) * $skew);
+    let x0 = x - cell + (cell.x + cell.y + cell.z) * $unskew;
+    let i = $name_wrap(cell);
+    let traversal = u32(x0.x > x0.y) * 4u + u32(x0.y > x0.z) * 2u + u32(x0.x > x0.z);
+    let i1 = $name_SIMPLEX_TRAVERSAL[traversal * 2u];
+    let i2 = $name_SIMPLEX_TRAVERSAL[traversal * 2u + 1u];
+    let x1 = x0 - vec3<f32>(i1) + $unskew;
+    let x2 = x0 - vec3<f32>(i2) + 2.0 * $unskew;
+    let x3 = x0 - 1.0 + 3.0 * $unskew;
+    let n0 = $name_simplex_contribution(x0, $name_hash(table, i) % 16u);
+    let n1 = $name_simplex_contribution(x1, $name_hash(table, i + i1) % 16u);
+    let n2 = $name_simplex_contribution(x2, $name_hash(table, i + i2) % 16u);
+    let n3 = $name_simplex_contribution(x3, $name_hash(table, i + 1u) % 16u);
+    return (n0 + n1 + n2 + n3) * $normalization;
+}
+";
+
+const SIMPLEX_CONTRIBUTION: &str = "
+fn $name_simplex_contribution(x: $vec, gradient: u32) -> f32 {
+    var t = 0.5 - dot(x, x);
+    if t <= 0.0 {
+        return 0.0;
+    }
+    t = t * t;
+    return t * t * dot($name_SIMPLEX_GRADIENTS[gradient], x);
+}
+";
+
+const PERLIN_2D: &str = "
+fn $name_perlin(x: vec2<f32>, table: u32) -> f32 {
+    let cell = floor(x);
+    let dx = x - cell;
+    let dxs = $name_smoothstep(dx);
+    let i = $name_wrap(cell);
+    let n00 = $name_perlin_contribution(table, i, dx, vec2<u32>(0u, 0u));
+    let n01 = $name_perlin_contribution(table, i, dx, vec2<u32>(0u, 1u));
+    let n10 = $name_perlin_contribution(table, i, dx, vec2<u32>(1u, 0u));
+    let n11 = $name_perlin_contribution(table, i, dx, vec2<u32>(1u, 1u));
+    let xn0 = $name_lerp(n00, n10, dxs.x);
+    let xn1 = $name_lerp(n01, n11, dxs.x);
+    return $name_lerp(xn0, xn1, dxs.y);
+}
+";
+
+const PERLIN_3D: &str = "
+fn $name_perlin(x: vec3<f32>, table: u32) -> f32 {
+    let cell = floor(x);
+    let dx = x - cell;
+    let dxs = $name_smoothstep(dx);
+    let i = $name_wrap(cell);
+    let n000 = $name_perlin_contribution(table, i, dx, vec3<u32>(0u, 0u, 0u));
+    let n001 = $name_perlin_contribution(table, i, dx, vec3<u32>(0u, 0u, 1u));
+    let n010 = $name_perlin_contribution(table, i, dx, vec3<u32>(0u, 1u, 0u));
+    let n011 = $name_perlin_contribution(table, i, dx, vec3<u32>(0u, 1u, 1u));
+    let n100 = $name_perlin_contribution(table, i, dx, vec3<u32>(1u, 0u, 0u));
+    let n101 = $name_perlin_contribution(table, i, dx, vec3<u32>(1u, 0u, 1u));
+    let n110 = $name_perlin_contribution(table, i, dx, vec3<u32>(1u, 1u, 0u));
+    let n111 = $name_perlin_contribution(table, i, dx, vec3<u32>(1u, 1u, 1u));
+    let xn00 = $name_lerp(n000, n100, dxs.x);
+    let xn01 = $name_lerp(n001, n101, dxs.x);
+    let xn10 = $name_lerp(n010, n110, dxs.x);
+    let xn11 = $name_lerp(n011, n111, dxs.x);
+    let yn0 = $name_lerp(xn00, xn10, dxs.y);
+    let yn1 = $name_lerp(xn01, xn11, dxs.y);
+    return $name_lerp(yn0, yn1, dxs.z) * 0.6666666666666666;
+}
+";
+
+const PERLIN_CONTRIBUTION: &str = "
+fn $name_perlin_contribution(table: u32, i: $uvec, dx: $vec, corner: $uvec) -> f32 {
+    let gradient = $name_PERLIN_GRADIENTS[$name_hash(table, i + corner) % $gradients];
+    return dot(gradient, dx - $vec(corner));
+}
+";
+
+const VALUE_2D: &str = "
+fn $name_value(x: vec2<f32>, table: u32) -> f32 {
+    let cell = floor(x);
+    let dxs = $name_smoothstep(x - cell);
+    let i = $name_wrap(cell);
+    let f00 = f32($name_hash(table, i));
+    let f01 = f32($name_hash(table, i + vec2<u32>(0u, 1u)));
+    let f10 = f32($name_hash(table, i + vec2<u32>(1u, 0u)));
+    let f11 = f32($name_hash(table, i + 1u));
+    let xf0 = $name_lerp(f00, f10, dxs.x);
+    let xf1 = $name_lerp(f01, f11, dxs.x);
+    return 2.0 / 256.0 * $name_lerp(xf0, xf1, dxs.y) - 1.0;
+}
+";
+
+const VALUE_3D: &str = "
+fn $name_value(x: vec3<f32>, table: u32) -> f32 {
+    let cell = floor(x);
+    let dxs = $name_smoothstep(x - cell);
+    let i = $name_wrap(cell);
+    let f000 = f32($name_hash(table, i));
+    let f001 = f32($name_hash(table, i + vec3<u32>(0u, 0u, 1u)));
+    let f010 = f32($name_hash(table, i + vec3<u32>(0u, 1u, 0u)));
+    let f011 = f32($name_hash(table, i + vec3<u32>(0u, 1u, 1u)));
+    let f100 = f32($name_hash(table, i + vec3<u32>(1u, 0u, 0u)));
+    let f101 = f32($name_hash(table, i + vec3<u32>(1u, 0u, 1u)));
+    let f110 = f32($name_hash(table, i + vec3<u32>(1u, 1u, 0u)));
+    let f111 = f32($name_hash(table, i + 1u));
+    let xf00 = $name_lerp(f000, f100, dxs.x);
+    let xf01 = $name_lerp(f001, f101, dxs.x);
+    let xf10 = $name_lerp(f010, f110, dxs.x);
+    let xf11 = $name_lerp(f011, f111, dxs.x);
+    let yf0 = $name_lerp(xf00, xf10, dxs.y);
+    let yf1 = $name_lerp(xf01, xf11, dxs.y);
+    return 2.0 / 256.0 * $name_lerp(yf0, yf1, dxs.z) - 1.0;
+}
+";
+
+const POWI: &str = "
+fn $name_powi(x: f32, exponent: i32) -> f32 {
+    var base = x;
+    if exponent < 0 {
+        base = 1.0 / x;
+    }
+    var remaining = u32(abs(exponent));
+    var result = 1.0;
+    while remaining > 0u {
+        if (remaining & 1u) == 1u {
+            result *= base;
+        }
+        base *= base;
+        remaining >>= 1u;
+    }
+    return result;
+}
+";
+
+// generates wgsl code for a lowered program, with the root node named after the program
+pub(crate) fn generate(program: &Program, name: &str) -> String {
+    let dimension = program.dimension;
+    let vec = format!("vec{}<f32>", dimension);
+    let mut library = String::new();
+    let lattices = |lattice| {
+        program
+            .nodes
+            .iter()
+            .any(|node| matches!(node, Node::Lattice { lattice: used, .. } if *used == lattice))
+    };
+
+    if !program.tables.is_empty() {
+        library += &permutation_tables(name, &program.tables);
+        library += &hash(name, dimension);
+        library += LATTICE;
+    }
+    if lattices(Lattice::Simplex) {
+        let (skew, unskew, normalization, gradients) = if dimension == 2 {
+            (
+                SIMPLEX_SKEW_FACTOR_2D,
+                SIMPLEX_UNSKEW_FACTOR_2D,
+                SIMPLEX_NORMALIZATION_FACTOR_2D,
+                array(&MIDPOINT_GRADIENT_LUT_2D),
+            )
+        } else {
+            library += &traversal(name);
+            (
+                SIMPLEX_SKEW_FACTOR_3D,
+                SIMPLEX_UNSKEW_FACTOR_3D,
+                SIMPLEX_NORMALIZATION_FACTOR_3D,
+                array(&MIDPOINT_GRADIENT_LUT_3D),
+            )
+        };
+        library += &gradient_table(name, "SIMPLEX", &vec, &gradients);
+        library += &if dimension == 2 {
+            SIMPLEX_2D
+        } else {
+            SIMPLEX_3D
+        }
+        .replace("$skew", &float(skew as f32))
+        .replace("$unskew", &float(unskew as f32))
+        .replace("$normalization", &float(normalization as f32));
+        library += SIMPLEX_CONTRIBUTION;
+    }
+    if lattices(Lattice::Perlin) {
+        let gradients = if dimension == 2 {
+            array(&CORNERPOINT_GRADIENT_LUT_2D)
+        } else {
+            array(&CORNERPOINT_GRADIENT_LUT_3D)
+        };
+        library += &gradient_table(name, "PERLIN", &vec, &gradients);
+        library += if dimension == 2 { PERLIN_2D } else { PERLIN_3D };
+        library += &PERLIN_CONTRIBUTION.replace("$gradients", &format!("{}u", gradients.len()));
+    }
+    if lattices(Lattice::Value) {
+        library += if dimension == 2 { VALUE_2D } else { VALUE_3D };
+    }
+    if program
+        .nodes
+        .iter()
+        .any(|node| matches!(node, Node::Checkerboard { smooth: false, .. }))
+    {
+        library += &checkerboard(dimension);
+    }
+    if program
+        .nodes
+        .iter()
+        .any(|node| matches!(node, Node::Checkerboard { smooth: true, .. }))
+    {
+        library += &checkerboard_smooth(dimension);
+    }
+    if program
+        .nodes
+        .iter()
+        .any(|node| matches!(node, Node::Powi { .. }))
+    {
+        library += POWI;
+    }
+
+    let mut code = format!("// {}(p: {}) -> f32, generated by libnoise\n", name, vec);
+    code += &library
+        .replace("$name", name)
+        .replace("$vec", &vec)
+        .replace("$uvec", &format!("vec{}<u32>", dimension))
+        .replace("$ivec", &format!("vec{}<i32>", dimension));
+    for (index, node) in program.nodes.iter().enumerate() {
+        let function = if index + 1 == program.nodes.len() {
+            name.to_string()
+        } else {
+            node_name(name, index)
+        };
+        let body = body(node, name, &vec);
+        let _ = write!(
+            code,
+            "\nfn {}(p: {}) -> f32 {{\n{}}}\n",
+            function, vec, body
+        );
+    }
+    code
+}
+
+fn node_name(name: &str, index: usize) -> String {
+    format!("{}_n{}", name, index)
+}
+
+// generates the statements of the function evaluating a node
+fn body(node: &Node, name: &str, vec: &str) -> String {
+    let call = |index: usize, point: &str| format!("{}({})", node_name(name, index), point);
+    let vector = |values: &[f32]| {
+        let values = values.iter().map(|&x| float(x)).collect::<Vec<_>>();
+        format!("{}({})", vec, values.join(", "))
+    };
+    let ret = |expression: String| format!("    return {};\n", expression);
+    match node {
+        Node::Constant(value) => ret(float(*value)),
+        Node::Checkerboard { period, smooth } => {
+            let function = if *smooth {
+                "checkerboard_smooth"
+            } else {
+                "checkerboard"
+            };
+            if period.iter().all(|&x| x == 1.0) {
+                ret(format!("{}_{}(p)", name, function))
+            } else {
+                ret(format!("{}_{}(p / {})", name, function, vector(period)))
+            }
+        }
+        Node::Lattice { lattice, table } => {
+            let function = match lattice {
+                Lattice::Simplex => "simplex",
+                Lattice::Perlin => "perlin",
+                Lattice::Value => "value",
+            };
+            let offset = table * 2 * PERMUTATION_TABLE_SIZE;
+            ret(format!("{}_{}(p, {}u)", name, function, offset))
+        }
+        Node::Scale { source, scale } => ret(call(*source, &format!("p * {}", vector(scale)))),
+        Node::Translate {
+            source,
+            translation,
+        } => ret(call(*source, &format!("p + {}", vector(translation)))),
+        Node::Rotate { source, matrix } => {
+            let dimension = (matrix.len() as f64).sqrt() as usize;
+            let matrix = matrix.iter().map(|&x| float(x)).collect::<Vec<_>>();
+            let matrix = format!(
+                "mat{}x{}<f32>({})",
+                dimension,
+                dimension,
+                matrix.join(", ")
+            );
+            ret(call(*source, &format!("{} * p", matrix)))
+        }
+        Node::Displace {
+            source,
+            displacement,
+            axis,
+        } => format!(
+            "    var q = p;\n    q.{} += {};\n    return {};\n",
+            ["x", "y", "z"][*axis],
+            call(*displacement, "p"),
+            call(*source, "q")
+        ),
+        Node::Unary { op, source } => {
+            let value = call(*source, "p");
+            ret(match op {
+                Unary::Neg => format!("-{}", value),
+                Unary::Abs => format!("abs({})", value),
+                Unary::Exp => format!("exp({})", value),
+            })
+        }
+        Node::Add { source, offset } => ret(format!("{} + {}", call(*source, "p"), float(*offset))),
+        Node::Mul { source, scale } => ret(format!("{} * {}", call(*source, "p"), float(*scale))),
+        Node::Powi { source, exponent } => ret(format!(
+            "{}_powi({}, {})",
+            name,
+            call(*source, "p"),
+            exponent
+        )),
+        Node::Powf { source, exponent } => ret(format!(
+            "pow({}, {})",
+            call(*source, "p"),
+            float(*exponent)
+        )),
+        Node::Clamp { source, min, max } => {
+            let value = call(*source, "p");
+            ret(match (min, max) {
+                (Some(min), Some(max)) => format!("clamp({}, {}, {})", value, float(*min), float(*max)),
+                (Some(min), None) => format!("max({}, {})", value, float(*min)),
+                (None, Some(max)) => format!("min({}, {})", value, float(*max)),
+                (None, None) => value,
+            })
+        }
+        Node::Binary { op, a, b } => {
+            let (a, b) = (call(*a, "p"), call(*b, "p"));
+            ret(match op {
+                Binary::Sum => format!("{} + {}", a, b),
+                Binary::Product => format!("{} * {}", a, b),
+                Binary::Min => format!("min({}, {})", a, b),
+                Binary::Max => format!("max({}, {})", a, b),
+                Binary::Power => format!("pow({}, {})", a, b),
+            })
+        }
+        Node::Blend { a, b, control } => format!(
+            "    let a = {};\n    let b = {};\n    let t = {} * 0.5 + 0.5;\n    return a + t * (b - a);\n",
+            call(*a, "p"),
+            call(*b, "p"),
+            call(*control, "p")
+        ),
+        Node::Select {
+            a,
+            b,
+            control,
+            min,
+            max,
+        } => {
+            let condition = match (min, max) {
+                (Some(min), Some(max)) => {
+                    format!("control >= {} && control <= {}", float(*min), float(*max))
+                }
+                (Some(min), None) => format!("control >= {}", float(*min)),
+                (None, Some(max)) => format!("control <= {}", float(*max)),
+                (None, None) => return ret(call(*a, "p")),
+            };
+            format!(
+                "    let control = {};\n    if {} {{\n        return {};\n    }}\n    return {};\n",
+                call(*control, "p"),
+                condition,
+                call(*a, "p"),
+                call(*b, "p")
+            )
+        }
+        Node::Fbm {
+            source,
+            octaves,
+            normalization,
+        } => fractal(octaves, *normalization, |octave| {
+            format!(
+                "{} * {}",
+                float(octave.amplitude),
+                call(*source, &format!("p * {}", float(octave.frequency)))
+            )
+        }),
+        Node::Billow {
+            source,
+            octaves,
+            normalization,
+        } => fractal(octaves, *normalization, |octave| {
+            format!(
+                "{} * (abs({}) * 2.0 - 1.0)",
+                float(octave.amplitude),
+                call(*source, &format!("p * {}", float(octave.frequency)))
+            )
+        }),
+        Node::RidgedMulti {
+            source,
+            frequencies,
+            attenuation,
+            normalization,
+        } => {
+            let mut body = "    var value = 0.0;\n    var amplitude = 1.0;\n    var layer = 0.0;\n"
+                .to_string();
+            for frequency in frequencies {
+                let _ = write!(
+                    body,
+                    "    layer = 1.0 - abs({});\n    layer = layer * layer * amplitude;\n    value += layer;\n    amplitude = clamp(layer / {}, 0.0, 1.0);\n",
+                    call(*source, &format!("p * {}", float(*frequency))),
+                    float(*attenuation)
+                );
+            }
+            body + &format!("    return value * {} * 2.0 - 1.0;\n", float(*normalization))
+        }
+        Node::DistanceLod {
+            source,
+            octaves,
+            focus,
+            near,
+        } => {
+            let mut body = format!(
+                "    let lod = max(log2(distance(p, {}) / {}), 0.0);\n    let remaining = max({} - lod, 1.0);\n    var value = 0.0;\n    var total = 0.0;\n    var weight = 0.0;\n",
+                vector(focus),
+                float(*near),
+                float(octaves.len() as f32)
+            );
+            for (index, octave) in octaves.iter().enumerate() {
+                let _ = write!(
+                    body,
+                    "    weight = min(remaining - {}, 1.0);\n    if weight > 0.0 {{\n        value += {} * weight * {};\n        total += {} * weight;\n    }}\n",
+                    float(index as f32),
+                    float(octave.amplitude),
+                    call(*source, &format!("p * {}", float(octave.frequency))),
+                    float(octave.amplitude)
+                );
+            }
+            body + "    return value * (1.0 / total);\n"
+        }
+    }
+}
+
+// unrolls the octaves of a fractal, whose frequencies and amplitudes are known in advance
+fn fractal(octaves: &[Octave], normalization: f32, octave: impl Fn(&Octave) -> String) -> String {
+    let mut body = "    var value = 0.0;\n".to_string();
+    for o in octaves {
+        let _ = writeln!(body, "    value += {};", octave(o));
+    }
+    body + &format!("    return value * {};\n", float(normalization))
+}
+
+// declares the permutation tables of all seeds, laid out one after another, where each table is
+// doubled up like on the cpu such that hashes never need to wrap
+fn permutation_tables(name: &str, seeds: &[u64]) -> String {
+    let entries = seeds
+        .iter()
+        .flat_map(|&seed| PermutationTable::new(seed, PERMUTATION_TABLE_SIZE, true).table)
+        .collect::<Vec<_>>();
+    let rows = entries
+        .chunks(16)
+        .map(|row| {
+            let row = row.iter().map(|x| format!("{}u", x)).collect::<Vec<_>>();
+            format!("    {},", row.join(", "))
+        })
+        .collect::<Vec<_>>();
+    format!(
+        "\nconst {}_PERM = array<u32, {}>(\n{}\n);\n",
+        name,
+        entries.len(),
+        rows.join("\n")
+    )
+}
+
+fn hash(name: &str, dimension: usize) -> String {
+    let lookup =
+        ["i.x", "i.y", "i.z"][..dimension]
+            .iter()
+            .fold(String::new(), |inner, component| {
+                if inner.is_empty() {
+                    format!("{}_PERM[table + {}]", name, component)
+                } else {
+                    format!("{}_PERM[table + {} + {}]", name, component, inner)
+                }
+            });
+    format!(
+        "\nfn $name_hash(table: u32, i: $uvec) -> u32 {{\n    return {};\n}}\n",
+        lookup
+    )
+}
+
+fn traversal(name: &str) -> String {
+    let rows = SIMPLEX_TRAVERSAL_LUT_3D
+        .iter()
+        .flat_map(|row| [&row[..3], &row[3..]])
+        .map(|i| format!("    vec3<u32>({}u, {}u, {}u),", i[0], i[1], i[2]))
+        .collect::<Vec<_>>();
+    format!(
+        "\nconst {}_SIMPLEX_TRAVERSAL = array<vec3<u32>, {}>(\n{}\n);\n",
+        name,
+        rows.len(),
+        rows.join("\n")
+    )
+}
+
+fn gradient_table(name: &str, kind: &str, vec: &str, gradients: &[Vec<f64>]) -> String {
+    let rows = gradients
+        .iter()
+        .map(|gradient| {
+            let gradient = gradient
+                .iter()
+                .map(|&x| float(x as f32))
+                .collect::<Vec<_>>();
+            format!("    {}({}),", vec, gradient.join(", "))
+        })
+        .collect::<Vec<_>>();
+    format!(
+        "\nconst {}_{}_GRADIENTS = array<{}, {}>(\n{}\n);\n",
+        name,
+        kind,
+        vec,
+        rows.len(),
+        rows.join("\n")
+    )
+}
+
+fn checkerboard(dimension: usize) -> String {
+    let parity = ["c.x", "c.y", "c.z"][..dimension].join(" ^ ");
+    format!(
+        "\nfn $name_checkerboard(x: $vec) -> f32 {{\n    let c = $ivec(x) & $ivec(1);\n    return f32({}) * 2.0 - 1.0;\n}}\n",
+        parity
+    )
+}
+
+fn checkerboard_smooth(dimension: usize) -> String {
+    let product = ["s.x", "s.y", "s.z"][..dimension].join(" * ");
+    format!(
+        "\nfn $name_checkerboard_smooth(x: $vec) -> f32 {{\n    let s = sin(3.14159265 * x);\n    return -({});\n}}\n",
+        product
+    )
+}
+
+fn array<const N: usize>(rows: &[[f64; N]]) -> Vec<Vec<f64>> {
+    rows.iter().map(|row| row.to_vec()).collect()
+}
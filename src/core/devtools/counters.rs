@@ -0,0 +1,12 @@
+use crate::DistanceField;
+
+/// Returns the number of nodes of the kd-tree of `generator` visited when sampling `point`.
+///
+/// Unlike timings, this measures the cost of a query independently of the machine, and can thus
+/// be used to verify the complexity of the search in tests.
+pub fn distance_field_visits<const D: usize>(
+    generator: &DistanceField<D>,
+    point: [f64; D],
+) -> usize {
+    generator.visits(point)
+}
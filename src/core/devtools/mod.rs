@@ -1 +1,2 @@
 pub mod benchtools;
+pub mod counters;
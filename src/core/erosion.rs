@@ -0,0 +1,369 @@
+//! Erosion simulations for post-processing height fields.
+//!
+//! Height fields generated from fractal noise are statistically uniform, lacking the valleys,
+//! ridges, and drainage patterns carved into real terrain by water and gravity. The functions of
+//! this module simulate such processes on a 2-dimensional [`NoiseBuffer`] in place, which is
+//! interpreted as a grid of heights.
+//!
+//! All simulations are deterministic: for a given buffer, set of parameters, and seed, the result
+//! is bit-identical across runs and platforms.
+
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::utils::noisebuf::NoiseBuffer;
+use alloc::{vec, vec::Vec};
+use core::f64::consts::{FRAC_PI_2, SQRT_2};
+use rand::Rng;
+use rand_chacha::{rand_core::SeedableRng, ChaCha12Rng};
+
+/// Parameters of the droplet simulation performed by [`hydraulic()`].
+///
+/// All distances are measured in cells of the height field. The default parameters are suitable
+/// for height fields with values in the range `[-1, 1]`, as produced by most generators.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::erosion::HydraulicParams;
+/// let params = HydraulicParams {
+///     droplets: 20_000,
+///     erosion_radius: 2,
+///     ..HydraulicParams::default()
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HydraulicParams {
+    /// The number of droplets to simulate.
+    pub droplets: usize,
+    /// The maximum number of steps a droplet takes before it evaporates completely.
+    pub max_lifetime: usize,
+    /// How much a droplet keeps its direction rather than following the gradient, in `[0, 1]`.
+    pub inertia: f64,
+    /// The factor of the amount of sediment a droplet can carry, relative to its speed, water,
+    /// and the height difference of its last step.
+    pub sediment_capacity_factor: f64,
+    /// The minimum amount of sediment a droplet can carry, which prevents flat terrain from
+    /// stopping erosion entirely.
+    pub min_sediment_capacity: f64,
+    /// The fraction of its free capacity a droplet erodes in each step, in `[0, 1]`.
+    pub erode_speed: f64,
+    /// The fraction of its excess sediment a droplet deposits in each step, in `[0, 1]`.
+    pub deposit_speed: f64,
+    /// The fraction of its water a droplet loses in each step, in `[0, 1]`.
+    pub evaporate_speed: f64,
+    /// The acceleration of droplets moving downhill.
+    pub gravity: f64,
+    /// The initial amount of water of each droplet.
+    pub initial_water: f64,
+    /// The initial speed of each droplet.
+    pub initial_speed: f64,
+    /// The radius of the brush droplets erode the terrain with. A radius of 1 only erodes the
+    /// cell the droplet is in.
+    pub erosion_radius: usize,
+}
+
+impl Default for HydraulicParams {
+    fn default() -> Self {
+        Self {
+            droplets: 50_000,
+            max_lifetime: 30,
+            inertia: 0.05,
+            sediment_capacity_factor: 4.0,
+            min_sediment_capacity: 0.01,
+            erode_speed: 0.3,
+            deposit_speed: 0.3,
+            evaporate_speed: 0.01,
+            gravity: 4.0,
+            initial_water: 1.0,
+            initial_speed: 1.0,
+            erosion_radius: 3,
+        }
+    }
+}
+
+/// Erodes the given height field by simulating droplets of water flowing downhill.
+///
+/// Each droplet starts at a random position, drawn from a generator seeded with `seed`, and
+/// moves along the gradient of the height field, which is interpolated bilinearly between grid
+/// points. Depending on its speed, water, and the height difference of each step, the droplet
+/// either picks up sediment from the terrain around it, distributed over a circular brush of
+/// radius [`erosion_radius`], or deposits sediment bilinearly onto the four grid points
+/// surrounding it. Whenever a droplet stops, because its lifetime ended, it came to a halt, or
+/// it left the height field, any sediment it still carries is deposited at its last position
+/// within the height field. Thus, the sum of all heights is preserved up to rounding errors.
+///
+/// Height fields with less than two values along either axis are left unchanged.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, Generator, NoiseBuffer, erosion::{self, HydraulicParams}};
+/// let generator = Source::simplex(42).fbm(6, 0.01, 2.0, 0.5);
+/// let mut buf = NoiseBuffer::<2>::new([128, 128], &generator);
+///
+/// let params = HydraulicParams { droplets: 10_000, ..HydraulicParams::default() };
+/// erosion::hydraulic(&mut buf, params, 42);
+/// ```
+///
+/// [`erosion_radius`]: HydraulicParams::erosion_radius
+pub fn hydraulic(buffer: &mut NoiseBuffer<2>, params: HydraulicParams, seed: u64) {
+    let [width, height] = buffer.shape;
+    if width < 2 || height < 2 {
+        return;
+    }
+    let brush = erosion_brush(params.erosion_radius);
+    let mut rng = ChaCha12Rng::seed_from_u64(seed);
+    for _ in 0..params.droplets {
+        let mut position = [
+            rng.gen_range(0.0..(width - 1) as f64),
+            rng.gen_range(0.0..(height - 1) as f64),
+        ];
+        let mut direction = [0.0, 0.0];
+        let mut speed = params.initial_speed;
+        let mut water = params.initial_water;
+        let mut sediment = 0.0;
+        for _ in 0..params.max_lifetime {
+            let (elevation, gradient) = height_and_gradient(buffer, position);
+            for (component, slope) in direction.iter_mut().zip(gradient) {
+                *component = *component * params.inertia - slope * (1.0 - params.inertia);
+            }
+            let length = (direction[0] * direction[0] + direction[1] * direction[1]).sqrt();
+            if length == 0.0 {
+                break;
+            }
+            direction = direction.map(|component| component / length);
+            let next = [position[0] + direction[0], position[1] + direction[1]];
+            if !in_bounds(buffer, next) {
+                break;
+            }
+            let delta = height_and_gradient(buffer, next).0 - elevation;
+            let capacity = (-delta * speed * water * params.sediment_capacity_factor)
+                .max(params.min_sediment_capacity);
+            if sediment > capacity || delta > 0.0 {
+                // fill up pits when moving uphill, and drop excess sediment otherwise
+                let amount = if delta > 0.0 {
+                    delta.min(sediment)
+                } else {
+                    (sediment - capacity) * params.deposit_speed
+                };
+                sediment -= amount;
+                deposit(buffer, position, amount);
+            } else {
+                // never erode more than the height difference, to avoid digging holes
+                let amount = ((capacity - sediment) * params.erode_speed).min(-delta);
+                sediment += erode(buffer, position, &brush, amount);
+            }
+            speed = (speed * speed + delta * params.gravity).max(0.0).sqrt();
+            water *= 1.0 - params.evaporate_speed;
+            position = next;
+        }
+        deposit(buffer, position, sediment);
+    }
+}
+
+fn in_bounds(buffer: &NoiseBuffer<2>, position: [f64; 2]) -> bool {
+    position
+        .iter()
+        .zip(buffer.shape)
+        .all(|(&component, dim_size)| component >= 0.0 && component < (dim_size - 1) as f64)
+}
+
+// the bilinearly interpolated height and its gradient at the given position, which must lie
+// within the height field, excluding its upper edges
+fn height_and_gradient(buffer: &NoiseBuffer<2>, [x, y]: [f64; 2]) -> (f64, [f64; 2]) {
+    let (ix, iy) = (x as usize, y as usize);
+    let (u, v) = (x - ix as f64, y - iy as f64);
+    let h00 = buffer[[ix, iy]];
+    let h10 = buffer[[ix + 1, iy]];
+    let h01 = buffer[[ix, iy + 1]];
+    let h11 = buffer[[ix + 1, iy + 1]];
+    let elevation =
+        h00 * (1.0 - u) * (1.0 - v) + h10 * u * (1.0 - v) + h01 * (1.0 - u) * v + h11 * u * v;
+    let gradient = [
+        (h10 - h00) * (1.0 - v) + (h11 - h01) * v,
+        (h01 - h00) * (1.0 - u) + (h11 - h10) * u,
+    ];
+    (elevation, gradient)
+}
+
+// distributes the given amount of sediment bilinearly onto the four grid points surrounding the
+// given position
+fn deposit(buffer: &mut NoiseBuffer<2>, [x, y]: [f64; 2], amount: f64) {
+    let (ix, iy) = (x as usize, y as usize);
+    let (u, v) = (x - ix as f64, y - iy as f64);
+    buffer[[ix, iy]] += amount * (1.0 - u) * (1.0 - v);
+    buffer[[ix + 1, iy]] += amount * u * (1.0 - v);
+    buffer[[ix, iy + 1]] += amount * (1.0 - u) * v;
+    buffer[[ix + 1, iy + 1]] += amount * u * v;
+}
+
+// removes the given amount of terrain, weighted by the brush centered on the grid point below
+// the given position, and returns the amount actually removed, as parts of the brush may lie
+// outside the height field
+fn erode(
+    buffer: &mut NoiseBuffer<2>,
+    [x, y]: [f64; 2],
+    brush: &[([isize; 2], f64)],
+    amount: f64,
+) -> f64 {
+    let center = [x as isize, y as isize];
+    let mut removed = 0.0;
+    for ([dx, dy], weight) in brush {
+        let (bx, by) = (center[0] + dx, center[1] + dy);
+        if bx < 0 || by < 0 || bx >= buffer.shape[0] as isize || by >= buffer.shape[1] as isize {
+            continue;
+        }
+        let weighted = amount * weight;
+        buffer[[bx as usize, by as usize]] -= weighted;
+        removed += weighted;
+    }
+    removed
+}
+
+// the offsets of the grid points within the given radius, weighted by their distance from the
+// center and normalized such that the weights sum up to 1
+fn erosion_brush(radius: usize) -> Vec<([isize; 2], f64)> {
+    let radius = radius.max(1) as isize;
+    let mut brush = Vec::new();
+    for dx in -radius + 1..radius {
+        for dy in -radius + 1..radius {
+            let distance = ((dx * dx + dy * dy) as f64).sqrt();
+            if distance < radius as f64 {
+                brush.push(([dx, dy], 1.0 - distance / radius as f64));
+            }
+        }
+    }
+    let total = brush.iter().map(|(_, weight)| weight).sum::<f64>();
+    for (_, weight) in brush.iter_mut() {
+        *weight /= total;
+    }
+    brush
+}
+
+/// The neighbors of a cell taken into account by [`thermal()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Neighborhood {
+    /// The 4 neighbors sharing an edge with the cell.
+    VonNeumann,
+    /// The 8 neighbors sharing an edge or a corner with the cell.
+    Moore,
+}
+
+impl Neighborhood {
+    // the offsets of the neighbors, paired with their distance in cells
+    fn offsets(self) -> &'static [([isize; 2], f64)] {
+        const VON_NEUMANN: [([isize; 2], f64); 4] =
+            [([-1, 0], 1.0), ([1, 0], 1.0), ([0, -1], 1.0), ([0, 1], 1.0)];
+        const MOORE: [([isize; 2], f64); 8] = [
+            ([-1, -1], SQRT_2),
+            ([-1, 0], 1.0),
+            ([-1, 1], SQRT_2),
+            ([0, -1], 1.0),
+            ([0, 1], 1.0),
+            ([1, -1], SQRT_2),
+            ([1, 0], 1.0),
+            ([1, 1], SQRT_2),
+        ];
+        match self {
+            Self::VonNeumann => &VON_NEUMANN,
+            Self::Moore => &MOORE,
+        }
+    }
+}
+
+/// Erodes the given height field by letting material slip down slopes steeper than the given
+/// talus angle.
+///
+/// The height field is interpreted as a grid with a spacing of `cell_size` between values along
+/// both axes, and `talus_angle` is the steepest stable slope in radians. In each of the given
+/// number of `iterations`, every cell whose slope towards any of its neighbors, as selected by
+/// `neighborhood`, exceeds the talus angle moves material to those neighbors. Half of the largest
+/// excess height difference is moved in total, distributed among the lower neighbors in
+/// proportion to their excess, which levels a pair of cells exactly to the talus angle. All cells
+/// are updated simultaneously, such that the result does not depend on the order in which cells
+/// are visited, and material is only ever moved between cells, such that the sum of all heights
+/// is preserved up to rounding errors. The simulation stops early once no material is moved.
+///
+/// This smooths steep slopes into scree slopes, and is considerably cheaper than [`hydraulic()`].
+///
+/// # Panics
+///
+/// Panics if `talus_angle` is not within `[0, pi/2)`, or `cell_size` is not positive and finite.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, Generator, NoiseBuffer, erosion::{self, Neighborhood}};
+/// let generator = Source::simplex(42).fbm(6, 0.01, 2.0, 0.5);
+/// let mut buf = NoiseBuffer::<2>::new([128, 128], &generator);
+///
+/// // settle slopes steeper than 30 degrees, with a spacing of 0.01 between grid points
+/// erosion::thermal(&mut buf, 50, 30f64.to_radians(), 0.01, Neighborhood::Moore);
+/// ```
+pub fn thermal(
+    buffer: &mut NoiseBuffer<2>,
+    iterations: usize,
+    talus_angle: f64,
+    cell_size: f64,
+    neighborhood: Neighborhood,
+) {
+    assert!(
+        (0.0..FRAC_PI_2).contains(&talus_angle),
+        "talus angle must be within [0, pi/2), got {}",
+        talus_angle
+    );
+    assert!(
+        cell_size > 0.0 && cell_size.is_finite(),
+        "cell size must be positive and finite, got {}",
+        cell_size
+    );
+    let [width, height] = buffer.shape;
+    let offsets = neighborhood.offsets();
+    let talus = talus_angle.tan() * cell_size;
+    let mut changes = vec![0.0; buffer.buffer.len()];
+    let mut excesses = Vec::with_capacity(offsets.len());
+    for _ in 0..iterations {
+        changes.fill(0.0);
+        let mut moved = false;
+        for x in 0..width {
+            for y in 0..height {
+                let elevation = buffer[[x, y]];
+                let (mut total, mut max) = (0.0, 0.0_f64);
+                excesses.clear();
+                for ([dx, dy], distance) in offsets {
+                    let (nx, ny) = (x as isize + dx, y as isize + dy);
+                    if nx < 0 || ny < 0 || nx >= width as isize || ny >= height as isize {
+                        continue;
+                    }
+                    let neighbor = nx as usize * height + ny as usize;
+                    let excess = elevation - buffer.buffer[neighbor] - talus * distance;
+                    if excess > 0.0 {
+                        excesses.push((neighbor, excess));
+                        total += excess;
+                        max = max.max(excess);
+                    }
+                }
+                for &(neighbor, excess) in &excesses {
+                    let amount = 0.5 * max * excess / total;
+                    changes[neighbor] += amount;
+                    changes[x * height + y] -= amount;
+                    moved = true;
+                }
+            }
+        }
+        if !moved {
+            break;
+        }
+        for (value, change) in buffer.buffer.iter_mut().zip(&changes) {
+            *value += change;
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+/// The error type for fallible operations of this crate.
+///
+/// Most of the functionality provided by this crate is infallible. Where an operation can fail
+/// due to invalid input, such as a buffer of the wrong length, or due to its environment, such as
+/// an unwritable path, this type describes the reason. Panics are reserved for violated
+/// invariants which are programmer errors, such as indexing a buffer out of bounds.
+///
+/// The errors of building a recipe and of generating shader code are described by the more
+/// detailed [`RecipeError`] and [`CodegenError`] respectively, which convert into this type,
+/// such that they can be propagated alongside other errors using the `?` operator.
+///
+/// [`RecipeError`]: crate::recipe::RecipeError
+/// [`CodegenError`]: crate::codegen::CodegenError
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The shape of the provided data does not match the expected shape.
+    ShapeMismatch {
+        /// The expected shape.
+        expected: Vec<usize>,
+        /// The shape which was actually provided.
+        found: Vec<usize>,
+    },
+    /// The provided shape is invalid, because the size of the resulting buffer overflows.
+    InvalidShape {
+        /// The offending shape.
+        shape: Vec<usize>,
+    },
+    /// A generator produced a value outside of the expected interval.
+    OutOfRange {
+        /// The point at which the generator was sampled.
+        point: Vec<f64>,
+        /// The offending value.
+        value: f64,
+        /// The lower bound of the expected interval.
+        min: f64,
+        /// The upper bound of the expected interval.
+        max: f64,
+    },
+    /// The provided point set is empty, but at least one point is required.
+    EmptyPointSet,
+    /// A point of the provided point set has a coordinate which is not finite.
+    NonFinitePoint {
+        /// The index of the offending point within the point set.
+        index: usize,
+        /// The offending point.
+        point: Vec<f64>,
+    },
+    /// A parameter has an invalid value.
+    InvalidParameter {
+        /// The name of the offending parameter.
+        name: &'static str,
+        /// The reason why the value is invalid.
+        reason: String,
+    },
+    /// An I/O operation failed, such as creating, reading, or writing a file.
+    #[cfg(feature = "std")]
+    Io {
+        /// The kind of the underlying I/O error.
+        kind: std::io::ErrorKind,
+        /// The message of the underlying I/O error.
+        message: String,
+    },
+    /// Data could not be encoded into or decoded from a file format, such as a corrupt file.
+    Encode {
+        /// The reason why the data could not be encoded or decoded.
+        reason: String,
+    },
+    /// The requested operation, format, or feature of a format is not supported.
+    Unsupported {
+        /// A description of what is not supported.
+        reason: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ShapeMismatch { expected, found } => {
+                write!(
+                    f,
+                    "shape mismatch: expected {:?}, found {:?}",
+                    expected, found
+                )
+            }
+            Self::InvalidShape { shape } => {
+                write!(f, "invalid shape {:?}: buffer size overflows", shape)
+            }
+            Self::OutOfRange {
+                point,
+                value,
+                min,
+                max,
+            } => {
+                write!(
+                    f,
+                    "value {} at point {:?} out of range [{}, {}]",
+                    value, point, min, max
+                )
+            }
+            Self::EmptyPointSet => write!(f, "point set is empty"),
+            Self::NonFinitePoint { index, point } => {
+                write!(f, "point {:?} at index {} is not finite", point, index)
+            }
+            Self::InvalidParameter { name, reason } => {
+                write!(f, "invalid parameter {}: {}", name, reason)
+            }
+            #[cfg(feature = "std")]
+            Self::Io { message, .. } => write!(f, "I/O error: {}", message),
+            Self::Encode { reason } => write!(f, "encoding error: {}", reason),
+            Self::Unsupported { reason } => write!(f, "unsupported: {}", reason),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io {
+            kind: err.kind(),
+            message: err.to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<image::ImageError> for Error {
+    fn from(err: image::ImageError) -> Self {
+        use image::ImageError;
+        match err {
+            ImageError::IoError(err) => err.into(),
+            ImageError::Decoding(_) | ImageError::Encoding(_) => Self::Encode {
+                reason: err.to_string(),
+            },
+            ImageError::Parameter(_) => Self::InvalidParameter {
+                name: "image",
+                reason: err.to_string(),
+            },
+            ImageError::Limits(_) | ImageError::Unsupported(_) => Self::Unsupported {
+                reason: err.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "exr")]
+impl From<exr::error::Error> for Error {
+    fn from(err: exr::error::Error) -> Self {
+        use exr::error::Error as ExrError;
+        match err {
+            ExrError::Io(err) => err.into(),
+            // writing is never aborted, as no progress callback is passed to the encoder
+            ExrError::Invalid(_) | ExrError::Aborted => Self::Encode {
+                reason: err.to_string(),
+            },
+            ExrError::NotSupported(_) => Self::Unsupported {
+                reason: err.to_string(),
+            },
+        }
+    }
+}
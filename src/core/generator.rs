@@ -1,5 +1,26 @@
 use crate::core::adapters;
-use std::marker::Sized;
+#[cfg(feature = "codegen")]
+use crate::core::codegen::{self, CodegenError, GlslOptions};
+use crate::core::error::Error;
+use crate::core::recipe::{NoiseRecipe, RecipeError};
+use crate::core::utils::density::DensityVolume;
+use crate::core::utils::describe::{self, Description};
+use crate::core::utils::distribution;
+use crate::core::utils::fingerprint::{self, Comparison, FingerprintDiff};
+use crate::core::utils::griditer::GridIter;
+use crate::core::utils::interval;
+use crate::core::utils::noisebuf::NoiseBuffer;
+use crate::core::utils::param::Parameter;
+use crate::core::utils::path;
+use crate::core::utils::sphere;
+use crate::core::utils::stats::{self, Analysis};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::Sized;
+use rand::Rng;
 
 /// A trait for building a coherent noise generation pipeline.
 ///
@@ -13,6 +34,9 @@ use std::marker::Sized;
 /// The constant generic `D` represents the dimensionality of the input space, and can typically be
 /// inferred without explicitly specifiying it when working with generators.
 ///
+/// This trait is not object safe. To store generators of different types together, box them
+/// as [`SampleGenerator<D>`] trait objects, which implement this trait in turn.
+///
 /// # Creating generators
 ///
 /// A source implements [`Generator<D>`] and requires no further generators as argument to be created,
@@ -54,6 +78,29 @@ use std::marker::Sized;
 /// // sample the generator at [0.2, 0.5, 0.3, 0.7]
 /// let value = generator.sample([0.2, 0.5, 0.3, 0.7]);
 /// ```
+///
+/// # Non-finite inputs
+///
+/// Noise sources produce NaN if any component of the input point is NaN or infinite. This is
+/// also the case for the checkerboard source, which would otherwise produce a plausible-looking
+/// value. Sources which do not depend on the input, such as [`constant()`], and custom sources
+/// are exempt. Adapters propagate NaN produced by the generators they wrap in the same way as
+/// the respective `f64` operations, that is, adapters such as [`clamp()`], [`min()`], [`max()`],
+/// or [`select()`] do not hide NaN. Finite inputs of any magnitude never cause a panic.
+///
+/// ```
+/// # use libnoise::{Source, Generator};
+/// let generator = Source::worley(42).clamp(-0.5, 0.5);
+///
+/// assert!(generator.sample([f64::NAN, 0.5]).is_nan());
+/// assert!(generator.sample([f64::INFINITY, 0.5]).is_nan());
+/// ```
+///
+/// [`constant()`]: crate::Source::constant
+/// [`clamp()`]: Generator::clamp
+/// [`min()`]: Generator::min
+/// [`max()`]: Generator::max
+/// [`select()`]: Generator::select
 pub trait Generator<const D: usize>: Sized {
     /// Samples the generator at a given `point` and returns the resulting value.
     ///
@@ -77,11 +124,298 @@ pub trait Generator<const D: usize>: Sized {
     /// ```
     fn sample(&self, point: [f64; D]) -> f64;
 
+    /// Samples the generator at a given `point` of the integer lattice and returns the resulting
+    /// value.
+    ///
+    /// The result always equals that of [`sample()`] at the point converted to `f64`, including
+    /// the rounding of coordinates beyond `2^53` by the conversion. The default implementation
+    /// performs exactly this conversion. Sources for which sampling at integer coordinates skips
+    /// part of the work override it, such as value noise, which reduces to a single lookup of
+    /// the lattice hash without interpolation, and white noise. Adapters use the default, as
+    /// they generally do not preserve integer coordinates, such that the fast path applies to
+    /// these sources when sampled directly, or through a boxed generator, recipe, or pipeline.
+    /// This is useful for voxel and tile based worlds, which sample whole grids at integer
+    /// coordinates, see [`NoiseBuffer::fill_lattice()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::value(42);
+    ///
+    /// assert_eq!(generator.sample_lattice([3, -7]), generator.sample([3.0, -7.0]));
+    /// ```
+    ///
+    /// [`sample()`]: Generator::sample
+    /// [`NoiseBuffer::fill_lattice()`]: crate::NoiseBuffer::fill_lattice
+    #[inline]
+    fn sample_lattice(&self, point: [i64; D]) -> f64 {
+        self.sample(point.map(|x| x as f64))
+    }
+
+    /// Computes an interval guaranteed to contain the results of the generator for every point
+    /// within the axis-aligned box spanning from `min` to `max`.
+    ///
+    /// The returned interval `(lower, upper)` may be larger than the actual range of results, but
+    /// never excludes a value that the generator can produce for a point within the box. This
+    /// allows skipping regions which are known not to contain certain values without sampling
+    /// them, for example culling chunks of a voxel density field which never crosses zero. NaN
+    /// results, as produced for non-finite inputs, are not taken into account.
+    ///
+    /// Sources bound their results by their maximum amplitude, and adapters propagate the bounds
+    /// of the generators they wrap using interval arithmetic. Generators whose results cannot be
+    /// bounded, such as closures passed to [`lambda()`] or [`custom()`], return the unbounded
+    /// interval `(f64::NEG_INFINITY, f64::INFINITY)`. This is also the default for custom
+    /// implementations of this trait.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::simplex(42)     // build a generator
+    ///     .fbm(3, 0.013, 2.0, 0.5)
+    ///     .add(1.5);
+    ///
+    /// // the results never cross zero, so the region can be culled
+    /// let (lower, upper) = generator.sample_interval([0.0, 0.0], [16.0, 16.0]);
+    /// assert!(lower > 0.0);
+    /// assert!(lower <= generator.sample([3.0, 7.0]) && generator.sample([3.0, 7.0]) <= upper);
+    /// ```
+    ///
+    /// [`lambda()`]: Generator::lambda
+    /// [`custom()`]: crate::Source::custom
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        let _ = (min, max);
+        interval::UNBOUNDED
+    }
+
+    /// Computes an interval guaranteed to contain every result of the generator, independent of
+    /// the input point.
+    ///
+    /// This is equivalent to [`sample_interval()`] for a box spanning the entire input space,
+    /// such that the bounds of sources are propagated through the chain of adapters: a source
+    /// such as simplex noise is bounded by its amplitude, [`add()`] shifts the bounds, [`fbm()`]
+    /// sums the bounds of its octaves, [`clamp()`] restricts them, and so forth. Generators whose
+    /// results cannot be bounded return the unbounded interval
+    /// `(f64::NEG_INFINITY, f64::INFINITY)`.
+    ///
+    /// The bounds are useful for choosing the value range of a visualization or a storage format
+    /// for the results.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::<2>::worley(42).abs().add(0.5);
+    ///
+    /// assert_eq!(generator.output_bounds(), (0.5, 1.5));
+    ///
+    /// let generator = Source::<2>::simplex(42).lambda(|x| x * 2.0);
+    ///
+    /// assert_eq!(generator.output_bounds(), (f64::NEG_INFINITY, f64::INFINITY));
+    /// ```
+    ///
+    /// [`sample_interval()`]: Generator::sample_interval
+    /// [`add()`]: Generator::add
+    /// [`fbm()`]: Generator::fbm
+    /// [`clamp()`]: Generator::clamp
+    #[inline]
+    fn output_bounds(&self) -> (f64, f64) {
+        self.sample_interval([f64::NEG_INFINITY; D], [f64::INFINITY; D])
+    }
+
+    /// Describes the structure of the generator as a tree of its sources and adapters.
+    ///
+    /// Every source and adapter of this library describes itself by its name and parameters,
+    /// along with the descriptions of the generators it wraps, which includes the displacement
+    /// generator of [`displace_x()`] and the other and control generators of [`blend()`] and
+    /// [`select()`]. The [`Display`] implementations of all sources and adapters print this
+    /// description. Closures are described by a placeholder, and custom implementations of this
+    /// trait are described by their type name, unless they override this method.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::<2>::simplex(42)
+    ///     .fbm(6, 1.0, 2.0, 0.5)
+    ///     .blend(Source::constant(0.0), Source::worley(43).scale([0.5, 0.5]))
+    ///     .clamp(-1.0, 1.0);
+    ///
+    /// assert_eq!(
+    ///     generator.to_string(),
+    ///     "\
+    /// Clamp(min=-1, max=1)
+    /// └─ Blend
+    ///    ├─ a: Fbm(octaves=6, frequency=1, lacunarity=2, persistence=0.5)
+    ///    │  └─ Simplex(seed=42)
+    ///    ├─ b: Constant(value=0)
+    ///    └─ control: Scale(scale=[0.5, 0.5])
+    ///       └─ Worley(seed=43)"
+    /// );
+    ///
+    /// let description = generator.describe();
+    /// assert_eq!(description.node_count(), 7);
+    /// assert_eq!(description.param("min"), Some("-1"));
+    /// ```
+    ///
+    /// [`displace_x()`]: Generator2D::displace_x
+    /// [`blend()`]: Generator::blend
+    /// [`select()`]: Generator::select
+    /// [`Display`]: std::fmt::Display
+    fn describe(&self) -> Description {
+        Description::new(describe::type_name::<Self>())
+    }
+
+    /// Converts the generator into an equivalent [`NoiseRecipe`].
+    ///
+    /// This is the inverse of [`NoiseRecipe::build()`]: building the returned recipe for the
+    /// same dimensionality yields a generator producing identical results. This allows saving
+    /// chains composed in code, and translating them into other representations, such as shader
+    /// code. Tweakable parameters are captured with their current values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RecipeError::Unrepresentable`] naming the first offending node if the chain
+    /// contains a generator without an equivalent recipe. This is the case for closures, as
+    /// used by [`lambda()`] and [`custom()`], and custom implementations of this trait, unless
+    /// they override this method.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{recipe::RecipeError, Source, Generator};
+    /// let generator = Source::<2>::simplex(42).fbm(6, 0.013, 2.0, 0.5).clamp(-1.0, 1.0);
+    /// let rebuilt = generator.to_recipe().unwrap().build::<2>().unwrap();
+    ///
+    /// assert_eq!(rebuilt.sample([0.2, 0.5]), generator.sample([0.2, 0.5]));
+    ///
+    /// let generator = Source::<2>::simplex(42).lambda(f64::sin).neg();
+    /// assert_eq!(
+    ///     generator.to_recipe(),
+    ///     Err(RecipeError::Unrepresentable { path: "source".to_string(), node: "Lambda" }),
+    /// );
+    /// ```
+    ///
+    /// [`NoiseRecipe::build()`]: crate::recipe::NoiseRecipe::build
+    /// [`RecipeError::Unrepresentable`]: crate::recipe::RecipeError::Unrepresentable
+    /// [`lambda()`]: Generator::lambda
+    /// [`custom()`]: crate::Source::custom
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Err(RecipeError::Unrepresentable {
+            path: String::new(),
+            node: describe::type_name::<Self>(),
+        })
+    }
+
+    /// Generates a self-contained WGSL function evaluating the generator on the GPU.
+    ///
+    /// The function is named `fn_name`, takes the input point as `vec2<f32>` or `vec3<f32>`, and
+    /// returns an `f32`. The generated code includes the permutation tables and gradients used
+    /// by the sources, seeded the same way as on the CPU, such that the shader reproduces the
+    /// results of [`sample()`] within the precision of [`f32`]. All other items are prefixed by
+    /// `fn_name`. For the supported sources and adapters and the expected precision, see the
+    /// [`codegen`] module.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dimensionality is not 2 or 3, `fn_name` is not a valid
+    /// identifier, or the chain contains a node which cannot be translated, such as a closure.
+    /// The error names the offending node.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{codegen::CodegenError, Source, Generator};
+    /// let generator = Source::<2>::simplex(42).fbm(6, 0.013, 2.0, 0.5).clamp(-1.0, 1.0);
+    /// let wgsl = generator.to_wgsl("terrain").unwrap();
+    ///
+    /// assert!(wgsl.contains("fn terrain(p: vec2<f32>) -> f32 {"));
+    ///
+    /// let generator = Source::<2>::simplex(42).lambda(f64::sin);
+    /// assert_eq!(
+    ///     generator.to_wgsl("terrain"),
+    ///     Err(CodegenError::Unsupported { path: String::new(), node: "Lambda" }),
+    /// );
+    /// ```
+    ///
+    /// [`sample()`]: Generator::sample
+    /// [`codegen`]: crate::codegen
+    #[cfg(feature = "codegen")]
+    fn to_wgsl(&self, fn_name: &str) -> Result<String, CodegenError> {
+        codegen::validate_dimension(D)?;
+        codegen::validate_name(fn_name, codegen::wgsl::is_reserved)?;
+        let program = codegen::Program::lower(&self.to_recipe()?, D)?;
+        Ok(codegen::wgsl::generate(&program, fn_name))
+    }
+
+    /// Generates a self-contained GLSL function evaluating the generator on the GPU.
+    ///
+    /// The function is named `fn_name`, takes the input point as `vec2` or `vec3`, and returns a
+    /// `float`. Apart from the shading language, the generated code behaves like the one of
+    /// [`to_wgsl()`], and all other items are prefixed by `fn_name` as well. The `options`
+    /// select the GLSL version and whether the permutation tables are embedded as a constant
+    /// array or read from a texture, as described in [`GlslOptions`]. A plain [`GlslVersion`]
+    /// embeds the tables.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`to_wgsl()`], except that `fn_name` is validated against the
+    /// reserved words of GLSL instead.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{codegen::{CodegenError, GlslVersion}, Source, Generator};
+    /// let generator = Source::<3>::simplex(42).fbm(6, 0.013, 2.0, 0.5).clamp(-1.0, 1.0);
+    /// let glsl = generator.to_glsl(GlslVersion::Glsl330, "terrain").unwrap();
+    ///
+    /// assert!(glsl.contains("float terrain(vec3 p) {"));
+    ///
+    /// let generator = Source::<3>::simplex(42).lambda(f64::sin);
+    /// assert_eq!(
+    ///     generator.to_glsl(GlslVersion::Glsl330, "terrain"),
+    ///     Err(CodegenError::Unsupported { path: String::new(), node: "Lambda" }),
+    /// );
+    /// ```
+    ///
+    /// [`to_wgsl()`]: Generator::to_wgsl
+    /// [`GlslOptions`]: crate::codegen::GlslOptions
+    /// [`GlslVersion`]: crate::codegen::GlslVersion
+    #[cfg(feature = "codegen")]
+    fn to_glsl(
+        &self,
+        options: impl Into<GlslOptions>,
+        fn_name: &str,
+    ) -> Result<String, CodegenError> {
+        codegen::validate_dimension(D)?;
+        codegen::validate_name(fn_name, codegen::glsl::is_reserved)?;
+        let program = codegen::Program::lower(&self.to_recipe()?, D)?;
+        Ok(codegen::glsl::generate(&program, &options.into(), fn_name))
+    }
+
     /// Create a generator which scales input points before passing them to the underlying generator.
     ///
     /// Takes a scale factor for each dimension of the input space and crates a generator which scales
     /// each input point accordingly before passing it to the underlying generator.
     ///
+    /// The scale factors accept any [`Parameter`].
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -98,8 +432,10 @@ pub trait Generator<const D: usize>: Sized {
     /// // [0.4, 0.1] is equivalent to [0.2 * 2.0, 0.5 * 0.2]
     /// assert_eq!(value, Source::simplex(42).sample([0.4, 0.1]))
     /// ```
+    ///
+    /// [`Parameter`]: crate::Parameter
     #[inline]
-    fn scale(self, scale: [f64; D]) -> adapters::Scale<D, Self> {
+    fn scale<P: Parameter>(self, scale: [P; D]) -> adapters::Scale<D, Self, P> {
         adapters::Scale::new(self, scale)
     }
 
@@ -108,6 +444,8 @@ pub trait Generator<const D: usize>: Sized {
     /// Takes a translation offset for each dimension of the input space and crates a generator which
     /// translates each input point accordingly before passing it to the underlying generator.
     ///
+    /// The translation accepts any [`Parameter`].
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -124,8 +462,10 @@ pub trait Generator<const D: usize>: Sized {
     /// // [0.5, 1.5] is equivalent to [0.2 + 0.3, 0.5 + 1.0]
     /// assert_eq!(value, Source::simplex(42).sample([0.5, 1.5]))
     /// ```
+    ///
+    /// [`Parameter`]: crate::Parameter
     #[inline]
-    fn translate(self, translation: [f64; D]) -> adapters::Translate<D, Self> {
+    fn translate<P: Parameter>(self, translation: [P; D]) -> adapters::Translate<D, Self, P> {
         adapters::Translate::new(self, translation)
     }
 
@@ -316,6 +656,8 @@ pub trait Generator<const D: usize>: Sized {
     /// greater than `max` and `min` is generated if the result is less than `min`. If the result was
     /// NaN, it will remain NaN after clamping.
     ///
+    /// The bounds accept any [`Parameter`].
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -331,11 +673,97 @@ pub trait Generator<const D: usize>: Sized {
     ///
     /// assert_eq!(value, Source::simplex(42).sample(point).clamp(-0.5, 0.5))
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Sampling the resulting generator panics if `min` is greater than `max`, or if either is
+    /// NaN. See [`try_clamp()`] for an alternative validating constant bounds upfront.
+    ///
+    /// [`Parameter`]: crate::Parameter
+    /// [`try_clamp()`]: Generator::try_clamp
     #[inline]
-    fn clamp(self, min: f64, max: f64) -> adapters::Clamp<D, Self> {
+    fn clamp<P: Parameter>(self, min: P, max: P) -> adapters::Clamp<D, Self, P> {
         adapters::Clamp::new(self, min, max)
     }
 
+    /// Create a generator clamping results of the underlying generator to a given interval, like
+    /// [`clamp()`], after validating the bounds of the interval.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if `min` is NaN, or if `max` is NaN or less than
+    /// `min`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, Error};
+    /// let generator = Source::<2>::simplex(42).try_clamp(-0.5, 0.5).unwrap();
+    ///
+    /// let error = Source::<2>::simplex(42).try_clamp(0.5, -0.5).unwrap_err();
+    /// assert!(matches!(error, Error::InvalidParameter { name: "max", .. }));
+    /// ```
+    ///
+    /// [`clamp()`]: Generator::clamp
+    #[inline]
+    fn try_clamp(self, min: f64, max: f64) -> Result<adapters::Clamp<D, Self>, Error> {
+        if min.is_nan() {
+            return Err(Error::InvalidParameter {
+                name: "min",
+                reason: format!("must not be NaN, got {}", min),
+            });
+        }
+        if max.is_nan() || max < min {
+            return Err(Error::InvalidParameter {
+                name: "max",
+                reason: format!("must not be less than min {}, got {}", min, max),
+            });
+        }
+        Ok(self.clamp(min, max))
+    }
+
+    /// Create a generator asserting that results of the underlying generator lie within a given
+    /// interval.
+    ///
+    /// Creates a generator which is exactly the same as the underlying generator, except that it
+    /// panics with the offending point and value if the result lies outside the interval [`min`,
+    /// `max`]. NaN is considered to lie outside of every interval. This is useful to catch long
+    /// chains of adapters leaving the nominal range of [-1, 1] early. A non-panicking alternative
+    /// is provided by [`Checked::try_sample()`].
+    ///
+    /// Like a debug assertion, the check is only performed in debug builds, or if the
+    /// `strict-range` feature is enabled. Otherwise, sampling is forwarded to the underlying
+    /// generator, such that the adapter compiles to nothing. [`Checked::try_sample()`] always
+    /// performs the check.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::simplex(42)     // build a generator
+    ///     .checked(-1.0, 1.0);                // apply the adapter
+    ///
+    /// let value = generator.sample(point);    // sample the generator
+    ///
+    /// assert_eq!(value, Source::simplex(42).sample(point));
+    ///
+    /// // an out-of-range result is reported as an error
+    /// let generator = Source::simplex(42).add(2.5).checked(-1.0, 1.0);
+    /// assert!(generator.try_sample(point).is_err());
+    /// ```
+    ///
+    /// [`Checked::try_sample()`]: adapters::Checked::try_sample
+    #[inline]
+    fn checked(self, min: f64, max: f64) -> adapters::Checked<D, Self> {
+        adapters::Checked::new(self, min, max)
+    }
+
     /// Create a generator applying the supplied closure to results of the underlying generator.
     ///
     /// Creates a generator which is exactly the same as the underlying generator, except the result
@@ -365,6 +793,43 @@ pub trait Generator<const D: usize>: Sized {
         adapters::Lambda::new(self, lambda)
     }
 
+    /// Create a generator applying the supplied closure to results of the underlying generator.
+    ///
+    /// Creates a generator which is exactly the same as the underlying generator, except the result
+    /// of the generator modified by the closure provided. Unlike [`lambda()`], the closure need not
+    /// be [`Copy`], such that it may capture owned data, and the adapter is [`Clone`] whenever the
+    /// closure is.
+    ///
+    /// The closure is responsible for the range of the results. As it cannot be inspected, the
+    /// bounds computed by [`sample_interval()`] and [`output_bounds()`] are unbounded.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::simplex(42)     // build a generator
+    ///     .map(f64::tanh);                    // apply the adapter
+    ///
+    /// let value = generator.sample(point);    // sample the generator
+    ///
+    /// assert_eq!(value, Source::simplex(42).sample(point).tanh())
+    /// ```
+    ///
+    /// [`lambda()`]: Generator::lambda
+    /// [`sample_interval()`]: Generator::sample_interval
+    /// [`output_bounds()`]: Generator::output_bounds
+    #[inline]
+    fn map<F>(self, f: F) -> adapters::Map<D, Self, F>
+    where
+        F: Fn(f64) -> f64 + Send + Sync,
+    {
+        adapters::Map::new(self, f)
+    }
+
     /// Create a generator adding results of the underlying generator to results of a given other
     /// generator.
     ///
@@ -427,7 +892,8 @@ pub trait Generator<const D: usize>: Sized {
     /// a given other generator.
     ///
     /// Creates a generator which is producing either the result of the underlying generator, or the
-    /// result of given the generator, whichever is less.
+    /// result of given the generator, whichever is less. Unlike [`f64::min()`], NaN is produced if
+    /// either result is NaN.
     ///
     /// # Examples
     ///
@@ -456,7 +922,8 @@ pub trait Generator<const D: usize>: Sized {
     /// a given other generator.
     ///
     /// Creates a generator which is producing either the result of the underlying generator, or the
-    /// result of given the generator, whichever is greater.
+    /// result of given the generator, whichever is greater. Unlike [`f64::max()`], NaN is produced
+    /// if either result is NaN.
     ///
     /// # Examples
     ///
@@ -511,6 +978,97 @@ pub trait Generator<const D: usize>: Sized {
         adapters::Power::new(self, other)
     }
 
+    /// Create a generator combining results of the underlying generator and a given other
+    /// generator using the supplied closure.
+    ///
+    /// Creates a generator which samples the underlying generator and the given generator for the
+    /// same input point, and produces the result of the closure applied to both results, in that
+    /// order. This allows prototyping combinations of generators for which no dedicated adapter
+    /// exists, such as smooth minimum functions or custom masking.
+    ///
+    /// The closure is responsible for the range of the results. As it cannot be inspected, the
+    /// bounds computed by [`sample_interval()`] and [`output_bounds()`] are unbounded.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    /// let closure = |x: f64, y: f64| (x * y).abs().sqrt().copysign(x);
+    ///
+    /// let generator = Source::simplex(42)                 // build a generator
+    ///     .zip_with(Source::simplex(43), closure);        // apply the adapter
+    ///
+    /// let value = generator.sample(point);                // sample the generator
+    ///
+    /// let a = Source::simplex(42).sample(point);
+    /// let b = Source::simplex(43).sample(point);
+    /// assert_eq!(value, closure(a, b))
+    /// ```
+    ///
+    /// [`sample_interval()`]: Generator::sample_interval
+    /// [`output_bounds()`]: Generator::output_bounds
+    #[inline]
+    fn zip_with<G, F>(self, other: G, f: F) -> adapters::ZipWith<D, Self, G, F>
+    where
+        G: Generator<D>,
+        F: Fn(f64, f64) -> f64 + Send + Sync,
+    {
+        adapters::ZipWith::new(self, other, f)
+    }
+
+    /// Create a generator combining results of the underlying generator and two given other
+    /// generators using the supplied closure.
+    ///
+    /// This is the same as [`zip_with()`], except for three generators, which allows prototyping
+    /// operations taking a control input in the manner of [`blend()`] or [`select()`]. The closure
+    /// receives the results of the underlying generator, `other`, and `control`, in that order.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// // blend using a sharper transition than the blend adapter
+    /// let generator = Source::simplex(42).zip3_with(
+    ///     Source::worley(43),
+    ///     Source::simplex(44),
+    ///     |a, b, t: f64| if t < 0.0 { a } else { b },
+    /// );
+    ///
+    /// let value = generator.sample(point);
+    ///
+    /// let expected = if Source::simplex(44).sample(point) < 0.0 {
+    ///     Source::simplex(42).sample(point)
+    /// } else {
+    ///     Source::worley(43).sample(point)
+    /// };
+    /// assert_eq!(value, expected);
+    /// ```
+    ///
+    /// [`zip_with()`]: Generator::zip_with
+    /// [`blend()`]: Generator::blend
+    /// [`select()`]: Generator::select
+    #[inline]
+    fn zip3_with<G, GC, F>(
+        self,
+        other: G,
+        control: GC,
+        f: F,
+    ) -> adapters::Zip3With<D, Self, G, GC, F>
+    where
+        G: Generator<D>,
+        GC: Generator<D>,
+        F: Fn(f64, f64, f64) -> f64 + Send + Sync,
+    {
+        adapters::Zip3With::new(self, other, control, f)
+    }
+
     /// Create a generator applying fractal brownian motion on the underlying generator.
     ///
     /// Within the context of coherent noise, fractal brownian motion is a common technique for making
@@ -526,6 +1084,11 @@ pub trait Generator<const D: usize>: Sized {
     /// of the frequency and amplitude, as octaves are increase. The result is the normalized sum of
     /// samples from each octave.
     ///
+    /// The sum is normalized by dividing it by the sum of the amplitudes of all octaves. Thus, if the
+    /// underlying generator produces values within [-1, 1], so does the result, regardless of the
+    /// number of octaves and the persistence. For example, with a persistence of 1, the result is
+    /// the mean of the octaves.
+    ///
     /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
     /// <strong>Note:</strong>
     /// The initial amplitude is not a parameter because the result of the generator is normalized.
@@ -537,6 +1100,15 @@ pub trait Generator<const D: usize>: Sized {
     /// lies somewhere between 0 and 1.
     /// </p>
     ///
+    /// The frequency, lacunarity, and persistence accept any [`Parameter`].
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `octaves` is 0, if `frequency` or `lacunarity` is not positive
+    /// and finite, or if `persistence` is not within [0, 1e9] or so large that the octave
+    /// amplitudes overflow. Release builds skip these checks. See [`Fbm::try_new()`] for a
+    /// constructor reporting invalid parameters as an error instead.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -571,15 +1143,70 @@ pub trait Generator<const D: usize>: Sized {
     ///
     /// assert!(value - expected < f64::EPSILON);
     /// ```
+    ///
+    /// [`Parameter`]: crate::Parameter
+    /// [`Fbm::try_new()`]: adapters::Fbm::try_new
+    #[inline]
+    #[track_caller]
+    fn fbm<P: Parameter>(
+        self,
+        octaves: u32,
+        frequency: P,
+        lacunarity: P,
+        persistence: P,
+    ) -> adapters::Fbm<D, Self, P> {
+        adapters::Fbm::new(self, octaves, frequency, lacunarity, persistence)
+    }
+
+    /// Create a generator applying fractal brownian motion on the underlying generator, which
+    /// supports sampling with a reduced level of detail.
+    ///
+    /// The created generator produces the same results as [`fbm()`] when sampled using
+    /// [`sample()`]. Additionally, [`sample_lod()`] allows fading out the highest-frequency
+    /// octaves, for example for terrain far away from the camera, where fine details are not
+    /// visible. To derive the level of detail from the distance to a focus point, use
+    /// [`distance_lod()`].
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics under the same conditions as [`fbm()`]. Release builds skip these
+    /// checks. See [`FbmLod::try_new()`] for a constructor reporting invalid parameters as an
+    /// error instead.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::simplex(42).fbm_lod(6, 1.0, 2.0, 0.5);
+    ///
+    /// // full detail
+    /// let value = generator.sample(point);
+    /// // three octaves faded out
+    /// let value_lod = generator.sample_lod(point, 3.0);
+    ///
+    /// assert_eq!(value, Source::simplex(42).fbm(6, 1.0, 2.0, 0.5).sample(point));
+    /// assert_eq!(value_lod, Source::simplex(42).fbm(3, 1.0, 2.0, 0.5).sample(point));
+    /// ```
+    ///
+    /// [`fbm()`]: Generator::fbm
+    /// [`sample()`]: Generator::sample
+    /// [`sample_lod()`]: adapters::FbmLod::sample_lod
+    /// [`distance_lod()`]: adapters::FbmLod::distance_lod
+    /// [`FbmLod::try_new()`]: adapters::FbmLod::try_new
     #[inline]
-    fn fbm(
+    #[track_caller]
+    fn fbm_lod(
         self,
         octaves: u32,
         frequency: f64,
         lacunarity: f64,
         persistence: f64,
-    ) -> adapters::Fbm<D, Self> {
-        adapters::Fbm::new(self, octaves, frequency, lacunarity, persistence)
+    ) -> adapters::FbmLod<D, Self> {
+        adapters::FbmLod::new(self, octaves, frequency, lacunarity, persistence)
     }
 
     /// Create a generator applying an [`fbm()`]-like effect on the underlying generator.
@@ -607,7 +1234,13 @@ pub trait Generator<const D: usize>: Sized {
     /// somewhere between 0 and 1.
     /// </p>
     ///
-    /// [`fbm()`]: Generator::fbm
+    /// The frequency, lacunarity, and persistence accept any [`Parameter`].
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics under the same conditions as [`fbm()`]. Release builds skip these
+    /// checks. See [`Billow::try_new()`] for a constructor reporting invalid parameters as an
+    /// error instead.
     ///
     /// # Examples
     ///
@@ -643,14 +1276,19 @@ pub trait Generator<const D: usize>: Sized {
     ///
     /// assert!(value - expected < f64::EPSILON);
     /// ```
+    ///
+    /// [`fbm()`]: Generator::fbm
+    /// [`Parameter`]: crate::Parameter
+    /// [`Billow::try_new()`]: adapters::Billow::try_new
     #[inline]
-    fn billow(
+    #[track_caller]
+    fn billow<P: Parameter>(
         self,
         octaves: u32,
-        frequency: f64,
-        lacunarity: f64,
-        persistence: f64,
-    ) -> adapters::Billow<D, Self> {
+        frequency: P,
+        lacunarity: P,
+        persistence: P,
+    ) -> adapters::Billow<D, Self, P> {
         adapters::Billow::new(self, octaves, frequency, lacunarity, persistence)
     }
 
@@ -683,7 +1321,14 @@ pub trait Generator<const D: usize>: Sized {
     /// Typically, desireable values for `lacunarity` and `attenuation` are 2.
     /// </p>
     ///
-    /// [`fbm()`]: Generator::fbm
+    /// The frequency, lacunarity, and attenuation accept any [`Parameter`].
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `octaves` is 0, if `frequency` or `lacunarity` is not positive
+    /// and finite, or if `attenuation` is not positive and finite or so small that the octave
+    /// weights overflow. Release builds skip these checks. See [`RidgedMulti::try_new()`] for a
+    /// constructor reporting invalid parameters as an error instead.
     ///
     /// # Examples
     ///
@@ -722,14 +1367,19 @@ pub trait Generator<const D: usize>: Sized {
     ///
     /// assert!(value - expected < f64::EPSILON);
     /// ```
+    ///
+    /// [`fbm()`]: Generator::fbm
+    /// [`Parameter`]: crate::Parameter
+    /// [`RidgedMulti::try_new()`]: adapters::RidgedMulti::try_new
     #[inline]
-    fn ridgedmulti(
+    #[track_caller]
+    fn ridgedmulti<P: Parameter>(
         self,
         octaves: u32,
-        frequency: f64,
-        lacunarity: f64,
-        attenuation: f64,
-    ) -> adapters::RidgedMulti<D, Self> {
+        frequency: P,
+        lacunarity: P,
+        attenuation: P,
+    ) -> adapters::RidgedMulti<D, Self, P> {
         adapters::RidgedMulti::new(self, octaves, frequency, lacunarity, attenuation)
     }
 
@@ -788,7 +1438,9 @@ pub trait Generator<const D: usize>: Sized {
     /// This adapter takes two generators, `other` and `control`, as well as an interval defined by
     /// `selection_min` and `selection_max`, as parameters. If the value produced by generator `control`
     /// lies within the provided interval, produce the result of the underlying generator. Otherwise,
-    /// produce the result of `other`.
+    /// produce the result of `other`. If the value produced by `control` is NaN, NaN is produced.
+    ///
+    /// The selection bounds accept any [`Parameter`].
     ///
     /// # Examples
     ///
@@ -813,20 +1465,635 @@ pub trait Generator<const D: usize>: Sized {
     ///
     /// assert!(value - expected < f64::EPSILON);
     /// ```
+    ///
+    /// [`Parameter`]: crate::Parameter
     #[inline]
-    fn select<G, GC>(
+    fn select<G, GC, P>(
         self,
         other: G,
         control: GC,
-        selection_min: f64,
-        selection_max: f64,
-    ) -> adapters::Select<D, Self, G, GC>
+        selection_min: P,
+        selection_max: P,
+    ) -> adapters::Select<D, Self, G, GC, P>
     where
         G: Generator<D>,
         GC: Generator<D>,
+        P: Parameter,
     {
         adapters::Select::new(self, other, control, selection_min, selection_max)
     }
+
+    /// Create a generator providing the results of the underlying generator after displacing the
+    /// input point by the result of the provided vector generator.
+    ///
+    /// Creates a generator which is exactly the same as the underlying generator, except that
+    /// every coordinate of the input point is first displaced by the respective component of the
+    /// result of `offsets` for that point. This is the vector-valued counterpart of
+    /// [`displace_x()`] and its equivalents for other axes, displacing all axes at once, which is
+    /// commonly known as domain warping. Since a single vector generator provides the offsets,
+    /// generators like [`VectorSimplex`] share the hashing work among the axes.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, VectorGenerator};
+    /// let mut point = [0.2, 0.5];
+    ///
+    /// let generator = Source::simplex(42)         // build a generator
+    ///     .warp(Source::vector_simplex(43));      // apply the adapter
+    ///
+    /// let value = generator.sample(point);        // sample the generator
+    ///
+    /// let offsets: [f64; 2] = Source::vector_simplex(43).sample(point);
+    /// point[0] += offsets[0];
+    /// point[1] += offsets[1];
+    /// assert_eq!(value, Source::simplex(42).sample(point))
+    /// ```
+    ///
+    /// [`displace_x()`]: Generator2D::displace_x
+    /// [`VectorSimplex`]: crate::VectorSimplex
+    #[inline]
+    fn warp<V>(self, offsets: V) -> adapters::Warp<D, Self, V>
+    where
+        V: VectorGenerator<D, D>,
+    {
+        adapters::Warp::new(self, offsets)
+    }
+
+    /// Computes a digest of the results of the generator on a regular grid.
+    ///
+    /// Samples the generator on a grid spanning the region from `region_min` to `region_max` with
+    /// `samples_per_axis` samples along each axis, and hashes the bit patterns of the results.
+    /// Comparing fingerprints is a cheap way to detect that the output of a generator changed,
+    /// for example after upgrading this crate. For finding out where two generators differ, see
+    /// [`fingerprint_diff()`].
+    ///
+    /// # Grid layout and hash
+    ///
+    /// The layout of the grid and the hash are part of the stable API and will not change across
+    /// versions of this crate. Consequently, a fingerprint only changes if the generator output
+    /// does.
+    ///
+    /// The `k`-th sample along axis `i` is located at
+    /// `region_min[i] + (region_max[i] - region_min[i]) * k as f64 / (samples_per_axis - 1) as f64`,
+    /// evaluated in exactly this order, or at `region_min[i]` if `samples_per_axis` is 1. The
+    /// samples are visited in row-major order, meaning the last axis varies fastest. The digest is
+    /// the 64-bit FNV-1a hash of the concatenated little-endian bytes of [`f64::to_bits()`] of all
+    /// results, where every NaN is replaced by [`f64::NAN`] beforehand.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::simplex(42);
+    /// let fingerprint = generator.fingerprint([-10.0; 2], [10.0; 2], 32);
+    ///
+    /// // the fingerprint depends on the generator output...
+    /// assert_eq!(fingerprint, Source::simplex(42).fingerprint([-10.0; 2], [10.0; 2], 32));
+    /// assert_ne!(fingerprint, Source::simplex(43).fingerprint([-10.0; 2], [10.0; 2], 32));
+    ///
+    /// // ...as well as on the grid
+    /// assert_ne!(fingerprint, generator.fingerprint([-10.0; 2], [10.0; 2], 33));
+    /// ```
+    ///
+    /// [`fingerprint_diff()`]: Generator::fingerprint_diff
+    fn fingerprint(
+        &self,
+        region_min: [f64; D],
+        region_max: [f64; D],
+        samples_per_axis: usize,
+    ) -> u64 {
+        fingerprint::fingerprint(self, region_min, region_max, samples_per_axis)
+    }
+
+    /// Compares the results of the generator with those of another generator on a regular grid.
+    ///
+    /// Samples both generators on the same grid as [`fingerprint()`] and reports every sample at
+    /// which the bit patterns of the results differ, with NaNs considered equal. The returned
+    /// [`FingerprintDiff`] pretty-prints the grid index, input point, and both results of each
+    /// mismatching sample when displayed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::simplex(42);
+    /// let modified = Source::simplex(42).clamp(-0.5, 0.5);
+    ///
+    /// let diff = generator.fingerprint_diff(&modified, [-10.0; 2], [10.0; 2], 32);
+    /// assert!(!diff.is_empty());
+    /// println!("{}", diff);
+    /// ```
+    ///
+    /// [`fingerprint()`]: Generator::fingerprint
+    /// [`FingerprintDiff`]: crate::FingerprintDiff
+    fn fingerprint_diff<G>(
+        &self,
+        other: &G,
+        region_min: [f64; D],
+        region_max: [f64; D],
+        samples_per_axis: usize,
+    ) -> FingerprintDiff<D>
+    where
+        G: Generator<D>,
+    {
+        fingerprint::fingerprint_diff(self, other, region_min, region_max, samples_per_axis)
+    }
+
+    /// Quantifies how much the results of the generator differ from those of another generator
+    /// on a regular grid.
+    ///
+    /// Samples both generators on the same grid as [`fingerprint()`] and computes the largest,
+    /// mean, and root mean square difference of the results, along with the point at which the
+    /// difference is largest. Unlike [`fingerprint_diff()`], which reports every sample whose
+    /// results differ in any bit, the returned [`Comparison`] tells apart negligible rounding
+    /// differences from substantial changes. Boxed [`SampleGenerator<D>`] trait objects can be
+    /// compared as well, such as a chain which was serialized and loaded again. For a visual
+    /// comparison, see `Visualizer::<2>::write_difference_to_file()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples_per_axis` is 0.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::simplex(42);
+    /// let modified = Source::simplex(42).clamp(-0.5, 0.5);
+    ///
+    /// let comparison = generator.compare(&modified, [-10.0; 2], [10.0; 2], 32);
+    /// assert!(comparison.max_abs_diff <= 0.5);
+    /// println!("{}", comparison);
+    /// ```
+    ///
+    /// [`fingerprint()`]: Generator::fingerprint
+    /// [`fingerprint_diff()`]: Generator::fingerprint_diff
+    /// [`Comparison`]: crate::Comparison
+    #[track_caller]
+    fn compare<G>(
+        &self,
+        other: &G,
+        region_min: [f64; D],
+        region_max: [f64; D],
+        samples_per_axis: usize,
+    ) -> Comparison<D>
+    where
+        G: Generator<D>,
+    {
+        fingerprint::compare(self, other, region_min, region_max, samples_per_axis)
+    }
+
+    /// Computes the distribution of the results of the generator on a regular grid.
+    ///
+    /// Samples the generator on the same grid as [`fingerprint()`] and computes the same summary
+    /// statistics and histogram with `bins` bins as [`NoiseBuffer::stats()`] and
+    /// [`NoiseBuffer::histogram()`] would for a buffer holding the samples. The samples are
+    /// processed in a streaming fashion without materializing a buffer, so arbitrarily large
+    /// grids can be analyzed in constant memory. The returned [`Analysis`] prints an ASCII
+    /// histogram when displayed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::simplex(42).fbm(5, 0.013, 2.0, 0.5);
+    ///
+    /// let analysis = generator.analyze([-500.0; 2], [500.0; 2], 256, 20);
+    /// assert!(-1.0 <= analysis.stats.min && analysis.stats.max <= 1.0);
+    /// println!("{}", analysis);
+    /// ```
+    ///
+    /// [`fingerprint()`]: Generator::fingerprint
+    /// [`NoiseBuffer::stats()`]: crate::NoiseBuffer::stats
+    /// [`NoiseBuffer::histogram()`]: crate::NoiseBuffer::histogram
+    /// [`Analysis`]: crate::Analysis
+    fn analyze(
+        &self,
+        region_min: [f64; D],
+        region_max: [f64; D],
+        samples_per_axis: usize,
+        bins: usize,
+    ) -> Analysis {
+        stats::analyze(self, region_min, region_max, samples_per_axis, bins)
+    }
+
+    /// Creates an iterator sampling the generator on the points of a hypergrid.
+    ///
+    /// The iterator yields the index, the coordinates, and the result of the generator for each
+    /// point of a hypergrid with the given `shape`, in row-major order. The coordinates are
+    /// `origin + index * step` per dimension, matching [`NoiseBuffer::new_mapped()`]. Unlike a
+    /// [`NoiseBuffer`], no memory is allocated, such that the results can be streamed into
+    /// statistics or a mesher. The iterator implements [`ExactSizeIterator`] and
+    /// [`DoubleEndedIterator`]. For details, see [`GridIter`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of points of the hypergrid overflows.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::simplex(42);
+    /// let mut samples = generator.grid_iter([0.0, 0.0], [0.5, 0.5], [4, 3]);
+    ///
+    /// assert_eq!(samples.len(), 12);
+    /// assert_eq!(samples.next(), Some(([0, 0], [0.0, 0.0], generator.sample([0.0, 0.0]))));
+    /// assert_eq!(samples.next_back(), Some(([3, 2], [1.5, 1.0], generator.sample([1.5, 1.0]))));
+    ///
+    /// let max = samples.map(|(_, _, value)| value).fold(f64::NEG_INFINITY, f64::max);
+    /// ```
+    ///
+    /// [`NoiseBuffer::new_mapped()`]: crate::NoiseBuffer::new_mapped
+    /// [`NoiseBuffer`]: crate::NoiseBuffer
+    /// [`GridIter`]: crate::GridIter
+    fn grid_iter(
+        &self,
+        origin: [f64; D],
+        step: [f64; D],
+        shape: [usize; D],
+    ) -> GridIter<'_, D, Self> {
+        GridIter::new(self, origin, step, shape)
+    }
+
+    /// Samples the generator at evenly spaced points along a line segment.
+    ///
+    /// Returns the results of the generator at `samples` points spaced evenly between `a` and `b`,
+    /// including both endpoints. The `k`-th point is located at `a + (b - a) * k / (samples - 1)`
+    /// per dimension. If `samples` is 1, the generator is sampled at `a` only. This is the simple
+    /// case of [`sample_path()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::simplex(42);
+    /// let values = generator.sample_segment([0.0, 0.0], [2.0, 1.0], 5);
+    ///
+    /// assert_eq!(values.len(), 5);
+    /// assert_eq!(values[2], generator.sample([1.0, 0.5]));
+    /// ```
+    ///
+    /// [`sample_path()`]: Generator::sample_path
+    fn sample_segment(&self, a: [f64; D], b: [f64; D], samples: usize) -> Vec<f64> {
+        path::sample_segment(self, a, b, samples)
+    }
+
+    /// Samples the generator at evenly spaced points along a polyline.
+    ///
+    /// The polyline connects the given `points` in order. The generator is sampled at `samples`
+    /// points spaced evenly with respect to the arc length of the polyline, including its first
+    /// and last point, such that the results can be used to follow rivers or roads, or to
+    /// automate parameters over time. Degenerate paths are handled gracefully: if `samples` is 1,
+    /// the polyline consists of a single point, or all of its points coincide, the generator is
+    /// sampled at the first point. Segments of zero length are skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` is empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::simplex(42);
+    ///
+    /// // an L-shaped path of length 4
+    /// let values = generator.sample_path(&[[0.0, 0.0], [3.0, 0.0], [3.0, 1.0]], 5);
+    ///
+    /// assert_eq!(values.len(), 5);
+    /// assert_eq!(values[3], generator.sample([3.0, 0.0]));
+    /// assert_eq!(values[4], generator.sample([3.0, 1.0]));
+    /// ```
+    fn sample_path(&self, points: &[[f64; D]], samples: usize) -> Vec<f64> {
+        path::sample_path(self, points, samples)
+    }
+
+    /// Samples the generator at `n` points drawn uniformly from a region using the supplied
+    /// random number generator.
+    ///
+    /// The points are drawn from the half-open region spanning from `min` (inclusive) to `max`
+    /// (exclusive), as done by [`NoiseDistribution`], which also details the distribution of the
+    /// results. Notably, the results are generally not uniformly distributed. For a given state
+    /// of `rng`, the results are deterministic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any component of the region bounds is not finite, or if `min` exceeds `max`
+    /// along any axis.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// use rand::SeedableRng;
+    /// use rand_chacha::ChaCha8Rng;
+    ///
+    /// let generator = Source::simplex(42);
+    /// let mut rng = ChaCha8Rng::seed_from_u64(7);
+    /// let values = generator.sample_region_random(&mut rng, [0.0, 0.0], [100.0, 100.0], 50);
+    ///
+    /// assert_eq!(values.len(), 50);
+    /// ```
+    ///
+    /// [`NoiseDistribution`]: crate::NoiseDistribution
+    #[track_caller]
+    fn sample_region_random<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        min: [f64; D],
+        max: [f64; D],
+        n: usize,
+    ) -> Vec<f64> {
+        distribution::assert_region(min, max);
+        (0..n)
+            .map(|_| self.sample(distribution::random_point(rng, min, max)))
+            .collect()
+    }
+}
+
+/// A trait for generators producing vectors rather than single values.
+///
+/// Applications such as flow fields, curl noise, or domain warping require several values for
+/// each point. While these can be obtained by sampling several scalar generators, a generator
+/// implementing this trait may share work among the components of the vector, such as the
+/// traversal of the lattice and the hashing of its vertices done by [`VectorSimplex`].
+///
+/// The constant generic `D` represents the dimensionality of the input space as for
+/// [`Generator<D>`], and `M` the number of components of the resulting vectors. Both can
+/// typically be inferred.
+///
+/// # Creating vector generators
+///
+/// Scalar generators can be bundled using [`Bundle`], such that each of them provides one
+/// component. Conversely, single components can be extracted as scalar generators using
+/// [`component()`], which allows applying the adapters of [`Generator<D>`] to them:
+///
+/// ```
+/// # use libnoise::{Bundle, Source, Generator, VectorGenerator};
+/// let generator = Bundle::new([Source::simplex(42), Source::simplex(43)]);
+/// let [x, y] = generator.sample([0.2, 0.5]);
+///
+/// assert_eq!(x, Source::simplex(42).sample([0.2, 0.5]));
+/// assert_eq!(y, Source::simplex(43).sample([0.2, 0.5]));
+///
+/// // extract the second component and apply further adapters to it
+/// let y = generator.component(1).abs();
+/// assert_eq!(y.sample([0.2, 0.5]), Source::simplex(43).sample([0.2, 0.5]).abs());
+/// ```
+///
+/// Vector generators displace the input points of scalar generators using [`warp()`].
+///
+/// [`VectorSimplex`]: crate::VectorSimplex
+/// [`Bundle`]: crate::Bundle
+/// [`component()`]: VectorGenerator::component
+/// [`warp()`]: Generator::warp
+pub trait VectorGenerator<const D: usize, const M: usize>: Sized {
+    /// Samples the generator at a given `point` and returns the resulting vector.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, VectorGenerator};
+    /// let generator = Source::vector_simplex(42);
+    /// let [x, y, z] = generator.sample([0.2, 0.5]);
+    ///
+    /// // every component lies between -1.0 and 1.0
+    /// assert!([x, y, z].iter().all(|v| -1.0 <= *v && *v <= 1.0));
+    /// ```
+    fn sample(&self, point: [f64; D]) -> [f64; M];
+
+    /// Computes intervals guaranteed to contain the respective components of the results of the
+    /// generator for every point within the axis-aligned box spanning from `min` to `max`.
+    ///
+    /// This is the vector-valued counterpart of [`Generator::sample_interval()`]. The default
+    /// implementation returns the unbounded interval for every component.
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> [(f64, f64); M] {
+        let _ = (min, max);
+        [interval::UNBOUNDED; M]
+    }
+
+    /// Describes the structure of the generator as a tree of its sources and adapters, as done
+    /// by [`Generator::describe()`].
+    fn describe(&self) -> Description {
+        Description::new(describe::type_name::<Self>())
+    }
+
+    /// Create a scalar generator providing the component at `index` of the results of the
+    /// underlying vector generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `M`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, VectorGenerator};
+    /// let vectors = Source::vector_simplex(42);
+    /// let generator = vectors.clone().component(1);
+    ///
+    /// let [_, y]: [f64; 2] = vectors.sample([0.2, 0.5]);
+    /// assert_eq!(generator.sample([0.2, 0.5]), y);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn component(self, index: usize) -> adapters::Component<D, M, Self> {
+        adapters::Component::new(self, index)
+    }
+
+    /// Create scalar generators providing every component of the results of the underlying
+    /// vector generator, as done by [`component()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, VectorGenerator};
+    /// let vectors = Source::vector_simplex(42);
+    /// let [x, y] = vectors.clone().components();
+    ///
+    /// assert_eq!([x.sample([0.2, 0.5]), y.sample([0.2, 0.5])], vectors.sample([0.2, 0.5]));
+    /// ```
+    ///
+    /// [`component()`]: VectorGenerator::component
+    #[inline]
+    fn components(self) -> [adapters::Component<D, M, Self>; M]
+    where
+        Self: Clone,
+    {
+        core::array::from_fn(|index| self.clone().component(index))
+    }
+}
+
+/// An object safe subset of [`Generator<D>`], allowing generators of different types to be
+/// stored and sampled through a common pointer type.
+///
+/// The adapter methods of [`Generator<D>`] take and return generators by value, such that the
+/// trait cannot be used as a trait object. This trait is implemented for every generator which is
+/// [`Clone`], [`Send`], [`Sync`], and `'static`, which includes all sources and adapters of this
+/// library as long as the closures and generators they wrap are. A `Box<dyn SampleGenerator<D>>`
+/// in turn implements [`Generator<D>`] and [`Clone`], such that boxed generators can be collected,
+/// sampled, and further modified using adapters like any other generator. Sampling the box
+/// produces the same results as sampling the generator it holds, and [`describe()`],
+/// [`to_recipe()`], [`sample_interval()`], and [`sample_lattice()`] are forwarded to it.
+///
+/// The methods of this trait carry a `_dyn` suffix, such that they do not collide with those of
+/// [`Generator<D>`] for generators implementing both traits. They are rarely called directly, as
+/// the boxed generator provides the usual methods of [`Generator<D>`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, Generator, SampleGenerator};
+/// let layers: Vec<Box<dyn SampleGenerator<2>>> = vec![
+///     Box::new(Source::simplex(42).fbm(6, 0.013, 2.0, 0.5)),
+///     Box::new(Source::worley(43).scale([0.05, 0.05])),
+///     Box::new(Source::constant(0.25)),
+/// ];
+///
+/// // sample each layer at [0.2, 0.5]
+/// let value: f64 = layers.iter().map(|layer| layer.sample([0.2, 0.5])).sum();
+///
+/// // boxed generators can be cloned and further modified using adapters
+/// let generator = layers[0].clone().abs().mul(2.0);
+/// assert_eq!(generator.sample([0.2, 0.5]), layers[0].sample([0.2, 0.5]).abs() * 2.0);
+/// ```
+///
+/// [`describe()`]: Generator::describe
+/// [`to_recipe()`]: Generator::to_recipe
+/// [`sample_interval()`]: Generator::sample_interval
+/// [`sample_lattice()`]: Generator::sample_lattice
+pub trait SampleGenerator<const D: usize>: Send + Sync {
+    /// Samples the generator at a given `point`, as done by [`Generator::sample()`].
+    fn sample_dyn(&self, point: [f64; D]) -> f64;
+
+    /// Samples the generator at a given `point` of the integer lattice, as done by
+    /// [`Generator::sample_lattice()`].
+    fn sample_lattice_dyn(&self, point: [i64; D]) -> f64;
+
+    /// Computes bounds of the results within a box, as done by [`Generator::sample_interval()`].
+    fn sample_interval_dyn(&self, min: [f64; D], max: [f64; D]) -> (f64, f64);
+
+    /// Describes the structure of the generator, as done by [`Generator::describe()`].
+    fn describe_dyn(&self) -> Description;
+
+    /// Converts the generator into a recipe, as done by [`Generator::to_recipe()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chain contains a generator without an equivalent recipe.
+    fn to_recipe_dyn(&self) -> Result<NoiseRecipe, RecipeError>;
+
+    /// Clones the generator into a new box.
+    fn clone_box(&self) -> Box<dyn SampleGenerator<D>>;
+}
+
+impl<const D: usize, G> SampleGenerator<D> for G
+where
+    G: Generator<D> + Clone + Send + Sync + 'static,
+{
+    #[inline]
+    fn sample_dyn(&self, point: [f64; D]) -> f64 {
+        self.sample(point)
+    }
+
+    #[inline]
+    fn sample_lattice_dyn(&self, point: [i64; D]) -> f64 {
+        self.sample_lattice(point)
+    }
+
+    #[inline]
+    fn sample_interval_dyn(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        self.sample_interval(min, max)
+    }
+
+    fn describe_dyn(&self) -> Description {
+        self.describe()
+    }
+
+    fn to_recipe_dyn(&self) -> Result<NoiseRecipe, RecipeError> {
+        self.to_recipe()
+    }
+
+    fn clone_box(&self) -> Box<dyn SampleGenerator<D>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<const D: usize> Clone for Box<dyn SampleGenerator<D>> {
+    fn clone(&self) -> Self {
+        self.as_ref().clone_box()
+    }
+}
+
+impl<const D: usize> fmt::Debug for dyn SampleGenerator<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SampleGenerator").finish_non_exhaustive()
+    }
+}
+
+impl<const D: usize> fmt::Display for dyn SampleGenerator<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.describe_dyn(), f)
+    }
+}
+
+impl Generator1D for Box<dyn SampleGenerator<1>> {}
+impl Generator2D for Box<dyn SampleGenerator<2>> {}
+impl Generator3D for Box<dyn SampleGenerator<3>> {}
+impl Generator4D for Box<dyn SampleGenerator<4>> {}
+
+impl<const D: usize> Generator<D> for Box<dyn SampleGenerator<D>> {
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.as_ref().sample_dyn(point)
+    }
+
+    #[inline]
+    fn sample_lattice(&self, point: [i64; D]) -> f64 {
+        self.as_ref().sample_lattice_dyn(point)
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        self.as_ref().sample_interval_dyn(min, max)
+    }
+
+    fn describe(&self) -> Description {
+        self.as_ref().describe_dyn()
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        self.as_ref().to_recipe_dyn()
+    }
 }
 
 /// A trait representing the specialization of [`Generator<D>`] for 1-dimensional input spaces.
@@ -866,6 +2133,43 @@ pub trait Generator1D: Generator<1> {
     {
         adapters::Displace::new(self, displacement_generator)
     }
+
+    /// Fills a buffer of audio samples by interpreting the input of the generator as time.
+    ///
+    /// Writes `out.len()` consecutive samples, where the `n`-th sample is the result of the
+    /// generator at time `start_time + n / sample_rate`, converted to [`f32`]. This is intended
+    /// for generating blocks of modulation or audio signals: the function neither allocates nor
+    /// locks, such that it is safe to call from a real-time thread. The sample times are computed
+    /// from the index rather than accumulated, such that each sample is identical to sampling the
+    /// generator at that time directly, and consecutive blocks starting at
+    /// `start_time + out.len() / sample_rate` continue the signal seamlessly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sample_rate` is not positive and finite.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, Generator1D};
+    /// let generator = Source::simplex(42).fbm(4, 2.0, 2.0, 0.5);
+    /// let mut block = [0.0; 64];
+    ///
+    /// generator.fill_samples(1.5, 48000.0, &mut block);
+    /// assert_eq!(block[10], generator.sample([1.5 + 10.0 / 48000.0]) as f32);
+    /// ```
+    #[inline]
+    fn fill_samples(&self, start_time: f64, sample_rate: f64, out: &mut [f32]) {
+        assert!(
+            sample_rate > 0.0 && sample_rate.is_finite(),
+            "sample rate must be positive and finite"
+        );
+        for (n, sample) in out.iter_mut().enumerate() {
+            *sample = self.sample([start_time + n as f64 / sample_rate]) as f32;
+        }
+    }
 }
 
 /// A trait representing the specialization of [`Generator<D>`] for 2-dimensional input spaces.
@@ -1097,6 +2401,143 @@ pub trait Generator3D: Generator<3> {
     {
         adapters::Displace::new(self, displacement_generator)
     }
+
+    /// Samples the generator at a point on the surface of a sphere centered at the origin.
+    ///
+    /// The point is given by its latitude `lat` and longitude `lon` in radians, with the z-axis as
+    /// polar axis, such that it is located at
+    /// `radius * [cos(lat) * cos(lon), cos(lat) * sin(lon), sin(lat)]`. Sampling the 3D generator
+    /// on the sphere avoids the distortion and pinching at the poles of mapping 2D noise onto
+    /// latitude and longitude directly. Latitudes of at least `pi/2` or at most `-pi/2` are
+    /// mapped exactly onto the respective pole, independent of the longitude.
+    ///
+    /// The `radius` determines the size of features relative to the sphere: the larger the
+    /// radius, the more features fit onto the surface.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, Generator3D};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let generator = Source::simplex(42);
+    ///
+    /// let value = generator.sample_sphere(0.0, FRAC_PI_2, 4.0);
+    /// assert_eq!(value, generator.sample([4.0 * FRAC_PI_2.cos(), 4.0, 0.0]));
+    ///
+    /// // the poles do not depend on the longitude
+    /// assert_eq!(generator.sample_sphere(FRAC_PI_2, 0.3, 4.0), generator.sample([0.0, 0.0, 4.0]));
+    /// ```
+    #[inline]
+    fn sample_sphere(&self, lat: f64, lon: f64, radius: f64) -> f64 {
+        self.sample(sphere::sphere_point(lat, lon, radius))
+    }
+
+    /// Creates an equirectangular map of the generator sampled on the surface of a sphere.
+    ///
+    /// Returns a buffer of shape `[height, width]`, indexed by row and column, as expected by the
+    /// visualizer. The value at `[row, column]` is [`sample_sphere()`] at latitude
+    /// `pi/2 - pi * row / (height - 1)` and longitude `2pi * column / (width - 1) - pi`. Thus the
+    /// first and last rows hold the north and south pole respectively, and are constant, while
+    /// the first and last columns both lie on the seam at longitude `-pi` and are identical, such
+    /// that the map wraps around seamlessly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, Generator3D};
+    /// let generator = Source::simplex(42).fbm(5, 1.0, 2.0, 0.5);
+    /// let map = generator.sphere_to_buffer(64, 32, 2.0);
+    ///
+    /// assert_eq!(map.shape, [32, 64]);
+    /// assert_eq!(map[[10, 0]], map[[10, 63]]);
+    /// assert_eq!(map[[0, 5]], map[[0, 40]]);
+    /// ```
+    ///
+    /// [`sample_sphere()`]: Generator3D::sample_sphere
+    fn sphere_to_buffer(&self, width: usize, height: usize, radius: f64) -> NoiseBuffer<2> {
+        sphere::sphere_to_buffer(self, width, height, radius)
+    }
+
+    /// Creates a density volume for meshing the iso-surfaces of the generator.
+    ///
+    /// Returns a [`DensityVolume`] with the given `shape` in samples, where the density at a given
+    /// index is the result of the generator at `(origin + index) * cell_size` per dimension, as
+    /// done by [`NoiseBuffer::fill_chunk()`]. The volume additionally stores the bounds of the
+    /// densities per block of cells, which allows skipping blocks that do not intersect the
+    /// iso-surface. For further detail see [`DensityVolume`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total size of the volume overflows.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, Generator3D, NoiseBuffer};
+    /// let generator = Source::simplex(42).fbm(5, 0.05, 2.0, 0.5);
+    /// let volume = generator.fill_density([32, 0, -32], 0.5, [17, 17, 17]);
+    ///
+    /// let chunk = NoiseBuffer::<3>::fill_chunk(&generator, [2, 0, -2], [16, 16, 16], 0.5);
+    /// assert_eq!(volume.densities()[[3, 4, 5]], chunk[[3, 4, 5]] as f32);
+    /// ```
+    fn fill_density(&self, origin: [i64; 3], cell_size: f64, shape: [usize; 3]) -> DensityVolume {
+        DensityVolume::new(self, origin, cell_size, shape)
+    }
+
+    /// Create a 2-dimensional generator animated over time by using the third dimension of the
+    /// underlying generator as the time axis.
+    ///
+    /// Sampling the created generator at `[x, y]` yields the result of the underlying generator at
+    /// `[x, y, time * time_scale]`, where `time` is set by [`set_time()`] and starts at 0. As
+    /// each frame is a plain 2-dimensional generator, it can be rendered with the
+    /// `Visualizer` or filled into a [`NoiseBuffer`] without further changes. Alternatively,
+    /// [`sample_at()`] samples a frame at a given time without setting it.
+    ///
+    /// The `time_scale` controls the speed of the animation relative to the features of the
+    /// underlying generator. Smaller values lead to smoother, slower animations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `time_scale` is not finite.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, Generator3D, NoiseBuffer};
+    /// let mut generator = Source::simplex(42)     // build a 3-dimensional generator
+    ///     .scale([0.05; 3])                       // scale the features
+    ///     .animated_2d(0.5);                      // apply the adapter
+    ///
+    /// // render a sequence of frames
+    /// let frames: Vec<NoiseBuffer<2>> = (0..10)
+    ///     .map(|frame| {
+    ///         generator.set_time(frame as f64);
+    ///         NoiseBuffer::<2>::new([64, 64], &generator)
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(frames[3][[5, 8]], generator.sample_at([5.0, 8.0], 3.0));
+    /// ```
+    ///
+    /// [`set_time()`]: adapters::Animated2D::set_time
+    /// [`sample_at()`]: adapters::Animated2D::sample_at
+    /// [`NoiseBuffer`]: crate::NoiseBuffer
+    #[inline]
+    fn animated_2d(self, time_scale: f64) -> adapters::Animated2D<Self> {
+        adapters::Animated2D::new(self, time_scale)
+    }
 }
 
 /// A trait representing the specialization of [`Generator<D>`] for 4-dimensional input spaces.
@@ -1268,4 +2709,92 @@ pub trait Generator4D: Generator<4> {
     {
         adapters::Displace::new(self, displacement_generator)
     }
+
+    /// Create a 2-dimensional generator which tiles seamlessly by sampling the underlying
+    /// 4-dimensional generator on a torus.
+    ///
+    /// Creates a generator which maps the x-coordinate of each input point onto a circle in the
+    /// plane of the first two axes of the underlying generator, and the y-coordinate onto a
+    /// circle in the plane of the last two axes. The circumferences of the circles equal the
+    /// respective component of `period`, such that the results repeat exactly after `period`
+    /// along each axis, without requiring a periodic source. Coordinates are reduced to the
+    /// period before they are mapped, so sampling at `x` and `x + k * period` for an integer `k`
+    /// yields identical results whenever the reduction is exact, as for the edges of a tile.
+    ///
+    /// As distances along the circles equal distances in the input space, features have roughly
+    /// the same size as those of 2-dimensional noise sampled directly. However, the torus is
+    /// curved in 4-dimensional space, such that points far apart relative to the period are
+    /// closer to each other than in the input space, which correlates the results over long
+    /// distances. For periods only a few times larger than the features, this visibly distorts
+    /// the noise. Also, 4-dimensional noise has a different distribution of results than its
+    /// 2-dimensional counterpart, typically with slightly lower contrast.
+    ///
+    /// To create a tileable fractal generator, apply [`fbm()`] or a similar adapter to the
+    /// 4-dimensional generator before this adapter, such that every octave is sampled on the
+    /// same torus and remains tileable. Scaling the 4-dimensional generator scales the features
+    /// without affecting the period.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any component of `period` is not positive and finite.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, Generator4D};
+    /// let generator = Source::simplex(42)     // build a 4-dimensional generator
+    ///     .fbm(5, 0.05, 2.0, 0.5)             // apply fractal brownian motion
+    ///     .tileable_2d([128.0, 64.0]);        // apply the adapter
+    ///
+    /// // the results repeat along both axes
+    /// assert_eq!(generator.sample([0.0, 10.0]), generator.sample([128.0, 10.0]));
+    /// assert_eq!(generator.sample([25.0, 0.0]), generator.sample([25.0, 64.0]));
+    /// ```
+    ///
+    /// [`fbm()`]: Generator::fbm
+    #[inline]
+    fn tileable_2d(self, period: [f64; 2]) -> adapters::Tileable2D<Self> {
+        adapters::Tileable2D::new(self, period)
+    }
+
+    /// Create a 2-dimensional generator which tiles seamlessly by applying fractal brownian
+    /// motion to the underlying 4-dimensional generator and sampling it on a torus.
+    ///
+    /// This is a shorthand for [`fbm()`] followed by [`tileable_2d()`]. As the octaves are
+    /// computed in 4-dimensional space, each octave scales the torus rather than the input
+    /// point, such that every octave tiles with the same `period`, independent of `frequency`
+    /// and `lacunarity`. In contrast, applying fractal brownian motion to the resulting
+    /// 2-dimensional generator would change the period of each octave.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any component of `period` is not positive and finite.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, Generator4D};
+    /// let generator = Source::simplex(42).tileable_fbm([256.0, 256.0], 6, 0.013, 2.0, 0.5);
+    ///
+    /// assert_eq!(generator.sample([0.0, 37.0]), generator.sample([256.0, 37.0]));
+    /// ```
+    ///
+    /// [`fbm()`]: Generator::fbm
+    /// [`tileable_2d()`]: Generator4D::tileable_2d
+    #[inline]
+    fn tileable_fbm(
+        self,
+        period: [f64; 2],
+        octaves: u32,
+        frequency: f64,
+        lacunarity: f64,
+        persistence: f64,
+    ) -> adapters::Tileable2D<adapters::Fbm<4, Self>> {
+        self.fbm(octaves, frequency, lacunarity, persistence)
+            .tileable_2d(period)
+    }
 }
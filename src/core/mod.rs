@@ -1,7 +1,22 @@
 pub mod adapters;
+#[cfg(feature = "codegen")]
+pub mod codegen;
 #[cfg(feature = "dev-tools")]
 pub mod devtools;
+pub mod erosion;
+pub mod error;
 pub mod generator;
+mod optimize;
+pub mod pipeline;
+pub mod planet;
+pub mod points;
+pub mod presets;
+pub mod raw;
+pub mod recipe;
 pub mod source;
 pub mod sources;
+pub mod testing;
 pub mod utils;
+#[cfg(feature = "wasm")]
+#[allow(unsafe_code)]
+pub mod wasm;
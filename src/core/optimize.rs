@@ -0,0 +1,290 @@
+use crate::core::{
+    generator::Generator,
+    recipe::{NoiseRecipe, RecipeError, TransformStep},
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+
+impl NoiseRecipe {
+    /// Returns an equivalent recipe which is cheaper to sample when built for `D` dimensions.
+    ///
+    /// Every node of a built recipe is called through a pointer, such that deep chains pay for
+    /// each of their nodes, even for nodes which have no effect. The optimized recipe is rewritten
+    /// bottom-up as follows:
+    ///
+    /// - Subtrees whose leaves are all [`Constant`](NoiseRecipe::Constant) nodes are evaluated
+    ///   into a single constant, such as [`Fbm`](NoiseRecipe::Fbm) of a constant, or the sum of
+    ///   two constants. Domain transforms of a constant are dropped likewise.
+    /// - Identity adapters are removed, namely scaling by 1, multiplying by 1, negating twice,
+    ///   and translating or adding zero, where the sign of zero matters as described below.
+    /// - [`Sum`](NoiseRecipe::Sum) and [`Product`](NoiseRecipe::Product) with a constant operand
+    ///   become [`Add`](NoiseRecipe::Add) and [`Mul`](NoiseRecipe::Mul) respectively.
+    /// - Consecutive [`Scale`](NoiseRecipe::Scale) and [`Translate`](NoiseRecipe::Translate)
+    ///   nodes are fused into a single [`Transform`](NoiseRecipe::Transform) node.
+    ///
+    /// The optimized recipe produces bit-identical results to the original recipe at every
+    /// point, which rules out rewrites that change rounding. In particular, fused transforms
+    /// still apply every step rather than a single combined matrix, as `(x * a) * b` and
+    /// `x * (a * b)` may differ in the last bit. Likewise, adding `0.0` turns `-0.0` into `0.0`,
+    /// such that [`Add`](NoiseRecipe::Add) and [`Translate`](NoiseRecipe::Translate) by
+    /// positive zero are only removed where this cannot make a difference. Only the bounds
+    /// reported by [`Generator::sample_interval()`] may become tighter, as constants have exact
+    /// bounds.
+    ///
+    /// Nodes which check or depend on the sampled position beyond their source, namely
+    /// [`Checked`](NoiseRecipe::Checked), [`FbmLod`](NoiseRecipe::FbmLod), and
+    /// [`DistanceLod`](NoiseRecipe::DistanceLod), are kept as they are.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the recipe cannot be built for the dimensionality `D`, see
+    /// [`NoiseRecipe::build()`]. The recipe is validated before it is optimized, such that
+    /// invalid nodes are reported even if optimizing would remove them.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{recipe::NoiseRecipe, Source, Generator};
+    /// let generator = Source::simplex(42)
+    ///     .scale([1.0, 1.0])
+    ///     .translate([0.5, 0.5])
+    ///     .scale([0.1, 0.1])
+    ///     .mul(1.0)
+    ///     .sum(Source::constant(0.25).fbm(3, 0.013, 2.0, 0.5));
+    /// let recipe = generator.to_recipe().unwrap();
+    /// let optimized = recipe.optimize::<2>().unwrap();
+    ///
+    /// let point = [0.2, 0.5];
+    /// let expected = recipe.build::<2>().unwrap().sample(point);
+    /// assert_eq!(optimized.build::<2>().unwrap().sample(point), expected);
+    /// assert!(matches!(optimized, NoiseRecipe::Add { .. }));
+    /// ```
+    pub fn optimize<const D: usize>(&self) -> Result<NoiseRecipe, RecipeError> {
+        // validating first guarantees that every subtree can be built for evaluation
+        self.build::<D>()?;
+        Ok(self.clone().optimize_node::<D>())
+    }
+
+    fn optimize_node<const D: usize>(mut self) -> NoiseRecipe {
+        let mut constant = true;
+        for child in self.children_mut() {
+            let node = core::mem::replace(child, NoiseRecipe::Constant { value: 0.0 });
+            *child = node.optimize_node::<D>();
+            constant &= child.constant().is_some();
+        }
+        if constant && self.is_pointwise() {
+            let value = self
+                .build::<D>()
+                .expect("recipe was validated")
+                .sample([0.0; D]);
+            return Self::Constant { value };
+        }
+        self.fold::<D>()
+    }
+
+    // rewrites a single node whose children are already optimized
+    fn fold<const D: usize>(self) -> NoiseRecipe {
+        match self {
+            // domain transforms have no effect on constants
+            Self::Scale { source, .. }
+            | Self::Translate { source, .. }
+            | Self::Transform { source, .. }
+            | Self::Rotate { source, .. }
+            | Self::Displace { source, .. }
+                if source.constant().is_some() =>
+            {
+                *source
+            }
+            Self::Scale { source, scale } if is_identity_scale(&scale) => *source,
+            Self::Translate {
+                source,
+                translation,
+            } if is_identity_translation(&translation) => *source,
+            recipe @ (Self::Scale { .. } | Self::Translate { .. } | Self::Transform { .. }) => {
+                let (source, mut steps) = recipe.into_steps();
+                let source = if source.is_domain_transform() {
+                    let (source, inner) = source.into_steps();
+                    steps.extend(inner);
+                    source
+                } else {
+                    source
+                };
+                steps.retain(|step| match step {
+                    TransformStep::Scale(scale) => !is_identity_scale(scale),
+                    TransformStep::Translate(translation) => !is_identity_translation(translation),
+                });
+                match steps.len() {
+                    0 => *source,
+                    1 => match steps.pop() {
+                        Some(TransformStep::Scale(scale)) => Self::Scale { source, scale },
+                        Some(TransformStep::Translate(translation)) => Self::Translate {
+                            source,
+                            translation,
+                        },
+                        None => unreachable!("transform has a step"),
+                    },
+                    _ => Self::Transform { source, steps },
+                }
+            }
+            Self::Mul { source, scale: 1.0 } => *source,
+            // adding negative zero never changes a value, while adding positive zero turns
+            // negative zero into positive zero
+            Self::Add { source, offset }
+                if offset == 0.0
+                    && (offset.is_sign_negative() || !source.yields_negative_zero()) =>
+            {
+                *source
+            }
+            Self::Neg { source } if matches!(*source, Self::Neg { .. }) => match *source {
+                Self::Neg { source } => *source,
+                _ => unreachable!("source is negated"),
+            },
+            // adding a constant equals the sum with a constant, where swapping the operands is
+            // exact unless both are NaN and the payload of either may be propagated
+            Self::Sum { a, b } => match (a.constant(), b.constant()) {
+                (_, Some(offset)) => Self::Add { source: a, offset }.fold::<D>(),
+                (Some(offset), _) if !offset.is_nan() => {
+                    Self::Add { source: b, offset }.fold::<D>()
+                }
+                _ => Self::Sum { a, b },
+            },
+            Self::Product { a, b } => match (a.constant(), b.constant()) {
+                (_, Some(scale)) => Self::Mul { source: a, scale }.fold::<D>(),
+                (Some(scale), _) if !scale.is_nan() => Self::Mul { source: b, scale }.fold::<D>(),
+                _ => Self::Product { a, b },
+            },
+            recipe => recipe,
+        }
+    }
+
+    fn constant(&self) -> Option<f64> {
+        match self {
+            Self::Constant { value } => Some(*value),
+            _ => None,
+        }
+    }
+
+    // whether the node may return negative zero, conservatively assuming that it does unless
+    // its result is known to be positive zero instead
+    fn yields_negative_zero(&self) -> bool {
+        match self {
+            Self::Constant { value } => *value == 0.0 && value.is_sign_negative(),
+            Self::Add { offset, .. } => *offset == 0.0 && offset.is_sign_negative(),
+            Self::Abs { .. } | Self::Exp { .. } => false,
+            _ => true,
+        }
+    }
+
+    // whether the node only combines the values of its children at the sampled point, such that
+    // it is constant if all of its children are
+    fn is_pointwise(&self) -> bool {
+        matches!(
+            self,
+            Self::Neg { .. }
+                | Self::Abs { .. }
+                | Self::Exp { .. }
+                | Self::Add { .. }
+                | Self::Mul { .. }
+                | Self::Powi { .. }
+                | Self::Powf { .. }
+                | Self::Clamp { .. }
+                | Self::Sum { .. }
+                | Self::Product { .. }
+                | Self::Min { .. }
+                | Self::Max { .. }
+                | Self::Power { .. }
+                | Self::Blend { .. }
+                | Self::Select { .. }
+                | Self::Fbm { .. }
+                | Self::Billow { .. }
+                | Self::RidgedMulti { .. }
+        )
+    }
+
+    fn is_domain_transform(&self) -> bool {
+        matches!(
+            self,
+            Self::Scale { .. } | Self::Translate { .. } | Self::Transform { .. }
+        )
+    }
+
+    // splits a scale, translate, or transform node into its source and its steps
+    fn into_steps(self) -> (Box<NoiseRecipe>, Vec<TransformStep>) {
+        match self {
+            Self::Scale { source, scale } => (source, vec![TransformStep::Scale(scale)]),
+            Self::Translate {
+                source,
+                translation,
+            } => (source, vec![TransformStep::Translate(translation)]),
+            Self::Transform { source, steps } => (source, steps),
+            _ => unreachable!("node is not a domain transform"),
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut NoiseRecipe> {
+        match self {
+            Self::Constant { .. }
+            | Self::Simplex { .. }
+            | Self::Value { .. }
+            | Self::Perlin { .. }
+            | Self::ImprovedPerlin { .. }
+            | Self::OpenSimplex2 { .. }
+            | Self::OpenSimplex2S { .. }
+            | Self::Worley { .. }
+            | Self::Checkerboard { .. }
+            | Self::White { .. }
+            | Self::Spot { .. }
+            | Self::Wavelet { .. }
+            | Self::Blue { .. }
+            | Self::DistanceField { .. }
+            | Self::LinearGradient { .. }
+            | Self::RadialGradient { .. }
+            | Self::Island { .. }
+            | Self::IslandRect { .. } => Vec::new(),
+            Self::Scale { source, .. }
+            | Self::Translate { source, .. }
+            | Self::Transform { source, .. }
+            | Self::Rotate { source, .. }
+            | Self::Neg { source }
+            | Self::Abs { source }
+            | Self::Exp { source }
+            | Self::Add { source, .. }
+            | Self::Mul { source, .. }
+            | Self::Powi { source, .. }
+            | Self::Powf { source, .. }
+            | Self::Clamp { source, .. }
+            | Self::Checked { source, .. }
+            | Self::Fbm { source, .. }
+            | Self::Billow { source, .. }
+            | Self::RidgedMulti { source, .. }
+            | Self::FbmLod { source, .. }
+            | Self::DistanceLod { source, .. } => vec![source],
+            Self::Displace {
+                source,
+                displacement,
+                ..
+            } => vec![source, displacement],
+            Self::Sum { a, b }
+            | Self::Product { a, b }
+            | Self::Min { a, b }
+            | Self::Max { a, b }
+            | Self::Power { a, b } => vec![a, b],
+            Self::Blend { a, b, control } | Self::Select { a, b, control, .. } => {
+                vec![a, b, control]
+            }
+        }
+    }
+}
+
+// multiplying by one never changes a value
+fn is_identity_scale(scale: &[f64]) -> bool {
+    scale.iter().all(|&factor| factor == 1.0)
+}
+
+// adding negative zero never changes a value, unlike positive zero, see above
+fn is_identity_translation(translation: &[f64]) -> bool {
+    translation
+        .iter()
+        .all(|&offset| offset == 0.0 && offset.is_sign_negative())
+}
@@ -0,0 +1,197 @@
+//! Helpers for sampling generators on the surface of a planet.
+//!
+//! Mapping a sphere onto a single equirectangular image, as done by
+//! [`Generator3D::sphere_to_buffer()`], stretches the cells towards the poles. A cube sphere
+//! instead projects the six faces of a cube onto the sphere, such that each face is a square grid
+//! with only little distortion, which suits chunked terrain and cubemap textures alike.
+//!
+//! The functions of this module sample a 3-dimensional generator on the sphere surface, such
+//! that the faces join seamlessly: the values on the edges and corners shared by adjacent faces
+//! are bit-identical.
+//!
+//! [`Generator3D::sphere_to_buffer()`]: crate::Generator3D::sphere_to_buffer
+
+use crate::core::generator::Generator;
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::utils::noisebuf::{self, NoiseBuffer};
+use core::f64::consts::FRAC_PI_4;
+
+/// A face of the cube which is projected onto the sphere.
+///
+/// The faces are oriented as the faces of a cubemap in OpenGL, viewed from the center of the
+/// cube. For a face of a buffer created by [`cube_sphere_face()`], the first axis of the buffer
+/// runs along the rows from top to bottom, and the second axis along the columns from left to
+/// right. Thus, each face is oriented as follows, where `u` and `v` range from `-1` at the first
+/// to `1` at the last column and row respectively:
+///
+/// | Face                          | Point on the cube |
+/// |-------------------------------|-------------------|
+/// | [`PositiveX`](Self::PositiveX) | `[1, -v, -u]`     |
+/// | [`NegativeX`](Self::NegativeX) | `[-1, -v, u]`     |
+/// | [`PositiveY`](Self::PositiveY) | `[u, 1, v]`       |
+/// | [`NegativeY`](Self::NegativeY) | `[u, -1, -v]`     |
+/// | [`PositiveZ`](Self::PositiveZ) | `[u, -v, 1]`      |
+/// | [`NegativeZ`](Self::NegativeZ) | `[-u, -v, -1]`    |
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CubeFace {
+    /// The face pointing along the positive x-axis.
+    PositiveX,
+    /// The face pointing along the negative x-axis.
+    NegativeX,
+    /// The face pointing along the positive y-axis.
+    PositiveY,
+    /// The face pointing along the negative y-axis.
+    NegativeY,
+    /// The face pointing along the positive z-axis.
+    PositiveZ,
+    /// The face pointing along the negative z-axis.
+    NegativeZ,
+}
+
+impl CubeFace {
+    /// All faces, in the order returned by [`all_faces()`].
+    pub const ALL: [CubeFace; 6] = [
+        CubeFace::PositiveX,
+        CubeFace::NegativeX,
+        CubeFace::PositiveY,
+        CubeFace::NegativeY,
+        CubeFace::PositiveZ,
+        CubeFace::NegativeZ,
+    ];
+
+    // maps the face coordinates u and v onto the surface of the cube with corners at -1 and 1
+    #[inline]
+    fn cube_point(self, u: f64, v: f64) -> [f64; 3] {
+        match self {
+            CubeFace::PositiveX => [1.0, -v, -u],
+            CubeFace::NegativeX => [-1.0, -v, u],
+            CubeFace::PositiveY => [u, 1.0, v],
+            CubeFace::NegativeY => [u, -1.0, -v],
+            CubeFace::PositiveZ => [u, -v, 1.0],
+            CubeFace::NegativeZ => [-u, -v, -1.0],
+        }
+    }
+}
+
+/// Samples the generator on one face of a cube sphere.
+///
+/// Returns a buffer of shape `[resolution, resolution]`, oriented as described for
+/// [`CubeFace`]. The grid of the face spans the whole face including its edges, such that the
+/// first and last rows and columns are shared with the adjacent faces. Each grid point is
+/// projected onto the sphere of the given `radius` centered at the origin, where the generator is
+/// sampled.
+///
+/// Rather than normalizing the points of the cube directly, which compresses cells towards the
+/// edges of the face, the coordinates are first adjusted by the tangent, such that the grid
+/// points are spaced evenly by angle. This yields cells of more uniform size across the face.
+///
+/// The `radius` determines the size of features relative to the sphere: the larger the radius,
+/// the more features fit onto the surface.
+///
+/// # Panics
+///
+/// Panics if `resolution` is less than 2, or if `radius` is not positive and finite.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, Generator, planet::{self, CubeFace}};
+/// let generator = Source::simplex(42).fbm(5, 1.0, 2.0, 0.5);
+/// let front = planet::cube_sphere_face(&generator, CubeFace::PositiveZ, 33, 2.0);
+/// let right = planet::cube_sphere_face(&generator, CubeFace::PositiveX, 33, 2.0);
+///
+/// assert_eq!(front.shape, [33, 33]);
+/// // the last column of the front face is the first column of the right face
+/// assert_eq!(front[[10, 32]], right[[10, 0]]);
+/// ```
+#[track_caller]
+pub fn cube_sphere_face<G>(
+    generator: &G,
+    face: CubeFace,
+    resolution: usize,
+    radius: f64,
+) -> NoiseBuffer<2>
+where
+    G: Generator<3>,
+{
+    assert!(
+        resolution >= 2,
+        "resolution must be at least 2, got {}",
+        resolution
+    );
+    assert!(
+        radius > 0.0 && radius.is_finite(),
+        "radius must be positive and finite, got {}",
+        radius
+    );
+    let mut noisebuf = NoiseBuffer::new_empty([resolution, resolution]);
+    for ([row, column], value) in
+        noisebuf::tensor_indices([resolution, resolution]).zip(&mut noisebuf.buffer)
+    {
+        let point = face.cube_point(
+            grid_coordinate(column, resolution),
+            grid_coordinate(row, resolution),
+        );
+        *value = generator.sample(sphere_point(point, radius));
+    }
+    noisebuf
+}
+
+/// Samples the generator on all six faces of a cube sphere.
+///
+/// Returns the faces in the order of [`CubeFace::ALL`], each as created by
+/// [`cube_sphere_face()`].
+///
+/// # Panics
+///
+/// Panics if `resolution` is less than 2, or if `radius` is not positive and finite.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, Generator, planet::{self, CubeFace}};
+/// let generator = Source::simplex(42).fbm(5, 1.0, 2.0, 0.5);
+/// let faces = planet::all_faces(&generator, 17, 2.0);
+///
+/// for (face, buf) in CubeFace::ALL.into_iter().zip(&faces) {
+///     assert_eq!(buf.buffer, planet::cube_sphere_face(&generator, face, 17, 2.0).buffer);
+/// }
+/// ```
+#[track_caller]
+pub fn all_faces<G>(generator: &G, resolution: usize, radius: f64) -> [NoiseBuffer<2>; 6]
+where
+    G: Generator<3>,
+{
+    CubeFace::ALL.map(|face| cube_sphere_face(generator, face, resolution, radius))
+}
+
+// the coordinate of the given grid index in [-1, 1]. the numerator is exact and negated for the
+// mirrored index, such that opposite grid points have exactly opposite coordinates.
+#[inline]
+fn grid_coordinate(index: usize, resolution: usize) -> f64 {
+    (2.0 * index as f64 - (resolution - 1) as f64) / (resolution - 1) as f64
+}
+
+// projects a point on the cube onto the sphere, spacing the points evenly by angle. every
+// component is adjusted alike, so a point shared by several faces is projected identically
+// regardless of the face it was computed for. zero keeps a positive sign, as faces may disagree
+// on the sign of zero at the centers of their edges.
+#[inline]
+fn sphere_point(point: [f64; 3], radius: f64) -> [f64; 3] {
+    let point = point.map(|x| {
+        let adjusted = (x.abs() * FRAC_PI_4).tan();
+        if x < 0.0 {
+            -adjusted
+        } else {
+            adjusted
+        }
+    });
+    let length = (point[0] * point[0] + point[1] * point[1] + point[2] * point[2]).sqrt();
+    point.map(|x| radius * (x / length))
+}
@@ -0,0 +1,289 @@
+//! Generation of well-spaced point sets.
+//!
+//! Scattering objects such as trees or stars at uniformly random positions produces clumps and
+//! gaps. The functions of this module instead generate Poisson-disk point sets, in which no two
+//! points are closer than a given minimum distance, while the points still appear random.
+//!
+//! All point sets are deterministic: for a given seed and set of parameters, the result is
+//! bit-identical across runs and platforms.
+
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::f64::consts::{SQRT_2, TAU};
+use rand::Rng;
+use rand_chacha::{rand_core::SeedableRng, ChaCha12Rng};
+
+// the number of candidates tried around an active point before it is retired, as suggested by
+// bridson
+const ATTEMPTS: usize = 30;
+
+/// Generates a Poisson-disk point set within a 2-dimensional region using Bridson's algorithm.
+///
+/// The points lie within the half-open region from `region_min` (inclusive) to `region_max`
+/// (exclusive), and no two points are closer than `min_distance`. Starting from a random point,
+/// new points are repeatedly placed at random within a distance of `min_distance` to twice that
+/// around previously placed points, until no more points fit. The randomness is solely derived
+/// from the value of `seed`.
+///
+/// The time and memory required are proportional to the area of the region divided by the
+/// square of `min_distance`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::points;
+/// let trees = points::poisson_disk(42, [0.0, 0.0], [100.0, 50.0], 4.0);
+///
+/// for [x, y] in &trees {
+///     assert!((0.0..100.0).contains(x) && (0.0..50.0).contains(y));
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if `min_distance` is not positive and finite, if any component of the region bounds
+/// is not finite, or if `region_min` exceeds `region_max` along any axis.
+pub fn poisson_disk(
+    seed: u64,
+    region_min: [f64; 2],
+    region_max: [f64; 2],
+    min_distance: f64,
+) -> Vec<[f64; 2]> {
+    assert_min_distance(min_distance);
+    assert!(
+        region_min.iter().chain(&region_max).all(|x| x.is_finite()),
+        "region bounds must be finite, got {:?} and {:?}",
+        region_min,
+        region_max
+    );
+    assert!(
+        region_min[0] <= region_max[0] && region_min[1] <= region_max[1],
+        "region_min {:?} must not exceed region_max {:?}",
+        region_min,
+        region_max
+    );
+    let mut rng = ChaCha12Rng::seed_from_u64(seed);
+    Sampler::new(region_min, region_max, min_distance, &[]).fill(&mut rng)
+}
+
+/// Generates the part of an infinite Poisson-disk point set lying within a square chunk.
+///
+/// The plane is divided into square chunks with a side length of `chunk_size`, where the chunk
+/// at `chunk_coord` covers the half-open region from `chunk_coord * chunk_size` (inclusive) to
+/// `(chunk_coord + 1) * chunk_size` (exclusive). The point set spans all chunks, such that no two
+/// points are closer than `min_distance`, including points in neighbouring chunks. Every point
+/// belongs to exactly one chunk, and the points of a chunk are the same regardless of which
+/// other chunks are generated, or in which order. Thus, chunks can be generated on demand, for
+/// example as the corresponding parts of a world are loaded.
+///
+/// To achieve this, the chunks are divided into four phases by the parity of their coordinates,
+/// such that neighbouring chunks are always in different phases. The points of each chunk are
+/// generated using Bridson's algorithm, as done by [`poisson_disk()`], while keeping the
+/// minimum distance to the points of its neighbours in earlier phases, which are generated
+/// first. As a consequence, generating a single chunk requires generating up to 16 of the chunks
+/// around it as well. The randomness of each chunk is solely derived from the value of `seed`
+/// and its coordinates.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::points;
+/// let stars = points::poisson_disk_chunked(42, [3, -2], 64.0, 5.0);
+/// let neighbours = points::poisson_disk_chunked(42, [4, -2], 64.0, 5.0);
+///
+/// // the points of neighbouring chunks keep their distance across the border
+/// for [x1, y1] in &stars {
+///     for [x2, y2] in &neighbours {
+///         assert!((x2 - x1).hypot(y2 - y1) >= 5.0);
+///     }
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if `min_distance` is not positive and finite, if `chunk_size` is not finite, or if
+/// `min_distance` exceeds `chunk_size`.
+pub fn poisson_disk_chunked(
+    seed: u64,
+    chunk_coord: [i64; 2],
+    chunk_size: f64,
+    min_distance: f64,
+) -> Vec<[f64; 2]> {
+    assert_min_distance(min_distance);
+    assert!(
+        chunk_size.is_finite() && min_distance <= chunk_size,
+        "min distance {} must not exceed the finite chunk size {}",
+        min_distance,
+        chunk_size
+    );
+    let mut chunks = BTreeMap::new();
+    generate_chunk(seed, chunk_coord, chunk_size, min_distance, &mut chunks).clone()
+}
+
+fn assert_min_distance(min_distance: f64) {
+    assert!(
+        min_distance > 0.0 && min_distance.is_finite(),
+        "min distance must be positive and finite, got {}",
+        min_distance
+    );
+}
+
+// generates the points of the given chunk, along with those of its neighbours in earlier phases,
+// all of which are stored in the given map
+fn generate_chunk(
+    seed: u64,
+    chunk: [i64; 2],
+    chunk_size: f64,
+    min_distance: f64,
+    chunks: &mut BTreeMap<[i64; 2], Vec<[f64; 2]>>,
+) -> &Vec<[f64; 2]> {
+    if !chunks.contains_key(&chunk) {
+        let phase = |[x, y]: [i64; 2]| x.rem_euclid(2) + 2 * y.rem_euclid(2);
+        let mut fixed = Vec::new();
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                let neighbour = [chunk[0] + dx, chunk[1] + dy];
+                if phase(neighbour) < phase(chunk) {
+                    let points = generate_chunk(seed, neighbour, chunk_size, min_distance, chunks);
+                    fixed.extend_from_slice(points);
+                }
+            }
+        }
+        let region_min = chunk.map(|c| c as f64 * chunk_size);
+        let region_max = chunk.map(|c| (c + 1) as f64 * chunk_size);
+        // the key of the generator is unique per seed and chunk
+        let mut key = [0; 32];
+        key[..8].copy_from_slice(&seed.to_le_bytes());
+        key[8..16].copy_from_slice(&chunk[0].to_le_bytes());
+        key[16..24].copy_from_slice(&chunk[1].to_le_bytes());
+        let mut rng = ChaCha12Rng::from_seed(key);
+        let points = Sampler::new(region_min, region_max, min_distance, &fixed).fill(&mut rng);
+        chunks.insert(chunk, points);
+    }
+    &chunks[&chunk]
+}
+
+// a background grid accelerating the search for points close to a candidate. the cells are
+// small enough to hold at most one point each, and the grid extends beyond the region by the
+// minimum distance to hold fixed points outside of it
+struct Sampler {
+    region_min: [f64; 2],
+    region_max: [f64; 2],
+    min_distance: f64,
+    grid_min: [f64; 2],
+    cell_size: f64,
+    grid_shape: [usize; 2],
+    grid: Vec<Option<[f64; 2]>>,
+}
+
+impl Sampler {
+    fn new(
+        region_min: [f64; 2],
+        region_max: [f64; 2],
+        min_distance: f64,
+        fixed: &[[f64; 2]],
+    ) -> Self {
+        let cell_size = min_distance / SQRT_2;
+        let grid_min = region_min.map(|x| x - min_distance);
+        let grid_shape = core::array::from_fn(|i| {
+            ((region_max[i] - grid_min[i] + min_distance) / cell_size).ceil() as usize + 1
+        });
+        let mut sampler = Self {
+            region_min,
+            region_max,
+            min_distance,
+            grid_min,
+            cell_size,
+            grid_shape,
+            grid: vec![None; grid_shape[0] * grid_shape[1]],
+        };
+        for &point in fixed {
+            if let Some(cell) = sampler.cell(point) {
+                sampler.grid[cell] = Some(point);
+            }
+        }
+        sampler
+    }
+
+    fn fill(mut self, rng: &mut ChaCha12Rng) -> Vec<[f64; 2]> {
+        let mut points = Vec::new();
+        if (0..2).any(|i| self.region_min[i] >= self.region_max[i]) {
+            return points;
+        }
+        let mut active = Vec::new();
+        for _ in 0..ATTEMPTS {
+            let candidate =
+                core::array::from_fn(|i| rng.gen_range(self.region_min[i]..self.region_max[i]));
+            if self.insert(candidate) {
+                points.push(candidate);
+                active.push(candidate);
+                break;
+            }
+        }
+        while !active.is_empty() {
+            let index = rng.gen_range(0..active.len());
+            let [x, y] = active[index];
+            let mut found = false;
+            for _ in 0..ATTEMPTS {
+                let angle = rng.gen_range(0.0..TAU);
+                let distance = rng.gen_range(self.min_distance..2.0 * self.min_distance);
+                let candidate = [x + distance * angle.cos(), y + distance * angle.sin()];
+                if self.insert(candidate) {
+                    points.push(candidate);
+                    active.push(candidate);
+                    found = true;
+                    break;
+                }
+            }
+            if !found {
+                active.swap_remove(index);
+            }
+        }
+        points
+    }
+
+    // inserts the candidate into the grid if it lies within the region and keeps the minimum
+    // distance to all points in the grid
+    fn insert(&mut self, candidate: [f64; 2]) -> bool {
+        let inside =
+            (0..2).all(|i| (self.region_min[i]..self.region_max[i]).contains(&candidate[i]));
+        if !inside {
+            return false;
+        }
+        let Some(cell) = self.cell(candidate) else {
+            return false;
+        };
+        let [cx, cy] = [cell / self.grid_shape[1], cell % self.grid_shape[1]];
+        // points closer than the minimum distance lie at most two cells away
+        for x in cx.saturating_sub(2)..(cx + 3).min(self.grid_shape[0]) {
+            for y in cy.saturating_sub(2)..(cy + 3).min(self.grid_shape[1]) {
+                if let Some([px, py]) = self.grid[x * self.grid_shape[1] + y] {
+                    let (dx, dy) = (candidate[0] - px, candidate[1] - py);
+                    if dx * dx + dy * dy < self.min_distance * self.min_distance {
+                        return false;
+                    }
+                }
+            }
+        }
+        self.grid[cell] = Some(candidate);
+        true
+    }
+
+    // the index of the grid cell containing the point, if any
+    fn cell(&self, point: [f64; 2]) -> Option<usize> {
+        let mut index = [0; 2];
+        for i in 0..2 {
+            let offset = ((point[i] - self.grid_min[i]) / self.cell_size).floor();
+            if !(0.0..self.grid_shape[i] as f64).contains(&offset) {
+                return None;
+            }
+            index[i] = offset as usize;
+        }
+        Some(index[0] * self.grid_shape[1] + index[1])
+    }
+}
@@ -0,0 +1,429 @@
+//! Ready-made terrain generators composed from the sources and adapters of this crate.
+//!
+//! Each preset is a function returning a generator which produces heights in `[-1, 1]`, built
+//! from the same pieces available to any user, such that it can be sampled, adapted further,
+//! serialized, and turned into a recipe like any other generator. The returned types are spelled
+//! out as type aliases, which document the composition of each preset.
+//!
+//! Every preset comes in two flavors: a function taking only a seed, such as [`mountains()`],
+//! which uses the default [`Knobs`] of the preset, and a function additionally taking the knobs,
+//! such as [`mountains_with()`], for tweaking the result:
+//!
+//! ```
+//! # use libnoise::{presets::{self, Knobs}, Generator};
+//! let generator = presets::mountains_with(42, Knobs { sea_level: -0.2, ..Knobs::MOUNTAINS });
+//! let height = generator.sample([120.0, 300.0]);
+//! ```
+//!
+//! The 2-dimensional presets are meant to be sampled in units of pixels or meters, such that a
+//! region of 1000 by 1000 units shows a few of their largest features with the default knobs:
+//!
+//! ```no_run
+//! # use libnoise::{presets, Visualizer};
+//! # fn main() -> Result<(), libnoise::Error> {
+//! Visualizer::<2>::new([1000, 1000], &presets::mountains(42)).write_to_file("mountains.png")?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! All presets are deterministic: for a given seed and set of knobs, the result is bit-identical
+//! across runs and platforms, as they only use adapters covered by the determinism guarantee of
+//! this crate.
+//!
+//! [`mountains()`]: mountains
+//! [`mountains_with()`]: mountains_with
+
+use crate::core::{
+    adapters::{Abs, Add, Blend, Clamp, Displace, Fbm, Max, Mul, RidgedMulti, Scale, Select, Sum},
+    generator::{Generator, Generator2D},
+    source::Source,
+    sources::{Constant, Simplex, Worley},
+};
+
+/// The tweakable parameters shared by all presets.
+///
+/// Each preset has its own defaults, such as [`Knobs::MOUNTAINS`], which serve as a starting
+/// point for struct update syntax.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::presets::{self, Knobs};
+/// let knobs = Knobs {
+///     scale: 800.0,
+///     roughness: 0.6,
+///     ..Knobs::ROLLING_HILLS
+/// };
+/// let generator = presets::rolling_hills_with(42, knobs);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Knobs {
+    /// The size of the largest features, in units of the input space.
+    pub scale: f64,
+    /// The height in `[-1, 1]` below which the terrain is flattened into a sea. For
+    /// [`caves3d()`], the density in `[-1, 1]` below which space is open, such that raising it
+    /// widens the tunnels.
+    pub sea_level: f64,
+    /// How strongly the finer details contribute relative to the larger features, in `(0, 1]`.
+    pub roughness: f64,
+}
+
+impl Knobs {
+    /// The default knobs of [`mountains()`].
+    pub const MOUNTAINS: Self = Self {
+        scale: 500.0,
+        sea_level: -0.6,
+        roughness: 0.5,
+    };
+
+    /// The default knobs of [`rolling_hills()`].
+    pub const ROLLING_HILLS: Self = Self {
+        scale: 600.0,
+        sea_level: -1.0,
+        roughness: 0.4,
+    };
+
+    /// The default knobs of [`archipelago()`].
+    pub const ARCHIPELAGO: Self = Self {
+        scale: 300.0,
+        sea_level: 0.0,
+        roughness: 0.5,
+    };
+
+    /// The default knobs of [`badlands()`].
+    pub const BADLANDS: Self = Self {
+        scale: 400.0,
+        sea_level: -1.0,
+        roughness: 0.55,
+    };
+
+    /// The default knobs of [`caves3d()`].
+    pub const CAVES: Self = Self {
+        scale: 60.0,
+        sea_level: -0.8,
+        roughness: 0.5,
+    };
+
+    fn validate(&self) {
+        assert!(
+            self.scale > 0.0 && self.scale.is_finite(),
+            "scale must be positive and finite, got {}",
+            self.scale
+        );
+        assert!(
+            (-1.0..=1.0).contains(&self.sea_level),
+            "sea_level must be within [-1, 1], got {}",
+            self.sea_level
+        );
+        assert!(
+            self.roughness > 0.0 && self.roughness <= 1.0,
+            "roughness must be within (0, 1], got {}",
+            self.roughness
+        );
+    }
+}
+
+// fractal noise with the given number of octaves, whose first octave has features of the given
+// size
+#[inline]
+fn fbm<const D: usize>(seed: u64, octaves: u32, scale: f64, roughness: f64) -> Fbm<D, Simplex<D>>
+where
+    Simplex<D>: Generator<D>,
+{
+    Source::simplex(seed).fbm(octaves, 1.0 / scale, 2.0, roughness)
+}
+
+// flattens the terrain below the sea level, and guards the range of the result
+#[inline]
+fn finish<G: Generator<2>>(terrain: G, sea_level: f64) -> Clamp<2, Max<2, G, Constant<2>>> {
+    terrain.max(Source::constant(sea_level)).clamp(-1.0, 1.0)
+}
+
+/// The generator returned by [`mountains()`].
+pub type Mountains = Clamp<
+    2,
+    Max<
+        2,
+        Displace<
+            2,
+            1,
+            Displace<
+                2,
+                0,
+                Blend<
+                    2,
+                    RidgedMulti<2, Simplex<2>>,
+                    Add<2, Mul<2, Fbm<2, Simplex<2>>>>,
+                    Fbm<2, Simplex<2>>,
+                >,
+                Mul<2, Fbm<2, Simplex<2>>>,
+            >,
+            Mul<2, Fbm<2, Simplex<2>>>,
+        >,
+        Constant<2>,
+    >,
+>;
+
+/// Creates a mountain range with sharp ridges rising from rounded foothills.
+///
+/// The ridges are ridged multifractal noise, which are blended with low fractal foothills by
+/// large-scale fractal noise, such that mountain ranges alternate with lowlands. The result is
+/// warped by fractal noise to break up the regularity of the ridges. Heights below the sea level
+/// are flattened. Uses the default knobs [`Knobs::MOUNTAINS`].
+///
+/// ![image](https://raw.githubusercontent.com/cookiephone/libnoise-rs/master/images/preset_mountains.png)
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{presets, Generator};
+/// let generator = presets::mountains(42);
+/// let height = generator.sample([120.0, 300.0]);
+/// ```
+pub fn mountains(seed: u64) -> Mountains {
+    mountains_with(seed, Knobs::MOUNTAINS)
+}
+
+/// Creates a mountain range as [`mountains()`] does, with the given knobs. The roughness
+/// controls both the foothills and how quickly the ridges fade into finer ridges.
+///
+/// # Panics
+///
+/// Panics if the knobs are out of range, see [`Knobs`].
+pub fn mountains_with(seed: u64, knobs: Knobs) -> Mountains {
+    knobs.validate();
+    let Knobs {
+        scale,
+        sea_level,
+        roughness,
+    } = knobs;
+    let ridges = Source::simplex(seed).ridgedmulti(6, 1.0 / scale, 2.0, 1.0 / roughness);
+    let foothills = fbm(seed.wrapping_add(1), 5, scale, roughness)
+        .mul(0.4)
+        .add(-0.3);
+    let ranges = fbm(seed.wrapping_add(2), 3, scale * 2.0, 0.5);
+    let warp_x = fbm(seed.wrapping_add(3), 3, scale, 0.5).mul(scale * 0.15);
+    let warp_y = fbm(seed.wrapping_add(4), 3, scale, 0.5).mul(scale * 0.15);
+    let terrain = ridges
+        .blend(foothills, ranges)
+        .displace_x(warp_x)
+        .displace_y(warp_y);
+    finish(terrain, sea_level)
+}
+
+/// The generator returned by [`rolling_hills()`].
+pub type RollingHills = Clamp<
+    2,
+    Max<
+        2,
+        Displace<2, 0, Add<2, Mul<2, Fbm<2, Simplex<2>>>>, Mul<2, Fbm<2, Simplex<2>>>>,
+        Constant<2>,
+    >,
+>;
+
+/// Creates gently rolling hills.
+///
+/// The hills are fractal noise of few octaves and low amplitude, warped along one axis by
+/// large-scale fractal noise, such that the hills form elongated, meandering chains. Heights
+/// below the sea level are flattened. Uses the default knobs [`Knobs::ROLLING_HILLS`], which
+/// place the sea below all hills.
+///
+/// ![image](https://raw.githubusercontent.com/cookiephone/libnoise-rs/master/images/preset_rolling_hills.png)
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{presets, Generator};
+/// let generator = presets::rolling_hills(42);
+/// let height = generator.sample([120.0, 300.0]);
+/// ```
+pub fn rolling_hills(seed: u64) -> RollingHills {
+    rolling_hills_with(seed, Knobs::ROLLING_HILLS)
+}
+
+/// Creates rolling hills as [`rolling_hills()`] does, with the given knobs.
+///
+/// # Panics
+///
+/// Panics if the knobs are out of range, see [`Knobs`].
+pub fn rolling_hills_with(seed: u64, knobs: Knobs) -> RollingHills {
+    knobs.validate();
+    let Knobs {
+        scale,
+        sea_level,
+        roughness,
+    } = knobs;
+    let hills = fbm(seed, 4, scale, roughness).mul(0.6).add(-0.1);
+    let warp = fbm(seed.wrapping_add(1), 2, scale * 2.0, 0.5).mul(scale * 0.5);
+    finish(hills.displace_x(warp), sea_level)
+}
+
+/// The generator returned by [`archipelago()`].
+pub type Archipelago = Clamp<
+    2,
+    Max<2, Add<2, Sum<2, Mul<2, Fbm<2, Simplex<2>>>, Mul<2, Scale<2, Worley<2>>>>>, Constant<2>>,
+>;
+
+/// Creates islands scattered across a sea.
+///
+/// Each island forms around a feature point of Worley noise, whose negated distance to the
+/// nearest feature point raises the terrain into round islands of varying size, while fractal
+/// noise shapes their coasts and occasionally merges or splits them. Heights below the sea
+/// level are flattened into the sea. Uses the default knobs [`Knobs::ARCHIPELAGO`].
+///
+/// ![image](https://raw.githubusercontent.com/cookiephone/libnoise-rs/master/images/preset_archipelago.png)
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{presets, Generator};
+/// let generator = presets::archipelago(42);
+/// let height = generator.sample([120.0, 300.0]);
+/// ```
+pub fn archipelago(seed: u64) -> Archipelago {
+    archipelago_with(seed, Knobs::ARCHIPELAGO)
+}
+
+/// Creates islands as [`archipelago()`] does, with the given knobs. The scale controls the
+/// spacing of the islands.
+///
+/// # Panics
+///
+/// Panics if the knobs are out of range, see [`Knobs`].
+pub fn archipelago_with(seed: u64, knobs: Knobs) -> Archipelago {
+    knobs.validate();
+    let Knobs {
+        scale,
+        sea_level,
+        roughness,
+    } = knobs;
+    let coasts = fbm(seed, 6, scale * 0.5, roughness).mul(0.45);
+    let islands = Source::worley(seed.wrapping_add(1))
+        .scale([1.0 / scale; 2])
+        .mul(-0.9);
+    finish(coasts.sum(islands).add(-0.05), sea_level)
+}
+
+/// The generator returned by [`badlands()`].
+pub type Badlands = Clamp<
+    2,
+    Max<
+        2,
+        Select<
+            2,
+            Add<2, Mul<2, Fbm<2, Simplex<2>>>>,
+            Sum<2, Clamp<2, Mul<2, Fbm<2, Simplex<2>>>>, Mul<2, RidgedMulti<2, Simplex<2>>>>,
+            Fbm<2, Simplex<2>>,
+        >,
+        Constant<2>,
+    >,
+>;
+
+/// Creates badlands of flat-topped mesas cut by steep canyons.
+///
+/// Steep fractal noise is clamped into plateaus and flat basins, whose surfaces are roughened
+/// by ridged multifractal noise of a smaller scale. Where fractal noise of a larger scale is
+/// close to zero, the terrain is replaced by low canyon floors, which carves a network of
+/// winding canyons with vertical walls. Heights below the sea level are flattened. Uses the
+/// default knobs [`Knobs::BADLANDS`], which place the sea below all canyons.
+///
+/// ![image](https://raw.githubusercontent.com/cookiephone/libnoise-rs/master/images/preset_badlands.png)
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{presets, Generator};
+/// let generator = presets::badlands(42);
+/// let height = generator.sample([120.0, 300.0]);
+/// ```
+pub fn badlands(seed: u64) -> Badlands {
+    badlands_with(seed, Knobs::BADLANDS)
+}
+
+/// Creates badlands as [`badlands()`] does, with the given knobs.
+///
+/// # Panics
+///
+/// Panics if the knobs are out of range, see [`Knobs`].
+pub fn badlands_with(seed: u64, knobs: Knobs) -> Badlands {
+    knobs.validate();
+    let Knobs {
+        scale,
+        sea_level,
+        roughness,
+    } = knobs;
+    let mesas = fbm(seed, 5, scale, roughness).mul(2.5).clamp(-0.3, 0.5);
+    let erosion = Source::simplex(seed.wrapping_add(1))
+        .ridgedmulti(4, 4.0 / scale, 2.0, 1.0 / roughness)
+        .mul(0.12);
+    let floors = fbm(seed.wrapping_add(2), 3, scale * 0.5, 0.5)
+        .mul(0.1)
+        .add(-0.7);
+    let canyons = fbm(seed.wrapping_add(3), 4, scale * 1.5, 0.5);
+    let terrain = floors.select(mesas.sum(erosion), canyons, -0.04, 0.04);
+    finish(terrain, sea_level)
+}
+
+/// The generator returned by [`caves3d()`].
+pub type Caves3D =
+    Clamp<3, Add<3, Mul<3, Max<3, Abs<3, Fbm<3, Simplex<3>>>, Abs<3, Fbm<3, Simplex<3>>>>>>>;
+
+/// Creates a 3-dimensional density field of interconnected cave tunnels.
+///
+/// Tunnels form along the curves where two independent fractal noise fields are both close to
+/// zero, which produces long, winding, and branching tunnels reminiscent of the caves of voxel
+/// games. The result is a density, which is negative within the tunnels and positive in solid
+/// rock, and is meant to be sampled with [`fill_density()`] or on a [`NoiseBuffer`]. The sea
+/// level of the knobs is the density threshold of the tunnels, such that raising it widens
+/// them. Uses the default knobs [`Knobs::CAVES`].
+///
+/// ![image](https://raw.githubusercontent.com/cookiephone/libnoise-rs/master/images/preset_caves3d.png)
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{presets, Generator};
+/// let generator = presets::caves3d(42);
+/// let is_open = generator.sample([12.0, -30.0, 7.5]) < 0.0;
+/// ```
+///
+/// [`fill_density()`]: crate::Generator3D::fill_density
+/// [`NoiseBuffer`]: crate::NoiseBuffer
+pub fn caves3d(seed: u64) -> Caves3D {
+    caves3d_with(seed, Knobs::CAVES)
+}
+
+/// Creates cave tunnels as [`caves3d()`] does, with the given knobs.
+///
+/// # Panics
+///
+/// Panics if the knobs are out of range, see [`Knobs`].
+pub fn caves3d_with(seed: u64, knobs: Knobs) -> Caves3D {
+    knobs.validate();
+    let Knobs {
+        scale,
+        sea_level,
+        roughness,
+    } = knobs;
+    let first = fbm::<3>(seed, 3, scale, roughness).abs();
+    let second = fbm::<3>(seed.wrapping_add(1), 3, scale, roughness).abs();
+    // the larger absolute value is at most 1, which is mapped to the density 1
+    first
+        .max(second)
+        .mul(2.0)
+        .add(-1.0 - sea_level)
+        .clamp(-1.0, 1.0)
+}
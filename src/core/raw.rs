@@ -0,0 +1,166 @@
+//! Low-level noise functions without the generator machinery.
+//!
+//! This module exposes the functions underlying the gradient and lattice sources as plain
+//! functions of a seed and the coordinates of a point, for use in code which brings its own
+//! composition layer and has no need for [`Generator`] and its adapters. For a given seed, each
+//! function produces bit-identical results to sampling the corresponding source created through
+//! [`Source`], such that `raw::simplex2d(seed, x, y)` equals
+//! `Source::simplex(seed).sample([x, y])`.
+//!
+//! Every source shuffles a permutation table from its seed, which takes considerably longer than
+//! evaluating the noise once. The functions taking a seed build this table on every call, and are
+//! thus only suitable for sampling a few points. When sampling many points, build the table once
+//! using [`build_permutation_table()`] and pass it to the `_with_table` variants instead, which
+//! leaves its lifetime and sharing up to the caller.
+//!
+//! # Stability
+//!
+//! The signatures of this module are part of the public API and follow semantic versioning like
+//! the rest of the crate. Beyond that, their results are covered by the same guarantee as the
+//! sources under [Determinism](crate#determinism): for a given seed or table and a given point,
+//! the result is bit-identical across platforms and will not change in minor or patch releases.
+//! This includes the simplex noise beyond 4 dimensions and with custom kernel radii, which only
+//! use exactly rounded arithmetic. None of the functions in this module are among the exceptions
+//! listed there.
+//! The contents of [`PermutationTable`] are intentionally opaque and may change.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! # use libnoise::{raw, Source, Generator};
+//! let table = raw::build_permutation_table(42);
+//! let value = raw::simplex2d_with_table(&table, 0.2, 0.5);
+//!
+//! assert_eq!(value, raw::simplex2d(42, 0.2, 0.5));
+//! assert_eq!(value, Source::simplex(42).sample([0.2, 0.5]));
+//! ```
+//!
+//! [`Generator`]: crate::Generator
+//! [`Source`]: crate::Source
+
+use crate::core::sources::functional::{self, constants::PERMUTATION_TABLE_SIZE, simplex::Kernel};
+use crate::core::utils::ptable;
+
+/// A permutation table from which the noise functions of this module derive their randomness.
+///
+/// Create a table using [`build_permutation_table()`]. The table is immutable once built, and can
+/// be shared freely, for example between threads.
+#[derive(Clone, Debug)]
+pub struct PermutationTable {
+    inner: ptable::PermutationTable,
+}
+
+impl PermutationTable {
+    /// Returns the seed from which the table was built.
+    #[inline]
+    pub fn seed(&self) -> u64 {
+        self.inner.seed
+    }
+}
+
+/// Builds the permutation table for the given `seed`, as used by all sources created through
+/// [`Source`] with that seed.
+///
+/// [`Source`]: crate::Source
+pub fn build_permutation_table(seed: u64) -> PermutationTable {
+    PermutationTable {
+        inner: ptable::PermutationTable::new(seed, PERMUTATION_TABLE_SIZE, true),
+    }
+}
+
+macro_rules! impl_raw_noise {
+    (
+        $name:ident,
+        $name_with_table:ident,
+        $source:literal,
+        $dim:literal,
+        [$($coordinate:ident),+],
+        $noise:expr
+    ) => {
+        #[doc = concat!(
+            "Samples ", $dim, "-dimensional ", $source, " noise seeded with `seed` at the given ",
+            "point.\n\nEquivalent to sampling `Source::", $source, "(seed)`. This builds a ",
+            "permutation table on every call, see [`", stringify!($name_with_table), "()`] for ",
+            "sampling many points."
+        )]
+        #[inline]
+        pub fn $name(seed: u64, $($coordinate: f64),+) -> f64 {
+            $name_with_table(&build_permutation_table(seed), $($coordinate),+)
+        }
+
+        #[doc = concat!(
+            "Samples ", $dim, "-dimensional ", $source, " noise derived from `table` at the ",
+            "given point.\n\nEquivalent to sampling `Source::", $source, "(seed)`, where `seed` ",
+            "is the seed from which `table` was built."
+        )]
+        #[inline]
+        pub fn $name_with_table(table: &PermutationTable, $($coordinate: f64),+) -> f64 {
+            ($noise)(&table.inner, [$($coordinate),+])
+        }
+    };
+}
+
+impl_raw_noise! {
+    simplex1d, simplex1d_with_table, "simplex", 1, [x],
+    |perm, point| functional::simplex::noise1d(perm, Kernel::DEFAULT, point)
+}
+impl_raw_noise! {
+    simplex2d, simplex2d_with_table, "simplex", 2, [x, y],
+    |perm, point| functional::simplex::noise2d(perm, Kernel::DEFAULT, point)
+}
+impl_raw_noise! {
+    simplex3d, simplex3d_with_table, "simplex", 3, [x, y, z],
+    |perm, point| functional::simplex::noise3d(perm, Kernel::DEFAULT, point)
+}
+impl_raw_noise! {
+    simplex4d, simplex4d_with_table, "simplex", 4, [x, y, z, w],
+    |perm, point| functional::simplex::noise4d(perm, Kernel::DEFAULT, point)
+}
+
+/// Samples `D`-dimensional simplex noise seeded with `seed` at the given point.
+///
+/// Equivalent to sampling `Source::simplex(seed)` with `D` dimensions, and thus to
+/// [`simplex2d()`] through [`simplex4d()`] for 2 to 4 dimensions. Higher dimensions traverse the
+/// simplicial lattice generically, which allows sampling 5 or more dimensions, for example to
+/// tile noise along several axes at once. This builds a permutation table on every call, see
+/// [`simplex_with_table()`] for sampling many points.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{raw, Source, Generator};
+/// let value = raw::simplex(42, [0.2, 0.5, 0.3, 0.1, 0.7, 0.4]);
+/// assert_eq!(value, Source::simplex(42).sample([0.2, 0.5, 0.3, 0.1, 0.7, 0.4]));
+///
+/// assert_eq!(raw::simplex(42, [0.2, 0.5]), raw::simplex2d(42, 0.2, 0.5));
+/// ```
+#[inline]
+pub fn simplex<const D: usize>(seed: u64, point: [f64; D]) -> f64 {
+    simplex_with_table(&build_permutation_table(seed), point)
+}
+
+/// Samples `D`-dimensional simplex noise derived from `table` at the given point.
+///
+/// Equivalent to sampling `Source::simplex(seed)` with `D` dimensions, where `seed` is the seed
+/// from which `table` was built. For further detail see [`simplex()`].
+#[inline]
+pub fn simplex_with_table<const D: usize>(table: &PermutationTable, point: [f64; D]) -> f64 {
+    functional::simplex::noise(&table.inner, Kernel::DEFAULT, false, point)
+}
+
+impl_raw_noise! { perlin1d, perlin1d_with_table, "perlin", 1, [x], functional::perlin::noise1d }
+impl_raw_noise! { perlin2d, perlin2d_with_table, "perlin", 2, [x, y], functional::perlin::noise2d }
+impl_raw_noise! { perlin3d, perlin3d_with_table, "perlin", 3, [x, y, z], functional::perlin::noise3d }
+impl_raw_noise! { perlin4d, perlin4d_with_table, "perlin", 4, [x, y, z, w], functional::perlin::noise4d }
+impl_raw_noise! { value1d, value1d_with_table, "value", 1, [x], functional::value::noise1d }
+impl_raw_noise! { value2d, value2d_with_table, "value", 2, [x, y], functional::value::noise2d }
+impl_raw_noise! { value3d, value3d_with_table, "value", 3, [x, y, z], functional::value::noise3d }
+impl_raw_noise! { value4d, value4d_with_table, "value", 4, [x, y, z, w], functional::value::noise4d }
+impl_raw_noise! { worley1d, worley1d_with_table, "worley", 1, [x], functional::worley::noise1d }
+impl_raw_noise! { worley2d, worley2d_with_table, "worley", 2, [x, y], functional::worley::noise2d }
+impl_raw_noise! { worley3d, worley3d_with_table, "worley", 3, [x, y, z], functional::worley::noise3d }
+impl_raw_noise! { worley4d, worley4d_with_table, "worley", 4, [x, y, z, w], functional::worley::noise4d }
@@ -0,0 +1,1360 @@
+//! Declarative descriptions of generators which can be built at runtime.
+//!
+//! A [`NoiseRecipe`] describes a chain of sources and adapters as a tree of plain data, which
+//! can be constructed in code or, with the `serde` feature enabled, loaded from any format
+//! supported by serde, such as JSON or RON. This allows tweaking generators without recompiling,
+//! for example in a level editor or from configuration files. Every node of the tree is tagged
+//! with its `type`, named after the [`Source`] function or [`Generator`] method creating the
+//! respective generator, and takes the same parameters:
+//!
+//! ```json
+//! {
+//!     "type": "clamp",
+//!     "source": {
+//!         "type": "fbm",
+//!         "source": { "type": "simplex", "seed": 42 },
+//!         "octaves": 6,
+//!         "frequency": 0.013,
+//!         "lacunarity": 2.0,
+//!         "persistence": 0.5
+//!     },
+//!     "min": -0.5,
+//!     "max": 0.5
+//! }
+//! ```
+//!
+//! Nodes wrapping a single generator name it `source`, nodes combining two generators name them
+//! `a` and `b`, and nodes controlled by a third generator name it `control`. Recipes are built
+//! for a given dimensionality using [`NoiseRecipe::build()`], which validates the parameters of
+//! every node. Unknown node types are rejected by serde when deserializing.
+//!
+//! [`Source`]: crate::Source
+
+use crate::core::{
+    adapters::{
+        fractal::{self, Decay},
+        Abs, Add, Billow, Blend, Checked, Clamp, Displace, Exp, Fbm, FbmLod, Max, Min, Mul, Neg,
+        Pow, Power, Product, RidgedMulti, Rotate, Scale, Select, Sum, Translate,
+    },
+    error::Error,
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D, SampleGenerator},
+    sources::{
+        functional, Blue, Checkerboard, Constant, DistanceField, FalloffCurve, ImprovedPerlin,
+        Island, IslandRect, LinearGradient, OpenSimplex2, OpenSimplex2S, Perlin, RadialGradient,
+        Simplex, Spot, SpotKernel, Value, Wavelet, White, Worley, WorleyReturnType,
+    },
+    utils::{
+        describe::{impl_display, Description},
+        interval,
+    },
+};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{any::Any, fmt};
+
+/// A declarative description of a generator.
+///
+/// For details, see the [module-level documentation](self).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{recipe::NoiseRecipe, Source, Generator};
+/// let simplex = NoiseRecipe::Simplex { seed: 42, large_coordinates: false, r_squared: None };
+/// let recipe = NoiseRecipe::Fbm {
+///     source: Box::new(simplex),
+///     octaves: 6,
+///     frequency: 0.013,
+///     lacunarity: 2.0,
+///     persistence: 0.5,
+/// };
+/// let generator = recipe.build::<2>().unwrap();
+///
+/// let expected = Source::simplex(42).fbm(6, 0.013, 2.0, 0.5);
+/// assert_eq!(generator.sample([0.2, 0.5]), expected.sample([0.2, 0.5]));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+#[non_exhaustive]
+pub enum NoiseRecipe {
+    /// See [`Source::constant()`](crate::Source::constant).
+    Constant { value: f64 },
+    /// See [`Source::simplex()`](crate::Source::simplex) and
+    /// [`Source::simplex_with_radius()`](crate::Source::simplex_with_radius). `r_squared`, if
+    /// given, must be within `[0.01, 0.5]`, and defaults to 0.5.
+    Simplex {
+        seed: u64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        large_coordinates: bool,
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        r_squared: Option<f64>,
+    },
+    /// See [`Source::value()`](crate::Source::value).
+    Value {
+        seed: u64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        large_coordinates: bool,
+    },
+    /// See [`Source::perlin()`](crate::Source::perlin).
+    Perlin {
+        seed: u64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        large_coordinates: bool,
+    },
+    /// See [`Source::improved_perlin()`](crate::Source::improved_perlin).
+    ImprovedPerlin {
+        seed: u64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        large_coordinates: bool,
+    },
+    /// See [`Source::open_simplex2()`](crate::Source::open_simplex2). Only supports 2 and 3
+    /// dimensions.
+    OpenSimplex2 { seed: u64 },
+    /// See [`Source::open_simplex2s()`](crate::Source::open_simplex2s). Only supports 2 and 3
+    /// dimensions.
+    #[cfg_attr(feature = "serde", serde(rename = "open_simplex2s"))]
+    OpenSimplex2S { seed: u64 },
+    /// See [`Source::worley()`](crate::Source::worley). The weights of a weighted `return_type`
+    /// must be finite.
+    Worley {
+        seed: u64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        large_coordinates: bool,
+        #[cfg_attr(feature = "serde", serde(default))]
+        return_type: WorleyReturnType,
+    },
+    /// See [`Source::checkerboard()`](crate::Source::checkerboard). `period`, if given, must
+    /// have one positive and finite component per dimension, and defaults to cells of unit
+    /// size.
+    Checkerboard {
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        period: Option<Vec<f64>>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        smooth: bool,
+    },
+    /// See [`Source::white()`](crate::Source::white).
+    White { seed: u64 },
+    /// See [`Source::spot()`](crate::Source::spot). `radius` must be positive and finite, and
+    /// `amplitude_jitter` must be within `[0, 1]`.
+    Spot {
+        seed: u64,
+        kernel: SpotKernel,
+        radius: f64,
+        density: u32,
+        amplitude_jitter: f64,
+    },
+    /// See [`Source::wavelet()`](crate::Source::wavelet). `tile_size` must be a positive even
+    /// number.
+    Wavelet { seed: u64, tile_size: usize },
+    /// See [`Source::blue()`](crate::Source::blue). Only supports 2 dimensions, and `resolution`
+    /// must be at least 1.
+    Blue {
+        seed: u64,
+        resolution: usize,
+        #[cfg_attr(feature = "serde", serde(default))]
+        interpolate: bool,
+    },
+    /// See [`Source::distance_field()`](crate::Source::distance_field). `points` must not be
+    /// empty, the length of every point must equal the dimensionality, all coordinates must be
+    /// finite, and `max_distance` must be positive and finite.
+    DistanceField {
+        points: Vec<Vec<f64>>,
+        max_distance: f64,
+    },
+    /// See [`Source::linear_gradient()`](crate::Source::linear_gradient). The lengths of `from`
+    /// and `to` must equal the dimensionality, and both must be finite and distinct.
+    LinearGradient { from: Vec<f64>, to: Vec<f64> },
+    /// See [`Source::radial_gradient()`](crate::Source::radial_gradient). The length of `center`
+    /// must equal the dimensionality, `inner_radius` must be non-negative, and `outer_radius`
+    /// must exceed it. All parameters must be finite.
+    RadialGradient {
+        center: Vec<f64>,
+        inner_radius: f64,
+        outer_radius: f64,
+    },
+    /// See [`Source::island()`](crate::Source::island). Only supports 2 dimensions. The length
+    /// of `center` must be 2, `radius` must be positive, and the exponent of
+    /// [`FalloffCurve::Exponent`] must be positive. All parameters must be finite.
+    Island {
+        center: Vec<f64>,
+        radius: f64,
+        falloff: FalloffCurve,
+    },
+    /// See [`Source::island_rect()`](crate::Source::island_rect). Only supports 2 dimensions.
+    /// The lengths of `min` and `max` must be 2, `max` must exceed `min` along every axis, and
+    /// the exponent of [`FalloffCurve::Exponent`] must be positive. All parameters must be
+    /// finite.
+    IslandRect {
+        min: Vec<f64>,
+        max: Vec<f64>,
+        falloff: FalloffCurve,
+    },
+    /// See [`Generator::scale()`]. The length of `scale` must equal the dimensionality.
+    Scale {
+        source: Box<NoiseRecipe>,
+        scale: Vec<f64>,
+    },
+    /// See [`Generator::translate()`]. The length of `translation` must equal the
+    /// dimensionality.
+    Translate {
+        source: Box<NoiseRecipe>,
+        translation: Vec<f64>,
+    },
+    /// A chain of [`Scale`](NoiseRecipe::Scale) and [`Translate`](NoiseRecipe::Translate) nodes
+    /// fused into a single node, as produced by [`NoiseRecipe::optimize()`]. The steps are applied
+    /// to the input point in order, such that the first step corresponds to the outermost node
+    /// of the chain. The length of every step must equal the dimensionality.
+    Transform {
+        source: Box<NoiseRecipe>,
+        steps: Vec<TransformStep>,
+    },
+    /// See [`Generator2D::rotate()`] and its equivalents for 3 and 4 dimensions. The length of
+    /// `rotation` must be 1, 3, or 6 for 2, 3, or 4 dimensions respectively.
+    Rotate {
+        source: Box<NoiseRecipe>,
+        rotation: Vec<f64>,
+    },
+    /// See [`Generator2D::displace_x()`] and its equivalents for other axes and dimensions,
+    /// where `axis` is the index of the displaced axis.
+    Displace {
+        source: Box<NoiseRecipe>,
+        displacement: Box<NoiseRecipe>,
+        axis: usize,
+    },
+    /// See [`Generator::neg()`].
+    Neg { source: Box<NoiseRecipe> },
+    /// See [`Generator::abs()`].
+    Abs { source: Box<NoiseRecipe> },
+    /// See [`Generator::exp()`].
+    Exp { source: Box<NoiseRecipe> },
+    /// See [`Generator::add()`].
+    Add {
+        source: Box<NoiseRecipe>,
+        offset: f64,
+    },
+    /// See [`Generator::mul()`].
+    Mul {
+        source: Box<NoiseRecipe>,
+        scale: f64,
+    },
+    /// See [`Generator::powi()`].
+    Powi {
+        source: Box<NoiseRecipe>,
+        exponent: i32,
+    },
+    /// See [`Generator::powf()`].
+    Powf {
+        source: Box<NoiseRecipe>,
+        exponent: f64,
+    },
+    /// See [`Generator::clamp()`].
+    Clamp {
+        source: Box<NoiseRecipe>,
+        min: f64,
+        max: f64,
+    },
+    /// See [`Generator::checked()`].
+    Checked {
+        source: Box<NoiseRecipe>,
+        min: f64,
+        max: f64,
+    },
+    /// See [`Generator::sum()`].
+    Sum {
+        a: Box<NoiseRecipe>,
+        b: Box<NoiseRecipe>,
+    },
+    /// See [`Generator::product()`].
+    Product {
+        a: Box<NoiseRecipe>,
+        b: Box<NoiseRecipe>,
+    },
+    /// See [`Generator::min()`].
+    Min {
+        a: Box<NoiseRecipe>,
+        b: Box<NoiseRecipe>,
+    },
+    /// See [`Generator::max()`].
+    Max {
+        a: Box<NoiseRecipe>,
+        b: Box<NoiseRecipe>,
+    },
+    /// See [`Generator::power()`].
+    Power {
+        a: Box<NoiseRecipe>,
+        b: Box<NoiseRecipe>,
+    },
+    /// See [`Generator::blend()`].
+    Blend {
+        a: Box<NoiseRecipe>,
+        b: Box<NoiseRecipe>,
+        control: Box<NoiseRecipe>,
+    },
+    /// See [`Generator::select()`].
+    Select {
+        a: Box<NoiseRecipe>,
+        b: Box<NoiseRecipe>,
+        control: Box<NoiseRecipe>,
+        selection_min: f64,
+        selection_max: f64,
+    },
+    /// See [`Generator::fbm()`]. `octaves` must be at least 1.
+    Fbm {
+        source: Box<NoiseRecipe>,
+        octaves: u32,
+        frequency: f64,
+        lacunarity: f64,
+        persistence: f64,
+    },
+    /// See [`Generator::billow()`]. `octaves` must be at least 1.
+    Billow {
+        source: Box<NoiseRecipe>,
+        octaves: u32,
+        frequency: f64,
+        lacunarity: f64,
+        persistence: f64,
+    },
+    /// See [`Generator::ridgedmulti()`]. `octaves` must be at least 1.
+    #[cfg_attr(feature = "serde", serde(rename = "ridgedmulti"))]
+    RidgedMulti {
+        source: Box<NoiseRecipe>,
+        octaves: u32,
+        frequency: f64,
+        lacunarity: f64,
+        attenuation: f64,
+    },
+    /// See [`Generator::fbm_lod()`]. `octaves` must be at least 1.
+    FbmLod {
+        source: Box<NoiseRecipe>,
+        octaves: u32,
+        frequency: f64,
+        lacunarity: f64,
+        persistence: f64,
+    },
+    /// See [`FbmLod::distance_lod()`]. `octaves` must be at least 1, and the length of `focus`
+    /// must equal the dimensionality.
+    DistanceLod {
+        source: Box<NoiseRecipe>,
+        octaves: u32,
+        frequency: f64,
+        lacunarity: f64,
+        persistence: f64,
+        focus: Vec<f64>,
+        near: f64,
+    },
+}
+
+/// A step of a [`NoiseRecipe::Transform`] node.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+pub enum TransformStep {
+    /// Multiplies the point by the given factors, see [`Generator::scale()`].
+    Scale(Vec<f64>),
+    /// Adds the given translation to the point, see [`Generator::translate()`].
+    Translate(Vec<f64>),
+}
+
+/// The error type for building a [`NoiseRecipe`].
+///
+/// Every variant names the offending node by its type and its path in the recipe tree. The path
+/// consists of the field names leading from the root to the node, separated by dots, and is
+/// empty for the root node.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum RecipeError {
+    /// The node does not support the requested dimensionality.
+    UnsupportedDimension {
+        /// The path of the offending node.
+        path: String,
+        /// The type of the offending node.
+        node: &'static str,
+        /// The requested dimensionality.
+        dimension: usize,
+    },
+    /// A parameter of the node has the wrong length for the requested dimensionality.
+    LengthMismatch {
+        /// The path of the offending node.
+        path: String,
+        /// The type of the offending node.
+        node: &'static str,
+        /// The name of the offending parameter.
+        parameter: &'static str,
+        /// The expected length.
+        expected: usize,
+        /// The length which was actually provided.
+        found: usize,
+    },
+    /// A parameter of the node has an invalid value.
+    InvalidParameter {
+        /// The path of the offending node.
+        path: String,
+        /// The type of the offending node.
+        node: &'static str,
+        /// The name of the offending parameter.
+        parameter: &'static str,
+        /// The reason why the value is invalid.
+        reason: &'static str,
+    },
+    /// The node cannot be represented by a recipe, such as a closure or a custom generator.
+    ///
+    /// This is only returned by [`Generator::to_recipe()`], in which case the type of the node
+    /// is the name of the offending generator type.
+    Unrepresentable {
+        /// The path of the offending node.
+        path: String,
+        /// The type of the offending node.
+        node: &'static str,
+    },
+}
+
+impl RecipeError {
+    /// Returns the path of the offending node.
+    pub fn path(&self) -> &str {
+        match self {
+            Self::UnsupportedDimension { path, .. }
+            | Self::LengthMismatch { path, .. }
+            | Self::InvalidParameter { path, .. }
+            | Self::Unrepresentable { path, .. } => path,
+        }
+    }
+
+    /// Returns the type of the offending node.
+    pub fn node(&self) -> &'static str {
+        match self {
+            Self::UnsupportedDimension { node, .. }
+            | Self::LengthMismatch { node, .. }
+            | Self::InvalidParameter { node, .. }
+            | Self::Unrepresentable { node, .. } => node,
+        }
+    }
+
+    // moves the offending node below the given field of a parent node
+    pub(crate) fn within(mut self, field: &str) -> Self {
+        let (Self::UnsupportedDimension { path, .. }
+        | Self::LengthMismatch { path, .. }
+        | Self::InvalidParameter { path, .. }
+        | Self::Unrepresentable { path, .. }) = &mut self;
+        *path = if path.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}.{}", field, path)
+        };
+        self
+    }
+}
+
+impl fmt::Display for RecipeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = match self.path() {
+            "" => "root",
+            path => path,
+        };
+        if let Self::Unrepresentable { node, .. } = self {
+            return write!(
+                f,
+                "{} node at {} cannot be represented by a recipe",
+                node, path
+            );
+        }
+        write!(f, "invalid {} node at {}: ", self.node(), path)?;
+        match self {
+            Self::UnsupportedDimension { dimension, .. } => {
+                write!(f, "{} dimensions are not supported", dimension)
+            }
+            Self::LengthMismatch {
+                parameter,
+                expected,
+                found,
+                ..
+            } => write!(
+                f,
+                "expected {} to have length {}, found {}",
+                parameter, expected, found
+            ),
+            Self::InvalidParameter {
+                parameter, reason, ..
+            } => write!(f, "{} {}", parameter, reason),
+            Self::Unrepresentable { .. } => Ok(()),
+        }
+    }
+}
+
+impl core::error::Error for RecipeError {}
+
+impl From<RecipeError> for Error {
+    fn from(error: RecipeError) -> Self {
+        match error {
+            RecipeError::LengthMismatch {
+                expected, found, ..
+            } => Self::ShapeMismatch {
+                expected: vec![expected],
+                found: vec![found],
+            },
+            RecipeError::InvalidParameter {
+                ref path,
+                node,
+                parameter,
+                reason,
+            } => Self::InvalidParameter {
+                name: parameter,
+                reason: match path.as_str() {
+                    "" => format!("{}, in {} node at root", reason, node),
+                    path => format!("{}, in {} node at {}", reason, node, path),
+                },
+            },
+            _ => Self::Unsupported {
+                reason: error.to_string(),
+            },
+        }
+    }
+}
+
+/// A generator built from a [`NoiseRecipe`].
+///
+/// This is created by [`NoiseRecipe::build()`], and wraps the built chain of sources and adapters
+/// behind a pointer, such that its type does not depend on the recipe. Like any other generator,
+/// it can be further modified using adapters.
+#[derive(Clone)]
+pub struct RecipeGenerator<const D: usize> {
+    generator: Box<dyn SampleGenerator<D>>,
+}
+
+impl<const D: usize> RecipeGenerator<D> {
+    fn new<G: Generator<D> + Clone + Send + Sync + 'static>(generator: G) -> Self {
+        Self {
+            generator: Box::new(generator),
+        }
+    }
+}
+
+impl<const D: usize> fmt::Debug for RecipeGenerator<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecipeGenerator").finish_non_exhaustive()
+    }
+}
+
+impl Generator1D for RecipeGenerator<1> {}
+impl Generator2D for RecipeGenerator<2> {}
+impl Generator3D for RecipeGenerator<3> {}
+impl Generator4D for RecipeGenerator<4> {}
+
+impl<const D: usize> Generator<D> for RecipeGenerator<D> {
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.generator.sample(point)
+    }
+
+    #[inline]
+    fn sample_lattice(&self, point: [i64; D]) -> f64 {
+        self.generator.sample_lattice(point)
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        self.generator.sample_interval(min, max)
+    }
+
+    fn describe(&self) -> Description {
+        self.generator.describe()
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        self.generator.to_recipe()
+    }
+}
+
+impl_display!([const D: usize] RecipeGenerator<D>);
+
+// the generator built from a transform node, which applies all of its steps before sampling
+// its source, rather than calling through a pointer for every step
+#[derive(Clone)]
+struct FusedTransform<const D: usize> {
+    source: RecipeGenerator<D>,
+    steps: Vec<Step<D>>,
+}
+
+#[derive(Clone, Copy)]
+enum Step<const D: usize> {
+    Scale([f64; D]),
+    Translate([f64; D]),
+}
+
+impl<const D: usize> Generator<D> for FusedTransform<D> {
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        // applies the steps exactly like the equivalent chain of adapters
+        let point = self.steps.iter().fold(point, |point, step| match step {
+            Step::Scale(scale) => core::array::from_fn(|i| point[i] * scale[i]),
+            Step::Translate(translation) => core::array::from_fn(|i| point[i] + translation[i]),
+        });
+        self.source.sample(point)
+    }
+
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        let (min, max) = self
+            .steps
+            .iter()
+            .fold((min, max), |(min, max), step| match step {
+                Step::Scale(scale) => interval::scale_box(min, max, *scale),
+                Step::Translate(translation) => (
+                    core::array::from_fn(|i| min[i] + translation[i]),
+                    core::array::from_fn(|i| max[i] + translation[i]),
+                ),
+            });
+        self.source.sample_interval(min, max)
+    }
+
+    fn describe(&self) -> Description {
+        let values = |values: &[f64]| {
+            values
+                .iter()
+                .map(f64::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let steps = self
+            .steps
+            .iter()
+            .map(|step| match step {
+                Step::Scale(scale) => format!("scale [{}]", values(scale)),
+                Step::Translate(translation) => format!("translate [{}]", values(translation)),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        Description::new("Transform")
+            .with_param("steps", steps)
+            .with_child("source", self.source.describe())
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Transform {
+            source: child("source", &self.source)?,
+            steps: self
+                .steps
+                .iter()
+                .map(|step| match step {
+                    Step::Scale(scale) => TransformStep::Scale(scale.to_vec()),
+                    Step::Translate(translation) => TransformStep::Translate(translation.to_vec()),
+                })
+                .collect(),
+        })
+    }
+}
+
+// converts a generator wrapped by an adapter into a recipe, placing offending nodes below the
+// given field of the adapter
+pub(crate) fn child<const D: usize, G: Generator<D>>(
+    field: &str,
+    generator: &G,
+) -> Result<Box<NoiseRecipe>, RecipeError> {
+    generator
+        .to_recipe()
+        .map(Box::new)
+        .map_err(|error| error.within(field))
+}
+
+// converts between generators of constant generic dimensionalities known to be equal, which
+// allows building generators only implemented for specific dimensionalities in generic code
+fn cast<const A: usize, const B: usize>(generator: RecipeGenerator<A>) -> RecipeGenerator<B> {
+    let generator: Box<dyn Any> = Box::new(generator);
+    *generator
+        .downcast()
+        .unwrap_or_else(|_| unreachable!("dimensionalities {} and {} differ", A, B))
+}
+
+// builds a generator for each of the supported dimensionalities, which default to 1 through 4,
+// binding the dimensionality to a constant of the given name
+macro_rules! per_dimension {
+    ($self:expr, $path:expr, $n:ident => $build:expr) => {
+        per_dimension!($self, $path, [1, 2, 3, 4], $n => $build)
+    };
+    ($self:expr, $path:expr, [$($dimension:literal),+], $n:ident => $build:expr) => {
+        match D {
+            $($dimension => {
+                const $n: usize = $dimension;
+                Ok(cast::<$n, D>(RecipeGenerator::new($build)))
+            })+
+            dimension => Err(RecipeError::UnsupportedDimension {
+                path: $path.to_string(),
+                node: $self.node(),
+                dimension,
+            }),
+        }
+    };
+}
+
+// builds a lattice source, optionally with large coordinate mode enabled
+macro_rules! lattice_source {
+    ($self:expr, $path:expr, $source:ident, $seed:expr, $large_coordinates:expr) => {
+        per_dimension!($self, $path, N => {
+            let source = $source::<N>::new(*$seed);
+            if *$large_coordinates {
+                source.with_large_coordinates()
+            } else {
+                source
+            }
+        })
+    };
+}
+
+impl NoiseRecipe {
+    /// Builds a `D`-dimensional generator from the recipe.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first offending node if a node does not support the
+    /// dimensionality `D`, a vector parameter has the wrong length, or a parameter is otherwise
+    /// invalid, such as a fractal node with 0 octaves.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{recipe::{NoiseRecipe, RecipeError}, Generator};
+    /// let simplex = NoiseRecipe::Simplex { seed: 42, large_coordinates: false, r_squared: None };
+    /// let recipe = NoiseRecipe::Scale {
+    ///     source: Box::new(simplex),
+    ///     scale: vec![0.05, 0.05],
+    /// };
+    ///
+    /// assert!(recipe.build::<2>().is_ok());
+    /// assert!(matches!(recipe.build::<3>(), Err(RecipeError::LengthMismatch { .. })));
+    /// ```
+    pub fn build<const D: usize>(&self) -> Result<RecipeGenerator<D>, RecipeError> {
+        self.build_node(String::new())
+    }
+
+    fn build_node<const D: usize>(&self, path: String) -> Result<RecipeGenerator<D>, RecipeError> {
+        let child = |field: &str, recipe: &NoiseRecipe| {
+            if path.is_empty() {
+                recipe.build_node::<D>(field.to_string())
+            } else {
+                recipe.build_node::<D>(format!("{}.{}", path, field))
+            }
+        };
+        match self {
+            Self::Constant { value } => Ok(RecipeGenerator::new(Constant::<D>::new(*value))),
+            Self::Simplex {
+                seed,
+                large_coordinates,
+                r_squared,
+            } => {
+                // simplex noise supports any dimensionality
+                let source = match r_squared {
+                    None => Simplex::<D>::new(*seed),
+                    Some(r_squared) => {
+                        if let Err((parameter, reason)) =
+                            functional::simplex::validate_r_squared(*r_squared)
+                        {
+                            return Err(self.invalid(&path, parameter, reason));
+                        }
+                        Simplex::<D>::new_with_radius(*seed, *r_squared).expect("radius is valid")
+                    }
+                };
+                if *large_coordinates {
+                    Ok(RecipeGenerator::new(source.with_large_coordinates()))
+                } else {
+                    Ok(RecipeGenerator::new(source))
+                }
+            }
+            Self::Value {
+                seed,
+                large_coordinates,
+            } => lattice_source!(self, path, Value, seed, large_coordinates),
+            Self::Perlin {
+                seed,
+                large_coordinates,
+            } => lattice_source!(self, path, Perlin, seed, large_coordinates),
+            Self::ImprovedPerlin {
+                seed,
+                large_coordinates,
+            } => lattice_source!(self, path, ImprovedPerlin, seed, large_coordinates),
+            Self::OpenSimplex2 { seed } => {
+                per_dimension!(self, path, [2, 3], N => OpenSimplex2::<N>::new(*seed))
+            }
+            Self::OpenSimplex2S { seed } => {
+                per_dimension!(self, path, [2, 3], N => OpenSimplex2S::<N>::new(*seed))
+            }
+            Self::Worley {
+                seed,
+                large_coordinates,
+                return_type,
+            } => {
+                if let Err((parameter, reason)) = return_type.validate() {
+                    return Err(self.invalid(&path, parameter, reason));
+                }
+                per_dimension!(self, path, N => {
+                    let source = Worley::<N>::new(*seed).with_return_type(*return_type);
+                    if *large_coordinates {
+                        source.with_large_coordinates()
+                    } else {
+                        source
+                    }
+                })
+            }
+            Self::Checkerboard { period, smooth } => {
+                if let Some(period) = period {
+                    if let Err((parameter, reason)) =
+                        functional::checkerboard::validate_period(period)
+                    {
+                        return Err(self.invalid(&path, parameter, reason));
+                    }
+                }
+                per_dimension!(self, path, N => {
+                    let source = Checkerboard::<N>::new().smooth(*smooth);
+                    match period {
+                        Some(period) => source.with_period(self.to_array(&path, "period", period)?),
+                        None => source,
+                    }
+                })
+            }
+            Self::White { seed } => per_dimension!(self, path, N => White::<N>::new(*seed)),
+            Self::Spot {
+                seed,
+                kernel,
+                radius,
+                density,
+                amplitude_jitter,
+            } => {
+                if !(*radius > 0.0 && radius.is_finite()) {
+                    return Err(self.invalid(&path, "radius", "must be positive and finite"));
+                }
+                if !(0.0..=1.0).contains(amplitude_jitter) {
+                    return Err(self.invalid(&path, "amplitude_jitter", "must be within [0, 1]"));
+                }
+                per_dimension!(self, path, N => Spot::<N>::new(*seed)
+                    .with_kernel(*kernel)
+                    .with_radius(*radius)
+                    .with_density(*density)
+                    .with_amplitude_jitter(*amplitude_jitter))
+            }
+            Self::Wavelet { seed, tile_size } => {
+                if *tile_size == 0 || !tile_size.is_multiple_of(2) {
+                    return Err(self.invalid(&path, "tile_size", "must be a positive even number"));
+                }
+                per_dimension!(self, path, N => Wavelet::<N>::new(*seed, *tile_size))
+            }
+            Self::Blue {
+                seed,
+                resolution,
+                interpolate,
+            } => {
+                if *resolution == 0 {
+                    return Err(self.invalid(&path, "resolution", "must be at least 1"));
+                }
+                if D != 2 {
+                    return Err(RecipeError::UnsupportedDimension {
+                        path: path.to_string(),
+                        node: self.node(),
+                        dimension: D,
+                    });
+                }
+                let source = Blue::new(*seed, *resolution);
+                let source = if *interpolate {
+                    source.with_interpolation()
+                } else {
+                    source
+                };
+                Ok(cast::<2, D>(RecipeGenerator::new(source)))
+            }
+            Self::DistanceField {
+                points,
+                max_distance,
+            } => {
+                if points.is_empty() {
+                    return Err(self.invalid(&path, "points", "must not be empty"));
+                }
+                if points.iter().flatten().any(|x| !x.is_finite()) {
+                    return Err(self.invalid(&path, "points", "must be finite"));
+                }
+                if !(*max_distance > 0.0 && max_distance.is_finite()) {
+                    return Err(self.invalid(&path, "max_distance", "must be positive and finite"));
+                }
+                per_dimension!(self, path, N => {
+                    let points = points
+                        .iter()
+                        .map(|point| self.to_array::<N>(&path, "points", point))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    DistanceField::<N>::new(points)
+                        .expect("point set is valid")
+                        .with_max_distance(*max_distance)
+                })
+            }
+            Self::LinearGradient { from, to } => {
+                if let Err((parameter, reason)) = functional::gradient::validate_linear(from, to) {
+                    return Err(self.invalid(&path, parameter, reason));
+                }
+                per_dimension!(self, path, N => LinearGradient::<N>::new(
+                    self.to_array(&path, "from", from)?,
+                    self.to_array(&path, "to", to)?,
+                )
+                .expect("gradient is valid"))
+            }
+            Self::RadialGradient {
+                center,
+                inner_radius,
+                outer_radius,
+            } => {
+                if let Err((parameter, reason)) =
+                    functional::gradient::validate_radial(center, *inner_radius, *outer_radius)
+                {
+                    return Err(self.invalid(&path, parameter, reason));
+                }
+                per_dimension!(self, path, N => RadialGradient::<N>::new(
+                    self.to_array(&path, "center", center)?,
+                    *inner_radius,
+                    *outer_radius,
+                )
+                .expect("gradient is valid"))
+            }
+            Self::Island {
+                center,
+                radius,
+                falloff,
+            } => {
+                if let Err((parameter, reason)) =
+                    functional::island::validate_round(center, *radius, *falloff)
+                {
+                    return Err(self.invalid(&path, parameter, reason));
+                }
+                if D != 2 {
+                    return Err(RecipeError::UnsupportedDimension {
+                        path: path.to_string(),
+                        node: self.node(),
+                        dimension: D,
+                    });
+                }
+                let center = self.to_array::<2>(&path, "center", center)?;
+                let source = Island::new(center, *radius, *falloff).expect("island is valid");
+                Ok(cast::<2, D>(RecipeGenerator::new(source)))
+            }
+            Self::IslandRect { min, max, falloff } => {
+                if let Err((parameter, reason)) =
+                    functional::island::validate_rect(min, max, *falloff)
+                {
+                    return Err(self.invalid(&path, parameter, reason));
+                }
+                if D != 2 {
+                    return Err(RecipeError::UnsupportedDimension {
+                        path: path.to_string(),
+                        node: self.node(),
+                        dimension: D,
+                    });
+                }
+                let source = IslandRect::new(
+                    self.to_array::<2>(&path, "min", min)?,
+                    self.to_array::<2>(&path, "max", max)?,
+                    *falloff,
+                )
+                .expect("island is valid");
+                Ok(cast::<2, D>(RecipeGenerator::new(source)))
+            }
+            Self::Scale { source, scale } => {
+                let scale = self.to_array(&path, "scale", scale)?;
+                Ok(RecipeGenerator::new(Scale::new(
+                    child("source", source)?,
+                    scale,
+                )))
+            }
+            Self::Translate {
+                source,
+                translation,
+            } => {
+                let translation = self.to_array(&path, "translation", translation)?;
+                Ok(RecipeGenerator::new(Translate::new(
+                    child("source", source)?,
+                    translation,
+                )))
+            }
+            Self::Transform { source, steps } => {
+                let steps = steps
+                    .iter()
+                    .map(|step| match step {
+                        TransformStep::Scale(scale) => {
+                            self.to_array(&path, "steps", scale).map(Step::Scale)
+                        }
+                        TransformStep::Translate(translation) => self
+                            .to_array(&path, "steps", translation)
+                            .map(Step::Translate),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(RecipeGenerator::new(FusedTransform {
+                    source: child("source", source)?,
+                    steps,
+                }))
+            }
+            Self::Rotate { source, rotation } => self.build_rotate(&path, source, rotation),
+            Self::Displace {
+                source,
+                displacement,
+                axis,
+            } => {
+                let source = child("source", source)?;
+                let displacement = child("displacement", displacement)?;
+                match *axis {
+                    0 if D > 0 => Ok(RecipeGenerator::new(Displace::<D, 0, _, _>::new(
+                        source,
+                        displacement,
+                    ))),
+                    1 if D > 1 => Ok(RecipeGenerator::new(Displace::<D, 1, _, _>::new(
+                        source,
+                        displacement,
+                    ))),
+                    2 if D > 2 => Ok(RecipeGenerator::new(Displace::<D, 2, _, _>::new(
+                        source,
+                        displacement,
+                    ))),
+                    3 if D > 3 => Ok(RecipeGenerator::new(Displace::<D, 3, _, _>::new(
+                        source,
+                        displacement,
+                    ))),
+                    _ => Err(self.invalid(&path, "axis", "must be less than the dimensionality")),
+                }
+            }
+            Self::Neg { source } => Ok(RecipeGenerator::new(Neg::new(child("source", source)?))),
+            Self::Abs { source } => Ok(RecipeGenerator::new(Abs::new(child("source", source)?))),
+            Self::Exp { source } => Ok(RecipeGenerator::new(Exp::new(child("source", source)?))),
+            Self::Add { source, offset } => Ok(RecipeGenerator::new(Add::new(
+                child("source", source)?,
+                *offset,
+            ))),
+            Self::Mul { source, scale } => Ok(RecipeGenerator::new(Mul::new(
+                child("source", source)?,
+                *scale,
+            ))),
+            Self::Powi { source, exponent } => Ok(RecipeGenerator::new(Pow::new(
+                child("source", source)?,
+                *exponent,
+            ))),
+            Self::Powf { source, exponent } => Ok(RecipeGenerator::new(Pow::new(
+                child("source", source)?,
+                *exponent,
+            ))),
+            Self::Clamp { source, min, max } => {
+                if min.is_nan() {
+                    return Err(self.invalid(&path, "min", "must not be NaN"));
+                }
+                if max.is_nan() || max < min {
+                    return Err(self.invalid(&path, "max", "must not be less than min"));
+                }
+                Ok(RecipeGenerator::new(Clamp::new(
+                    child("source", source)?,
+                    *min,
+                    *max,
+                )))
+            }
+            Self::Checked { source, min, max } => Ok(RecipeGenerator::new(Checked::new(
+                child("source", source)?,
+                *min,
+                *max,
+            ))),
+            Self::Sum { a, b } => Ok(RecipeGenerator::new(Sum::new(
+                child("a", a)?,
+                child("b", b)?,
+            ))),
+            Self::Product { a, b } => Ok(RecipeGenerator::new(Product::new(
+                child("a", a)?,
+                child("b", b)?,
+            ))),
+            Self::Min { a, b } => Ok(RecipeGenerator::new(Min::new(
+                child("a", a)?,
+                child("b", b)?,
+            ))),
+            Self::Max { a, b } => Ok(RecipeGenerator::new(Max::new(
+                child("a", a)?,
+                child("b", b)?,
+            ))),
+            Self::Power { a, b } => Ok(RecipeGenerator::new(Power::new(
+                child("a", a)?,
+                child("b", b)?,
+            ))),
+            Self::Blend { a, b, control } => Ok(RecipeGenerator::new(Blend::new(
+                child("a", a)?,
+                child("b", b)?,
+                child("control", control)?,
+            ))),
+            Self::Select {
+                a,
+                b,
+                control,
+                selection_min,
+                selection_max,
+            } => Ok(RecipeGenerator::new(Select::new(
+                child("a", a)?,
+                child("b", b)?,
+                child("control", control)?,
+                *selection_min,
+                *selection_max,
+            ))),
+            Self::Fbm {
+                source,
+                octaves,
+                frequency,
+                lacunarity,
+                persistence,
+            } => {
+                self.validate_fractal(
+                    &path,
+                    *octaves,
+                    *frequency,
+                    *lacunarity,
+                    Decay::Persistence(*persistence),
+                )?;
+                let source = child("source", source)?;
+                per_dimension!(self, path, N => Fbm::new(
+                    cast::<D, N>(source),
+                    *octaves,
+                    *frequency,
+                    *lacunarity,
+                    *persistence,
+                ))
+            }
+            Self::Billow {
+                source,
+                octaves,
+                frequency,
+                lacunarity,
+                persistence,
+            } => {
+                self.validate_fractal(
+                    &path,
+                    *octaves,
+                    *frequency,
+                    *lacunarity,
+                    Decay::Persistence(*persistence),
+                )?;
+                let source = child("source", source)?;
+                per_dimension!(self, path, N => Billow::new(
+                    cast::<D, N>(source),
+                    *octaves,
+                    *frequency,
+                    *lacunarity,
+                    *persistence,
+                ))
+            }
+            Self::RidgedMulti {
+                source,
+                octaves,
+                frequency,
+                lacunarity,
+                attenuation,
+            } => {
+                self.validate_fractal(
+                    &path,
+                    *octaves,
+                    *frequency,
+                    *lacunarity,
+                    Decay::Attenuation(*attenuation),
+                )?;
+                let source = child("source", source)?;
+                per_dimension!(self, path, N => RidgedMulti::new(
+                    cast::<D, N>(source),
+                    *octaves,
+                    *frequency,
+                    *lacunarity,
+                    *attenuation,
+                ))
+            }
+            Self::FbmLod {
+                source,
+                octaves,
+                frequency,
+                lacunarity,
+                persistence,
+            } => {
+                self.validate_fractal(
+                    &path,
+                    *octaves,
+                    *frequency,
+                    *lacunarity,
+                    Decay::Persistence(*persistence),
+                )?;
+                Ok(RecipeGenerator::new(FbmLod::new(
+                    child("source", source)?,
+                    *octaves,
+                    *frequency,
+                    *lacunarity,
+                    *persistence,
+                )))
+            }
+            Self::DistanceLod {
+                source,
+                octaves,
+                frequency,
+                lacunarity,
+                persistence,
+                focus,
+                near,
+            } => {
+                self.validate_fractal(
+                    &path,
+                    *octaves,
+                    *frequency,
+                    *lacunarity,
+                    Decay::Persistence(*persistence),
+                )?;
+                let focus = self.to_array(&path, "focus", focus)?;
+                let fbm = FbmLod::new(
+                    child("source", source)?,
+                    *octaves,
+                    *frequency,
+                    *lacunarity,
+                    *persistence,
+                );
+                Ok(RecipeGenerator::new(fbm.distance_lod(focus, *near)))
+            }
+        }
+    }
+
+    fn build_rotate<const D: usize>(
+        &self,
+        path: &str,
+        source: &NoiseRecipe,
+        rotation: &[f64],
+    ) -> Result<RecipeGenerator<D>, RecipeError> {
+        let expected = match D {
+            2 => 1,
+            3 => 3,
+            4 => 6,
+            dimension => {
+                return Err(RecipeError::UnsupportedDimension {
+                    path: path.to_string(),
+                    node: self.node(),
+                    dimension,
+                })
+            }
+        };
+        if rotation.len() != expected {
+            return Err(RecipeError::LengthMismatch {
+                path: path.to_string(),
+                node: self.node(),
+                parameter: "rotation",
+                expected,
+                found: rotation.len(),
+            });
+        }
+        let source_path = if path.is_empty() {
+            "source".to_string()
+        } else {
+            format!("{}.source", path)
+        };
+        let source = source.build_node::<D>(source_path)?;
+        Ok(match D {
+            2 => cast::<2, D>(RecipeGenerator::new(Rotate::<2, 1, _>::new(
+                cast::<D, 2>(source),
+                [rotation[0]],
+            ))),
+            3 => cast::<3, D>(RecipeGenerator::new(Rotate::<3, 3, _>::new(
+                cast::<D, 3>(source),
+                core::array::from_fn(|i| rotation[i]),
+            ))),
+            _ => cast::<4, D>(RecipeGenerator::new(Rotate::<4, 6, _>::new(
+                cast::<D, 4>(source),
+                core::array::from_fn(|i| rotation[i]),
+            ))),
+        })
+    }
+
+    // returns the name of the node, as used for the type tag when serializing
+    pub(crate) fn node(&self) -> &'static str {
+        match self {
+            Self::Constant { .. } => "constant",
+            Self::Simplex { .. } => "simplex",
+            Self::Value { .. } => "value",
+            Self::Perlin { .. } => "perlin",
+            Self::ImprovedPerlin { .. } => "improved_perlin",
+            Self::OpenSimplex2 { .. } => "open_simplex2",
+            Self::OpenSimplex2S { .. } => "open_simplex2s",
+            Self::Worley { .. } => "worley",
+            Self::Checkerboard { .. } => "checkerboard",
+            Self::White { .. } => "white",
+            Self::Spot { .. } => "spot",
+            Self::Wavelet { .. } => "wavelet",
+            Self::Blue { .. } => "blue",
+            Self::DistanceField { .. } => "distance_field",
+            Self::LinearGradient { .. } => "linear_gradient",
+            Self::RadialGradient { .. } => "radial_gradient",
+            Self::Island { .. } => "island",
+            Self::IslandRect { .. } => "island_rect",
+            Self::Scale { .. } => "scale",
+            Self::Translate { .. } => "translate",
+            Self::Transform { .. } => "transform",
+            Self::Rotate { .. } => "rotate",
+            Self::Displace { .. } => "displace",
+            Self::Neg { .. } => "neg",
+            Self::Abs { .. } => "abs",
+            Self::Exp { .. } => "exp",
+            Self::Add { .. } => "add",
+            Self::Mul { .. } => "mul",
+            Self::Powi { .. } => "powi",
+            Self::Powf { .. } => "powf",
+            Self::Clamp { .. } => "clamp",
+            Self::Checked { .. } => "checked",
+            Self::Sum { .. } => "sum",
+            Self::Product { .. } => "product",
+            Self::Min { .. } => "min",
+            Self::Max { .. } => "max",
+            Self::Power { .. } => "power",
+            Self::Blend { .. } => "blend",
+            Self::Select { .. } => "select",
+            Self::Fbm { .. } => "fbm",
+            Self::Billow { .. } => "billow",
+            Self::RidgedMulti { .. } => "ridgedmulti",
+            Self::FbmLod { .. } => "fbm_lod",
+            Self::DistanceLod { .. } => "distance_lod",
+        }
+    }
+
+    fn to_array<const D: usize>(
+        &self,
+        path: &str,
+        parameter: &'static str,
+        values: &[f64],
+    ) -> Result<[f64; D], RecipeError> {
+        values.try_into().map_err(|_| RecipeError::LengthMismatch {
+            path: path.to_string(),
+            node: self.node(),
+            parameter,
+            expected: D,
+            found: values.len(),
+        })
+    }
+
+    fn validate_fractal(
+        &self,
+        path: &str,
+        octaves: u32,
+        frequency: f64,
+        lacunarity: f64,
+        decay: Decay,
+    ) -> Result<(), RecipeError> {
+        fractal::validate(octaves, frequency, lacunarity, decay)
+            .map_err(|(parameter, reason, _)| self.invalid(path, parameter, reason))
+    }
+
+    fn invalid(&self, path: &str, parameter: &'static str, reason: &'static str) -> RecipeError {
+        RecipeError::InvalidParameter {
+            path: path.to_string(),
+            node: self.node(),
+            parameter,
+            reason,
+        }
+    }
+}
@@ -1,6 +1,19 @@
+use crate::core::adapters::Tileable2D;
+use crate::core::error::Error;
+#[cfg(feature = "image")]
+use crate::core::sources::ImageSource;
 use crate::core::sources::{
-    Checkerboard, Constant, Custom, ImprovedPerlin, Perlin, Simplex, Value, Worley,
+    Blue, Checkerboard, Constant, Custom, CustomWithState, DistanceField, FalloffCurve,
+    ImprovedPerlin, Island, IslandRect, LinearGradient, OpenSimplex2, OpenSimplex2S, Perlin,
+    RadialGradient, Simplex, Spot, Value, VectorSimplex, Wavelet, White, Worley,
 };
+#[cfg(target_has_atomic = "ptr")]
+use crate::core::sources::{BufferSource, CustomDyn};
+#[cfg(target_has_atomic = "ptr")]
+use crate::core::utils::noisebuf::NoiseBuffer;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::vec::Vec;
 
 /// A struct serving as entry point for building generators.
 ///
@@ -66,7 +79,10 @@ impl<const D: usize> Source<D> {
     /// with each point being assigned a pseudorandom n-dimensional gradient. This randomness is
     /// solely derived from the value of `seed`. The actual noise value is determined from the
     /// relative position of the input point in the simplex it resides in as well as the gradients
-    /// assigned to the simplex corners.
+    /// assigned to the simplex corners. Any dimensionality is supported, with dimensions beyond
+    /// 4 using a generic and somewhat slower implementation. As the simplices grow with the
+    /// dimensionality, so does the radius within which the corners contribute, such that every
+    /// point is reached by some corner. Beyond 10 dimensions, the output is clamped to [-1, 1].
     ///
     /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
     /// <strong>Note:</strong>
@@ -88,6 +104,65 @@ impl<const D: usize> Source<D> {
         Simplex::new(seed)
     }
 
+    /// Create a generator which produces n-dimensional simplex noise with a custom kernel radius.
+    ///
+    /// The created generator returns n-dimensional simplex noise as produced by [`simplex()`],
+    /// except that the gradients assigned to the simplex corners contribute within a sphere of
+    /// the squared radius `r_squared` around each corner, instead of 0.5. Smaller radii produce
+    /// noise with more isolated features. The output is rescaled according to the radius, such
+    /// that it retains the range of [`simplex()`]. Beyond 4 dimensions, where the default radius
+    /// grows with the dimensionality, the radius is scaled by the same factor.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if `r_squared` is not within `[0.01, 0.5]`. Beyond 0.5,
+    /// the noise would become discontinuous at the borders of the simplices.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::simplex_with_radius(42, 0.4).unwrap();
+    /// let value = generator.sample([0.2, 0.5]);
+    /// ```
+    ///
+    /// [`simplex()`]: Source::simplex
+    pub fn simplex_with_radius(seed: u64, r_squared: f64) -> Result<Simplex<D>, Error> {
+        Simplex::new_with_radius(seed, r_squared)
+    }
+
+    /// Create a vector generator which produces vectors of n-dimensional simplex noise.
+    ///
+    /// The created generator returns vectors with `M` components, each of which is simplex noise
+    /// in the range [-1, 1] like that of [`simplex()`], based on its own set of gradients. As the
+    /// components share the traversal of the simplicial lattice and the hashing of its corners,
+    /// sampling the generator is considerably cheaper than sampling `M` independent generators.
+    /// This makes it well suited for the offsets of [`warp()`] and for flow fields. The
+    /// implementation is the same for every dimensionality, such that the results differ from
+    /// those of [`simplex()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, VectorGenerator};
+    /// let generator = Source::vector_simplex(42);
+    /// let [dx, dy] = generator.sample([0.2, 0.5]);
+    ///
+    /// // warp simplex noise by the vectors
+    /// let warped = Source::simplex(43).warp(generator);
+    /// let value = warped.sample([0.2, 0.5]);
+    /// ```
+    ///
+    /// [`simplex()`]: Source::simplex
+    /// [`warp()`]: crate::Generator::warp
+    pub fn vector_simplex<const M: usize>(seed: u64) -> VectorSimplex<D, M> {
+        VectorSimplex::new(seed)
+    }
+
     /// Create a generator which produces n-dimensional value noise.
     ///
     /// The created generator returns n-dimensional value noise. Value noise subdivides the input
@@ -172,17 +247,99 @@ impl<const D: usize> Source<D> {
         ImprovedPerlin::new(seed)
     }
 
+    /// Create a generator which produces 2- or 3-dimensional OpenSimplex2 noise.
+    ///
+    /// The created generator returns 2- or 3-dimensional OpenSimplex2 noise, in its fast
+    /// variant. Like simplex noise, it assigns pseudorandom gradients derived from `seed` to the
+    /// vertices of a lattice, and sums the contributions of all vertices within a fixed radius of
+    /// the input point. It differs from [`simplex()`] in the choice of the lattice and gradients,
+    /// which reduces the visible lattice artifacts:
+    ///
+    /// - In 2D, the lattice of triangles is that of simplex noise, but the gradients are 24
+    ///   evenly spaced directions, none of which aligns with the edges of the triangles.
+    /// - In 3D, the lattice is the body-centered cubic lattice, rotated such that none of its axes
+    ///   aligns with the axes of the input. Its vertices are closer to uniformly distributed than
+    ///   those of the lattice of simplex noise. The gradients are 48 evenly spread directions.
+    ///
+    /// Unlike the reference implementation, no 4-dimensional variant is provided, such that
+    /// `OpenSimplex2<1>` and `OpenSimplex2<4>` do not implement [`Generator`](crate::Generator).
+    /// For 4-dimensional noise, such as looping 3-dimensional animations, use [`simplex()`]
+    /// instead.
+    ///
+    /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// OpenSimplex2 noise is expected to return a value in the range [-1, 1]. However, for
+    /// sufficiently large inputs (which typically are unreasonable), the skewing of the input may
+    /// overflow, resulting in the generator returning NaN instead.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::open_simplex2(42);
+    /// let value = generator.sample([0.2, 0.5]);
+    /// ```
+    ///
+    /// For the smoother variant with a larger radius, see [`open_simplex2s()`].
+    ///
+    /// [`simplex()`]: Source::simplex
+    /// [`open_simplex2s()`]: Source::open_simplex2s
+    pub fn open_simplex2(seed: u64) -> OpenSimplex2<D> {
+        OpenSimplex2::new(seed)
+    }
+
+    /// Create a generator which produces 2- or 3-dimensional smooth OpenSimplex2 noise.
+    ///
+    /// The created generator returns 2- or 3-dimensional smooth OpenSimplex2 noise, also known
+    /// as OpenSimplex2S. It uses the same lattices and gradients as [`open_simplex2()`], such
+    /// that the same `seed` assigns the same gradients, but evaluates every vertex within a larger
+    /// radius around the input point. Each vertex thus influences a larger area, and its
+    /// contribution falls off more gradually, which yields smoother noise with fewer bumps, at the
+    /// cost of evaluating more vertices per sample. This suits uses which differentiate the noise,
+    /// such as normal mapping of terrain.
+    ///
+    /// Like [`open_simplex2()`], only 2 and 3 dimensions are supported.
+    ///
+    /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// Smooth OpenSimplex2 noise is expected to return a value in the range [-1, 1]. However, for
+    /// sufficiently large inputs (which typically are unreasonable), the skewing of the input may
+    /// overflow, resulting in the generator returning NaN instead.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::open_simplex2s(42);
+    /// let value = generator.sample([0.2, 0.5, 0.7]);
+    /// ```
+    ///
+    /// [`open_simplex2()`]: Source::open_simplex2
+    pub fn open_simplex2s(seed: u64) -> OpenSimplex2S<D> {
+        OpenSimplex2S::new(seed)
+    }
+
     /// Create a generator which produces n-dimensional worley noise.
     ///
     /// The created generator returns n-dimensional worley noise (also called cell noise, cellular
     /// noise, voronoi noise). The noise is computed by dividing the input space into a grid lattice.
     /// Each hypercube is assigned a pseudorandom point that lies within it. This randomness is solely
     /// derived from the value of `seed`. For a given input point, the noise value is determined by
-    /// computing the euclidean (L2) distance to the nearest such point.
+    /// computing the euclidean (L2) distance to the nearest such point. Alternatively, the distance
+    /// to the nearest border between the cells of these points, the distance to the second nearest
+    /// point, their difference, or a weighted sum of the distances can be returned, which can be
+    /// selected using [`with_return_type()`].
     ///
     /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
     /// <strong>Note:</strong>
-    /// Worley noise is expected to return a value in the range [-1, 1].
+    /// Worley noise is expected to return a value in the range [-1, 1], unless a weighted sum of
+    /// the distances is returned.
     /// </p>
     ///
     /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
@@ -202,10 +359,262 @@ impl<const D: usize> Source<D> {
     /// let generator = Source::worley(42);
     /// let value = generator.sample([0.2, 0.5]);
     /// ```
+    ///
+    /// [`with_return_type()`]: Worley::with_return_type
     pub fn worley(seed: u64) -> Worley<D> {
         Worley::new(seed)
     }
 
+    /// Create a generator which produces n-dimensional wavelet noise.
+    ///
+    /// The created generator returns n-dimensional wavelet noise as introduced by Cook and
+    /// DeRose. Unlike lattice noise such as [`perlin()`], whose energy is spread across a wide
+    /// range of frequencies, wavelet noise is band-limited to roughly a single octave. Sampling
+    /// it at a lower rate, as happens when texturing distant geometry, therefore causes little
+    /// aliasing, and fractal sums of it do not blur into each other.
+    ///
+    /// The noise is computed from a tile with `tile_size` values along each axis, which is
+    /// precomputed when the generator is created, and whose randomness is solely derived from
+    /// the value of `seed`. The tile is filled with random values, from which their coarse scale
+    /// part is removed by downsampling and upsampling it along every axis and subtracting the
+    /// result. The noise is interpolated between the values of the tile, which lie at integer
+    /// points, using quadratic B-splines. The tile wraps around at its edges, such that the
+    /// noise repeats seamlessly with a period of `tile_size` along each axis. The frequencies of
+    /// the noise lie mostly between a quarter and a half cycle per unit.
+    ///
+    /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// The tile holds `tile_size` to the power of the dimensionality values of 8 bytes each,
+    /// such as 2 MiB for a 3-dimensional tile of size 64, or 256 KiB for a 2-dimensional tile of
+    /// size 128. About three times as much memory is required temporarily while computing it,
+    /// which takes time proportional to the size of the tile.
+    /// </p>
+    ///
+    /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// Wavelet noise is guaranteed to return a value in the range [-1, 1], as the tile is scaled
+    /// such that its largest magnitude is 1. Most values are considerably closer to 0.
+    /// </p>
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tile_size` is not a positive even number.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::wavelet(42, 32);
+    /// let value = generator.sample([0.25, 0.5, 3.75]);
+    ///
+    /// // the tile repeats every 32 units
+    /// assert_eq!(value, generator.sample([32.25, -31.5, 3.75]));
+    /// ```
+    ///
+    /// [`perlin()`]: Source::perlin
+    pub fn wavelet(seed: u64, tile_size: usize) -> Wavelet<D> {
+        Wavelet::new(seed, tile_size)
+    }
+
+    /// Create a generator which produces n-dimensional sparse convolution noise, also called spot
+    /// noise.
+    ///
+    /// The created generator returns n-dimensional sparse convolution noise. The input space is
+    /// divided into a grid lattice of unit hypercubes, each of which is assigned a number of
+    /// impulses at pseudorandom positions within it, with pseudorandom amplitudes. This
+    /// randomness is solely derived from the value of `seed`. A kernel is placed at every
+    /// impulse, and the noise value is determined by summing the kernels reaching the input
+    /// point, weighted by the amplitudes of their impulses. The result are scattered spots,
+    /// which are useful for texture bombing or splatter patterns.
+    ///
+    /// The generator places a Gaussian kernel with a radius of 0.5 at a single impulse per
+    /// hypercube by default. This can be customized using [`with_kernel()`], [`with_radius()`],
+    /// [`with_density()`], and [`with_amplitude_jitter()`]. The hypercubes scanned for impulses
+    /// are derived from the radius, such that every kernel reaching the input point is
+    /// considered and the noise is continuous. The cost of sampling thus grows with the density
+    /// and with the radius raised to the power of the dimensionality.
+    ///
+    /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// Spot noise returns a value in the range [-1, 1]. Points which no kernel reaches return
+    /// -1, and the sum of overlapping kernels is capped such that it does not exceed 1.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, SpotKernel};
+    /// let generator = Source::spot(42)
+    ///     .with_kernel(SpotKernel::Ring)
+    ///     .with_radius(0.8)
+    ///     .with_density(2)
+    ///     .with_amplitude_jitter(0.5)
+    ///     .scale([0.05; 2]);
+    /// let value = generator.sample([0.2, 0.5]);
+    /// ```
+    ///
+    /// [`with_kernel()`]: Spot::with_kernel
+    /// [`with_radius()`]: Spot::with_radius
+    /// [`with_density()`]: Spot::with_density
+    /// [`with_amplitude_jitter()`]: Spot::with_amplitude_jitter
+    pub fn spot(seed: u64) -> Spot<D> {
+        Spot::new(seed)
+    }
+
+    /// Create a generator which produces n-dimensional white noise.
+    ///
+    /// The created generator returns n-dimensional white noise. Unlike the other noise sources,
+    /// white noise is not built on a lattice. Instead, the bit patterns of the coordinates of the
+    /// input point are hashed together with `seed`, and the hash is mapped to a value in the range
+    /// [-1, 1]. Thus, the same input point always yields the same value, while the values of any
+    /// two distinct input points are uncorrelated and uniformly distributed. This is useful for
+    /// dithering, or for assigning a random value to every cell of a grid by sampling it at
+    /// integer coordinates.
+    ///
+    /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// White noise is discontinuous everywhere, as even the smallest change to the input point
+    /// results in an unrelated value. It is therefore unsuitable as the base of fractal adapters
+    /// such as [`fbm()`], which rely on smooth noise to add detail at increasing frequencies.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::white(42);
+    /// let value = generator.sample([2.0, 5.0]);
+    ///
+    /// assert_eq!(value, generator.sample([2.0, 5.0]));
+    /// ```
+    ///
+    /// [`fbm()`]: crate::Generator::fbm
+    pub fn white(seed: u64) -> White<D> {
+        White::new(seed)
+    }
+
+    /// Create a generator which produces the n-dimensional distance to the nearest point of the
+    /// given point set.
+    ///
+    /// The created generator returns the euclidean distance from the input point to the nearest
+    /// of `points`, such as those generated by [`poisson_disk()`], which is useful for crater
+    /// fields or influence maps. The distance is mapped from the range [0, `max_distance`] to
+    /// [-1, 1], such that the points themselves return -1, and any input farther than
+    /// `max_distance` from every point returns 1. The maximum distance is 1 by default, and can
+    /// be customized using [`with_max_distance()`].
+    ///
+    /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// The points are arranged into a kd-tree when creating the generator, which takes time
+    /// proportional to `n log n` for `n` points. Looking up the nearest point then typically
+    /// takes time proportional to `log n`, rather than visiting every point.
+    /// </p>
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::EmptyPointSet`] if `points` is empty, and [`Error::NonFinitePoint`] if
+    /// any coordinate of a point is not finite.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{points, Source, Generator};
+    /// let craters = points::poisson_disk(42, [0.0, 0.0], [100.0, 100.0], 8.0);
+    /// let generator = Source::distance_field(craters).unwrap().with_max_distance(4.0);
+    /// let value = generator.sample([20.0, 50.0]);
+    ///
+    /// assert!((-1.0..=1.0).contains(&value));
+    /// ```
+    ///
+    /// [`poisson_disk()`]: crate::points::poisson_disk
+    /// [`with_max_distance()`]: DistanceField::with_max_distance
+    /// [`Error::EmptyPointSet`]: crate::Error::EmptyPointSet
+    /// [`Error::NonFinitePoint`]: crate::Error::NonFinitePoint
+    pub fn distance_field(points: Vec<[f64; D]>) -> Result<DistanceField<D>, Error> {
+        DistanceField::new(points)
+    }
+
+    /// Create a generator which produces an n-dimensional linear gradient from `from` to `to`.
+    ///
+    /// The created generator returns the position of the input point along the segment from
+    /// `from` to `to`, mapped to the range [-1, 1]. The input point is projected onto the line
+    /// through both points, such that all points on a hyperplane perpendicular to it have the
+    /// same value. The generator returns -1 at `from` and 1 at `to`, and the value is clamped
+    /// beyond them. This is useful as a mask for [`blend()`] or [`select()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if any coordinate of `from` or `to` is not finite, or
+    /// if `from` equals `to`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// // fade from plains in the west to mountains in the east
+    /// let mask = Source::linear_gradient([0.0, 0.0], [100.0, 0.0]).unwrap();
+    /// let plains = Source::simplex(42).scale([0.01; 2]).mul(0.1);
+    /// let mountains = Source::simplex(43).scale([0.03; 2]);
+    /// let generator = plains.blend(mountains, mask);
+    ///
+    /// assert_eq!(mask.sample([25.0, 70.0]), -0.5);
+    /// ```
+    ///
+    /// [`blend()`]: crate::Generator::blend
+    /// [`select()`]: crate::Generator::select
+    /// [`Error::InvalidParameter`]: crate::Error::InvalidParameter
+    pub fn linear_gradient(from: [f64; D], to: [f64; D]) -> Result<LinearGradient<D>, Error> {
+        LinearGradient::new(from, to)
+    }
+
+    /// Create a generator which produces an n-dimensional radial gradient around `center`.
+    ///
+    /// The created generator returns 1 within `inner_radius` of `center`, and -1 beyond
+    /// `outer_radius`. In between, the value falls linearly with the euclidean distance to
+    /// `center`. This is useful as a mask for [`blend()`] or [`select()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if any coordinate of `center` is not finite, if
+    /// `inner_radius` is negative or not finite, or if `outer_radius` is not finite or does not
+    /// exceed `inner_radius`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// // a crater with a flat floor and walls sloping up towards the rim
+    /// let mask = Source::radial_gradient([50.0, 50.0], 10.0, 30.0).unwrap();
+    /// let floor = Source::constant(-0.5);
+    /// let terrain = Source::simplex(42).scale([0.02; 2]);
+    /// let generator = terrain.blend(floor, mask);
+    ///
+    /// assert_eq!(mask.sample([50.0, 70.0]), 0.0);
+    /// ```
+    ///
+    /// [`blend()`]: crate::Generator::blend
+    /// [`select()`]: crate::Generator::select
+    /// [`Error::InvalidParameter`]: crate::Error::InvalidParameter
+    pub fn radial_gradient(
+        center: [f64; D],
+        inner_radius: f64,
+        outer_radius: f64,
+    ) -> Result<RadialGradient<D>, Error> {
+        RadialGradient::new(center, inner_radius, outer_radius)
+    }
+
     /// Create a generator which produces an n-dimensional checkerboard pattern.
     ///
     /// The created generator returns n-dimensional checkerboard pattern. That is, the input space
@@ -213,6 +622,11 @@ impl<const D: usize> Source<D> {
     /// no two adjacent hypercubes are assigned the same value. The noise value is determined by
     /// returning the value assigned to the hypercube in which the input point lies.
     ///
+    /// The size of the hypercubes along each axis defaults to 1 and can be set with
+    /// [`with_period()`]. With [`smooth()`], the hard edges between the hypercubes are replaced
+    /// by a continuous sine wave with the same period, which is useful where derivatives must
+    /// exist, such as when debugging domain warping.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -222,6 +636,17 @@ impl<const D: usize> Source<D> {
     /// let generator = Source::checkerboard();
     /// let value = generator.sample([0.2, 0.5]);
     /// ```
+    ///
+    /// Create a smooth checkerboard with cells twice as wide as they are high:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::checkerboard().with_period([2.0, 1.0]).smooth(true);
+    /// let value = generator.sample([0.2, 0.5]);
+    /// ```
+    ///
+    /// [`with_period()`]: Checkerboard::with_period
+    /// [`smooth()`]: Checkerboard::smooth
     pub fn checkerboard() -> Checkerboard<D> {
         Checkerboard::new()
     }
@@ -232,6 +657,11 @@ impl<const D: usize> Source<D> {
     /// for the input point and producing the result. This allows usage of adapters and other
     /// library functionality without being restricted to a specific source.
     ///
+    /// The closure may capture owned state, such as a loaded heightmap or a lookup table. The
+    /// generator is [`Clone`], [`Send`], and [`Sync`] whenever the closure is. To choose the
+    /// closure at runtime, see [`custom_dyn()`], and for a function pointer with explicit state,
+    /// see [`from_fn_with_state()`].
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -241,7 +671,384 @@ impl<const D: usize> Source<D> {
     /// let generator = Source::custom(|[x, y]| x % 2.0 + (1.0 - y * y) % 3.0);
     /// let value = generator.sample([0.2, 0.5]);
     /// ```
+    ///
+    /// Capturing state:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let heights = vec![0.0, 0.5, 1.0, 0.25];
+    /// let generator = Source::custom(move |[x]| heights[(x.abs() as usize) % heights.len()]);
+    /// assert_eq!(generator.sample([2.5]), 1.0);
+    /// ```
+    ///
+    /// [`custom_dyn()`]: Source::custom_dyn
+    /// [`from_fn_with_state()`]: Source::from_fn_with_state
     pub fn custom<F: Fn([f64; D]) -> f64>(f: F) -> Custom<D, F> {
         Custom::new(f)
     }
+
+    /// Create a generator which produces n-dimensional values based on the provided shared
+    /// closure.
+    ///
+    /// This is equivalent to [`custom()`], except that the closure is a trait object. The type of
+    /// the generator thus does not depend on the closure, such that the closure can be chosen at
+    /// runtime. Cloning the generator shares the closure.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use libnoise::{Source, Generator};
+    /// let ridged = true;
+    /// let generator = Source::custom_dyn(if ridged {
+    ///     Arc::new(|[x, y]: [f64; 2]| 1.0 - (x * y).sin().abs())
+    /// } else {
+    ///     Arc::new(|[x, y]: [f64; 2]| (x * y).sin())
+    /// });
+    /// let value = generator.sample([0.2, 0.5]);
+    /// ```
+    ///
+    /// [`custom()`]: Source::custom
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn custom_dyn(f: Arc<dyn Fn([f64; D]) -> f64 + Send + Sync>) -> CustomDyn<D> {
+        CustomDyn::new(f)
+    }
+
+    /// Create a generator which produces n-dimensional values based on the provided function
+    /// and state.
+    ///
+    /// The created generator returns n-dimensional values by calling the function pointer `f`
+    /// with a reference to `state` and the input point. Unlike [`custom()`], this requires
+    /// neither closures nor allocation, and the generator is [`Copy`] whenever the state is,
+    /// which makes it suitable for embedded use.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// const LUT: [f64; 4] = [0.0, 0.5, 1.0, 0.25];
+    ///
+    /// fn lookup(table: &[f64; 4], [x, y]: [f64; 2]) -> f64 {
+    ///     table[((x + y).abs() as usize) % table.len()]
+    /// }
+    ///
+    /// let generator = Source::from_fn_with_state(LUT, lookup).add(1.0);
+    /// assert_eq!(generator.sample([1.5, 0.7]), 2.0);
+    /// ```
+    ///
+    /// [`custom()`]: Source::custom
+    pub fn from_fn_with_state<S>(state: S, f: fn(&S, [f64; D]) -> f64) -> CustomWithState<D, S> {
+        CustomWithState::new(state, f)
+    }
+
+    /// Create a generator which produces n-dimensional values by interpolating the values of a
+    /// [`NoiseBuffer`].
+    ///
+    /// This is the inverse of filling a buffer: the value at index `i` of `buffer` is placed at
+    /// the point `origin + i * cell_size`, and values in between are interpolated bilinearly
+    /// by default, which refers to trilinear interpolation for 3-dimensional buffers. This
+    /// allows buffers which were processed further, such as eroded heightmaps, or which were
+    /// loaded from a file, to feed back into adapter chains. With an `origin` of zero and a
+    /// `cell_size` of one, the generator reproduces the buffer at the integer points at which
+    /// [`NoiseBuffer::new()`] samples generators.
+    ///
+    /// The interpolation and the handling of points beyond the edges of the buffer can be
+    /// changed using [`with_interpolation()`] and [`with_boundary()`]. By default, points beyond
+    /// the edges take the value of the nearest edge. With [`Boundary::Wrap`], the generator tiles
+    /// seamlessly with a period of the shape of the buffer times `cell_size`.
+    ///
+    /// The buffer is shared via [`Arc`], such that the generator is cheap to clone, and a shared
+    /// buffer can be passed to create several generators without copying it. Sampling does not
+    /// allocate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if `buffer` has a length of zero along any axis, if
+    /// any coordinate of `origin` is not finite, or if any component of `cell_size` is not
+    /// positive and finite.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, NoiseBuffer, Boundary, Interpolation};
+    /// let heightmap = NoiseBuffer::<2>::new([64, 64], &Source::simplex(42).scale([0.05; 2]));
+    ///
+    /// // stretch the heightmap over 640 by 640 units, and add finer detail on top
+    /// let generator = Source::from_buffer(heightmap.clone(), [0.0, 0.0], [10.0, 10.0])
+    ///     .unwrap()
+    ///     .with_interpolation(Interpolation::Bicubic)
+    ///     .with_boundary(Boundary::Wrap)
+    ///     .sum(Source::simplex(43).scale([0.1; 2]).mul(0.1));
+    ///
+    /// let source = Source::from_buffer(heightmap.clone(), [0.0, 0.0], [10.0, 10.0]).unwrap();
+    /// assert_eq!(source.sample([30.0, 50.0]), heightmap[[3, 5]]);
+    /// ```
+    ///
+    /// [`NoiseBuffer`]: crate::NoiseBuffer
+    /// [`NoiseBuffer::new()`]: crate::NoiseBuffer::new
+    /// [`with_interpolation()`]: BufferSource::with_interpolation
+    /// [`with_boundary()`]: BufferSource::with_boundary
+    /// [`Boundary::Wrap`]: crate::Boundary::Wrap
+    /// [`Error::InvalidParameter`]: crate::Error::InvalidParameter
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn from_buffer(
+        buffer: impl Into<Arc<NoiseBuffer<D>>>,
+        origin: [f64; D],
+        cell_size: [f64; D],
+    ) -> Result<BufferSource<D>, Error> {
+        BufferSource::new(buffer, origin, cell_size)
+    }
+}
+
+impl Source<2> {
+    /// Create a generator which produces 2-dimensional simplex noise tiling seamlessly with the
+    /// given `period`.
+    ///
+    /// The created generator samples 4-dimensional simplex noise on a torus, as done by
+    /// [`tileable_2d()`], such that the results repeat exactly after `period` along each axis.
+    /// It is a regular 2-dimensional generator, which can be modified further using adapters.
+    /// For fractal noise which remains tileable, see [`tileable_fbm()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if any component of `period` is not positive and finite.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::tileable_simplex(42, [64.0, 32.0]).clamp(-0.5, 0.5);
+    ///
+    /// assert_eq!(generator.sample([0.0, 10.0]), generator.sample([64.0, 10.0]));
+    /// assert_eq!(generator.sample([25.0, 0.0]), generator.sample([25.0, 32.0]));
+    /// ```
+    ///
+    /// [`tileable_2d()`]: crate::Generator4D::tileable_2d
+    /// [`tileable_fbm()`]: crate::Generator4D::tileable_fbm
+    pub fn tileable_simplex(seed: u64, period: [f64; 2]) -> Tileable2D<Simplex<4>> {
+        Tileable2D::new(Simplex::new(seed), period)
+    }
+
+    /// Create a generator which produces 2-dimensional blue noise.
+    ///
+    /// Blue noise consists of uniformly distributed values without low-frequency components,
+    /// such that similar values do not clump together as they do in white noise. This makes it
+    /// well suited for dithering, or for thresholding into evenly spread scatter masks.
+    ///
+    /// The created generator precomputes a square tile of `resolution` by `resolution` values
+    /// using the void-and-cluster algorithm, where the randomness is solely derived from the
+    /// value of `seed`. Each value lies in the range [-1, 1], and every value of the tile is
+    /// distinct. The tile is treated as wrapping around at its edges when it is computed, such
+    /// that it tiles seamlessly. The input space is covered by repeating the tile, with the
+    /// value at index `[i, j]` of the tile being placed at the integer point `[i, j]`. Other
+    /// points take the value of the nearest point with lower integer coordinates, or are
+    /// interpolated bilinearly if [`with_interpolation()`] is enabled.
+    ///
+    /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// Computing the tile takes time proportional to the fourth power of `resolution`. In
+    /// optimized builds, this amounts to tens of milliseconds for a resolution of 64, but to
+    /// several seconds for a resolution of 256. The tile occupies 8 bytes per value, and about five
+    /// times as much memory is required temporarily while computing it. The generator should
+    /// therefore be constructed once and cloned as needed rather than constructed repeatedly.
+    /// </p>
+    ///
+    /// # Panics
+    ///
+    /// Panics if `resolution` is 0.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::blue(42, 32);
+    ///
+    /// // threshold the noise into a mask with evenly spread points covering a quarter of the area
+    /// let scattered = generator.sample([5.0, 7.0]) > 0.5;
+    ///
+    /// // the tile repeats every 32 units
+    /// assert_eq!(generator.sample([5.0, 7.0]), generator.sample([37.0, -25.0]));
+    /// ```
+    ///
+    /// [`with_interpolation()`]: Blue::with_interpolation
+    pub fn blue(seed: u64, resolution: usize) -> Blue {
+        Blue::new(seed, resolution)
+    }
+
+    /// Create a generator which produces a 2-dimensional round island mask.
+    ///
+    /// The created generator returns 1 at `center`, falling off along the curve given by
+    /// `falloff` to -1 at a distance of `radius` from the center, and -1 everywhere beyond. The
+    /// curves are evaluated on the distance relative to `radius`, see [`FalloffCurve`] for the
+    /// available shapes. Except for [`FalloffCurve::SquareBump`], which rounds off the corners
+    /// of a square, the island is a disc.
+    ///
+    /// Taking the [`min()`] of the mask and some terrain noise, raised such that it is mostly
+    /// above sea level, sinks the terrain into the sea towards the coast while leaving the
+    /// interior untouched, as in the example below. An image of the resulting terrain is
+    /// rendered by the `island` example. For a rectangular map, see [`island_rect()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if any coordinate of `center` is not finite, if
+    /// `radius` is not positive and finite, or if the exponent of [`FalloffCurve::Exponent`] is
+    /// not positive and finite.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{FalloffCurve, Source, Generator};
+    /// let mask = Source::island([500.0, 500.0], 450.0, FalloffCurve::Smoothstep).unwrap();
+    /// let terrain = Source::simplex(42).fbm(5, 0.006, 2.0, 0.5).add(0.2);
+    /// let generator = mask.min(terrain);
+    ///
+    /// assert_eq!(mask.sample([500.0, 500.0]), 1.0);
+    /// assert_eq!(generator.sample([0.0, 0.0]), -1.0);
+    /// ```
+    ///
+    /// [`min()`]: crate::Generator::min
+    /// [`island_rect()`]: Source::island_rect
+    /// [`Error::InvalidParameter`]: crate::Error::InvalidParameter
+    pub fn island(center: [f64; 2], radius: f64, falloff: FalloffCurve) -> Result<Island, Error> {
+        Island::new(center, radius, falloff)
+    }
+
+    /// Create a generator which produces a 2-dimensional rectangular island mask.
+    ///
+    /// This is the rectangular counterpart of [`island()`], falling off towards the edges of the
+    /// rectangle from `min` to `max` rather than towards a circle, which suits maps that should
+    /// be surrounded by sea on all sides. The created generator returns 1 at the center of the
+    /// rectangle, falling off along the curve given by `falloff` to -1 at its edges, and -1
+    /// everywhere outside of it. The curves are evaluated on the largest offset from the center
+    /// relative to the half-extent along the same axis, such that the contours are rectangles,
+    /// except for [`FalloffCurve::SquareBump`], which is evaluated per axis.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if any coordinate of `min` or `max` is not finite, if
+    /// `max` does not exceed `min` along every axis, or if the exponent of
+    /// [`FalloffCurve::Exponent`] is not positive and finite.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{FalloffCurve, Source, Generator};
+    /// // a map of 1024 by 512 units which is bounded by sea
+    /// let falloff = FalloffCurve::SquareBump;
+    /// let mask = Source::island_rect([0.0, 0.0], [1024.0, 512.0], falloff).unwrap();
+    /// let terrain = Source::simplex(42).fbm(5, 0.006, 2.0, 0.5).add(0.2);
+    /// let generator = mask.min(terrain);
+    ///
+    /// assert_eq!(generator.sample([0.0, 100.0]), -1.0);
+    /// ```
+    ///
+    /// [`island()`]: Source::island
+    /// [`Error::InvalidParameter`]: crate::Error::InvalidParameter
+    pub fn island_rect(
+        min: [f64; 2],
+        max: [f64; 2],
+        falloff: FalloffCurve,
+    ) -> Result<IslandRect, Error> {
+        IslandRect::new(min, max, falloff)
+    }
+
+    /// Create a generator which produces 2-dimensional values from the pixels of an image.
+    ///
+    /// The created generator returns the brightness of the pixels of `image`, mapped such that
+    /// black is -1 and white is 1, which allows hand-painted masks to be combined with
+    /// procedural noise using adapters such as [`blend()`] and [`select()`]. Images with 16
+    /// bits per channel keep their full precision, and colored images are converted to
+    /// grayscale. The value of each pixel is placed at the center of the pixel, and values in
+    /// between are interpolated bilinearly by default. The first coordinate of the input point
+    /// runs along the rows of the image, from top to bottom, and the second coordinate runs
+    /// along its columns, from left to right, which matches the orientation of [`Visualizer`].
+    ///
+    /// By default, the image covers the rectangle from the origin to its height and width, such
+    /// that each pixel is a unit square, and points beyond it take the value of the nearest
+    /// pixel. The covered rectangle, the interpolation, the values beyond the rectangle, and
+    /// the values of black and white can be changed using the builder methods of
+    /// [`ImageSource`]. To load the image from a file, see [`image_file()`].
+    ///
+    /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// The brightness of every pixel is copied into the generator, which occupies 8 bytes per
+    /// pixel. Cloning the generator copies them, such that it should be shared by reference
+    /// where possible.
+    /// </p>
+    ///
+    /// # Panics
+    ///
+    /// Panics if `image` has a width or height of 0.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, ImageBoundary};
+    /// // a mask of 2 by 2 pixels, with a white pixel at the top right
+    /// let pixels = image::GrayImage::from_raw(2, 2, vec![0, 255, 0, 0]).unwrap();
+    /// let mask = Source::image(&image::DynamicImage::ImageLuma8(pixels))
+    ///     .with_bounds([0.0, 0.0], [100.0, 100.0])
+    ///     .with_boundary(ImageBoundary::Constant(-1.0));
+    ///
+    /// // mountains where the mask is white, and plains elsewhere
+    /// let mountains = Source::simplex(42).scale([0.05; 2]);
+    /// let generator = Source::constant(-0.5).blend(mountains, mask.clone());
+    ///
+    /// assert_eq!(mask.sample([25.0, 75.0]), 1.0);
+    /// assert_eq!(mask.sample([50.0, 50.0]), -0.5);
+    /// ```
+    ///
+    /// [`blend()`]: crate::Generator::blend
+    /// [`select()`]: crate::Generator::select
+    /// [`Visualizer`]: crate::Visualizer
+    /// [`image_file()`]: Source::image_file
+    #[cfg(feature = "image")]
+    pub fn image(image: &image::DynamicImage) -> ImageSource {
+        ImageSource::new(image)
+    }
+
+    /// Create a generator which produces 2-dimensional values from the pixels of the image file
+    /// at `path`.
+    ///
+    /// This loads the image and passes it to [`image()`], see there for details. The format of
+    /// the file is determined by its extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the file cannot be read, [`Error::Encode`] if it cannot be
+    /// decoded, [`Error::Unsupported`] if its format is not supported, and
+    /// [`Error::InvalidParameter`] if the image has a width or height of 0.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// # use libnoise::{Source, Generator};
+    /// let mask = Source::image_file("mask.png")?.with_bounds([0.0, 0.0], [1000.0, 1000.0]);
+    /// let generator = Source::simplex(42).scale([0.01; 2]).min(mask);
+    /// # Ok::<(), libnoise::Error>(())
+    /// ```
+    ///
+    /// [`image()`]: Source::image
+    #[cfg(feature = "image")]
+    pub fn image_file(path: &str) -> Result<ImageSource, Error> {
+        ImageSource::open(path)
+    }
 }
@@ -0,0 +1,162 @@
+use super::functional;
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::{
+    generator::{Generator, Generator2D},
+    recipe::{NoiseRecipe, RecipeError},
+    utils::describe::Description,
+};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A generator which produces 2-dimensional blue noise from a precomputed tile.
+///
+/// For details, see the documentation of [`blue()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`blue()`] implemented by [`Source`], should be used to
+/// create a blue noise generator.
+///
+/// # Direct usage of this struct
+///
+/// Direct instantiation of this struct:
+///
+/// ```
+/// # use libnoise::{Blue, Generator};
+/// let generator = Blue::new(42, 16).with_interpolation();
+/// let value = generator.sample([0.2, 0.5]);
+/// ```
+///
+/// [`blue()`]: crate::Source::blue
+/// [`Source`]: crate::Source
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "BlueParams", try_from = "BlueParams")
+)]
+pub struct Blue {
+    seed: u64,
+    resolution: usize,
+    interpolate: bool,
+    tile: Vec<f64>,
+}
+
+impl Generator2D for Blue {}
+
+impl Blue {
+    /// Create a new blue noise generator, precomputing a tile with `resolution` values along
+    /// each axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `resolution` is 0.
+    pub fn new(seed: u64, resolution: usize) -> Self {
+        assert!(resolution > 0, "resolution must be at least 1");
+        Self {
+            seed,
+            resolution,
+            interpolate: false,
+            tile: functional::blue::tile(seed, resolution),
+        }
+    }
+
+    /// Enable bilinear interpolation between the values of the tile, which are otherwise
+    /// sampled as nearest neighbours.
+    #[inline]
+    pub fn with_interpolation(mut self) -> Self {
+        self.interpolate = true;
+        self
+    }
+
+    // the value of the tile at the given integer coordinates, wrapped into the tile
+    #[inline]
+    fn texel(&self, x: f64, y: f64) -> f64 {
+        let wrap = |coordinate: f64| {
+            (coordinate.rem_euclid(self.resolution as f64) as usize).min(self.resolution - 1)
+        };
+        self.tile[wrap(x) * self.resolution + wrap(y)]
+    }
+}
+
+impl Generator<2> for Blue {
+    #[inline]
+    fn sample(&self, point: [f64; 2]) -> f64 {
+        let [x, y] = point;
+        if !x.is_finite() || !y.is_finite() {
+            return f64::NAN;
+        }
+        let (x0, y0) = (x.floor(), y.floor());
+        if !self.interpolate {
+            return self.texel(x0, y0);
+        }
+        let (tx, ty) = (x - x0, y - y0);
+        let v00 = self.texel(x0, y0);
+        let v01 = self.texel(x0, y0 + 1.0);
+        let v10 = self.texel(x0 + 1.0, y0);
+        let v11 = self.texel(x0 + 1.0, y0 + 1.0);
+        let v0 = v00 + tx * (v10 - v00);
+        let v1 = v01 + tx * (v11 - v01);
+        v0 + ty * (v1 - v0)
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; 2], _max: [f64; 2]) -> (f64, f64) {
+        (-1.0, 1.0)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Blue")
+            .with_param("seed", self.seed)
+            .with_param("resolution", self.resolution)
+            .with_param("interpolate", self.interpolate)
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Blue {
+            seed: self.seed,
+            resolution: self.resolution,
+            interpolate: self.interpolate,
+        })
+    }
+}
+
+impl fmt::Display for Blue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.describe(), f)
+    }
+}
+
+// the serialized form of the generator, from which the tile is recomputed on deserialization
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct BlueParams {
+    seed: u64,
+    resolution: usize,
+    interpolate: bool,
+}
+
+#[cfg(feature = "serde")]
+impl From<Blue> for BlueParams {
+    fn from(generator: Blue) -> Self {
+        Self {
+            seed: generator.seed,
+            resolution: generator.resolution,
+            interpolate: generator.interpolate,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<BlueParams> for Blue {
+    type Error = &'static str;
+
+    fn try_from(params: BlueParams) -> Result<Self, Self::Error> {
+        if params.resolution == 0 {
+            return Err("resolution must be at least 1");
+        }
+        let generator = Self::new(params.seed, params.resolution);
+        Ok(Self {
+            interpolate: params.interpolate,
+            ..generator
+        })
+    }
+}
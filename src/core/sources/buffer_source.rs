@@ -0,0 +1,175 @@
+use crate::core::{
+    error::Error,
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    utils::{
+        describe::{impl_display, Description},
+        filter::Boundary,
+        noisebuf::{self, NoiseBuffer},
+        resample::{self, Interpolation},
+    },
+};
+use alloc::{format, sync::Arc};
+
+/// A generator which produces n-dimensional values by interpolating the values of a
+/// [`NoiseBuffer`].
+///
+/// For details, see the documentation of [`from_buffer()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`from_buffer()`] implemented by [`Source`], should be used to
+/// create a buffer generator.
+///
+/// # Direct usage of this struct
+///
+/// Direct instantiation of this struct:
+///
+/// ```
+/// # use libnoise::{BufferSource, Generator, NoiseBuffer, Source};
+/// let buf = NoiseBuffer::<2>::new([16, 16], &Source::simplex(42).scale([0.1; 2]));
+/// let generator = BufferSource::new(buf, [0.0, 0.0], [1.0, 1.0]).unwrap();
+/// let value = generator.sample([2.5, 7.25]);
+/// ```
+///
+/// [`from_buffer()`]: crate::Source::from_buffer
+/// [`Source`]: crate::Source
+#[derive(Clone, Debug)]
+pub struct BufferSource<const D: usize> {
+    buffer: Arc<NoiseBuffer<D>>,
+    origin: [f64; D],
+    cell_size: [f64; D],
+    interpolation: Interpolation,
+    boundary: Boundary,
+    // the smallest and largest value of the buffer
+    range: (f64, f64),
+}
+
+impl Generator1D for BufferSource<1> {}
+impl Generator2D for BufferSource<2> {}
+impl Generator3D for BufferSource<3> {}
+impl Generator4D for BufferSource<4> {}
+
+impl<const D: usize> BufferSource<D> {
+    /// Create a new buffer generator placing the value at index `i` of `buffer` at the point
+    /// `origin + i * cell_size`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if `buffer` has a length of zero along any axis, if
+    /// any coordinate of `origin` is not finite, or if any component of `cell_size` is not
+    /// positive and finite.
+    pub fn new(
+        buffer: impl Into<Arc<NoiseBuffer<D>>>,
+        origin: [f64; D],
+        cell_size: [f64; D],
+    ) -> Result<Self, Error> {
+        let buffer = buffer.into();
+        if buffer.shape.contains(&0) {
+            return Err(Error::InvalidParameter {
+                name: "buffer",
+                reason: format!("must not be empty, got shape {:?}", buffer.shape),
+            });
+        }
+        if origin.iter().any(|x| !x.is_finite()) {
+            return Err(Error::InvalidParameter {
+                name: "origin",
+                reason: format!("must be finite, got {:?}", origin),
+            });
+        }
+        if cell_size.iter().any(|x| !(*x > 0.0 && x.is_finite())) {
+            return Err(Error::InvalidParameter {
+                name: "cell_size",
+                reason: format!("must be positive and finite, got {:?}", cell_size),
+            });
+        }
+        let (mut lower, mut upper) = (f64::INFINITY, f64::NEG_INFINITY);
+        for &value in &buffer.buffer {
+            if value < lower {
+                lower = value;
+            }
+            if value > upper {
+                upper = value;
+            }
+        }
+        Ok(Self {
+            buffer,
+            origin,
+            cell_size,
+            interpolation: Interpolation::Bilinear,
+            boundary: Boundary::Clamp,
+            range: (lower, upper),
+        })
+    }
+
+    /// Set the method used to interpolate between the values of the buffer. By default,
+    /// [`Interpolation::Bilinear`] is used. Interpolated values are clamped to the range of the
+    /// values of the buffer, such that [`Interpolation::Bicubic`] does not overshoot it.
+    #[inline]
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Set the handling of points beyond the edges of the buffer. By default,
+    /// [`Boundary::Clamp`] is used.
+    #[inline]
+    pub fn with_boundary(mut self, boundary: Boundary) -> Self {
+        self.boundary = boundary;
+        self
+    }
+}
+
+impl<const D: usize> Generator<D> for BufferSource<D> {
+    fn sample(&self, point: [f64; D]) -> f64 {
+        if point.iter().any(|x| !x.is_finite()) {
+            return f64::NAN;
+        }
+        let taps: [([usize; 4], [f64; 4]); D] = core::array::from_fn(|axis| {
+            let coord = (point[axis] - self.origin[axis]) / self.cell_size[axis];
+            let (first, weights) = resample::tap_weights(coord, self.interpolation);
+            let len = self.buffer.shape[axis];
+            let indices = core::array::from_fn(|tap| {
+                self.boundary
+                    .index((first as isize).saturating_add(tap as isize), len)
+            });
+            (indices, weights)
+        });
+        let num_taps = resample::num_taps(self.interpolation);
+        let mut value = 0.0;
+        for tap in noisebuf::tensor_indices([num_taps; D]) {
+            let mut flat_index = 0;
+            let mut weight = 1.0;
+            for axis in 0..D {
+                flat_index += taps[axis].0[tap[axis]] * self.buffer.offsets[axis];
+                weight *= taps[axis].1[tap[axis]];
+            }
+            value += weight * self.buffer.buffer[flat_index];
+        }
+        // guards against overshooting with bicubic interpolation, and against rounding errors,
+        // while letting non-finite values of the buffer through
+        let (lower, upper) = self.range;
+        if value < lower {
+            lower
+        } else if value > upper {
+            upper
+        } else {
+            value
+        }
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; D], _max: [f64; D]) -> (f64, f64) {
+        match self.range {
+            (lower, upper) if lower <= upper => (lower, upper),
+            _ => (f64::NEG_INFINITY, f64::INFINITY),
+        }
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("BufferSource")
+            .with_param("shape", format_args!("{:?}", self.buffer.shape))
+            .with_array_param("origin", &self.origin)
+            .with_array_param("cell_size", &self.cell_size)
+            .with_param("interpolation", format_args!("{:?}", self.interpolation))
+            .with_param("boundary", format_args!("{:?}", self.boundary))
+    }
+}
+
+impl_display!([const D: usize] BufferSource<D>);
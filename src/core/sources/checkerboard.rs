@@ -1,5 +1,9 @@
 use super::functional;
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{NoiseRecipe, RecipeError},
+    utils::describe::{impl_display, Description},
+};
 
 /// A generator which produces an n-dimensional checkerboard pattern.
 ///
@@ -9,27 +13,26 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 ///
 /// # Direct usage of this struct
 ///
-/// [`Checkerboard`] is a unit struct and thus can be used directly:
+/// Direct instantiation of this struct:
 ///
 /// ```
 /// # use libnoise::{Checkerboard, Generator};
-/// let value = Checkerboard.sample([0.2, 0.5]);
-/// ```
-///
-/// Alternatively, for the sake of a unified API, the function [`new()`] is provided:
-///
-/// ```
-/// // create
-/// # use libnoise::{Checkerboard, Generator};
-/// let generator = Checkerboard::new();
+/// let generator = Checkerboard::new().with_period([2.0, 0.5]).smooth(true);
 /// let value = generator.sample([0.2, 0.5]);
 /// ```
 ///
 /// [`checkerboard()`]: crate::Source::checkerboard
 /// [`Source`]: crate::Source
-/// [`new()`]: Checkerboard::new
 #[derive(Clone, Copy, Debug)]
-pub struct Checkerboard<const D: usize>;
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "CheckerboardParams<D>", try_from = "CheckerboardParams<D>")
+)]
+pub struct Checkerboard<const D: usize> {
+    period: [f64; D],
+    smooth: bool,
+}
 
 impl Generator1D for Checkerboard<1> {}
 impl Generator2D for Checkerboard<2> {}
@@ -38,37 +41,210 @@ impl Generator4D for Checkerboard<4> {}
 
 #[allow(clippy::new_without_default)]
 impl<const D: usize> Checkerboard<D> {
-    /// Create a new checkerboard generator.
+    /// Create a new checkerboard generator with cells of unit size.
     #[inline]
     pub fn new() -> Self {
-        Self
+        Self {
+            period: [1.0; D],
+            smooth: false,
+        }
+    }
+
+    /// Set the size of the cells along each axis. By default, every cell is a unit hypercube.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any component of `period` is not positive and finite.
+    #[inline]
+    pub fn with_period(mut self, period: [f64; D]) -> Self {
+        assert!(
+            functional::checkerboard::validate_period(&period).is_ok(),
+            "period must be positive and finite, got {:?}",
+            period
+        );
+        self.period = period;
+        self
+    }
+
+    /// Set whether to produce a smooth wave instead of a hard-edged pattern. The smooth
+    /// checkerboard is the negated product of a sine wave along each axis, which has the same
+    /// period as the hard-edged checkerboard, and agrees with its sign for non-negative
+    /// coordinates. By default, the hard-edged pattern is produced.
+    #[inline]
+    pub fn smooth(mut self, smooth: bool) -> Self {
+        self.smooth = smooth;
+        self
+    }
+
+    // the point in units of cells
+    #[inline]
+    fn cells(&self, point: [f64; D]) -> [f64; D] {
+        core::array::from_fn(|i| point[i] / self.period[i])
+    }
+
+    #[inline]
+    fn noise(&self, point: [f64; D], hard: fn([f64; D]) -> f64) -> f64 {
+        let point = self.cells(point);
+        if self.smooth {
+            functional::checkerboard::smooth(point)
+        } else {
+            hard(point)
+        }
+    }
+
+    #[inline]
+    fn interval(&self, min: [f64; D], max: [f64; D], hard: fn([f64; D]) -> f64) -> (f64, f64) {
+        let (min, max) = (self.cells(min), self.cells(max));
+        if self.smooth {
+            functional::checkerboard::smooth_interval(min, max)
+        } else {
+            interval_within_cell(min, max, hard)
+        }
+    }
+
+    fn description(&self) -> Description {
+        let mut description = Description::new("Checkerboard");
+        if self.period != [1.0; D] {
+            description = description.with_array_param("period", &self.period);
+        }
+        if self.smooth {
+            description = description.with_param("smooth", true);
+        }
+        description
+    }
+
+    fn recipe(&self) -> NoiseRecipe {
+        NoiseRecipe::Checkerboard {
+            period: (self.period != [1.0; D]).then(|| self.period.to_vec()),
+            smooth: self.smooth,
+        }
     }
 }
 
 impl Generator<1> for Checkerboard<1> {
     #[inline]
     fn sample(&self, point: [f64; 1]) -> f64 {
-        functional::checkerboard::noise1d(point)
+        self.noise(point, functional::checkerboard::noise1d)
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; 1], max: [f64; 1]) -> (f64, f64) {
+        self.interval(min, max, functional::checkerboard::noise1d)
+    }
+
+    fn describe(&self) -> Description {
+        self.description()
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(self.recipe())
     }
 }
 
 impl Generator<2> for Checkerboard<2> {
     #[inline]
     fn sample(&self, point: [f64; 2]) -> f64 {
-        functional::checkerboard::noise2d(point)
+        self.noise(point, functional::checkerboard::noise2d)
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; 2], max: [f64; 2]) -> (f64, f64) {
+        self.interval(min, max, functional::checkerboard::noise2d)
+    }
+
+    fn describe(&self) -> Description {
+        self.description()
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(self.recipe())
     }
 }
 
 impl Generator<3> for Checkerboard<3> {
     #[inline]
     fn sample(&self, point: [f64; 3]) -> f64 {
-        functional::checkerboard::noise3d(point)
+        self.noise(point, functional::checkerboard::noise3d)
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; 3], max: [f64; 3]) -> (f64, f64) {
+        self.interval(min, max, functional::checkerboard::noise3d)
+    }
+
+    fn describe(&self) -> Description {
+        self.description()
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(self.recipe())
     }
 }
 
 impl Generator<4> for Checkerboard<4> {
     #[inline]
     fn sample(&self, point: [f64; 4]) -> f64 {
-        functional::checkerboard::noise4d(point)
+        self.noise(point, functional::checkerboard::noise4d)
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; 4], max: [f64; 4]) -> (f64, f64) {
+        self.interval(min, max, functional::checkerboard::noise4d)
+    }
+
+    fn describe(&self) -> Description {
+        self.description()
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(self.recipe())
+    }
+}
+
+// the checkerboard is constant within boxes whose corners lie in the same cell
+#[inline]
+fn interval_within_cell<const D: usize>(
+    min: [f64; D],
+    max: [f64; D],
+    noise: fn([f64; D]) -> f64,
+) -> (f64, f64) {
+    let value = noise(min);
+    if !value.is_nan() && (0..D).all(|i| min[i] as i64 == max[i] as i64) {
+        (value, value)
+    } else {
+        (-1.0, 1.0)
+    }
+}
+
+impl_display!([const D: usize] Checkerboard<D>);
+
+// the serialized form of the generator, which is validated like with_period() on deserialization
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct CheckerboardParams<const D: usize> {
+    #[serde(with = "crate::core::utils::serde_array")]
+    period: [f64; D],
+    smooth: bool,
+}
+
+#[cfg(feature = "serde")]
+impl<const D: usize> From<Checkerboard<D>> for CheckerboardParams<D> {
+    fn from(generator: Checkerboard<D>) -> Self {
+        Self {
+            period: generator.period,
+            smooth: generator.smooth,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const D: usize> TryFrom<CheckerboardParams<D>> for Checkerboard<D> {
+    type Error = &'static str;
+
+    fn try_from(params: CheckerboardParams<D>) -> Result<Self, Self::Error> {
+        if functional::checkerboard::validate_period(&params.period).is_err() {
+            return Err("period must be positive and finite");
+        }
+        Ok(Self::new().with_period(params.period).smooth(params.smooth))
     }
 }
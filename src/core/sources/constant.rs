@@ -1,4 +1,8 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{NoiseRecipe, RecipeError},
+    utils::describe::{impl_display, Description},
+};
 
 /// A generator which produces the supplied value for every input point.
 ///
@@ -19,6 +23,7 @@ use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, G
 /// [`constant()`]: crate::Source::constant
 /// [`Source`]: crate::Source
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Constant<const D: usize> {
     value: f64,
 }
@@ -41,4 +46,19 @@ impl<const D: usize> Generator<D> for Constant<D> {
     fn sample(&self, _point: [f64; D]) -> f64 {
         self.value
     }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; D], _max: [f64; D]) -> (f64, f64) {
+        (self.value, self.value)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Constant").with_param("value", self.value)
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Constant { value: self.value })
+    }
 }
+
+impl_display!([const D: usize] Constant<D>);
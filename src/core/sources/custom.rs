@@ -1,4 +1,7 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    utils::describe::{impl_display, Description},
+};
 
 /// A generator which produces n-dimensional values based on the provided closure.
 ///
@@ -41,4 +44,10 @@ impl<const D: usize, N: Fn([f64; D]) -> f64> Generator<D> for Custom<D, N> {
     fn sample(&self, point: [f64; D]) -> f64 {
         (self.noise)(point)
     }
+
+    fn describe(&self) -> Description {
+        Description::new("Custom").with_param("noise", "<closure>")
+    }
 }
+
+impl_display!([const D: usize, N] Custom<D, N>);
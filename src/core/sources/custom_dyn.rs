@@ -0,0 +1,62 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    utils::describe::{impl_display, Description},
+};
+use alloc::sync::Arc;
+use core::fmt;
+
+/// A generator which produces n-dimensional values based on the provided shared closure.
+///
+/// For details, see the documentation of [`custom_dyn()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`custom_dyn()`] implemented by [`Source`], should be used to
+/// create a custom generator.
+///
+/// # Direct usage of this struct
+///
+/// Direct instantiation of this struct:
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use libnoise::{CustomDyn, Generator};
+/// let generator = CustomDyn::new(Arc::new(|[x, y]: [f64; 2]| x % 2.0 + (1.0 - y * y) % 3.0));
+/// let value = generator.sample([0.2, 0.5]);
+/// ```
+///
+/// [`custom_dyn()`]: crate::Source::custom_dyn
+/// [`Source`]: crate::Source
+#[derive(Clone)]
+pub struct CustomDyn<const D: usize> {
+    noise: Arc<dyn Fn([f64; D]) -> f64 + Send + Sync>,
+}
+
+impl Generator1D for CustomDyn<1> {}
+impl Generator2D for CustomDyn<2> {}
+impl Generator3D for CustomDyn<3> {}
+impl Generator4D for CustomDyn<4> {}
+
+impl<const D: usize> CustomDyn<D> {
+    /// Create a new custom generator from a shared closure.
+    #[inline]
+    pub fn new(noise: Arc<dyn Fn([f64; D]) -> f64 + Send + Sync>) -> Self {
+        Self { noise }
+    }
+}
+
+impl<const D: usize> Generator<D> for CustomDyn<D> {
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        (self.noise)(point)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("CustomDyn").with_param("noise", "<closure>")
+    }
+}
+
+impl<const D: usize> fmt::Debug for CustomDyn<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomDyn").finish_non_exhaustive()
+    }
+}
+
+impl_display!([const D: usize] CustomDyn<D>);
@@ -0,0 +1,60 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    utils::describe::{impl_display, Description},
+};
+
+/// A generator which produces n-dimensional values based on the provided function and state.
+///
+/// For details, see the documentation of [`from_fn_with_state()`]. Typically, this struct is not
+/// meant to be used directly. Instead, [`from_fn_with_state()`] implemented by [`Source`], should
+/// be used to create a custom generator.
+///
+/// # Direct usage of this struct
+///
+/// Direct instantiation of this struct:
+///
+/// ```
+/// # use libnoise::{CustomWithState, Generator};
+/// let generator = CustomWithState::new(2.0, |scale: &f64, [x, y]| (x * scale).sin() * y);
+/// let value = generator.sample([0.2, 0.5]);
+/// ```
+///
+/// [`from_fn_with_state()`]: crate::Source::from_fn_with_state
+/// [`Source`]: crate::Source
+#[derive(Clone, Copy, Debug)]
+pub struct CustomWithState<const D: usize, S> {
+    state: S,
+    noise: fn(&S, [f64; D]) -> f64,
+}
+
+impl<S> Generator1D for CustomWithState<1, S> {}
+impl<S> Generator2D for CustomWithState<2, S> {}
+impl<S> Generator3D for CustomWithState<3, S> {}
+impl<S> Generator4D for CustomWithState<4, S> {}
+
+impl<const D: usize, S> CustomWithState<D, S> {
+    /// Create a new custom generator from a state and a function sampling it.
+    #[inline]
+    pub fn new(state: S, noise: fn(&S, [f64; D]) -> f64) -> Self {
+        Self { state, noise }
+    }
+
+    /// Returns a reference to the state passed to the function.
+    #[inline]
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+}
+
+impl<const D: usize, S> Generator<D> for CustomWithState<D, S> {
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        (self.noise)(&self.state, point)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("CustomWithState").with_param("noise", "<fn>")
+    }
+}
+
+impl_display!([const D: usize, S] CustomWithState<D, S>);
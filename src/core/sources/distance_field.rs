@@ -0,0 +1,186 @@
+use super::functional;
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::{
+    error::Error,
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{NoiseRecipe, RecipeError},
+    utils::describe::{impl_display, Description},
+};
+use alloc::vec::Vec;
+
+/// A generator which produces the n-dimensional distance to the nearest point of a point set.
+///
+/// For details, see the documentation of [`distance_field()`]. Typically, this struct is not
+/// meant to be used directly. Instead, [`distance_field()`] implemented by [`Source`], should be
+/// used to create a distance field generator.
+///
+/// # Direct usage of this struct
+///
+/// Direct instantiation of this struct:
+///
+/// ```
+/// # use libnoise::{DistanceField, Generator};
+/// let generator = DistanceField::new(vec![[0.0, 0.0], [3.0, 4.0]]).unwrap();
+/// let value = generator.sample([0.2, 0.5]);
+/// ```
+///
+/// [`distance_field()`]: crate::Source::distance_field
+/// [`Source`]: crate::Source
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "DistanceFieldParams", try_from = "DistanceFieldParams")
+)]
+pub struct DistanceField<const D: usize> {
+    tree: Vec<[f64; D]>,
+    max_distance: f64,
+}
+
+impl Generator1D for DistanceField<1> {}
+impl Generator2D for DistanceField<2> {}
+impl Generator3D for DistanceField<3> {}
+impl Generator4D for DistanceField<4> {}
+
+impl<const D: usize> DistanceField<D> {
+    /// Create a new distance field generator for the given point set, with a maximum distance
+    /// of 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::EmptyPointSet`] if `points` is empty, and [`Error::NonFinitePoint`] if
+    /// any coordinate of a point is not finite.
+    pub fn new(points: Vec<[f64; D]>) -> Result<Self, Error> {
+        if points.is_empty() {
+            return Err(Error::EmptyPointSet);
+        }
+        if let Some(index) = points
+            .iter()
+            .position(|point| point.iter().any(|x| !x.is_finite()))
+        {
+            return Err(Error::NonFinitePoint {
+                index,
+                point: points[index].to_vec(),
+            });
+        }
+        let mut tree = points;
+        functional::distance_field::build(&mut tree);
+        Ok(Self {
+            tree,
+            max_distance: 1.0,
+        })
+    }
+
+    /// Set the distance mapped to 1. Points at a distance of `max_distance` or more from the
+    /// nearest point of the point set return 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_distance` is not positive and finite.
+    pub fn with_max_distance(mut self, max_distance: f64) -> Self {
+        assert!(
+            max_distance > 0.0 && max_distance.is_finite(),
+            "max distance must be positive and finite, got {}",
+            max_distance
+        );
+        self.max_distance = max_distance;
+        self
+    }
+
+    // maps a distance within [0, max_distance] to [-1, 1], clamping larger distances. unlike
+    // min(), clamp() propagates NaN
+    fn normalize(&self, distance: f64) -> f64 {
+        (distance / self.max_distance).clamp(0.0, 1.0) * 2.0 - 1.0
+    }
+
+    // the number of nodes of the kd-tree visited when sampling the given point
+    #[cfg(feature = "dev-tools")]
+    pub(crate) fn visits(&self, point: [f64; D]) -> usize {
+        functional::distance_field::nearest_distance_visits(&self.tree, point).1
+    }
+}
+
+impl<const D: usize> Generator<D> for DistanceField<D> {
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.normalize(functional::distance_field::nearest_distance(
+            &self.tree, point,
+        ))
+    }
+
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        // the distance changes by at most the distance between two points, and thus differs
+        // from the distance at the center of the box by at most half its diagonal
+        let center = core::array::from_fn(|i| min[i] + (max[i] - min[i]) / 2.0);
+        let half_diagonal = (0..D)
+            .map(|i| (max[i] - min[i]) / 2.0)
+            .map(|x| x * x)
+            .sum::<f64>()
+            .sqrt();
+        let distance = functional::distance_field::nearest_distance(&self.tree, center);
+        if distance.is_nan() || half_diagonal.is_nan() {
+            return (-1.0, 1.0);
+        }
+        (
+            self.normalize((distance - half_diagonal).max(0.0)),
+            self.normalize(distance + half_diagonal),
+        )
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("DistanceField")
+            .with_param("points", self.tree.len())
+            .with_param("max_distance", self.max_distance)
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::DistanceField {
+            points: self.tree.iter().map(|point| point.to_vec()).collect(),
+            max_distance: self.max_distance,
+        })
+    }
+}
+
+impl_display!([const D: usize] DistanceField<D>);
+
+// the serialized form of the generator, from which the kd-tree is rebuilt on deserialization.
+// the points are stored as vectors, as serde does not support arrays of generic length
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct DistanceFieldParams {
+    points: Vec<Vec<f64>>,
+    max_distance: f64,
+}
+
+#[cfg(feature = "serde")]
+impl<const D: usize> From<DistanceField<D>> for DistanceFieldParams {
+    fn from(generator: DistanceField<D>) -> Self {
+        Self {
+            points: generator.tree.iter().map(|point| point.to_vec()).collect(),
+            max_distance: generator.max_distance,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const D: usize> TryFrom<DistanceFieldParams> for DistanceField<D> {
+    type Error = &'static str;
+
+    fn try_from(params: DistanceFieldParams) -> Result<Self, Self::Error> {
+        let points = params
+            .points
+            .iter()
+            .map(|point| point.as_slice().try_into())
+            .collect::<Result<Vec<[f64; D]>, _>>()
+            .map_err(|_| "points must have one coordinate per dimension")?;
+        if !(params.max_distance > 0.0 && params.max_distance.is_finite()) {
+            return Err("max distance must be positive and finite");
+        }
+        let generator = Self::new(points).map_err(|error| match error {
+            Error::EmptyPointSet => "point set must not be empty",
+            _ => "points must be finite",
+        })?;
+        Ok(generator.with_max_distance(params.max_distance))
+    }
+}
@@ -0,0 +1,186 @@
+use alloc::{vec, vec::Vec};
+use rand::seq::SliceRandom;
+use rand_chacha::{rand_core::SeedableRng, ChaCha12Rng};
+
+// the radius at which the gaussian filter measuring the clustering of a binary pattern is
+// truncated
+const RADIUS: usize = 5;
+
+// the gaussian filter with a standard deviation of 1.5, indexed by the squared distance from its
+// center, such that the tile does not depend on the exponential function of the platform
+const GAUSSIAN_WEIGHTS: [f64; 2 * RADIUS * RADIUS + 1] = [
+    1.0,
+    0.8007374029168081,
+    0.6411803884299546,
+    0.513417119032592,
+    0.41111229050718745,
+    0.32919298780790557,
+    0.26359713811572677,
+    0.2110720877910902,
+    0.1690133154060661,
+    0.1353352832366127,
+    0.10836802322189586,
+    0.08677432947392923,
+    0.06948345122280154,
+    0.05563799827784281,
+    0.04455142624448969,
+    0.035673993347252395,
+    0.028565500784550377,
+    0.02287346491123891,
+    0.01831563888873418,
+    0.014666017116527099,
+    0.01174362845702136,
+    0.009403562551495206,
+    0.007529784255650023,
+    0.0060293798893930745,
+    0.004827949993831441,
+    0.0038659201394728076,
+    0.00309558685236524,
+    0.0024787521766663585,
+    0.0019848295804182044,
+    0.0015893272834565308,
+    0.0012726338013398079,
+    0.0010190454849489828,
+    0.0008159878350721483,
+    0.0006533919798673806,
+    0.0005231953970456776,
+    0.0004189421234483841,
+    0.00033546262790251185,
+    0.0002686174734423051,
+    0.00021509205806226583,
+    0.00017223225596081014,
+    0.00013791280933656192,
+    0.00011043194477711959,
+    8.842698865988302e-05,
+    7.080679724726883e-05,
+    5.669765093663497e-05,
+    4.5399929762484854e-05,
+    3.6353421850617646e-05,
+    2.910954459980269e-05,
+    2.3309101142937016e-05,
+    1.866446911352057e-05,
+    1.4945338524781451e-05,
+];
+
+// generates a square tile of blue noise with the given side length using the void-and-cluster
+// algorithm. every pixel is assigned a distinct rank, which is mapped to a value in [-1, 1]
+// such that the values are uniformly distributed.
+pub(crate) fn tile(seed: u64, resolution: usize) -> Vec<f64> {
+    let size = resolution * resolution;
+    let mut rng = ChaCha12Rng::seed_from_u64(seed);
+
+    // random initial binary pattern with roughly a tenth of the pixels set
+    let mut indices = (0..size).collect::<Vec<_>>();
+    indices.shuffle(&mut rng);
+    let initial = (size / 10).max(1);
+    let mut field = Field::new(resolution);
+    for &index in &indices[..initial] {
+        field.toggle(index);
+    }
+
+    // move the pixel in the tightest cluster into the largest void until this converges, such
+    // that the set pixels are distributed evenly
+    for _ in 0..size {
+        let cluster = field.tightest_cluster();
+        field.toggle(cluster);
+        let void = field.largest_void();
+        field.toggle(void);
+        if void == cluster {
+            break;
+        }
+    }
+    let prototype = field.clone();
+
+    // rank the set pixels by removing the tightest cluster one at a time
+    let mut ranks = vec![0; size];
+    for rank in (0..initial).rev() {
+        let cluster = field.tightest_cluster();
+        field.toggle(cluster);
+        ranks[cluster] = rank;
+    }
+    // rank the remaining pixels by filling the largest void one at a time. once the majority of
+    // pixels is set, this amounts to removing the tightest cluster of unset pixels
+    let mut field = prototype;
+    for rank in initial..size {
+        let void = field.largest_void();
+        field.toggle(void);
+        ranks[void] = rank;
+    }
+
+    ranks
+        .into_iter()
+        .map(|rank| (rank as f64 + 0.5) / size as f64 * 2.0 - 1.0)
+        .collect()
+}
+
+// a binary pattern on a torus, along with the energy of every pixel, which is the sum of the
+// gaussian filter centered on the pixel over all set pixels
+#[derive(Clone)]
+struct Field {
+    resolution: usize,
+    kernel: Vec<(usize, usize, f64)>,
+    pattern: Vec<bool>,
+    energy: Vec<f64>,
+}
+
+impl Field {
+    fn new(resolution: usize) -> Self {
+        // the kernel must not wrap around the torus, or offsets would be counted repeatedly
+        let radius = RADIUS.min((resolution - 1) / 2);
+        let mut kernel = Vec::new();
+        for dx in 0..=2 * radius {
+            for dy in 0..=2 * radius {
+                let (ox, oy) = (dx.abs_diff(radius), dy.abs_diff(radius));
+                let weight = GAUSSIAN_WEIGHTS[ox * ox + oy * oy];
+                // offsets are stored shifted into the range [0, resolution), such that they can
+                // be added to an index and wrapped using a single remainder
+                kernel.push((
+                    (dx + resolution - radius) % resolution,
+                    (dy + resolution - radius) % resolution,
+                    weight,
+                ));
+            }
+        }
+        let size = resolution * resolution;
+        Self {
+            resolution,
+            kernel,
+            pattern: vec![false; size],
+            energy: vec![0.0; size],
+        }
+    }
+
+    fn toggle(&mut self, index: usize) {
+        let set = !self.pattern[index];
+        self.pattern[index] = set;
+        let sign = if set { 1.0 } else { -1.0 };
+        let (x, y) = (index / self.resolution, index % self.resolution);
+        for &(dx, dy, weight) in &self.kernel {
+            let nx = (x + dx) % self.resolution;
+            let ny = (y + dy) % self.resolution;
+            self.energy[nx * self.resolution + ny] += sign * weight;
+        }
+    }
+
+    // the set pixel with the highest energy
+    fn tightest_cluster(&self) -> usize {
+        self.extremum(true, |energy, best| energy > best)
+    }
+
+    // the unset pixel with the lowest energy
+    fn largest_void(&self) -> usize {
+        self.extremum(false, |energy, best| energy < best)
+    }
+
+    // the first pixel with the given state whose energy is not exceeded by any other according
+    // to the given comparison
+    fn extremum(&self, set: bool, better: fn(f64, f64) -> bool) -> usize {
+        let mut best = None;
+        for (index, (&state, &energy)) in self.pattern.iter().zip(&self.energy).enumerate() {
+            if state == set && best.is_none_or(|(_, best)| better(energy, best)) {
+                best = Some((index, energy));
+            }
+        }
+        best.map_or(0, |(index, _)| index)
+    }
+}
@@ -1,20 +1,93 @@
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use core::f64::consts::PI;
+
 pub(crate) fn noise1d(point: [f64; 1]) -> f64 {
-    ((point[0] as isize & 1) as f64).mul_add(2.0, -1.0)
+    if !is_finite(point) {
+        return f64::NAN;
+    }
+    (point[0] as i64 & 1) as f64 * 2.0 - 1.0
 }
 
 pub(crate) fn noise2d(point: [f64; 2]) -> f64 {
-    (((point[0] as isize & 1) ^ (point[1] as isize & 1)) as f64).mul_add(2.0, -1.0)
+    if !is_finite(point) {
+        return f64::NAN;
+    }
+    ((point[0] as i64 & 1) ^ (point[1] as i64 & 1)) as f64 * 2.0 - 1.0
 }
 
 pub(crate) fn noise3d(point: [f64; 3]) -> f64 {
-    (((point[0] as isize & 1) ^ (point[1] as isize & 1) ^ (point[2] as isize & 1)) as f64)
-        .mul_add(2.0, -1.0)
+    if !is_finite(point) {
+        return f64::NAN;
+    }
+    ((point[0] as i64 & 1) ^ (point[1] as i64 & 1) ^ (point[2] as i64 & 1)) as f64 * 2.0 - 1.0
 }
 
 pub(crate) fn noise4d(point: [f64; 4]) -> f64 {
-    (((point[0] as isize & 1)
-        ^ (point[1] as isize & 1)
-        ^ (point[2] as isize & 1)
-        ^ (point[3] as isize & 1)) as f64)
-        .mul_add(2.0, -1.0)
+    if !is_finite(point) {
+        return f64::NAN;
+    }
+    ((point[0] as i64 & 1) ^ (point[1] as i64 & 1) ^ (point[2] as i64 & 1) ^ (point[3] as i64 & 1))
+        as f64
+        * 2.0
+        - 1.0
+}
+
+#[inline]
+fn is_finite<const D: usize>(point: [f64; D]) -> bool {
+    point.iter().all(|x| x.is_finite())
+}
+
+// checks the period of a checkerboard, returning the name of the offending parameter and the
+// reason why it is invalid
+pub(crate) fn validate_period(period: &[f64]) -> Result<(), (&'static str, &'static str)> {
+    if period.iter().any(|x| !(*x > 0.0 && x.is_finite())) {
+        return Err(("period", "must be positive and finite"));
+    }
+    Ok(())
+}
+
+// a sine wave with the same period and sign as the checkerboard, for non-negative coordinates
+pub(crate) fn smooth<const D: usize>(point: [f64; D]) -> f64 {
+    if !is_finite(point) {
+        return f64::NAN;
+    }
+    -point.iter().map(|x| (PI * x).sin()).product::<f64>()
+}
+
+// the range of the smooth checkerboard within the box spanned by min and max
+pub(crate) fn smooth_interval<const D: usize>(min: [f64; D], max: [f64; D]) -> (f64, f64) {
+    let (mut lower, mut upper) = (1.0, 1.0);
+    for i in 0..D {
+        let (low, high) = sine_interval(min[i], max[i]);
+        let products = [lower * low, lower * high, upper * low, upper * high];
+        (lower, upper) = (f64::INFINITY, f64::NEG_INFINITY);
+        for product in products {
+            if product < lower {
+                lower = product;
+            }
+            if product > upper {
+                upper = product;
+            }
+        }
+    }
+    (-upper, -lower)
+}
+
+// the range of sin(pi * x) for x within [min, max]
+fn sine_interval(min: f64, max: f64) -> (f64, f64) {
+    let width = max - min;
+    if width.is_nan() || width >= 2.0 {
+        return (-1.0, 1.0);
+    }
+    let (a, b) = ((PI * min).sin(), (PI * max).sin());
+    let (mut lower, mut upper) = if a < b { (a, b) } else { (b, a) };
+    // the maxima lie at 1/2 + 2k and the minima at 3/2 + 2k
+    if ((min - 0.5) * 0.5).ceil() * 2.0 + 0.5 <= max {
+        upper = 1.0;
+    }
+    if ((min - 1.5) * 0.5).ceil() * 2.0 + 1.5 <= max {
+        lower = -1.0;
+    }
+    (lower, upper)
 }
@@ -122,6 +122,21 @@ pub(crate) const SIMPLEX_TRAVERSAL_LUT_3D: [[usize; 6]; 8] = [
 pub(crate) const SIMPLEX_SKEW_FACTOR_4D: f64 = 0.30901699437494745;
 pub(crate) const SIMPLEX_UNSKEW_FACTOR_4D: f64 = 0.13819660112501053;
 pub(crate) const SIMPLEX_NORMALIZATION_FACTOR_4D: f64 = 62.795597150623436;
+// the normalization factors of the generic simplex noise in 1 to 10 dimensions, obtained by
+// numerically maximizing the sum of the contributions of all lattice points within the radius of
+// the dimensionality, with every unit gradient pointing away from its lattice point
+pub(crate) const SIMPLEX_NORMALIZATION_FACTORS_ND: [f64; 10] = [
+    71.51381174666906,
+    99.20433458271864,
+    107.65348539728195,
+    108.567826464029,
+    42.69284824635199,
+    17.28197519575494,
+    8.163435230982435,
+    4.325306760621054,
+    2.4945936044548347,
+    1.5322746715590576,
+];
 pub(crate) const SIMPLEX_TRAVERSAL_LUT_4D: [[usize; 12]; 64] = [
     [0, 0, 0, 1, 0, 0, 1, 1, 0, 1, 1, 1], // 00: wzyx
     [0, 0, 1, 0, 0, 0, 1, 1, 0, 1, 1, 1], // 01: zwyx
@@ -188,3 +203,148 @@ pub(crate) const SIMPLEX_TRAVERSAL_LUT_4D: [[usize; 12]; 64] = [
     [1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 1], // 62: xywz
     [1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 1, 0], // 63: xyzw
 ];
+
+// opensimplex2 noise evaluates every vertex within a squared radius of 0.5 around the input
+// point. the normalization factors are the reciprocals of the maxima of the sum of the
+// contributions with every unit gradient pointing away from its vertex, obtained numerically. in
+// 2D, this is the same as for the generic simplex noise, as the lattice and radius are the same.
+pub(crate) const OPENSIMPLEX2_R_SQUARED: f64 = 0.5;
+pub(crate) const OPENSIMPLEX2_NORMALIZATION_FACTOR_2D: f64 = 99.20433458271864;
+pub(crate) const OPENSIMPLEX2_NORMALIZATION_FACTOR_3D: f64 = 107.653485397282;
+// the smooth variant evaluates every vertex within the distance between neighboring vertices in
+// 2D, and within the distance between the center and the corners of a cubic cell in 3D, and is
+// normalized alike
+pub(crate) const OPENSIMPLEX2S_R_SQUARED_2D: f64 = 2.0 / 3.0;
+pub(crate) const OPENSIMPLEX2S_R_SQUARED_3D: f64 = 0.75;
+pub(crate) const OPENSIMPLEX2S_NORMALIZATION_FACTOR_2D: f64 = 18.12237340376929;
+pub(crate) const OPENSIMPLEX2S_NORMALIZATION_FACTOR_3D: f64 = 11.523891901274776;
+// 24 unit gradients at angles of 7.5 + 15k degrees, which avoids aligning any of them with the
+// edges of the triangular lattice
+pub(crate) const OPENSIMPLEX2_GRADIENT_LUT_2D_SIZE: usize = 24;
+pub(crate) const OPENSIMPLEX2_GRADIENT_LUT_2D: [[f64; 2]; OPENSIMPLEX2_GRADIENT_LUT_2D_SIZE] = [
+    [0.9914448613738104, 0.13052619222005157],
+    [0.9238795325112867, 0.3826834323650898],
+    [0.7933533402912352, 0.6087614290087207],
+    [0.6087614290087207, 0.7933533402912352],
+    [0.3826834323650898, 0.9238795325112867],
+    [0.13052619222005157, 0.9914448613738104],
+    [-0.13052619222005157, 0.9914448613738104],
+    [-0.3826834323650898, 0.9238795325112867],
+    [-0.6087614290087207, 0.7933533402912352],
+    [-0.7933533402912352, 0.6087614290087207],
+    [-0.9238795325112867, 0.3826834323650898],
+    [-0.9914448613738104, 0.13052619222005157],
+    [-0.9914448613738104, -0.13052619222005157],
+    [-0.9238795325112867, -0.3826834323650898],
+    [-0.7933533402912352, -0.6087614290087207],
+    [-0.6087614290087207, -0.7933533402912352],
+    [-0.3826834323650898, -0.9238795325112867],
+    [-0.13052619222005157, -0.9914448613738104],
+    [0.13052619222005157, -0.9914448613738104],
+    [0.3826834323650898, -0.9238795325112867],
+    [0.6087614290087207, -0.7933533402912352],
+    [0.7933533402912352, -0.6087614290087207],
+    [0.9238795325112867, -0.3826834323650898],
+    [0.9914448613738104, -0.13052619222005157],
+];
+// 48 unit gradients, the permutations of [1, a, a] with a = 1 + sqrt(3 / 2) and of
+// [0.2 + 0.3 * sqrt(6), 0.6 - 0.1 * sqrt(6), 0] with all signs, normalized
+pub(crate) const OPENSIMPLEX2_GRADIENT_LUT_3D_SIZE: usize = 48;
+pub(crate) const OPENSIMPLEX2_GRADIENT_LUT_3D: [[f64; 3]; OPENSIMPLEX2_GRADIENT_LUT_3D_SIZE] = [
+    [0.30290544652768625, 0.6738873386790492, 0.6738873386790492],
+    [0.30290544652768625, 0.6738873386790492, -0.6738873386790492],
+    [0.30290544652768625, -0.6738873386790492, 0.6738873386790492],
+    [
+        0.30290544652768625,
+        -0.6738873386790492,
+        -0.6738873386790492,
+    ],
+    [-0.30290544652768625, 0.6738873386790492, 0.6738873386790492],
+    [
+        -0.30290544652768625,
+        0.6738873386790492,
+        -0.6738873386790492,
+    ],
+    [
+        -0.30290544652768625,
+        -0.6738873386790492,
+        0.6738873386790492,
+    ],
+    [
+        -0.30290544652768625,
+        -0.6738873386790492,
+        -0.6738873386790492,
+    ],
+    [0.6738873386790492, 0.30290544652768625, 0.6738873386790492],
+    [0.6738873386790492, 0.30290544652768625, -0.6738873386790492],
+    [0.6738873386790492, -0.30290544652768625, 0.6738873386790492],
+    [
+        0.6738873386790492,
+        -0.30290544652768625,
+        -0.6738873386790492,
+    ],
+    [-0.6738873386790492, 0.30290544652768625, 0.6738873386790492],
+    [
+        -0.6738873386790492,
+        0.30290544652768625,
+        -0.6738873386790492,
+    ],
+    [
+        -0.6738873386790492,
+        -0.30290544652768625,
+        0.6738873386790492,
+    ],
+    [
+        -0.6738873386790492,
+        -0.30290544652768625,
+        -0.6738873386790492,
+    ],
+    [0.6738873386790492, 0.6738873386790492, 0.30290544652768625],
+    [0.6738873386790492, 0.6738873386790492, -0.30290544652768625],
+    [0.6738873386790492, -0.6738873386790492, 0.30290544652768625],
+    [
+        0.6738873386790492,
+        -0.6738873386790492,
+        -0.30290544652768625,
+    ],
+    [-0.6738873386790492, 0.6738873386790492, 0.30290544652768625],
+    [
+        -0.6738873386790492,
+        0.6738873386790492,
+        -0.30290544652768625,
+    ],
+    [
+        -0.6738873386790492,
+        -0.6738873386790492,
+        0.30290544652768625,
+    ],
+    [
+        -0.6738873386790492,
+        -0.6738873386790492,
+        -0.30290544652768625,
+    ],
+    [0.9348469228349534, 0.3550510257216822, 0.0],
+    [0.9348469228349534, -0.3550510257216822, 0.0],
+    [-0.9348469228349534, 0.3550510257216822, 0.0],
+    [-0.9348469228349534, -0.3550510257216822, 0.0],
+    [0.9348469228349534, 0.0, 0.3550510257216822],
+    [0.9348469228349534, 0.0, -0.3550510257216822],
+    [-0.9348469228349534, 0.0, 0.3550510257216822],
+    [-0.9348469228349534, 0.0, -0.3550510257216822],
+    [0.3550510257216822, 0.9348469228349534, 0.0],
+    [-0.3550510257216822, 0.9348469228349534, 0.0],
+    [0.3550510257216822, -0.9348469228349534, 0.0],
+    [-0.3550510257216822, -0.9348469228349534, 0.0],
+    [0.0, 0.9348469228349534, 0.3550510257216822],
+    [0.0, 0.9348469228349534, -0.3550510257216822],
+    [0.0, -0.9348469228349534, 0.3550510257216822],
+    [0.0, -0.9348469228349534, -0.3550510257216822],
+    [0.3550510257216822, 0.0, 0.9348469228349534],
+    [-0.3550510257216822, 0.0, 0.9348469228349534],
+    [0.3550510257216822, 0.0, -0.9348469228349534],
+    [-0.3550510257216822, 0.0, -0.9348469228349534],
+    [0.0, 0.3550510257216822, 0.9348469228349534],
+    [0.0, -0.3550510257216822, 0.9348469228349534],
+    [0.0, 0.3550510257216822, -0.9348469228349534],
+    [0.0, -0.3550510257216822, -0.9348469228349534],
+];
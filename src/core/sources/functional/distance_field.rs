@@ -0,0 +1,73 @@
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use core::cmp::Ordering;
+
+// arranges the points into an implicit kd-tree. the median of every subslice along the axis
+// given by its depth is moved to the middle of the subslice, with the points below it on the
+// left and the remaining points on the right
+pub(crate) fn build<const D: usize>(points: &mut [[f64; D]]) {
+    build_node(points, 0);
+}
+
+fn build_node<const D: usize>(points: &mut [[f64; D]], depth: usize) {
+    if points.len() <= 1 {
+        return;
+    }
+    let axis = depth % D;
+    let mid = points.len() / 2;
+    points.select_nth_unstable_by(mid, |a, b| a[axis].total_cmp(&b[axis]));
+    let (left, right) = points.split_at_mut(mid);
+    build_node(left, depth + 1);
+    build_node(&mut right[1..], depth + 1);
+}
+
+// the euclidean distance from the point to the nearest point of the kd-tree built by build()
+pub(crate) fn nearest_distance<const D: usize>(tree: &[[f64; D]], point: [f64; D]) -> f64 {
+    nearest_distance_visits(tree, point).0
+}
+
+// like nearest_distance(), but also returns the number of nodes of the kd-tree visited by the
+// search, which allows verifying its complexity independently of the machine
+pub(crate) fn nearest_distance_visits<const D: usize>(
+    tree: &[[f64; D]],
+    point: [f64; D],
+) -> (f64, usize) {
+    if point.iter().any(|x| !x.is_finite()) {
+        return (f64::NAN, 0);
+    }
+    let (mut best, mut visits) = (f64::INFINITY, 0);
+    search(tree, point, 0, &mut best, &mut visits);
+    (best.sqrt(), visits)
+}
+
+// descends into the half containing the point first, and only visits the other half if the
+// splitting plane is closer than the nearest point found so far. distances are squared
+fn search<const D: usize>(
+    tree: &[[f64; D]],
+    point: [f64; D],
+    depth: usize,
+    best: &mut f64,
+    visits: &mut usize,
+) {
+    if tree.is_empty() {
+        return;
+    }
+    *visits += 1;
+    let axis = depth % D;
+    let mid = tree.len() / 2;
+    let node = tree[mid];
+    let distance = (0..D)
+        .map(|i| point[i] - node[i])
+        .map(|x| x * x)
+        .sum::<f64>();
+    *best = best.min(distance);
+    let delta = point[axis] - node[axis];
+    let (near, far) = match delta.total_cmp(&0.0) {
+        Ordering::Less => (&tree[..mid], &tree[mid + 1..]),
+        _ => (&tree[mid + 1..], &tree[..mid]),
+    };
+    search(near, point, depth + 1, best, visits);
+    if delta * delta < *best {
+        search(far, point, depth + 1, best, visits);
+    }
+}
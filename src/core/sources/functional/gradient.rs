@@ -0,0 +1,75 @@
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+
+// checks the endpoints of a linear gradient, returning the name of the offending parameter and
+// the reason why it is invalid
+pub(crate) fn validate_linear(
+    from: &[f64],
+    to: &[f64],
+) -> Result<(), (&'static str, &'static str)> {
+    if from.iter().any(|x| !x.is_finite()) {
+        return Err(("from", "must be finite"));
+    }
+    if to.iter().any(|x| !x.is_finite()) {
+        return Err(("to", "must be finite"));
+    }
+    let length_sq = from
+        .iter()
+        .zip(to)
+        .map(|(from, to)| to - from)
+        .map(|x| x * x)
+        .sum::<f64>();
+    if length_sq == 0.0 {
+        return Err(("to", "must differ from from"));
+    }
+    if !length_sq.is_finite() {
+        return Err(("to", "must be within a finite distance of from"));
+    }
+    Ok(())
+}
+
+// checks the parameters of a radial gradient, returning the name of the offending parameter and
+// the reason why it is invalid
+pub(crate) fn validate_radial(
+    center: &[f64],
+    inner_radius: f64,
+    outer_radius: f64,
+) -> Result<(), (&'static str, &'static str)> {
+    if center.iter().any(|x| !x.is_finite()) {
+        return Err(("center", "must be finite"));
+    }
+    if !(inner_radius >= 0.0 && inner_radius.is_finite()) {
+        return Err(("inner_radius", "must be non-negative and finite"));
+    }
+    if !(outer_radius > inner_radius && outer_radius.is_finite()) {
+        return Err(("outer_radius", "must be finite and exceed inner_radius"));
+    }
+    Ok(())
+}
+
+// the position of the projection of the point onto the line through both endpoints, where 0
+// is at from and 1 is at to
+pub(crate) fn linear<const D: usize>(from: [f64; D], to: [f64; D], point: [f64; D]) -> f64 {
+    if point.iter().any(|x| !x.is_finite()) {
+        return f64::NAN;
+    }
+    let (mut projection, mut length_sq) = (0.0, 0.0);
+    for i in 0..D {
+        let direction = to[i] - from[i];
+        projection += (point[i] - from[i]) * direction;
+        length_sq += direction * direction;
+    }
+    projection / length_sq
+}
+
+// the euclidean distance from the center to the point
+pub(crate) fn radial<const D: usize>(center: [f64; D], point: [f64; D]) -> f64 {
+    if point.iter().any(|x| !x.is_finite()) {
+        return f64::NAN;
+    }
+    (0..D)
+        .map(|i| point[i] - center[i])
+        .map(|x| x * x)
+        .sum::<f64>()
+        .sqrt()
+}
@@ -0,0 +1,91 @@
+use crate::core::sources::FalloffCurve;
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+
+// checks the parameters of a round island, returning the name of the offending parameter and
+// the reason why it is invalid
+pub(crate) fn validate_round(
+    center: &[f64],
+    radius: f64,
+    falloff: FalloffCurve,
+) -> Result<(), (&'static str, &'static str)> {
+    if center.iter().any(|x| !x.is_finite()) {
+        return Err(("center", "must be finite"));
+    }
+    if !(radius > 0.0 && radius.is_finite()) {
+        return Err(("radius", "must be positive and finite"));
+    }
+    validate_falloff(falloff)
+}
+
+// checks the parameters of a rectangular island, returning the name of the offending parameter
+// and the reason why it is invalid
+pub(crate) fn validate_rect(
+    min: &[f64],
+    max: &[f64],
+    falloff: FalloffCurve,
+) -> Result<(), (&'static str, &'static str)> {
+    if min.iter().any(|x| !x.is_finite()) {
+        return Err(("min", "must be finite"));
+    }
+    if max.iter().any(|x| !x.is_finite()) {
+        return Err(("max", "must be finite"));
+    }
+    if min
+        .iter()
+        .zip(max)
+        .any(|(min, max)| !(max - min > 0.0 && (max - min).is_finite()))
+    {
+        return Err(("max", "must exceed min by a finite amount along every axis"));
+    }
+    validate_falloff(falloff)
+}
+
+fn validate_falloff(falloff: FalloffCurve) -> Result<(), (&'static str, &'static str)> {
+    match falloff {
+        FalloffCurve::Exponent(exponent) if !(exponent > 0.0 && exponent.is_finite()) => {
+            Err(("falloff", "exponent must be positive and finite"))
+        }
+        _ => Ok(()),
+    }
+}
+
+// maps the offsets of the point from the center of the island, relative to the extent of the
+// island along each axis, to a value falling from 1 at the center to -1 at the edge and beyond.
+// the edge lies where the given distance of the offsets reaches 1, except for the square bump,
+// which is the product of a bump along each axis and thus ignores the distance
+pub(crate) fn falloff(
+    curve: FalloffCurve,
+    offsets: [f64; 2],
+    distance: impl Fn([f64; 2]) -> f64,
+) -> f64 {
+    if offsets.iter().any(|x| !x.is_finite()) {
+        return f64::NAN;
+    }
+    let height = match curve {
+        FalloffCurve::Linear => 1.0 - distance(offsets).min(1.0),
+        FalloffCurve::Smoothstep => {
+            let d = distance(offsets).min(1.0);
+            1.0 - d * d * (3.0 - 2.0 * d)
+        }
+        FalloffCurve::SquareBump => offsets
+            .iter()
+            .map(|x| {
+                let x = x.abs().min(1.0);
+                1.0 - x * x
+            })
+            .product(),
+        FalloffCurve::Exponent(exponent) => 1.0 - distance(offsets).min(1.0).powf(exponent),
+    };
+    height * 2.0 - 1.0
+}
+
+// the euclidean length of the offsets, for round islands
+pub(crate) fn euclidean([x, y]: [f64; 2]) -> f64 {
+    (x * x + y * y).sqrt()
+}
+
+// the largest absolute offset, for rectangular islands
+pub(crate) fn chebyshev([x, y]: [f64; 2]) -> f64 {
+    x.abs().max(y.abs())
+}
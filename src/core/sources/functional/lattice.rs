@@ -0,0 +1,85 @@
+use super::constants::PERMUTATION_TABLE_SIZE;
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+
+/// Reduces the point modulo the period of the permutation table along each dimension.
+///
+/// Noise on a grid lattice depends on the lattice part of the input only through the hashed
+/// lattice coordinates, which repeat with the size of the permutation table. The reduction of the
+/// lattice part is exact, so only the fractional part is rounded, and it is rounded the same way
+/// regardless of the distance of the input to the origin.
+#[inline]
+pub(crate) fn reduce_to_period<const D: usize>(point: [f64; D]) -> [f64; D] {
+    point.map(|x| {
+        let lattice = x.floor();
+        lattice.rem_euclid(PERMUTATION_TABLE_SIZE as f64) + (x - lattice)
+    })
+}
+
+/// Reduces an integer lattice point to the hashed lattice coordinates of the equivalent `f64`
+/// point, for sampling noise on a grid lattice without interpolation.
+///
+/// The point is converted to `f64` first, such that coordinates beyond `2^53` are rounded exactly
+/// as when sampling the converted point. Unless `exact` is set, the coordinates are then cast to
+/// `i64` before they are reduced, which saturates at `i64::MAX` like the cast of the floored
+/// input in the noise functions.
+#[inline]
+pub(crate) fn reduce_lattice_point<const D: usize>(point: [i64; D], exact: bool) -> [usize; D] {
+    point.map(|x| {
+        let x = x as f64;
+        if exact {
+            x.rem_euclid(PERMUTATION_TABLE_SIZE as f64) as usize
+        } else {
+            (x as i64).rem_euclid(PERMUTATION_TABLE_SIZE as i64) as usize
+        }
+    })
+}
+
+/// Computes the origin of the skewed simplex lattice cell in which the point lies, as well as the
+/// position of the point relative to that origin in unskewed space.
+///
+/// Instead of skewing the input directly, the point is split into its integer lattice part, which
+/// is kept as `i64`, and its fractional part. Large products of the lattice part with the skew
+/// factors are split the same way, such that the relative position is computed from small values
+/// only and retains its precision far from the origin.
+#[inline]
+pub(crate) fn skew_split<const D: usize>(
+    point: [f64; D],
+    skew_factor: f64,
+    unskew_factor: f64,
+) -> ([i64; D], [f64; D]) {
+    let lattice = point.map(f64::floor);
+    let fract: [f64; D] = core::array::from_fn(|i| point[i] - lattice[i]);
+    let lattice = lattice.map(|x| x as i64);
+    let lattice_sum = lattice.iter().fold(0_i64, |acc, &x| acc.wrapping_add(x));
+    let (skew_int, skew_fract) = mul_split(lattice_sum, skew_factor);
+    let skew_fract = skew_fract + fract.iter().sum::<f64>() * skew_factor;
+    let cell: [i64; D] = core::array::from_fn(|i| {
+        lattice[i]
+            .wrapping_add(skew_int)
+            .wrapping_add((fract[i] + skew_fract).floor() as i64)
+    });
+    let cell_sum = cell.iter().fold(0_i64, |acc, &x| acc.wrapping_add(x));
+    let (unskew_int, unskew_fract) = mul_split(cell_sum, unskew_factor);
+    let relative = core::array::from_fn(|i| {
+        lattice[i].wrapping_sub(cell[i]).wrapping_add(unskew_int) as f64 + fract[i] + unskew_fract
+    });
+    (cell, relative)
+}
+
+/// Wraps a lattice coordinate into the range of the permutation table.
+#[inline]
+pub(crate) fn wrap(x: i64) -> usize {
+    x.rem_euclid(PERMUTATION_TABLE_SIZE as i64) as usize
+}
+
+/// Computes the exact product of `n` and `factor`, split into its floor and fractional part.
+///
+/// The factor is represented exactly as a fixed-point number with 64 fractional bits, which is
+/// possible for any `f64` in `(-1, 1)` not smaller in magnitude than `2^-11`.
+#[inline]
+fn mul_split(n: i64, factor: f64) -> (i64, f64) {
+    const SCALE: f64 = 18446744073709551616.0; // 2^64
+    let product = n as i128 * (factor * SCALE) as i128;
+    ((product >> 64) as i64, product as u64 as f64 / SCALE)
+}
@@ -1,7 +1,16 @@
+pub(crate) mod blue;
 pub(crate) mod checkerboard;
 pub(crate) mod constants;
+pub(crate) mod distance_field;
+pub(crate) mod gradient;
 pub(crate) mod improved_perlin;
+pub(crate) mod island;
+pub(crate) mod lattice;
+pub(crate) mod open_simplex2;
 pub(crate) mod perlin;
 pub(crate) mod simplex;
+pub(crate) mod spot;
 pub(crate) mod value;
+pub(crate) mod wavelet;
+pub(crate) mod white;
 pub(crate) mod worley;
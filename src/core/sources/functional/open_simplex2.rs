@@ -0,0 +1,186 @@
+use super::{constants::*, lattice::wrap};
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::utils::ptable::{lookup, PermutationTable};
+
+// conservative bounds of the absolute output value. the normalization factors are obtained from
+// the worst case, such that the margin only accounts for rounding.
+pub(crate) const BOUND_2D: f64 = 1.001;
+pub(crate) const BOUND_3D: f64 = 1.001;
+
+pub(crate) fn noise2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
+    let [x, y] = point;
+    // transform into lattice space and floor for the origin of the rhombus
+    let skew = (x + y) * SIMPLEX_SKEW_FACTOR_2D;
+    let (xsb, ysb) = ((x + skew).floor(), (y + skew).floor());
+    let (xsi, ysi) = (x + skew - xsb, y + skew - ysb);
+    // input point relative to the origin of the rhombus in source space
+    let unskew = (xsi + ysi) * SIMPLEX_UNSKEW_FACTOR_2D;
+    let (dx0, dy0) = (xsi - unskew, ysi - unskew);
+    let (i, j) = (wrap(xsb as i64), wrap(ysb as i64));
+    // the vertices of the triangle containing the point are the origin, the opposite corner of
+    // the rhombus, and the corner on the side of the diagonal the point lies on
+    let mut value = contribution2d(OPENSIMPLEX2_R_SQUARED, perm.hash2d(i, j), dx0, dy0);
+    let (dx1, dy1) = (
+        dx0 - 1.0 + 2.0 * SIMPLEX_UNSKEW_FACTOR_2D,
+        dy0 - 1.0 + 2.0 * SIMPLEX_UNSKEW_FACTOR_2D,
+    );
+    value += contribution2d(OPENSIMPLEX2_R_SQUARED, perm.hash2d(i + 1, j + 1), dx1, dy1);
+    value += if dx0 > dy0 {
+        let (dx2, dy2) = (
+            dx0 - 1.0 + SIMPLEX_UNSKEW_FACTOR_2D,
+            dy0 + SIMPLEX_UNSKEW_FACTOR_2D,
+        );
+        contribution2d(OPENSIMPLEX2_R_SQUARED, perm.hash2d(i + 1, j), dx2, dy2)
+    } else {
+        let (dx2, dy2) = (
+            dx0 + SIMPLEX_UNSKEW_FACTOR_2D,
+            dy0 - 1.0 + SIMPLEX_UNSKEW_FACTOR_2D,
+        );
+        contribution2d(OPENSIMPLEX2_R_SQUARED, perm.hash2d(i, j + 1), dx2, dy2)
+    };
+    value * OPENSIMPLEX2_NORMALIZATION_FACTOR_2D
+}
+
+pub(crate) fn noise3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
+    // rotate by half a turn around the main diagonal, such that the lattice is not aligned with
+    // the axes of the input
+    let rotation = (point[0] + point[1] + point[2]) * (2.0 / 3.0);
+    let point = point.map(|x| rotation - x);
+    // the body-centered cubic lattice consists of two cubic lattices, offset by half a cell
+    // along every axis. the closest vertex of the first one is found by rounding.
+    let cell = point.map(|x| (x + 0.5).floor());
+    let offset: [f64; 3] = core::array::from_fn(|i| point[i] - cell[i]);
+    let cell = cell.map(|x| x as i64);
+    let value = cubic_lattice3d(perm, cell, 0, offset);
+    // the closest vertex of the second lattice is the corner of the cell of the first one on the
+    // side of the point, which is identified by the lower corner of the cell
+    let cell = core::array::from_fn(|i| cell[i].wrapping_sub((offset[i] < 0.0) as i64));
+    let offset = offset.map(|x| if x < 0.0 { x + 0.5 } else { x - 0.5 });
+    let value = value + cubic_lattice3d(perm, cell, 1, offset);
+    value * OPENSIMPLEX2_NORMALIZATION_FACTOR_3D
+}
+
+pub(crate) fn smooth_noise2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
+    let [x, y] = point;
+    let skew = (x + y) * SIMPLEX_SKEW_FACTOR_2D;
+    let (xsb, ysb) = ((x + skew).floor(), (y + skew).floor());
+    let (xsi, ysi) = (x + skew - xsb, y + skew - ysb);
+    let unskew = (xsi + ysi) * SIMPLEX_UNSKEW_FACTOR_2D;
+    let (dx0, dy0) = (xsi - unskew, ysi - unskew);
+    let (i, j) = (wrap(xsb as i64) as i64, wrap(ysb as i64) as i64);
+    // the vertices within range are those of the triangle containing the point, and the far
+    // vertices of the three triangles sharing an edge with it, given in lattice space
+    let vertices = if dx0 > dy0 {
+        [[0, 0], [1, 0], [1, 1], [0, -1], [2, 1], [0, 1]]
+    } else {
+        [[0, 0], [0, 1], [1, 1], [-1, 0], [1, 2], [1, 0]]
+    };
+    let mut value = 0.0;
+    for [di, dj] in vertices {
+        let unskew = (di + dj) as f64 * SIMPLEX_UNSKEW_FACTOR_2D;
+        value += contribution2d(
+            OPENSIMPLEX2S_R_SQUARED_2D,
+            perm.hash2d(wrap(i + di), wrap(j + dj)),
+            dx0 - di as f64 + unskew,
+            dy0 - dj as f64 + unskew,
+        );
+    }
+    value * OPENSIMPLEX2S_NORMALIZATION_FACTOR_2D
+}
+
+pub(crate) fn smooth_noise3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
+    let rotation = (point[0] + point[1] + point[2]) * (2.0 / 3.0);
+    let point = point.map(|x| rotation - x);
+    // the vertices of the second lattice with cell coordinates i lie at i + 0.5
+    let value = cubic_cell3d(perm, point, 0) + cubic_cell3d(perm, point.map(|x| x - 0.5), 1);
+    value * OPENSIMPLEX2S_NORMALIZATION_FACTOR_3D
+}
+
+// sums the contributions of the corners of the cell of one of the cubic lattices containing the
+// point. within the larger radius of the smooth variant, these are all the vertices in range.
+#[inline]
+fn cubic_cell3d(perm: &PermutationTable, point: [f64; 3], lattice: usize) -> f64 {
+    let base = point.map(|x| x.floor());
+    let offset: [f64; 3] = core::array::from_fn(|i| point[i] - base[i]);
+    let base = base.map(|x| x as i64);
+    let mut value = 0.0;
+    for corner in 0..8 {
+        let corner = [corner & 1, (corner >> 1) & 1, (corner >> 2) & 1];
+        value += contribution3d(
+            perm,
+            OPENSIMPLEX2S_R_SQUARED_3D,
+            core::array::from_fn(|i| base[i].wrapping_add(corner[i])),
+            lattice,
+            core::array::from_fn(|i| offset[i] - corner[i] as f64),
+        );
+    }
+    value
+}
+
+// sums the contributions of the vertices of one of the cubic lattices, given its closest vertex
+#[inline]
+fn cubic_lattice3d(
+    perm: &PermutationTable,
+    cell: [i64; 3],
+    lattice: usize,
+    offset: [f64; 3],
+) -> f64 {
+    let value = contribution3d(perm, OPENSIMPLEX2_R_SQUARED, cell, lattice, offset);
+    // the only other vertex within range is the neighbor along the axis of the largest offset
+    let mut axis = 0;
+    for i in 1..3 {
+        if offset[i].abs() > offset[axis].abs() {
+            axis = i;
+        }
+    }
+    let (mut neighbor, mut neighbor_offset) = (cell, offset);
+    let step = if offset[axis] < 0.0 { -1 } else { 1 };
+    neighbor[axis] = neighbor[axis].wrapping_add(step);
+    neighbor_offset[axis] -= step as f64;
+    value
+        + contribution3d(
+            perm,
+            OPENSIMPLEX2_R_SQUARED,
+            neighbor,
+            lattice,
+            neighbor_offset,
+        )
+}
+
+fn contribution2d(r_squared: f64, hash: usize, x: f64, y: f64) -> f64 {
+    let mut t = r_squared - x * x - y * y;
+    if t <= 0.0 {
+        0.0
+    } else {
+        let gradient = lookup(
+            &OPENSIMPLEX2_GRADIENT_LUT_2D,
+            hash % OPENSIMPLEX2_GRADIENT_LUT_2D_SIZE,
+        );
+        t *= t;
+        t * t * (gradient[0] * x + gradient[1] * y)
+    }
+}
+
+// the lattice is hashed as a fourth coordinate, such that the vertices of both lattices which
+// share the same cell coordinates are assigned independent gradients
+fn contribution3d(
+    perm: &PermutationTable,
+    r_squared: f64,
+    cell: [i64; 3],
+    lattice: usize,
+    x: [f64; 3],
+) -> f64 {
+    let mut t = r_squared - x[0] * x[0] - x[1] * x[1] - x[2] * x[2];
+    if t <= 0.0 {
+        0.0
+    } else {
+        let [i, j, k] = cell.map(wrap);
+        let gradient = lookup(
+            &OPENSIMPLEX2_GRADIENT_LUT_3D,
+            perm.hash4d(i, j, k, lattice) % OPENSIMPLEX2_GRADIENT_LUT_3D_SIZE,
+        );
+        t *= t;
+        t * t * (gradient[0] * x[0] + gradient[1] * x[1] + gradient[2] * x[2])
+    }
+}
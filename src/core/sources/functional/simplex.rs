@@ -1,10 +1,132 @@
-use super::constants::*;
+use super::{constants::*, lattice, white::mix};
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
 use crate::core::utils::{
     math::{Vec2, Vec3, Vec4},
-    ptable::PermutationTable,
+    ptable::{lookup, PermutationTable},
 };
 
-pub(crate) fn noise1d(perm: &PermutationTable, point: [f64; 1]) -> f64 {
+// conservative bounds of the absolute output value, obtained by maximizing the sum of the corner
+// contributions with the worst case gradient at every corner. the normalization factors scale
+// typical maxima to 1, which the worst case may slightly exceed, hence the margin.
+pub(crate) const BOUND_1D: f64 = 1.001;
+pub(crate) const BOUND_2D: f64 = 0.977;
+pub(crate) const BOUND_3D: f64 = 1.001;
+pub(crate) const BOUND_4D: f64 = 1.001;
+pub(crate) const BOUND_ND: f64 = 1.001;
+
+// the squared radius of the corner contributions, along with the factor by which the
+// normalization factors are scaled to compensate for the magnitude of the contributions, which
+// is proportional to the radius to the power of 9
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Kernel {
+    pub(crate) r_squared: f64,
+    scale: f64,
+}
+
+impl Kernel {
+    pub(crate) const DEFAULT: Self = Self {
+        r_squared: SIMPLEX_R_SQUARED,
+        scale: 1.0,
+    };
+
+    pub(crate) fn new(r_squared: f64) -> Self {
+        // the ratio to the power of 4.5, computed from exactly rounded operations only
+        let ratio = SIMPLEX_R_SQUARED / r_squared;
+        let ratio_squared = ratio * ratio;
+        Self {
+            r_squared,
+            scale: ratio_squared * ratio_squared * ratio.sqrt(),
+        }
+    }
+
+    pub(crate) fn is_default(&self) -> bool {
+        *self == Self::DEFAULT
+    }
+}
+
+impl Default for Kernel {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Kernel {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.r_squared)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Kernel {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let r_squared = f64::deserialize(deserializer)?;
+        validate_r_squared(r_squared)
+            .map_err(|(name, reason)| serde::de::Error::custom(format_args!("{name} {reason}")))?;
+        Ok(Self::new(r_squared))
+    }
+}
+
+// the smallest supported squared radius of the corner contributions, below which the noise
+// degenerates into isolated dots, and the rescaling grows towards overflowing
+const MIN_R_SQUARED: f64 = 0.01;
+
+// checks the squared radius of the corner contributions, returning the name of the offending
+// parameter and the reason why it is invalid. beyond the default radius, the contributions of
+// lattice points outside of the simplex containing the point would reach into it without being
+// summed, and thus cause discontinuities at the borders of the simplices.
+pub(crate) fn validate_r_squared(r_squared: f64) -> Result<(), (&'static str, &'static str)> {
+    if (MIN_R_SQUARED..=SIMPLEX_R_SQUARED).contains(&r_squared) {
+        Ok(())
+    } else {
+        Err(("r_squared", "must be within [0.01, 0.5]"))
+    }
+}
+
+// simplex noise for any dimensionality. the specialized implementations for up to 4 dimensions
+// are faster than the generic one, and the conversions between the point types are resolved at
+// compile time
+#[inline]
+pub(crate) fn noise<const D: usize>(
+    perm: &PermutationTable,
+    kernel: Kernel,
+    large: bool,
+    point: [f64; D],
+) -> f64 {
+    match D {
+        1 => {
+            let point = if large {
+                lattice::reduce_to_period(resize(point))
+            } else {
+                resize(point)
+            };
+            noise1d(perm, kernel, point)
+        }
+        2 if large => noise2d_large(perm, kernel, resize(point)),
+        2 => noise2d(perm, kernel, resize(point)),
+        3 if large => noise3d_large(perm, kernel, resize(point)),
+        3 => noise3d(perm, kernel, resize(point)),
+        4 if large => noise4d_large(perm, kernel, resize(point)),
+        4 => noise4d(perm, kernel, resize(point)),
+        _ => {
+            let [value] = if large {
+                noise_nd_large(perm, kernel, point)
+            } else {
+                noise_nd(perm, kernel, point)
+            };
+            value
+        }
+    }
+}
+
+// converts a point between dimensionalities known to be equal
+#[inline]
+fn resize<const D: usize, const N: usize>(point: [f64; D]) -> [f64; N] {
+    core::array::from_fn(|i| point[i])
+}
+
+pub(crate) fn noise1d(perm: &PermutationTable, kernel: Kernel, point: [f64; 1]) -> f64 {
     let x = point[0];
     // no transformation into lattice space required, get cube origin
     let i0 = x.floor();
@@ -13,21 +135,41 @@ pub(crate) fn noise1d(perm: &PermutationTable, point: [f64; 1]) -> f64 {
     let x1 = x0 - 1.0;
     // hashed gradient (-1 or 1) directly
     let i0 = i0.rem_euclid(PERMUTATION_TABLE_SIZE as f64) as usize;
-    let gi0 = unsafe { perm.hash1d(i0) % GRADIENT_LUT_1D_SIZE };
-    let gi1 = unsafe { perm.hash1d(i0 + 1) % GRADIENT_LUT_1D_SIZE };
+    let gi0 = perm.hash1d(i0) % GRADIENT_LUT_1D_SIZE;
+    let gi1 = perm.hash1d(i0 + 1) % GRADIENT_LUT_1D_SIZE;
     // compute contributions
-    let n0 = unsafe { contribution1d(x0, gi0) };
-    let n1 = unsafe { contribution1d(x1, gi1) };
+    let n0 = contribution1d(kernel.r_squared, x0, gi0);
+    let n1 = contribution1d(kernel.r_squared, x1, gi1);
     // combine contributions and scale to [-1, 1]
-    (n0 + n1) * SIMPLEX_NORMALIZATION_FACTOR_1D
+    (n0 + n1) * SIMPLEX_NORMALIZATION_FACTOR_1D * kernel.scale
 }
 
-pub(crate) fn noise2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
+pub(crate) fn noise2d(perm: &PermutationTable, kernel: Kernel, point: [f64; 2]) -> f64 {
     let x = Vec2::from(point);
     // transform into lattice space and floor for cube origin
     let is = (x + x.sum() * SIMPLEX_SKEW_FACTOR_2D).floor();
     // input point relative to unskewed cube (and simplex) origin in source space
     let x0 = x - is + is.sum() * SIMPLEX_UNSKEW_FACTOR_2D;
+    let is = is
+        .cast::<i64>()
+        .rem_euclid(PERMUTATION_TABLE_SIZE as i64)
+        .cast();
+    simplex2d(perm, kernel, is, x0)
+}
+
+pub(crate) fn noise2d_large(perm: &PermutationTable, kernel: Kernel, point: [f64; 2]) -> f64 {
+    // split into lattice and fractional part, then skew into lattice space
+    let (is, x0) = lattice::skew_split(point, SIMPLEX_SKEW_FACTOR_2D, SIMPLEX_UNSKEW_FACTOR_2D);
+    simplex2d(
+        perm,
+        kernel,
+        Vec2::from(is.map(lattice::wrap)),
+        Vec2::from(x0),
+    )
+}
+
+#[inline]
+fn simplex2d(perm: &PermutationTable, kernel: Kernel, is: Vec2<usize>, x0: Vec2<f64>) -> f64 {
     // compute middle simplex traversal vector(s) between 0-vector and 1-vector
     let mut i1 = Vec2::from([1, 0]);
     if x0.x < x0.y {
@@ -38,24 +180,43 @@ pub(crate) fn noise2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
     let x1 = x0 - i1.cast() + SIMPLEX_UNSKEW_FACTOR_2D;
     let x2 = x0 - 1.0 + 2.0 * SIMPLEX_UNSKEW_FACTOR_2D;
     // hashed gradient indices
-    let is = is.rem_euclid(PERMUTATION_TABLE_SIZE as f64).cast();
-    let gi0 = unsafe { perm.hash2d(is.x, is.y) } % MIDPOINT_GRADIENT_LUT_2D_SIZE;
-    let gi1 = unsafe { perm.hash2d(is.x + i1.x, is.y + i1.y) } % MIDPOINT_GRADIENT_LUT_2D_SIZE;
-    let gi2 = unsafe { perm.hash2d(is.x + 1, is.y + 1) } % MIDPOINT_GRADIENT_LUT_2D_SIZE;
+    let gi0 = perm.hash2d(is.x, is.y) % MIDPOINT_GRADIENT_LUT_2D_SIZE;
+    let gi1 = perm.hash2d(is.x + i1.x, is.y + i1.y) % MIDPOINT_GRADIENT_LUT_2D_SIZE;
+    let gi2 = perm.hash2d(is.x + 1, is.y + 1) % MIDPOINT_GRADIENT_LUT_2D_SIZE;
     // compute contributions
-    let n0 = unsafe { contribution2d(x0, gi0) };
-    let n1 = unsafe { contribution2d(x1, gi1) };
-    let n2 = unsafe { contribution2d(x2, gi2) };
+    let n0 = contribution2d(kernel.r_squared, x0, gi0);
+    let n1 = contribution2d(kernel.r_squared, x1, gi1);
+    let n2 = contribution2d(kernel.r_squared, x2, gi2);
     // combine contributions and scale to [-1, 1]
-    (n0 + n1 + n2) * SIMPLEX_NORMALIZATION_FACTOR_2D
+    (n0 + n1 + n2) * SIMPLEX_NORMALIZATION_FACTOR_2D * kernel.scale
 }
 
-pub(crate) fn noise3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
+pub(crate) fn noise3d(perm: &PermutationTable, kernel: Kernel, point: [f64; 3]) -> f64 {
     let x = Vec3::from(point);
     // transform into lattice space and floor for cube origin
     let is = (x + x.sum() * SIMPLEX_SKEW_FACTOR_3D).floor();
     // input point relative to unskewed cube (and simplex) origin in source space
     let x0 = x - is + is.sum() * SIMPLEX_UNSKEW_FACTOR_3D;
+    let is = is
+        .cast::<i64>()
+        .rem_euclid(PERMUTATION_TABLE_SIZE as i64)
+        .cast();
+    simplex3d(perm, kernel, is, x0)
+}
+
+pub(crate) fn noise3d_large(perm: &PermutationTable, kernel: Kernel, point: [f64; 3]) -> f64 {
+    // split into lattice and fractional part, then skew into lattice space
+    let (is, x0) = lattice::skew_split(point, SIMPLEX_SKEW_FACTOR_3D, SIMPLEX_UNSKEW_FACTOR_3D);
+    simplex3d(
+        perm,
+        kernel,
+        Vec3::from(is.map(lattice::wrap)),
+        Vec3::from(x0),
+    )
+}
+
+#[inline]
+fn simplex3d(perm: &PermutationTable, kernel: Kernel, is: Vec3<usize>, x0: Vec3<f64>) -> f64 {
     // compute middle simplex traversal vector(s) between 0-vector and 1-vector
     let idx = (x0.x > x0.y) as usize * 4 + (x0.y > x0.z) as usize * 2 + (x0.x > x0.z) as usize;
     let i1 = Vec3::from([
@@ -73,26 +234,45 @@ pub(crate) fn noise3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
     let x2 = x0 - i2.cast() + 2.0 * SIMPLEX_UNSKEW_FACTOR_3D;
     let x3 = x0 - 1.0 + 3.0 * SIMPLEX_UNSKEW_FACTOR_3D;
     // hashed gradient indices
-    let is = is.cast().rem_euclid(PERMUTATION_TABLE_SIZE);
-    let gi0 = unsafe { perm.hash3d_vec(is) } % MIDPOINT_GRADIENT_LUT_3D_SIZE;
-    let gi1 = unsafe { perm.hash3d_vec(is + i1) } % MIDPOINT_GRADIENT_LUT_3D_SIZE;
-    let gi2 = unsafe { perm.hash3d_vec(is + i2) } % MIDPOINT_GRADIENT_LUT_3D_SIZE;
-    let gi3 = unsafe { perm.hash3d_vec(is + 1) } % MIDPOINT_GRADIENT_LUT_3D_SIZE;
+    let gi0 = perm.hash3d_vec(is) % MIDPOINT_GRADIENT_LUT_3D_SIZE;
+    let gi1 = perm.hash3d_vec(is + i1) % MIDPOINT_GRADIENT_LUT_3D_SIZE;
+    let gi2 = perm.hash3d_vec(is + i2) % MIDPOINT_GRADIENT_LUT_3D_SIZE;
+    let gi3 = perm.hash3d_vec(is + 1) % MIDPOINT_GRADIENT_LUT_3D_SIZE;
     // compute contributions
-    let n0 = unsafe { contribution3d(x0, gi0) };
-    let n1 = unsafe { contribution3d(x1, gi1) };
-    let n2 = unsafe { contribution3d(x2, gi2) };
-    let n3 = unsafe { contribution3d(x3, gi3) };
+    let n0 = contribution3d(kernel.r_squared, x0, gi0);
+    let n1 = contribution3d(kernel.r_squared, x1, gi1);
+    let n2 = contribution3d(kernel.r_squared, x2, gi2);
+    let n3 = contribution3d(kernel.r_squared, x3, gi3);
     // combine contributions and scale to [-1, 1]
-    (n0 + n1 + n2 + n3) * SIMPLEX_NORMALIZATION_FACTOR_3D
+    (n0 + n1 + n2 + n3) * SIMPLEX_NORMALIZATION_FACTOR_3D * kernel.scale
 }
 
-pub(crate) fn noise4d(perm: &PermutationTable, point: [f64; 4]) -> f64 {
+pub(crate) fn noise4d(perm: &PermutationTable, kernel: Kernel, point: [f64; 4]) -> f64 {
     let x = Vec4::from(point);
     // transform into lattice space and floor for cube origin
     let is = (x + x.sum() * SIMPLEX_SKEW_FACTOR_4D).floor();
     // input point relative to unskewed cube (and simplex) origin in source space
     let x0 = x - is + is.sum() * SIMPLEX_UNSKEW_FACTOR_4D;
+    let is = is
+        .cast::<i64>()
+        .rem_euclid(PERMUTATION_TABLE_SIZE as i64)
+        .cast();
+    simplex4d(perm, kernel, is, x0)
+}
+
+pub(crate) fn noise4d_large(perm: &PermutationTable, kernel: Kernel, point: [f64; 4]) -> f64 {
+    // split into lattice and fractional part, then skew into lattice space
+    let (is, x0) = lattice::skew_split(point, SIMPLEX_SKEW_FACTOR_4D, SIMPLEX_UNSKEW_FACTOR_4D);
+    simplex4d(
+        perm,
+        kernel,
+        Vec4::from(is.map(lattice::wrap)),
+        Vec4::from(x0),
+    )
+}
+
+#[inline]
+fn simplex4d(perm: &PermutationTable, kernel: Kernel, is: Vec4<usize>, x0: Vec4<f64>) -> f64 {
     // compute middle simplex traversal vector(s) between 0-vector and 1-vector
     let idx = (x0.x > x0.y) as usize * 32
         + (x0.x > x0.z) as usize * 16
@@ -124,68 +304,305 @@ pub(crate) fn noise4d(perm: &PermutationTable, point: [f64; 4]) -> f64 {
     let x3 = x0 - i3.cast() + 3.0 * SIMPLEX_UNSKEW_FACTOR_4D;
     let x4 = x0 - 1.0 + 4.0 * SIMPLEX_UNSKEW_FACTOR_4D;
     // hashed gradient indices
-    let is = is.cast().rem_euclid(PERMUTATION_TABLE_SIZE);
-    let gi0 = unsafe { perm.hash4d_vec(is) } % MIDPOINT_GRADIENT_LUT_4D_SIZE;
-    let gi1 = unsafe { perm.hash4d_vec(is + i1) } % MIDPOINT_GRADIENT_LUT_4D_SIZE;
-    let gi2 = unsafe { perm.hash4d_vec(is + i2) } % MIDPOINT_GRADIENT_LUT_4D_SIZE;
-    let gi3 = unsafe { perm.hash4d_vec(is + i3) } % MIDPOINT_GRADIENT_LUT_4D_SIZE;
-    let gi4 = unsafe { perm.hash4d_vec(is + 1) } % MIDPOINT_GRADIENT_LUT_4D_SIZE;
+    let gi0 = perm.hash4d_vec(is) % MIDPOINT_GRADIENT_LUT_4D_SIZE;
+    let gi1 = perm.hash4d_vec(is + i1) % MIDPOINT_GRADIENT_LUT_4D_SIZE;
+    let gi2 = perm.hash4d_vec(is + i2) % MIDPOINT_GRADIENT_LUT_4D_SIZE;
+    let gi3 = perm.hash4d_vec(is + i3) % MIDPOINT_GRADIENT_LUT_4D_SIZE;
+    let gi4 = perm.hash4d_vec(is + 1) % MIDPOINT_GRADIENT_LUT_4D_SIZE;
     // compute contributions
-    let n0 = unsafe { contribution4d(x0, gi0) };
-    let n1 = unsafe { contribution4d(x1, gi1) };
-    let n2 = unsafe { contribution4d(x2, gi2) };
-    let n3 = unsafe { contribution4d(x3, gi3) };
-    let n4 = unsafe { contribution4d(x4, gi4) };
+    let n0 = contribution4d(kernel.r_squared, x0, gi0);
+    let n1 = contribution4d(kernel.r_squared, x1, gi1);
+    let n2 = contribution4d(kernel.r_squared, x2, gi2);
+    let n3 = contribution4d(kernel.r_squared, x3, gi3);
+    let n4 = contribution4d(kernel.r_squared, x4, gi4);
     // combine contributions and scale to [-1, 1]
-    (n0 + n1 + n2 + n3 + n4) * SIMPLEX_NORMALIZATION_FACTOR_4D
+    (n0 + n1 + n2 + n3 + n4) * SIMPLEX_NORMALIZATION_FACTOR_4D * kernel.scale
+}
+
+// simplex noise for any dimensionality, computing m components at once from m sets of gradients,
+// such that the traversal of the simplex and the hashing of its vertices are shared among them
+pub(crate) fn noise_nd<const D: usize, const M: usize>(
+    perm: &PermutationTable,
+    kernel: Kernel,
+    point: [f64; D],
+) -> [f64; M] {
+    let (skew_factor, unskew_factor) = skew_factors(D);
+    // transform into lattice space and floor for cube origin
+    let skew = point.iter().sum::<f64>() * skew_factor;
+    let is = point.map(|x| (x + skew).floor());
+    // input point relative to unskewed cube (and simplex) origin in source space
+    let unskew = is.iter().sum::<f64>() * unskew_factor;
+    let x0 = core::array::from_fn(|i| point[i] - is[i] + unskew);
+    simplex_nd(perm, kernel, is.map(|i| lattice::wrap(i as i64)), x0)
+}
+
+pub(crate) fn noise_nd_large<const D: usize, const M: usize>(
+    perm: &PermutationTable,
+    kernel: Kernel,
+    point: [f64; D],
+) -> [f64; M] {
+    let (skew_factor, unskew_factor) = skew_factors(D);
+    // split into lattice and fractional part, then skew into lattice space
+    let (is, x0) = lattice::skew_split(point, skew_factor, unskew_factor);
+    simplex_nd(perm, kernel, is.map(lattice::wrap), x0)
+}
+
+// the factors skewing the cubic lattice into a lattice of simplices with edges of similar length,
+// and back, which are (sqrt(d + 1) - 1) / d and (1 - 1 / sqrt(d + 1)) / d respectively
+#[inline]
+fn skew_factors(dim: usize) -> (f64, f64) {
+    let root = ((dim + 1) as f64).sqrt();
+    ((root - 1.0) / dim as f64, (1.0 - 1.0 / root) / dim as f64)
+}
+
+// the squared radius of the corner contributions in the given dimensionality. as the simplices
+// grow with the dimensionality, so does their circumradius, whose square is d(d + 2) / 12(d + 1).
+// as the circumcenter is the point furthest from every corner, the radius is kept beyond it by a
+// quarter of the squared circumradius, such that no region is beyond the reach of every corner.
+// up to 4 dimensions, this is within the default radius of the specialized implementations
+#[inline]
+fn r_squared_nd(dim: usize) -> f64 {
+    let dim = dim as f64;
+    let circumradius_squared = dim * (dim + 2.0) / (12.0 * (dim + 1.0));
+    (1.25 * circumradius_squared).max(SIMPLEX_R_SQUARED)
+}
+
+// the maximum contribution of a single corner with the given squared radius and a unit gradient
+// pointing away from it, which is (8 / 9 * r²)⁴ * r / 3
+#[inline]
+fn peak_contribution(r_squared: f64) -> f64 {
+    let t = 8.0 / 9.0 * r_squared;
+    let t = t * t;
+    t * t * r_squared.sqrt() / 3.0
+}
+
+// the normalization factor in the given dimensionality. beyond the tabulated dimensionalities,
+// the overlap of the contributions of the corners is assumed to remain that of the last one,
+// which may slightly underestimate their maximum, hence the output is clamped in that case
+#[inline]
+fn normalization_factor_nd(dim: usize) -> f64 {
+    let table = &SIMPLEX_NORMALIZATION_FACTORS_ND;
+    match table.get(dim.wrapping_sub(1)) {
+        Some(&factor) => factor,
+        None => {
+            table[table.len() - 1] * peak_contribution(r_squared_nd(table.len()))
+                / peak_contribution(r_squared_nd(dim))
+        }
+    }
+}
+
+#[inline]
+fn simplex_nd<const D: usize, const M: usize>(
+    perm: &PermutationTable,
+    kernel: Kernel,
+    is: [usize; D],
+    x0: [f64; D],
+) -> [f64; M] {
+    let (_, unskew_factor) = skew_factors(D);
+    let r_squared = kernel.r_squared * r_squared_nd(D) / SIMPLEX_R_SQUARED;
+    let mut sum = [0.0; M];
+    if r_squared <= SIMPLEX_R_SQUARED {
+        // the corners of other simplices are at least sqrt(0.5) away from any point of the
+        // simplex containing the input point, such that only its own corners are in reach. rank
+        // the coordinates of the input point, such that the simplex is traversed from the
+        // 0-vector to the 1-vector by stepping along the axes in order of decreasing
+        // coordinates. this replaces the traversal lookup tables of the specialized
+        // implementations
+        let mut axes: [usize; D] = core::array::from_fn(|i| i);
+        axes.sort_unstable_by(|&a, &b| x0[b].total_cmp(&x0[a]));
+        // sum up the contributions of all d + 1 simplex vertices
+        let mut vertex = is;
+        let mut x = x0;
+        contribution_nd(perm, r_squared, vertex, x, &mut sum);
+        for axis in axes {
+            vertex[axis] += 1;
+            x[axis] -= 1.0;
+            x.iter_mut().for_each(|xi| *xi += unskew_factor);
+            contribution_nd(perm, r_squared, vertex, x, &mut sum);
+        }
+    } else {
+        LatticeSearch {
+            perm,
+            r_squared,
+            is,
+            sum: &mut sum,
+        }
+        .run(x0, unskew_factor);
+    }
+    // scale to [-1, 1]
+    let factor = normalization_factor_nd(D) * kernel.scale;
+    if D > SIMPLEX_NORMALIZATION_FACTORS_ND.len() {
+        sum.map(|n| (n * factor).clamp(-1.0, 1.0))
+    } else {
+        sum.map(|n| n * factor)
+    }
+}
+
+// sums the contributions of all lattice points within the radius of the corner contributions,
+// which reach beyond the simplex containing the input point in 5 or more dimensions. a lattice
+// point is offset from the origin of the skewed hypercube containing the input point by integer
+// steps along each axis, and its offset from the input point in source space is x0 - steps + the
+// unskew factor times the sum of the steps. the lattice points are thus enumerated by the sum of
+// their steps first and by the step along each axis second, skipping all steps which cannot lead
+// to a lattice point within the radius
+struct LatticeSearch<'a, const D: usize, const M: usize> {
+    perm: &'a PermutationTable,
+    r_squared: f64,
+    is: [usize; D],
+    sum: &'a mut [f64; M],
+}
+
+impl<const D: usize, const M: usize> LatticeSearch<'_, D, M> {
+    fn run(&mut self, x0: [f64; D], unskew_factor: f64) {
+        // for a given sum of the steps, the squared distance is at least the square of the sum of
+        // the offset divided by d, where the offset sums up to sum(x0) - sum(steps) / sqrt(d + 1)
+        let root = ((D + 1) as f64).sqrt();
+        let reach = (self.r_squared * D as f64).sqrt();
+        let x0_sum = x0.iter().sum::<f64>();
+        if !x0_sum.is_finite() {
+            // propagate non-finite input, which the traversal of the simplex does as well
+            self.sum.fill(f64::NAN);
+            return;
+        }
+        let min_steps = ((root * (x0_sum - reach)).floor() as i64).saturating_add(1);
+        let max_steps = ((root * (x0_sum + reach)).ceil() as i64).saturating_sub(1);
+        for steps in min_steps..=max_steps {
+            let center: [f64; D] = core::array::from_fn(|i| x0[i] + unskew_factor * steps as f64);
+            // the sums of the centers of the remaining axes, for bounding the remaining distance
+            let mut remaining_center = [0.0; D];
+            for i in (0..D - 1).rev() {
+                remaining_center[i] = remaining_center[i + 1] + center[i + 1];
+            }
+            self.visit(&center, &remaining_center, [0; D], 0, steps, 0.0);
+        }
+    }
+
+    fn visit(
+        &mut self,
+        center: &[f64; D],
+        remaining_center: &[f64; D],
+        mut steps: [i64; D],
+        axis: usize,
+        remaining_steps: i64,
+        dist_sq: f64,
+    ) {
+        if axis == D - 1 {
+            // the step along the last axis is determined by the sum of the steps
+            steps[axis] = remaining_steps;
+            let x: [f64; D] = core::array::from_fn(|i| center[i] - steps[i] as f64);
+            let vertex = core::array::from_fn(|i| {
+                (self.is[i] as i64 + steps[i]).rem_euclid(PERMUTATION_TABLE_SIZE as i64) as usize
+            });
+            contribution_nd(self.perm, self.r_squared, vertex, x, self.sum);
+            return;
+        }
+        let reach = (self.r_squared - dist_sq).sqrt();
+        let first = (center[axis] - reach).floor() as i64 + 1;
+        let last = (center[axis] + reach).ceil() as i64 - 1;
+        for step in first..=last {
+            let offset = center[axis] - step as f64;
+            let dist_sq = dist_sq + offset * offset;
+            // the offsets along the remaining axes are at least their mean in magnitude
+            let remaining_offset = remaining_center[axis] - (remaining_steps - step) as f64;
+            let bound = remaining_offset * remaining_offset / (D - 1 - axis) as f64;
+            if dist_sq + bound < self.r_squared {
+                steps[axis] = step;
+                self.visit(
+                    center,
+                    remaining_center,
+                    steps,
+                    axis + 1,
+                    remaining_steps - step,
+                    dist_sq,
+                );
+            }
+        }
+    }
 }
 
-unsafe fn contribution1d(x: f64, gi: usize) -> f64 {
-    if x.abs() >= std::f64::consts::FRAC_1_SQRT_2 {
+#[inline]
+fn contribution_nd<const D: usize, const M: usize>(
+    perm: &PermutationTable,
+    r_squared: f64,
+    vertex: [usize; D],
+    x: [f64; D],
+    sum: &mut [f64; M],
+) {
+    let mut t = r_squared - x.iter().map(|xi| xi * xi).sum::<f64>();
+    if t <= 0.0 {
+        return;
+    }
+    let hash = vertex.iter().fold(0, |hash, &i| perm.get(i + hash));
+    t *= t;
+    t *= t;
+    for (set, n) in sum.iter_mut().enumerate() {
+        let gradient = gradient_nd::<D>(hash, set);
+        *n += t * (0..D).map(|i| gradient[i] * x[i]).sum::<f64>();
+    }
+}
+
+// a unit vector drawn from the hypersphere by normalizing a vector of approximately normally
+// distributed components, derived from the hash salted by the index of the gradient set. each
+// component is the centered sum of four uniformly distributed 16-bit numbers, which is close
+// enough to a normal distribution for the gradients to appear isotropic, while avoiding
+// transcendental functions, whose results may differ between platforms
+#[inline]
+fn gradient_nd<const D: usize>(hash: usize, set: usize) -> [f64; D] {
+    let mut state = mix(set as u64) ^ hash as u64;
+    let gradient: [f64; D] = core::array::from_fn(|_| {
+        state = mix(state);
+        let sum = (0..4)
+            .map(|k| (state >> (16 * k)) as u16 as f64)
+            .sum::<f64>();
+        sum - 2.0 * u16::MAX as f64
+    });
+    let norm = gradient.iter().map(|g| g * g).sum::<f64>().sqrt();
+    if norm == 0.0 {
+        // all components are centered exactly, which is practically impossible
+        return core::array::from_fn(|i| if i == 0 { 1.0 } else { 0.0 });
+    }
+    gradient.map(|g| g / norm)
+}
+
+fn contribution1d(r_squared: f64, x: f64, gi: usize) -> f64 {
+    let mut t = r_squared - x * x;
+    if t <= 0.0 {
         0.0
     } else {
-        let mut t = SIMPLEX_R_SQUARED - x * x;
         t *= t;
-        t * t * GRADIENT_LUT_1D.get_unchecked(gi) * x
+        t * t * lookup(&GRADIENT_LUT_1D, gi) * x
     }
 }
 
-unsafe fn contribution2d(x: Vec2<f64>, gi: usize) -> f64 {
-    let mut t = SIMPLEX_R_SQUARED - x.x * x.x - x.y * x.y;
+fn contribution2d(r_squared: f64, x: Vec2<f64>, gi: usize) -> f64 {
+    let mut t = r_squared - x.x * x.x - x.y * x.y;
     if t <= 0.0 {
         0.0
     } else {
-        let gradient = MIDPOINT_GRADIENT_LUT_2D.get_unchecked(gi);
+        let gradient = lookup(&MIDPOINT_GRADIENT_LUT_2D, gi);
         t *= t;
-        t * t * (gradient.get_unchecked(0) * x.x + gradient.get_unchecked(1) * x.y)
+        t * t * (gradient[0] * x.x + gradient[1] * x.y)
     }
 }
 
-unsafe fn contribution3d(x: Vec3<f64>, gi: usize) -> f64 {
-    let mut t = SIMPLEX_R_SQUARED - x.x * x.x - x.y * x.y - x.z * x.z;
+fn contribution3d(r_squared: f64, x: Vec3<f64>, gi: usize) -> f64 {
+    let mut t = r_squared - x.x * x.x - x.y * x.y - x.z * x.z;
     if t <= 0.0 {
         0.0
     } else {
-        let gradient = MIDPOINT_GRADIENT_LUT_3D.get_unchecked(gi);
+        let gradient = lookup(&MIDPOINT_GRADIENT_LUT_3D, gi);
         t *= t;
-        t * t
-            * (gradient.get_unchecked(0) * x.x
-                + gradient.get_unchecked(1) * x.y
-                + gradient.get_unchecked(2) * x.z)
+        t * t * (gradient[0] * x.x + gradient[1] * x.y + gradient[2] * x.z)
     }
 }
 
-unsafe fn contribution4d(x: Vec4<f64>, gi: usize) -> f64 {
-    let mut t = SIMPLEX_R_SQUARED - x.x * x.x - x.y * x.y - x.z * x.z - x.w * x.w;
+fn contribution4d(r_squared: f64, x: Vec4<f64>, gi: usize) -> f64 {
+    let mut t = r_squared - x.x * x.x - x.y * x.y - x.z * x.z - x.w * x.w;
     if t <= 0.0 {
         0.0
     } else {
-        let gradient = MIDPOINT_GRADIENT_LUT_4D.get_unchecked(gi);
+        let gradient = lookup(&MIDPOINT_GRADIENT_LUT_4D, gi);
         t *= t;
-        t * t
-            * (gradient.get_unchecked(0) * x.x
-                + gradient.get_unchecked(1) * x.y
-                + gradient.get_unchecked(2) * x.z
-                + gradient.get_unchecked(3) * x.w)
+        t * t * (gradient[0] * x.x + gradient[1] * x.y + gradient[2] * x.z + gradient[3] * x.w)
     }
 }
@@ -0,0 +1,73 @@
+use super::white::mix;
+use crate::core::sources::SpotKernel;
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+
+pub(crate) fn noise<const D: usize>(
+    seed: u64,
+    kernel: SpotKernel,
+    radius: f64,
+    density: u32,
+    amplitude_jitter: f64,
+    point: [f64; D],
+) -> f64 {
+    if !point.iter().all(|x| x.is_finite()) {
+        return f64::NAN;
+    }
+    // impulses lie within the unit cell they belong to, such that only the cells overlapping
+    // the bounding box of the kernel radius around the point can contribute
+    let lower = point.map(|x| (x - radius).floor() as i64);
+    let upper = point.map(|x| (x + radius).floor() as i64);
+    let mut cell = lower;
+    let mut sum = 0.0;
+    'cells: loop {
+        let mut state = mix(seed);
+        for c in cell {
+            state = mix(state ^ c as u64);
+        }
+        let mut random = || {
+            state = mix(state);
+            (state >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+        };
+        for _ in 0..density {
+            let mut distance_squared = 0.0;
+            for i in 0..D {
+                let offset = point[i] - (cell[i] as f64 + random());
+                distance_squared += offset * offset;
+            }
+            let amplitude = 1.0 - amplitude_jitter * random();
+            if distance_squared < radius * radius {
+                sum += amplitude * profile(kernel, distance_squared.sqrt() / radius);
+            }
+        }
+        // advance to the next cell in the box
+        for i in 0..D {
+            if cell[i] < upper[i] {
+                cell[i] += 1;
+                continue 'cells;
+            }
+            cell[i] = lower[i];
+        }
+        break;
+    }
+    sum.min(1.0) * 2.0 - 1.0
+}
+
+// the value of the kernel at the given distance from its center relative to its radius. every
+// profile falls off to 0 at a relative distance of 1, such that the noise is continuous
+#[inline]
+fn profile(kernel: SpotKernel, distance: f64) -> f64 {
+    match kernel {
+        SpotKernel::Gaussian => {
+            // a gaussian with a standard deviation of a third of the radius, lowered and
+            // rescaled such that it reaches 0 at the radius
+            let tail = (-4.5_f64).exp();
+            ((-4.5 * distance * distance).exp() - tail) / (1.0 - tail)
+        }
+        SpotKernel::Cone => 1.0 - distance,
+        SpotKernel::Ring => {
+            let x = 2.0 * distance - 1.0;
+            (1.0 - x * x) * (1.0 - x * x)
+        }
+    }
+}
@@ -1,4 +1,6 @@
 use super::constants::PERMUTATION_TABLE_SIZE;
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
 use crate::core::utils::{
     math::{Vec2, Vec3, Vec4},
     ptable::PermutationTable,
@@ -12,8 +14,8 @@ pub(crate) fn noise1d(perm: &PermutationTable, point: [f64; 1]) -> f64 {
     let dxs = smoothstep_3(x - x0);
     // get values from hypercube corners
     let x0 = x0.rem_euclid(PERMUTATION_TABLE_SIZE as f64) as usize;
-    let f0 = unsafe { perm.hash1d(x0) } as f64;
-    let f1 = unsafe { perm.hash1d(x0 + 1) } as f64;
+    let f0 = perm.hash1d(x0) as f64;
+    let f1 = perm.hash1d(x0 + 1) as f64;
     // interpolate values from hypercube corners
     let xf = lerp(f0, f1, dxs);
     normalize(xf)
@@ -26,11 +28,14 @@ pub(crate) fn noise2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
     // smoothed distance from hypercube origin
     let dxs = (x - x0).map(smoothstep_3);
     // get values from hypercube corners
-    let x0 = x0.cast().rem_euclid(PERMUTATION_TABLE_SIZE);
-    let f00 = unsafe { perm.hash2d(x0.x, x0.y) } as f64;
-    let f01 = unsafe { perm.hash2d(x0.x, x0.y + 1) } as f64;
-    let f10 = unsafe { perm.hash2d(x0.x + 1, x0.y) } as f64;
-    let f11 = unsafe { perm.hash2d(x0.x + 1, x0.y + 1) } as f64;
+    let x0 = x0
+        .cast::<i64>()
+        .rem_euclid(PERMUTATION_TABLE_SIZE as i64)
+        .cast();
+    let f00 = perm.hash2d(x0.x, x0.y) as f64;
+    let f01 = perm.hash2d(x0.x, x0.y + 1) as f64;
+    let f10 = perm.hash2d(x0.x + 1, x0.y) as f64;
+    let f11 = perm.hash2d(x0.x + 1, x0.y + 1) as f64;
     // interpolate values from hypercube corners
     let xf0 = lerp(f00, f10, dxs.x);
     let xf1 = lerp(f01, f11, dxs.x);
@@ -45,15 +50,18 @@ pub(crate) fn noise3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
     // smoothed distance from hypercube origin
     let dxs = (x - x0).map(smoothstep_3);
     // get values from hypercube corners
-    let x0 = x0.cast().rem_euclid(PERMUTATION_TABLE_SIZE);
-    let f000 = unsafe { perm.hash3d(x0.x, x0.y, x0.z) } as f64;
-    let f001 = unsafe { perm.hash3d(x0.x, x0.y, x0.z + 1) } as f64;
-    let f010 = unsafe { perm.hash3d(x0.x, x0.y + 1, x0.z) } as f64;
-    let f011 = unsafe { perm.hash3d(x0.x, x0.y + 1, x0.z + 1) } as f64;
-    let f100 = unsafe { perm.hash3d(x0.x + 1, x0.y, x0.z) } as f64;
-    let f101 = unsafe { perm.hash3d(x0.x + 1, x0.y, x0.z + 1) } as f64;
-    let f110 = unsafe { perm.hash3d(x0.x + 1, x0.y + 1, x0.z) } as f64;
-    let f111 = unsafe { perm.hash3d(x0.x + 1, x0.y + 1, x0.z + 1) } as f64;
+    let x0 = x0
+        .cast::<i64>()
+        .rem_euclid(PERMUTATION_TABLE_SIZE as i64)
+        .cast();
+    let f000 = perm.hash3d(x0.x, x0.y, x0.z) as f64;
+    let f001 = perm.hash3d(x0.x, x0.y, x0.z + 1) as f64;
+    let f010 = perm.hash3d(x0.x, x0.y + 1, x0.z) as f64;
+    let f011 = perm.hash3d(x0.x, x0.y + 1, x0.z + 1) as f64;
+    let f100 = perm.hash3d(x0.x + 1, x0.y, x0.z) as f64;
+    let f101 = perm.hash3d(x0.x + 1, x0.y, x0.z + 1) as f64;
+    let f110 = perm.hash3d(x0.x + 1, x0.y + 1, x0.z) as f64;
+    let f111 = perm.hash3d(x0.x + 1, x0.y + 1, x0.z + 1) as f64;
     // interpolate values from hypercube corners
     let xf00 = lerp(f000, f100, dxs.x);
     let xf01 = lerp(f001, f101, dxs.x);
@@ -72,23 +80,26 @@ pub(crate) fn noise4d(perm: &PermutationTable, point: [f64; 4]) -> f64 {
     // smoothed distance from hypercube origin
     let dxs = (x - x0).map(smoothstep_3);
     // get values from hypercube corners
-    let x0 = x0.cast().rem_euclid(PERMUTATION_TABLE_SIZE);
-    let f0000 = unsafe { perm.hash4d(x0.x, x0.y, x0.z, x0.w) } as f64;
-    let f0001 = unsafe { perm.hash4d(x0.x, x0.y, x0.z, x0.w + 1) } as f64;
-    let f0010 = unsafe { perm.hash4d(x0.x, x0.y, x0.z + 1, x0.w) } as f64;
-    let f0011 = unsafe { perm.hash4d(x0.x, x0.y, x0.z + 1, x0.w + 1) } as f64;
-    let f0100 = unsafe { perm.hash4d(x0.x, x0.y + 1, x0.z, x0.w) } as f64;
-    let f0101 = unsafe { perm.hash4d(x0.x, x0.y + 1, x0.z, x0.w + 1) } as f64;
-    let f0110 = unsafe { perm.hash4d(x0.x, x0.y + 1, x0.z + 1, x0.w) } as f64;
-    let f0111 = unsafe { perm.hash4d(x0.x, x0.y + 1, x0.z + 1, x0.w + 1) } as f64;
-    let f1000 = unsafe { perm.hash4d(x0.x + 1, x0.y, x0.z, x0.w) } as f64;
-    let f1001 = unsafe { perm.hash4d(x0.x + 1, x0.y, x0.z, x0.w + 1) } as f64;
-    let f1010 = unsafe { perm.hash4d(x0.x + 1, x0.y, x0.z + 1, x0.w) } as f64;
-    let f1011 = unsafe { perm.hash4d(x0.x + 1, x0.y, x0.z + 1, x0.w + 1) } as f64;
-    let f1100 = unsafe { perm.hash4d(x0.x + 1, x0.y + 1, x0.z, x0.w) } as f64;
-    let f1101 = unsafe { perm.hash4d(x0.x + 1, x0.y + 1, x0.z, x0.w + 1) } as f64;
-    let f1110 = unsafe { perm.hash4d(x0.x + 1, x0.y + 1, x0.z + 1, x0.w) } as f64;
-    let f1111 = unsafe { perm.hash4d(x0.x + 1, x0.y + 1, x0.z + 1, x0.w + 1) } as f64;
+    let x0 = x0
+        .cast::<i64>()
+        .rem_euclid(PERMUTATION_TABLE_SIZE as i64)
+        .cast();
+    let f0000 = perm.hash4d(x0.x, x0.y, x0.z, x0.w) as f64;
+    let f0001 = perm.hash4d(x0.x, x0.y, x0.z, x0.w + 1) as f64;
+    let f0010 = perm.hash4d(x0.x, x0.y, x0.z + 1, x0.w) as f64;
+    let f0011 = perm.hash4d(x0.x, x0.y, x0.z + 1, x0.w + 1) as f64;
+    let f0100 = perm.hash4d(x0.x, x0.y + 1, x0.z, x0.w) as f64;
+    let f0101 = perm.hash4d(x0.x, x0.y + 1, x0.z, x0.w + 1) as f64;
+    let f0110 = perm.hash4d(x0.x, x0.y + 1, x0.z + 1, x0.w) as f64;
+    let f0111 = perm.hash4d(x0.x, x0.y + 1, x0.z + 1, x0.w + 1) as f64;
+    let f1000 = perm.hash4d(x0.x + 1, x0.y, x0.z, x0.w) as f64;
+    let f1001 = perm.hash4d(x0.x + 1, x0.y, x0.z, x0.w + 1) as f64;
+    let f1010 = perm.hash4d(x0.x + 1, x0.y, x0.z + 1, x0.w) as f64;
+    let f1011 = perm.hash4d(x0.x + 1, x0.y, x0.z + 1, x0.w + 1) as f64;
+    let f1100 = perm.hash4d(x0.x + 1, x0.y + 1, x0.z, x0.w) as f64;
+    let f1101 = perm.hash4d(x0.x + 1, x0.y + 1, x0.z, x0.w + 1) as f64;
+    let f1110 = perm.hash4d(x0.x + 1, x0.y + 1, x0.z + 1, x0.w) as f64;
+    let f1111 = perm.hash4d(x0.x + 1, x0.y + 1, x0.z + 1, x0.w + 1) as f64;
     // interpolate values from hypercube corners
     let xf000 = lerp(f0000, f1000, dxs.x);
     let xf001 = lerp(f0001, f1001, dxs.x);
@@ -108,6 +119,24 @@ pub(crate) fn noise4d(perm: &PermutationTable, point: [f64; 4]) -> f64 {
     normalize(wf)
 }
 
+// at lattice points, the smoothed distances are zero and interpolating yields the value of the
+// origin of the hypercube exactly, such that the noise reduces to a single hash
+pub(crate) fn lattice1d(perm: &PermutationTable, point: [usize; 1]) -> f64 {
+    normalize(perm.hash1d(point[0]) as f64)
+}
+
+pub(crate) fn lattice2d(perm: &PermutationTable, point: [usize; 2]) -> f64 {
+    normalize(perm.hash2d(point[0], point[1]) as f64)
+}
+
+pub(crate) fn lattice3d(perm: &PermutationTable, point: [usize; 3]) -> f64 {
+    normalize(perm.hash3d(point[0], point[1], point[2]) as f64)
+}
+
+pub(crate) fn lattice4d(perm: &PermutationTable, point: [usize; 4]) -> f64 {
+    normalize(perm.hash4d(point[0], point[1], point[2], point[3]) as f64)
+}
+
 #[inline]
 fn normalize(x: f64) -> f64 {
     2.0 / PERMUTATION_TABLE_SIZE as f64 * x - 1.0
@@ -0,0 +1,134 @@
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use alloc::{vec, vec::Vec};
+use rand::Rng;
+use rand_chacha::{rand_core::SeedableRng, ChaCha12Rng};
+
+// radius of the downsampling filter, and its coefficients as given by cook and derose
+const DOWNSAMPLE_RADIUS: isize = 16;
+const DOWNSAMPLE_COEFFICIENTS: [f64; 2 * DOWNSAMPLE_RADIUS as usize] = [
+    0.000334, -0.001528, 0.000410, 0.003545, -0.000938, -0.008233, 0.002172, 0.019120, -0.005040,
+    -0.044412, 0.011655, 0.103311, -0.025936, -0.243780, 0.033979, 0.655340, 0.655340, 0.033979,
+    -0.243780, -0.025936, 0.103311, 0.011655, -0.044412, -0.005040, 0.019120, 0.002172, -0.008233,
+    -0.000938, 0.003546, 0.000410, -0.001528, 0.000334,
+];
+// coefficients of the upsampling filter, which is the refinement of the quadratic b-spline
+const UPSAMPLE_COEFFICIENTS: [f64; 4] = [0.25, 0.75, 0.75, 0.25];
+
+// generates a tile of band-limited noise with `tile_size` values along each of the `D` axes,
+// stored in row-major order. the tile is constructed as described by cook and derose: random
+// values are downsampled and upsampled again along every axis, which retains only their coarse
+// scale part, which is then subtracted from the random values. the values are scaled such that
+// their largest magnitude is 1, which bounds the interpolated noise to [-1, 1].
+pub(crate) fn tile<const D: usize>(seed: u64, tile_size: usize) -> Vec<f64> {
+    let len = tile_size.pow(D as u32);
+    let mut rng = ChaCha12Rng::seed_from_u64(seed);
+    let mut noise = (0..len)
+        .map(|_| rng.gen_range(-1.0..1.0))
+        .collect::<Vec<f64>>();
+
+    // compute the coarse scale part
+    let mut coarse = noise.clone();
+    let (mut line, mut half) = (vec![0.0; tile_size], vec![0.0; tile_size / 2]);
+    for axis in 0..D {
+        let stride = tile_size.pow((D - 1 - axis) as u32);
+        for start in (0..len).filter(|start| (start / stride).is_multiple_of(tile_size)) {
+            for (i, value) in line.iter_mut().enumerate() {
+                *value = coarse[start + i * stride];
+            }
+            downsample(&line, &mut half);
+            upsample(&half, &mut line);
+            for (i, value) in line.iter().enumerate() {
+                coarse[start + i * stride] = *value;
+            }
+        }
+    }
+    for (value, coarse) in noise.iter_mut().zip(coarse) {
+        *value -= coarse;
+    }
+
+    // the variance of the interpolated noise differs between points at even and odd offsets
+    // from the lattice. adding the tile shifted by an odd offset along every axis evens it out
+    let mut offset = tile_size / 2;
+    if offset.is_multiple_of(2) {
+        offset += 1;
+    }
+    let shifted = (0..len)
+        .map(|index| {
+            let mut shifted_index = 0;
+            let mut stride = 1;
+            for _ in 0..D {
+                let coordinate = (index / stride) % tile_size;
+                shifted_index += (coordinate + offset) % tile_size * stride;
+                stride *= tile_size;
+            }
+            noise[shifted_index]
+        })
+        .collect::<Vec<_>>();
+    for (value, shifted) in noise.iter_mut().zip(shifted) {
+        *value += shifted;
+    }
+
+    let max = noise
+        .iter()
+        .fold(0.0, |max: f64, value| max.max(value.abs()));
+    if max > 0.0 {
+        noise.iter_mut().for_each(|value| *value /= max);
+    }
+    noise
+}
+
+// halves the resolution of a periodic signal of even length
+fn downsample(from: &[f64], to: &mut [f64]) {
+    let len = from.len() as isize;
+    for (i, value) in to.iter_mut().enumerate() {
+        let center = 2 * i as isize;
+        *value = (center - DOWNSAMPLE_RADIUS..center + DOWNSAMPLE_RADIUS)
+            .zip(DOWNSAMPLE_COEFFICIENTS)
+            .map(|(k, coefficient)| coefficient * from[k.rem_euclid(len) as usize])
+            .sum();
+    }
+}
+
+// doubles the resolution of a periodic signal
+fn upsample(from: &[f64], to: &mut [f64]) {
+    let len = from.len() as isize;
+    for (i, value) in to.iter_mut().enumerate() {
+        let i = i as isize;
+        *value = (i / 2..=i / 2 + 1)
+            .map(|k| {
+                UPSAMPLE_COEFFICIENTS[(i - 2 * k + 2) as usize] * from[k.rem_euclid(len) as usize]
+            })
+            .sum();
+    }
+}
+
+// evaluates the noise represented by the tile at the given point, by weighting the values at
+// the nearest 3 lattice points along each axis with quadratic b-spline basis functions
+pub(crate) fn noise<const D: usize>(tile: &[f64], tile_size: usize, point: [f64; D]) -> f64 {
+    if !point.iter().all(|x| x.is_finite()) {
+        return f64::NAN;
+    }
+    let mut mid = [0; D];
+    let mut weights = [[0.0; 3]; D];
+    for i in 0..D {
+        let center = (point[i] - 0.5).ceil();
+        let t = center - (point[i] - 0.5);
+        mid[i] = (center as i64).rem_euclid(tile_size as i64) as usize;
+        weights[i] = [t * t / 2.0, 0.0, (1.0 - t) * (1.0 - t) / 2.0];
+        weights[i][1] = 1.0 - weights[i][0] - weights[i][2];
+    }
+    let mut result = 0.0;
+    for tap in 0..3_usize.pow(D as u32) {
+        let (mut weight, mut index, mut rest) = (1.0, 0, tap);
+        for i in 0..D {
+            let offset = rest % 3;
+            rest /= 3;
+            weight *= weights[i][offset];
+            let coordinate = (mid[i] + offset + tile_size - 1) % tile_size;
+            index = index * tile_size + coordinate;
+        }
+        result += weight * tile[index];
+    }
+    result
+}
@@ -0,0 +1,31 @@
+pub(crate) fn noise<const D: usize>(seed: u64, point: [f64; D]) -> f64 {
+    if !point.iter().all(|x| x.is_finite()) {
+        return f64::NAN;
+    }
+    let mut hash = mix(seed ^ 0x9e37_79b9_7f4a_7c15);
+    for x in point {
+        // adding zero maps negative zero to positive zero, such that both hash identically
+        hash = mix(hash ^ (x + 0.0).to_bits());
+    }
+    // the upper 53 bits are mapped to [0, 1) exactly, and from there to [-1, 1)
+    (hash >> 11) as f64 * (1.0 / (1u64 << 53) as f64) * 2.0 - 1.0
+}
+
+// equals noise() at the point converted to f64, which is always finite and never negative zero
+pub(crate) fn lattice<const D: usize>(seed: u64, point: [i64; D]) -> f64 {
+    let mut hash = mix(seed ^ 0x9e37_79b9_7f4a_7c15);
+    for x in point {
+        hash = mix(hash ^ (x as f64).to_bits());
+    }
+    (hash >> 11) as f64 * (1.0 / (1u64 << 53) as f64) * 2.0 - 1.0
+}
+
+// the finalizer of splitmix64, a bijection on u64 with full avalanche, which is also used to
+// derive the impulses of spot noise
+#[inline]
+pub(crate) fn mix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
@@ -1,16 +1,23 @@
-use super::constants::PERMUTATION_TABLE_SIZE;
-use crate::core::utils::{
-    math::{Vec2, Vec3, Vec4},
-    ptable::PermutationTable,
+use super::{constants::PERMUTATION_TABLE_SIZE, white::mix};
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::{
+    generator::Generator,
+    utils::{
+        math::{Vec2, Vec3, Vec4},
+        ptable::PermutationTable,
+    },
 };
+use alloc::vec::Vec;
 
 pub(crate) fn noise1d(perm: &PermutationTable, point: [f64; 1]) -> f64 {
     let x = point[0];
     // origin of hypercube in which input lies and relative input position
     let x0 = x.floor();
     let dx = x - x0;
-    // compute distance to closest neighbor
-    let mut min_dist = f64::INFINITY;
+    // compute distance to closest neighbor, starting from NaN such that non-finite input
+    // propagates, as f64::min only returns NaN if both arguments are NaN
+    let mut min_dist = f64::NAN;
     for i in (-1..=1).map(|val| val as f64) {
         let pn = point1d(perm, x0 + i);
         let dn = (pn + i - dx).abs();
@@ -25,8 +32,9 @@ pub(crate) fn noise2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
     // origin of hypercube in which input lies and relative input position
     let x0 = x.floor();
     let dx = x - x0;
-    // compute distance to closest neighbor
-    let mut min_dist_sq = f64::INFINITY;
+    // compute distance to closest neighbor, starting from NaN such that non-finite input
+    // propagates, as f64::min only returns NaN if both arguments are NaN
+    let mut min_dist_sq = f64::NAN;
     for i in -1..=1 {
         for j in -1..=1 {
             let offset = Vec2::from([i, j]).cast();
@@ -44,8 +52,9 @@ pub(crate) fn noise3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
     // origin of hypercube in which input lies and relative input position
     let x0 = x.floor();
     let dx = x - x0;
-    // compute distance to closest neighbor
-    let mut min_dist_sq = f64::INFINITY;
+    // compute distance to closest neighbor, starting from NaN such that non-finite input
+    // propagates, as f64::min only returns NaN if both arguments are NaN
+    let mut min_dist_sq = f64::NAN;
     for i in -1..=1 {
         for j in -1..=1 {
             for k in -1..=1 {
@@ -65,8 +74,9 @@ pub(crate) fn noise4d(perm: &PermutationTable, point: [f64; 4]) -> f64 {
     // origin of hypercube in which input lies and relative input position
     let x0 = x.floor();
     let dx = x - x0;
-    // compute distance to closest neighbor
-    let mut min_dist_sq = f64::INFINITY;
+    // compute distance to closest neighbor, starting from NaN such that non-finite input
+    // propagates, as f64::min only returns NaN if both arguments are NaN
+    let mut min_dist_sq = f64::NAN;
     for i in -1..=1 {
         for j in -1..=1 {
             for k in -1..=1 {
@@ -83,32 +93,485 @@ pub(crate) fn noise4d(perm: &PermutationTable, point: [f64; 4]) -> f64 {
     min_dist_sq.sqrt().clamp(0.0, 1.0) * 2.0 - 1.0
 }
 
+pub(crate) fn edge1d(perm: &PermutationTable, point: [f64; 1]) -> f64 {
+    edge_distance(point, |[x0]| [point1d(perm, x0)])
+}
+
+pub(crate) fn edge2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
+    edge_distance(point, |x0| {
+        let pn = point2d(perm, Vec2::from(x0));
+        [pn.x, pn.y]
+    })
+}
+
+pub(crate) fn edge3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
+    edge_distance(point, |x0| {
+        let pn = point3d(perm, Vec3::from(x0));
+        [pn.x, pn.y, pn.z]
+    })
+}
+
+pub(crate) fn edge4d(perm: &PermutationTable, point: [f64; 4]) -> f64 {
+    edge_distance(point, |x0| {
+        let pn = point4d(perm, Vec4::from(x0));
+        [pn.x, pn.y, pn.z, pn.w]
+    })
+}
+
+pub(crate) fn weighted1d(perm: &PermutationTable, weights: [f64; 2], point: [f64; 1]) -> f64 {
+    weighted_distance(&offsets1d(perm, point), |offset| offset * offset, weights)
+}
+
+pub(crate) fn weighted2d(perm: &PermutationTable, weights: [f64; 2], point: [f64; 2]) -> f64 {
+    weighted_distance(&offsets2d(perm, point), Vec2::norm_l2_squared, weights)
+}
+
+pub(crate) fn weighted3d(perm: &PermutationTable, weights: [f64; 2], point: [f64; 3]) -> f64 {
+    weighted_distance(&offsets3d(perm, point), Vec3::norm_l2_squared, weights)
+}
+
+pub(crate) fn weighted4d(perm: &PermutationTable, weights: [f64; 2], point: [f64; 4]) -> f64 {
+    weighted_distance(&offsets4d(perm, point), Vec4::norm_l2_squared, weights)
+}
+
+// collects the offsets of the feature points of all neighbors from the input
+fn offsets1d(perm: &PermutationTable, point: [f64; 1]) -> [f64; 3] {
+    let x = point[0];
+    // origin of hypercube in which input lies and relative input position
+    let x0 = x.floor();
+    let dx = x - x0;
+    let mut offsets = [0.0; 3];
+    for (offset, i) in offsets.iter_mut().zip((-1..=1).map(|val| val as f64)) {
+        *offset = point1d(perm, x0 + i) + i - dx;
+    }
+    offsets
+}
+
+fn offsets2d(perm: &PermutationTable, point: [f64; 2]) -> [Vec2<f64>; 9] {
+    let x = Vec2::from(point);
+    // origin of hypercube in which input lies and relative input position
+    let x0 = x.floor();
+    let dx = x - x0;
+    let mut offsets = [dx; 9];
+    let mut index = 0;
+    for i in -1..=1 {
+        for j in -1..=1 {
+            let offset = Vec2::from([i, j]).cast();
+            offsets[index] = point2d(perm, x0 + offset) + offset - dx;
+            index += 1;
+        }
+    }
+    offsets
+}
+
+fn offsets3d(perm: &PermutationTable, point: [f64; 3]) -> [Vec3<f64>; 27] {
+    let x = Vec3::from(point);
+    // origin of hypercube in which input lies and relative input position
+    let x0 = x.floor();
+    let dx = x - x0;
+    let mut offsets = [dx; 27];
+    let mut index = 0;
+    for i in -1..=1 {
+        for j in -1..=1 {
+            for k in -1..=1 {
+                let offset = Vec3::from([i, j, k]).cast();
+                offsets[index] = point3d(perm, x0 + offset) + offset - dx;
+                index += 1;
+            }
+        }
+    }
+    offsets
+}
+
+fn offsets4d(perm: &PermutationTable, point: [f64; 4]) -> [Vec4<f64>; 81] {
+    let x = Vec4::from(point);
+    // origin of hypercube in which input lies and relative input position
+    let x0 = x.floor();
+    let dx = x - x0;
+    let mut offsets = [dx; 81];
+    let mut index = 0;
+    for i in -1..=1 {
+        for j in -1..=1 {
+            for k in -1..=1 {
+                for l in -1..=1 {
+                    let offset = Vec4::from([i, j, k, l]).cast();
+                    offsets[index] = point4d(perm, x0 + offset) + offset - dx;
+                    index += 1;
+                }
+            }
+        }
+    }
+    offsets
+}
+
+// the neighborhood of a cell, ordered such that the cells nearest to any point of the center cell
+// come first
+const NEIGHBORHOOD_2D: [[i64; 2]; 9] = [
+    [0, 0],
+    [-1, 0],
+    [1, 0],
+    [0, -1],
+    [0, 1],
+    [-1, -1],
+    [-1, 1],
+    [1, -1],
+    [1, 1],
+];
+
+// the radius of the largest ring of cells searched for the edge distance with a density, such
+// that the result is exact while the nearest point lies within a distance of radius - 1
+const DENSITY_EDGE_RADIUS: i64 = 6;
+
+// the number of points kept on the stack for the edge distance with a density
+const DENSITY_EDGE_CAPACITY: usize = 128;
+
+// the cells at the given chebyshev distance from the center cell
+fn ring2d(radius: i64) -> impl Iterator<Item = [i64; 2]> {
+    (-radius..=radius)
+        .flat_map(move |i| (-radius..=radius).map(move |j| [i, j]))
+        .filter(move |offset| offset[0].abs() == radius || offset[1].abs() == radius)
+}
+
+// the distance along one axis from the relative input position to the nearest point of the cell
+// at the given offset
+fn cell_gap(d: f64, offset: i64) -> f64 {
+    match offset {
+        o if o < 0 => d - o as f64 - 1.0,
+        o if o > 0 => o as f64 - d,
+        _ => 0.0,
+    }
+}
+
+pub(crate) fn density2d<G: Generator<2>>(
+    seed: u64,
+    density: &G,
+    max_points: u32,
+    point: [f64; 2],
+) -> f64 {
+    if !point.iter().all(|x| x.is_finite()) {
+        return f64::NAN;
+    }
+    let x = Vec2::from(point);
+    let x0 = x.floor();
+    let dx = x - x0;
+    let cell = point.map(|x| x.floor() as i64);
+    // distances are clamped to 1, such that only cells within a distance of 1 can affect the
+    // result, all of which lie in the neighborhood, even if some cells are empty. cells which
+    // cannot contain a point nearer than the nearest one found so far are skipped, which saves
+    // sampling the density generator for their candidates
+    let mut min_dist_sq: f64 = 1.0;
+    for offset in NEIGHBORHOOD_2D {
+        let (gap_x, gap_y) = (cell_gap(dx.x, offset[0]), cell_gap(dx.y, offset[1]));
+        if gap_x * gap_x + gap_y * gap_y >= min_dist_sq {
+            continue;
+        }
+        let neighbor = [cell[0] + offset[0], cell[1] + offset[1]];
+        let offset = Vec2::from(offset).cast();
+        density_points2d(seed, density, max_points, neighbor, |pn| {
+            min_dist_sq = min_dist_sq.min((pn + offset - dx).norm_l2_squared());
+        });
+    }
+    min_dist_sq.sqrt() * 2.0 - 1.0
+}
+
+pub(crate) fn density_edge2d<G: Generator<2>>(
+    seed: u64,
+    density: &G,
+    max_points: u32,
+    point: [f64; 2],
+) -> f64 {
+    if !point.iter().all(|x| x.is_finite()) {
+        return f64::NAN;
+    }
+    let x = Vec2::from(point);
+    let x0 = x.floor();
+    let dx = x - x0;
+    let cell = point.map(|x| x.floor() as i64);
+    let gap_sq = |offset: [i64; 2]| {
+        let (gap_x, gap_y) = (cell_gap(dx.x, offset[0]), cell_gap(dx.y, offset[1]));
+        gap_x * gap_x + gap_y * gap_y
+    };
+    // as empty cells leave the nearest point and the points bounding its cell arbitrarily far
+    // away, the cells are searched in rings of growing radius. only points within a distance of
+    // F1 + 1 can bound the cell of the nearest point nearer than 0.5, beyond which the result is
+    // clamped, such that the search stops once no further cell lies within reach. the offsets of
+    // the points found are kept on the stack, unless there are too many of them
+    let mut offsets = [Vec2::new(0.0, 0.0); DENSITY_EDGE_CAPACITY];
+    let mut len = 0;
+    let mut nearest = None;
+    let mut min_dist_sq = f64::INFINITY;
+    for radius in 0..=DENSITY_EDGE_RADIUS {
+        let reach = min_dist_sq.sqrt() + 1.0;
+        // cells in the ring of the given radius lie at least radius - 1 away
+        if (radius - 1) as f64 >= reach {
+            break;
+        }
+        for offset in ring2d(radius) {
+            if gap_sq(offset) >= reach * reach {
+                continue;
+            }
+            let neighbor = [cell[0] + offset[0], cell[1] + offset[1]];
+            let offset = Vec2::from(offset).cast();
+            density_points2d(seed, density, max_points, neighbor, |pn| {
+                let offset = pn + offset - dx;
+                let dist_sq = offset.norm_l2_squared();
+                if dist_sq < min_dist_sq {
+                    min_dist_sq = dist_sq;
+                    nearest = Some(offset);
+                }
+                if let Some(slot) = offsets.get_mut(len) {
+                    *slot = offset;
+                }
+                len += 1;
+            });
+        }
+    }
+    // without any point nearby, the input is far from any border
+    let Some(nearest) = nearest else {
+        return 1.0;
+    };
+    let border = |offset: Vec2<f64>| {
+        border_distance([offset.x, offset.y], [nearest.x, nearest.y], min_dist_sq)
+    };
+    let mut min_edge = f64::INFINITY;
+    if len <= DENSITY_EDGE_CAPACITY {
+        for &offset in &offsets[..len] {
+            min_edge = min_edge.min(border(offset));
+        }
+    } else {
+        // the points are deterministic, such that they can be generated again instead
+        let reach = min_dist_sq.sqrt() + 1.0;
+        for radius in 0..=DENSITY_EDGE_RADIUS {
+            for offset in ring2d(radius) {
+                if gap_sq(offset) >= reach * reach {
+                    continue;
+                }
+                let neighbor = [cell[0] + offset[0], cell[1] + offset[1]];
+                let offset = Vec2::from(offset).cast();
+                density_points2d(seed, density, max_points, neighbor, |pn| {
+                    min_edge = min_edge.min(border(pn + offset - dx));
+                });
+            }
+        }
+    }
+    (2.0 * min_edge).clamp(0.0, 1.0) * 2.0 - 1.0
+}
+
+pub(crate) fn density_weighted2d<G: Generator<2>>(
+    seed: u64,
+    density: &G,
+    max_points: u32,
+    weights: [f64; 2],
+    point: [f64; 2],
+) -> f64 {
+    if !point.iter().all(|x| x.is_finite()) {
+        return f64::NAN;
+    }
+    let x = Vec2::from(point);
+    let x0 = x.floor();
+    let dx = x - x0;
+    let cell = point.map(|x| x.floor() as i64);
+    // collect the offsets of all points in the neighborhood from the input
+    let mut offsets = Vec::new();
+    for offset in NEIGHBORHOOD_2D {
+        let neighbor = [cell[0] + offset[0], cell[1] + offset[1]];
+        let offset = Vec2::from(offset).cast();
+        density_points2d(seed, density, max_points, neighbor, |pn| {
+            offsets.push(pn + offset - dx);
+        });
+    }
+    // without any point nearby, both distances are clamped to 1
+    if offsets.is_empty() {
+        return (weights[0] + weights[1]) * 2.0 - 1.0;
+    }
+    weighted_distance(&offsets, Vec2::norm_l2_squared, weights)
+}
+
+// passes the positions relative to the cell origin of the feature points of the given cell to
+// the callback. every cell spawns between 1 and max_points candidates, each of which is kept if
+// a random threshold lies below the value of the density generator at its position, such that
+// the expected number of points is proportional to the density
+fn density_points2d<G: Generator<2>>(
+    seed: u64,
+    density: &G,
+    max_points: u32,
+    cell: [i64; 2],
+    mut callback: impl FnMut(Vec2<f64>),
+) {
+    let mut state = mix(seed);
+    for c in cell {
+        state = mix(state ^ c as u64);
+    }
+    let mut random = || {
+        state = mix(state);
+        (state >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    };
+    let candidates = 1 + (random() * max_points as f64) as u32;
+    for _ in 0..candidates {
+        let pn = Vec2::new(random(), random());
+        let threshold = random() * 2.0 - 1.0;
+        if threshold < density.sample([cell[0] as f64 + pn.x, cell[1] as f64 + pn.y]) {
+            callback(pn);
+        }
+    }
+}
+
+// computes the distance from the input to the border of the cell of the nearest neighbor, given
+// the position of the feature point of every cell relative to the cell origin, and normalizes it
+// such that a distance of 0.5 or more maps to 1. the cell is bounded by the perpendicular
+// bisectors between the nearest neighbor and every other neighbor, the closest of which is the
+// nearest border. as in the two-pass border distance of inigo quilez, the nearest neighbor is
+// found first, and the neighbors bounding its cell are searched around it second, where both
+// passes skip the cells which cannot contain a relevant neighbor
+fn edge_distance<const D: usize, F>(point: [f64; D], feature_point: F) -> f64
+where
+    F: Fn([f64; D]) -> [f64; D],
+{
+    // origin of hypercube in which input lies and relative input position
+    let x0 = point.map(|x| x.floor());
+    let dx: [f64; D] = core::array::from_fn(|i| point[i] - x0[i]);
+    // the offset from the input of the feature point of the cell at the given offset
+    let neighbor = |cell: [f64; D]| -> [f64; D] {
+        let pn = feature_point(core::array::from_fn(|i| x0[i] + cell[i]));
+        core::array::from_fn(|i| pn[i] + cell[i] - dx[i])
+    };
+    // the squared distance from the input to the nearest point of the cell at the given offset
+    let gap_sq = |cell: [f64; D]| -> f64 {
+        norm_l2_squared::<D>(core::array::from_fn(|i| match cell[i] {
+            c if c < 0.0 => dx[i] - c - 1.0,
+            c if c > 0.0 => c - dx[i],
+            _ => 0.0,
+        }))
+    };
+    // the feature point of the cell of the input lies within a distance of sqrt(D), which is at
+    // most 2 for up to 4 dimensions, such that the nearest neighbor lies within a distance of 2
+    // cells. distances of NaN never compare less, such that non-finite input propagates
+    let mut nearest = None;
+    let mut min_dist_sq = f64::INFINITY;
+    for cell in cube_cells::<D>(2) {
+        if gap_sq(cell) >= min_dist_sq {
+            continue;
+        }
+        let offset = neighbor(cell);
+        let dist_sq = norm_l2_squared(offset);
+        if dist_sq < min_dist_sq {
+            min_dist_sq = dist_sq;
+            nearest = Some(offset);
+        }
+    }
+    let Some(nearest) = nearest else {
+        return f64::NAN;
+    };
+    // a neighbor at a distance of r bounds the cell of the nearest neighbor at a distance of at
+    // least (r - F1) / 2, such that only neighbors within a distance of F1 + 1 can bound it
+    // nearer than 0.5, beyond which the result is clamped. cells k apart lie at least k - 1 away
+    let reach = min_dist_sq.sqrt() + 1.0;
+    let mut min_edge = f64::INFINITY;
+    for cell in cube_cells::<D>((reach + 1.0).ceil() as i32) {
+        if gap_sq(cell) >= reach * reach {
+            continue;
+        }
+        min_edge = min_edge.min(border_distance(neighbor(cell), nearest, min_dist_sq));
+    }
+    (2.0 * min_edge).clamp(0.0, 1.0) * 2.0 - 1.0
+}
+
+// computes the distance from the input to the perpendicular bisector between the nearest
+// neighbor and another neighbor, given their offsets from the input. the projection of the offset
+// of the midpoint between both neighbors onto the direction between them simplifies to the
+// difference of their squared distances over twice their separation. neighbors of distinct cells
+// never coincide, such that only the nearest neighbor itself has a separation of 0, which is
+// skipped by returning infinity
+#[inline]
+fn border_distance<const D: usize>(offset: [f64; D], nearest: [f64; D], min_dist_sq: f64) -> f64 {
+    let separation_sq = norm_l2_squared::<D>(core::array::from_fn(|i| offset[i] - nearest[i]));
+    if separation_sq > 0.0 {
+        (norm_l2_squared(offset) - min_dist_sq) / (2.0 * separation_sq.sqrt())
+    } else {
+        f64::INFINITY
+    }
+}
+
+#[inline]
+fn norm_l2_squared<const D: usize>(offset: [f64; D]) -> f64 {
+    offset.iter().map(|x| x * x).sum()
+}
+
+// iterates the offsets of all cells within the given distance of a cell along every axis
+fn cube_cells<const D: usize>(radius: i32) -> impl Iterator<Item = [f64; D]> {
+    let width = 2 * radius + 1;
+    (0..width.pow(D as u32)).map(move |mut index| {
+        core::array::from_fn(|_| {
+            let offset = index % width - radius;
+            index /= width;
+            offset as f64
+        })
+    })
+}
+
+// computes the weighted sum of the distances from the input to the nearest and the second nearest
+// neighbor, given the offsets of all neighbors from the input. both distances are clamped to 1,
+// as the neighborhood only contains all neighbors within a distance of 1, and the sum is mapped
+// such that the distance 0 maps to -1 and a distance of 1 with unit weight maps to 1
+fn weighted_distance<V, F>(offsets: &[V], norm_l2_squared: F, weights: [f64; 2]) -> f64
+where
+    V: Copy,
+    F: Fn(V) -> f64,
+{
+    // the nearest distance starts from NaN such that non-finite input propagates, while the
+    // second nearest distance of a single neighbor is unbounded
+    let (mut nearest_sq, mut second_sq) = (f64::NAN, f64::INFINITY);
+    for &offset in offsets {
+        let dist_sq = norm_l2_squared(offset);
+        if dist_sq < nearest_sq || nearest_sq.is_nan() {
+            second_sq = second_sq.min(nearest_sq);
+            nearest_sq = dist_sq;
+        } else {
+            second_sq = second_sq.min(dist_sq);
+        }
+    }
+    let [nearest, second] = [nearest_sq, second_sq].map(|dist_sq| dist_sq.sqrt().clamp(0.0, 1.0));
+    (weights[0] * nearest + weights[1] * second) * 2.0 - 1.0
+}
+
 #[inline]
 fn point1d(perm: &PermutationTable, x0: f64) -> f64 {
-    let x = unsafe { perm.hash1d((x0 as usize).rem_euclid(PERMUTATION_TABLE_SIZE)) };
+    let x = perm.hash1d((x0 as i64).rem_euclid(PERMUTATION_TABLE_SIZE as i64) as usize);
     x as f64 / PERMUTATION_TABLE_SIZE as f64
 }
 
 #[inline]
 fn point2d(perm: &PermutationTable, x0: Vec2<f64>) -> Vec2<f64> {
-    let x = unsafe { perm.hash2d_vec(x0.cast().rem_euclid(PERMUTATION_TABLE_SIZE)) };
-    let y = unsafe { perm.hash1d(x) };
+    let x = perm.hash2d_vec(
+        x0.cast::<i64>()
+            .rem_euclid(PERMUTATION_TABLE_SIZE as i64)
+            .cast(),
+    );
+    let y = perm.hash1d(x);
     Vec2::from([x, y]).cast() / PERMUTATION_TABLE_SIZE as f64
 }
 
 #[inline]
 fn point3d(perm: &PermutationTable, x0: Vec3<f64>) -> Vec3<f64> {
-    let x = unsafe { perm.hash3d_vec(x0.cast().rem_euclid(PERMUTATION_TABLE_SIZE)) };
-    let y = unsafe { perm.hash1d(x) };
-    let z = unsafe { perm.hash1d(y) };
+    let x = perm.hash3d_vec(
+        x0.cast::<i64>()
+            .rem_euclid(PERMUTATION_TABLE_SIZE as i64)
+            .cast(),
+    );
+    let y = perm.hash1d(x);
+    let z = perm.hash1d(y);
     Vec3::from([x, y, z]).cast() / PERMUTATION_TABLE_SIZE as f64
 }
 
 #[inline]
 fn point4d(perm: &PermutationTable, x0: Vec4<f64>) -> Vec4<f64> {
-    let x = unsafe { perm.hash4d_vec(x0.cast().rem_euclid(PERMUTATION_TABLE_SIZE)) };
-    let y = unsafe { perm.hash1d(x) };
-    let z = unsafe { perm.hash1d(y) };
-    let w = unsafe { perm.hash1d(z) };
+    let x = perm.hash4d_vec(
+        x0.cast::<i64>()
+            .rem_euclid(PERMUTATION_TABLE_SIZE as i64)
+            .cast(),
+    );
+    let y = perm.hash1d(x);
+    let z = perm.hash1d(y);
+    let w = perm.hash1d(z);
     Vec4::from([x, y, z, w]).cast() / PERMUTATION_TABLE_SIZE as f64
 }
@@ -0,0 +1,262 @@
+use crate::core::{
+    error::Error,
+    generator::{Generator, Generator2D},
+    utils::{
+        describe::Description,
+        resample::{self, Interpolation},
+    },
+};
+use alloc::{format, vec::Vec};
+use core::fmt;
+use image::DynamicImage;
+
+/// The value of an [`ImageSource`] at points beyond the rectangle covered by the image.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ImageBoundary {
+    /// Use the value of the nearest pixel at the edge of the image.
+    #[default]
+    Clamp,
+    /// Repeat the image, continuing at the opposite edge, as appropriate for tileable images.
+    Wrap,
+    /// Use the given value. Within half a pixel of the edge of the image, this value is
+    /// interpolated with the pixels at the edge.
+    Constant(f64),
+}
+
+/// A generator which produces 2-dimensional values from the pixels of an image.
+///
+/// For details, see the documentation of [`image()`]. Typically, this struct is not meant to be
+/// used directly. Instead, [`image()`] implemented by [`Source`], should be used to create an
+/// image generator.
+///
+/// # Direct usage of this struct
+///
+/// Direct instantiation of this struct:
+///
+/// ```
+/// # use libnoise::{ImageSource, Generator};
+/// let image = image::DynamicImage::ImageLuma8(image::GrayImage::new(16, 16));
+/// let generator = ImageSource::new(&image).with_bounds([0.0, 0.0], [1.0, 1.0]);
+/// let value = generator.sample([0.2, 0.5]);
+/// ```
+///
+/// [`image()`]: crate::Source::image
+/// [`Source`]: crate::Source
+#[derive(Clone, Debug)]
+pub struct ImageSource {
+    // the number of rows and columns of the image
+    shape: [usize; 2],
+    // the brightness of each pixel in [0, 1], in row-major order
+    pixels: Vec<f64>,
+    min: [f64; 2],
+    max: [f64; 2],
+    interpolation: Interpolation,
+    boundary: ImageBoundary,
+    low: f64,
+    high: f64,
+}
+
+impl Generator2D for ImageSource {}
+
+impl ImageSource {
+    /// Create a new image generator from the brightness of the pixels of `image`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `image` has a width or height of 0.
+    pub fn new(image: &DynamicImage) -> Self {
+        assert!(
+            image.width() > 0 && image.height() > 0,
+            "image must not be empty"
+        );
+        let shape = [image.height() as usize, image.width() as usize];
+        let pixels = match image {
+            DynamicImage::ImageLuma8(_)
+            | DynamicImage::ImageLumaA8(_)
+            | DynamicImage::ImageRgb8(_)
+            | DynamicImage::ImageRgba8(_) => image
+                .to_luma8()
+                .into_raw()
+                .into_iter()
+                .map(|value| value as f64 / u8::MAX as f64)
+                .collect(),
+            _ => image
+                .to_luma16()
+                .into_raw()
+                .into_iter()
+                .map(|value| value as f64 / u16::MAX as f64)
+                .collect(),
+        };
+        Self {
+            shape,
+            pixels,
+            min: [0.0, 0.0],
+            max: shape.map(|len| len as f64),
+            interpolation: Interpolation::Bilinear,
+            boundary: ImageBoundary::Clamp,
+            low: -1.0,
+            high: 1.0,
+        }
+    }
+
+    /// Create a new image generator from the brightness of the pixels of the image file at
+    /// `path`. The format of the file is determined by its extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the file cannot be read, [`Error::Encode`] if it cannot be
+    /// decoded, [`Error::Unsupported`] if its format is not supported, and
+    /// [`Error::InvalidParameter`] if the image has a width or height of 0.
+    pub fn open(path: &str) -> Result<Self, Error> {
+        let image = image::open(path)?;
+        if image.width() == 0 || image.height() == 0 {
+            return Err(Error::InvalidParameter {
+                name: "image",
+                reason: format!(
+                    "must not be empty, got {}x{} pixels",
+                    image.width(),
+                    image.height()
+                ),
+            });
+        }
+        Ok(Self::new(&image))
+    }
+
+    /// Set the rectangle from `min` to `max` covered by the image. By default, the image covers
+    /// the rectangle from the origin to its height and width, such that each pixel is a unit
+    /// square.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any coordinate of `min` or `max` is not finite, or if `max` does not exceed
+    /// `min` along every axis.
+    #[inline]
+    pub fn with_bounds(mut self, min: [f64; 2], max: [f64; 2]) -> Self {
+        assert!(
+            (0..2).all(|i| min[i].is_finite() && max[i].is_finite() && max[i] > min[i]),
+            "bounds must be finite, and max must exceed min along every axis"
+        );
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Set the method used to interpolate between the centers of the pixels. By default,
+    /// [`Interpolation::Bilinear`] is used. Interpolated values are clamped to the range of the
+    /// values of the image, such that [`Interpolation::Bicubic`] does not overshoot it.
+    #[inline]
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Set the value at points beyond the rectangle covered by the image. By default,
+    /// [`ImageBoundary::Clamp`] is used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value of [`ImageBoundary::Constant`] is not finite.
+    #[inline]
+    pub fn with_boundary(mut self, boundary: ImageBoundary) -> Self {
+        if let ImageBoundary::Constant(value) = boundary {
+            assert!(value.is_finite(), "boundary value must be finite");
+        }
+        self.boundary = boundary;
+        self
+    }
+
+    /// Set the values to which black and white pixels are mapped, with shades of gray being
+    /// mapped linearly in between. By default, black is mapped to -1 and white to 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low` or `high` is not finite.
+    #[inline]
+    pub fn with_value_range(mut self, low: f64, high: f64) -> Self {
+        assert!(
+            low.is_finite() && high.is_finite(),
+            "value range must be finite"
+        );
+        self.low = low;
+        self.high = high;
+        self
+    }
+
+    // the value of the pixel at the given possibly out of bounds row and column
+    #[inline]
+    fn texel(&self, row: f64, column: f64) -> f64 {
+        let [rows, columns] = self.shape.map(|len| len as f64);
+        let (row, column) = match self.boundary {
+            ImageBoundary::Clamp => (row.clamp(0.0, rows - 1.0), column.clamp(0.0, columns - 1.0)),
+            ImageBoundary::Wrap => (row.rem_euclid(rows), column.rem_euclid(columns)),
+            ImageBoundary::Constant(value) => {
+                if !(0.0..rows).contains(&row) || !(0.0..columns).contains(&column) {
+                    return value;
+                }
+                (row, column)
+            }
+        };
+        let (row, column) = (
+            (row as usize).min(self.shape[0] - 1),
+            (column as usize).min(self.shape[1] - 1),
+        );
+        let brightness = self.pixels[row * self.shape[1] + column];
+        self.low + brightness * (self.high - self.low)
+    }
+
+    // the smallest and largest value of the generator
+    fn value_range(&self) -> (f64, f64) {
+        let (mut lower, mut upper) = (self.low.min(self.high), self.low.max(self.high));
+        if let ImageBoundary::Constant(value) = self.boundary {
+            lower = lower.min(value);
+            upper = upper.max(value);
+        }
+        (lower, upper)
+    }
+}
+
+impl Generator<2> for ImageSource {
+    fn sample(&self, point: [f64; 2]) -> f64 {
+        if point.iter().any(|x| !x.is_finite()) {
+            return f64::NAN;
+        }
+        // the continuous pixel coordinates, with the centers of the pixels at integers
+        let [(row, row_weights), (column, column_weights)] = core::array::from_fn(|i| {
+            let coord =
+                (point[i] - self.min[i]) / (self.max[i] - self.min[i]) * self.shape[i] as f64;
+            resample::tap_weights(coord - 0.5, self.interpolation)
+        });
+        let num_taps = resample::num_taps(self.interpolation);
+        let mut value = 0.0;
+        for (i, row_weight) in row_weights.iter().take(num_taps).enumerate() {
+            for (j, column_weight) in column_weights.iter().take(num_taps).enumerate() {
+                value += row_weight * column_weight * self.texel(row + i as f64, column + j as f64);
+            }
+        }
+        // guards against overshooting with bicubic interpolation, and against rounding errors
+        let (lower, upper) = self.value_range();
+        value.clamp(lower, upper)
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; 2], _max: [f64; 2]) -> (f64, f64) {
+        self.value_range()
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("ImageSource")
+            .with_param("shape", format_args!("{}x{}", self.shape[0], self.shape[1]))
+            .with_array_param("min", &self.min)
+            .with_array_param("max", &self.max)
+            .with_param("interpolation", format_args!("{:?}", self.interpolation))
+            .with_param("boundary", format_args!("{:?}", self.boundary))
+            .with_param("low", self.low)
+            .with_param("high", self.high)
+    }
+}
+
+impl fmt::Display for ImageSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.describe(), f)
+    }
+}
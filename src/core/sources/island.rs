@@ -0,0 +1,171 @@
+use super::functional;
+use crate::core::{
+    error::Error,
+    generator::{Generator, Generator2D},
+    recipe::{NoiseRecipe, RecipeError},
+    utils::describe::Description,
+};
+use alloc::format;
+use core::fmt;
+
+/// The curve along which the value of an island falls off from its center towards its edge.
+///
+/// The curves are expressed in terms of the distance from the center of the island, relative to
+/// its size, such that the distance is 0 at the center and 1 at the edge. For details, see
+/// [`Source::island()`](crate::Source::island).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum FalloffCurve {
+    /// Falls off linearly with the distance, forming a cone.
+    Linear,
+    /// Falls off along the smoothstep curve `3d² - 2d³`, forming a dome which is flat at the
+    /// center and meets the edge smoothly.
+    #[default]
+    Smoothstep,
+    /// The product `(1 - x²)(1 - y²)` of the relative offsets `x` and `y` along each axis, which
+    /// keeps more land towards the corners of a square than the other curves.
+    SquareBump,
+    /// Falls off with the distance raised to the given exponent. Exponents above 1 keep a wide
+    /// plateau with steep coasts, while exponents below 1 form a sharp peak with shallow coasts.
+    /// The exponent must be positive and finite.
+    Exponent(f64),
+}
+
+/// A generator which produces a 2-dimensional round island mask.
+///
+/// For details, see the documentation of [`island()`]. Typically, this struct is not meant to be
+/// used directly. Instead, [`island()`] implemented by [`Source`], should be used to create an
+/// island generator.
+///
+/// # Direct usage of this struct
+///
+/// Direct instantiation of this struct:
+///
+/// ```
+/// # use libnoise::{FalloffCurve, Island, Generator};
+/// let generator = Island::new([0.0, 0.0], 5.0, FalloffCurve::Linear).unwrap();
+/// let value = generator.sample([3.0, 2.0]);
+/// ```
+///
+/// [`island()`]: crate::Source::island
+/// [`Source`]: crate::Source
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "IslandParams", try_from = "IslandParams")
+)]
+pub struct Island {
+    center: [f64; 2],
+    radius: f64,
+    falloff: FalloffCurve,
+}
+
+impl Generator2D for Island {}
+
+impl Island {
+    /// Create a new island generator centered at `center`, falling off along `falloff` until
+    /// reaching the edge at `radius`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if any coordinate of `center` is not finite, if
+    /// `radius` is not positive and finite, or if the exponent of [`FalloffCurve::Exponent`] is
+    /// not positive and finite.
+    pub fn new(center: [f64; 2], radius: f64, falloff: FalloffCurve) -> Result<Self, Error> {
+        functional::island::validate_round(&center, radius, falloff).map_err(
+            |(name, reason)| Error::InvalidParameter {
+                name,
+                reason: format!(
+                    "{}, got center {:?}, radius {} and falloff {:?}",
+                    reason, center, radius, falloff
+                ),
+            },
+        )?;
+        Ok(Self {
+            center,
+            radius,
+            falloff,
+        })
+    }
+}
+
+impl Generator<2> for Island {
+    #[inline]
+    fn sample(&self, point: [f64; 2]) -> f64 {
+        let offsets = core::array::from_fn(|i| (point[i] - self.center[i]) / self.radius);
+        functional::island::falloff(self.falloff, offsets, functional::island::euclidean)
+    }
+
+    fn sample_interval(&self, min: [f64; 2], max: [f64; 2]) -> (f64, f64) {
+        // the value falls with the absolute offset along each axis, and is thus highest at the
+        // point of the box closest to the center, and lowest at the corner farthest from it
+        let closest = core::array::from_fn(|i| self.center[i].max(min[i]).min(max[i]));
+        let farthest = core::array::from_fn(|i| {
+            if (self.center[i] - min[i]).abs() > (max[i] - self.center[i]).abs() {
+                min[i]
+            } else {
+                max[i]
+            }
+        });
+        let (lower, upper) = (self.sample(farthest), self.sample(closest));
+        if lower.is_nan() || upper.is_nan() {
+            return (-1.0, 1.0);
+        }
+        (lower, upper)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Island")
+            .with_array_param("center", &self.center)
+            .with_param("radius", self.radius)
+            .with_param("falloff", format_args!("{:?}", self.falloff))
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Island {
+            center: self.center.to_vec(),
+            radius: self.radius,
+            falloff: self.falloff,
+        })
+    }
+}
+
+impl fmt::Display for Island {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.describe(), f)
+    }
+}
+
+// the serialized form of the generator, which is validated like new() on deserialization
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct IslandParams {
+    center: [f64; 2],
+    radius: f64,
+    falloff: FalloffCurve,
+}
+
+#[cfg(feature = "serde")]
+impl From<Island> for IslandParams {
+    fn from(generator: Island) -> Self {
+        Self {
+            center: generator.center,
+            radius: generator.radius,
+            falloff: generator.falloff,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<IslandParams> for Island {
+    type Error = Error;
+
+    fn try_from(params: IslandParams) -> Result<Self, Self::Error> {
+        Self::new(params.center, params.radius, params.falloff)
+    }
+}
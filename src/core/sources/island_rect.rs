@@ -0,0 +1,148 @@
+use super::{functional, FalloffCurve};
+use crate::core::{
+    error::Error,
+    generator::{Generator, Generator2D},
+    recipe::{NoiseRecipe, RecipeError},
+    utils::describe::Description,
+};
+use alloc::format;
+use core::fmt;
+
+/// A generator which produces a 2-dimensional rectangular island mask.
+///
+/// For details, see the documentation of [`island_rect()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`island_rect()`] implemented by [`Source`], should be used to
+/// create a rectangular island generator.
+///
+/// # Direct usage of this struct
+///
+/// Direct instantiation of this struct:
+///
+/// ```
+/// # use libnoise::{FalloffCurve, IslandRect, Generator};
+/// let generator = IslandRect::new([0.0, 0.0], [512.0, 256.0], FalloffCurve::SquareBump).unwrap();
+/// let value = generator.sample([300.0, 20.0]);
+/// ```
+///
+/// [`island_rect()`]: crate::Source::island_rect
+/// [`Source`]: crate::Source
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "IslandRectParams", try_from = "IslandRectParams")
+)]
+pub struct IslandRect {
+    min: [f64; 2],
+    max: [f64; 2],
+    falloff: FalloffCurve,
+}
+
+impl Generator2D for IslandRect {}
+
+impl IslandRect {
+    /// Create a new rectangular island generator covering the rectangle from `min` to `max`,
+    /// falling off along `falloff` from its center until reaching its edges.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if any coordinate of `min` or `max` is not finite, if
+    /// `max` does not exceed `min` along every axis, or if the exponent of
+    /// [`FalloffCurve::Exponent`] is not positive and finite.
+    pub fn new(min: [f64; 2], max: [f64; 2], falloff: FalloffCurve) -> Result<Self, Error> {
+        functional::island::validate_rect(&min, &max, falloff).map_err(|(name, reason)| {
+            Error::InvalidParameter {
+                name,
+                reason: format!(
+                    "{}, got min {:?}, max {:?} and falloff {:?}",
+                    reason, min, max, falloff
+                ),
+            }
+        })?;
+        Ok(Self { min, max, falloff })
+    }
+
+    #[inline]
+    fn center(&self) -> [f64; 2] {
+        core::array::from_fn(|i| (self.min[i] + self.max[i]) * 0.5)
+    }
+}
+
+impl Generator<2> for IslandRect {
+    #[inline]
+    fn sample(&self, point: [f64; 2]) -> f64 {
+        let center = self.center();
+        let offsets =
+            core::array::from_fn(|i| (point[i] - center[i]) / ((self.max[i] - self.min[i]) * 0.5));
+        functional::island::falloff(self.falloff, offsets, functional::island::chebyshev)
+    }
+
+    fn sample_interval(&self, min: [f64; 2], max: [f64; 2]) -> (f64, f64) {
+        // the value falls with the absolute offset along each axis, and is thus highest at the
+        // point of the box closest to the center, and lowest at the corner farthest from it
+        let center = self.center();
+        let closest = core::array::from_fn(|i| center[i].max(min[i]).min(max[i]));
+        let farthest = core::array::from_fn(|i| {
+            if (center[i] - min[i]).abs() > (max[i] - center[i]).abs() {
+                min[i]
+            } else {
+                max[i]
+            }
+        });
+        let (lower, upper) = (self.sample(farthest), self.sample(closest));
+        if lower.is_nan() || upper.is_nan() {
+            return (-1.0, 1.0);
+        }
+        (lower, upper)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("IslandRect")
+            .with_array_param("min", &self.min)
+            .with_array_param("max", &self.max)
+            .with_param("falloff", format_args!("{:?}", self.falloff))
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::IslandRect {
+            min: self.min.to_vec(),
+            max: self.max.to_vec(),
+            falloff: self.falloff,
+        })
+    }
+}
+
+impl fmt::Display for IslandRect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.describe(), f)
+    }
+}
+
+// the serialized form of the generator, which is validated like new() on deserialization
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct IslandRectParams {
+    min: [f64; 2],
+    max: [f64; 2],
+    falloff: FalloffCurve,
+}
+
+#[cfg(feature = "serde")]
+impl From<IslandRect> for IslandRectParams {
+    fn from(generator: IslandRect) -> Self {
+        Self {
+            min: generator.min,
+            max: generator.max,
+            falloff: generator.falloff,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<IslandRectParams> for IslandRect {
+    type Error = Error;
+
+    fn try_from(params: IslandRectParams) -> Result<Self, Self::Error> {
+        Self::new(params.min, params.max, params.falloff)
+    }
+}
@@ -0,0 +1,125 @@
+use super::functional;
+use crate::core::{
+    error::Error,
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{NoiseRecipe, RecipeError},
+    utils::describe::{impl_display, Description},
+};
+use alloc::format;
+
+/// A generator which produces an n-dimensional linear gradient between two points.
+///
+/// For details, see the documentation of [`linear_gradient()`]. Typically, this struct is not
+/// meant to be used directly. Instead, [`linear_gradient()`] implemented by [`Source`], should
+/// be used to create a linear gradient generator.
+///
+/// # Direct usage of this struct
+///
+/// Direct instantiation of this struct:
+///
+/// ```
+/// # use libnoise::{LinearGradient, Generator};
+/// let generator = LinearGradient::new([0.0, 0.0], [4.0, 0.0]).unwrap();
+/// let value = generator.sample([1.0, 3.0]);
+/// ```
+///
+/// [`linear_gradient()`]: crate::Source::linear_gradient
+/// [`Source`]: crate::Source
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "LinearGradientParams<D>", try_from = "LinearGradientParams<D>")
+)]
+pub struct LinearGradient<const D: usize> {
+    from: [f64; D],
+    to: [f64; D],
+}
+
+impl Generator1D for LinearGradient<1> {}
+impl Generator2D for LinearGradient<2> {}
+impl Generator3D for LinearGradient<3> {}
+impl Generator4D for LinearGradient<4> {}
+
+impl<const D: usize> LinearGradient<D> {
+    /// Create a new linear gradient generator from `from` to `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if any coordinate of `from` or `to` is not finite, or
+    /// if `from` equals `to`.
+    pub fn new(from: [f64; D], to: [f64; D]) -> Result<Self, Error> {
+        functional::gradient::validate_linear(&from, &to).map_err(|(name, reason)| {
+            Error::InvalidParameter {
+                name,
+                reason: format!("{}, got from {:?} and to {:?}", reason, from, to),
+            }
+        })?;
+        Ok(Self { from, to })
+    }
+}
+
+impl<const D: usize> Generator<D> for LinearGradient<D> {
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        let t = functional::gradient::linear(self.from, self.to, point);
+        t.clamp(0.0, 1.0) * 2.0 - 1.0
+    }
+
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        // the position along the gradient is an affine function of the point, and is thus
+        // extremal at the corners of the box, which are found per axis
+        let ascending = |i: usize| self.to[i] >= self.from[i];
+        let lower = core::array::from_fn(|i| if ascending(i) { min[i] } else { max[i] });
+        let upper = core::array::from_fn(|i| if ascending(i) { max[i] } else { min[i] });
+        let (lower, upper) = (self.sample(lower), self.sample(upper));
+        if lower.is_nan() || upper.is_nan() {
+            return (-1.0, 1.0);
+        }
+        (lower, upper)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("LinearGradient")
+            .with_array_param("from", &self.from)
+            .with_array_param("to", &self.to)
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::LinearGradient {
+            from: self.from.to_vec(),
+            to: self.to.to_vec(),
+        })
+    }
+}
+
+impl_display!([const D: usize] LinearGradient<D>);
+
+// the serialized form of the generator, which is validated like new() on deserialization
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct LinearGradientParams<const D: usize> {
+    #[serde(with = "crate::core::utils::serde_array")]
+    from: [f64; D],
+    #[serde(with = "crate::core::utils::serde_array")]
+    to: [f64; D],
+}
+
+#[cfg(feature = "serde")]
+impl<const D: usize> From<LinearGradient<D>> for LinearGradientParams<D> {
+    fn from(generator: LinearGradient<D>) -> Self {
+        Self {
+            from: generator.from,
+            to: generator.to,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const D: usize> TryFrom<LinearGradientParams<D>> for LinearGradient<D> {
+    type Error = Error;
+
+    fn try_from(params: LinearGradientParams<D>) -> Result<Self, Self::Error> {
+        Self::new(params.from, params.to)
+    }
+}
@@ -1,17 +1,55 @@
+mod blue;
+#[cfg(target_has_atomic = "ptr")]
+mod buffer_source;
 mod checkerboard;
 mod constant;
 mod custom;
-mod functional;
+#[cfg(target_has_atomic = "ptr")]
+mod custom_dyn;
+mod custom_state;
+mod distance_field;
+pub(crate) mod functional;
+#[cfg(feature = "image")]
+mod image_source;
 mod improved_perlin;
+mod island;
+mod island_rect;
+mod linear_gradient;
+mod open_simplex2;
+mod open_simplex2s;
 mod perlin;
+mod radial_gradient;
 mod simplex;
+mod spot;
 mod value;
+mod vector_simplex;
+mod wavelet;
+mod white;
 mod worley;
+pub use blue::Blue;
+#[cfg(target_has_atomic = "ptr")]
+pub use buffer_source::BufferSource;
 pub use checkerboard::Checkerboard;
 pub use constant::Constant;
 pub use custom::Custom;
+#[cfg(target_has_atomic = "ptr")]
+pub use custom_dyn::CustomDyn;
+pub use custom_state::CustomWithState;
+pub use distance_field::DistanceField;
+#[cfg(feature = "image")]
+pub use image_source::{ImageBoundary, ImageSource};
 pub use improved_perlin::ImprovedPerlin;
+pub use island::{FalloffCurve, Island};
+pub use island_rect::IslandRect;
+pub use linear_gradient::LinearGradient;
+pub use open_simplex2::OpenSimplex2;
+pub use open_simplex2s::OpenSimplex2S;
 pub use perlin::Perlin;
+pub use radial_gradient::RadialGradient;
 pub use simplex::Simplex;
+pub use spot::{Spot, SpotKernel};
 pub use value::Value;
-pub use worley::Worley;
+pub use vector_simplex::VectorSimplex;
+pub use wavelet::Wavelet;
+pub use white::White;
+pub use worley::{DensityWorley, Worley, WorleyReturnType};
@@ -0,0 +1,104 @@
+use super::functional::{self, constants::PERMUTATION_TABLE_SIZE};
+use crate::core::{
+    generator::{Generator, Generator2D, Generator3D},
+    recipe::{NoiseRecipe, RecipeError},
+    utils::{
+        describe::{impl_display, Description},
+        ptable::PermutationTable,
+    },
+};
+
+/// A generator which produces 2- or 3-dimensional OpenSimplex2 noise.
+///
+/// For details, see the documentation of [`open_simplex2()`]. Typically, this struct is not
+/// meant to be used directly. Instead, [`open_simplex2()`] implemented by [`Source`], should be
+/// used to create an OpenSimplex2 noise generator.
+///
+/// # Direct usage of this struct
+///
+/// Direct instantiation of this struct:
+///
+/// ```
+/// # use libnoise::{OpenSimplex2, Generator};
+/// let generator = OpenSimplex2::new(42);
+/// let value = generator.sample([0.2, 0.5]);
+/// ```
+///
+/// [`open_simplex2()`]: crate::Source::open_simplex2
+/// [`Source`]: crate::Source
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OpenSimplex2<const D: usize> {
+    #[cfg_attr(feature = "serde", serde(rename = "seed"))]
+    permutation_table: PermutationTable,
+}
+
+impl Generator2D for OpenSimplex2<2> {}
+impl Generator3D for OpenSimplex2<3> {}
+
+impl<const D: usize> OpenSimplex2<D> {
+    /// Create a new OpenSimplex2 noise generator.
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        let permutation_table = PermutationTable::new(seed, PERMUTATION_TABLE_SIZE, true);
+        Self { permutation_table }
+    }
+
+    fn description(&self) -> Description {
+        Description::new("OpenSimplex2").with_param("seed", self.permutation_table.seed)
+    }
+
+    fn recipe(&self) -> NoiseRecipe {
+        NoiseRecipe::OpenSimplex2 {
+            seed: self.permutation_table.seed,
+        }
+    }
+}
+
+impl Generator<2> for OpenSimplex2<2> {
+    #[inline]
+    fn sample(&self, point: [f64; 2]) -> f64 {
+        functional::open_simplex2::noise2d(&self.permutation_table, point)
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; 2], _max: [f64; 2]) -> (f64, f64) {
+        (
+            -functional::open_simplex2::BOUND_2D,
+            functional::open_simplex2::BOUND_2D,
+        )
+    }
+
+    fn describe(&self) -> Description {
+        self.description()
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(self.recipe())
+    }
+}
+
+impl Generator<3> for OpenSimplex2<3> {
+    #[inline]
+    fn sample(&self, point: [f64; 3]) -> f64 {
+        functional::open_simplex2::noise3d(&self.permutation_table, point)
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; 3], _max: [f64; 3]) -> (f64, f64) {
+        (
+            -functional::open_simplex2::BOUND_3D,
+            functional::open_simplex2::BOUND_3D,
+        )
+    }
+
+    fn describe(&self) -> Description {
+        self.description()
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(self.recipe())
+    }
+}
+
+impl_display!([const D: usize] OpenSimplex2<D>);
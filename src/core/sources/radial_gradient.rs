@@ -0,0 +1,148 @@
+use super::functional;
+use crate::core::{
+    error::Error,
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{NoiseRecipe, RecipeError},
+    utils::describe::{impl_display, Description},
+};
+use alloc::format;
+
+/// A generator which produces an n-dimensional radial gradient around a center.
+///
+/// For details, see the documentation of [`radial_gradient()`]. Typically, this struct is not
+/// meant to be used directly. Instead, [`radial_gradient()`] implemented by [`Source`], should
+/// be used to create a radial gradient generator.
+///
+/// # Direct usage of this struct
+///
+/// Direct instantiation of this struct:
+///
+/// ```
+/// # use libnoise::{RadialGradient, Generator};
+/// let generator = RadialGradient::new([0.0, 0.0], 1.0, 5.0).unwrap();
+/// let value = generator.sample([3.0, 2.0]);
+/// ```
+///
+/// [`radial_gradient()`]: crate::Source::radial_gradient
+/// [`Source`]: crate::Source
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "RadialGradientParams<D>", try_from = "RadialGradientParams<D>")
+)]
+pub struct RadialGradient<const D: usize> {
+    center: [f64; D],
+    inner_radius: f64,
+    outer_radius: f64,
+}
+
+impl Generator1D for RadialGradient<1> {}
+impl Generator2D for RadialGradient<2> {}
+impl Generator3D for RadialGradient<3> {}
+impl Generator4D for RadialGradient<4> {}
+
+impl<const D: usize> RadialGradient<D> {
+    /// Create a new radial gradient generator around `center`, falling off from
+    /// `inner_radius` to `outer_radius`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if any coordinate of `center` is not finite, if
+    /// `inner_radius` is negative or not finite, or if `outer_radius` is not finite or does not
+    /// exceed `inner_radius`.
+    pub fn new(center: [f64; D], inner_radius: f64, outer_radius: f64) -> Result<Self, Error> {
+        functional::gradient::validate_radial(&center, inner_radius, outer_radius).map_err(
+            |(name, reason)| Error::InvalidParameter {
+                name,
+                reason: format!(
+                    "{}, got center {:?}, inner_radius {} and outer_radius {}",
+                    reason, center, inner_radius, outer_radius
+                ),
+            },
+        )?;
+        Ok(Self {
+            center,
+            inner_radius,
+            outer_radius,
+        })
+    }
+
+    // maps a distance within [inner_radius, outer_radius] to [1, -1], clamping beyond
+    fn normalize(&self, distance: f64) -> f64 {
+        let t = (distance - self.inner_radius) / (self.outer_radius - self.inner_radius);
+        1.0 - t.clamp(0.0, 1.0) * 2.0
+    }
+}
+
+impl<const D: usize> Generator<D> for RadialGradient<D> {
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.normalize(functional::gradient::radial(self.center, point))
+    }
+
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        // the value falls with the distance, which is smallest at the point of the box closest
+        // to the center, and largest at the corner farthest from it
+        let closest = core::array::from_fn(|i| self.center[i].max(min[i]).min(max[i]));
+        let farthest = core::array::from_fn(|i| {
+            if (self.center[i] - min[i]).abs() > (max[i] - self.center[i]).abs() {
+                min[i]
+            } else {
+                max[i]
+            }
+        });
+        let (lower, upper) = (self.sample(farthest), self.sample(closest));
+        if lower.is_nan() || upper.is_nan() {
+            return (-1.0, 1.0);
+        }
+        (lower, upper)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("RadialGradient")
+            .with_array_param("center", &self.center)
+            .with_param("inner_radius", self.inner_radius)
+            .with_param("outer_radius", self.outer_radius)
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::RadialGradient {
+            center: self.center.to_vec(),
+            inner_radius: self.inner_radius,
+            outer_radius: self.outer_radius,
+        })
+    }
+}
+
+impl_display!([const D: usize] RadialGradient<D>);
+
+// the serialized form of the generator, which is validated like new() on deserialization
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RadialGradientParams<const D: usize> {
+    #[serde(with = "crate::core::utils::serde_array")]
+    center: [f64; D],
+    inner_radius: f64,
+    outer_radius: f64,
+}
+
+#[cfg(feature = "serde")]
+impl<const D: usize> From<RadialGradient<D>> for RadialGradientParams<D> {
+    fn from(generator: RadialGradient<D>) -> Self {
+        Self {
+            center: generator.center,
+            inner_radius: generator.inner_radius,
+            outer_radius: generator.outer_radius,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const D: usize> TryFrom<RadialGradientParams<D>> for RadialGradient<D> {
+    type Error = Error;
+
+    fn try_from(params: RadialGradientParams<D>) -> Result<Self, Self::Error> {
+        Self::new(params.center, params.inner_radius, params.outer_radius)
+    }
+}
@@ -1,8 +1,14 @@
-use super::functional::{self, constants::PERMUTATION_TABLE_SIZE};
+use super::functional::{self, constants::PERMUTATION_TABLE_SIZE, simplex::Kernel};
 use crate::core::{
+    error::Error,
     generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
-    utils::ptable::PermutationTable,
+    recipe::{NoiseRecipe, RecipeError},
+    utils::{
+        describe::{self, impl_display, Description},
+        ptable::PermutationTable,
+    },
 };
+use alloc::format;
 
 /// A generator which produces n-dimensional simplex noise.
 ///
@@ -20,11 +26,60 @@ use crate::core::{
 /// let value = generator.sample([0.2, 0.5]);
 /// ```
 ///
+/// # Large coordinates
+///
+/// Far from the origin, skewing the input into lattice space loses precision, such that the
+/// noise degrades visibly for inputs of large magnitude. Calling [`with_large_coordinates()`]
+/// enables a mode in which the input is split into an integer lattice part kept as `i64` and a
+/// fractional part, such that the contribution math only operates on values close to zero and
+/// the quality of the noise is uniform regardless of the distance to the origin:
+///
+/// ```
+/// # use libnoise::{Simplex, Generator};
+/// let generator = Simplex::new(42).with_large_coordinates();
+/// let value = generator.sample([1e12 + 0.2, -1e12 + 0.5]);
+/// ```
+///
+/// Near the origin, the output of this mode differs from the default mode only by rounding, that
+/// is, by a relative amount on the order of `1e-15`. The 1-dimensional noise does not involve
+/// skewing and is reduced exactly to the period of the lattice hashing instead.
+///
+/// # Kernel radius
+///
+/// Each corner of the simplex containing the input point contributes to the noise within a
+/// sphere around it, whose squared radius is 0.5 by default. With [`new_with_radius()`], a
+/// smaller radius can be chosen, which produces noise with more isolated features, and the
+/// output is rescaled such that it retains its range:
+///
+/// ```
+/// # use libnoise::{Simplex, Generator};
+/// let generator = Simplex::new_with_radius(42, 0.4).unwrap();
+/// let value = generator.sample([0.2, 0.5]);
+/// ```
+///
+/// Larger radii, such as the 0.6 used by some reference implementations, are rejected, as the
+/// contributions of corners outside of the simplex would then reach into it without being
+/// summed, causing discontinuities at the borders of the simplices.
+///
 /// [`simplex()`]: crate::Source::simplex
+/// [`new_with_radius()`]: Simplex::new_with_radius
+/// [`with_large_coordinates()`]: Simplex::with_large_coordinates
 /// [`Source`]: crate::Source
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Simplex<const D: usize> {
+    #[cfg_attr(feature = "serde", serde(rename = "seed"))]
     permutation_table: PermutationTable,
+    large_coordinates: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "r_squared",
+            default,
+            skip_serializing_if = "Kernel::is_default"
+        )
+    )]
+    kernel: Kernel,
 }
 
 impl Generator1D for Simplex<1> {}
@@ -37,34 +92,93 @@ impl<const D: usize> Simplex<D> {
     #[inline]
     pub fn new(seed: u64) -> Self {
         let permutation_table = PermutationTable::new(seed, PERMUTATION_TABLE_SIZE, true);
-        Self { permutation_table }
+        Self {
+            permutation_table,
+            large_coordinates: false,
+            kernel: Kernel::DEFAULT,
+        }
     }
-}
 
-impl Generator<1> for Simplex<1> {
-    #[inline]
-    fn sample(&self, point: [f64; 1]) -> f64 {
-        functional::simplex::noise1d(&self.permutation_table, point)
+    /// Create a new simplex noise generator whose corners contribute within a sphere of the
+    /// squared radius `r_squared`. For further detail see the [Kernel radius](#kernel-radius)
+    /// section.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if `r_squared` is not within `[0.01, 0.5]`.
+    pub fn new_with_radius(seed: u64, r_squared: f64) -> Result<Self, Error> {
+        functional::simplex::validate_r_squared(r_squared).map_err(|(name, reason)| {
+            Error::InvalidParameter {
+                name,
+                reason: format!("{}, got {}", reason, r_squared),
+            }
+        })?;
+        Ok(Self {
+            kernel: Kernel::new(r_squared),
+            ..Self::new(seed)
+        })
     }
-}
 
-impl Generator<2> for Simplex<2> {
+    /// Enable the large-coordinate mode of this generator. For further detail see the
+    /// [Large coordinates](#large-coordinates) section.
     #[inline]
-    fn sample(&self, point: [f64; 2]) -> f64 {
-        functional::simplex::noise2d(&self.permutation_table, point)
+    pub fn with_large_coordinates(mut self) -> Self {
+        self.large_coordinates = true;
+        self
+    }
+
+    fn description(&self) -> Description {
+        let description = describe::lattice_source(
+            "Simplex",
+            self.permutation_table.seed,
+            self.large_coordinates,
+        );
+        if self.kernel.is_default() {
+            description
+        } else {
+            description.with_param("r_squared", self.kernel.r_squared)
+        }
+    }
+
+    fn recipe(&self) -> NoiseRecipe {
+        NoiseRecipe::Simplex {
+            seed: self.permutation_table.seed,
+            large_coordinates: self.large_coordinates,
+            r_squared: (!self.kernel.is_default()).then_some(self.kernel.r_squared),
+        }
     }
 }
 
-impl Generator<3> for Simplex<3> {
+impl<const D: usize> Generator<D> for Simplex<D> {
     #[inline]
-    fn sample(&self, point: [f64; 3]) -> f64 {
-        functional::simplex::noise3d(&self.permutation_table, point)
+    fn sample(&self, point: [f64; D]) -> f64 {
+        functional::simplex::noise(
+            &self.permutation_table,
+            self.kernel,
+            self.large_coordinates,
+            point,
+        )
     }
-}
 
-impl Generator<4> for Simplex<4> {
     #[inline]
-    fn sample(&self, point: [f64; 4]) -> f64 {
-        functional::simplex::noise4d(&self.permutation_table, point)
+    fn sample_interval(&self, _min: [f64; D], _max: [f64; D]) -> (f64, f64) {
+        let bound = match D {
+            1 => functional::simplex::BOUND_1D,
+            2 => functional::simplex::BOUND_2D,
+            3 => functional::simplex::BOUND_3D,
+            4 => functional::simplex::BOUND_4D,
+            _ => functional::simplex::BOUND_ND,
+        };
+        (-bound, bound)
+    }
+
+    fn describe(&self) -> Description {
+        self.description()
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(self.recipe())
     }
 }
+
+impl_display!([const D: usize] Simplex<D>);
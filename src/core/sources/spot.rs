@@ -0,0 +1,209 @@
+use super::functional;
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{NoiseRecipe, RecipeError},
+    utils::describe::{impl_display, Description},
+};
+
+/// The shape of the kernel placed at every impulse of a [`Spot`] generator.
+///
+/// Each kernel has a value of 0 at its radius and beyond.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum SpotKernel {
+    /// A Gaussian blob with a standard deviation of a third of the radius, with a value of 1 at
+    /// its center.
+    Gaussian,
+    /// A cone falling off linearly from a value of 1 at its center.
+    Cone,
+    /// A ring with a value of 1 at half the radius, falling off smoothly to 0 towards both its
+    /// center and its radius.
+    Ring,
+}
+
+/// A generator which produces n-dimensional sparse convolution noise.
+///
+/// For details, see the documentation of [`spot()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`spot()`] implemented by [`Source`], should be used to
+/// create a spot noise generator.
+///
+/// # Direct usage of this struct
+///
+/// Direct instantiation of this struct:
+///
+/// ```
+/// # use libnoise::{Spot, SpotKernel, Generator};
+/// let generator = Spot::new(42).with_kernel(SpotKernel::Ring);
+/// let value = generator.sample([0.2, 0.5]);
+/// ```
+///
+/// [`spot()`]: crate::Source::spot
+/// [`Source`]: crate::Source
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "SpotParams", try_from = "SpotParams")
+)]
+pub struct Spot<const D: usize> {
+    seed: u64,
+    kernel: SpotKernel,
+    radius: f64,
+    density: u32,
+    amplitude_jitter: f64,
+}
+
+impl Generator1D for Spot<1> {}
+impl Generator2D for Spot<2> {}
+impl Generator3D for Spot<3> {}
+impl Generator4D for Spot<4> {}
+
+impl<const D: usize> Spot<D> {
+    /// Create a new spot noise generator, placing a Gaussian kernel with a radius of 0.5 at a
+    /// single impulse per cell, without amplitude jitter.
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            kernel: SpotKernel::Gaussian,
+            radius: 0.5,
+            density: 1,
+            amplitude_jitter: 0.0,
+        }
+    }
+
+    /// Set the shape of the kernel placed at every impulse.
+    #[inline]
+    pub fn with_kernel(mut self, kernel: SpotKernel) -> Self {
+        self.kernel = kernel;
+        self
+    }
+
+    /// Set the radius of the kernel, in units of cells.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radius` is not positive and finite.
+    #[inline]
+    pub fn with_radius(mut self, radius: f64) -> Self {
+        assert!(
+            radius > 0.0 && radius.is_finite(),
+            "radius must be positive and finite, got {}",
+            radius
+        );
+        self.radius = radius;
+        self
+    }
+
+    /// Set the number of impulses per cell.
+    #[inline]
+    pub fn with_density(mut self, density: u32) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Set the amount by which the amplitude of the impulses is randomly lowered, such that each
+    /// amplitude is drawn uniformly from `[1 - amplitude_jitter, 1]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `amplitude_jitter` is not within `[0, 1]`.
+    #[inline]
+    pub fn with_amplitude_jitter(mut self, amplitude_jitter: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&amplitude_jitter),
+            "amplitude jitter must be within [0, 1], got {}",
+            amplitude_jitter
+        );
+        self.amplitude_jitter = amplitude_jitter;
+        self
+    }
+}
+
+impl<const D: usize> Generator<D> for Spot<D> {
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        functional::spot::noise(
+            self.seed,
+            self.kernel,
+            self.radius,
+            self.density,
+            self.amplitude_jitter,
+            point,
+        )
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; D], _max: [f64; D]) -> (f64, f64) {
+        (-1.0, 1.0)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Spot")
+            .with_param("seed", self.seed)
+            .with_param("kernel", format_args!("{:?}", self.kernel))
+            .with_param("radius", self.radius)
+            .with_param("density", self.density)
+            .with_param("amplitude_jitter", self.amplitude_jitter)
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Spot {
+            seed: self.seed,
+            kernel: self.kernel,
+            radius: self.radius,
+            density: self.density,
+            amplitude_jitter: self.amplitude_jitter,
+        })
+    }
+}
+
+impl_display!([const D: usize] Spot<D>);
+
+// the serialized form of the generator, which is validated like the builder methods on
+// deserialization
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SpotParams {
+    seed: u64,
+    kernel: SpotKernel,
+    radius: f64,
+    density: u32,
+    amplitude_jitter: f64,
+}
+
+#[cfg(feature = "serde")]
+impl<const D: usize> From<Spot<D>> for SpotParams {
+    fn from(generator: Spot<D>) -> Self {
+        Self {
+            seed: generator.seed,
+            kernel: generator.kernel,
+            radius: generator.radius,
+            density: generator.density,
+            amplitude_jitter: generator.amplitude_jitter,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const D: usize> TryFrom<SpotParams> for Spot<D> {
+    type Error = &'static str;
+
+    fn try_from(params: SpotParams) -> Result<Self, Self::Error> {
+        if !(params.radius > 0.0 && params.radius.is_finite()) {
+            return Err("radius must be positive and finite");
+        }
+        if !(0.0..=1.0).contains(&params.amplitude_jitter) {
+            return Err("amplitude jitter must be within [0, 1]");
+        }
+        Ok(Self::new(params.seed)
+            .with_kernel(params.kernel)
+            .with_radius(params.radius)
+            .with_density(params.density)
+            .with_amplitude_jitter(params.amplitude_jitter))
+    }
+}
@@ -1,7 +1,11 @@
-use super::functional::{self, constants::PERMUTATION_TABLE_SIZE};
+use super::functional::{self, constants::PERMUTATION_TABLE_SIZE, lattice};
 use crate::core::{
     generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
-    utils::ptable::PermutationTable,
+    recipe::{NoiseRecipe, RecipeError},
+    utils::{
+        describe::{self, impl_display, Description},
+        ptable::PermutationTable,
+    },
 };
 
 /// A generator which produces n-dimensional value noise.
@@ -20,11 +24,33 @@ use crate::core::{
 /// let value = generator.sample([0.2, 0.5]);
 /// ```
 ///
+/// # Large coordinates
+///
+/// Far from the origin, the spacing of representable `f64` values grows and casting the lattice
+/// coordinates for hashing may misbehave. Calling [`with_large_coordinates()`] enables a mode in
+/// which the integer lattice part of the input is reduced exactly to the period of the lattice
+/// hashing before the noise is computed, such that the quality of the noise is uniform
+/// regardless of the distance to the origin:
+///
+/// ```
+/// # use libnoise::{Value, Generator};
+/// let generator = Value::new(42).with_large_coordinates();
+/// let value = generator.sample([1e12 + 0.2, -1e12 + 0.5]);
+/// ```
+///
+/// The output of this mode equals that of the default mode up to rounding of the fractional
+/// part of the input, which is performed relative to the period of `256` rather than the input
+/// itself, that is, an absolute difference on the order of `1e-13` in the input.
+///
 /// [`value()`]: crate::Source::value
+/// [`with_large_coordinates()`]: Value::with_large_coordinates
 /// [`Source`]: crate::Source
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Value<const D: usize> {
+    #[cfg_attr(feature = "serde", serde(rename = "seed"))]
     permutation_table: PermutationTable,
+    large_coordinates: bool,
 }
 
 impl Generator1D for Value<1> {}
@@ -37,34 +63,155 @@ impl<const D: usize> Value<D> {
     #[inline]
     pub fn new(seed: u64) -> Self {
         let permutation_table = PermutationTable::new(seed, PERMUTATION_TABLE_SIZE, true);
-        Self { permutation_table }
+        Self {
+            permutation_table,
+            large_coordinates: false,
+        }
+    }
+
+    /// Enable the large-coordinate mode of this generator. For further detail see the
+    /// [Large coordinates](#large-coordinates) section.
+    #[inline]
+    pub fn with_large_coordinates(mut self) -> Self {
+        self.large_coordinates = true;
+        self
     }
 }
 
 impl Generator<1> for Value<1> {
     #[inline]
     fn sample(&self, point: [f64; 1]) -> f64 {
+        let point = if self.large_coordinates {
+            lattice::reduce_to_period(point)
+        } else {
+            point
+        };
         functional::value::noise1d(&self.permutation_table, point)
     }
+
+    #[inline]
+    fn sample_lattice(&self, point: [i64; 1]) -> f64 {
+        let point = lattice::reduce_lattice_point(point, true);
+        functional::value::lattice1d(&self.permutation_table, point)
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; 1], _max: [f64; 1]) -> (f64, f64) {
+        (-1.0, 1.0)
+    }
+
+    fn describe(&self) -> Description {
+        describe::lattice_source("Value", self.permutation_table.seed, self.large_coordinates)
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Value {
+            seed: self.permutation_table.seed,
+            large_coordinates: self.large_coordinates,
+        })
+    }
 }
 
 impl Generator<2> for Value<2> {
     #[inline]
     fn sample(&self, point: [f64; 2]) -> f64 {
+        let point = if self.large_coordinates {
+            lattice::reduce_to_period(point)
+        } else {
+            point
+        };
         functional::value::noise2d(&self.permutation_table, point)
     }
+
+    #[inline]
+    fn sample_lattice(&self, point: [i64; 2]) -> f64 {
+        let point = lattice::reduce_lattice_point(point, self.large_coordinates);
+        functional::value::lattice2d(&self.permutation_table, point)
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; 2], _max: [f64; 2]) -> (f64, f64) {
+        (-1.0, 1.0)
+    }
+
+    fn describe(&self) -> Description {
+        describe::lattice_source("Value", self.permutation_table.seed, self.large_coordinates)
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Value {
+            seed: self.permutation_table.seed,
+            large_coordinates: self.large_coordinates,
+        })
+    }
 }
 
 impl Generator<3> for Value<3> {
     #[inline]
     fn sample(&self, point: [f64; 3]) -> f64 {
+        let point = if self.large_coordinates {
+            lattice::reduce_to_period(point)
+        } else {
+            point
+        };
         functional::value::noise3d(&self.permutation_table, point)
     }
+
+    #[inline]
+    fn sample_lattice(&self, point: [i64; 3]) -> f64 {
+        let point = lattice::reduce_lattice_point(point, self.large_coordinates);
+        functional::value::lattice3d(&self.permutation_table, point)
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; 3], _max: [f64; 3]) -> (f64, f64) {
+        (-1.0, 1.0)
+    }
+
+    fn describe(&self) -> Description {
+        describe::lattice_source("Value", self.permutation_table.seed, self.large_coordinates)
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Value {
+            seed: self.permutation_table.seed,
+            large_coordinates: self.large_coordinates,
+        })
+    }
 }
 
 impl Generator<4> for Value<4> {
     #[inline]
     fn sample(&self, point: [f64; 4]) -> f64 {
+        let point = if self.large_coordinates {
+            lattice::reduce_to_period(point)
+        } else {
+            point
+        };
         functional::value::noise4d(&self.permutation_table, point)
     }
+
+    #[inline]
+    fn sample_lattice(&self, point: [i64; 4]) -> f64 {
+        let point = lattice::reduce_lattice_point(point, self.large_coordinates);
+        functional::value::lattice4d(&self.permutation_table, point)
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; 4], _max: [f64; 4]) -> (f64, f64) {
+        (-1.0, 1.0)
+    }
+
+    fn describe(&self) -> Description {
+        describe::lattice_source("Value", self.permutation_table.seed, self.large_coordinates)
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Value {
+            seed: self.permutation_table.seed,
+            large_coordinates: self.large_coordinates,
+        })
+    }
 }
+
+impl_display!([const D: usize] Value<D>);
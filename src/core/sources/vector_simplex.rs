@@ -0,0 +1,94 @@
+use super::functional::{self, constants::PERMUTATION_TABLE_SIZE, simplex::Kernel};
+use crate::core::{
+    generator::VectorGenerator,
+    utils::{
+        describe::{self, Description},
+        ptable::PermutationTable,
+    },
+};
+use core::fmt;
+
+/// A vector generator which produces vectors of n-dimensional simplex noise.
+///
+/// For details, see the documentation of [`vector_simplex()`]. Typically, this struct is not
+/// meant to be used directly. Instead, [`vector_simplex()`] implemented by [`Source`], should be
+/// used to create a vector simplex noise generator.
+///
+/// # Direct usage of this struct
+///
+/// Direct instantiation of this struct:
+///
+/// ```
+/// # use libnoise::{VectorSimplex, VectorGenerator};
+/// let generator = VectorSimplex::new(42);
+/// let [x, y] = generator.sample([0.2, 0.5]);
+/// ```
+///
+/// # Large coordinates
+///
+/// As for [`Simplex`], calling [`with_large_coordinates()`] enables a mode in which the quality
+/// of the noise is uniform regardless of the distance to the origin.
+///
+/// [`vector_simplex()`]: crate::Source::vector_simplex
+/// [`with_large_coordinates()`]: VectorSimplex::with_large_coordinates
+/// [`Simplex`]: crate::Simplex
+/// [`Source`]: crate::Source
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VectorSimplex<const D: usize, const M: usize> {
+    #[cfg_attr(feature = "serde", serde(rename = "seed"))]
+    permutation_table: PermutationTable,
+    large_coordinates: bool,
+}
+
+impl<const D: usize, const M: usize> VectorSimplex<D, M> {
+    /// Create a new vector simplex noise generator.
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        let permutation_table = PermutationTable::new(seed, PERMUTATION_TABLE_SIZE, true);
+        Self {
+            permutation_table,
+            large_coordinates: false,
+        }
+    }
+
+    /// Enable the large-coordinate mode of this generator. For further detail see the
+    /// [Large coordinates](#large-coordinates) section.
+    #[inline]
+    pub fn with_large_coordinates(mut self) -> Self {
+        self.large_coordinates = true;
+        self
+    }
+}
+
+impl<const D: usize, const M: usize> VectorGenerator<D, M> for VectorSimplex<D, M> {
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> [f64; M] {
+        let perm = &self.permutation_table;
+        if self.large_coordinates {
+            functional::simplex::noise_nd_large(perm, Kernel::DEFAULT, point)
+        } else {
+            functional::simplex::noise_nd(perm, Kernel::DEFAULT, point)
+        }
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; D], _max: [f64; D]) -> [(f64, f64); M] {
+        let bound = functional::simplex::BOUND_ND;
+        [(-bound, bound); M]
+    }
+
+    fn describe(&self) -> Description {
+        describe::lattice_source(
+            "VectorSimplex",
+            self.permutation_table.seed,
+            self.large_coordinates,
+        )
+    }
+}
+
+impl<const D: usize, const M: usize> fmt::Display for VectorSimplex<D, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.describe(), f)
+    }
+}
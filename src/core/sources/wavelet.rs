@@ -0,0 +1,120 @@
+use super::functional;
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{NoiseRecipe, RecipeError},
+    utils::describe::{impl_display, Description},
+};
+use alloc::vec::Vec;
+
+/// A generator which produces n-dimensional wavelet noise from a precomputed tile.
+///
+/// For details, see the documentation of [`wavelet()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`wavelet()`] implemented by [`Source`], should be used to
+/// create a wavelet noise generator.
+///
+/// # Direct usage of this struct
+///
+/// Direct instantiation of this struct:
+///
+/// ```
+/// # use libnoise::{Wavelet, Generator};
+/// let generator = Wavelet::new(42, 32);
+/// let value = generator.sample([0.2, 0.5]);
+/// ```
+///
+/// [`wavelet()`]: crate::Source::wavelet
+/// [`Source`]: crate::Source
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "WaveletParams", try_from = "WaveletParams")
+)]
+pub struct Wavelet<const D: usize> {
+    seed: u64,
+    tile_size: usize,
+    tile: Vec<f64>,
+}
+
+impl Generator1D for Wavelet<1> {}
+impl Generator2D for Wavelet<2> {}
+impl Generator3D for Wavelet<3> {}
+impl Generator4D for Wavelet<4> {}
+
+impl<const D: usize> Wavelet<D> {
+    /// Create a new wavelet noise generator, precomputing a tile with `tile_size` values along
+    /// each axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tile_size` is not a positive even number.
+    pub fn new(seed: u64, tile_size: usize) -> Self {
+        assert!(
+            tile_size > 0 && tile_size.is_multiple_of(2),
+            "tile size must be a positive even number, got {}",
+            tile_size
+        );
+        Self {
+            seed,
+            tile_size,
+            tile: functional::wavelet::tile::<D>(seed, tile_size),
+        }
+    }
+}
+
+impl<const D: usize> Generator<D> for Wavelet<D> {
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        functional::wavelet::noise(&self.tile, self.tile_size, point)
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; D], _max: [f64; D]) -> (f64, f64) {
+        (-1.0, 1.0)
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("Wavelet")
+            .with_param("seed", self.seed)
+            .with_param("tile_size", self.tile_size)
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::Wavelet {
+            seed: self.seed,
+            tile_size: self.tile_size,
+        })
+    }
+}
+
+impl_display!([const D: usize] Wavelet<D>);
+
+// the serialized form of the generator, from which the tile is recomputed on deserialization
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct WaveletParams {
+    seed: u64,
+    tile_size: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<const D: usize> From<Wavelet<D>> for WaveletParams {
+    fn from(generator: Wavelet<D>) -> Self {
+        Self {
+            seed: generator.seed,
+            tile_size: generator.tile_size,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const D: usize> TryFrom<WaveletParams> for Wavelet<D> {
+    type Error = &'static str;
+
+    fn try_from(params: WaveletParams) -> Result<Self, Self::Error> {
+        if params.tile_size == 0 || !params.tile_size.is_multiple_of(2) {
+            return Err("tile size must be a positive even number");
+        }
+        Ok(Self::new(params.seed, params.tile_size))
+    }
+}
@@ -0,0 +1,75 @@
+use super::functional;
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    recipe::{NoiseRecipe, RecipeError},
+    utils::describe::{impl_display, Description},
+};
+
+/// A generator which produces n-dimensional white noise.
+///
+/// For details, see the documentation of [`white()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`white()`] implemented by [`Source`], should be used to
+/// create a white noise generator.
+///
+/// # Direct usage of this struct
+///
+/// Direct instantiation of this struct:
+///
+/// ```
+/// # use libnoise::{White, Generator};
+/// let generator = White::new(42);
+/// let value = generator.sample([0.2, 0.5]);
+/// ```
+///
+/// [`white()`]: crate::Source::white
+/// [`Source`]: crate::Source
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct White<const D: usize> {
+    seed: u64,
+}
+
+impl Generator1D for White<1> {}
+impl Generator2D for White<2> {}
+impl Generator3D for White<3> {}
+impl Generator4D for White<4> {}
+
+impl<const D: usize> White<D> {
+    /// Create a new white noise generator.
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+}
+
+impl<const D: usize> Generator<D> for White<D> {
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        functional::white::noise(self.seed, point)
+    }
+
+    #[inline]
+    fn sample_lattice(&self, point: [i64; D]) -> f64 {
+        functional::white::lattice(self.seed, point)
+    }
+
+    #[inline]
+    fn sample_interval(&self, min: [f64; D], max: [f64; D]) -> (f64, f64) {
+        if min == max {
+            let value = self.sample(min);
+            (value, value)
+        } else {
+            (-1.0, 1.0)
+        }
+    }
+
+    fn describe(&self) -> Description {
+        Description::new("White").with_param("seed", self.seed)
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(NoiseRecipe::White { seed: self.seed })
+    }
+}
+
+impl_display!([const D: usize] White<D>);
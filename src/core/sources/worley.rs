@@ -1,9 +1,90 @@
-use super::functional::{self, constants::PERMUTATION_TABLE_SIZE};
+use super::functional::{self, constants::PERMUTATION_TABLE_SIZE, lattice};
 use crate::core::{
     generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
-    utils::ptable::PermutationTable,
+    recipe::{NoiseRecipe, RecipeError},
+    utils::{
+        describe::{self, impl_display, Description},
+        ptable::PermutationTable,
+    },
 };
 
+/// The value returned by a [`Worley`] generator.
+///
+/// Except for [`EdgeDistance`](Self::EdgeDistance), the values are computed from the distance
+/// `F1` to the nearest feature point and the distance `F2` to the second nearest feature point,
+/// both of which are clamped to 1. Distances of 0 and 1 map to -1 and 1 respectively.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum WorleyReturnType {
+    /// The distance to the nearest feature point, such that the value is lowest at the feature
+    /// points.
+    #[default]
+    Distance,
+    /// The distance to the nearest cell border, such that the value is lowest on the borders
+    /// between cells. The borders of the cell of the nearest feature point are the
+    /// perpendicular bisectors between it and the other feature points, and the nearest of them
+    /// is used.
+    EdgeDistance,
+    /// The distance `F2` to the second nearest feature point, which yields rounded cells with
+    /// creases along the borders between them.
+    SecondDistance,
+    /// The difference `F2 - F1` of the distances to the second nearest and nearest feature
+    /// point, such that the value is lowest on the borders between cells. Unlike
+    /// [`EdgeDistance`](Self::EdgeDistance), the value rises with the distance to the feature
+    /// point of the other cell, which yields the crackle look of cells with rounded ridges.
+    DistanceDifference,
+    /// The weighted sum `nearest * F1 + second * F2` of the distances to the nearest and second
+    /// nearest feature point. The weights must be finite, and [`Distance`](Self::Distance),
+    /// [`SecondDistance`](Self::SecondDistance), and
+    /// [`DistanceDifference`](Self::DistanceDifference) equal the weights `(1, 0)`, `(0, 1)`, and
+    /// `(-1, 1)` respectively. The value ranges between the extremes of `0`, `second`, and
+    /// `nearest + second`, mapped like a distance.
+    WeightedDistance { nearest: f64, second: f64 },
+}
+
+impl WorleyReturnType {
+    // the weights of the distances to the nearest and second nearest feature point
+    pub(crate) fn weights(self) -> [f64; 2] {
+        match self {
+            Self::Distance => [1.0, 0.0],
+            Self::SecondDistance => [0.0, 1.0],
+            Self::DistanceDifference => [-1.0, 1.0],
+            Self::WeightedDistance { nearest, second } => [nearest, second],
+            Self::EdgeDistance => unreachable!("edge distance is not a weighted sum of distances"),
+        }
+    }
+
+    pub(crate) fn validate(self) -> Result<(), (&'static str, &'static str)> {
+        match self {
+            Self::WeightedDistance { nearest, second }
+                if !(nearest.is_finite() && second.is_finite()) =>
+            {
+                Err(("return_type", "must have finite weights"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // the bounds of the value, where the weighted sum is linear in the distances and thus
+    // attains its extremes at the corners of the domain 0 <= F1 <= F2 <= 1
+    fn interval(self) -> (f64, f64) {
+        match self {
+            Self::EdgeDistance => (-1.0, 1.0),
+            return_type => {
+                let [nearest, second] = return_type.weights();
+                let corners = [0.0, second, nearest + second];
+                let min = corners.into_iter().fold(f64::INFINITY, f64::min);
+                let max = corners.into_iter().fold(f64::NEG_INFINITY, f64::max);
+                (min * 2.0 - 1.0, max * 2.0 - 1.0)
+            }
+        }
+    }
+}
+
 /// A generator which produces n-dimensional worley noise.
 ///
 /// For details, see the documentation of [`worley()`]. Typically, this struct is not meant
@@ -20,11 +101,35 @@ use crate::core::{
 /// let value = generator.sample([0.2, 0.5]);
 /// ```
 ///
+/// # Large coordinates
+///
+/// Far from the origin, the spacing of representable `f64` values grows and casting the lattice
+/// coordinates for hashing may misbehave. Calling [`with_large_coordinates()`] enables a mode in
+/// which the integer lattice part of the input is reduced exactly to the period of the lattice
+/// hashing before the noise is computed, such that the quality of the noise is uniform
+/// regardless of the distance to the origin:
+///
+/// ```
+/// # use libnoise::{Worley, Generator};
+/// let generator = Worley::new(42).with_large_coordinates();
+/// let value = generator.sample([1e12 + 0.2, -1e12 + 0.5]);
+/// ```
+///
+/// The output of this mode equals that of the default mode up to rounding of the fractional
+/// part of the input, which is performed relative to the period of `256` rather than the input
+/// itself, that is, an absolute difference on the order of `1e-13` in the input.
+///
 /// [`worley()`]: crate::Source::worley
+/// [`with_large_coordinates()`]: Worley::with_large_coordinates
 /// [`Source`]: crate::Source
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Worley<const D: usize> {
+    #[cfg_attr(feature = "serde", serde(rename = "seed"))]
     permutation_table: PermutationTable,
+    large_coordinates: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    return_type: WorleyReturnType,
 }
 
 impl Generator1D for Worley<1> {}
@@ -37,34 +142,344 @@ impl<const D: usize> Worley<D> {
     #[inline]
     pub fn new(seed: u64) -> Self {
         let permutation_table = PermutationTable::new(seed, PERMUTATION_TABLE_SIZE, true);
-        Self { permutation_table }
+        Self {
+            permutation_table,
+            large_coordinates: false,
+            return_type: WorleyReturnType::Distance,
+        }
+    }
+
+    /// Enable the large-coordinate mode of this generator. For further detail see the
+    /// [Large coordinates](#large-coordinates) section.
+    #[inline]
+    pub fn with_large_coordinates(mut self) -> Self {
+        self.large_coordinates = true;
+        self
+    }
+
+    /// Set the value returned by this generator. By default, the distance to the nearest
+    /// feature point is returned.
+    ///
+    /// With [`WorleyReturnType::EdgeDistance`], the distance to the nearest cell border is
+    /// returned instead, which yields crisp cell walls such as those of cracked mud. A distance
+    /// of 0.5 or more is mapped to 1, and the borders themselves are mapped to -1. The other
+    /// return types combine the distances to the nearest and second nearest feature point, see
+    /// [`WorleyReturnType`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the weights of [`WorleyReturnType::WeightedDistance`] are not finite.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Worley, WorleyReturnType, Generator};
+    /// let generator = Worley::new(42).with_return_type(WorleyReturnType::EdgeDistance);
+    /// let value = generator.sample([0.2, 0.5]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn with_return_type(mut self, return_type: WorleyReturnType) -> Self {
+        if let Err((_, reason)) = return_type.validate() {
+            panic!("return_type {}, got {:?}", reason, return_type);
+        }
+        self.return_type = return_type;
+        self
+    }
+
+    fn description(&self) -> Description {
+        let description = describe::lattice_source(
+            "Worley",
+            self.permutation_table.seed,
+            self.large_coordinates,
+        );
+        match self.return_type {
+            WorleyReturnType::Distance => description,
+            return_type => description.with_param("return_type", format_args!("{:?}", return_type)),
+        }
+    }
+
+    fn recipe(&self) -> NoiseRecipe {
+        NoiseRecipe::Worley {
+            seed: self.permutation_table.seed,
+            large_coordinates: self.large_coordinates,
+            return_type: self.return_type,
+        }
+    }
+}
+
+impl Worley<2> {
+    /// Modulate the density of the feature points by the given `density` generator.
+    ///
+    /// Instead of a single feature point per lattice cell, every cell spawns a hash-determined
+    /// number of candidate points between 1 and `max_points_per_cell`. Each candidate is kept if
+    /// a hash-determined threshold lies below the result of `density` at its position, where a
+    /// density of -1 or less rejects all candidates and a density of 1 or more keeps all of
+    /// them. Thus, regions where `density` is high contain more feature points, which is useful
+    /// for distributing settlements or vegetation. The points are deterministic for a given seed
+    /// and density generator.
+    ///
+    /// The seed and the return type of this generator are retained, while the large-coordinate
+    /// mode is not needed, as the cells are hashed without reducing them to a period.
+    ///
+    /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// Sampling evaluates `density` for every candidate point near the input, up to
+    /// `9 * max_points_per_cell` times, so cheap density generators are preferable. With
+    /// [`WorleyReturnType::EdgeDistance`], the search widens until the nearest border is
+    /// certain, which evaluates `density` more often in sparse regions. The edge distance is
+    /// exact as long as the nearest feature point lies within 5 cells of the input, and 1
+    /// without any feature point within 6 cells.
+    /// </p>
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_points_per_cell` is 0.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// // more feature points towards the right
+    /// let density = Source::linear_gradient([0.0, 0.0], [100.0, 0.0]).unwrap();
+    /// let generator = Source::worley(42).with_density(density, 4);
+    /// let value = generator.sample([0.2, 0.5]);
+    /// ```
+    #[inline]
+    pub fn with_density<G: Generator<2>>(
+        self,
+        density: G,
+        max_points_per_cell: u32,
+    ) -> DensityWorley<G> {
+        assert!(
+            max_points_per_cell > 0,
+            "max_points_per_cell must be at least 1, got {}",
+            max_points_per_cell
+        );
+        DensityWorley {
+            seed: self.permutation_table.seed,
+            density,
+            max_points_per_cell,
+            return_type: self.return_type,
+        }
     }
 }
 
 impl Generator<1> for Worley<1> {
     #[inline]
     fn sample(&self, point: [f64; 1]) -> f64 {
-        functional::worley::noise1d(&self.permutation_table, point)
+        let point = if self.large_coordinates {
+            lattice::reduce_to_period(point)
+        } else {
+            point
+        };
+        match self.return_type {
+            WorleyReturnType::Distance => {
+                functional::worley::noise1d(&self.permutation_table, point)
+            }
+            WorleyReturnType::EdgeDistance => {
+                functional::worley::edge1d(&self.permutation_table, point)
+            }
+            return_type => functional::worley::weighted1d(
+                &self.permutation_table,
+                return_type.weights(),
+                point,
+            ),
+        }
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; 1], _max: [f64; 1]) -> (f64, f64) {
+        self.return_type.interval()
+    }
+
+    fn describe(&self) -> Description {
+        self.description()
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(self.recipe())
     }
 }
 
 impl Generator<2> for Worley<2> {
     #[inline]
     fn sample(&self, point: [f64; 2]) -> f64 {
-        functional::worley::noise2d(&self.permutation_table, point)
+        let point = if self.large_coordinates {
+            lattice::reduce_to_period(point)
+        } else {
+            point
+        };
+        match self.return_type {
+            WorleyReturnType::Distance => {
+                functional::worley::noise2d(&self.permutation_table, point)
+            }
+            WorleyReturnType::EdgeDistance => {
+                functional::worley::edge2d(&self.permutation_table, point)
+            }
+            return_type => functional::worley::weighted2d(
+                &self.permutation_table,
+                return_type.weights(),
+                point,
+            ),
+        }
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; 2], _max: [f64; 2]) -> (f64, f64) {
+        self.return_type.interval()
+    }
+
+    fn describe(&self) -> Description {
+        self.description()
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(self.recipe())
     }
 }
 
 impl Generator<3> for Worley<3> {
     #[inline]
     fn sample(&self, point: [f64; 3]) -> f64 {
-        functional::worley::noise3d(&self.permutation_table, point)
+        let point = if self.large_coordinates {
+            lattice::reduce_to_period(point)
+        } else {
+            point
+        };
+        match self.return_type {
+            WorleyReturnType::Distance => {
+                functional::worley::noise3d(&self.permutation_table, point)
+            }
+            WorleyReturnType::EdgeDistance => {
+                functional::worley::edge3d(&self.permutation_table, point)
+            }
+            return_type => functional::worley::weighted3d(
+                &self.permutation_table,
+                return_type.weights(),
+                point,
+            ),
+        }
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; 3], _max: [f64; 3]) -> (f64, f64) {
+        self.return_type.interval()
+    }
+
+    fn describe(&self) -> Description {
+        self.description()
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(self.recipe())
     }
 }
 
 impl Generator<4> for Worley<4> {
     #[inline]
     fn sample(&self, point: [f64; 4]) -> f64 {
-        functional::worley::noise4d(&self.permutation_table, point)
+        let point = if self.large_coordinates {
+            lattice::reduce_to_period(point)
+        } else {
+            point
+        };
+        match self.return_type {
+            WorleyReturnType::Distance => {
+                functional::worley::noise4d(&self.permutation_table, point)
+            }
+            WorleyReturnType::EdgeDistance => {
+                functional::worley::edge4d(&self.permutation_table, point)
+            }
+            return_type => functional::worley::weighted4d(
+                &self.permutation_table,
+                return_type.weights(),
+                point,
+            ),
+        }
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; 4], _max: [f64; 4]) -> (f64, f64) {
+        self.return_type.interval()
+    }
+
+    fn describe(&self) -> Description {
+        self.description()
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, RecipeError> {
+        Ok(self.recipe())
+    }
+}
+
+impl_display!([const D: usize] Worley<D>);
+
+/// A generator which produces 2-dimensional worley noise with a density of feature points
+/// modulated by another generator.
+///
+/// For details, see the documentation of [`Worley::with_density()`], which should be used to
+/// create [`DensityWorley`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DensityWorley<G> {
+    seed: u64,
+    density: G,
+    max_points_per_cell: u32,
+    return_type: WorleyReturnType,
+}
+
+impl<G: Generator<2>> Generator2D for DensityWorley<G> {}
+
+impl<G: Generator<2>> Generator<2> for DensityWorley<G> {
+    #[inline]
+    fn sample(&self, point: [f64; 2]) -> f64 {
+        match self.return_type {
+            WorleyReturnType::Distance => functional::worley::density2d(
+                self.seed,
+                &self.density,
+                self.max_points_per_cell,
+                point,
+            ),
+            WorleyReturnType::EdgeDistance => functional::worley::density_edge2d(
+                self.seed,
+                &self.density,
+                self.max_points_per_cell,
+                point,
+            ),
+            return_type => functional::worley::density_weighted2d(
+                self.seed,
+                &self.density,
+                self.max_points_per_cell,
+                return_type.weights(),
+                point,
+            ),
+        }
+    }
+
+    #[inline]
+    fn sample_interval(&self, _min: [f64; 2], _max: [f64; 2]) -> (f64, f64) {
+        self.return_type.interval()
+    }
+
+    fn describe(&self) -> Description {
+        let description = Description::new("DensityWorley")
+            .with_param("seed", self.seed)
+            .with_param("max_points_per_cell", self.max_points_per_cell);
+        let description = match self.return_type {
+            WorleyReturnType::Distance => description,
+            return_type => description.with_param("return_type", format_args!("{:?}", return_type)),
+        };
+        description.with_child("density", self.density.describe())
+    }
+}
+
+impl<G: Generator<2>> core::fmt::Display for DensityWorley<G> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.describe(), f)
     }
 }
@@ -0,0 +1,124 @@
+//! Utilities for verifying properties of generators in tests.
+//!
+//! The functions in this module panic with a descriptive message if the verified property does
+//! not hold, which makes them suitable for use in tests of downstream crates as well as in the
+//! tests of this crate.
+
+use crate::core::generator::Generator;
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::utils::noisebuf::tensor_indices;
+
+/// Asserts that a 2-dimensional generator tiles seamlessly with the given `period`.
+///
+/// A generator tiles with period `p` if sampling it at `x + p` yields the same value as sampling
+/// it at `x`, for each axis. This is verified for `samples` points along each edge of the tile
+/// spanning from the origin to `period`, comparing every point on an edge to the corresponding
+/// point on the opposite edge. The points are evenly spaced, but jittered by a fixed
+/// quasi-random amount to avoid aligning with the integer lattice. To also catch
+/// discontinuities of the derivative at the seam, the comparison is repeated for a band of
+/// points less than one sample spacing inside and outside of the edges.
+///
+/// # Panics
+///
+/// Panics if the absolute difference of any pair of corresponding values exceeds `tolerance`
+/// or is NaN, reporting the worst offending pair of points.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{testing::assert_tileable, Source};
+/// // lattice noise repeats after 256 units along each axis
+/// assert_tileable(&Source::perlin(42), [256.0, 256.0], 64, 1e-9);
+/// ```
+///
+/// A generator which does not tile with the given period fails the assertion:
+///
+/// ```should_panic
+/// # use libnoise::{testing::assert_tileable, Source};
+/// assert_tileable(&Source::perlin(42), [100.0, 100.0], 64, 1e-9);
+/// ```
+pub fn assert_tileable<G: Generator<2>>(
+    generator: &G,
+    period: [f64; 2],
+    samples: usize,
+    tolerance: f64,
+) {
+    assert_tileable_nd(generator, period, samples, tolerance);
+}
+
+/// Asserts that a 3-dimensional generator tiles seamlessly with the given `period`.
+///
+/// This is the 3-dimensional equivalent of [`assert_tileable()`], where every face of the tile
+/// is sampled on a grid with `samples` points along each of its axes and compared to the
+/// opposite face.
+///
+/// # Panics
+///
+/// Panics if the absolute difference of any pair of corresponding values exceeds `tolerance`
+/// or is NaN, reporting the worst offending pair of points.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{testing::assert_tileable_3d, Source};
+/// assert_tileable_3d(&Source::value(42), [256.0, 256.0, 256.0], 16, 1e-9);
+/// ```
+pub fn assert_tileable_3d<G: Generator<3>>(
+    generator: &G,
+    period: [f64; 3],
+    samples: usize,
+    tolerance: f64,
+) {
+    assert_tileable_nd(generator, period, samples, tolerance);
+}
+
+// points are jittered by a quasi-random fraction of the sample spacing, such that they do not align
+// with the integer lattice of lattice noise, at which e.g. perlin noise is always zero
+const JITTER: f64 = 0.618_033_988_749_895;
+
+fn assert_tileable_nd<const D: usize, G: Generator<D>>(
+    generator: &G,
+    period: [f64; D],
+    samples: usize,
+    tolerance: f64,
+) {
+    // the worst offending pair of points and the difference of their values
+    let mut worst: Option<([f64; D], [f64; D], f64)> = None;
+    for axis in 0..D {
+        let offset = period[axis] / samples as f64 * JITTER;
+        // sample the face orthogonal to the current axis
+        let mut shape = [samples; D];
+        shape[axis] = 1;
+        for index in tensor_indices(shape) {
+            let mut point: [f64; D] = core::array::from_fn(|i| {
+                let jitter = ((index[i] + 1) as f64 * JITTER).fract();
+                (index[i] as f64 + jitter) * period[i] / samples as f64
+            });
+            for offset in [0.0, offset, -offset] {
+                point[axis] = offset;
+                let mut opposite = point;
+                opposite[axis] = period[axis] + offset;
+                let difference = (generator.sample(point) - generator.sample(opposite)).abs();
+                // NaN differences exceed every tolerance, and the total order ranks them
+                // above every other difference
+                let exceeds = difference > tolerance || difference.is_nan();
+                if exceeds && worst.is_none_or(|(_, _, worst)| difference.total_cmp(&worst).is_gt())
+                {
+                    worst = Some((point, opposite, difference));
+                }
+            }
+        }
+    }
+    if let Some((point, opposite, difference)) = worst {
+        panic!(
+            "generator does not tile with period {:?}: values at {:?} and {:?} differ by {}, \
+             exceeding the tolerance of {}",
+            period, point, opposite, difference, tolerance
+        );
+    }
+}
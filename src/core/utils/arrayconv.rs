@@ -0,0 +1,79 @@
+use crate::core::utils::noisebuf::{self, NoiseBuffer};
+use ndarray::{Array, ArrayD, ArrayView, Ix1, Ix2, Ix3, Ix4, IxDyn};
+
+impl<const D: usize> From<NoiseBuffer<D>> for ArrayD<f64> {
+    fn from(noisebuf: NoiseBuffer<D>) -> Self {
+        ArrayD::from_shape_vec(IxDyn(&noisebuf.shape), noisebuf.buffer).unwrap()
+    }
+}
+
+macro_rules! impl_array_conversions {
+    ($dim:literal, $ix:ty) => {
+        impl From<NoiseBuffer<$dim>> for Array<f64, $ix> {
+            fn from(noisebuf: NoiseBuffer<$dim>) -> Self {
+                Array::from_shape_vec(noisebuf.shape, noisebuf.buffer).unwrap()
+            }
+        }
+
+        impl From<Array<f64, $ix>> for NoiseBuffer<$dim> {
+            /// Converts an owned array into a noise buffer, reusing the storage of the array if
+            /// its layout is standard C-order.
+            fn from(array: Array<f64, $ix>) -> Self {
+                if !array.is_standard_layout() {
+                    return Self::from_array(array.view());
+                }
+                let shape: [usize; $dim] = array.shape().try_into().unwrap();
+                let len = array.len();
+                let (mut buffer, offset) = array.into_raw_vec_and_offset();
+                let offset = offset.unwrap_or(0);
+                if offset != 0 || buffer.len() != len {
+                    buffer = buffer[offset..offset + len].to_vec();
+                }
+                Self {
+                    shape,
+                    offsets: noisebuf::precompute_flat_index_offsets(&shape)
+                        .try_into()
+                        .unwrap(),
+                    buffer,
+                }
+            }
+        }
+
+        impl NoiseBuffer<$dim> {
+            /// Creates a new noise buffer by copying the values of the given `array` view, which
+            /// may have an arbitrary memory layout. The resulting buffer has the same shape as the
+            /// array, and indexing either results in the same values.
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// # use libnoise::NoiseBuffer;
+            /// # use ndarray::Array;
+            #[doc = concat!("let array = Array::from_elem([4; ", $dim, "], 0.5);")]
+            ///
+            #[doc = concat!("let buf = NoiseBuffer::<", $dim, ">::from_array(array.view());")]
+            ///
+            /// assert_eq!(buf.shape, array.shape());
+            /// ```
+            pub fn from_array(array: ArrayView<'_, f64, $ix>) -> Self {
+                let mut noisebuf = Self::new_empty(array.shape().try_into().unwrap());
+                for (value, &array_value) in noisebuf.buffer.iter_mut().zip(array.iter()) {
+                    *value = array_value;
+                }
+                noisebuf
+            }
+
+            /// Returns a borrowed [`ndarray`] view of the buffer without copying its values.
+            pub fn as_array_view(&self) -> ArrayView<'_, f64, $ix> {
+                ArrayView::from_shape(self.shape, &self.buffer).unwrap()
+            }
+        }
+    };
+}
+
+impl_array_conversions!(1, Ix1);
+impl_array_conversions!(2, Ix2);
+impl_array_conversions!(3, Ix3);
+impl_array_conversions!(4, Ix4);
@@ -0,0 +1,180 @@
+use crate::core::{
+    error::Error,
+    generator::Generator,
+    utils::noisebuf::{self, NoiseBuffer},
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+/// A thread-safe cache of noise buffers for chunks of an infinite n-dimensional grid.
+///
+/// This struct divides an infinite grid into chunks of a given chunk shape, each addressed by its
+/// integer chunk coordinates. The chunk with coordinates `chunk_coord` covers the grid points
+/// `chunk_coord * chunk_shape + index` for every index within the chunk shape, which are sampled
+/// from the wrapped generator. Thus, adjacent chunks line up seamlessly, and every chunk equals
+/// the respective part of a single large buffer sampled on the same grid, including for negative
+/// chunk coordinates.
+///
+/// Chunks are generated on demand by [`get_or_generate()`] and kept in the cache up to a given
+/// capacity, after which the least recently used chunk is evicted. The cache uses internal
+/// locking and can be shared between threads. The lock is not held while a chunk is generated,
+/// such that concurrent requests for the same missing chunk may generate it more than once.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, Generator, ChunkCache};
+/// let generator = Source::simplex(42);
+/// let cache = ChunkCache::new(generator.clone(), [16, 16], 64);
+///
+/// let chunk = cache.get_or_generate([-1, 2]);
+///
+/// assert_eq!(chunk[[3, 5]], generator.sample([-16.0 + 3.0, 32.0 + 5.0]));
+/// assert_eq!(cache.misses(), 1);
+/// ```
+///
+/// [`get_or_generate()`]: ChunkCache::get_or_generate
+#[derive(Debug)]
+pub struct ChunkCache<const D: usize, G> {
+    generator: G,
+    chunk_shape: [usize; D],
+    capacity: usize,
+    state: Mutex<CacheState<D>>,
+}
+
+#[derive(Debug)]
+struct CacheState<const D: usize> {
+    chunks: HashMap<[i64; D], (Arc<NoiseBuffer<D>>, u64)>,
+    tick: u64,
+    hits: u64,
+    misses: u64,
+}
+
+impl<const D: usize, G: Generator<D>> ChunkCache<D, G> {
+    /// Creates a new, empty chunk cache for chunks of the given `chunk_shape`, filled with noise
+    /// generated by the given `generator`, holding at most `capacity` chunks. For further detail
+    /// see the [struct-level documentation](ChunkCache).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(generator: G, chunk_shape: [usize; D], capacity: usize) -> Self {
+        assert!(capacity > 0, "chunk cache capacity must be positive");
+        Self {
+            generator,
+            chunk_shape,
+            capacity,
+            state: Mutex::new(CacheState {
+                chunks: HashMap::new(),
+                tick: 0,
+                hits: 0,
+                misses: 0,
+            }),
+        }
+    }
+
+    /// Returns the chunk with the given `chunk_coord`, generating and caching it if it is not
+    /// cached yet. If the cache is full, the least recently used chunk is evicted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total size of a chunk overflows, or if a global grid index of the chunk does
+    /// not fit into an `i64`.
+    pub fn get_or_generate(&self, chunk_coord: [i64; D]) -> Arc<NoiseBuffer<D>> {
+        self.try_get_or_generate(chunk_coord)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Returns the chunk with the given `chunk_coord`, like [`get_or_generate()`]. Instead of
+    /// panicking, an error is returned if the total size of a chunk overflows, or if a global
+    /// grid index of the chunk does not fit into an `i64`.
+    ///
+    /// [`get_or_generate()`]: ChunkCache::get_or_generate
+    pub fn try_get_or_generate(&self, chunk_coord: [i64; D]) -> Result<Arc<NoiseBuffer<D>>, Error> {
+        let origin = noisebuf::chunk_origin(chunk_coord, self.chunk_shape)?;
+        {
+            let mut state = self.state.lock().unwrap();
+            state.tick += 1;
+            let tick = state.tick;
+            if let Some((chunk, last_used)) = state.chunks.get_mut(&chunk_coord) {
+                *last_used = tick;
+                let chunk = Arc::clone(chunk);
+                state.hits += 1;
+                return Ok(chunk);
+            }
+            state.misses += 1;
+        }
+        let chunk = Arc::new(self.generate(origin)?);
+        let mut state = self.state.lock().unwrap();
+        if !state.chunks.contains_key(&chunk_coord) && state.chunks.len() >= self.capacity {
+            let least_recently_used = state
+                .chunks
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(&coord, _)| coord);
+            if let Some(coord) = least_recently_used {
+                state.chunks.remove(&coord);
+            }
+        }
+        let tick = state.tick;
+        state.chunks.insert(chunk_coord, (Arc::clone(&chunk), tick));
+        Ok(chunk)
+    }
+
+    /// Removes the chunk with the given `chunk_coord` from the cache, such that it is generated
+    /// anew when requested the next time. Returns whether the chunk was cached.
+    pub fn invalidate(&self, chunk_coord: [i64; D]) -> bool {
+        self.state
+            .lock()
+            .unwrap()
+            .chunks
+            .remove(&chunk_coord)
+            .is_some()
+    }
+
+    /// Removes all chunks from the cache.
+    pub fn clear(&self) {
+        self.state.lock().unwrap().chunks.clear();
+    }
+
+    /// Returns whether the chunk with the given `chunk_coord` is currently cached.
+    pub fn contains(&self, chunk_coord: [i64; D]) -> bool {
+        self.state.lock().unwrap().chunks.contains_key(&chunk_coord)
+    }
+
+    /// Returns the number of currently cached chunks.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().chunks.len()
+    }
+
+    /// Returns whether no chunks are currently cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of requests served from the cache.
+    pub fn hits(&self) -> u64 {
+        self.state.lock().unwrap().hits
+    }
+
+    /// Returns the number of requests which required a chunk to be generated.
+    pub fn misses(&self) -> u64 {
+        self.state.lock().unwrap().misses
+    }
+
+    fn generate(&self, origin: [i64; D]) -> Result<NoiseBuffer<D>, Error> {
+        let mut noisebuf = NoiseBuffer::try_new_empty(self.chunk_shape)?;
+        noisebuf::fill_mapped(
+            &self.generator,
+            self.chunk_shape,
+            noisebuf.buffer.iter_mut(),
+            |index| noisebuf::map_chunk_index(index, origin, 1.0),
+            |value| value,
+        );
+        Ok(noisebuf)
+    }
+}
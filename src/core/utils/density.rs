@@ -0,0 +1,191 @@
+use crate::core::{
+    generator::Generator,
+    utils::noisebuf::{self, NoiseBuffer},
+};
+use alloc::vec::Vec;
+
+/// The classification of a block of a [`DensityVolume`] with respect to a threshold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BlockClass {
+    /// All densities of the block are less than the threshold.
+    Below,
+    /// All densities of the block are greater than or equal to the threshold.
+    Above,
+    /// The block contains densities on both sides of the threshold, and thus the iso-surface.
+    Mixed,
+}
+
+/// A 3-dimensional volume of densities with precomputed bounds per block, for meshing algorithms
+/// such as marching cubes.
+///
+/// The volume stores `f32` densities sampled on a regular grid. The density at a given index is
+/// computed by sampling the generator at `(origin + index) * cell_size` per dimension, where the
+/// global grid index is computed in integer arithmetic before it is scaled. This matches the
+/// convention of [`NoiseBuffer::fill_chunk()`], such that a volume with `origin` set to
+/// `chunk_coord * chunk_shape` contains the same values as the respective chunk, and volumes
+/// sharing a border contain bit-identical densities along it.
+///
+/// The cells between the samples are grouped into blocks of [`BLOCK_SIZE`] cells along each
+/// dimension, and the minimum and maximum density among the samples at the corners of all cells
+/// of a block are precomputed. This allows a mesher to [`classify()`] a block and skip it if it
+/// lies entirely on one side of the iso-surface. Block `[bx, by, bz]` covers the samples
+/// `BLOCK_SIZE * b..=BLOCK_SIZE * (b + 1)` along each dimension, shrunk to the volume at its
+/// upper edges. Thus, neighbouring blocks share the samples on their common face.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, Generator, BlockClass, DensityVolume};
+/// // signed distance to a sphere of radius 10 around the origin
+/// let generator = Source::custom(|[x, y, z]| (x * x + y * y + z * z).sqrt() - 10.0);
+///
+/// // 33 samples per dimension span 32 cells, and thus 4 blocks, per dimension
+/// let volume = DensityVolume::new(&generator, [-16, -16, -16], 1.0, [33, 33, 33]);
+/// assert_eq!(volume.block_shape(), [4, 4, 4]);
+///
+/// assert_eq!(volume.classify([0, 0, 0], 0.0), BlockClass::Above);
+/// assert_eq!(volume.classify([1, 1, 1], 0.0), BlockClass::Mixed);
+/// ```
+///
+/// [`BLOCK_SIZE`]: DensityVolume::BLOCK_SIZE
+/// [`classify()`]: DensityVolume::classify
+#[derive(Clone, Debug)]
+pub struct DensityVolume {
+    origin: [i64; 3],
+    cell_size: f64,
+    densities: NoiseBuffer<3, f32>,
+    block_shape: [usize; 3],
+    block_bounds: Vec<(f32, f32)>,
+}
+
+impl DensityVolume {
+    /// The number of cells along each dimension of a block.
+    pub const BLOCK_SIZE: usize = 8;
+
+    /// Creates a new density volume with the given `shape` in samples, filled with densities
+    /// generated by the given `generator` at the global grid indices starting at `origin`, with a
+    /// spacing of `cell_size` between samples. For further detail see the
+    /// [struct-level documentation](DensityVolume).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total size of the volume overflows.
+    pub fn new<G: Generator<3>>(
+        generator: &G,
+        origin: [i64; 3],
+        cell_size: f64,
+        shape: [usize; 3],
+    ) -> Self {
+        let mut densities = NoiseBuffer::<3, f32>::new_empty(shape);
+        noisebuf::fill_mapped(
+            generator,
+            shape,
+            densities.buffer.iter_mut(),
+            |index| core::array::from_fn(|i| (origin[i] + index[i] as i64) as f64 * cell_size),
+            |value| value as f32,
+        );
+        let block_shape =
+            shape.map(|dim_size| dim_size.saturating_sub(1).div_ceil(Self::BLOCK_SIZE));
+        let block_bounds = noisebuf::tensor_indices(block_shape)
+            .map(|block| compute_block_bounds(&densities, block))
+            .collect();
+        Self {
+            origin,
+            cell_size,
+            densities,
+            block_shape,
+            block_bounds,
+        }
+    }
+
+    /// Returns the global grid index of the first sample of the volume.
+    pub fn origin(&self) -> [i64; 3] {
+        self.origin
+    }
+
+    /// Returns the spacing between samples.
+    pub fn cell_size(&self) -> f64 {
+        self.cell_size
+    }
+
+    /// Returns the number of samples along each dimension.
+    pub fn shape(&self) -> [usize; 3] {
+        self.densities.shape
+    }
+
+    /// Returns the number of blocks along each dimension.
+    pub fn block_shape(&self) -> [usize; 3] {
+        self.block_shape
+    }
+
+    /// Returns the densities of the volume.
+    pub fn densities(&self) -> &NoiseBuffer<3, f32> {
+        &self.densities
+    }
+
+    /// Returns the point at which the density with the given `index` was sampled.
+    pub fn position(&self, index: [usize; 3]) -> [f64; 3] {
+        core::array::from_fn(|i| (self.origin[i] + index[i] as i64) as f64 * self.cell_size)
+    }
+
+    /// Returns the minimum and maximum density among the samples of the given `block`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block` is out of bounds of the block grid.
+    pub fn block_bounds(&self, block: [usize; 3]) -> (f32, f32) {
+        assert!(
+            block
+                .iter()
+                .zip(self.block_shape)
+                .all(|(&b, size)| b < size),
+            "block {:?} out of bounds for block grid of shape {:?}",
+            block,
+            self.block_shape
+        );
+        let [bx, by, bz] = block;
+        let [_, sy, sz] = self.block_shape;
+        self.block_bounds[(bx * sy + by) * sz + bz]
+    }
+
+    /// Classifies the given `block` with respect to the iso-surface at `threshold`.
+    ///
+    /// A block is classified as [`BlockClass::Below`] if all of its densities are less than
+    /// `threshold`, as [`BlockClass::Above`] if all of them are greater than or equal to
+    /// `threshold`, and as [`BlockClass::Mixed`] otherwise. Only mixed blocks contain parts of
+    /// the iso-surface, such that the others can be skipped when meshing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block` is out of bounds of the block grid.
+    pub fn classify(&self, block: [usize; 3], threshold: f32) -> BlockClass {
+        let (min, max) = self.block_bounds(block);
+        if max < threshold {
+            BlockClass::Below
+        } else if min >= threshold {
+            BlockClass::Above
+        } else {
+            BlockClass::Mixed
+        }
+    }
+}
+
+fn compute_block_bounds(densities: &NoiseBuffer<3, f32>, block: [usize; 3]) -> (f32, f32) {
+    let start = block.map(|b| b * DensityVolume::BLOCK_SIZE);
+    let mut end = [0; 3];
+    for i in 0..3 {
+        end[i] = (start[i] + DensityVolume::BLOCK_SIZE + 1).min(densities.shape[i]);
+    }
+    let mut bounds = (f32::INFINITY, f32::NEG_INFINITY);
+    for x in start[0]..end[0] {
+        for y in start[1]..end[1] {
+            for z in start[2]..end[2] {
+                let density = densities[[x, y, z]];
+                bounds = (bounds.0.min(density), bounds.1.max(density));
+            }
+        }
+    }
+    bounds
+}
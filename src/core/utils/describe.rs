@@ -0,0 +1,185 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+/// A structured description of a generator, forming a tree of its sources and adapters.
+///
+/// This is created by [`Generator::describe()`]. Each node names a source or adapter, lists its
+/// parameters, and holds the descriptions of the generators it wraps, each labeled by its role,
+/// such as `source`, `a`, `b`, or `control`. Its [`Display`] implementation renders the tree
+/// across multiple lines, which is useful for inspecting the structure of a composed chain:
+///
+/// ```text
+/// Clamp(min=-1, max=1)
+/// └─ Fbm(octaves=6, frequency=1, lacunarity=2, persistence=0.5)
+///    └─ Simplex(seed=42)
+/// ```
+///
+/// Parameters are stored as they are printed. Closures, as used by [`lambda()`] and
+/// [`custom()`], cannot be inspected and are described by the placeholder `<closure>`.
+///
+/// [`Generator::describe()`]: crate::Generator::describe
+/// [`lambda()`]: crate::Generator::lambda
+/// [`custom()`]: crate::Source::custom
+/// [`Display`]: fmt::Display
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Description {
+    name: &'static str,
+    params: Vec<(&'static str, String)>,
+    children: Vec<(&'static str, Description)>,
+}
+
+impl Description {
+    /// Create a description of a node with the given `name`, without parameters or children.
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            params: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Adds a parameter with the given `name` and `value` to the node.
+    pub fn with_param(mut self, name: &'static str, value: impl fmt::Display) -> Self {
+        self.params.push((name, value.to_string()));
+        self
+    }
+
+    /// Adds a parameter holding an array of values to the node.
+    pub fn with_array_param(mut self, name: &'static str, values: &[f64]) -> Self {
+        let values = values
+            .iter()
+            .map(f64::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.params.push((name, format!("[{}]", values)));
+        self
+    }
+
+    /// Adds the description of a wrapped generator to the node, labeled by its `role`.
+    pub fn with_child(mut self, role: &'static str, child: Description) -> Self {
+        self.children.push((role, child));
+        self
+    }
+
+    /// Returns the name of the node.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the parameters of the node as pairs of name and printed value.
+    pub fn params(&self) -> &[(&'static str, String)] {
+        &self.params
+    }
+
+    /// Returns the printed value of the parameter with the given `name`, if any.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| *param == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the descriptions of the wrapped generators as pairs of role and description.
+    pub fn children(&self) -> &[(&'static str, Description)] {
+        &self.children
+    }
+
+    /// Returns the description of the wrapped generator with the given `role`, if any.
+    pub fn child(&self, role: &str) -> Option<&Description> {
+        self.children
+            .iter()
+            .find(|(child, _)| *child == role)
+            .map(|(_, description)| description)
+    }
+
+    /// Returns the number of nodes in the tree, including this node.
+    pub fn node_count(&self) -> usize {
+        1 + self
+            .children
+            .iter()
+            .map(|(_, child)| child.node_count())
+            .sum::<usize>()
+    }
+
+    /// Returns a single line rendering the node and its parameters, excluding children.
+    pub fn header(&self) -> String {
+        if self.params.is_empty() {
+            return self.name.to_string();
+        }
+        let params = self
+            .params
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("{}({})", self.name, params)
+    }
+
+    fn fmt_children(&self, f: &mut fmt::Formatter<'_>, indent: &str) -> fmt::Result {
+        for (i, (role, child)) in self.children.iter().enumerate() {
+            let last = i + 1 == self.children.len();
+            let (branch, continuation) = if last {
+                ("└─ ", "   ")
+            } else {
+                ("├─ ", "│  ")
+            };
+            write!(f, "\n{}{}", indent, branch)?;
+            // the role of a sole wrapped generator is implied
+            if self.children.len() > 1 {
+                write!(f, "{}: ", role)?;
+            }
+            write!(f, "{}", child.header())?;
+            child.fmt_children(f, &format!("{}{}", indent, continuation))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Description {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.header())?;
+        self.fmt_children(f, "")
+    }
+}
+
+// the unqualified name of a type without generic arguments, used as the name of generators which
+// do not describe themselves
+pub(crate) fn type_name<T>() -> &'static str {
+    let name = core::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+// the description shared by all sources based on a permutation table
+pub(crate) fn lattice_source(
+    name: &'static str,
+    seed: u64,
+    large_coordinates: bool,
+) -> Description {
+    let description = Description::new(name).with_param("seed", seed);
+    if large_coordinates {
+        description.with_param("large_coordinates", true)
+    } else {
+        description
+    }
+}
+
+// implements Display for a generator by rendering its description
+macro_rules! impl_display {
+    ([$($params:tt)*] $generator:ty) => {
+        impl<$($params)*> core::fmt::Display for $generator
+        where
+            $generator: crate::core::generator::Generator<D>,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&crate::core::generator::Generator::describe(self), f)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_display;
@@ -0,0 +1,97 @@
+use crate::core::generator::Generator;
+use rand::{distributions::Distribution, Rng};
+
+/// A probability distribution of the results of a generator at uniformly random points.
+///
+/// Sampling this distribution draws a point uniformly from the half-open region spanning from
+/// `min` (inclusive) to `max` (exclusive) using the supplied random number generator, and
+/// returns the result of the generator at that point. This allows driving pipelines of the
+/// [`rand`](https://docs.rs/rand) crate with noise, such as for probabilistic placement.
+///
+/// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
+/// <strong>Note:</strong>
+/// While the points are uniformly distributed, the resulting values are generally not. Their
+/// distribution is that of the generator results over the region, which, for a source such as
+/// simplex noise, is concentrated around 0 and only rarely approaches the output bounds.
+/// </p>
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{NoiseDistribution, Source};
+/// use rand::{distributions::Distribution, SeedableRng};
+/// use rand_chacha::ChaCha8Rng;
+///
+/// let distribution = NoiseDistribution::new(Source::simplex(42), [0.0, 0.0], [100.0, 100.0]);
+/// let mut rng = ChaCha8Rng::seed_from_u64(7);
+///
+/// let values: Vec<f64> = distribution.sample_iter(&mut rng).take(100).collect();
+/// assert!(values.iter().all(|value| (-1.0..=1.0).contains(value)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NoiseDistribution<const D: usize, G> {
+    generator: G,
+    min: [f64; D],
+    max: [f64; D],
+}
+
+impl<const D: usize, G> NoiseDistribution<D, G>
+where
+    G: Generator<D>,
+{
+    /// Create a new distribution of the results of `generator` at points drawn uniformly from
+    /// the region spanning from `min` to `max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any component of the region bounds is not finite, or if `min` exceeds `max`
+    /// along any axis.
+    #[inline]
+    #[track_caller]
+    pub fn new(generator: G, min: [f64; D], max: [f64; D]) -> Self {
+        assert_region(min, max);
+        Self {
+            generator,
+            min,
+            max,
+        }
+    }
+}
+
+impl<const D: usize, G> Distribution<f64> for NoiseDistribution<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.generator.sample(random_point(rng, self.min, self.max))
+    }
+}
+
+#[track_caller]
+pub(crate) fn assert_region<const D: usize>(min: [f64; D], max: [f64; D]) {
+    assert!(
+        min.iter().chain(&max).all(|x| x.is_finite()),
+        "region bounds must be finite, got {:?} and {:?}",
+        min,
+        max
+    );
+    assert!(
+        (0..D).all(|i| min[i] <= max[i]),
+        "region min {:?} must not exceed region max {:?}",
+        min,
+        max
+    );
+}
+
+// a point drawn uniformly from the half-open region spanning from min to max
+#[inline]
+pub(crate) fn random_point<const D: usize, R: Rng + ?Sized>(
+    rng: &mut R,
+    min: [f64; D],
+    max: [f64; D],
+) -> [f64; D] {
+    core::array::from_fn(|i| min[i] + (max[i] - min[i]) * rng.gen::<f64>())
+}
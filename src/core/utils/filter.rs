@@ -0,0 +1,217 @@
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::utils::noisebuf::{self, NoiseBuffer};
+use alloc::vec::Vec;
+
+/// The handling of the edges of a buffer by filters reading values beyond them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Boundary {
+    /// Use the value at the nearest edge.
+    Clamp,
+    /// Continue at the opposite edge, as appropriate for tileable buffers.
+    Wrap,
+    /// Reflect the buffer at its edges, without repeating the values at the edges.
+    Mirror,
+}
+
+impl Boundary {
+    // maps a possibly out of bounds index along an axis of the given length into bounds
+    pub(crate) fn index(self, index: isize, len: usize) -> usize {
+        let len = len as isize;
+        let index = match self {
+            Self::Clamp => index.clamp(0, len - 1),
+            Self::Wrap => index.rem_euclid(len),
+            Self::Mirror if len == 1 => 0,
+            Self::Mirror => {
+                let period = 2 * (len - 1);
+                let index = index.rem_euclid(period);
+                if index < len {
+                    index
+                } else {
+                    period - index
+                }
+            }
+        };
+        index as usize
+    }
+}
+
+impl<const D: usize> NoiseBuffer<D> {
+    /// Creates a new noise buffer by applying a Gaussian blur with the given standard deviation
+    /// `sigma`, in cells, to this buffer.
+    ///
+    /// The blur is implemented as a separable convolution, which convolves the buffer with a
+    /// 1-dimensional Gaussian kernel along each axis in turn. The kernel is sampled at integer
+    /// offsets up to a radius of `ceil(3 * sigma)`, and normalized such that its weights sum up
+    /// to 1. Values beyond the edges of the buffer are read according to the given `boundary`.
+    /// A `sigma` of 0 leaves the buffer unchanged.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, NoiseBuffer, Boundary};
+    /// let mask = NoiseBuffer::<2>::new([64, 64], &Source::worley(42).scale([0.1; 2]));
+    ///
+    /// // soften the edges of the mask
+    /// let softened = mask.gaussian_blur(2.0, Boundary::Clamp);
+    ///
+    /// assert_eq!(softened.shape, mask.shape);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sigma` is negative or not finite.
+    pub fn gaussian_blur(&self, sigma: f64, boundary: Boundary) -> Self {
+        assert!(
+            sigma >= 0.0 && sigma.is_finite(),
+            "sigma must be non-negative and finite, got {}",
+            sigma
+        );
+        let kernel = gaussian_kernel(sigma);
+        let mut result = self.clone();
+        for axis in 0..D {
+            result = result.convolve_axis(axis, &kernel, boundary);
+        }
+        result
+    }
+
+    // convolves the buffer along the given axis with a kernel of odd length centered on its
+    // middle element
+    fn convolve_axis(&self, axis: usize, kernel: &[f64], boundary: Boundary) -> Self {
+        let mut result = self.clone();
+        let (len, offset) = (self.shape[axis], self.offsets[axis]);
+        let radius = (kernel.len() / 2) as isize;
+        for ((flat_index, index), value) in noisebuf::tensor_indices(self.shape)
+            .enumerate()
+            .zip(result.buffer.iter_mut())
+        {
+            let base = flat_index - index[axis] * offset;
+            *value = kernel
+                .iter()
+                .zip(-radius..=radius)
+                .map(|(weight, shift)| {
+                    let tap = boundary.index(index[axis] as isize + shift, len);
+                    weight * self.buffer[base + tap * offset]
+                })
+                .sum();
+        }
+        result
+    }
+}
+
+fn gaussian_kernel(sigma: f64) -> Vec<f64> {
+    let radius = (3.0 * sigma).ceil() as isize;
+    let mut kernel = (-radius..=radius)
+        .map(|offset| {
+            if sigma == 0.0 {
+                1.0
+            } else {
+                (-((offset * offset) as f64) / (2.0 * sigma * sigma)).exp()
+            }
+        })
+        .collect::<Vec<_>>();
+    let total = kernel.iter().sum::<f64>();
+    for weight in kernel.iter_mut() {
+        *weight /= total;
+    }
+    kernel
+}
+
+impl NoiseBuffer<2> {
+    /// Creates a new noise buffer holding the magnitude of the gradient of this buffer, as
+    /// computed by the Sobel operator.
+    ///
+    /// The Sobel operator estimates the derivative along each axis by convolving the buffer with
+    /// a 3x3 kernel, which differentiates along that axis and smooths along the other. This
+    /// makes the result useful as a mask of edges, cliffs, or ridges. Values beyond the edges of
+    /// the buffer are replicated from the nearest edge, as done by [`Boundary::Clamp`].
+    ///
+    /// If `normalize` is `true`, the magnitudes are divided by their maximum, such that the
+    /// result lies within `[0, 1]`, unless all magnitudes are zero. Otherwise, the raw magnitudes
+    /// are returned. For a buffer with a spacing of one unit between values, these are 8 times
+    /// the magnitude of the gradient, as the weights of each kernel sum up to 4 on either side.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, NoiseBuffer};
+    /// let terrain = NoiseBuffer::<2>::new([64, 64], &Source::simplex(42).scale([0.05; 2]));
+    ///
+    /// let edges = terrain.sobel(true);
+    ///
+    /// assert!(edges.buffer.iter().all(|value| (0.0..=1.0).contains(value)));
+    /// ```
+    pub fn sobel(&self, normalize: bool) -> Self {
+        self.sobel_with_direction(normalize).0
+    }
+
+    /// Creates new noise buffers holding the magnitude and direction of the gradient of this
+    /// buffer, as computed by the Sobel operator.
+    ///
+    /// The magnitude is computed as done by [`sobel()`]. The direction is the angle of the
+    /// gradient in radians in the range `[-pi, pi]`, measured from the first axis of the buffer
+    /// towards the second, pointing towards increasing values. Where the gradient is exactly
+    /// zero, the direction is undefined and set to NaN.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, NoiseBuffer};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// // values increasing along the second axis
+    /// let ramp = NoiseBuffer::<2>::new([16, 16], &Source::custom(|[_, y]| y));
+    ///
+    /// let (magnitude, direction) = ramp.sobel_with_direction(false);
+    ///
+    /// assert_eq!(magnitude[[5, 5]], 8.0);
+    /// assert_eq!(direction[[5, 5]], FRAC_PI_2);
+    /// ```
+    ///
+    /// [`sobel()`]: NoiseBuffer::sobel
+    pub fn sobel_with_direction(&self, normalize: bool) -> (Self, Self) {
+        let [width, height] = self.shape;
+        let mut magnitude = self.clone();
+        let mut direction = self.clone();
+        let value = |x: usize, dx: isize, y: usize, dy: isize| {
+            self[[
+                Boundary::Clamp.index(x as isize + dx, width),
+                Boundary::Clamp.index(y as isize + dy, height),
+            ]]
+        };
+        for (([x, y], magnitude), direction) in noisebuf::tensor_indices(self.shape)
+            .zip(magnitude.buffer.iter_mut())
+            .zip(direction.buffer.iter_mut())
+        {
+            // the values on the given side of the center, weighted by 1, 2, 1 along the
+            // perpendicular axis. summing each side before taking the difference cancels
+            // constant regions exactly
+            let side = |dx: isize, dy: isize| {
+                let (px, py) = (dy.abs(), dx.abs());
+                value(x, dx - px, y, dy - py)
+                    + 2.0 * value(x, dx, y, dy)
+                    + value(x, dx + px, y, dy + py)
+            };
+            let gx = side(1, 0) - side(-1, 0);
+            let gy = side(0, 1) - side(0, -1);
+            *magnitude = (gx * gx + gy * gy).sqrt();
+            *direction = if gx == 0.0 && gy == 0.0 {
+                f64::NAN
+            } else {
+                gy.atan2(gx)
+            };
+        }
+        if normalize {
+            let max = magnitude.buffer.iter().copied().fold(0.0, f64::max);
+            if max > 0.0 {
+                magnitude.buffer.iter_mut().for_each(|value| *value /= max);
+            }
+        }
+        (magnitude, direction)
+    }
+}
@@ -0,0 +1,220 @@
+use crate::core::generator::Generator;
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::utils::noisebuf::tensor_indices;
+use alloc::vec::Vec;
+use core::fmt;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A report of the samples at which two generators differ on a fingerprinting grid.
+///
+/// This is created by [`Generator::fingerprint_diff()`], and samples the same grid as
+/// [`Generator::fingerprint()`]. Its [`Display`] implementation pretty-prints the grid index,
+/// the input point, and both results for every mismatching sample, which makes it useful for
+/// tracking down which part of a generator changed after its fingerprint did.
+///
+/// [`Display`]: fmt::Display
+#[derive(Clone, Debug, PartialEq)]
+pub struct FingerprintDiff<const D: usize> {
+    /// The number of samples in the grid.
+    pub samples: usize,
+    /// The mismatching samples, ordered as they appear in the grid.
+    pub mismatches: Vec<SampleMismatch<D>>,
+}
+
+/// A single sample at which two generators produce results with different bit patterns.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SampleMismatch<const D: usize> {
+    /// The index of the sample along each axis of the grid.
+    pub index: [usize; D],
+    /// The input point of the sample.
+    pub point: [f64; D],
+    /// The result of the generator on which the diff was computed.
+    pub left: f64,
+    /// The result of the generator it was compared with.
+    pub right: f64,
+}
+
+impl<const D: usize> FingerprintDiff<D> {
+    /// Returns `true` if both generators produced bit-identical results for every sample.
+    pub fn is_empty(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+impl<const D: usize> fmt::Display for FingerprintDiff<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} of {} samples differ",
+            self.mismatches.len(),
+            self.samples
+        )?;
+        for mismatch in &self.mismatches {
+            writeln!(
+                f,
+                "  {:?} at {:?}: {:?} != {:?}",
+                mismatch.index, mismatch.point, mismatch.left, mismatch.right
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Statistics of the differences between the results of two generators on a regular grid.
+///
+/// This is created by [`Generator::compare()`], and samples the same grid as
+/// [`Generator::fingerprint()`]. Where [`FingerprintDiff`] reports which samples differ at all,
+/// this quantifies by how much, which is useful for judging whether a refactored chain or an
+/// upgrade of this crate changed the results noticeably.
+///
+/// The difference of two results is their absolute difference, except that two NaNs, as well
+/// as two infinities of the same sign, are considered equal, and the difference between NaN and
+/// any other value is infinite.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Comparison<const D: usize> {
+    /// The number of samples in the grid.
+    pub samples: usize,
+    /// The largest difference of any sample.
+    pub max_abs_diff: f64,
+    /// The mean of the differences of all samples.
+    pub mean_abs_diff: f64,
+    /// The root of the mean of the squared differences of all samples.
+    pub rmse: f64,
+    /// The input point of the first sample with the largest difference.
+    pub worst_point: [f64; D],
+}
+
+impl<const D: usize> fmt::Display for Comparison<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "max |diff| {} at {:?}, mean |diff| {}, rmse {} over {} samples",
+            self.max_abs_diff, self.worst_point, self.mean_abs_diff, self.rmse, self.samples
+        )
+    }
+}
+
+pub(crate) fn fingerprint<const D: usize, G: Generator<D>>(
+    generator: &G,
+    region_min: [f64; D],
+    region_max: [f64; D],
+    samples_per_axis: usize,
+) -> u64 {
+    grid_points(region_min, region_max, samples_per_axis)
+        .map(|(_, point)| sample_bits(generator, point))
+        .flat_map(u64::to_le_bytes)
+        .fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+        })
+}
+
+pub(crate) fn fingerprint_diff<const D: usize, GA, GB>(
+    generator_a: &GA,
+    generator_b: &GB,
+    region_min: [f64; D],
+    region_max: [f64; D],
+    samples_per_axis: usize,
+) -> FingerprintDiff<D>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    let mismatches = grid_points(region_min, region_max, samples_per_axis)
+        .filter(|&(_, point)| sample_bits(generator_a, point) != sample_bits(generator_b, point))
+        .map(|(index, point)| SampleMismatch {
+            index,
+            point,
+            left: generator_a.sample(point),
+            right: generator_b.sample(point),
+        })
+        .collect();
+    FingerprintDiff {
+        samples: samples_per_axis.pow(D as u32),
+        mismatches,
+    }
+}
+
+#[track_caller]
+pub(crate) fn compare<const D: usize, GA, GB>(
+    generator_a: &GA,
+    generator_b: &GB,
+    region_min: [f64; D],
+    region_max: [f64; D],
+    samples_per_axis: usize,
+) -> Comparison<D>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    assert!(
+        samples_per_axis > 0,
+        "samples_per_axis must be at least 1, got 0"
+    );
+    let mut comparison = Comparison {
+        samples: 0,
+        max_abs_diff: 0.0,
+        mean_abs_diff: 0.0,
+        rmse: 0.0,
+        worst_point: region_min,
+    };
+    let mut sum_squares = 0.0;
+    for (_, point) in grid_points(region_min, region_max, samples_per_axis) {
+        let diff = abs_diff(generator_a.sample(point), generator_b.sample(point));
+        if diff > comparison.max_abs_diff {
+            comparison.max_abs_diff = diff;
+            comparison.worst_point = point;
+        }
+        comparison.mean_abs_diff += diff;
+        sum_squares += diff * diff;
+        comparison.samples += 1;
+    }
+    comparison.mean_abs_diff /= comparison.samples as f64;
+    comparison.rmse = (sum_squares / comparison.samples as f64).sqrt();
+    comparison
+}
+
+// the absolute difference, considering equal values, including infinities and NaNs, to not
+// differ at all, and NaNs to differ infinitely from anything else
+#[inline]
+pub(crate) fn abs_diff(a: f64, b: f64) -> f64 {
+    if a == b || (a.is_nan() && b.is_nan()) {
+        0.0
+    } else if a.is_nan() || b.is_nan() {
+        f64::INFINITY
+    } else {
+        (a - b).abs()
+    }
+}
+
+pub(crate) fn grid_points<const D: usize>(
+    region_min: [f64; D],
+    region_max: [f64; D],
+    samples_per_axis: usize,
+) -> impl Iterator<Item = ([usize; D], [f64; D])> {
+    tensor_indices([samples_per_axis; D]).map(move |index| {
+        let point = core::array::from_fn(|i| {
+            if samples_per_axis == 1 {
+                region_min[i]
+            } else {
+                region_min[i]
+                    + (region_max[i] - region_min[i]) * index[i] as f64
+                        / (samples_per_axis - 1) as f64
+            }
+        });
+        (index, point)
+    })
+}
+
+// NaN payloads are not preserved consistently across platforms, so all NaNs hash identically
+#[inline]
+fn sample_bits<const D: usize, G: Generator<D>>(generator: &G, point: [f64; D]) -> u64 {
+    let value = generator.sample(point);
+    if value.is_nan() {
+        f64::NAN.to_bits()
+    } else {
+        value.to_bits()
+    }
+}
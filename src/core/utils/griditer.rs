@@ -0,0 +1,112 @@
+use crate::core::{generator::Generator, utils::noisebuf};
+use core::iter::FusedIterator;
+
+/// An iterator sampling a generator on the points of a hypergrid without allocating a buffer.
+///
+/// This struct is created by [`grid_iter()`]. For each point of a hypergrid with the given shape,
+/// it yields the index of the point, its coordinates, and the result of the generator at these
+/// coordinates. The points are yielded in row-major order, that is, in the order of the values
+/// of a [`NoiseBuffer`] of the same shape. The coordinates of the point at a given index are
+/// `origin + index * step` per dimension, matching [`NoiseBuffer::new_mapped()`], such that the
+/// yielded values are identical to those of a buffer created with the same arguments.
+///
+/// The iterator samples the generator lazily, and can be iterated from either end.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, Generator, NoiseBuffer};
+/// let generator = Source::simplex(42);
+/// let buf = NoiseBuffer::<2>::new_mapped([30, 20], [-5.0, 2.0], [0.5, 0.25], &generator);
+///
+/// for (index, point, value) in generator.grid_iter([-5.0, 2.0], [0.5, 0.25], [30, 20]) {
+///     assert_eq!(point, [-5.0 + index[0] as f64 * 0.5, 2.0 + index[1] as f64 * 0.25]);
+///     assert_eq!(value, buf[index]);
+/// }
+/// ```
+///
+/// [`grid_iter()`]: Generator::grid_iter
+/// [`NoiseBuffer`]: crate::NoiseBuffer
+/// [`NoiseBuffer::new_mapped()`]: crate::NoiseBuffer::new_mapped
+#[derive(Clone, Debug)]
+pub struct GridIter<'a, const D: usize, G> {
+    generator: &'a G,
+    origin: [f64; D],
+    step: [f64; D],
+    shape: [usize; D],
+    front: usize,
+    back: usize,
+}
+
+impl<'a, const D: usize, G: Generator<D>> GridIter<'a, D, G> {
+    /// Creates a new iterator sampling the given `generator` on a hypergrid with the given
+    /// `origin`, `step`, and `shape`. For further detail see the
+    /// [struct-level documentation](GridIter).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of points of the hypergrid overflows.
+    pub fn new(generator: &'a G, origin: [f64; D], step: [f64; D], shape: [usize; D]) -> Self {
+        let len = shape
+            .iter()
+            .try_fold(1_usize, |acc, &dim_size| acc.checked_mul(dim_size))
+            .unwrap_or_else(|| panic!("number of grid points overflows for shape {:?}", shape));
+        Self {
+            generator,
+            origin,
+            step,
+            shape,
+            front: 0,
+            back: len,
+        }
+    }
+
+    fn item(&self, flat_index: usize) -> ([usize; D], [f64; D], f64) {
+        let index = noisebuf::tensor_index(self.shape, flat_index);
+        let point = noisebuf::map_index(index, self.origin, self.step);
+        (index, point, self.generator.sample(point))
+    }
+}
+
+impl<const D: usize, G: Generator<D>> Iterator for GridIter<'_, D, G> {
+    type Item = ([usize; D], [f64; D], f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.item(self.front - 1))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<const D: usize, G: Generator<D>> DoubleEndedIterator for GridIter<'_, D, G> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.item(self.back))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.back = self.back.saturating_sub(n).max(self.front);
+        self.next_back()
+    }
+}
+
+impl<const D: usize, G: Generator<D>> ExactSizeIterator for GridIter<'_, D, G> {}
+
+impl<const D: usize, G: Generator<D>> FusedIterator for GridIter<'_, D, G> {}
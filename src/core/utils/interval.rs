@@ -0,0 +1,187 @@
+// interval arithmetic used for computing conservative output bounds of generators. bounds
+// containing NaN are replaced by the unbounded interval, and results of functions implemented
+// by libm, which are not guaranteed to be correctly rounded or monotonic, are slightly widened.
+
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+
+pub(crate) type Interval = (f64, f64);
+
+pub(crate) const UNBOUNDED: Interval = (f64::NEG_INFINITY, f64::INFINITY);
+
+#[inline]
+pub(crate) fn hull_of<I: IntoIterator<Item = f64>>(values: I) -> Interval {
+    let mut hull = (f64::INFINITY, f64::NEG_INFINITY);
+    for value in values {
+        if value.is_nan() {
+            return UNBOUNDED;
+        }
+        hull = (hull.0.min(value), hull.1.max(value));
+    }
+    hull
+}
+
+#[inline]
+pub(crate) fn hull(a: Interval, b: Interval) -> Interval {
+    hull_of([a.0, a.1, b.0, b.1])
+}
+
+#[inline]
+pub(crate) fn add(a: Interval, b: Interval) -> Interval {
+    hull_of([a.0 + b.0, a.1 + b.1])
+}
+
+#[inline]
+pub(crate) fn offset(a: Interval, offset: f64) -> Interval {
+    hull_of([a.0 + offset, a.1 + offset])
+}
+
+#[inline]
+pub(crate) fn sub(a: Interval, b: Interval) -> Interval {
+    hull_of([a.0 - b.1, a.1 - b.0])
+}
+
+#[inline]
+pub(crate) fn mul(a: Interval, b: Interval) -> Interval {
+    hull_of([a.0 * b.0, a.0 * b.1, a.1 * b.0, a.1 * b.1])
+}
+
+#[inline]
+pub(crate) fn scale(a: Interval, factor: f64) -> Interval {
+    hull_of([a.0 * factor, a.1 * factor])
+}
+
+#[inline]
+pub(crate) fn neg(a: Interval) -> Interval {
+    (-a.1, -a.0)
+}
+
+#[inline]
+pub(crate) fn abs(a: Interval) -> Interval {
+    if a.0 >= 0.0 {
+        a
+    } else if a.1 <= 0.0 {
+        neg(a)
+    } else {
+        (0.0, a.1.max(-a.0))
+    }
+}
+
+#[inline]
+pub(crate) fn min(a: Interval, b: Interval) -> Interval {
+    (a.0.min(b.0), a.1.min(b.1))
+}
+
+#[inline]
+pub(crate) fn max(a: Interval, b: Interval) -> Interval {
+    (a.0.max(b.0), a.1.max(b.1))
+}
+
+#[inline]
+pub(crate) fn clamp(a: Interval, min: f64, max: f64) -> Interval {
+    (a.0.clamp(min, max), a.1.clamp(min, max))
+}
+
+#[inline]
+pub(crate) fn exp(a: Interval) -> Interval {
+    widen((a.0.exp(), a.1.exp()))
+}
+
+pub(crate) fn powi(a: Interval, exponent: i32) -> Interval {
+    let bounds = if exponent == 0 {
+        return (1.0, 1.0);
+    } else if exponent < 0 && a.0 <= 0.0 && 0.0 <= a.1 {
+        return UNBOUNDED;
+    } else if exponent % 2 == 0 && a.0 < 0.0 && 0.0 < a.1 {
+        (0.0, a.0.powi(exponent).max(a.1.powi(exponent)))
+    } else {
+        // monotonic on intervals not containing a pole or extremum
+        hull_of([a.0.powi(exponent), a.1.powi(exponent)])
+    };
+    widen(bounds)
+}
+
+pub(crate) fn powf(a: Interval, exponent: f64) -> Interval {
+    if exponent == 0.0 {
+        (1.0, 1.0)
+    } else if a.0 >= 0.0 {
+        widen(hull_of([a.0.powf(exponent), a.1.powf(exponent)]))
+    } else if exponent.fract() == 0.0 && exponent.abs() <= i32::MAX as f64 {
+        powi(a, exponent as i32)
+    } else if a.1 >= 0.0 {
+        // negative bases produce NaN for fractional exponents
+        powf((0.0, a.1), exponent)
+    } else {
+        UNBOUNDED
+    }
+}
+
+pub(crate) fn pow(base: Interval, exponent: Interval) -> Interval {
+    if exponent.0 == exponent.1 {
+        powf(base, exponent.0)
+    } else if base.0 > 0.0 {
+        // x^y = exp(y * ln(x)) is monotonic in both arguments for fixed signs of y and ln(x),
+        // so the extrema are attained at the corners
+        widen(hull_of([
+            base.0.powf(exponent.0),
+            base.0.powf(exponent.1),
+            base.1.powf(exponent.0),
+            base.1.powf(exponent.1),
+        ]))
+    } else {
+        UNBOUNDED
+    }
+}
+
+#[inline]
+pub(crate) fn widen(a: Interval) -> Interval {
+    const RELATIVE: f64 = 1e-12;
+    (
+        a.0 - a.0.abs() * RELATIVE - f64::MIN_POSITIVE,
+        a.1 + a.1.abs() * RELATIVE + f64::MIN_POSITIVE,
+    )
+}
+
+#[inline]
+pub(crate) fn scale_box<const D: usize>(
+    min: [f64; D],
+    max: [f64; D],
+    factors: [f64; D],
+) -> ([f64; D], [f64; D]) {
+    let bounds: [Interval; D] = core::array::from_fn(|i| scale((min[i], max[i]), factors[i]));
+    (bounds.map(|b| b.0), bounds.map(|b| b.1))
+}
+
+// computes a box containing the images of all points within a box under a linear
+// transformation, which is the hull of the images of its corners. the result is widened
+// relative to the magnitude of the coordinates to account for rounding errors, and axes
+// for which the images of the corners are not defined are unbounded.
+pub(crate) fn transform_box<const D: usize, F>(
+    min: [f64; D],
+    max: [f64; D],
+    transform: F,
+) -> ([f64; D], [f64; D])
+where
+    F: Fn([f64; D]) -> [f64; D],
+{
+    const RELATIVE: f64 = 1e-12;
+    let mut lower = [f64::INFINITY; D];
+    let mut upper = [f64::NEG_INFINITY; D];
+    let mut magnitude = min
+        .iter()
+        .chain(&max)
+        .fold(0.0_f64, |acc, x| acc.max(x.abs()));
+    for corner in 0..1_usize << D {
+        let point = core::array::from_fn(|i| if corner >> i & 1 == 0 { min[i] } else { max[i] });
+        for (i, x) in transform(point).into_iter().enumerate() {
+            if x.is_nan() {
+                (lower[i], upper[i]) = UNBOUNDED;
+            } else {
+                (lower[i], upper[i]) = (lower[i].min(x), upper[i].max(x));
+            }
+            magnitude = magnitude.max(x.abs());
+        }
+    }
+    let margin = magnitude * RELATIVE + f64::MIN_POSITIVE;
+    (lower.map(|x| x - margin), upper.map(|x| x + margin))
+}
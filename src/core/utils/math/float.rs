@@ -0,0 +1,114 @@
+// floating point methods which std provides but core lacks, implemented using libm. they share
+// the names and signatures of the std methods, such that call sites are the same with and
+// without the std feature, and are only imported when building without it.
+pub(crate) trait FloatExt {
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn fract(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn sqrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn exp(self) -> Self;
+    fn log2(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+}
+
+impl FloatExt for f64 {
+    #[inline]
+    fn floor(self) -> f64 {
+        libm::floor(self)
+    }
+
+    #[inline]
+    fn ceil(self) -> f64 {
+        libm::ceil(self)
+    }
+
+    #[inline]
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+
+    #[inline]
+    fn fract(self) -> f64 {
+        self - libm::trunc(self)
+    }
+
+    #[inline]
+    fn rem_euclid(self, rhs: f64) -> f64 {
+        let r = self % rhs;
+        if r < 0.0 {
+            r + rhs.abs()
+        } else {
+            r
+        }
+    }
+
+    #[inline]
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+
+    // exponentiation by squaring, as done by the compiler intrinsic backing the std method
+    #[inline]
+    fn powi(self, n: i32) -> f64 {
+        let mut base = self;
+        let mut exponent = n.unsigned_abs();
+        let mut result = 1.0;
+        loop {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            exponent >>= 1;
+            if exponent == 0 {
+                break;
+            }
+            base *= base;
+        }
+        if n < 0 {
+            1.0 / result
+        } else {
+            result
+        }
+    }
+
+    #[inline]
+    fn powf(self, n: f64) -> f64 {
+        libm::pow(self, n)
+    }
+
+    #[inline]
+    fn exp(self) -> f64 {
+        libm::exp(self)
+    }
+
+    #[inline]
+    fn log2(self) -> f64 {
+        libm::log2(self)
+    }
+
+    #[inline]
+    fn sin(self) -> f64 {
+        libm::sin(self)
+    }
+
+    #[inline]
+    fn cos(self) -> f64 {
+        libm::cos(self)
+    }
+
+    #[inline]
+    fn tan(self) -> f64 {
+        libm::tan(self)
+    }
+
+    #[inline]
+    fn atan2(self, other: f64) -> f64 {
+        libm::atan2(self, other)
+    }
+}
@@ -1,2 +1,4 @@
+#[cfg(not(feature = "std"))]
+pub(crate) mod float;
 mod vector;
 pub(crate) use vector::{Vec2, Vec3, Vec4};
@@ -1,5 +1,5 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 use num_traits::{identities::Zero, Euclid, Float, Pow};
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 pub(crate) trait NumCast<T>: Sized {
     fn from(n: T) -> Self;
@@ -0,0 +1,259 @@
+use crate::core::{
+    error::Error,
+    generator::Generator,
+    utils::noisebuf::{self, NoiseBuffer, NoiseElement},
+};
+use memmap2::MmapMut;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::{
+    fs::{File, OpenOptions},
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+/// A noise buffer backed by a memory-mapped file.
+///
+/// This struct is the file-backed analogue of [`NoiseBuffer`], allowing buffers which do not fit
+/// in memory to be generated and accessed. The values are stored in the file in the same layout as
+/// in the underlying flat vector of a [`NoiseBuffer`] with the same shape, using the native byte
+/// order, and indexing reads and writes through the map. Changes are written back to the file by
+/// the operating system, but are only guaranteed to be persisted after calling [`flush()`].
+///
+/// A memory-mapped buffer is created and filled using [`NoiseBuffer::new_mmapped()`], and an
+/// existing file can be mapped again without refilling it using
+/// [`NoiseBuffer::open_mmapped()`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, Generator, NoiseBuffer};
+/// # use tempdir::TempDir;
+/// let generator = Source::simplex(42);
+///
+/// let path = "noise.bin";
+/// # let tmp_dir = TempDir::new("libnoise").unwrap();
+/// # let path = &tmp_dir.path().join(path).into_os_string().into_string().unwrap();
+/// let buf = NoiseBuffer::<2, f32>::new_mmapped(path, [300, 200], &generator).unwrap();
+/// buf.flush().unwrap();
+/// drop(buf);
+///
+/// let buf = NoiseBuffer::<2, f32>::open_mmapped(path, [300, 200]).unwrap();
+/// assert_eq!(buf[[170, 90]], generator.sample([170.0, 90.0]) as f32);
+/// ```
+///
+/// [`flush()`]: MmapNoiseBuffer::flush
+#[derive(Debug)]
+pub struct MmapNoiseBuffer<const D: usize, T = f64> {
+    /// Stores the length of the n-dimensional array along each dimension.
+    pub shape: [usize; D],
+    /// Stores offsets which are used to convert n-dimensional coordinates to flat vector indices.
+    pub offsets: [usize; D],
+    mmap: MmapMut,
+    len: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<const D: usize, T: NoiseElement> NoiseBuffer<D, T> {
+    /// Creates a new memory-mapped noise buffer with the given `shape`, stored in the file at the
+    /// given `path`, and filled with noise generated by the given `generator`. For further detail
+    /// see [`MmapNoiseBuffer`].
+    ///
+    /// The file is created if it does not exist, and truncated or extended to the size of the
+    /// buffer otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the file cannot be created, resized, or mapped, e.g. due to
+    /// missing permissions or insufficient disk space, and [`Error::InvalidShape`] if the size of
+    /// the buffer overflows.
+    pub fn new_mmapped<G: Generator<D>>(
+        path: &str,
+        shape: [usize; D],
+        generator: &G,
+    ) -> Result<MmapNoiseBuffer<D, T>, Error> {
+        let mut noisebuf = MmapNoiseBuffer::create(path, shape)?;
+        noisebuf.refill(generator);
+        Ok(noisebuf)
+    }
+
+    /// Creates a new memory-mapped noise buffer like [`new_mmapped()`], except that it is filled
+    /// in parallel.
+    ///
+    /// [`new_mmapped()`]: NoiseBuffer::new_mmapped
+    #[cfg(feature = "rayon")]
+    pub fn par_new_mmapped<G: Generator<D> + Sync>(
+        path: &str,
+        shape: [usize; D],
+        generator: &G,
+    ) -> Result<MmapNoiseBuffer<D, T>, Error>
+    where
+        T: Send,
+    {
+        let mut noisebuf = MmapNoiseBuffer::create(path, shape)?;
+        noisebuf.par_refill(generator);
+        Ok(noisebuf)
+    }
+
+    /// Maps an existing file at the given `path`, previously filled using [`new_mmapped()`], as a
+    /// noise buffer with the given `shape` without refilling it.
+    ///
+    /// [`new_mmapped()`]: NoiseBuffer::new_mmapped
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the file cannot be opened or mapped, [`Error::InvalidShape`] if
+    /// the size of the buffer overflows, and [`Error::ShapeMismatch`] with the expected and
+    /// actual size of the file in bytes if its size does not match the given `shape`.
+    pub fn open_mmapped(path: &str, shape: [usize; D]) -> Result<MmapNoiseBuffer<D, T>, Error> {
+        let (len, bytes) = mmap_size::<D, T>(shape)?;
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        let file_len = file.metadata()?.len();
+        if file_len != bytes as u64 {
+            return Err(Error::ShapeMismatch {
+                expected: vec![bytes],
+                found: vec![file_len as usize],
+            });
+        }
+        MmapNoiseBuffer::map(&file, shape, len)
+    }
+}
+
+impl<const D: usize, T: NoiseElement> Index<[usize; D]> for MmapNoiseBuffer<D, T> {
+    type Output = T;
+    fn index(&self, index: [usize; D]) -> &Self::Output {
+        &self.as_slice()[self.flat_index(index)]
+    }
+}
+
+impl<const D: usize, T: NoiseElement> IndexMut<[usize; D]> for MmapNoiseBuffer<D, T> {
+    fn index_mut(&mut self, index: [usize; D]) -> &mut Self::Output {
+        let idx = self.flat_index(index);
+        &mut self.as_mut_slice()[idx]
+    }
+}
+
+impl<const D: usize, T: NoiseElement> MmapNoiseBuffer<D, T> {
+    /// Refills the buffer with noise generated by the given `generator`, analogous to
+    /// [`NoiseBuffer::refill()`].
+    pub fn refill<G: Generator<D>>(&mut self, generator: &G) {
+        let shape = self.shape;
+        noisebuf::fill_mapped(
+            generator,
+            shape,
+            self.as_mut_slice().iter_mut(),
+            |index| index.map(|x| x as f64),
+            T::from_f64,
+        );
+    }
+
+    /// Refills the buffer with noise generated by the given `generator` in parallel. The result
+    /// is identical to that of [`refill()`].
+    ///
+    /// [`refill()`]: MmapNoiseBuffer::refill
+    #[cfg(feature = "rayon")]
+    pub fn par_refill<G: Generator<D> + Sync>(&mut self, generator: &G)
+    where
+        T: Send,
+    {
+        let shape = self.shape;
+        self.as_mut_slice()
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(flat_index, value)| {
+                let index = noisebuf::tensor_index(shape, flat_index);
+                *value = T::from_f64(generator.sample(index.map(|x| x as f64)));
+            });
+    }
+
+    /// Writes all changes of the buffer back to the underlying file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the changes cannot be written to the file.
+    pub fn flush(&self) -> Result<(), Error> {
+        self.mmap.flush()?;
+        Ok(())
+    }
+
+    /// Returns the values of the buffer as a slice, in the layout of the underlying flat vector
+    /// of a [`NoiseBuffer`].
+    pub fn as_slice(&self) -> &[T] {
+        if self.len == 0 {
+            return &[];
+        }
+        // SAFETY: the map is page-aligned and holds exactly `len` values of `T`. The sealed
+        // `NoiseElement` trait limits `T` to `f32`, `f64`, and `half::f16`, the latter being a
+        // transparent wrapper around `u16`. All of them are valid for any bit pattern, and their
+        // alignment of at most 8 bytes is satisfied by the page alignment.
+        unsafe { std::slice::from_raw_parts(self.mmap.as_ptr() as *const T, self.len) }
+    }
+
+    /// Returns the values of the buffer as a mutable slice, in the layout of the underlying flat
+    /// vector of a [`NoiseBuffer`].
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        if self.len == 0 {
+            return &mut [];
+        }
+        // SAFETY: see `as_slice()`, and the map is uniquely borrowed.
+        unsafe { std::slice::from_raw_parts_mut(self.mmap.as_mut_ptr() as *mut T, self.len) }
+    }
+
+    /// Copies the values of the buffer into a new, in-memory [`NoiseBuffer`].
+    pub fn to_buffer(&self) -> NoiseBuffer<D, T> {
+        let mut noisebuf = NoiseBuffer::new_empty(self.shape);
+        noisebuf.buffer.copy_from_slice(self.as_slice());
+        noisebuf
+    }
+
+    fn create(path: &str, shape: [usize; D]) -> Result<Self, Error> {
+        let (len, bytes) = mmap_size::<D, T>(shape)?;
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        file.set_len(bytes as u64)?;
+        Self::map(&file, shape, len)
+    }
+
+    fn map(file: &File, shape: [usize; D], len: usize) -> Result<Self, Error> {
+        // SAFETY: the file is sized to hold exactly `len` values. Concurrent modification of the
+        // file by other processes is not guarded against, as is inherent to memory maps.
+        let mmap = unsafe { MmapMut::map_mut(file)? };
+        Ok(Self {
+            shape,
+            offsets: noisebuf::precompute_flat_index_offsets(&shape)
+                .try_into()
+                .unwrap(),
+            mmap,
+            len,
+            phantom: PhantomData,
+        })
+    }
+
+    fn flat_index(&self, index: [usize; D]) -> usize {
+        for (axis, (&idx, &dim_size)) in index.iter().zip(&self.shape).enumerate() {
+            assert!(
+                idx < dim_size,
+                "index {} out of bounds for axis {} of buffer with shape {:?}",
+                idx,
+                axis,
+                self.shape
+            );
+        }
+        index
+            .iter()
+            .zip(&self.offsets)
+            .map(|(idx, offset)| idx * offset)
+            .sum()
+    }
+}
+
+fn mmap_size<const D: usize, T>(shape: [usize; D]) -> Result<(usize, usize), Error> {
+    let len = noisebuf::checked_size(shape)?;
+    Ok((len, len * std::mem::size_of::<T>()))
+}
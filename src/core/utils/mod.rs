@@ -1,5 +1,42 @@
+#[cfg(feature = "ndarray")]
+mod arrayconv;
+#[cfg(feature = "std")]
+pub mod chunkcache;
+pub mod density;
+pub mod describe;
+pub mod distribution;
+pub mod filter;
+pub mod fingerprint;
+pub mod griditer;
+pub(super) mod interval;
 pub(super) mod math;
+#[cfg(feature = "mmap")]
+#[allow(unsafe_code)]
+pub mod mmapnoisebuf;
+pub mod multinoisebuf;
+#[cfg(feature = "noise-compat")]
+pub mod noise_compat;
 pub mod noisebuf;
+pub mod noisebufview;
+pub mod noisechunks;
+#[cfg(feature = "std")]
+mod npy;
+#[cfg(feature = "std")]
+mod obj;
+#[cfg(feature = "exr")]
+mod openexr;
+mod ops;
+pub mod param;
+pub(crate) mod path;
 pub(super) mod ptable;
+pub(crate) mod quantize;
+pub mod resample;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_array;
+#[cfg(feature = "analysis")]
+pub mod spectrum;
+pub(crate) mod sphere;
+pub mod stats;
+mod terrain;
 #[cfg(feature = "image")]
 pub mod visualizer;
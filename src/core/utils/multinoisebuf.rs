@@ -0,0 +1,215 @@
+#[cfg(feature = "image")]
+use crate::core::utils::{quantize::quantize_u8, visualizer};
+use crate::core::{
+    error::Error,
+    generator::Generator,
+    utils::noisebuf::{self, NoiseBuffer},
+};
+use alloc::vec::Vec;
+use core::ops::{Index, IndexMut};
+#[cfg(feature = "image")]
+use image::{RgbImage, RgbaImage};
+
+/// A trait for groups of generators which are sampled together at the same points.
+///
+/// This trait is implemented for arrays of generators of the same type, as well as tuples of up to
+/// four generators of arbitrary types. Sampling a group of `C` generators at a given point yields
+/// an array with the value of each generator at that point, in order.
+pub trait GeneratorChannels<const D: usize, const C: usize> {
+    /// Samples every generator of the group at the given `point` and returns the resulting
+    /// values, in order.
+    fn sample_channels(&self, point: [f64; D]) -> [f64; C];
+}
+
+impl<const D: usize, const C: usize, G: Generator<D>> GeneratorChannels<D, C> for [G; C] {
+    #[inline]
+    fn sample_channels(&self, point: [f64; D]) -> [f64; C] {
+        core::array::from_fn(|channel| self[channel].sample(point))
+    }
+}
+
+macro_rules! impl_generator_channels {
+    ($channels:literal, $($generator:ident => $idx:tt),+) => {
+        impl<const D: usize, $($generator: Generator<D>),+> GeneratorChannels<D, $channels>
+            for ($($generator,)+)
+        {
+            #[inline]
+            fn sample_channels(&self, point: [f64; D]) -> [f64; $channels] {
+                [$(self.$idx.sample(point)),+]
+            }
+        }
+    };
+}
+
+impl_generator_channels!(1, G0 => 0);
+impl_generator_channels!(2, G0 => 0, G1 => 1);
+impl_generator_channels!(3, G0 => 0, G1 => 1, G2 => 2);
+impl_generator_channels!(4, G0 => 0, G1 => 1, G2 => 2, G3 => 3);
+
+/// A struct for generating an n-dimensional array of multiple channels, each filled with noise
+/// values from a different generator.
+///
+/// This struct is the multi-channel analogue of [`NoiseBuffer`]. Each cell of the n-dimensional
+/// array stores an array of `C` values, one per channel, which are interleaved in the underlying
+/// flat vector. When creating a new [`MultiNoiseBuffer`] using the [`new()`] method, a group of
+/// `C` generators, that is, an object implementing [`GeneratorChannels`], must be provided. The
+/// sampling point of every cell is computed once and shared across all channels.
+///
+/// The values of a single channel can be extracted as a [`NoiseBuffer`] using [`channel()`]. The
+/// values of each channel are identical to those of a [`NoiseBuffer`] filled with the respective
+/// generator.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, Generator, NoiseBuffer, MultiNoiseBuffer};
+/// // create generators of different types for each channel
+/// let red = Source::simplex(42).fbm(3, 0.013, 2.0, 0.5);
+/// let green = Source::worley(43).scale([0.02; 2]);
+/// let blue = Source::perlin(44).scale([0.05; 2]);
+///
+/// // create a new multi-channel noise buffer
+/// let buf = MultiNoiseBuffer::<2, 3>::new([30, 20], &(red, green.clone(), blue.clone()));
+///
+/// assert_eq!(buf[[17, 9]][1], green.sample([17.0, 9.0]));
+/// assert_eq!(buf.channel(2).buffer, NoiseBuffer::<2>::new([30, 20], &blue).buffer);
+/// ```
+///
+/// [`new()`]: MultiNoiseBuffer::new
+/// [`channel()`]: MultiNoiseBuffer::channel
+#[derive(Clone, Debug)]
+pub struct MultiNoiseBuffer<const D: usize, const C: usize> {
+    /// Stores the length of the n-dimensional array along each dimension.
+    pub shape: [usize; D],
+    /// Stores offsets which are used to convert n-dimensional coordinates to flat vector indices.
+    pub offsets: [usize; D],
+    /// The underlying flat vector storing the noise values of all channels for each cell.
+    pub buffer: Vec<[f64; C]>,
+}
+
+impl<const D: usize, const C: usize> Index<[usize; D]> for MultiNoiseBuffer<D, C> {
+    type Output = [f64; C];
+    fn index(&self, index: [usize; D]) -> &Self::Output {
+        let idx = self.flat_index(index);
+        &self.buffer[idx]
+    }
+}
+
+impl<const D: usize, const C: usize> IndexMut<[usize; D]> for MultiNoiseBuffer<D, C> {
+    fn index_mut(&mut self, index: [usize; D]) -> &mut Self::Output {
+        let idx = self.flat_index(index);
+        &mut self.buffer[idx]
+    }
+}
+
+impl<const D: usize, const C: usize> MultiNoiseBuffer<D, C> {
+    /// Creates a new multi-channel noise buffer with the given `shape` and filled with noise
+    /// generated by the given group of `generators`. For further detail see the
+    /// [struct-level documentation](MultiNoiseBuffer).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the resulting buffer overflows.
+    pub fn new<G: GeneratorChannels<D, C>>(shape: [usize; D], generators: &G) -> Self {
+        noisebuf::checked_size(shape)
+            .and_then(|len| noisebuf::checked_size([len, C]))
+            .map_err(|_| Error::InvalidShape {
+                shape: shape.to_vec(),
+            })
+            .unwrap_or_else(|err| panic!("{}", err));
+        let offsets = noisebuf::precompute_flat_index_offsets(&shape)
+            .try_into()
+            .unwrap();
+        let buffer = noisebuf::tensor_indices(shape)
+            .map(|index| generators.sample_channels(index.map(|idx| idx as f64)))
+            .collect();
+        Self {
+            shape,
+            offsets,
+            buffer,
+        }
+    }
+
+    /// Extracts the values of the given `channel` into a new [`NoiseBuffer`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channel` is not less than `C`.
+    pub fn channel(&self, channel: usize) -> NoiseBuffer<D> {
+        assert!(
+            channel < C,
+            "channel {} out of range for buffer with {} channels",
+            channel,
+            C
+        );
+        let mut noisebuf = NoiseBuffer::new_empty(self.shape);
+        for (value, values) in noisebuf.buffer.iter_mut().zip(&self.buffer) {
+            *value = values[channel];
+        }
+        noisebuf
+    }
+
+    fn flat_index(&self, index: [usize; D]) -> usize {
+        index
+            .iter()
+            .zip(&self.offsets)
+            .map(|(idx, offset)| idx * offset)
+            .sum()
+    }
+
+    #[cfg(feature = "image")]
+    fn pixel_buffer(&self) -> Vec<u8> {
+        self.buffer
+            .iter()
+            .flat_map(|values| values.map(quantize_u8))
+            .collect()
+    }
+}
+
+#[cfg(feature = "image")]
+impl MultiNoiseBuffer<2, 3> {
+    /// Write an RGB PNG file to the given `path`, mapping the channels to the red, green, and blue
+    /// components in order. Values are quantized in the same way as done by
+    /// [`NoiseBuffer::quantize_u8()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the buffer is empty along any axis, [`Error::Io`]
+    /// if the file cannot be written, and [`Error::Encode`] if the image cannot be encoded.
+    pub fn write_rgb_png(&self, path: &str) -> Result<(), Error> {
+        visualizer::validate_image_shape(&self.shape)?;
+        let image = RgbImage::from_raw(
+            self.shape[1] as u32,
+            self.shape[0] as u32,
+            self.pixel_buffer(),
+        )
+        .unwrap();
+        image.save(path)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "image")]
+impl MultiNoiseBuffer<2, 4> {
+    /// Write an RGBA PNG file to the given `path`, mapping the channels to the red, green, blue,
+    /// and alpha components in order. Values are quantized in the same way as done by
+    /// [`NoiseBuffer::quantize_u8()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the buffer is empty along any axis, [`Error::Io`]
+    /// if the file cannot be written, and [`Error::Encode`] if the image cannot be encoded.
+    pub fn write_rgba_png(&self, path: &str) -> Result<(), Error> {
+        visualizer::validate_image_shape(&self.shape)?;
+        let image = RgbaImage::from_raw(
+            self.shape[1] as u32,
+            self.shape[0] as u32,
+            self.pixel_buffer(),
+        )
+        .unwrap();
+        image.save(path)?;
+        Ok(())
+    }
+}
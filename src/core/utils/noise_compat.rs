@@ -0,0 +1,131 @@
+use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use noise::NoiseFn;
+
+/// A wrapper implementing the [`NoiseFn`] trait of the [`noise`] crate for a generator.
+///
+/// This allows passing generators of this crate to code written against the [`noise`] crate,
+/// including its own modifiers and utilities. The wrapper implements `NoiseFn<f64, D>` for
+/// generators implementing [`Generator<D>`], such that a generator of the wrong dimensionality
+/// is rejected at compile time. Sampling the wrapper produces the same results as sampling the
+/// generator it holds.
+///
+/// Requires the `noise-compat` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{AsNoiseFn, Source, Generator};
+/// use noise::NoiseFn;
+///
+/// let generator = Source::simplex(42).fbm(3, 0.013, 2.0, 0.5);
+/// let noise_fn = AsNoiseFn::new(generator.clone());
+///
+/// assert_eq!(noise_fn.get([0.2, 0.5]), generator.sample([0.2, 0.5]));
+/// ```
+///
+/// A generator of the wrong dimensionality does not compile:
+///
+/// ```compile_fail
+/// # use libnoise::{AsNoiseFn, Source};
+/// use noise::NoiseFn;
+///
+/// let noise_fn = AsNoiseFn::new(Source::<2>::simplex(42));
+/// let value = noise_fn.get([0.2, 0.5, 0.7]);
+/// ```
+///
+/// [`noise`]: https://docs.rs/noise
+#[derive(Clone, Copy, Debug)]
+pub struct AsNoiseFn<G> {
+    generator: G,
+}
+
+impl<G> AsNoiseFn<G> {
+    /// Wraps `generator`, such that it implements [`NoiseFn`].
+    #[inline]
+    pub fn new(generator: G) -> Self {
+        Self { generator }
+    }
+
+    /// Returns the wrapped generator.
+    #[inline]
+    pub fn into_inner(self) -> G {
+        self.generator
+    }
+}
+
+impl<const D: usize, G> NoiseFn<f64, D> for AsNoiseFn<G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    fn get(&self, point: [f64; D]) -> f64 {
+        self.generator.sample(point)
+    }
+}
+
+/// A generator wrapping a [`NoiseFn`] of the [`noise`] crate.
+///
+/// This allows using the sources and modifiers of the [`noise`] crate with the adapters of this
+/// crate, as well as with [`NoiseBuffer`] and `Visualizer`. The wrapper implements
+/// [`Generator<D>`] for functions implementing `NoiseFn<f64, D>`, such that a function of the
+/// wrong dimensionality is rejected at compile time. Sampling the wrapper produces the same
+/// results as the function it holds.
+///
+/// As the results of the wrapped function are unknown to this crate, [`sample_interval()`]
+/// returns the unbounded interval, and the generator cannot be converted into a recipe.
+///
+/// Requires the `noise-compat` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{FromNoiseFn, Generator};
+/// use noise::{NoiseFn, Perlin};
+///
+/// let generator = FromNoiseFn::new(Perlin::new(42)).fbm(3, 0.013, 2.0, 0.5);
+/// let value = generator.sample([0.2, 0.5]);
+/// ```
+///
+/// [`noise`]: https://docs.rs/noise
+/// [`NoiseBuffer`]: crate::NoiseBuffer
+/// [`sample_interval()`]: Generator::sample_interval
+#[derive(Clone, Copy, Debug)]
+pub struct FromNoiseFn<const D: usize, N> {
+    noise_fn: N,
+}
+
+impl<N: NoiseFn<f64, 1>> Generator1D for FromNoiseFn<1, N> {}
+impl<N: NoiseFn<f64, 2>> Generator2D for FromNoiseFn<2, N> {}
+impl<N: NoiseFn<f64, 3>> Generator3D for FromNoiseFn<3, N> {}
+impl<N: NoiseFn<f64, 4>> Generator4D for FromNoiseFn<4, N> {}
+
+impl<const D: usize, N> FromNoiseFn<D, N>
+where
+    N: NoiseFn<f64, D>,
+{
+    /// Wraps `noise_fn`, such that it implements [`Generator<D>`].
+    #[inline]
+    pub fn new(noise_fn: N) -> Self {
+        Self { noise_fn }
+    }
+
+    /// Returns the wrapped function.
+    #[inline]
+    pub fn into_inner(self) -> N {
+        self.noise_fn
+    }
+}
+
+impl<const D: usize, N> Generator<D> for FromNoiseFn<D, N>
+where
+    N: NoiseFn<f64, D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.noise_fn.get(point)
+    }
+}
@@ -1,6 +1,6 @@
-use crate::core::generator::Generator;
-use itertools::Itertools;
-use std::ops::{Index, IndexMut};
+use crate::core::{error::Error, generator::Generator};
+use alloc::{format, vec, vec::Vec};
+use core::ops::{Index, IndexMut};
 
 /// A struct for generating an n-dimensional array and efficiently filling it with noise values.
 ///
@@ -38,31 +38,212 @@ use std::ops::{Index, IndexMut};
 /// grid can be modified by calling adapters such as [`scale()`], [`translate()`], or [`rotate()`]
 /// on the generator before using it to create a [`NoiseBuffer`].
 ///
+/// # Sampling arbitrary grids
+///
+/// Alternatively, a buffer can be created using the [`new_mapped()`] method, which samples the
+/// generator on a hypergrid with a given origin and a given step between adjacent points along
+/// each dimension. Specifically, the value at a given buffer index is computed by sampling the
+/// generator at `origin + index * step` per dimension. Steps may be fractional or negative, the
+/// latter reversing the respective axis:
+///
+/// ```
+/// # use libnoise::{Source, Generator, NoiseBuffer};
+/// // create a generator
+/// let generator = Source::simplex(42);
+///
+/// // sample the region [100.0, 128.0) x [-5.0, -10.0) with a step of 0.25 and -0.5 respectively
+/// let buf = NoiseBuffer::<2>::new_mapped([112, 10], [100.0, -5.0], [0.25, -0.5], &generator);
+///
+/// assert_eq!(buf[[17, 3]], generator.sample([100.0 + 17.0 * 0.25, -5.0 + 3.0 * -0.5]));
+/// ```
+///
+/// # Filling chunks
+///
+/// When an infinite grid is generated piece by piece, the [`fill_chunk()`] method creates the
+/// buffer for a single chunk addressed by its integer chunk coordinates. The value at a given
+/// index of the chunk with coordinates `chunk_coord` is computed by sampling the generator at
+/// `(chunk_coord * chunk_shape + index) * cell_size` per dimension, where the global grid index
+/// is computed in integer arithmetic before it is scaled. Thus, adjacent chunks line up without
+/// seams and every chunk is bit-identical to the respective part of a larger chunk covering it,
+/// including for negative chunk coordinates:
+///
+/// ```
+/// # use libnoise::{Source, Generator, NoiseBuffer};
+/// // create a generator
+/// let generator = Source::simplex(42);
+///
+/// // create the chunk left of and below the origin, with a spacing of 0.5 between grid points
+/// let chunk = NoiseBuffer::<2>::fill_chunk(&generator, [-1, -1], [16, 16], 0.5);
+///
+/// assert_eq!(chunk[[3, 5]], generator.sample([(-16.0 + 3.0) * 0.5, (-16.0 + 5.0) * 0.5]));
+/// ```
+///
+/// # Sampling integer lattices
+///
+/// Voxel and tile based worlds often sample generators at integer coordinates only. The
+/// [`fill_lattice()`] method creates a buffer by sampling the generator at `origin + index`
+/// per dimension, where `origin` is given as integer coordinates, using
+/// [`Generator::sample_lattice()`]. The results equal those of sampling at the same coordinates
+/// as `f64`, but sources such as value noise skip their interpolation at lattice points:
+///
+/// ```
+/// # use libnoise::{Source, Generator, NoiseBuffer};
+/// // create a generator
+/// let generator = Source::value(42);
+///
+/// // create the 16x16 tile of voxels starting at [-32, 48]
+/// let tile = NoiseBuffer::<2>::fill_lattice(&generator, [-32, 48], [16, 16]);
+///
+/// assert_eq!(tile[[3, 5]], generator.sample([-32.0 + 3.0, 48.0 + 5.0]));
+/// ```
+///
+/// # Element type
+///
+/// By default, a noise buffer stores `f64` values. Alternatively, `f32` can be chosen as element
+/// type to halve the memory footprint, in which case the values produced by the generator are
+/// converted when filling the buffer. With the `half` feature enabled, `half::f16` is available
+/// as well, for example for streaming heightmaps into `R16Float` textures. Post-processing
+/// functionality, such as resampling, is only available for `f64` buffers.
+///
+/// ```
+/// # use libnoise::{Source, Generator, NoiseBuffer};
+/// // create a generator
+/// let generator = Source::simplex(42);
+///
+/// // create a new noise buffer storing f32 values
+/// let buf = NoiseBuffer::<3, f32>::new([30, 20, 25], &generator);
+///
+/// assert_eq!(buf[[17, 9, 21]], generator.sample([17.0, 9.0, 21.0]) as f32);
+/// ```
+///
 /// [`new()`]: NoiseBuffer::new
+/// [`new_mapped()`]: NoiseBuffer::new_mapped
+/// [`fill_chunk()`]: NoiseBuffer::fill_chunk
+/// [`fill_lattice()`]: NoiseBuffer::fill_lattice
 /// [`scale()`]: Generator::scale
 /// [`translate()`]: Generator::translate
 /// [`rotate()`]: crate::Generator2D::rotate
 #[derive(Clone, Debug)]
-pub struct NoiseBuffer<const D: usize> {
+pub struct NoiseBuffer<const D: usize, T = f64> {
     /// Stores the length of the n-dimensional array along each dimension.
     pub shape: [usize; D],
     /// Stores offsets which are used to convert n-dimensional coordinates to flat vector indices.
     pub offsets: [usize; D],
     /// The underlying flat vector storing the noise values.
-    pub buffer: Vec<f64>,
+    pub buffer: Vec<T>,
+}
+
+/// A sealed trait for the element types a [`NoiseBuffer`] can store, namely `f64` and `f32`, as
+/// well as `half::f16` if the `half` feature is enabled.
+///
+/// Generators always produce `f64` values, which are converted to the element type of the buffer
+/// when it is filled. The conversion rounds to the nearest representable value, with ties
+/// rounding to even, and values exceeding the range of the element type become infinite.
+pub trait NoiseElement: Copy + Default + private::Sealed {
+    /// Converts a noise value produced by a generator to this element type.
+    fn from_f64(value: f64) -> Self;
+
+    /// Converts a value of this element type to `f64`.
+    fn to_f64(self) -> f64;
+}
+
+impl NoiseElement for f64 {
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+impl NoiseElement for f32 {
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+#[cfg(feature = "half")]
+impl NoiseElement for half::f16 {
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        half::f16::from_bits(f64_to_f16_bits(value))
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.to_f64()
+    }
+}
+
+// converts to half precision rounding to nearest, ties to even, directly from the full precision
+// value. `half::f16::from_f64()` truncates the mantissa or goes through `f32` first, such that
+// values close to a tie may be rounded the wrong way, depending on the platform.
+#[cfg(feature = "half")]
+fn f64_to_f16_bits(value: f64) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 48) & 0x8000) as u16;
+    let exponent = ((bits >> 52) & 0x7ff) as i32;
+    let mantissa = bits & ((1 << 52) - 1);
+    if exponent == 0x7ff {
+        // infinity stays infinity, and every nan becomes a quiet nan
+        return sign | if mantissa == 0 { 0x7c00 } else { 0x7e00 };
+    }
+    let exponent = exponent - 1023;
+    if exponent > 15 {
+        return sign | 0x7c00;
+    }
+    // the number of mantissa bits to drop, such that the remaining bits count multiples of the
+    // spacing of half precision values at this magnitude, which is fixed below the normal range
+    let shift = 42 + (-14 - exponent).max(0) as u32;
+    if exponent == -1023 || shift >= 64 {
+        // far below half of the smallest subnormal, including zero and f64 subnormals
+        return sign;
+    }
+    let significand = mantissa | (1 << 52);
+    let mut rounded = significand >> shift;
+    let remainder = significand & ((1 << shift) - 1);
+    let halfway = 1 << (shift - 1);
+    if remainder > halfway || (remainder == halfway && rounded & 1 == 1) {
+        rounded += 1;
+    }
+    // a carry out of the mantissa correctly increments the exponent, up to infinity
+    let magnitude = if exponent >= -14 {
+        (((exponent + 15) as u64) << 10) + rounded - (1 << 10)
+    } else {
+        rounded
+    };
+    sign | magnitude.min(0x7c00) as u16
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for f64 {}
+    impl Sealed for f32 {}
+    #[cfg(feature = "half")]
+    impl Sealed for half::f16 {}
 }
 
 macro_rules! impl_indexing {
     ($dim:literal) => {
-        impl Index<[usize; $dim]> for NoiseBuffer<$dim> {
-            type Output = f64;
+        impl<T: NoiseElement> Index<[usize; $dim]> for NoiseBuffer<$dim, T> {
+            type Output = T;
             fn index(&self, index: [usize; $dim]) -> &Self::Output {
                 let idx = self.flat_index(index);
                 &self.buffer[idx]
             }
         }
 
-        impl IndexMut<[usize; $dim]> for NoiseBuffer<$dim> {
+        impl<T: NoiseElement> IndexMut<[usize; $dim]> for NoiseBuffer<$dim, T> {
             fn index_mut(&mut self, index: [usize; $dim]) -> &mut Self::Output {
                 let idx = self.flat_index(index);
                 &mut self.buffer[idx]
@@ -73,14 +254,118 @@ macro_rules! impl_indexing {
 
 macro_rules! impl_new {
     ($dim:literal) => {
-        impl NoiseBuffer<$dim> {
+        impl<T: NoiseElement> NoiseBuffer<$dim, T> {
             /// Creates a new noise buffer with the given `shape` and filled with noise generated
             /// by the given `generator`. For further detail see the
             /// [Creating a noise buffer](#creating-a-noise-buffer) section.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the total size of the buffer overflows. See [`try_new()`] for a
+            /// non-panicking alternative.
+            ///
+            /// [`try_new()`]: NoiseBuffer::try_new
             pub fn new<G: Generator<$dim>>(shape: [usize; $dim], generator: &G) -> Self {
                 let mut noisebuf = Self::new_empty(shape);
-                for point in noisebuf.tensor_indices() {
-                    noisebuf[point] = generator.sample(point.map(|x| x as f64));
+                noisebuf.refill(generator);
+                noisebuf
+            }
+
+            /// Creates a new noise buffer with the given `shape` and filled with noise generated
+            /// by the given `generator`, like [`new()`]. Instead of panicking, an error is
+            /// returned if the total size of the buffer overflows.
+            ///
+            /// [`new()`]: NoiseBuffer::new
+            pub fn try_new<G: Generator<$dim>>(
+                shape: [usize; $dim],
+                generator: &G,
+            ) -> Result<Self, Error> {
+                let mut noisebuf = Self::try_new_empty(shape)?;
+                noisebuf.refill(generator);
+                Ok(noisebuf)
+            }
+
+            /// Creates a new noise buffer with the given `shape` and filled with noise generated
+            /// by the given `generator` sampled on a hypergrid with the given `origin` and `step`
+            /// along each dimension. For further detail see the
+            /// [Sampling arbitrary grids](#sampling-arbitrary-grids) section.
+            pub fn new_mapped<G: Generator<$dim>>(
+                shape: [usize; $dim],
+                origin: [f64; $dim],
+                step: [f64; $dim],
+                generator: &G,
+            ) -> Self {
+                let mut noisebuf = Self::new_empty(shape);
+                fill_mapped(
+                    generator,
+                    shape,
+                    noisebuf.buffer.iter_mut(),
+                    |index| map_index(index, origin, step),
+                    T::from_f64,
+                );
+                noisebuf
+            }
+
+            /// Creates a new noise buffer for the chunk with the given `chunk_coord` of an
+            /// infinite grid divided into chunks of the given `chunk_shape`, filled with noise
+            /// generated by the given `generator`. For further detail see the
+            /// [Filling chunks](#filling-chunks) section.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the total size of the buffer overflows, or if a global grid index of
+            /// the chunk does not fit into an `i64`.
+            pub fn fill_chunk<G: Generator<$dim>>(
+                generator: &G,
+                chunk_coord: [i64; $dim],
+                chunk_shape: [usize; $dim],
+                cell_size: f64,
+            ) -> Self {
+                Self::try_fill_chunk(generator, chunk_coord, chunk_shape, cell_size)
+                    .unwrap_or_else(|err| panic!("{}", err))
+            }
+
+            /// Creates a new noise buffer for the chunk with the given `chunk_coord`, like
+            /// [`fill_chunk()`]. Instead of panicking, an error is returned if the total size of
+            /// the buffer overflows, or if a global grid index of the chunk does not fit into an
+            /// `i64`.
+            ///
+            /// [`fill_chunk()`]: NoiseBuffer::fill_chunk
+            pub fn try_fill_chunk<G: Generator<$dim>>(
+                generator: &G,
+                chunk_coord: [i64; $dim],
+                chunk_shape: [usize; $dim],
+                cell_size: f64,
+            ) -> Result<Self, Error> {
+                let origin = chunk_origin(chunk_coord, chunk_shape)?;
+                let mut noisebuf = Self::try_new_empty(chunk_shape)?;
+                fill_mapped(
+                    generator,
+                    chunk_shape,
+                    noisebuf.buffer.iter_mut(),
+                    |index| map_chunk_index(index, origin, cell_size),
+                    T::from_f64,
+                );
+                Ok(noisebuf)
+            }
+
+            /// Creates a new noise buffer with the given `shape`, filled with noise generated by
+            /// the given `generator` sampled at the points of the integer lattice starting at
+            /// `origin`. For further detail see the
+            /// [Sampling integer lattices](#sampling-integer-lattices) section.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the total size of the buffer overflows.
+            pub fn fill_lattice<G: Generator<$dim>>(
+                generator: &G,
+                origin: [i64; $dim],
+                shape: [usize; $dim],
+            ) -> Self {
+                let mut noisebuf = Self::new_empty(shape);
+                for (index, value) in tensor_indices(shape).zip(noisebuf.buffer.iter_mut()) {
+                    let point = core::array::from_fn(|i| origin[i] + index[i] as i64);
+                    *value = T::from_f64(generator.sample_lattice(point));
                 }
                 noisebuf
             }
@@ -98,31 +383,277 @@ impl_new!(2);
 impl_new!(3);
 impl_new!(4);
 
-impl<const D: usize> NoiseBuffer<D> {
-    fn new_empty(shape: [usize; D]) -> Self {
-        let bufsize = shape.iter().product();
-        Self {
+impl<const D: usize, T: NoiseElement> NoiseBuffer<D, T> {
+    /// Refills the noise buffer with noise generated by the given `generator`.
+    ///
+    /// The existing storage is reused and the shape of the buffer is retained. Values are written
+    /// in the same order and for the same points as when creating a buffer using [`new()`], such
+    /// that refilling a buffer is equivalent to creating a new one with the same shape.
+    ///
+    /// [`new()`]: NoiseBuffer::new
+    pub fn refill<G: Generator<D>>(&mut self, generator: &G) {
+        fill(generator, self.shape, self.buffer.iter_mut(), T::from_f64);
+    }
+
+    /// Refills the hyperrectangle of the noise buffer with the given `origin` and `shape` with
+    /// noise generated by the given `generator`, leaving the remaining values untouched.
+    ///
+    /// Each value within the region is sampled at the same point as when creating a buffer using
+    /// [`new()`], such that refilling a region of a buffer created with the same generator leaves
+    /// the buffer unchanged. Regions exceeding the bounds of the buffer are clipped to the buffer.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, NoiseBuffer};
+    /// let mut buf = NoiseBuffer::<2>::new([30, 20], &Source::simplex(42));
+    ///
+    /// // replace a 5x5 region with a constant
+    /// buf.refill_region(&Source::constant(0.5), [10, 10], [5, 5]);
+    ///
+    /// assert_eq!(buf[[12, 14]], 0.5);
+    /// ```
+    ///
+    /// [`new()`]: NoiseBuffer::new
+    pub fn refill_region<G: Generator<D>>(
+        &mut self,
+        generator: &G,
+        origin: [usize; D],
+        shape: [usize; D],
+    ) {
+        self.refill_region_with(generator, origin, shape, |index| index.map(|x| x as f64));
+    }
+
+    /// Refills the hyperrectangle of the noise buffer with the given `origin` and `shape` like
+    /// [`refill_region()`], except that values are sampled on a hypergrid with the given
+    /// `grid_origin` and `step`, as when creating a buffer using [`new_mapped()`].
+    ///
+    /// [`refill_region()`]: NoiseBuffer::refill_region
+    /// [`new_mapped()`]: NoiseBuffer::new_mapped
+    pub fn refill_region_mapped<G: Generator<D>>(
+        &mut self,
+        generator: &G,
+        origin: [usize; D],
+        shape: [usize; D],
+        grid_origin: [f64; D],
+        step: [f64; D],
+    ) {
+        self.refill_region_with(generator, origin, shape, |index| {
+            map_index(index, grid_origin, step)
+        });
+    }
+
+    fn refill_region_with<G, M>(
+        &mut self,
+        generator: &G,
+        origin: [usize; D],
+        shape: [usize; D],
+        map_point: M,
+    ) where
+        G: Generator<D>,
+        M: Fn([usize; D]) -> [f64; D],
+    {
+        let region_shape: [usize; D] =
+            core::array::from_fn(|i| shape[i].min(self.shape[i].saturating_sub(origin[i])));
+        for region_index in tensor_indices(region_shape) {
+            let index = core::array::from_fn(|i| origin[i] + region_index[i]);
+            let idx = self.flat_index(index);
+            self.buffer[idx] = T::from_f64(generator.sample(map_point(index)));
+        }
+    }
+
+    pub(crate) fn new_empty(shape: [usize; D]) -> Self {
+        Self::try_new_empty(shape).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub(crate) fn try_new_empty(shape: [usize; D]) -> Result<Self, Error> {
+        let bufsize = checked_size(shape)?;
+        Ok(Self {
             shape,
             offsets: precompute_flat_index_offsets(&shape).try_into().unwrap(),
-            buffer: vec![0.0; bufsize],
-        }
+            buffer: vec![T::default(); bufsize],
+        })
     }
 
-    fn flat_index(&self, index: [usize; D]) -> usize {
+    pub(crate) fn flat_index(&self, index: [usize; D]) -> usize {
         index
             .iter()
             .zip(&self.offsets)
             .map(|(idx, offset)| idx * offset)
             .sum()
     }
+}
 
-    pub(crate) fn tensor_indices(&self) -> impl Iterator<Item = [usize; D]> {
-        self.shape
-            .iter()
-            .map(|&dim_size| 0..dim_size)
-            .multi_cartesian_product()
-            .map(|point| point.try_into().unwrap())
+impl<const D: usize> NoiseBuffer<D> {
+    /// Fills the provided slice `out` with noise generated by the given `generator`, as if it
+    /// were the underlying flat vector of a noise buffer with the given `shape`.
+    ///
+    /// This allows for filling existing memory without allocating. The layout of the written
+    /// values is exactly that of a [`NoiseBuffer`] with the same `shape`. An error is returned,
+    /// if the length of `out` does not equal the product of the lengths along each dimension.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, NoiseBuffer};
+    /// let generator = Source::simplex(42);
+    ///
+    /// let mut out = vec![0.0; 30 * 20];
+    /// NoiseBuffer::<2>::fill_slice(&generator, [30, 20], &mut out).unwrap();
+    ///
+    /// assert_eq!(out, NoiseBuffer::<2>::new([30, 20], &generator).buffer);
+    /// ```
+    pub fn fill_slice<G: Generator<D>>(
+        generator: &G,
+        shape: [usize; D],
+        out: &mut [f64],
+    ) -> Result<(), Error> {
+        validate_slice_len(shape, out.len())?;
+        fill(generator, shape, out.iter_mut(), |value| value);
+        Ok(())
+    }
+
+    /// Fills the provided slice `out` with noise generated by the given `generator`, as if it
+    /// were the underlying flat vector of a noise buffer with the given `shape`.
+    ///
+    /// This is equivalent to [`fill_slice()`], except that values are converted to `f32` before
+    /// they are written.
+    ///
+    /// [`fill_slice()`]: NoiseBuffer::fill_slice
+    pub fn fill_slice_f32<G: Generator<D>>(
+        generator: &G,
+        shape: [usize; D],
+        out: &mut [f32],
+    ) -> Result<(), Error> {
+        validate_slice_len(shape, out.len())?;
+        fill(generator, shape, out.iter_mut(), |value| value as f32);
+        Ok(())
+    }
+}
+
+fn fill<'a, const D: usize, G, T, F>(
+    generator: &G,
+    shape: [usize; D],
+    out: impl Iterator<Item = &'a mut T>,
+    convert: F,
+) where
+    G: Generator<D>,
+    T: 'a,
+    F: Fn(f64) -> T,
+{
+    fill_mapped(
+        generator,
+        shape,
+        out,
+        |index| index.map(|x| x as f64),
+        convert,
+    );
+}
+
+pub(crate) fn fill_mapped<'a, const D: usize, G, T, M, F>(
+    generator: &G,
+    shape: [usize; D],
+    out: impl Iterator<Item = &'a mut T>,
+    map_point: M,
+    convert: F,
+) where
+    G: Generator<D>,
+    T: 'a,
+    M: Fn([usize; D]) -> [f64; D],
+    F: Fn(f64) -> T,
+{
+    for (index, value) in tensor_indices(shape).zip(out) {
+        *value = convert(generator.sample(map_point(index)));
+    }
+}
+
+#[inline]
+pub(crate) fn map_index<const D: usize>(
+    index: [usize; D],
+    origin: [f64; D],
+    step: [f64; D],
+) -> [f64; D] {
+    core::array::from_fn(|i| origin[i] + index[i] as f64 * step[i])
+}
+
+// computes the global grid index of the first point of a chunk, ensuring that the global grid
+// index of every point within the chunk fits into an i64
+pub(crate) fn chunk_origin<const D: usize>(
+    chunk_coord: [i64; D],
+    chunk_shape: [usize; D],
+) -> Result<[i64; D], Error> {
+    let mut origin = [0; D];
+    for (i, start) in origin.iter_mut().enumerate() {
+        *start = i64::try_from(chunk_shape[i])
+            .ok()
+            .and_then(|len| {
+                let start = chunk_coord[i].checked_mul(len)?;
+                start.checked_add((len - 1).max(0))?;
+                Some(start)
+            })
+            .ok_or_else(|| Error::InvalidParameter {
+                name: "chunk_coord",
+                reason: format!(
+                    "grid indices of chunk {:?} with shape {:?} overflow i64",
+                    chunk_coord, chunk_shape
+                ),
+            })?;
+    }
+    Ok(origin)
+}
+
+#[inline]
+pub(crate) fn map_chunk_index<const D: usize>(
+    index: [usize; D],
+    origin: [i64; D],
+    cell_size: f64,
+) -> [f64; D] {
+    core::array::from_fn(|i| (origin[i] + index[i] as i64) as f64 * cell_size)
+}
+
+pub(crate) fn checked_size<const D: usize>(shape: [usize; D]) -> Result<usize, Error> {
+    shape
+        .iter()
+        .try_fold(1_usize, |acc, &dim_size| acc.checked_mul(dim_size))
+        .filter(|size| {
+            size.checked_mul(core::mem::size_of::<f64>())
+                .is_some_and(|bytes| bytes <= isize::MAX as usize)
+        })
+        .ok_or_else(|| Error::InvalidShape {
+            shape: shape.to_vec(),
+        })
+}
+
+fn validate_slice_len<const D: usize>(shape: [usize; D], len: usize) -> Result<(), Error> {
+    let expected = checked_size(shape)?;
+    if len != expected {
+        return Err(Error::ShapeMismatch {
+            expected: vec![expected],
+            found: vec![len],
+        });
+    }
+    Ok(())
+}
+
+pub(crate) fn tensor_indices<const D: usize>(
+    shape: [usize; D],
+) -> impl Iterator<Item = [usize; D]> {
+    let len = shape.iter().product();
+    (0..len).map(move |flat_index| tensor_index(shape, flat_index))
+}
+
+#[inline]
+pub(crate) fn tensor_index<const D: usize>(shape: [usize; D], mut flat_index: usize) -> [usize; D] {
+    let mut index = [0; D];
+    for (component, dim_size) in index.iter_mut().zip(shape).rev() {
+        *component = flat_index % dim_size;
+        flat_index /= dim_size;
     }
+    index
 }
 
 pub(crate) fn precompute_flat_index_offsets(shape: &[usize]) -> Vec<usize> {
@@ -0,0 +1,168 @@
+use crate::core::utils::noisebuf::{self, NoiseBuffer};
+use core::ops::Index;
+
+/// A borrowed, read-only view into a region of a [`NoiseBuffer`].
+///
+/// A view references the values of the underlying buffer without copying them. Views are created
+/// from a noise buffer, e.g. by taking a lower-dimensional slice of a 3D buffer using
+/// [`slice()`], or a sub-rectangle of a 2D buffer using [`view()`]. Just like [`NoiseBuffer`],
+/// a view can be indexed with an index array of the appropriate length, with indices relative to
+/// the origin of the view.
+///
+/// A view can be converted into an owned [`NoiseBuffer`] using [`to_buffer()`], and, as such,
+/// be passed to any functionality consuming noise buffers, such as the `Visualizer`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, NoiseBuffer};
+/// let buf = NoiseBuffer::<3>::new([30, 20, 25], &Source::simplex(42));
+///
+/// // take the 2D slice at z-index 7
+/// let view = buf.slice(2, 7);
+///
+/// assert_eq!(view.shape, [30, 20]);
+/// assert_eq!(view[[17, 9]], buf[[17, 9, 7]]);
+/// ```
+///
+/// [`slice()`]: NoiseBuffer::slice
+/// [`view()`]: NoiseBuffer::view
+/// [`to_buffer()`]: NoiseBufferView::to_buffer
+#[derive(Clone, Copy, Debug)]
+pub struct NoiseBufferView<'a, const D: usize> {
+    /// Stores the length of the view along each dimension.
+    pub shape: [usize; D],
+    /// Stores offsets which are used to convert n-dimensional coordinates to indices into the
+    /// underlying flat vector.
+    pub offsets: [usize; D],
+    start: usize,
+    buffer: &'a [f64],
+}
+
+impl<const D: usize> Index<[usize; D]> for NoiseBufferView<'_, D> {
+    type Output = f64;
+    fn index(&self, index: [usize; D]) -> &Self::Output {
+        for (axis, (&idx, &dim_size)) in index.iter().zip(&self.shape).enumerate() {
+            assert!(
+                idx < dim_size,
+                "index {} out of bounds for axis {} of view with shape {:?}",
+                idx,
+                axis,
+                self.shape
+            );
+        }
+        &self.buffer[self.flat_index(index)]
+    }
+}
+
+impl<'a, const D: usize> NoiseBufferView<'a, D> {
+    /// Returns an iterator over the values of the view in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = f64> + '_ {
+        noisebuf::tensor_indices(self.shape).map(|index| self.buffer[self.flat_index(index)])
+    }
+
+    /// Copies the values of the view into a new, owned [`NoiseBuffer`] with the shape of the view.
+    pub fn to_buffer(&self) -> NoiseBuffer<D> {
+        let mut noisebuf = NoiseBuffer::new_empty(self.shape);
+        for (value, view_value) in noisebuf.buffer.iter_mut().zip(self.iter()) {
+            *value = view_value;
+        }
+        noisebuf
+    }
+
+    fn flat_index(&self, index: [usize; D]) -> usize {
+        self.start
+            + index
+                .iter()
+                .zip(&self.offsets)
+                .map(|(idx, offset)| idx * offset)
+                .sum::<usize>()
+    }
+}
+
+impl<'a, const D: usize> From<&'a NoiseBuffer<D>> for NoiseBufferView<'a, D> {
+    fn from(noisebuf: &'a NoiseBuffer<D>) -> Self {
+        Self {
+            shape: noisebuf.shape,
+            offsets: noisebuf.offsets,
+            start: 0,
+            buffer: &noisebuf.buffer,
+        }
+    }
+}
+
+impl<const D: usize> From<NoiseBufferView<'_, D>> for NoiseBuffer<D> {
+    fn from(view: NoiseBufferView<'_, D>) -> Self {
+        view.to_buffer()
+    }
+}
+
+impl NoiseBuffer<3> {
+    /// Creates a 2D view of the buffer by fixing the index along the given `axis` to `index`.
+    ///
+    /// The remaining axes of the resulting view retain their relative order. For example, fixing
+    /// `axis` 1 results in a view indexed by the original axes 0 and 2. For further detail see
+    /// [`NoiseBufferView`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not less than 3, or if `index` is out of bounds for the given `axis`.
+    pub fn slice(&self, axis: usize, index: usize) -> NoiseBufferView<'_, 2> {
+        assert!(
+            axis < 3,
+            "axis {} out of range for 3-dimensional buffer",
+            axis
+        );
+        assert!(
+            index < self.shape[axis],
+            "index {} out of bounds for axis {} of buffer with shape {:?}",
+            index,
+            axis,
+            self.shape
+        );
+        let remaining = match axis {
+            0 => [1, 2],
+            1 => [0, 2],
+            _ => [0, 1],
+        };
+        NoiseBufferView {
+            shape: remaining.map(|i| self.shape[i]),
+            offsets: remaining.map(|i| self.offsets[i]),
+            start: index * self.offsets[axis],
+            buffer: &self.buffer,
+        }
+    }
+}
+
+impl NoiseBuffer<2> {
+    /// Creates a 2D view of the sub-rectangle of the buffer with the given `origin` and `shape`.
+    ///
+    /// Indices into the resulting view are relative to `origin`. For further detail see
+    /// [`NoiseBufferView`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sub-rectangle exceeds the bounds of the buffer along any axis.
+    pub fn view(&self, origin: [usize; 2], shape: [usize; 2]) -> NoiseBufferView<'_, 2> {
+        for axis in 0..2 {
+            assert!(
+                origin[axis]
+                    .checked_add(shape[axis])
+                    .is_some_and(|end| end <= self.shape[axis]),
+                "view with origin {:?} and shape {:?} exceeds axis {} of buffer with shape {:?}",
+                origin,
+                shape,
+                axis,
+                self.shape
+            );
+        }
+        NoiseBufferView {
+            shape,
+            offsets: self.offsets,
+            start: origin[0] * self.offsets[0] + origin[1] * self.offsets[1],
+            buffer: &self.buffer,
+        }
+    }
+}
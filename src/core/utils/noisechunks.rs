@@ -0,0 +1,110 @@
+use crate::core::{
+    generator::Generator,
+    utils::noisebuf::{self, NoiseBuffer},
+};
+
+/// An iterator for filling a large n-dimensional array with noise values chunk by chunk.
+///
+/// This struct divides an n-dimensional array of a given shape into chunks of a given chunk
+/// shape and yields each chunk as a [`NoiseBuffer`] paired with the index of its origin within
+/// the full array. The chunks are yielded in row-major order with respect to the chunk grid.
+/// If the shape of the full array is not divisible by the chunk shape, the chunks at the upper
+/// edges of the array are shrunk accordingly, such that the chunks always exactly cover the full
+/// array without overlap.
+///
+/// Only a single chunk is held in memory at a time, allowing arrays which do not fit in memory
+/// to be processed, e.g. by writing each chunk to disk. The values of every chunk are identical
+/// to those of a [`NoiseBuffer`] filled for the full shape at the same global indices.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, NoiseBuffer, NoiseChunks};
+/// let generator = Source::simplex(42);
+/// let full = NoiseBuffer::<2>::new([30, 20], &generator);
+///
+/// for (origin, chunk) in NoiseChunks::new(&generator, [30, 20], [16, 16]) {
+///     let index = [origin[0] + 3, origin[1] + 2];
+///     assert_eq!(chunk[[3, 2]], full[index]);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct NoiseChunks<'a, const D: usize, G> {
+    generator: &'a G,
+    shape: [usize; D],
+    chunk_shape: [usize; D],
+    grid_shape: [usize; D],
+    next_chunk: usize,
+    num_chunks: usize,
+}
+
+impl<'a, const D: usize, G: Generator<D>> NoiseChunks<'a, D, G> {
+    /// Creates a new chunk iterator covering an n-dimensional array with the given `shape` in
+    /// chunks of the given `chunk_shape`, filled with noise generated by the given `generator`.
+    /// For further detail see the [struct-level documentation](NoiseChunks).
+    ///
+    /// # Panics
+    ///
+    /// Panics if any component of `chunk_shape` is zero.
+    pub fn new(generator: &'a G, shape: [usize; D], chunk_shape: [usize; D]) -> Self {
+        assert!(
+            chunk_shape.iter().all(|&dim_size| dim_size > 0),
+            "chunk shape must be positive along every dimension, got {:?}",
+            chunk_shape
+        );
+        let mut grid_shape = [0; D];
+        for (grid_size, (dim_size, chunk_size)) in
+            grid_shape.iter_mut().zip(shape.iter().zip(chunk_shape))
+        {
+            *grid_size = dim_size.div_ceil(chunk_size);
+        }
+        Self {
+            generator,
+            shape,
+            chunk_shape,
+            grid_shape,
+            next_chunk: 0,
+            num_chunks: grid_shape.iter().product(),
+        }
+    }
+
+    fn chunk(&self, chunk_index: [usize; D]) -> ([usize; D], NoiseBuffer<D>) {
+        let mut origin = [0; D];
+        let mut shape = [0; D];
+        for i in 0..D {
+            origin[i] = chunk_index[i] * self.chunk_shape[i];
+            shape[i] = self.chunk_shape[i].min(self.shape[i] - origin[i]);
+        }
+        let mut noisebuf = NoiseBuffer::new_empty(shape);
+        noisebuf::fill_mapped(
+            self.generator,
+            shape,
+            noisebuf.buffer.iter_mut(),
+            |index| core::array::from_fn(|i| (origin[i] + index[i]) as f64),
+            |value| value,
+        );
+        (origin, noisebuf)
+    }
+}
+
+impl<const D: usize, G: Generator<D>> Iterator for NoiseChunks<'_, D, G> {
+    type Item = ([usize; D], NoiseBuffer<D>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_chunk >= self.num_chunks {
+            return None;
+        }
+        let chunk_index = noisebuf::tensor_index(self.grid_shape, self.next_chunk);
+        self.next_chunk += 1;
+        Some(self.chunk(chunk_index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.num_chunks - self.next_chunk;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<const D: usize, G: Generator<D>> ExactSizeIterator for NoiseChunks<'_, D, G> {}
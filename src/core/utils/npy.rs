@@ -0,0 +1,285 @@
+use crate::core::{
+    error::Error,
+    utils::noisebuf::{self, NoiseBuffer},
+};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Read, Write},
+};
+
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+const NPY_ALIGNMENT: usize = 64;
+
+macro_rules! impl_write_npy {
+    ($type:ty, $descr:literal) => {
+        impl<const D: usize> NoiseBuffer<D, $type> {
+            #[doc = concat!("Write the buffer to a `.npy` file at the given `path`, with dtype `", $descr, "`.")]
+            ///
+            /// The file is written in version 1.0 of the NumPy file format. The shape of the
+            /// resulting array equals the shape of the buffer, and the data is written in C-order,
+            /// such that indexing the loaded array yields the same values as indexing the buffer.
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// # use libnoise::{Source, NoiseBuffer};
+            /// # use tempdir::TempDir;
+            /// let buf = NoiseBuffer::<3>::new([30, 20, 25], &Source::simplex(42));
+            ///
+            /// let path = "noise.npy";
+            /// # let tmp_dir = TempDir::new("libnoise").unwrap();
+            /// # let path = &tmp_dir.path().join(path).into_os_string().into_string().unwrap();
+            /// buf.write_npy(path).unwrap();
+            /// ```
+            ///
+            /// The resulting file can then be loaded using NumPy:
+            ///
+            /// ```python
+            /// import numpy as np
+            ///
+            /// noise = np.load("noise.npy")
+            /// assert noise.shape == (30, 20, 25)
+            /// ```
+            ///
+            /// # Errors
+            ///
+            /// Returns [`Error::Io`] if the file cannot be created or written.
+            pub fn write_npy(&self, path: &str) -> Result<(), Error> {
+                let mut writer = BufWriter::new(File::create(path)?);
+                write_npy_header(&mut writer, $descr, &self.shape)?;
+                for value in self.buffer.iter() {
+                    writer.write_all(&value.to_le_bytes())?;
+                }
+                writer.flush()?;
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_write_npy!(f64, "<f8");
+impl_write_npy!(f32, "<f4");
+
+fn write_npy_header<W: Write>(writer: &mut W, descr: &str, shape: &[usize]) -> io::Result<()> {
+    let shape = match shape {
+        [dim_size] => format!("({},)", dim_size),
+        _ => format!(
+            "({})",
+            shape
+                .iter()
+                .map(|dim_size| dim_size.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}",
+        descr, shape
+    );
+    // the magic string, version, and header length take up 10 bytes, and the header must be
+    // terminated by a newline such that the data is aligned
+    let unpadded_len = NPY_MAGIC.len() + 4 + header.len() + 1;
+    let padding = (NPY_ALIGNMENT - unpadded_len % NPY_ALIGNMENT) % NPY_ALIGNMENT;
+    header.extend(std::iter::repeat_n(' ', padding));
+    header.push('\n');
+    writer.write_all(NPY_MAGIC)?;
+    writer.write_all(&[1, 0])?;
+    writer.write_all(&(header.len() as u16).to_le_bytes())?;
+    writer.write_all(header.as_bytes())
+}
+
+impl<const D: usize> NoiseBuffer<D> {
+    /// Reads a buffer from the `.npy` file at the given `path`.
+    ///
+    /// Versions 1.0, 2.0, and 3.0 of the NumPy file format are supported. The array stored in
+    /// the file must have exactly `D` dimensions and a floating point dtype of 4 or 8 bytes, or
+    /// an integer dtype of 1, 2, 4, or 8 bytes, in either byte order. Values are converted to
+    /// `f64`. Both C-order and Fortran-order arrays are supported, and the resulting buffer is
+    /// indexed like the array in NumPy, regardless of the order of the stored data.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, NoiseBuffer};
+    /// # use tempdir::TempDir;
+    /// let buf = NoiseBuffer::<2>::new([30, 20], &Source::simplex(42));
+    ///
+    /// let path = "noise.npy";
+    /// # let tmp_dir = TempDir::new("libnoise").unwrap();
+    /// # let path = &tmp_dir.path().join(path).into_os_string().into_string().unwrap();
+    /// buf.write_npy(path).unwrap();
+    /// let loaded = NoiseBuffer::<2>::read_npy(path).unwrap();
+    ///
+    /// assert_eq!(loaded.buffer, buf.buffer);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the file cannot be read, and [`Error::Unsupported`] if the
+    /// version of the file format or the dtype of the stored array is not supported. If the file
+    /// is not a valid `.npy` file, its header or data is corrupt, or the number of dimensions of
+    /// the stored array does not equal `D`, [`Error::Encode`] is returned.
+    pub fn read_npy(path: &str) -> Result<Self, Error> {
+        let mut bytes = Vec::new();
+        File::open(path)?.read_to_end(&mut bytes)?;
+        let (header, data) = split_npy_header(&bytes)?;
+        let dtype = NpyDtype::parse(&parse_header_str(&header, "descr")?)?;
+        let fortran_order = match parse_header_value(&header, "fortran_order")? {
+            "True" => true,
+            "False" => false,
+            value => return Err(invalid_data(format!("invalid fortran_order {}", value))),
+        };
+        let shape = parse_header_shape(&header)?;
+        let shape: [usize; D] = shape.as_slice().try_into().map_err(|_| {
+            invalid_data(format!(
+                "expected array with {} dimensions, found shape {:?}",
+                D, shape
+            ))
+        })?;
+        let mut noisebuf = Self::try_new_empty(shape)?;
+        if data.len() != noisebuf.buffer.len() * dtype.size {
+            return Err(invalid_data(format!(
+                "expected {} bytes of data for shape {:?}, found {}",
+                noisebuf.buffer.len() * dtype.size,
+                shape,
+                data.len()
+            )));
+        }
+        let values = data
+            .chunks_exact(dtype.size)
+            .map(|bytes| dtype.decode(bytes));
+        if fortran_order {
+            let mut reversed_shape = shape;
+            reversed_shape.reverse();
+            for (flat_index, value) in values.enumerate() {
+                let mut index = noisebuf::tensor_index(reversed_shape, flat_index);
+                index.reverse();
+                let idx = noisebuf.flat_index(index);
+                noisebuf.buffer[idx] = value;
+            }
+        } else {
+            for (out, value) in noisebuf.buffer.iter_mut().zip(values) {
+                *out = value;
+            }
+        }
+        Ok(noisebuf)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct NpyDtype {
+    kind: char,
+    size: usize,
+    big_endian: bool,
+}
+
+impl NpyDtype {
+    fn parse(descr: &str) -> Result<Self, Error> {
+        let unsupported = || Error::Unsupported {
+            reason: format!("dtype '{}'", descr),
+        };
+        let mut chars = descr.chars();
+        let big_endian = match chars.next() {
+            Some('<') | Some('|') => false,
+            Some('>') => true,
+            Some('=') => cfg!(target_endian = "big"),
+            _ => return Err(unsupported()),
+        };
+        let kind = chars.next().ok_or_else(unsupported)?;
+        let size = chars.as_str().parse::<usize>().map_err(|_| unsupported())?;
+        match (kind, size) {
+            ('f', 4 | 8) | ('i' | 'u', 1 | 2 | 4 | 8) => Ok(Self {
+                kind,
+                size,
+                big_endian,
+            }),
+            _ => Err(unsupported()),
+        }
+    }
+
+    fn decode(&self, bytes: &[u8]) -> f64 {
+        let mut raw = [0; 8];
+        raw[..self.size].copy_from_slice(bytes);
+        if self.big_endian {
+            raw[..self.size].reverse();
+        }
+        match (self.kind, self.size) {
+            ('f', 4) => f32::from_le_bytes(raw[..4].try_into().unwrap()) as f64,
+            ('f', _) => f64::from_le_bytes(raw),
+            ('i', 1) => i8::from_le_bytes([raw[0]]) as f64,
+            ('i', 2) => i16::from_le_bytes(raw[..2].try_into().unwrap()) as f64,
+            ('i', 4) => i32::from_le_bytes(raw[..4].try_into().unwrap()) as f64,
+            ('i', _) => i64::from_le_bytes(raw) as f64,
+            // unsigned integers are zero-extended
+            _ => u64::from_le_bytes(raw) as f64,
+        }
+    }
+}
+
+fn split_npy_header(bytes: &[u8]) -> Result<(String, &[u8]), Error> {
+    if !bytes.starts_with(NPY_MAGIC) || bytes.len() < NPY_MAGIC.len() + 4 {
+        return Err(invalid_data("not a .npy file".to_string()));
+    }
+    let rest = &bytes[NPY_MAGIC.len()..];
+    let (header_len, rest) = match rest[0] {
+        1 => (u16::from_le_bytes([rest[2], rest[3]]) as usize, &rest[4..]),
+        2 | 3 if rest.len() >= 6 => (
+            u32::from_le_bytes(rest[2..6].try_into().unwrap()) as usize,
+            &rest[6..],
+        ),
+        version => {
+            return Err(Error::Unsupported {
+                reason: format!(".npy format version {}.{}", version, rest[1]),
+            })
+        }
+    };
+    if rest.len() < header_len {
+        return Err(invalid_data("truncated .npy header".to_string()));
+    }
+    let header = std::str::from_utf8(&rest[..header_len])
+        .map_err(|_| invalid_data("corrupt .npy header".to_string()))?;
+    Ok((header.to_string(), &rest[header_len..]))
+}
+
+fn parse_header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, Error> {
+    let missing = || invalid_data(format!("missing key '{}' in .npy header", key));
+    let start = header.find(&format!("'{}':", key)).ok_or_else(missing)? + key.len() + 3;
+    let value = header[start..].trim_start();
+    let end = if value.starts_with('(') {
+        value.find(')').map(|end| end + 1)
+    } else {
+        value.find([',', '}'])
+    };
+    end.map(|end| value[..end].trim()).ok_or_else(missing)
+}
+
+fn parse_header_str(header: &str, key: &str) -> Result<String, Error> {
+    let value = parse_header_value(header, key)?;
+    value
+        .strip_prefix('\'')
+        .and_then(|value| value.strip_suffix('\''))
+        .map(str::to_string)
+        .ok_or_else(|| invalid_data(format!("invalid {} {}", key, value)))
+}
+
+fn parse_header_shape(header: &str) -> Result<Vec<usize>, Error> {
+    let value = parse_header_value(header, "shape")?;
+    let invalid = || invalid_data(format!("invalid shape {}", value));
+    value
+        .strip_prefix('(')
+        .and_then(|value| value.strip_suffix(')'))
+        .ok_or_else(invalid)?
+        .split(',')
+        .map(str::trim)
+        .filter(|dim_size| !dim_size.is_empty())
+        .map(|dim_size| dim_size.parse().map_err(|_| invalid()))
+        .collect()
+}
+
+fn invalid_data(reason: String) -> Error {
+    Error::Encode { reason }
+}
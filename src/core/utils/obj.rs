@@ -0,0 +1,132 @@
+use crate::core::{
+    error::Error,
+    utils::noisebuf::{NoiseBuffer, NoiseElement},
+};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+};
+
+impl<T: NoiseElement> NoiseBuffer<2, T> {
+    /// Write the buffer as a heightmap mesh to a Wavefront `.obj` file at the given `path`.
+    ///
+    /// The mesh contains one vertex per value of the buffer, where the value at index `[x, y]`
+    /// is placed at `(x * cell_size, value * height_scale, y * cell_size)`, such that the y-axis
+    /// points up, as expected by most modelling software. Every vertex is given a normal computed
+    /// from the height field using central differences, or one-sided differences at the edges,
+    /// and texture coordinates `(x / (width - 1), y / (height - 1))` spanning `[0, 1]²`, where
+    /// `[width, height]` is the shape of the buffer. Each grid cell is written as a quad face
+    /// with its vertices in counter-clockwise order when viewed from above. Vertices, texture
+    /// coordinates, and normals share their indices, which follow the row-major order of the
+    /// buffer, starting at 1 as mandated by the format.
+    ///
+    /// The mesh is streamed to the file without building it in memory first, such that large
+    /// buffers can be exported.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, NoiseBuffer};
+    /// # use tempdir::TempDir;
+    /// let buf = NoiseBuffer::<2>::new([128, 128], &Source::simplex(42).scale([0.05; 2]));
+    ///
+    /// let path = "terrain.obj";
+    /// # let tmp_dir = TempDir::new("libnoise").unwrap();
+    /// # let path = &tmp_dir.path().join(path).into_os_string().into_string().unwrap();
+    /// buf.write_obj(path, 0.5, 8.0).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the file cannot be created or written.
+    pub fn write_obj(&self, path: &str, cell_size: f64, height_scale: f64) -> Result<(), Error> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_obj_to(&mut writer, cell_size, height_scale)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn write_obj_to<W: Write>(
+        &self,
+        writer: &mut W,
+        cell_size: f64,
+        height_scale: f64,
+    ) -> io::Result<()> {
+        let [width, height] = self.shape;
+        let elevation = |x: usize, y: usize| self[[x, y]].to_f64() * height_scale;
+        writeln!(writer, "# heightmap of {}x{} vertices", width, height)?;
+        for x in 0..width {
+            for y in 0..height {
+                writeln!(
+                    writer,
+                    "v {} {} {}",
+                    x as f64 * cell_size,
+                    elevation(x, y),
+                    y as f64 * cell_size
+                )?;
+            }
+        }
+        for x in 0..width {
+            for y in 0..height {
+                writeln!(
+                    writer,
+                    "vt {} {}",
+                    texture_coordinate(x, width),
+                    texture_coordinate(y, height)
+                )?;
+            }
+        }
+        for x in 0..width {
+            for y in 0..height {
+                let (x0, x1) = (x.saturating_sub(1), (x + 1).min(width - 1));
+                let (y0, y1) = (y.saturating_sub(1), (y + 1).min(height - 1));
+                let slope_x = slope(elevation(x0, y), elevation(x1, y), x1 - x0, cell_size);
+                let slope_y = slope(elevation(x, y0), elevation(x, y1), y1 - y0, cell_size);
+                let norm = (slope_x * slope_x + slope_y * slope_y + 1.0).sqrt();
+                // subtracting from zero avoids writing negative zeros for flat terrain
+                writeln!(
+                    writer,
+                    "vn {} {} {}",
+                    (0.0 - slope_x) / norm,
+                    1.0 / norm,
+                    (0.0 - slope_y) / norm
+                )?;
+            }
+        }
+        let vertex = |x: usize, y: usize| x * height + y + 1;
+        for x in 0..width.saturating_sub(1) {
+            for y in 0..height.saturating_sub(1) {
+                let corners = [
+                    vertex(x, y),
+                    vertex(x, y + 1),
+                    vertex(x + 1, y + 1),
+                    vertex(x + 1, y),
+                ];
+                write!(writer, "f")?;
+                for corner in corners {
+                    write!(writer, " {0}/{0}/{0}", corner)?;
+                }
+                writeln!(writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn texture_coordinate(index: usize, dim_size: usize) -> f64 {
+    if dim_size > 1 {
+        index as f64 / (dim_size - 1) as f64
+    } else {
+        0.0
+    }
+}
+
+fn slope(low: f64, high: f64, steps: usize, cell_size: f64) -> f64 {
+    if steps > 0 {
+        (high - low) / (steps as f64 * cell_size)
+    } else {
+        0.0
+    }
+}
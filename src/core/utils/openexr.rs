@@ -0,0 +1,77 @@
+use crate::core::{error::Error, utils::noisebuf::NoiseBuffer};
+use exr::prelude::{
+    AnyChannel, AnyChannels, Encoding, FlatSamples, Image, Layer, LayerAttributes, SmallVec,
+    WritableImage,
+};
+use half::f16;
+
+macro_rules! impl_write_exr {
+    ($type:ty, $pixel_type:literal, $samples:expr) => {
+        impl NoiseBuffer<2, $type> {
+            #[doc = concat!("Write the buffer to an OpenEXR file at the given `path`, storing the values as a single `", $pixel_type, "` channel.")]
+            ///
+            /// The image consists of a single channel named `Y`, and is as wide as the buffer
+            /// along its second axis and as high as the buffer along its first axis, like the
+            /// images written by `Visualizer`. The values are not rescaled, such that the file
+            /// can be uploaded to the GPU as a floating point texture without further
+            /// processing. As OpenEXR has no double precision channels, `f64` values are rounded
+            /// to `f32`.
+            ///
+            /// Requires the `exr` feature.
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// # use libnoise::{Source, NoiseBuffer};
+            /// # use tempdir::TempDir;
+            /// # use half::f16;
+            #[doc = concat!("let buf = NoiseBuffer::<2, ", stringify!($type), ">::new([300, 200], &Source::simplex(42));")]
+            ///
+            /// let path = "heightmap.exr";
+            /// # let tmp_dir = TempDir::new("libnoise").unwrap();
+            /// # let path = &tmp_dir.path().join(path).into_os_string().into_string().unwrap();
+            /// buf.write_exr(path).unwrap();
+            /// ```
+            ///
+            /// # Errors
+            ///
+            /// Returns [`Error::InvalidParameter`] if the buffer is empty along any axis,
+            /// [`Error::Io`] if the file cannot be created or written, and [`Error::Encode`] if
+            /// the image cannot be encoded.
+            pub fn write_exr(&self, path: &str) -> Result<(), Error> {
+                validate_exr_shape(self.shape)?;
+                let channel = AnyChannel::new("Y", ($samples)(&self.buffer));
+                let layer = Layer::new(
+                    (self.shape[1], self.shape[0]),
+                    LayerAttributes::default(),
+                    Encoding::FAST_LOSSLESS,
+                    AnyChannels::sort(SmallVec::from_vec(vec![channel])),
+                );
+                Image::from_layer(layer).write().to_file(path)?;
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_write_exr!(f64, "FLOAT", |buffer: &[f64]| FlatSamples::F32(
+    buffer.iter().map(|&value| value as f32).collect()
+));
+impl_write_exr!(f32, "FLOAT", |buffer: &[f32]| FlatSamples::F32(
+    buffer.to_vec()
+));
+impl_write_exr!(f16, "HALF", |buffer: &[f16]| FlatSamples::F16(
+    buffer.to_vec()
+));
+
+fn validate_exr_shape(shape: [usize; 2]) -> Result<(), Error> {
+    if shape.contains(&0) {
+        return Err(Error::InvalidParameter {
+            name: "shape",
+            reason: format!("must be positive along every axis, got {:?}", shape),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use crate::core::{
+    error::Error,
+    utils::noisebuf::{self, NoiseBuffer},
+};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+impl<const D: usize> NoiseBuffer<D> {
+    /// Applies the given function `f` to every value of the buffer in place.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, NoiseBuffer};
+    /// let mut buf = NoiseBuffer::<2>::new([30, 20], &Source::simplex(42));
+    ///
+    /// // remap values from [-1, 1] to [0, 1]
+    /// buf.map_assign(|value| value * 0.5 + 0.5);
+    ///
+    /// assert!(buf.buffer.iter().all(|value| (0.0..=1.0).contains(value)));
+    /// ```
+    pub fn map_assign<F: Fn(f64) -> f64>(&mut self, f: F) {
+        for value in self.buffer.iter_mut() {
+            *value = f(*value);
+        }
+    }
+
+    /// Calls the given function `f` on every value of the buffer alongside its index, allowing the
+    /// value to be modified in place depending on its position.
+    ///
+    /// The values are visited in row-major order, that is, in the order in which they are stored
+    /// in the underlying flat vector, with the last index component varying fastest.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, NoiseBuffer};
+    /// let mut buf = NoiseBuffer::<2>::new([30, 20], &Source::simplex(42));
+    ///
+    /// // fade out values towards the upper edge of the first axis
+    /// buf.for_each_indexed(|[x, _], value| *value *= 1.0 - x as f64 / 30.0);
+    /// ```
+    pub fn for_each_indexed<F: FnMut([usize; D], &mut f64)>(&mut self, mut f: F) {
+        for (index, value) in noisebuf::tensor_indices(self.shape).zip(self.buffer.iter_mut()) {
+            f(index, value);
+        }
+    }
+
+    /// Calls the given function `f` on every value of the buffer alongside its index in parallel.
+    ///
+    /// This is the parallel equivalent of [`for_each_indexed()`]. The order in which values are
+    /// visited is unspecified, but for functions that only depend on the index and value passed to
+    /// them, the result is identical to that of [`for_each_indexed()`].
+    ///
+    /// [`for_each_indexed()`]: NoiseBuffer::for_each_indexed
+    #[cfg(feature = "rayon")]
+    pub fn par_for_each_indexed<F: Fn([usize; D], &mut f64) + Sync + Send>(&mut self, f: F) {
+        let shape = self.shape;
+        self.buffer
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(flat_index, value)| f(noisebuf::tensor_index(shape, flat_index), value));
+    }
+
+    /// Adds the values of `other` to the values of this buffer element-wise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ShapeMismatch`] and leaves this buffer unchanged if the shapes of the
+    /// buffers differ.
+    pub fn add_assign(&mut self, other: &Self) -> Result<(), Error> {
+        self.zip_assign(other, |a, b| a + b)
+    }
+
+    /// Multiplies the values of this buffer by the values of `other` element-wise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ShapeMismatch`] and leaves this buffer unchanged if the shapes of the
+    /// buffers differ.
+    pub fn mul_assign(&mut self, other: &Self) -> Result<(), Error> {
+        self.zip_assign(other, |a, b| a * b)
+    }
+
+    /// Replaces the values of this buffer by the minimum of the values of both buffers
+    /// element-wise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ShapeMismatch`] and leaves this buffer unchanged if the shapes of the
+    /// buffers differ.
+    pub fn min_assign(&mut self, other: &Self) -> Result<(), Error> {
+        self.zip_assign(other, f64::min)
+    }
+
+    /// Replaces the values of this buffer by the maximum of the values of both buffers
+    /// element-wise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ShapeMismatch`] and leaves this buffer unchanged if the shapes of the
+    /// buffers differ.
+    pub fn max_assign(&mut self, other: &Self) -> Result<(), Error> {
+        self.zip_assign(other, f64::max)
+    }
+
+    /// Linearly interpolates between the values of this buffer and the values of `other`
+    /// element-wise, such that a value `a` of this buffer and the corresponding value `b` of
+    /// `other` result in `a + t * (b - a)`. Thus, a `t` of 0.0 retains this buffer, while a `t` of
+    /// 1.0 results in a copy of `other`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, NoiseBuffer};
+    /// let mut buf = NoiseBuffer::<2>::new([30, 20], &Source::constant(0.2));
+    /// let other = NoiseBuffer::<2>::new([30, 20], &Source::constant(0.6));
+    ///
+    /// buf.lerp_assign(&other, 0.25)?;
+    ///
+    /// assert!((buf[[17, 9]] - 0.3).abs() < 1e-12);
+    /// # Ok::<(), libnoise::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ShapeMismatch`] and leaves this buffer unchanged if the shapes of the
+    /// buffers differ.
+    pub fn lerp_assign(&mut self, other: &Self, t: f64) -> Result<(), Error> {
+        self.zip_assign(other, |a, b| a + t * (b - a))
+    }
+
+    fn zip_assign<F: Fn(f64, f64) -> f64>(&mut self, other: &Self, f: F) -> Result<(), Error> {
+        if self.shape != other.shape {
+            return Err(Error::ShapeMismatch {
+                expected: self.shape.to_vec(),
+                found: other.shape.to_vec(),
+            });
+        }
+        for (value, &other_value) in self.buffer.iter_mut().zip(&other.buffer) {
+            *value = f(*value, other_value);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+use alloc::rc::Rc;
+#[cfg(target_has_atomic = "64")]
+use alloc::sync::Arc;
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::{cell::Cell, fmt};
+
+/// A value parameterizing an adapter, such as the frequency of [`fbm()`] or the bounds of
+/// [`clamp()`].
+///
+/// Adapters supporting tweakable parameters accept any type implementing this trait in place of
+/// [`f64`]. Plain [`f64`] values are fixed when the adapter is created, while the handles
+/// [`Param`] and [`LocalParam`] can be modified after the chain has been built, such that every
+/// subsequent sample uses the new value. This makes interactive tuning possible without
+/// reconstructing the chain. The value of a parameter is read once per sample, so a concurrent
+/// modification never affects a sample partway through.
+///
+/// Tweakable parameters are supported by [`scale()`], [`translate()`], [`clamp()`], [`select()`],
+/// [`fbm()`], [`billow()`], and [`ridgedmulti()`]. All parameters of a single adapter share the
+/// same type, so a fixed value which is to be mixed with tweakable ones is passed as a handle
+/// which is never modified.
+///
+/// [`fbm()`]: crate::Generator::fbm
+/// [`clamp()`]: crate::Generator::clamp
+/// [`scale()`]: crate::Generator::scale
+/// [`translate()`]: crate::Generator::translate
+/// [`select()`]: crate::Generator::select
+/// [`billow()`]: crate::Generator::billow
+/// [`ridgedmulti()`]: crate::Generator::ridgedmulti
+pub trait Parameter {
+    /// Whether the value never changes, which allows adapters to precompute values derived from
+    /// it, such as the normalization factor of [`fbm()`].
+    ///
+    /// [`fbm()`]: crate::Generator::fbm
+    const FIXED: bool = false;
+
+    /// Returns the current value of the parameter.
+    fn get(&self) -> f64;
+}
+
+impl Parameter for f64 {
+    const FIXED: bool = true;
+
+    #[inline]
+    fn get(&self) -> f64 {
+        *self
+    }
+}
+
+/// A parameter which can be modified after a chain has been built, and shared across threads.
+///
+/// Clones of a [`Param`] are handles to the same value, so a clone kept outside the chain
+/// modifies the value used by the chain. The value is stored as the bits of an [`f64`] in an
+/// [`AtomicU64`], such that reading it while sampling is lock-free. For details, see
+/// [`Parameter`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Param, Source, Generator};
+/// let persistence = Param::new(0.5);
+/// let generator = Source::simplex(42).fbm(
+///     6,
+///     Param::new(0.013),
+///     Param::new(2.0),
+///     persistence.clone(),
+/// );
+/// let value = generator.sample([0.2, 0.5]);
+///
+/// // tweak the chain without rebuilding it
+/// persistence.set(0.45);
+/// assert_ne!(generator.sample([0.2, 0.5]), value);
+/// ```
+#[cfg(target_has_atomic = "64")]
+#[derive(Clone, Default)]
+pub struct Param {
+    bits: Arc<AtomicU64>,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl Param {
+    /// Create a new parameter with the given initial `value`.
+    pub fn new(value: f64) -> Self {
+        Self {
+            bits: Arc::new(AtomicU64::new(value.to_bits())),
+        }
+    }
+
+    /// Returns the current value of the parameter.
+    #[inline]
+    pub fn get(&self) -> f64 {
+        f64::from_bits(self.bits.load(Ordering::Relaxed))
+    }
+
+    /// Sets the value of the parameter, affecting all subsequent samples of chains using it.
+    #[inline]
+    pub fn set(&self, value: f64) {
+        self.bits.store(value.to_bits(), Ordering::Relaxed);
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl Parameter for Param {
+    #[inline]
+    fn get(&self) -> f64 {
+        Param::get(self)
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl fmt::Debug for Param {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Param").field(&self.get()).finish()
+    }
+}
+
+/// A parameter which can be modified after a chain has been built, for use within a single
+/// thread.
+///
+/// This behaves like [`Param`], but stores its value in a [`Cell`] rather than an atomic, which
+/// avoids the synchronization overhead on platforms where it is noticeable. Chains using it can
+/// neither be sent nor shared across threads. For details, see [`Parameter`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{LocalParam, Source, Generator};
+/// let min = LocalParam::new(-1.0);
+/// let generator = Source::simplex(42).clamp(min.clone(), LocalParam::new(1.0));
+///
+/// min.set(0.0);
+/// assert!(generator.sample([0.2, 0.5]) >= 0.0);
+/// ```
+#[derive(Clone, Default)]
+pub struct LocalParam {
+    value: Rc<Cell<f64>>,
+}
+
+impl LocalParam {
+    /// Create a new parameter with the given initial `value`.
+    pub fn new(value: f64) -> Self {
+        Self {
+            value: Rc::new(Cell::new(value)),
+        }
+    }
+
+    /// Returns the current value of the parameter.
+    #[inline]
+    pub fn get(&self) -> f64 {
+        self.value.get()
+    }
+
+    /// Sets the value of the parameter, affecting all subsequent samples of chains using it.
+    #[inline]
+    pub fn set(&self, value: f64) {
+        self.value.set(value);
+    }
+}
+
+impl Parameter for LocalParam {
+    #[inline]
+    fn get(&self) -> f64 {
+        LocalParam::get(self)
+    }
+}
+
+impl fmt::Debug for LocalParam {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LocalParam").field(&self.get()).finish()
+    }
+}
+
+// parameters are serialized as their current value, and deserialized into new handles
+#[cfg(feature = "serde")]
+macro_rules! impl_serde {
+    ($param:ident) => {
+        impl serde::Serialize for $param {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_f64(self.get())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $param {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                f64::deserialize(deserializer).map(Self::new)
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "serde", target_has_atomic = "64"))]
+impl_serde!(Param);
+#[cfg(feature = "serde")]
+impl_serde!(LocalParam);
+
+// reads the current values of an array of parameters
+#[inline]
+pub(crate) fn values<const D: usize, P: Parameter>(params: &[P; D]) -> [f64; D] {
+    core::array::from_fn(|i| params[i].get())
+}
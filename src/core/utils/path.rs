@@ -0,0 +1,86 @@
+use crate::core::generator::Generator;
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use alloc::vec::Vec;
+
+pub(crate) fn sample_segment<const D: usize, G: Generator<D>>(
+    generator: &G,
+    a: [f64; D],
+    b: [f64; D],
+    samples: usize,
+) -> Vec<f64> {
+    (0..samples)
+        .map(|k| generator.sample(lerp(a, b, fraction(k, samples))))
+        .collect()
+}
+
+pub(crate) fn sample_path<const D: usize, G: Generator<D>>(
+    generator: &G,
+    points: &[[f64; D]],
+    samples: usize,
+) -> Vec<f64> {
+    assert!(!points.is_empty(), "path must contain at least one point");
+    // arc length at each point of the polyline
+    let arc_lengths = points
+        .iter()
+        .zip(points.iter().skip(1))
+        .scan(0.0, |length, (&a, &b)| {
+            *length += distance(a, b);
+            Some(*length)
+        })
+        .collect::<Vec<_>>();
+    let total_length = arc_lengths.last().copied().unwrap_or(0.0);
+    let mut segment = 0;
+    (0..samples)
+        .map(|k| {
+            if total_length == 0.0 || k == 0 {
+                return generator.sample(points[0]);
+            }
+            if k == samples - 1 {
+                return generator.sample(points[points.len() - 1]);
+            }
+            let target = total_length * fraction(k, samples);
+            // targets increase monotonically, so the segment containing them only ever advances
+            while segment + 1 < arc_lengths.len() && arc_lengths[segment] < target {
+                segment += 1;
+            }
+            let end = arc_lengths[segment];
+            let start = if segment == 0 {
+                0.0
+            } else {
+                arc_lengths[segment - 1]
+            };
+            let length = end - start;
+            let t = if length > 0.0 {
+                ((target - start) / length).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            generator.sample(lerp(points[segment], points[segment + 1], t))
+        })
+        .collect()
+}
+
+// position of the k-th of the given number of evenly spaced samples on the unit interval
+#[inline]
+fn fraction(k: usize, samples: usize) -> f64 {
+    if samples > 1 {
+        k as f64 / (samples - 1) as f64
+    } else {
+        0.0
+    }
+}
+
+#[inline]
+fn lerp<const D: usize>(a: [f64; D], b: [f64; D], t: f64) -> [f64; D] {
+    core::array::from_fn(|i| a[i] + (b[i] - a[i]) * t)
+}
+
+#[inline]
+fn distance<const D: usize>(a: [f64; D], b: [f64; D]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| (b - a) * (b - a))
+        .sum::<f64>()
+        .sqrt()
+}
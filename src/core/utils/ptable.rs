@@ -1,9 +1,13 @@
 use super::math::{Vec2, Vec3, Vec4};
+#[cfg(feature = "serde")]
+use crate::core::sources::functional::constants::PERMUTATION_TABLE_SIZE;
+use alloc::vec::Vec;
 use rand::seq::SliceRandom;
 use rand_chacha::{rand_core::SeedableRng, ChaCha12Rng};
 
 #[derive(Clone, Debug)]
 pub(crate) struct PermutationTable {
+    pub(crate) seed: u64,
     pub(crate) table: Vec<usize>,
 }
 
@@ -16,46 +20,86 @@ impl PermutationTable {
             table.extend_from_within(..);
         }
         table.shrink_to_fit();
-        Self { table }
+        Self { seed, table }
     }
 
     #[inline]
-    pub(crate) unsafe fn get(&self, i: usize) -> usize {
-        *self.table.get_unchecked(i)
+    pub(crate) fn get(&self, i: usize) -> usize {
+        *lookup(&self.table, i)
     }
 
     #[inline]
-    pub(crate) unsafe fn hash1d(&self, i: usize) -> usize {
+    pub(crate) fn hash1d(&self, i: usize) -> usize {
         self.get(i)
     }
 
     #[inline]
-    pub(crate) unsafe fn hash2d(&self, i: usize, j: usize) -> usize {
+    pub(crate) fn hash2d(&self, i: usize, j: usize) -> usize {
         self.get(j + self.get(i))
     }
 
     #[inline]
-    pub(crate) unsafe fn hash3d(&self, i: usize, j: usize, k: usize) -> usize {
+    pub(crate) fn hash3d(&self, i: usize, j: usize, k: usize) -> usize {
         self.get(k + self.get(j + self.get(i)))
     }
 
     #[inline]
-    pub(crate) unsafe fn hash4d(&self, i: usize, j: usize, k: usize, l: usize) -> usize {
+    pub(crate) fn hash4d(&self, i: usize, j: usize, k: usize, l: usize) -> usize {
         self.get(l + self.get(k + self.get(j + self.get(i))))
     }
 
     #[inline]
-    pub(crate) unsafe fn hash2d_vec(&self, value: Vec2<usize>) -> usize {
+    pub(crate) fn hash2d_vec(&self, value: Vec2<usize>) -> usize {
         self.get(value.y + self.get(value.x))
     }
 
     #[inline]
-    pub(crate) unsafe fn hash3d_vec(&self, value: Vec3<usize>) -> usize {
+    pub(crate) fn hash3d_vec(&self, value: Vec3<usize>) -> usize {
         self.get(value.z + self.get(value.y + self.get(value.x)))
     }
 
     #[inline]
-    pub(crate) unsafe fn hash4d_vec(&self, value: Vec4<usize>) -> usize {
+    pub(crate) fn hash4d_vec(&self, value: Vec4<usize>) -> usize {
         self.get(value.w + self.get(value.z + self.get(value.y + self.get(value.x))))
     }
 }
+
+// looks up an entry of a permutation table or gradient lookup table in the hot path. the sources
+// only compute indices which are in bounds by construction, such that the bounds check is
+// skipped unless the `forbid-unsafe` feature is enabled. with that feature, an index out of
+// bounds panics instead, as it does in debug builds either way.
+#[inline(always)]
+pub(crate) fn lookup<T>(table: &[T], index: usize) -> &T {
+    debug_assert!(
+        index < table.len(),
+        "table index {} out of bounds for length {}",
+        index,
+        table.len()
+    );
+    #[cfg(feature = "forbid-unsafe")]
+    {
+        &table[index]
+    }
+    // SAFETY: all indices are reduced modulo the size of the table, or are the sum of two such
+    // indices into a permutation table which is doubled up to twice its size
+    #[cfg(not(feature = "forbid-unsafe"))]
+    unsafe {
+        table.get_unchecked(index)
+    }
+}
+
+// tables are serialized as their seed, and rebuilt in the configuration used by all sources
+#[cfg(feature = "serde")]
+impl serde::Serialize for PermutationTable {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.seed)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PermutationTable {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let seed = u64::deserialize(deserializer)?;
+        Ok(Self::new(seed, PERMUTATION_TABLE_SIZE, true))
+    }
+}
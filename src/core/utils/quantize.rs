@@ -0,0 +1,79 @@
+use crate::core::utils::noisebuf::{NoiseBuffer, NoiseElement};
+use alloc::vec::Vec;
+
+impl<const D: usize, T: NoiseElement> NoiseBuffer<D, T> {
+    /// Quantizes the values of the buffer to `u8` integers.
+    ///
+    /// Values are mapped from [-1, 1] to the full range [0, 255] and truncated towards zero, such
+    /// that only 1 itself is mapped to 255. Values outside of [-1, 1] are clamped, and NaN is
+    /// mapped to 0. The returned vector has the same layout as the underlying flat vector of the
+    /// buffer.
+    ///
+    /// This is exactly the quantization used by the `Visualizer` when writing images, such that
+    /// the quantized values equal the pixels of an image created from the same buffer.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, NoiseBuffer};
+    /// let buf = NoiseBuffer::<2>::new([30, 20], &Source::simplex(42));
+    ///
+    /// let pixels = buf.quantize_u8();
+    ///
+    /// assert_eq!(pixels.len(), buf.buffer.len());
+    /// ```
+    pub fn quantize_u8(&self) -> Vec<u8> {
+        self.buffer
+            .iter()
+            .map(|value| quantize_u8(value.to_f64()))
+            .collect()
+    }
+
+    /// Quantizes the values of the buffer to `u16` integers.
+    ///
+    /// This is equivalent to [`quantize_u8()`], except that values are mapped to the full range
+    /// [0, 65535].
+    ///
+    /// [`quantize_u8()`]: NoiseBuffer::quantize_u8
+    pub fn quantize_u16(&self) -> Vec<u16> {
+        self.buffer
+            .iter()
+            .map(|value| quantize_u16(value.to_f64()))
+            .collect()
+    }
+}
+
+#[inline]
+pub(crate) fn quantize_u8(x: f64) -> u8 {
+    quantize(x, u8::MAX as f64) as u8
+}
+
+#[inline]
+pub(crate) fn quantize_u16(x: f64) -> u16 {
+    quantize(x, u16::MAX as f64) as u16
+}
+
+// maps [-1, 1] onto [0, max], clamping values outside of the range. the caller truncates the
+// result, which also maps NaN to 0
+#[inline]
+fn quantize(x: f64, max: f64) -> f64 {
+    let half = max * 0.5;
+    half + x.clamp(-1.0, 1.0) * half
+}
+
+// maps [-1, 1] onto [0, 1], clamping values outside of the range
+#[cfg(feature = "image")]
+#[inline]
+pub(crate) fn to_unit(x: f64) -> f64 {
+    (x.clamp(-1.0, 1.0) + 1.0) * 0.5
+}
+
+// quantizes a value in [0, 1] like quantize_u8() quantizes a value in [-1, 1]. apart from
+// rounding, quantize_unit_u8(to_unit(x)) equals quantize_u8(x)
+#[cfg(feature = "image")]
+#[inline]
+pub(crate) fn quantize_unit_u8(x: f64) -> u8 {
+    (x.clamp(0.0, 1.0) * u8::MAX as f64) as u8
+}
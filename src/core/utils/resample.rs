@@ -0,0 +1,225 @@
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::utils::noisebuf::{self, NoiseBuffer};
+use alloc::vec::Vec;
+
+/// The interpolation method used when sampling values between the points of a grid.
+///
+/// The variants are named after their 2-dimensional form, but generalize naturally to any
+/// number of dimensions. For example, [`Bilinear`] interpolation of a 3D buffer refers to
+/// trilinear interpolation.
+///
+/// [`Bilinear`]: Interpolation::Bilinear
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Use the value of the nearest grid point.
+    Nearest,
+    /// Linearly interpolate between the values of the adjacent grid points along each axis.
+    Bilinear,
+    /// Interpolate between the values of the four nearest grid points along each axis using a
+    /// Catmull-Rom spline.
+    Bicubic,
+}
+
+impl<const D: usize> NoiseBuffer<D> {
+    /// Creates a new noise buffer with the given `new_shape` by resampling this buffer using the
+    /// given `interpolation` method.
+    ///
+    /// The centers of the cells of the resulting buffer are mapped into the domain of this buffer
+    /// such that both buffers cover the same region. Specifically, along each axis, the index `i`
+    /// of the resulting buffer maps to the coordinate `(i + 0.5) * len / new_len - 0.5` in this
+    /// buffer, which is then interpolated. Coordinates and interpolation taps beyond the edges of
+    /// this buffer are clamped to the nearest edge.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, NoiseBuffer, Interpolation};
+    /// let buf = NoiseBuffer::<2>::new([50, 50], &Source::simplex(42).scale([0.05; 2]));
+    ///
+    /// // upscale the buffer by a factor of 4
+    /// let upscaled = buf.resample([200, 200], Interpolation::Bicubic);
+    ///
+    /// assert_eq!(upscaled.shape, [200, 200]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if this buffer has a length of zero along an axis for which `new_shape` does not.
+    pub fn resample(&self, new_shape: [usize; D], interpolation: Interpolation) -> Self {
+        for (axis, (&len, &new_len)) in self.shape.iter().zip(&new_shape).enumerate() {
+            assert!(
+                len > 0 || new_len == 0,
+                "cannot resample empty axis {} of buffer with shape {:?} to length {}",
+                axis,
+                self.shape,
+                new_len
+            );
+        }
+        let num_taps = num_taps(interpolation);
+        let mut resampled = Self::new_empty(new_shape);
+        for (index, value) in noisebuf::tensor_indices(new_shape).zip(resampled.buffer.iter_mut()) {
+            let taps: [([usize; 4], [f64; 4]); D] = core::array::from_fn(|axis| {
+                let len = self.shape[axis];
+                let coord = (index[axis] as f64 + 0.5) * len as f64 / new_shape[axis] as f64 - 0.5;
+                compute_taps(coord, len, interpolation)
+            });
+            *value = noisebuf::tensor_indices([num_taps; D])
+                .map(|tap| {
+                    let mut flat_index = 0;
+                    let mut weight = 1.0;
+                    for axis in 0..D {
+                        flat_index += taps[axis].0[tap[axis]] * self.offsets[axis];
+                        weight *= taps[axis].1[tap[axis]];
+                    }
+                    weight * self.buffer[flat_index]
+                })
+                .sum();
+        }
+        resampled
+    }
+}
+
+fn compute_taps(coord: f64, len: usize, interpolation: Interpolation) -> ([usize; 4], [f64; 4]) {
+    let max_index = len as f64 - 1.0;
+    let coord = coord.clamp(0.0, max_index);
+    let (first, weights) = tap_weights(coord, interpolation);
+    let clamp_index = |tap: usize| (first + tap as f64).clamp(0.0, max_index) as usize;
+    (core::array::from_fn(clamp_index), weights)
+}
+
+// returns the index of the first grid point contributing to the interpolated value at the given
+// coordinate, and the weights of the consecutive grid points starting at it. only the first
+// num_taps() weights are used, the remaining ones are zero
+pub(crate) fn tap_weights(coord: f64, interpolation: Interpolation) -> (f64, [f64; 4]) {
+    match interpolation {
+        Interpolation::Nearest => ((coord + 0.5).floor(), [1.0, 0.0, 0.0, 0.0]),
+        Interpolation::Bilinear => {
+            let i0 = coord.floor();
+            let t = coord - i0;
+            (i0, [1.0 - t, t, 0.0, 0.0])
+        }
+        Interpolation::Bicubic => {
+            let i0 = coord.floor();
+            let t = coord - i0;
+            let t2 = t * t;
+            let t3 = t2 * t;
+            (
+                i0 - 1.0,
+                [
+                    0.5 * (-t3 + 2.0 * t2 - t),
+                    0.5 * (3.0 * t3 - 5.0 * t2 + 2.0),
+                    0.5 * (-3.0 * t3 + 4.0 * t2 + t),
+                    0.5 * (t3 - t2),
+                ],
+            )
+        }
+    }
+}
+
+// the number of grid points along each axis contributing to an interpolated value
+pub(crate) fn num_taps(interpolation: Interpolation) -> usize {
+    match interpolation {
+        Interpolation::Nearest => 1,
+        Interpolation::Bilinear => 2,
+        Interpolation::Bicubic => 4,
+    }
+}
+
+impl<const D: usize> NoiseBuffer<D> {
+    /// Creates a new noise buffer by downsampling this buffer by the given integer `factor` using
+    /// box filtering.
+    ///
+    /// Each cell of the resulting buffer is the mean of the `factor` cells along each axis of this
+    /// buffer which it covers. The length of the resulting buffer along each axis is the length of
+    /// this buffer divided by `factor`, rounded up. If the length of this buffer is not divisible
+    /// by `factor`, the footprint of the cells at the upper edges of the resulting buffer lies
+    /// partially outside of this buffer, and the mean is computed only over the covered cells.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, NoiseBuffer};
+    /// let buf = NoiseBuffer::<2>::new([4, 3], &Source::custom(|[x, y]| x + y));
+    ///
+    /// let downsampled = buf.downsample(2);
+    ///
+    /// assert_eq!(downsampled.shape, [2, 2]);
+    /// assert_eq!(downsampled[[0, 0]], 1.0);   // mean of 0, 1, 1, 2
+    /// assert_eq!(downsampled[[1, 1]], 4.5);   // mean of 4, 5
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is zero.
+    pub fn downsample(&self, factor: usize) -> Self {
+        assert!(factor > 0, "downsampling factor must be positive");
+        self.downsample_by(factor as f64)
+    }
+
+    /// Creates a new noise buffer by downsampling this buffer by the given, possibly non-integer
+    /// `factor` using area-weighted averaging.
+    ///
+    /// This is a generalization of [`downsample()`]. Along each axis, the cell `i` of the resulting
+    /// buffer covers the interval `[i * factor, (i + 1) * factor)` of this buffer, and its value is
+    /// the mean of the covered cells of this buffer, weighted by the length of their overlap with
+    /// that interval. The length of the resulting buffer along each axis is the length of this
+    /// buffer divided by `factor`, rounded up. Parts of the interval lying outside of this buffer
+    /// are ignored.
+    ///
+    /// [`downsample()`]: NoiseBuffer::downsample
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is not a positive finite number.
+    pub fn downsample_by(&self, factor: f64) -> Self {
+        assert!(
+            factor.is_finite() && factor > 0.0,
+            "downsampling factor must be positive and finite, got {}",
+            factor
+        );
+        let new_shape = self.shape.map(|len| (len as f64 / factor).ceil() as usize);
+        let footprints: [Vec<Vec<(usize, f64)>>; D] = core::array::from_fn(|axis| {
+            (0..new_shape[axis])
+                .map(|i| compute_footprint(i, factor, self.shape[axis]))
+                .collect()
+        });
+        let mut downsampled = Self::new_empty(new_shape);
+        for (index, value) in noisebuf::tensor_indices(new_shape).zip(downsampled.buffer.iter_mut())
+        {
+            let footprint: [&Vec<(usize, f64)>; D] =
+                core::array::from_fn(|axis| &footprints[axis][index[axis]]);
+            let mut sum = 0.0;
+            let mut weight_sum = 0.0;
+            for tap in noisebuf::tensor_indices(footprint.map(|cells| cells.len())) {
+                let mut flat_index = 0;
+                let mut weight = 1.0;
+                for axis in 0..D {
+                    let (cell, cell_weight) = footprint[axis][tap[axis]];
+                    flat_index += cell * self.offsets[axis];
+                    weight *= cell_weight;
+                }
+                sum += weight * self.buffer[flat_index];
+                weight_sum += weight;
+            }
+            *value = sum / weight_sum;
+        }
+        downsampled
+    }
+}
+
+fn compute_footprint(i: usize, factor: f64, len: usize) -> Vec<(usize, f64)> {
+    let start = i as f64 * factor;
+    let end = ((i + 1) as f64 * factor).min(len as f64);
+    (start.floor() as usize..(end.ceil() as usize).min(len))
+        .map(|cell| {
+            let overlap = (end.min(cell as f64 + 1.0) - start.max(cell as f64)).max(0.0);
+            (cell, overlap)
+        })
+        .filter(|&(_, overlap)| overlap > 0.0)
+        .collect()
+}
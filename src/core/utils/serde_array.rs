@@ -0,0 +1,54 @@
+// serde only implements its traits for arrays of up to 32 elements with a literal length, so
+// arrays whose length is a constant generic parameter are serialized as tuples using this module
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+pub(crate) fn serialize<S, T, const N: usize>(
+    array: &[T; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut tuple = serializer.serialize_tuple(N)?;
+    for element in array {
+        tuple.serialize_element(element)?;
+    }
+    tuple.end()
+}
+
+pub(crate) fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+    impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVisitor<T, N> {
+        type Value = [T; N];
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "an array of length {}", N)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut elements = Vec::with_capacity(N);
+            while let Some(element) = seq.next_element()? {
+                if elements.len() == N {
+                    return Err(A::Error::invalid_length(N + 1, &self));
+                }
+                elements.push(element);
+            }
+            elements
+                .try_into()
+                .map_err(|elements: Vec<T>| A::Error::invalid_length(elements.len(), &self))
+        }
+    }
+
+    deserializer.deserialize_tuple(N, ArrayVisitor(PhantomData))
+}
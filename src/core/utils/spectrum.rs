@@ -0,0 +1,283 @@
+use crate::core::utils::noisebuf::NoiseBuffer;
+use std::{
+    f64::consts::PI,
+    ops::{Add, Mul, Sub},
+};
+
+/// Computes the radially averaged power spectrum of a 2-dimensional noise buffer.
+///
+/// The mean is removed from the buffer and a Hann window is applied along both axes to reduce
+/// leakage caused by the discontinuities at the buffer edges, before the power of the discrete
+/// Fourier transform is computed. Powers are then averaged over rings of equal radial frequency,
+/// with a ring width of one over the shorter side length of the buffer. The result contains one
+/// `(frequency, power)` pair per ring in ascending order of frequency, measured in cycles per
+/// sample, up to the Nyquist frequency of 0.5. The constant component at frequency 0 is omitted.
+///
+/// For fractal noise, the power is expected to fall off roughly as `1 / frequency^beta`, where
+/// the exponent `beta` can be estimated using [`fit_spectral_slope()`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{spectrum2d, Generator, NoiseBuffer, Source};
+/// let generator = Source::simplex(42).fbm(6, 0.05, 2.0, 0.5);
+/// let buf = NoiseBuffer::<2>::new([128, 128], &generator);
+///
+/// for (frequency, power) in spectrum2d(&buf) {
+///     println!("{:.4}: {:e}", frequency, power);
+/// }
+/// ```
+pub fn spectrum2d(buffer: &NoiseBuffer<2>) -> Vec<(f64, f64)> {
+    let [width, height] = buffer.shape;
+    let size = width.min(height);
+    if size < 2 {
+        return Vec::new();
+    }
+    let count = (width * height) as f64;
+    let mean = buffer.buffer.iter().sum::<f64>() / count;
+    let window_x = hann_window(width);
+    let window_y = hann_window(height);
+    let window_energy =
+        window_x.iter().map(|w| w * w).sum::<f64>() * window_y.iter().map(|w| w * w).sum::<f64>();
+
+    // windowed, zero-mean input in row-major order, with the last axis varying fastest
+    let mut data = buffer
+        .buffer
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let (x, y) = (i / height, i % height);
+            Complex::new((value - mean) * window_x[x] * window_y[y], 0.0)
+        })
+        .collect::<Vec<_>>();
+
+    // transform along the last axis, then along the first axis
+    for row in data.chunks_exact_mut(height) {
+        fft(row);
+    }
+    let mut column = vec![Complex::default(); width];
+    for y in 0..height {
+        for (x, value) in column.iter_mut().enumerate() {
+            *value = data[x * height + y];
+        }
+        fft(&mut column);
+        for (x, value) in column.iter().enumerate() {
+            data[x * height + y] = *value;
+        }
+    }
+
+    let rings = size / 2;
+    let mut power = vec![0.0; rings + 1];
+    let mut samples = vec![0_usize; rings + 1];
+    for (i, value) in data.iter().enumerate() {
+        let fx = signed_frequency(i / height, width);
+        let fy = signed_frequency(i % height, height);
+        let ring = ((fx * fx + fy * fy).sqrt() * size as f64).round() as usize;
+        if ring <= rings {
+            power[ring] += value.norm_sqr() / window_energy;
+            samples[ring] += 1;
+        }
+    }
+    (1..=rings)
+        .filter(|&ring| samples[ring] > 0)
+        .map(|ring| {
+            (
+                ring as f64 / size as f64,
+                power[ring] / samples[ring] as f64,
+            )
+        })
+        .collect()
+}
+
+/// Estimates the exponent `beta` of a power law `power ~ 1 / frequency^beta` fitted to a power
+/// spectrum.
+///
+/// The fit is computed by least squares linear regression of the logarithm of the power against
+/// the logarithm of the frequency, such that `beta` is the negated slope of the regression line.
+/// Entries with a frequency or power that is not positive and finite are ignored. If fewer than
+/// two distinct frequencies remain, NaN is returned. White noise has a flat spectrum with `beta`
+/// close to 0, while fractal noise typically has `beta` between 1 and 3.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::fit_spectral_slope;
+/// let spectrum = [(0.1, 100.0), (0.2, 25.0), (0.4, 6.25)];
+///
+/// assert!((fit_spectral_slope(&spectrum) - 2.0).abs() < 1e-12);
+/// ```
+pub fn fit_spectral_slope(spectrum: &[(f64, f64)]) -> f64 {
+    let points = spectrum
+        .iter()
+        .filter(|(frequency, power)| is_positive(*frequency) && is_positive(*power))
+        .map(|(frequency, power)| (frequency.ln(), power.ln()))
+        .collect::<Vec<_>>();
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let (covariance, variance) = points.iter().fold((0.0, 0.0), |(cov, var), (x, y)| {
+        (
+            cov + (x - mean_x) * (y - mean_y),
+            var + (x - mean_x) * (x - mean_x),
+        )
+    });
+    if variance == 0.0 || variance.is_nan() {
+        return f64::NAN;
+    }
+    -covariance / variance
+}
+
+#[inline]
+fn is_positive(value: f64) -> bool {
+    value.is_finite() && value > 0.0
+}
+
+#[inline]
+fn signed_frequency(index: usize, len: usize) -> f64 {
+    if index <= len / 2 {
+        index as f64 / len as f64
+    } else {
+        index as f64 / len as f64 - 1.0
+    }
+}
+
+fn hann_window(len: usize) -> Vec<f64> {
+    (0..len)
+        .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f64 / len as f64).cos())
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+impl Complex {
+    #[inline]
+    fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    #[inline]
+    fn from_angle(angle: f64) -> Self {
+        Self::new(angle.cos(), angle.sin())
+    }
+
+    #[inline]
+    fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    #[inline]
+    fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+// computes the forward discrete fourier transform in place, using the iterative radix-2
+// cooley-tukey algorithm for power of two lengths and bluestein's algorithm otherwise, such that
+// every length is transformed in O(n log n)
+fn fft(data: &mut [Complex]) {
+    let len = data.len();
+    if len <= 1 {
+        return;
+    }
+    if len.is_power_of_two() {
+        fft_radix2(data);
+    } else {
+        fft_bluestein(data);
+    }
+}
+
+// expresses the transform as a circular convolution with a chirp, zero-padded to a power of two
+// length of at least 2 * len - 1 and evaluated using the radix-2 transform
+fn fft_bluestein(data: &mut [Complex]) {
+    let len = data.len();
+    let padded = (2 * len - 1).next_power_of_two();
+    // k^2 is reduced modulo 2 * len to keep the angle small, as the chirp has this period
+    let chirp = (0..len)
+        .map(|k| Complex::from_angle(-PI * ((k * k) % (2 * len)) as f64 / len as f64))
+        .collect::<Vec<_>>();
+
+    let mut a = vec![Complex::default(); padded];
+    for ((a, x), w) in a.iter_mut().zip(data.iter()).zip(&chirp) {
+        *a = *x * *w;
+    }
+    let mut b = vec![Complex::default(); padded];
+    b[0] = chirp[0].conj();
+    for k in 1..len {
+        b[k] = chirp[k].conj();
+        b[padded - k] = chirp[k].conj();
+    }
+    fft_radix2(&mut a);
+    fft_radix2(&mut b);
+
+    // the inverse transform of the product is computed as the conjugated forward transform of
+    // the conjugated product, scaled by the inverse length
+    for (a, b) in a.iter_mut().zip(&b) {
+        *a = (*a * *b).conj();
+    }
+    fft_radix2(&mut a);
+    let scale = 1.0 / padded as f64;
+    for ((out, a), w) in data.iter_mut().zip(&a).zip(&chirp) {
+        *out = Complex::new(a.re * scale, -a.im * scale) * *w;
+    }
+}
+
+fn fft_radix2(data: &mut [Complex]) {
+    let len = data.len();
+    let bits = len.trailing_zeros();
+    for i in 0..len {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+    let mut half = 1;
+    while half < len {
+        for start in (0..len).step_by(2 * half) {
+            for i in start..start + half {
+                let twiddle = Complex::from_angle(-PI * (i - start) as f64 / half as f64);
+                let odd = data[i + half] * twiddle;
+                data[i + half] = data[i] - odd;
+                data[i] = data[i] + odd;
+            }
+        }
+        half *= 2;
+    }
+}
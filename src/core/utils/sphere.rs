@@ -0,0 +1,50 @@
+use crate::core::generator::Generator;
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::utils::noisebuf::{self, NoiseBuffer};
+use core::f64::consts::{FRAC_PI_2, PI};
+
+// maps a latitude and longitude in radians onto the sphere of the given radius, with the z-axis
+// as polar axis. the poles are mapped exactly, such that every longitude yields the same point.
+#[inline]
+pub(crate) fn sphere_point(lat: f64, lon: f64, radius: f64) -> [f64; 3] {
+    if lat >= FRAC_PI_2 {
+        return [0.0, 0.0, radius];
+    }
+    if lat <= -FRAC_PI_2 {
+        return [0.0, 0.0, -radius];
+    }
+    let (sin_lat, cos_lat) = (lat.sin(), lat.cos());
+    let (sin_lon, cos_lon) = (lon.sin(), lon.cos());
+    [
+        radius * cos_lat * cos_lon,
+        radius * cos_lat * sin_lon,
+        radius * sin_lat,
+    ]
+}
+
+pub(crate) fn sphere_to_buffer<G: Generator<3>>(
+    generator: &G,
+    width: usize,
+    height: usize,
+    radius: f64,
+) -> NoiseBuffer<2> {
+    assert!(
+        width >= 2 && height >= 2,
+        "equirectangular map must be at least 2x2, got {}x{}",
+        width,
+        height
+    );
+    let mut noisebuf = NoiseBuffer::new_empty([height, width]);
+    for ([row, column], value) in
+        noisebuf::tensor_indices([height, width]).zip(&mut noisebuf.buffer)
+    {
+        let lat = FRAC_PI_2 - PI * (row as f64 / (height - 1) as f64);
+        // the last column lies on the seam, which is sampled at the longitude of the first
+        // column, as the trigonometric functions do not agree exactly at -pi and pi
+        let column = if column == width - 1 { 0 } else { column };
+        let lon = 2.0 * PI * (column as f64 / (width - 1) as f64) - PI;
+        *value = generator.sample(sphere_point(lat, lon, radius));
+    }
+    noisebuf
+}
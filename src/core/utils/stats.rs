@@ -0,0 +1,224 @@
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::{
+    generator::Generator,
+    utils::{
+        fingerprint,
+        noisebuf::{NoiseBuffer, NoiseElement},
+    },
+};
+use alloc::{format, string::String, vec, vec::Vec};
+use core::fmt;
+
+/// Summary statistics of a set of noise values.
+///
+/// This is computed by [`NoiseBuffer::stats()`] or, without materializing a buffer, as part of
+/// [`Generator::analyze()`]. NaN values are skipped. The standard deviation is the population
+/// standard deviation, that is, the squared deviations from the mean are divided by the number of
+/// values. If there are no values, `min` is infinity, `max` is negative infinity, and `mean` and
+/// `std_dev` are NaN.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    /// The smallest value.
+    pub min: f64,
+    /// The largest value.
+    pub max: f64,
+    /// The arithmetic mean of the values.
+    pub mean: f64,
+    /// The population standard deviation of the values.
+    pub std_dev: f64,
+}
+
+/// The distribution of the results of a generator on a regular grid.
+///
+/// This is created by [`Generator::analyze()`]. Its [`Display`] implementation prints the
+/// summary statistics followed by an ASCII histogram, which is convenient for quick inspection in
+/// a terminal.
+///
+/// [`Display`]: fmt::Display
+#[derive(Clone, Debug, PartialEq)]
+pub struct Analysis {
+    /// The summary statistics of the sampled values.
+    pub stats: Stats,
+    /// The histogram of the sampled values, as described in [`NoiseBuffer::histogram()`].
+    pub histogram: Vec<u64>,
+}
+
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "min: {}, max: {}, mean: {}, std_dev: {}",
+            self.stats.min, self.stats.max, self.stats.mean, self.stats.std_dev
+        )?;
+        f.write_str(&ascii_histogram(&self.histogram, 60))
+    }
+}
+
+/// Renders a histogram as produced by [`NoiseBuffer::histogram()`] as ASCII art.
+///
+/// Every bin is printed on its own line, starting with the interval covered by the bin and
+/// followed by a bar of `#` characters and the count. The longest bar, which belongs to the bin
+/// with the highest count, has a length of `width` characters.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{ascii_histogram, Generator, NoiseBuffer, Source};
+/// let buf = NoiseBuffer::<2>::new([100, 100], &Source::simplex(42).scale([0.05; 2]));
+///
+/// println!("{}", ascii_histogram(&buf.histogram(20), 60));
+/// ```
+pub fn ascii_histogram(histogram: &[u64], width: usize) -> String {
+    let max_count = histogram.iter().copied().max().unwrap_or(0).max(1);
+    let bin_width = 2.0 / histogram.len() as f64;
+    histogram
+        .iter()
+        .enumerate()
+        .map(|(bin, &count)| {
+            let lower = -1.0 + bin as f64 * bin_width;
+            let bar = (count as f64 / max_count as f64 * width as f64).round() as usize;
+            format!(
+                "[{:+.3}, {:+.3}) {} {}\n",
+                lower,
+                lower + bin_width,
+                "#".repeat(bar),
+                count
+            )
+        })
+        .collect()
+}
+
+impl<const D: usize, T: NoiseElement> NoiseBuffer<D, T> {
+    /// Computes summary statistics of the values in the buffer.
+    ///
+    /// See [`Stats`] for how the statistics are defined.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, NoiseBuffer};
+    /// let buf = NoiseBuffer::<2>::new([30, 20], &Source::constant(0.5));
+    /// let stats = buf.stats();
+    ///
+    /// assert_eq!((stats.min, stats.max, stats.mean, stats.std_dev), (0.5, 0.5, 0.5, 0.0));
+    /// ```
+    pub fn stats(&self) -> Stats {
+        let mut accumulator = StatsAccumulator::new();
+        for value in &self.buffer {
+            accumulator.push(value.to_f64());
+        }
+        accumulator.finish()
+    }
+
+    /// Counts the values of the buffer falling into each of `bins` equally sized intervals
+    /// dividing the nominal noise range [-1, 1].
+    ///
+    /// Bin `i` covers the half-open interval starting at `-1 + i * 2 / bins`, except that the
+    /// last bin also includes 1. Values outside of [-1, 1] are counted in the first or last bin
+    /// respectively, and NaN values are skipped.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, NoiseBuffer};
+    /// let buf = NoiseBuffer::<2>::new([30, 20], &Source::simplex(42));
+    /// let histogram = buf.histogram(10);
+    ///
+    /// assert_eq!(histogram.iter().sum::<u64>(), 30 * 20);
+    /// ```
+    pub fn histogram(&self, bins: usize) -> Vec<u64> {
+        let mut histogram = vec![0; bins];
+        for value in &self.buffer {
+            add_to_histogram(&mut histogram, value.to_f64());
+        }
+        histogram
+    }
+}
+
+pub(crate) fn analyze<const D: usize, G: Generator<D>>(
+    generator: &G,
+    region_min: [f64; D],
+    region_max: [f64; D],
+    samples_per_axis: usize,
+    bins: usize,
+) -> Analysis {
+    let mut accumulator = StatsAccumulator::new();
+    let mut histogram = vec![0; bins];
+    for (_, point) in fingerprint::grid_points(region_min, region_max, samples_per_axis) {
+        let value = generator.sample(point);
+        accumulator.push(value);
+        add_to_histogram(&mut histogram, value);
+    }
+    Analysis {
+        stats: accumulator.finish(),
+        histogram,
+    }
+}
+
+#[inline]
+fn add_to_histogram(histogram: &mut [u64], value: f64) {
+    if value.is_nan() || histogram.is_empty() {
+        return;
+    }
+    let bins = histogram.len();
+    let bin = ((value + 1.0) * 0.5 * bins as f64).clamp(0.0, (bins - 1) as f64) as usize;
+    histogram[bin] += 1;
+}
+
+// computes the mean and variance in a single pass using welford's algorithm
+struct StatsAccumulator {
+    count: u64,
+    min: f64,
+    max: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl StatsAccumulator {
+    fn new() -> Self {
+        Self {
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.count += 1;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    fn finish(self) -> Stats {
+        if self.count == 0 {
+            return Stats {
+                min: self.min,
+                max: self.max,
+                mean: f64::NAN,
+                std_dev: f64::NAN,
+            };
+        }
+        Stats {
+            min: self.min,
+            max: self.max,
+            mean: self.mean,
+            std_dev: (self.m2 / self.count as f64).sqrt(),
+        }
+    }
+}
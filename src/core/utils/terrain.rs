@@ -0,0 +1,91 @@
+#[cfg(not(feature = "std"))]
+use crate::core::utils::math::float::FloatExt;
+use crate::core::utils::noisebuf::{self, NoiseBuffer};
+
+impl NoiseBuffer<2> {
+    /// Computes the slope of the height field represented by the buffer.
+    ///
+    /// Returns a buffer of the same shape, where each value is the magnitude of the gradient of
+    /// the height field at the respective index, in units of rise over run. The buffer is
+    /// interpreted as a grid with a spacing of `cell_size` between values along both axes. The
+    /// gradient is computed using central differences, and one-sided differences at the edges of
+    /// the buffer. Along axes of length 1, the gradient is zero.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, NoiseBuffer};
+    /// // a plane rising by 3 units per cell along the first axis
+    /// let buf = NoiseBuffer::<2>::new([10, 10], &Source::custom(|[x, _]| 3.0 * x));
+    /// let slope = buf.slope(2.0);
+    ///
+    /// assert_eq!(slope[[4, 7]], 1.5);
+    /// ```
+    pub fn slope(&self, cell_size: f64) -> Self {
+        self.map_gradient(cell_size, |[dx, dy]| (dx * dx + dy * dy).sqrt())
+    }
+
+    /// Computes the aspect of the height field represented by the buffer.
+    ///
+    /// Returns a buffer of the same shape, where each value is the direction of steepest descent
+    /// at the respective index, that is, the direction the terrain is facing. The direction is
+    /// given as an angle in radians in the range `(-pi, pi]`, measured from the first axis of the
+    /// buffer towards the second, such that an angle of 0 means the terrain falls towards
+    /// increasing first indices, and an angle of `pi/2` means it falls towards increasing second
+    /// indices. The gradient is computed as for [`slope()`]. Flat cells, where the gradient is
+    /// exactly zero, have no defined direction and are set to NaN.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, NoiseBuffer};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// // a plane rising along the second axis, thus falling towards decreasing second indices
+    /// let buf = NoiseBuffer::<2>::new([10, 10], &Source::custom(|[_, y]| 0.5 * y));
+    /// let aspect = buf.aspect(1.0);
+    ///
+    /// assert_eq!(aspect[[4, 7]], -FRAC_PI_2);
+    ///
+    /// // flat terrain does not face any direction
+    /// let flat = NoiseBuffer::<2>::new([10, 10], &Source::constant(0.3));
+    /// assert!(flat.aspect(1.0).buffer.iter().all(|value| value.is_nan()));
+    /// ```
+    ///
+    /// [`slope()`]: NoiseBuffer::slope
+    pub fn aspect(&self, cell_size: f64) -> Self {
+        self.map_gradient(cell_size, |[dx, dy]| {
+            if dx == 0.0 && dy == 0.0 {
+                f64::NAN
+            } else {
+                // subtracting from zero avoids negative zeros, for which a descent towards
+                // decreasing first indices would be mapped to -pi rather than pi
+                (0.0 - dy).atan2(0.0 - dx)
+            }
+        })
+    }
+
+    fn map_gradient<F: Fn([f64; 2]) -> f64>(&self, cell_size: f64, f: F) -> Self {
+        let mut result = Self::new_empty(self.shape);
+        for (index, value) in noisebuf::tensor_indices(self.shape).zip(result.buffer.iter_mut()) {
+            *value = f([0, 1].map(|axis| self.difference(index, axis, cell_size)));
+        }
+        result
+    }
+
+    // the derivative of the height field along the given axis, using central differences where
+    // possible and one-sided differences at the edges
+    fn difference(&self, index: [usize; 2], axis: usize, cell_size: f64) -> f64 {
+        let (mut low, mut high) = (index, index);
+        low[axis] = index[axis].saturating_sub(1);
+        high[axis] = (index[axis] + 1).min(self.shape[axis] - 1);
+        let steps = high[axis] - low[axis];
+        if steps == 0 {
+            return 0.0;
+        }
+        (self[high] - self[low]) / (steps as f64 * cell_size)
+    }
+}
@@ -1,13 +1,18 @@
+use crate::core::error::Error;
 use crate::core::generator::Generator;
-use crate::core::utils::noisebuf::NoiseBuffer;
+use crate::core::utils::{
+    noisebuf::{NoiseBuffer, NoiseElement},
+    noisebufview::NoiseBufferView,
+    quantize::{quantize_u8, quantize_unit_u8, to_unit},
+};
 use image::{
     codecs::gif::{GifEncoder, Repeat},
-    ColorType, GrayImage, ImageError,
+    ColorType, GrayImage, ImageBuffer, ImageFormat, Luma, Rgb, RgbImage,
 };
 use itertools::Itertools;
 use std::{
     fs::OpenOptions,
-    io::Error,
+    io::Cursor,
     ops::{Index, IndexMut},
 };
 
@@ -88,17 +93,140 @@ impl<const D: usize> IndexMut<&[usize]> for Visualizer<D> {
     }
 }
 
-impl<const D: usize> From<NoiseBuffer<D>> for Visualizer<D> {
-    fn from(noisebuf: NoiseBuffer<D>) -> Self {
+impl<const D: usize, T: NoiseElement> From<NoiseBuffer<D, T>> for Visualizer<D> {
+    fn from(noisebuf: NoiseBuffer<D, T>) -> Self {
+        Self::from_buffer_with_range(noisebuf, (-1.0, 1.0))
+    }
+}
+
+impl<const D: usize> From<NoiseBufferView<'_, D>> for Visualizer<D> {
+    fn from(view: NoiseBufferView<'_, D>) -> Self {
+        view.to_buffer().into()
+    }
+}
+
+impl<const D: usize> Visualizer<D> {
+    /// Creates a new [`Visualizer`] from a [`NoiseBuffer`], mapping the given value `range`
+    /// rather than [-1, 1] to the full range of grayscale values.
+    ///
+    /// This is useful for visualizing generators whose results are not in [-1, 1], in which case
+    /// the range can be obtained from [`Generator::output_bounds()`]. Values outside of the range
+    /// are clamped. If the range is empty or not finite, [-1, 1] is used instead.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, NoiseBuffer, Visualizer, Generator};
+    /// let generator = Source::<2>::worley(42).add(3.0);
+    /// let buf = NoiseBuffer::<2>::new([30, 20], &generator);
+    ///
+    /// let visualizer = Visualizer::from_buffer_with_range(buf, generator.output_bounds());
+    /// ```
+    pub fn from_buffer_with_range<T: NoiseElement>(
+        noisebuf: NoiseBuffer<D, T>,
+        range: (f64, f64),
+    ) -> Self {
+        let (offset, scale) = range_mapping(range);
         Self {
             shape: noisebuf.shape,
             offsets: noisebuf.offsets,
-            pixel_buffer: noisebuf.buffer.into_iter().map(norm_to_u8).collect(),
+            pixel_buffer: noisebuf
+                .buffer
+                .into_iter()
+                .map(|value| quantize_u8((value.to_f64() - offset) * scale - 1.0))
+                .collect(),
         }
     }
-}
 
-impl<const D: usize> Visualizer<D> {
+    /// Creates a new [`Visualizer`] from a [`NoiseBuffer`] like [`from_buffer_with_range()`],
+    /// applying the given transfer function to the normalized values before quantizing them.
+    ///
+    /// The transfer function is applied after the values are mapped onto [0, 1], where 0 and 1
+    /// correspond to black and white respectively, and before they are quantized to grayscale
+    /// values. This allows revealing structure hidden by a linear mapping, such as values
+    /// concentrated near the lower end of the range with rare large spikes, as is typical for
+    /// ridged noise or absolute differences. Results outside of [0, 1] are clamped, and NaN is
+    /// mapped to black as usual.
+    ///
+    /// The transfer function can either be a closure mapping each value independently, or one of
+    /// the built-in [`Transfer`] functions. Unlike [`with_transfer()`], it sees the values at
+    /// full precision, which only exist while the visualizer is built.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, NoiseBuffer, Visualizer, Transfer, Generator};
+    /// let generator = Source::<2>::simplex(42).ridgedmulti(5, 0.05, 2.0, 0.5);
+    /// let buf = NoiseBuffer::<2>::new([30, 20], &generator);
+    ///
+    /// // apply a gamma curve
+    /// let visualizer =
+    ///     Visualizer::from_buffer_with_transfer(buf.clone(), (-1.0, 1.0), |x: f64| x.powf(0.5));
+    ///
+    /// // spread the values evenly across all grayscale values
+    /// let visualizer =
+    ///     Visualizer::from_buffer_with_transfer(buf, (-1.0, 1.0), Transfer::Equalize);
+    /// ```
+    ///
+    /// [`from_buffer_with_range()`]: Visualizer::from_buffer_with_range
+    /// [`with_transfer()`]: Visualizer::with_transfer
+    pub fn from_buffer_with_transfer<T: NoiseElement, F: TransferFunction>(
+        noisebuf: NoiseBuffer<D, T>,
+        range: (f64, f64),
+        transfer: F,
+    ) -> Self {
+        let (offset, scale) = range_mapping(range);
+        let mut values = noisebuf
+            .buffer
+            .into_iter()
+            .map(|value| to_unit((value.to_f64() - offset) * scale - 1.0))
+            .collect::<Vec<_>>();
+        transfer.apply(&mut values);
+        Self {
+            shape: noisebuf.shape,
+            offsets: noisebuf.offsets,
+            pixel_buffer: values.into_iter().map(quantize_unit_u8).collect(),
+        }
+    }
+
+    /// Applies the given transfer function to the pixels of the visualizer.
+    ///
+    /// Each pixel is mapped onto [0, 1] as its grayscale value divided by 255, passed through the
+    /// transfer function like in [`from_buffer_with_transfer()`], and quantized anew. Pixels
+    /// modified through indexing are taken into account, and applying several transfer functions
+    /// composes them in order. As the visualizer only stores the quantized pixels, details finer
+    /// than one grayscale value are lost before the transfer function is applied. To apply it to
+    /// the values at full precision, use [`from_buffer_with_transfer()`] instead.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, NoiseBuffer, Visualizer, Transfer, Generator};
+    /// let generator = Source::<2>::simplex(42).fbm(5, 0.05, 2.0, 0.5);
+    /// let visualizer = Visualizer::<2>::new([30, 20], &generator);
+    ///
+    /// // invert the image
+    /// let visualizer = visualizer.with_transfer(|x: f64| 1.0 - x);
+    /// ```
+    ///
+    /// [`from_buffer_with_transfer()`]: Visualizer::from_buffer_with_transfer
+    pub fn with_transfer<F: TransferFunction>(mut self, transfer: F) -> Self {
+        let mut values = self
+            .pixel_buffer
+            .iter()
+            .map(|&pixel| pixel as f64 / u8::MAX as f64)
+            .collect::<Vec<_>>();
+        transfer.apply(&mut values);
+        self.pixel_buffer = values.into_iter().map(quantize_unit_u8).collect();
+        self
+    }
+
     fn flat_index(&self, index: &[usize]) -> usize {
         index
             .iter()
@@ -116,14 +244,42 @@ impl Visualizer<1> {
         NoiseBuffer::<1>::new(shape, generator).into()
     }
 
+    /// Creates a grayscale image visualizing the output of the provided generator, as written
+    /// by [`write_to_file()`]. For further detail see the [struct-level documentation](Visualizer).
+    ///
+    /// [`write_to_file()`]: Visualizer::<1>::write_to_file
+    pub fn to_image(&self) -> GrayImage {
+        GrayImage::from_raw(self.shape[0] as u32, 1, self.pixel_buffer.clone()).unwrap()
+    }
+
     /// Write a PNG file to the given `path`, visualizing the output of the provided
     /// generator. For further detail see the [struct-level documentation](Visualizer).
-    pub fn write_to_file(&self, path: &str) -> Result<(), ImageError> {
-        let image =
-            GrayImage::from_raw(self.shape[0] as u32, 1, self.pixel_buffer.clone()).unwrap();
-        image.save(path)?;
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the visualizer is empty along any axis,
+    /// [`Error::Io`] if the file cannot be written, and [`Error::Encode`] if the image cannot be
+    /// encoded.
+    pub fn write_to_file(&self, path: &str) -> Result<(), Error> {
+        validate_image_shape(&self.shape)?;
+        self.to_image().save(path)?;
         Ok(())
     }
+
+    /// Encodes a PNG file visualizing the output of the provided generator, as written by
+    /// [`write_to_file()`], into memory.
+    ///
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the visualizer is empty along any axis, and
+    /// [`Error::Encode`] if the image cannot be encoded.
+    ///
+    /// [`write_to_file()`]: Visualizer::<1>::write_to_file
+    pub fn write_to_vec(&self) -> Result<Vec<u8>, Error> {
+        validate_image_shape(&self.shape)?;
+        encode_png(&self.to_image())
+    }
 }
 
 impl Visualizer<2> {
@@ -134,14 +290,116 @@ impl Visualizer<2> {
         NoiseBuffer::<2>::new(shape, generator).into()
     }
 
-    pub fn write_to_file(&self, path: &str) -> Result<(), ImageError> {
-        let image = GrayImage::from_raw(
+    /// Creates a grayscale image visualizing the output of the provided generator, as written
+    /// by [`write_to_file()`]. For further detail see the [struct-level documentation](Visualizer).
+    ///
+    /// [`write_to_file()`]: Visualizer::<2>::write_to_file
+    pub fn to_image(&self) -> GrayImage {
+        GrayImage::from_raw(
             self.shape[1] as u32,
             self.shape[0] as u32,
             self.pixel_buffer.clone(),
         )
-        .unwrap();
-        image.save(path)?;
+        .unwrap()
+    }
+
+    /// Write a PNG file to the given `path`, visualizing the output of the provided
+    /// generator. For further detail see the [struct-level documentation](Visualizer).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the visualizer is empty along any axis,
+    /// [`Error::Io`] if the file cannot be written, and [`Error::Encode`] if the image cannot be
+    /// encoded.
+    pub fn write_to_file(&self, path: &str) -> Result<(), Error> {
+        validate_image_shape(&self.shape)?;
+        self.to_image().save(path)?;
+        Ok(())
+    }
+
+    /// Encodes a PNG file visualizing the output of the provided generator, as written by
+    /// [`write_to_file()`], into memory.
+    ///
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the visualizer is empty along any axis, and
+    /// [`Error::Encode`] if the image cannot be encoded.
+    ///
+    /// [`write_to_file()`]: Visualizer::<2>::write_to_file
+    pub fn write_to_vec(&self) -> Result<Vec<u8>, Error> {
+        validate_image_shape(&self.shape)?;
+        encode_png(&self.to_image())
+    }
+
+    /// Creates a color image visualizing the signed difference `a - b` of the outputs of two
+    /// generators, sampled like [`new()`].
+    ///
+    /// The image uses a diverging colormap which is symmetric around zero and scaled to the
+    /// largest finite difference: pixels where both generators agree are white, pixels where `a`
+    /// exceeds `b` are shaded towards red, and pixels where `b` exceeds `a` are shaded towards
+    /// blue. Pixels where the difference is not finite, such as where exactly one of the
+    /// generators returns NaN, are black. To quantify the difference instead, see
+    /// [`Generator::compare()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, Visualizer};
+    /// let generator = Source::simplex(42).fbm(3, 0.013, 2.0, 0.5);
+    /// let modified = generator.clone().clamp(-0.5, 0.5);
+    ///
+    /// let image = Visualizer::<2>::difference_image([100, 100], &generator, &modified);
+    /// ```
+    ///
+    /// [`new()`]: Visualizer::<2>::new
+    pub fn difference_image<GA, GB>(shape: [usize; 2], a: &GA, b: &GB) -> RgbImage
+    where
+        GA: Generator<2>,
+        GB: Generator<2>,
+    {
+        let buffer_a = NoiseBuffer::<2>::new(shape, a);
+        let buffer_b = NoiseBuffer::<2>::new(shape, b);
+        let differences = buffer_a
+            .buffer
+            .iter()
+            .zip(&buffer_b.buffer)
+            .map(|(&a, &b)| signed_difference(a, b))
+            .collect::<Vec<_>>();
+        let scale = differences
+            .iter()
+            .filter(|diff| diff.is_finite())
+            .fold(0.0_f64, |max, diff| max.max(diff.abs()));
+        let pixels = differences
+            .iter()
+            .flat_map(|&diff| diverging_color(diff, scale).0)
+            .collect();
+        RgbImage::from_raw(shape[1] as u32, shape[0] as u32, pixels).unwrap()
+    }
+
+    /// Write a PNG file to the given `path`, visualizing the signed difference of the outputs
+    /// of two generators, as created by [`difference_image()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if `shape` is empty along any axis, [`Error::Io`] if
+    /// the file cannot be written, and [`Error::Encode`] if the image cannot be encoded.
+    ///
+    /// [`difference_image()`]: Visualizer::<2>::difference_image
+    pub fn write_difference_to_file<GA, GB>(
+        shape: [usize; 2],
+        a: &GA,
+        b: &GB,
+        path: &str,
+    ) -> Result<(), Error>
+    where
+        GA: Generator<2>,
+        GB: Generator<2>,
+    {
+        validate_image_shape(&shape)?;
+        Self::difference_image(shape, a, b).save(path)?;
         Ok(())
     }
 }
@@ -154,26 +412,59 @@ impl Visualizer<3> {
         NoiseBuffer::<3>::new(shape, generator).into()
     }
 
+    /// Creates a grayscale image visualizing the output of the provided generator, as written
+    /// by [`write_to_file()`]. For further detail see the [struct-level documentation](Visualizer).
+    ///
+    /// [`write_to_file()`]: Visualizer::<3>::write_to_file
+    pub fn to_image(&self) -> GrayImage {
+        isometric_image([self.shape[0], self.shape[1], self.shape[2]], |x, y, z| {
+            self[&[x, y, z]]
+        })
+    }
+
     /// Write a PNG file to the given `path`, visualizing the output of the provided
     /// generator. For further detail see the [struct-level documentation](Visualizer).
-    pub fn write_to_file(&self, path: &str) -> Result<(), ImageError> {
-        let scale = 0.45;
-        let center = (self.shape[0] as f64 * 0.5, self.shape[1] as f64 * 0.5);
-        let mut buf = vec![0; self.shape[0] * self.shape[1]];
-        for z_idx in (0..self.shape[2]).rev() {
-            for p in tensor_indices(&[self.shape[0], self.shape[1]]) {
-                if let Some(buf_idx) =
-                    xyz_screen_to_buff_indices(p[0], p[1], z_idx, center.0, center.1, scale)
-                {
-                    buf[p[0] * self.shape[1] + p[1]] = self[&[buf_idx.0, buf_idx.1, buf_idx.2]];
-                }
-            }
-        }
-
-        let image = GrayImage::from_raw(self.shape[1] as u32, self.shape[0] as u32, buf).unwrap();
-        image.save(path)?;
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the visualizer is empty along any axis,
+    /// [`Error::Io`] if the file cannot be written, and [`Error::Encode`] if the image cannot be
+    /// encoded.
+    pub fn write_to_file(&self, path: &str) -> Result<(), Error> {
+        validate_image_shape(&self.shape)?;
+        self.to_image().save(path)?;
         Ok(())
     }
+
+    /// Encodes a PNG file visualizing the output of the provided generator, as written by
+    /// [`write_to_file()`], into memory.
+    ///
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the visualizer is empty along any axis, and
+    /// [`Error::Encode`] if the image cannot be encoded.
+    ///
+    /// [`write_to_file()`]: Visualizer::<3>::write_to_file
+    pub fn write_to_vec(&self) -> Result<Vec<u8>, Error> {
+        validate_image_shape(&self.shape)?;
+        encode_png(&self.to_image())
+    }
+}
+
+impl<T: NoiseElement> NoiseBuffer<2, T> {
+    /// Creates a 16-bit grayscale image from the buffer, with values quantized as done by
+    /// [`quantize_u16()`]. The first axis of the buffer maps to the rows of the image.
+    ///
+    /// [`quantize_u16()`]: NoiseBuffer::quantize_u16
+    pub fn to_luma16(&self) -> ImageBuffer<Luma<u16>, Vec<u16>> {
+        ImageBuffer::from_raw(
+            self.shape[1] as u32,
+            self.shape[0] as u32,
+            self.quantize_u16(),
+        )
+        .unwrap()
+    }
 }
 
 impl Visualizer<4> {
@@ -184,49 +475,222 @@ impl Visualizer<4> {
         NoiseBuffer::<4>::new(shape, generator).into()
     }
 
+    /// Creates one grayscale image per step along the 4th dimension, each visualizing the
+    /// output of the provided generator like [`Visualizer::<3>::to_image()`], which are written
+    /// as the frames of the animation by [`write_to_file()`].
+    ///
+    /// As the 4D visualization is an animation rather than a single image, there is no
+    /// `to_image()` method in the 4D case.
+    ///
+    /// [`write_to_file()`]: Visualizer::<4>::write_to_file
+    pub fn to_images(&self) -> Vec<GrayImage> {
+        (0..self.shape[3])
+            .map(|t| {
+                isometric_image([self.shape[0], self.shape[1], self.shape[2]], |x, y, z| {
+                    self[&[x, y, z, t]]
+                })
+            })
+            .collect()
+    }
+
     /// Write a GIF file to the given `path`, visualizing the output of the provided
     /// generator. For further detail see the [struct-level documentation](Visualizer).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the visualizer is empty along any axis,
+    /// [`Error::Io`] if the file cannot be written, and [`Error::Encode`] if the image cannot be
+    /// encoded.
     pub fn write_to_file(&self, path: &str) -> Result<(), Error> {
-        let file_out = OpenOptions::new().write(true).create(true).open(path)?;
+        validate_image_shape(&self.shape)?;
+        let file_out = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
 
         let mut encoder = GifEncoder::new(file_out);
-        encoder.set_repeat(Repeat::Infinite).unwrap();
-
-        let scale = 0.45;
-        let center = (self.shape[0] as f64 * 0.5, self.shape[1] as f64 * 0.5);
-        for t in 0..self.shape[3] {
-            let mut buf = vec![0; self.shape[0] * self.shape[1]];
-            for z_idx in (0..self.shape[2]).rev() {
-                for p in tensor_indices(&[self.shape[0], self.shape[1]]) {
-                    if let Some(buf_idx) =
-                        xyz_screen_to_buff_indices(p[0], p[1], z_idx, center.0, center.1, scale)
-                    {
-                        buf[p[0] * self.shape[0] + p[1]] =
-                            self[&[buf_idx.0, buf_idx.1, buf_idx.2, t]];
+        encoder.set_repeat(Repeat::Infinite)?;
+
+        for frame in self.to_images() {
+            let buf = frame
+                .as_raw()
+                .iter()
+                .flat_map(|&val| std::iter::repeat_n(val, 3))
+                .collect::<Vec<_>>();
+
+            encoder.encode(&buf, frame.width(), frame.height(), ColorType::Rgb8)?;
+        }
+        Ok(())
+    }
+}
+
+/// A function applied to the normalized values of a [`Visualizer`] before quantization.
+///
+/// This trait is implemented for closures mapping each value independently, and for the
+/// built-in [`Transfer`] functions. For further detail see
+/// [`Visualizer::from_buffer_with_transfer()`].
+pub trait TransferFunction {
+    /// Maps the given `values` in place. The values are in [0, 1] or NaN.
+    fn apply(&self, values: &mut [f64]);
+}
+
+impl<F: Fn(f64) -> f64> TransferFunction for F {
+    fn apply(&self, values: &mut [f64]) {
+        for value in values {
+            *value = self(*value);
+        }
+    }
+}
+
+/// Built-in transfer functions for use with [`Visualizer::from_buffer_with_transfer()`] and
+/// [`Visualizer::with_transfer()`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, NoiseBuffer, Visualizer, Transfer, Generator};
+/// let generator = Source::<2>::worley(42).mul(0.1).add(-0.9);
+/// let buf = NoiseBuffer::<2>::new([30, 20], &generator);
+///
+/// let visualizer = Visualizer::from_buffer_with_transfer(buf, (-1.0, 1.0), Transfer::Log(0.01));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Transfer {
+    /// Logarithmic scaling, mapping `x` to `ln(1 + x / epsilon) / ln(1 + 1 / epsilon)`.
+    ///
+    /// This keeps 0 and 1 in place while brightening small values, where a smaller `epsilon`
+    /// brightens them more strongly.
+    ///
+    /// # Panics
+    ///
+    /// Applying this function panics if `epsilon` is not positive and finite.
+    Log(f64),
+    /// Histogram equalization, mapping each value to its rank among all values of the
+    /// visualizer, such that the grayscale values are distributed approximately uniformly.
+    ///
+    /// Each value is mapped to the center of its range of the cumulative distribution, which is
+    /// `(i + 0.5) / n` for the `i`-th smallest of `n` distinct values. Equal values share the
+    /// same result, and NaN values are ignored.
+    Equalize,
+}
+
+impl TransferFunction for Transfer {
+    #[track_caller]
+    fn apply(&self, values: &mut [f64]) {
+        match *self {
+            Transfer::Log(epsilon) => {
+                assert!(
+                    epsilon > 0.0 && epsilon.is_finite(),
+                    "epsilon must be positive and finite, got {}",
+                    epsilon
+                );
+                let norm = (1.0 / epsilon).ln_1p();
+                (|x: f64| (x / epsilon).ln_1p() / norm).apply(values);
+            }
+            Transfer::Equalize => {
+                // the first pass collects the distribution of the values, which the second pass
+                // looks up the rank of each value in
+                let mut sorted = values
+                    .iter()
+                    .copied()
+                    .filter(|x| !x.is_nan())
+                    .collect::<Vec<_>>();
+                sorted.sort_unstable_by(f64::total_cmp);
+                let len = sorted.len() as f64;
+                (|x: f64| {
+                    if x.is_nan() {
+                        return x;
                     }
-                }
+                    let below = sorted.partition_point(|&y| y < x);
+                    let up_to = sorted.partition_point(|&y| y <= x);
+                    (below + up_to) as f64 * 0.5 / len
+                })
+                .apply(values);
             }
+        }
+    }
+}
 
-            buf = buf
-                .into_iter()
-                .flat_map(|val| std::iter::repeat(val).take(3))
-                .collect();
-
-            encoder
-                .encode(
-                    &buf,
-                    self.shape[0] as u32,
-                    self.shape[1] as u32,
-                    ColorType::Rgb8,
-                )
-                .unwrap();
+// the offset and scale mapping the given range onto [-1, 1], or the identity if the range is
+// empty or not finite
+fn range_mapping((lower, upper): (f64, f64)) -> (f64, f64) {
+    if lower.is_finite() && upper.is_finite() && lower < upper {
+        (lower, 2.0 / (upper - lower))
+    } else {
+        (-1.0, 1.0)
+    }
+}
+
+// images must have a positive size fitting into 32 bits along each axis
+pub(crate) fn validate_image_shape(shape: &[usize]) -> Result<(), Error> {
+    if shape
+        .iter()
+        .any(|&dim_size| dim_size == 0 || dim_size > u32::MAX as usize)
+    {
+        return Err(Error::InvalidParameter {
+            name: "shape",
+            reason: format!(
+                "must be positive and fit into 32 bits along every axis, got {:?}",
+                shape
+            ),
+        });
+    }
+    Ok(())
+}
+
+// the difference a - b, considering equal values, including infinities and NaNs, to not differ
+// at all, and NaNs to differ by NaN from anything else
+fn signed_difference(a: f64, b: f64) -> f64 {
+    if a == b || (a.is_nan() && b.is_nan()) {
+        0.0
+    } else {
+        a - b
+    }
+}
+
+// white at zero, shading towards red for positive and blue for negative differences relative to
+// the given scale, and black for non-finite differences
+fn diverging_color(diff: f64, scale: f64) -> Rgb<u8> {
+    if !diff.is_finite() {
+        return Rgb([0, 0, 0]);
+    }
+    if diff == 0.0 {
+        return Rgb([255, 255, 255]);
+    }
+    let fade = 255 - (diff.abs() / scale * 255.0).round() as u8;
+    if diff > 0.0 {
+        Rgb([255, fade, fade])
+    } else {
+        Rgb([fade, fade, 255])
+    }
+}
+
+// renders an isometric view on a cube of the given shape, with the first two axes mapping to the
+// rows and columns of the image
+fn isometric_image(shape: [usize; 3], value: impl Fn(usize, usize, usize) -> u8) -> GrayImage {
+    let scale = 0.45;
+    let center = (shape[0] as f64 * 0.5, shape[1] as f64 * 0.5);
+    let mut buf = vec![0; shape[0] * shape[1]];
+    for z_idx in (0..shape[2]).rev() {
+        for p in tensor_indices(&[shape[0], shape[1]]) {
+            if let Some(buf_idx) =
+                xyz_screen_to_buff_indices(p[0], p[1], z_idx, center.0, center.1, scale)
+            {
+                buf[p[0] * shape[1] + p[1]] = value(buf_idx.0, buf_idx.1, buf_idx.2);
+            }
         }
-        Ok(())
     }
+
+    GrayImage::from_raw(shape[1] as u32, shape[0] as u32, buf).unwrap()
 }
 
-pub(crate) fn norm_to_u8(x: f64) -> u8 {
-    (127.5 + x * 127.5) as u8
+fn encode_png(image: &GrayImage) -> Result<Vec<u8>, Error> {
+    let mut bytes = Vec::new();
+    image.write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)?;
+    Ok(bytes)
 }
 
 fn xyz_screen_to_buff_indices(
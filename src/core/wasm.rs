@@ -0,0 +1,143 @@
+//! Bindings for JavaScript.
+//!
+//! With the `wasm` feature enabled, generators built from a [`NoiseRecipe`] or [`Pipeline`] can
+//! be exported to JavaScript using [`wasm-bindgen`](https://docs.rs/wasm-bindgen), for example to
+//! preview noise chains in a browser. The exported [`JsGenerator`] class is constructed from the
+//! JSON representation of a recipe, as produced by the `serde` feature:
+//!
+//! ```js
+//! import { JsGenerator } from "./pkg/libnoise.js";
+//!
+//! const generator = new JsGenerator(JSON.stringify({
+//!     type: "scale",
+//!     source: { type: "simplex", seed: 42, large_coordinates: false, r_squared: null },
+//!     scale: [0.05, 0.05],
+//! }));
+//! const value = generator.sample2(0.2, 0.5);
+//! const image = new ImageData(generator.fillImageData(256, 256), 256, 256);
+//! canvas.getContext("2d").putImageData(image, 0, 0);
+//! ```
+//!
+//! The crate compiles for `wasm32-unknown-unknown` without further configuration. As described
+//! under [Determinism](crate#determinism), the sources do not depend on the math library of the
+//! platform, such that generators sampled in the browser match the golden vectors of native
+//! targets.
+
+use crate::core::{
+    error::Error,
+    generator::Generator,
+    pipeline::Pipeline,
+    recipe::{NoiseRecipe, RecipeError, RecipeGenerator},
+    utils::quantize::quantize_u8,
+};
+use alloc::vec::Vec;
+use wasm_bindgen::{prelude::*, Clamped};
+
+/// A 2-dimensional generator exported to JavaScript.
+///
+/// In JavaScript, this is constructed from the JSON representation of a [`NoiseRecipe`], and
+/// provides the methods `sample2(x, y)` and `fillImageData(width, height)`. In Rust, it can be
+/// created from a recipe or pipeline directly, using [`from_recipe()`] or [`from_pipeline()`].
+///
+/// [`from_recipe()`]: JsGenerator::from_recipe
+/// [`from_pipeline()`]: JsGenerator::from_pipeline
+#[wasm_bindgen]
+pub struct JsGenerator {
+    generator: RecipeGenerator<2>,
+}
+
+impl JsGenerator {
+    /// Creates a generator from the given `recipe`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the recipe cannot be built for 2 dimensions, as described by
+    /// [`NoiseRecipe::build()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{recipe::NoiseRecipe, wasm::JsGenerator};
+    /// let recipe = NoiseRecipe::Simplex { seed: 42, large_coordinates: false, r_squared: None };
+    /// let generator = JsGenerator::from_recipe(&recipe).unwrap();
+    ///
+    /// let value = generator.sample2(0.2, 0.5);
+    /// ```
+    pub fn from_recipe(recipe: &NoiseRecipe) -> Result<Self, RecipeError> {
+        Ok(Self {
+            generator: recipe.build()?,
+        })
+    }
+
+    /// Creates a generator from the recipe of the given `pipeline`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the recipe of the pipeline cannot be built, as described by
+    /// [`NoiseRecipe::build()`].
+    pub fn from_pipeline(pipeline: &Pipeline<2>) -> Result<Self, RecipeError> {
+        Self::from_recipe(&pipeline.recipe())
+    }
+
+    /// Fills an RGBA image of the given size, as described by [`fill_image_data()`], and returns
+    /// its pixels.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidShape`] if the size of the image overflows.
+    ///
+    /// [`fill_image_data()`]: JsGenerator::fill_image_data
+    pub fn try_fill_image_data(&self, width: u32, height: u32) -> Result<Vec<u8>, Error> {
+        let shape = [height as usize, width as usize];
+        let size = shape[0]
+            .checked_mul(shape[1])
+            .and_then(|size| size.checked_mul(4))
+            .ok_or_else(|| Error::InvalidShape {
+                shape: shape.to_vec(),
+            })?;
+        let mut pixels = Vec::with_capacity(size);
+        for y in 0..height {
+            for x in 0..width {
+                let value = quantize_u8(self.generator.sample([x as f64, y as f64]));
+                pixels.extend_from_slice(&[value, value, value, u8::MAX]);
+            }
+        }
+        Ok(pixels)
+    }
+}
+
+#[wasm_bindgen]
+impl JsGenerator {
+    /// Creates a generator from the JSON representation of a [`NoiseRecipe`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON does not represent a recipe, or if the recipe cannot be
+    /// built for 2 dimensions.
+    #[wasm_bindgen(constructor)]
+    pub fn new(recipe: &str) -> Result<JsGenerator, JsError> {
+        let recipe: NoiseRecipe = serde_json::from_str(recipe)?;
+        Ok(Self::from_recipe(&recipe)?)
+    }
+
+    /// Samples the generator at the point `[x, y]`.
+    pub fn sample2(&self, x: f64, y: f64) -> f64 {
+        self.generator.sample([x, y])
+    }
+
+    /// Samples the generator at the integer points of an image of the given size, and returns
+    /// the grayscale pixels in the RGBA layout of a canvas `ImageData`.
+    ///
+    /// The pixel in column `x` and row `y` shows `sample2(x, y)`, quantized like the images of
+    /// the `Visualizer`. In JavaScript, the pixels are returned as a `Uint8ClampedArray`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the size of the image overflows.
+    #[wasm_bindgen(js_name = fillImageData)]
+    pub fn fill_image_data(&self, width: u32, height: u32) -> Result<Clamped<Vec<u8>>, JsError> {
+        Ok(Clamped(self.try_fill_image_data(width, height)?))
+    }
+}
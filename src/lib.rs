@@ -55,15 +55,176 @@
 //! produce space-time noise such as:
 //!
 //! ![image](https://raw.githubusercontent.com/cookiephone/libnoise-rs/master/images/doc_image_001_f7049b4.gif)
+//!
+//! # Determinism
+//!
+//! For a given seed and input point, all sources produce bit-identical results
+//! on every supported platform, including 32-bit targets such as `wasm32`,
+//! with the exceptions listed below. This covers simplex noise of any
+//! dimensionality and kernel radius, whose gradients and kernels are computed
+//! without transcendental functions, as well as the tables precomputed by
+//! sources such as [`Blue`].
+//! The same holds for all adapters which are built from basic arithmetic,
+//! comparisons, and [`f64::sqrt`], as these operations are exactly rounded
+//! according to IEEE 754. This includes the fractal adapters such as
+//! [`Fbm`], [`Billow`], and [`RidgedMulti`], as well as transformations like
+//! [`Scale`], [`Translate`], and [`Displace`]. The guarantee is enforced by a
+//! suite of golden test vectors run on every CI target.
+//!
+//! Exact equality is not guaranteed for the following sources and adapters,
+//! because they rely on transcendental functions whose results are delegated
+//! to the math library of the platform and may differ in the last bits:
+//!
+//! - [`Spot`] with the default [`SpotKernel::Gaussian`] computes the
+//!   exponential function.
+//! - [`Checkerboard`] computes sines if made [smooth](Checkerboard::smooth).
+//! - [`Island`] with [`FalloffCurve::Exponent`] computes [`f64::powf`].
+//! - [`Rotate`] computes the sine and cosine of its rotation angles.
+//! - [`Tileable2D`] computes the sine and cosine of its input coordinates.
+//! - [`FbmLod`] computes the logarithm of the distance to the viewer.
+//! - [`Exp`] computes the exponential function.
+//! - [`Pow`] and [`Power`] compute [`f64::powi`] or [`f64::powf`].
+//!
+//! Similarly, the results of [`Lambda`] and [`Custom`] depend entirely on the
+//! user-provided closure.
+//!
+//! # Serialization
+//!
+//! With the `serde` feature enabled, all sources and adapters implement
+//! `Serialize` and `Deserialize`, such that entire generators can be saved and
+//! loaded using any format supported by serde. Sources are represented by their
+//! seed rather than their internal permutation tables, and derived parameters
+//! such as the normalization factor of [`Fbm`] are recomputed when
+//! deserializing. A deserialized generator produces bit-identical results to
+//! the original.
+//!
+//! ```
+//! use libnoise::prelude::*;
+//!
+//! let generator = Source::<2>::simplex(42).fbm(6, 1.0, 2.0, 0.5).clamp(-1.0, 1.0);
+//! let json = serde_json::to_string(&generator).unwrap();
+//! let loaded: Clamp<2, Fbm<2, Simplex<2>>> = serde_json::from_str(&json).unwrap();
+//!
+//! assert_eq!(loaded.sample([0.2, 0.5]), generator.sample([0.2, 0.5]));
+//! ```
+//!
+//! Closures cannot be serialized, so [`Lambda`] and [`Custom`] do not implement
+//! the serde traits, and neither does any generator containing them. Using them
+//! in a context requiring serialization is a compile-time error.
+//!
+//! # Compatibility with `noise`
+//!
+//! With the `noise-compat` feature enabled, generators can be exchanged with
+//! code written against the [`noise`](https://docs.rs/noise) crate. Wrapping a
+//! generator in `AsNoiseFn` implements its `NoiseFn` trait, and wrapping a
+//! `NoiseFn` in `FromNoiseFn` implements [`Generator`], such that the sources of
+//! either crate can be combined with the adapters of the other.
+//!
+//! # Forbidding Unsafe Code
+//!
+//! By default, the sources look up permutation and gradient tables without
+//! bounds checks, as all indices are in bounds by construction. With the
+//! `forbid-unsafe` feature enabled, all lookups are bounds-checked instead, and
+//! the crate is compiled with `#![forbid(unsafe_code)]`. The results are
+//! bit-identical either way. The bounds checks are cheap, as most indices are
+//! reduced modulo the table size right before the lookup, such that the
+//! difference in speed is typically within measurement noise. It can be
+//! measured by running the benchmarks with and without the feature, for example
+//! using `cargo bench --bench simplex --features forbid-unsafe`.
+//! Memory-mapping files cannot be done without unsafe code, so if the `mmap`
+//! feature is enabled as well, the crate is compiled with `#![deny(unsafe_code)]`
+//! instead, with `MmapNoiseBuffer` as the only exception. The same applies to
+//! the `wasm` feature, whose bindings generated by `wasm-bindgen` contain
+//! unsafe code.
+//!
+//! # Range Checks
+//!
+//! The [`Generator::checked()`] adapter panics if a generator produces a value
+//! outside of a given interval. Like a debug assertion, the check is only
+//! performed in debug builds by default, and compiled out of release builds.
+//! Enabling the `strict-range` feature performs the check in release builds as
+//! well.
+//!
+//! # `no_std` Support
+//!
+//! The `std` feature is enabled by default. Disabling default features builds
+//! the crate as `#![no_std]`, requiring only `alloc`, such that all sources,
+//! adapters, and [`NoiseBuffer`] can be used on embedded targets. Floating
+//! point functions missing from `core` are provided by [`libm`](https://docs.rs/libm)
+//! in that case, which may differ from the platform math library in the last
+//! bits for the adapters listed under [Determinism](#determinism).
+//!
+//! ```toml
+//! [dependencies]
+//! libnoise = { version = "*", default-features = false }
+//! ```
+//!
+//! The atomic [`Param`] is only available on targets supporting 64-bit atomics,
+//! while `LocalParam` is always available. File I/O, `ChunkCache`, as well as
+//! the `image`, `exr`, `mmap`, `rayon`, `serde`, `codegen`, `analysis`,
+//! `ndarray`, `noise-compat`, and `wasm` features require `std` and enable it
+//! implicitly. Half precision buffers of the `half` feature are available
+//! without `std`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(
+    all(
+        feature = "forbid-unsafe",
+        not(feature = "mmap"),
+        not(feature = "wasm")
+    ),
+    forbid(unsafe_code)
+)]
+#![cfg_attr(
+    all(feature = "forbid-unsafe", any(feature = "mmap", feature = "wasm")),
+    deny(unsafe_code)
+)]
+
+extern crate alloc;
 
 mod core;
 pub mod prelude;
 pub use crate::core::adapters::*;
+#[cfg(feature = "codegen")]
+pub use crate::core::codegen;
 #[cfg(feature = "dev-tools")]
 pub use crate::core::devtools;
+pub use crate::core::erosion;
+pub use crate::core::error::Error;
 pub use crate::core::generator::*;
+pub use crate::core::pipeline;
+pub use crate::core::planet;
+pub use crate::core::points;
+pub use crate::core::presets;
+pub use crate::core::raw;
+pub use crate::core::recipe;
 pub use crate::core::source::Source;
 pub use crate::core::sources::*;
-pub use crate::core::utils::noisebuf::NoiseBuffer;
+pub use crate::core::testing;
+#[cfg(feature = "std")]
+pub use crate::core::utils::chunkcache::ChunkCache;
+pub use crate::core::utils::density::{BlockClass, DensityVolume};
+pub use crate::core::utils::describe::Description;
+pub use crate::core::utils::distribution::NoiseDistribution;
+pub use crate::core::utils::filter::Boundary;
+pub use crate::core::utils::fingerprint::{Comparison, FingerprintDiff, SampleMismatch};
+pub use crate::core::utils::griditer::GridIter;
+#[cfg(feature = "mmap")]
+pub use crate::core::utils::mmapnoisebuf::MmapNoiseBuffer;
+pub use crate::core::utils::multinoisebuf::{GeneratorChannels, MultiNoiseBuffer};
+#[cfg(feature = "noise-compat")]
+pub use crate::core::utils::noise_compat::{AsNoiseFn, FromNoiseFn};
+pub use crate::core::utils::noisebuf::{NoiseBuffer, NoiseElement};
+pub use crate::core::utils::noisebufview::NoiseBufferView;
+pub use crate::core::utils::noisechunks::NoiseChunks;
+#[cfg(target_has_atomic = "64")]
+pub use crate::core::utils::param::Param;
+pub use crate::core::utils::param::{LocalParam, Parameter};
+pub use crate::core::utils::resample::Interpolation;
+#[cfg(feature = "analysis")]
+pub use crate::core::utils::spectrum::{fit_spectral_slope, spectrum2d};
+pub use crate::core::utils::stats::{ascii_histogram, Analysis, Stats};
 #[cfg(feature = "image")]
-pub use crate::core::utils::visualizer::Visualizer;
+pub use crate::core::utils::visualizer::{Transfer, TransferFunction, Visualizer};
+#[cfg(feature = "wasm")]
+pub use crate::core::wasm;
@@ -10,6 +10,12 @@ macro_rules! strategy_float_numeric {
     };
 }
 
+macro_rules! strategy_float_positive {
+    () => {
+        prop::num::f64::POSITIVE | prop::num::f64::NORMAL | prop::num::f64::SUBNORMAL
+    };
+}
+
 macro_rules! strategy_array_float_numeric {
     () => {
         prop::array::uniform(strategy_float_numeric!())
@@ -83,7 +89,7 @@ proptest! {
     // test adapter: billow
     // =================================================================
     #[test]
-    fn test_billow_1d(value in strategy_float_numeric!(), octaves in 0_u32..10, frequency in strategy_float_numeric!(), lacunarity in strategy_float_numeric!(), persistence in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+    fn test_billow_1d(value in strategy_float_numeric!(), octaves in 1_u32..10, frequency in strategy_float_positive!(), lacunarity in strategy_float_positive!(), persistence in 0_f64..=1e9, point in strategy_array_float_numeric!()) {
         let n = Source::<1>::constant(value).billow(octaves, frequency, lacunarity, persistence).sample(point);
         let mut expected = 0.0;
         let mut amp = 1.0;
@@ -91,12 +97,12 @@ proptest! {
             expected += amp * (value.abs() * 2.0 - 1.0);
             amp *= persistence;
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32));
+        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + (0..octave).fold(1.0, |amp, _| amp * persistence));
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", n, expected);
     }
 
     #[test]
-    fn test_billow_2d(value in strategy_float_numeric!(), octaves in 0_u32..10, frequency in strategy_float_numeric!(), lacunarity in strategy_float_numeric!(), persistence in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+    fn test_billow_2d(value in strategy_float_numeric!(), octaves in 1_u32..10, frequency in strategy_float_positive!(), lacunarity in strategy_float_positive!(), persistence in 0_f64..=1e9, point in strategy_array_float_numeric!()) {
         let n = Source::<2>::constant(value).billow(octaves, frequency, lacunarity, persistence).sample(point);
         let mut expected = 0.0;
         let mut amp = 1.0;
@@ -104,12 +110,12 @@ proptest! {
             expected += amp * (value.abs() * 2.0 - 1.0);
             amp *= persistence;
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32));
+        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + (0..octave).fold(1.0, |amp, _| amp * persistence));
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", n, expected);
     }
 
     #[test]
-    fn test_billow_3d(value in strategy_float_numeric!(), octaves in 0_u32..10, frequency in strategy_float_numeric!(), lacunarity in strategy_float_numeric!(), persistence in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+    fn test_billow_3d(value in strategy_float_numeric!(), octaves in 1_u32..10, frequency in strategy_float_positive!(), lacunarity in strategy_float_positive!(), persistence in 0_f64..=1e9, point in strategy_array_float_numeric!()) {
         let n = Source::<3>::constant(value).billow(octaves, frequency, lacunarity, persistence).sample(point);
         let mut expected = 0.0;
         let mut amp = 1.0;
@@ -117,12 +123,12 @@ proptest! {
             expected += amp * (value.abs() * 2.0 - 1.0);
             amp *= persistence;
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32));
+        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + (0..octave).fold(1.0, |amp, _| amp * persistence));
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", n, expected);
     }
 
     #[test]
-    fn test_billow_4d(value in strategy_float_numeric!(), octaves in 0_u32..10, frequency in strategy_float_numeric!(), lacunarity in strategy_float_numeric!(), persistence in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+    fn test_billow_4d(value in strategy_float_numeric!(), octaves in 1_u32..10, frequency in strategy_float_positive!(), lacunarity in strategy_float_positive!(), persistence in 0_f64..=1e9, point in strategy_array_float_numeric!()) {
         let n = Source::<4>::constant(value).billow(octaves, frequency, lacunarity, persistence).sample(point);
         let mut expected = 0.0;
         let mut amp = 1.0;
@@ -130,7 +136,7 @@ proptest! {
             expected += amp * (value.abs() * 2.0 - 1.0);
             amp *= persistence;
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32));
+        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + (0..octave).fold(1.0, |amp, _| amp * persistence));
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", n, expected);
     }
 
@@ -316,7 +322,7 @@ proptest! {
     // test adapter: fbm
     // =================================================================
     #[test]
-    fn test_fbm_1d(value in strategy_float_numeric!(), octaves in 0_u32..10, frequency in strategy_float_numeric!(), lacunarity in strategy_float_numeric!(), persistence in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+    fn test_fbm_1d(value in strategy_float_numeric!(), octaves in 1_u32..10, frequency in strategy_float_positive!(), lacunarity in strategy_float_positive!(), persistence in 0_f64..=1e9, point in strategy_array_float_numeric!()) {
         let n = Source::<1>::constant(value).fbm(octaves, frequency, lacunarity, persistence).sample(point);
         let mut expected = 0.0;
         let mut amp = 1.0;
@@ -324,12 +330,12 @@ proptest! {
             expected += amp * value;
             amp *= persistence;
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32));
+        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + (0..octave).fold(1.0, |amp, _| amp * persistence));
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", n, expected);
     }
 
     #[test]
-    fn test_fbm_2d(value in strategy_float_numeric!(), octaves in 0_u32..10, frequency in strategy_float_numeric!(), lacunarity in strategy_float_numeric!(), persistence in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+    fn test_fbm_2d(value in strategy_float_numeric!(), octaves in 1_u32..10, frequency in strategy_float_positive!(), lacunarity in strategy_float_positive!(), persistence in 0_f64..=1e9, point in strategy_array_float_numeric!()) {
         let n = Source::<2>::constant(value).fbm(octaves, frequency, lacunarity, persistence).sample(point);
         let mut expected = 0.0;
         let mut amp = 1.0;
@@ -337,12 +343,12 @@ proptest! {
             expected += amp * value;
             amp *= persistence;
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32));
+        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + (0..octave).fold(1.0, |amp, _| amp * persistence));
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", n, expected);
     }
 
     #[test]
-    fn test_fbm_3d(value in strategy_float_numeric!(), octaves in 0_u32..10, frequency in strategy_float_numeric!(), lacunarity in strategy_float_numeric!(), persistence in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+    fn test_fbm_3d(value in strategy_float_numeric!(), octaves in 1_u32..10, frequency in strategy_float_positive!(), lacunarity in strategy_float_positive!(), persistence in 0_f64..=1e9, point in strategy_array_float_numeric!()) {
         let n = Source::<3>::constant(value).fbm(octaves, frequency, lacunarity, persistence).sample(point);
         let mut expected = 0.0;
         let mut amp = 1.0;
@@ -350,12 +356,12 @@ proptest! {
             expected += amp * value;
             amp *= persistence;
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32));
+        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + (0..octave).fold(1.0, |amp, _| amp * persistence));
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", n, expected);
     }
 
     #[test]
-    fn test_fbm_4d(value in strategy_float_numeric!(), octaves in 0_u32..10, frequency in strategy_float_numeric!(), lacunarity in strategy_float_numeric!(), persistence in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+    fn test_fbm_4d(value in strategy_float_numeric!(), octaves in 1_u32..10, frequency in strategy_float_positive!(), lacunarity in strategy_float_positive!(), persistence in 0_f64..=1e9, point in strategy_array_float_numeric!()) {
         let n = Source::<4>::constant(value).fbm(octaves, frequency, lacunarity, persistence).sample(point);
         let mut expected = 0.0;
         let mut amp = 1.0;
@@ -363,7 +369,7 @@ proptest! {
             expected += amp * value;
             amp *= persistence;
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32));
+        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + (0..octave).fold(1.0, |amp, _| amp * persistence));
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", n, expected);
     }
 
@@ -651,7 +657,7 @@ proptest! {
     // test adapter: ridgedmulti
     // =================================================================
     #[test]
-    fn test_ridgedmulti_1d(value in strategy_float_numeric!(), octaves in 0_u32..10, frequency in strategy_float_numeric!(), lacunarity in strategy_float_numeric!(), attenuation in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+    fn test_ridgedmulti_1d(value in strategy_float_numeric!(), octaves in 1_u32..10, frequency in strategy_float_positive!(), lacunarity in strategy_float_positive!(), attenuation in 1e-9_f64..1e9, point in strategy_array_float_numeric!()) {
         let n = Source::<1>::constant(value).ridgedmulti(octaves, frequency, lacunarity, attenuation).sample(point);
         let mut expected = 0.0;
         let mut amp = 1.0;
@@ -663,14 +669,14 @@ proptest! {
             amp = (layer / attenuation).clamp(0.0, 1.0);
         }
         expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| {
-            acc + (1.0 / attenuation).powi(octave as i32)
+            acc + (0..octave).fold(1.0, |weight, _| weight / attenuation)
         });
         expected = expected * 2.0 - 1.0;
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", n, expected);
     }
 
     #[test]
-    fn test_ridgedmulti_2d(value in strategy_float_numeric!(), octaves in 0_u32..10, frequency in strategy_float_numeric!(), lacunarity in strategy_float_numeric!(), attenuation in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+    fn test_ridgedmulti_2d(value in strategy_float_numeric!(), octaves in 1_u32..10, frequency in strategy_float_positive!(), lacunarity in strategy_float_positive!(), attenuation in 1e-9_f64..1e9, point in strategy_array_float_numeric!()) {
         let n = Source::<2>::constant(value).ridgedmulti(octaves, frequency, lacunarity, attenuation).sample(point);
         let mut expected = 0.0;
         let mut amp = 1.0;
@@ -682,14 +688,14 @@ proptest! {
             amp = (layer / attenuation).clamp(0.0, 1.0);
         }
         expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| {
-            acc + (1.0 / attenuation).powi(octave as i32)
+            acc + (0..octave).fold(1.0, |weight, _| weight / attenuation)
         });
         expected = expected * 2.0 - 1.0;
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", n, expected);
     }
 
     #[test]
-    fn test_ridgedmulti_3d(value in strategy_float_numeric!(), octaves in 0_u32..10, frequency in strategy_float_numeric!(), lacunarity in strategy_float_numeric!(), attenuation in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+    fn test_ridgedmulti_3d(value in strategy_float_numeric!(), octaves in 1_u32..10, frequency in strategy_float_positive!(), lacunarity in strategy_float_positive!(), attenuation in 1e-9_f64..1e9, point in strategy_array_float_numeric!()) {
         let n = Source::<3>::constant(value).ridgedmulti(octaves, frequency, lacunarity, attenuation).sample(point);
         let mut expected = 0.0;
         let mut amp = 1.0;
@@ -701,14 +707,14 @@ proptest! {
             amp = (layer / attenuation).clamp(0.0, 1.0);
         }
         expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| {
-            acc + (1.0 / attenuation).powi(octave as i32)
+            acc + (0..octave).fold(1.0, |weight, _| weight / attenuation)
         });
         expected = expected * 2.0 - 1.0;
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", n, expected);
     }
 
     #[test]
-    fn test_ridgedmulti_4d(value in strategy_float_numeric!(), octaves in 0_u32..10, frequency in strategy_float_numeric!(), lacunarity in strategy_float_numeric!(), attenuation in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+    fn test_ridgedmulti_4d(value in strategy_float_numeric!(), octaves in 1_u32..10, frequency in strategy_float_positive!(), lacunarity in strategy_float_positive!(), attenuation in 1e-9_f64..1e9, point in strategy_array_float_numeric!()) {
         let n = Source::<4>::constant(value).ridgedmulti(octaves, frequency, lacunarity, attenuation).sample(point);
         let mut expected = 0.0;
         let mut amp = 1.0;
@@ -720,7 +726,7 @@ proptest! {
             amp = (layer / attenuation).clamp(0.0, 1.0);
         }
         expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| {
-            acc + (1.0 / attenuation).powi(octave as i32)
+            acc + (0..octave).fold(1.0, |weight, _| weight / attenuation)
         });
         expected = expected * 2.0 - 1.0;
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", n, expected);
@@ -888,3 +894,1185 @@ proptest! {
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", n, expected);
     }
 }
+
+// =================================================================
+// test non-finite inputs through adapter chains
+// =================================================================
+#[test]
+fn test_non_finite_inputs_chain() {
+    let generator = Source::<2>::simplex(42)
+        .fbm(3, 0.013, 2.0, 0.5)
+        .abs()
+        .clamp(0.0, 0.5)
+        .min(Source::worley(43))
+        .max(Source::constant(-0.5))
+        .select(Source::value(44), Source::perlin(45), -0.3, 0.1)
+        .blend(Source::checkerboard(), Source::improved_perlin(46))
+        .displace_x(Source::simplex(47).mul(5.0));
+    for special in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert!(generator.sample([special, 0.3]).is_nan());
+        assert!(generator.sample([0.3, special]).is_nan());
+    }
+    for special in [f64::MAX, f64::MIN] {
+        let value = generator.sample([special; 2]);
+        assert!(value.is_nan() || (-1.0..=1.0).contains(&value));
+    }
+}
+
+#[test]
+fn test_nan_propagation() {
+    let nan = Source::constant(f64::NAN);
+    let point = [0.3, 0.5];
+    assert!(nan.clamp(-0.5, 0.5).sample(point).is_nan());
+    assert!(nan.min(Source::constant(0.0)).sample(point).is_nan());
+    assert!(Source::constant(0.0).min(nan).sample(point).is_nan());
+    assert!(nan.max(Source::constant(0.0)).sample(point).is_nan());
+    assert!(Source::constant(0.0).max(nan).sample(point).is_nan());
+    let selected = Source::constant(0.0).select(Source::constant(1.0), nan, -0.5, 0.5);
+    assert!(selected.sample(point).is_nan());
+}
+
+// =================================================================
+// test adapter: checked
+// =================================================================
+#[test]
+fn test_checked_in_range() {
+    let generator = Source::<2>::simplex(42)
+        .fbm(5, 0.013, 2.0, 0.5)
+        .checked(-1.0, 1.0);
+    let buf = NoiseBuffer::<2>::new([100, 100], &generator);
+    assert_eq!(
+        buf.buffer,
+        NoiseBuffer::<2>::new([100, 100], &Source::simplex(42).fbm(5, 0.013, 2.0, 0.5)).buffer
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "out of range")]
+fn test_checked_out_of_range_panics() {
+    let generator = Source::<2>::simplex(42)
+        .sum(Source::simplex(43))
+        .sum(Source::simplex(44))
+        .checked(-1.0, 1.0);
+    NoiseBuffer::<2>::new([100, 100], &generator.scale([0.05; 2]));
+}
+
+#[test]
+fn test_checked_try_sample() {
+    let generator = Source::<2>::constant(1.5).checked(-1.0, 1.0);
+    assert_eq!(
+        generator.try_sample([0.2, 0.5]),
+        Err(Error::OutOfRange {
+            point: vec![0.2, 0.5],
+            value: 1.5,
+            min: -1.0,
+            max: 1.0
+        })
+    );
+    let generator = Source::<2>::constant(f64::NAN).checked(-1.0, 1.0);
+    assert!(generator.try_sample([0.2, 0.5]).is_err());
+    let generator = Source::<2>::constant(1.0).checked(-1.0, 1.0);
+    assert_eq!(generator.try_sample([0.2, 0.5]), Ok(1.0));
+}
+
+// =================================================================
+// test sample_interval
+// =================================================================
+// samples random points within random boxes and asserts that the results lie within the
+// interval computed for the box
+fn assert_interval_contains<const D: usize, G: Generator<D>>(generator: &G, extent: f64) {
+    let mut state = 0x853c_49e6_748f_ea9b_u64;
+    let mut random = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 11) as f64 / (1_u64 << 53) as f64
+    };
+    for _ in 0..200 {
+        let min: [f64; D] = std::array::from_fn(|_| (random() * 2.0 - 1.0) * 100.0);
+        let max: [f64; D] = std::array::from_fn(|i| min[i] + random() * extent);
+        let (lower, upper) = generator.sample_interval(min, max);
+        assert!(lower <= upper, "empty interval ({lower}, {upper})");
+        for _ in 0..200 {
+            let point = std::array::from_fn(|i| min[i] + random() * (max[i] - min[i]));
+            let value = generator.sample(point);
+            assert!(
+                value.is_nan() || (lower <= value && value <= upper),
+                "value {value} at {point:?} not within ({lower}, {upper}) computed for box \
+                 from {min:?} to {max:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_sample_interval_transforms() {
+    let generator = Source::<2>::simplex(42)
+        .scale([0.3, 0.7])
+        .translate([5.0, -3.0])
+        .rotate([0.4]);
+    assert_interval_contains(&generator, 10.0);
+    let generator = Source::<3>::checkerboard()
+        .rotate([0.4, 1.1, -0.3])
+        .displace_x(Source::perlin(43).mul(0.5));
+    assert_interval_contains(&generator, 0.5);
+    let generator = Source::<4>::checkerboard().rotate([0.4, 1.1, -0.3, 0.9, 2.0, -1.2]);
+    assert_interval_contains(&generator, 0.5);
+}
+
+#[test]
+fn test_sample_interval_arithmetic() {
+    let generator = Source::<2>::simplex(42)
+        .abs()
+        .add(0.25)
+        .mul(-2.0)
+        .exp()
+        .neg()
+        .clamp(-0.7, 0.2);
+    assert_interval_contains(&generator, 10.0);
+    let generator = Source::<3>::perlin(42)
+        .powi(3)
+        .sum(Source::value(43).powf(2.5))
+        .product(Source::worley(44).add(0.5))
+        .min(Source::constant(0.3))
+        .max(Source::improved_perlin(45));
+    assert_interval_contains(&generator, 10.0);
+    let generator = Source::<2>::value(42)
+        .add(1.5)
+        .power(Source::simplex(43))
+        .blend(Source::perlin(44), Source::value(45));
+    assert_interval_contains(&generator, 10.0);
+    let generator = Source::<2>::checkerboard().scale([0.1; 2]).select(
+        Source::simplex(43),
+        Source::value(44),
+        -0.5,
+        0.5,
+    );
+    assert_interval_contains(&generator, 5.0);
+}
+
+#[test]
+fn test_sample_interval_fractals() {
+    let generator = Source::<2>::simplex(42).fbm(5, 0.013, 2.0, 0.5);
+    assert_interval_contains(&generator, 20.0);
+    let generator = Source::<3>::perlin(42).billow(4, 0.05, 2.0, 0.6);
+    assert_interval_contains(&generator, 20.0);
+    let generator = Source::<2>::improved_perlin(42).ridgedmulti(4, 0.05, 2.0, 1.5);
+    assert_interval_contains(&generator, 20.0);
+    let generator = Source::<1>::constant(0.5).fbm(3, 0.1, 2.0, 0.5).mul(2.0);
+    assert_eq!(generator.sample_interval([0.0], [1.0]), (1.0, 1.0));
+}
+
+#[test]
+fn test_sample_interval_unbounded() {
+    let generator = Source::<2>::simplex(42).lambda(|x| x * 2.0).add(1.0);
+    assert_eq!(
+        generator.sample_interval([0.0; 2], [1.0; 2]),
+        (f64::NEG_INFINITY, f64::INFINITY)
+    );
+}
+
+// =================================================================
+// test output_bounds
+// =================================================================
+fn assert_bounds_contain_samples<G: Generator<2> + Clone>(generator: &G) {
+    let (lower, upper) = generator.output_bounds();
+    let stats = NoiseBuffer::<2>::new([200, 200], &generator.clone().scale([0.07; 2])).stats();
+    assert!(
+        lower <= stats.min && stats.max <= upper,
+        "observed range ({}, {}) not within bounds ({lower}, {upper})",
+        stats.min,
+        stats.max
+    );
+}
+
+#[test]
+fn test_output_bounds() {
+    let generator = Source::<2>::constant(0.25).add(0.5);
+    assert_eq!(generator.output_bounds(), (0.75, 0.75));
+    let generator = Source::<2>::value(42).add(0.5);
+    assert_eq!(generator.output_bounds(), (-0.5, 1.5));
+    assert_bounds_contain_samples(&generator);
+    let generator = Source::<2>::simplex(42).clamp(-0.5, 0.25);
+    assert_eq!(generator.output_bounds(), (-0.5, 0.25));
+    assert_bounds_contain_samples(&generator);
+    let generator = Source::<2>::worley(42).abs().mul(-2.0);
+    assert_eq!(generator.output_bounds(), (-2.0, 0.0));
+    assert_bounds_contain_samples(&generator);
+    let generator = Source::<2>::value(42)
+        .sum(Source::worley(43))
+        .max(Source::constant(0.0));
+    assert_eq!(generator.output_bounds(), (0.0, 2.0));
+    assert_bounds_contain_samples(&generator);
+    let generator = Source::<2>::checkerboard().rotate([0.3]).scale([0.2; 2]);
+    assert_eq!(generator.output_bounds(), (-1.0, 1.0));
+    assert_bounds_contain_samples(&generator);
+    let generator = Source::<2>::perlin(42).fbm(4, 1.0, 2.0, 0.5);
+    let (lower, upper) = generator.output_bounds();
+    assert!((lower + 1.001).abs() < 1e-12 && (upper - 1.001).abs() < 1e-12);
+    assert_bounds_contain_samples(&generator);
+    let generator = Source::<2>::value(42).fbm(4, 1.0, 2.0, 0.5).mul(3.0);
+    let (lower, upper) = generator.output_bounds();
+    assert!((lower + 3.0).abs() < 1e-12 && (upper - 3.0).abs() < 1e-12);
+    assert_bounds_contain_samples(&generator);
+    let generator = Source::<2>::simplex(42)
+        .lambda(|x| x * 2.0)
+        .clamp(-1.0, 1.0);
+    assert_eq!(generator.output_bounds(), (-1.0, 1.0));
+    let generator = Source::<2>::simplex(42).lambda(|x| x * 2.0);
+    assert_eq!(
+        generator.output_bounds(),
+        (f64::NEG_INFINITY, f64::INFINITY)
+    );
+}
+
+// =================================================================
+// test adapter: fbm_lod
+// =================================================================
+#[test]
+fn test_fbm_lod_identities() {
+    let generator = Source::<3>::simplex(42).fbm_lod(8, 0.013, 2.0, 0.5);
+    let full = Source::<3>::simplex(42).fbm(8, 0.013, 2.0, 0.5);
+    let single = Source::<3>::simplex(42).fbm(1, 0.013, 2.0, 0.5);
+    for i in 0..1000 {
+        let point = [i as f64 * 0.37, i as f64 * -1.13, i as f64 * 2.71];
+        assert_eq!(generator.sample_lod(point, 0.0), full.sample(point));
+        assert_eq!(generator.sample_lod(point, -1.0), full.sample(point));
+        assert_eq!(generator.sample(point), full.sample(point));
+        assert_eq!(generator.sample_lod(point, 7.0), single.sample(point));
+        assert_eq!(generator.sample_lod(point, 100.0), single.sample(point));
+        for octaves in 1..8 {
+            let reduced = Source::<3>::simplex(42).fbm(octaves, 0.013, 2.0, 0.5);
+            let lod = (8 - octaves) as f64;
+            assert_eq!(generator.sample_lod(point, lod), reduced.sample(point));
+        }
+    }
+}
+
+#[test]
+fn test_fbm_lod_continuity() {
+    let generator = Source::<2>::perlin(42).fbm_lod(6, 0.05, 2.0, 0.5);
+    let point = [12.3, 45.6];
+    for step in 0..50 {
+        let lod = step as f64 * 0.1;
+        let difference =
+            (generator.sample_lod(point, lod) - generator.sample_lod(point, lod + 1e-6)).abs();
+        assert!(
+            difference < 1e-5,
+            "discontinuity at lod {lod}: {difference}"
+        );
+    }
+}
+
+#[test]
+fn test_fbm_lod_convergence() {
+    let generator = Source::<2>::simplex(42).fbm_lod(6, 0.02, 2.0, 0.5);
+    let points = (0..4096)
+        .map(|i| [(i % 64) as f64 * 1.7, (i / 64) as f64 * 1.7])
+        .collect::<Vec<_>>();
+    let mean_squared_error = |lod: f64| {
+        points
+            .iter()
+            .map(|&point| (generator.sample_lod(point, lod) - generator.sample(point)).powi(2))
+            .sum::<f64>()
+            / points.len() as f64
+    };
+    let errors = (0..=20)
+        .rev()
+        .map(|step| mean_squared_error(step as f64 * 0.25))
+        .collect::<Vec<_>>();
+    for pair in errors.windows(2) {
+        assert!(
+            pair[1] <= pair[0],
+            "error increased as lod decreased: {errors:?}"
+        );
+    }
+    assert_eq!(errors.last(), Some(&0.0));
+}
+
+#[test]
+fn test_distance_lod() {
+    let fbm = Source::<2>::simplex(42).fbm_lod(6, 0.013, 2.0, 0.5);
+    let generator = fbm.clone().distance_lod([10.0, -5.0], 50.0);
+    assert_eq!(generator.sample([10.0, -5.0]), fbm.sample([10.0, -5.0]));
+    assert_eq!(generator.sample([40.0, 35.0]), fbm.sample([40.0, 35.0]));
+    assert_eq!(
+        generator.sample([10.0, 195.0]),
+        fbm.sample_lod([10.0, 195.0], 2.0)
+    );
+    assert_eq!(
+        generator.sample([1e9, 0.0]),
+        fbm.sample_lod([1e9, 0.0], 5.0)
+    );
+    assert_interval_contains(&generator, 100.0);
+}
+
+// =================================================================
+// test tweakable parameters
+// =================================================================
+fn assert_same_samples<GA: Generator<2>, GB: Generator<2>>(generator_a: &GA, generator_b: &GB) {
+    let diff = generator_a.fingerprint_diff(generator_b, [-100.0; 2], [100.0; 2], 32);
+    assert!(diff.is_empty(), "{}", diff);
+}
+
+#[test]
+fn test_param_fractals() {
+    let frequency = Param::new(0.013);
+    let persistence = Param::new(0.5);
+    let attenuation = Param::new(2.0);
+    let fbm =
+        Source::<2>::simplex(42).fbm(6, frequency.clone(), Param::new(2.0), persistence.clone());
+    let billow =
+        Source::<2>::simplex(42).billow(6, frequency.clone(), Param::new(2.0), persistence.clone());
+    let ridgedmulti = Source::<2>::simplex(42).ridgedmulti(
+        6,
+        frequency.clone(),
+        Param::new(2.0),
+        attenuation.clone(),
+    );
+    assert_same_samples(&fbm, &Source::simplex(42).fbm(6, 0.013, 2.0, 0.5));
+    let value = fbm.sample([3.0, 7.0]);
+    persistence.set(0.45);
+    frequency.set(0.02);
+    attenuation.set(1.5);
+    assert_ne!(fbm.sample([3.0, 7.0]), value);
+    assert_same_samples(&fbm, &Source::simplex(42).fbm(6, 0.02, 2.0, 0.45));
+    assert_same_samples(&billow, &Source::simplex(42).billow(6, 0.02, 2.0, 0.45));
+    assert_same_samples(
+        &ridgedmulti,
+        &Source::simplex(42).ridgedmulti(6, 0.02, 2.0, 1.5),
+    );
+}
+
+#[test]
+fn test_param_transforms() {
+    let scale = Param::new(0.5);
+    let offset = Param::new(10.0);
+    let generator = Source::<2>::simplex(42)
+        .scale([scale.clone(), Param::new(2.0)])
+        .translate([offset.clone(), Param::new(0.0)]);
+    assert_same_samples(
+        &generator,
+        &Source::simplex(42).scale([0.5, 2.0]).translate([10.0, 0.0]),
+    );
+    scale.set(0.25);
+    offset.set(-3.0);
+    assert_same_samples(
+        &generator,
+        &Source::simplex(42)
+            .scale([0.25, 2.0])
+            .translate([-3.0, 0.0]),
+    );
+}
+
+#[test]
+fn test_param_clamp_select() {
+    let min = LocalParam::new(-1.0);
+    let max = LocalParam::new(1.0);
+    let generator = Source::<2>::simplex(42).clamp(min.clone(), max.clone());
+    let point = [1.3, 2.7];
+    let value = Source::<2>::simplex(42).sample(point);
+    assert_eq!(generator.sample(point), value);
+    min.set(value + 0.1);
+    max.set(value + 0.2);
+    assert_eq!(generator.sample(point), value + 0.1);
+    assert_eq!(generator.output_bounds(), (value + 0.1, value + 0.2));
+
+    let threshold = LocalParam::new(1.0);
+    let generator = Source::<2>::constant(1.0).select(
+        Source::constant(2.0),
+        Source::constant(0.5),
+        LocalParam::new(-1.0),
+        threshold.clone(),
+    );
+    assert_eq!(generator.sample(point), 1.0);
+    threshold.set(0.0);
+    assert_eq!(generator.sample(point), 2.0);
+    assert_eq!(generator.output_bounds(), (2.0, 2.0));
+}
+
+#[test]
+fn test_param_threaded() {
+    let persistence = Param::new(0.5);
+    let generator =
+        Source::<2>::simplex(42).fbm(4, Param::new(0.05), Param::new(2.0), persistence.clone());
+    let before = Source::<2>::simplex(42).fbm(4, 0.05, 2.0, 0.5);
+    let after = Source::<2>::simplex(42).fbm(4, 0.05, 2.0, 0.7);
+    let points = (0..64)
+        .map(|i| [i as f64 * 1.7, i as f64 * -0.3])
+        .collect::<Vec<_>>();
+    std::thread::scope(|scope| {
+        let workers = (0..4)
+            .map(|_| {
+                scope.spawn(|| {
+                    for _ in 0..200 {
+                        for &point in &points {
+                            let value = generator.sample(point);
+                            // each sample sees either the old or the new value, never a mixture
+                            assert!(value == before.sample(point) || value == after.sample(point));
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        persistence.set(0.7);
+        for worker in workers {
+            worker.join().unwrap();
+        }
+    });
+    for &point in &points {
+        assert_eq!(generator.sample(point), after.sample(point));
+    }
+}
+
+// =================================================================
+// test boxed generators
+// =================================================================
+#[test]
+fn test_boxed_heterogeneous() {
+    let layers: Vec<Box<dyn SampleGenerator<2>>> = vec![
+        Box::new(Source::simplex(42)),
+        Box::new(Source::worley(43).scale([0.05, 0.05])),
+        Box::new(Source::perlin(44).fbm(4, 0.013, 2.0, 0.5).clamp(-0.5, 0.5)),
+        Box::new(Source::constant(0.25)),
+    ];
+    let point = [0.2, 0.5];
+    let expected = [
+        Source::simplex(42).sample(point),
+        Source::worley(43).scale([0.05, 0.05]).sample(point),
+        Source::perlin(44)
+            .fbm(4, 0.013, 2.0, 0.5)
+            .clamp(-0.5, 0.5)
+            .sample(point),
+        0.25,
+    ];
+    for (layer, expected) in layers.iter().zip(expected) {
+        assert_eq!(layer.sample(point), expected);
+        assert_eq!(layer.sample_dyn(point), expected);
+    }
+    assert_eq!(layers[2].output_bounds(), (-0.5, 0.5));
+    assert_eq!(
+        layers[1].describe(),
+        Source::<2>::worley(43).scale([0.05, 0.05]).describe()
+    );
+}
+
+#[test]
+fn test_boxed_adapters() {
+    let boxed: Box<dyn SampleGenerator<2>> = Box::new(Source::simplex(42).scale([0.5, 0.5]));
+    let generator = boxed
+        .fbm(4, 0.013, 2.0, 0.5)
+        .blend(Source::worley(43), Source::constant(0.3))
+        .rotate([0.7]);
+    let expected = Source::simplex(42)
+        .scale([0.5, 0.5])
+        .fbm(4, 0.013, 2.0, 0.5)
+        .blend(Source::worley(43), Source::constant(0.3))
+        .rotate([0.7]);
+    assert_same_samples(&generator, &expected);
+    assert_eq!(generator.to_recipe(), expected.to_recipe());
+}
+
+#[test]
+fn test_boxed_clone() {
+    let boxed: Box<dyn SampleGenerator<3>> = Box::new(Source::simplex(42).fbm(3, 0.1, 2.0, 0.5));
+    let cloned = boxed.clone();
+    let rebuilt = boxed.to_recipe().unwrap().build::<3>().unwrap().clone();
+    for point in [[0.2, 0.5, 0.1], [13.0, -7.5, 2.25]] {
+        assert_eq!(cloned.sample(point), boxed.sample(point));
+        assert_eq!(rebuilt.sample(point), boxed.sample(point));
+    }
+    assert_eq!(boxed.to_string(), cloned.to_string());
+}
+
+// =================================================================
+// test adapter: map
+// =================================================================
+#[test]
+fn test_map_evaluation_order() {
+    let point = [0.2, 0.5, 0.3];
+    let value = Source::<3>::simplex(42).sample(point);
+    let generator = Source::simplex(42).add(1.0).map(|x| x * x).mul(0.5);
+    assert_eq!(generator.sample(point), (value + 1.0) * (value + 1.0) * 0.5);
+    let generator = Source::simplex(42).map(|x| x * x).add(1.0).map(f64::sqrt);
+    assert_eq!(generator.sample(point), (value * value + 1.0).sqrt());
+    assert_eq!(
+        generator.output_bounds(),
+        (f64::NEG_INFINITY, f64::INFINITY)
+    );
+}
+
+#[test]
+fn test_map_capturing() {
+    let levels = [-0.5, 0.0, 0.25, 0.75].to_vec();
+    let quantize = move |x: f64| {
+        levels
+            .iter()
+            .copied()
+            .min_by(|a, b| (a - x).abs().total_cmp(&(b - x).abs()))
+            .unwrap()
+    };
+    let generator = Source::<2>::simplex(42)
+        .fbm(3, 0.013, 2.0, 0.5)
+        .map(quantize);
+    let cloned = generator.clone();
+    for point in [[0.2, 0.5], [13.0, -7.5], [250.0, 80.0]] {
+        let value = generator.sample(point);
+        assert!([-0.5, 0.0, 0.25, 0.75].contains(&value));
+        assert_eq!(cloned.sample(point), value);
+    }
+    assert_eq!(
+        format!("{:?}", Source::<2>::constant(1.0).map(f64::tanh)),
+        "Map { generator: Constant { value: 1.0 }, map: <closure> }"
+    );
+    assert_eq!(
+        generator.to_string(),
+        "\
+Map(map=<closure>)
+└─ Fbm(octaves=3, frequency=0.013, lacunarity=2, persistence=0.5)
+   └─ Simplex(seed=42)"
+    );
+}
+
+// =================================================================
+// test adapters: zip_with, zip3_with
+// =================================================================
+// wraps a generator, recording every point it is sampled at
+#[derive(Debug)]
+struct Recording<G> {
+    generator: G,
+    points: std::cell::RefCell<Vec<[f64; 2]>>,
+}
+
+impl<G: Generator<2>> Recording<G> {
+    fn new(generator: G) -> Self {
+        Self {
+            generator,
+            points: Default::default(),
+        }
+    }
+}
+
+impl<G: Generator<2>> Generator<2> for &Recording<G> {
+    fn sample(&self, point: [f64; 2]) -> f64 {
+        self.points.borrow_mut().push(point);
+        self.generator.sample(point)
+    }
+}
+
+#[test]
+fn test_zip_with_samples_once() {
+    let a = Recording::new(Source::simplex(42));
+    let b = Recording::new(Source::worley(43));
+    let generator = (&a).zip_with(&b, |x, y| (x * y).abs().sqrt().copysign(x));
+    let points = [[0.2, 0.5], [13.0, -7.5], [250.0, 80.0]];
+    for point in points {
+        let x = Source::simplex(42).sample(point);
+        let y = Source::worley(43).sample(point);
+        assert_eq!(generator.sample(point), (x * y).abs().sqrt().copysign(x));
+    }
+    assert_eq!(*a.points.borrow(), points);
+    assert_eq!(*b.points.borrow(), points);
+}
+
+#[test]
+fn test_zip3_with_samples_once() {
+    let a = Recording::new(Source::simplex(42));
+    let b = Recording::new(Source::worley(43));
+    let c = Recording::new(Source::perlin(44));
+    let generator = (&a).zip3_with(&b, &c, |x, y, z| x * 100.0 + y * 10.0 + z);
+    let points = [[0.2, 0.5], [13.0, -7.5], [250.0, 80.0]];
+    for point in points {
+        let expected = Source::simplex(42).sample(point) * 100.0
+            + Source::worley(43).sample(point) * 10.0
+            + Source::perlin(44).sample(point);
+        assert_eq!(generator.sample(point), expected);
+    }
+    assert_eq!(*a.points.borrow(), points);
+    assert_eq!(*b.points.borrow(), points);
+    assert_eq!(*c.points.borrow(), points);
+}
+
+#[test]
+fn test_zip_with_result_unchanged() {
+    let generator = Source::<3>::constant(2.0).zip_with(Source::constant(3.0), |_, _| f64::NAN);
+    assert!(generator.sample([0.0; 3]).is_nan());
+    let generator = Source::<3>::constant(2.0)
+        .zip_with(Source::constant(3.0), |x, y| x - y)
+        .zip3_with(Source::constant(5.0), Source::constant(7.0), |x, y, z| {
+            x * y + z
+        });
+    assert_eq!(generator.sample([0.2, 0.5, 0.3]), 2.0);
+    assert_eq!(
+        generator.output_bounds(),
+        (f64::NEG_INFINITY, f64::INFINITY)
+    );
+    assert_eq!(
+        generator.clone().to_string(),
+        "\
+Zip3With(zip=<closure>)
+├─ a: ZipWith(zip=<closure>)
+│  ├─ a: Constant(value=2)
+│  └─ b: Constant(value=3)
+├─ b: Constant(value=5)
+└─ c: Constant(value=7)"
+    );
+}
+
+// =================================================================
+// test adapter: tileable_2d
+// =================================================================
+#[test]
+fn test_tileable_2d() {
+    for period in [[64.0, 64.0], [100.0, 37.5], [7.0, 300.0]] {
+        let generator = Source::<4>::simplex(42).scale([0.1; 4]).tileable_2d(period);
+        libnoise::testing::assert_tileable(&generator, period, 64, 1e-9);
+        // edges of the tile are identical
+        for t in [0.0, 0.3, 0.5, 0.9] {
+            let (x, y) = (t * period[0], t * period[1]);
+            assert_eq!(generator.sample([0.0, y]), generator.sample([period[0], y]));
+            assert_eq!(generator.sample([x, 0.0]), generator.sample([x, period[1]]));
+            // other multiples of the period agree up to the rounding of the reduction
+            let difference = generator.sample([x, y]) - generator.sample([x - 3.0 * period[0], y]);
+            assert!(difference.abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_tileable_2d_bounds() {
+    let generator = Source::<4>::simplex(42).add(0.5).tileable_2d([64.0, 32.0]);
+    assert_eq!(
+        generator.output_bounds(),
+        Source::<4>::simplex(42).add(0.5).output_bounds()
+    );
+    assert_bounds_contain_samples(&generator);
+    assert_eq!(
+        generator.to_string(),
+        "\
+Tileable2D(period=[64, 32])
+└─ Add(offset=0.5)
+   └─ Simplex(seed=42)"
+    );
+}
+
+#[test]
+#[should_panic(expected = "period must be positive and finite")]
+fn test_tileable_2d_invalid_period() {
+    Source::<4>::simplex(42).tileable_2d([64.0, 0.0]);
+}
+
+#[test]
+fn test_tileable_fbm() {
+    let period = [96.0, 40.0];
+    for octaves in [1, 2, 4, 8] {
+        for lacunarity in [2.0, 2.17] {
+            let generator =
+                Source::simplex(42).tileable_fbm(period, octaves, 0.05, lacunarity, 0.5);
+            // every octave tiles with the same period, so the edges are identical
+            for t in [0.0, 0.25, 0.6, 0.95] {
+                let (x, y) = (t * period[0], t * period[1]);
+                assert_eq!(generator.sample([0.0, y]), generator.sample([period[0], y]));
+                assert_eq!(generator.sample([x, 0.0]), generator.sample([x, period[1]]));
+            }
+            libnoise::testing::assert_tileable(&generator, period, 32, 1e-9);
+            let reference = Source::<4>::simplex(42)
+                .fbm(octaves, 0.05, lacunarity, 0.5)
+                .tileable_2d(period);
+            assert_eq!(generator.sample([13.7, 5.2]), reference.sample([13.7, 5.2]));
+        }
+    }
+}
+
+#[test]
+fn test_tileable_simplex() {
+    let period = [64.0, 32.0];
+    let generator = Source::tileable_simplex(42, period);
+    libnoise::testing::assert_tileable(&generator, period, 64, 1e-9);
+    for t in [0.0, 0.3, 0.5, 0.9] {
+        let (x, y) = (t * period[0], t * period[1]);
+        assert_eq!(generator.sample([0.0, y]), generator.sample([period[0], y]));
+        assert_eq!(generator.sample([x, 0.0]), generator.sample([x, period[1]]));
+    }
+    let reference = Source::<4>::simplex(42).tileable_2d(period);
+    assert_eq!(
+        generator.sample([10.5, 3.25]),
+        reference.sample([10.5, 3.25])
+    );
+}
+
+#[test]
+#[should_panic(expected = "period must be positive and finite")]
+fn test_tileable_simplex_invalid_period() {
+    Source::tileable_simplex(42, [-64.0, 32.0]);
+}
+
+// =================================================================
+// test adapters: fbm, billow, ridgedmulti, fbm_lod validation
+// =================================================================
+// asserts that an error names the given parameter and value
+fn assert_invalid<T: std::fmt::Debug>(result: Result<T, Error>, parameter: &str, value: &str) {
+    match result {
+        Err(Error::InvalidParameter { name, reason }) => {
+            assert_eq!(name, parameter);
+            assert!(
+                reason.ends_with(&format!("got {value}")),
+                "reason \"{reason}\" does not mention {value}"
+            );
+        }
+        other => panic!("expected an invalid {parameter}, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_fractal_try_new_invalid() {
+    let source = || Source::<2>::simplex(42);
+    assert_invalid(Fbm::try_new(source(), 0, 1.0, 2.0, 0.5), "octaves", "0");
+    assert_invalid(Fbm::try_new(source(), 4, -1.0, 2.0, 0.5), "frequency", "-1");
+    assert_invalid(
+        Fbm::try_new(source(), 4, f64::INFINITY, 2.0, 0.5),
+        "frequency",
+        "inf",
+    );
+    assert_invalid(Fbm::try_new(source(), 4, 1.0, 0.0, 0.5), "lacunarity", "0");
+    assert_invalid(
+        Fbm::try_new(source(), 4, 1.0, f64::NAN, 0.5),
+        "lacunarity",
+        "NaN",
+    );
+    assert_invalid(
+        Fbm::try_new(source(), 4, 1.0, 2.0, 1e10),
+        "persistence",
+        "10000000000",
+    );
+    assert_invalid(
+        Fbm::try_new(source(), 4, 1.0, 2.0, -0.5),
+        "persistence",
+        "-0.5",
+    );
+    assert_invalid(
+        Fbm::try_new(source(), 100, 1.0, 2.0, 1e9),
+        "persistence",
+        "1000000000",
+    );
+
+    assert_invalid(Billow::try_new(source(), 0, 1.0, 2.0, 0.5), "octaves", "0");
+    assert_invalid(
+        Billow::try_new(source(), 4, 0.0, 2.0, 0.5),
+        "frequency",
+        "0",
+    );
+    assert_invalid(
+        Billow::try_new(source(), 4, 1.0, -2.0, 0.5),
+        "lacunarity",
+        "-2",
+    );
+    assert_invalid(
+        Billow::try_new(source(), 4, 1.0, 2.0, f64::NAN),
+        "persistence",
+        "NaN",
+    );
+
+    assert_invalid(
+        RidgedMulti::try_new(source(), 0, 1.0, 2.0, 2.0),
+        "octaves",
+        "0",
+    );
+    assert_invalid(
+        RidgedMulti::try_new(source(), 4, -0.1, 2.0, 2.0),
+        "frequency",
+        "-0.1",
+    );
+    assert_invalid(
+        RidgedMulti::try_new(source(), 4, 1.0, f64::NEG_INFINITY, 2.0),
+        "lacunarity",
+        "-inf",
+    );
+    assert_invalid(
+        RidgedMulti::try_new(source(), 4, 1.0, 2.0, 0.0),
+        "attenuation",
+        "0",
+    );
+    assert_invalid(
+        RidgedMulti::try_new(source(), 100, 1.0, 2.0, 1e-10),
+        "attenuation",
+        "0.0000000001",
+    );
+
+    assert_invalid(FbmLod::try_new(source(), 0, 1.0, 2.0, 0.5), "octaves", "0");
+    assert_invalid(
+        FbmLod::try_new(source(), 4, 1.0, 2.0, 2e9),
+        "persistence",
+        "2000000000",
+    );
+
+    // tweakable parameters are validated with their initial values
+    assert_invalid(
+        Fbm::try_new(
+            source(),
+            4,
+            Param::new(-1.0),
+            Param::new(2.0),
+            Param::new(0.5),
+        ),
+        "frequency",
+        "-1",
+    );
+
+    let error = Fbm::try_new(source(), 4, 1.0, 0.0, 0.5).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid parameter lacunarity: must be positive and finite, got 0"
+    );
+}
+
+#[test]
+fn test_fractal_deserialize_invalid() {
+    use serde_json::json;
+
+    // serializes a valid generator, overwrites the given fields, and deserializes the result
+    fn modified<G: serde::Serialize + serde::de::DeserializeOwned>(
+        generator: &G,
+        fields: &[(&str, serde_json::Value)],
+    ) -> Result<G, String> {
+        let mut json = serde_json::to_value(generator).unwrap();
+        for (field, value) in fields {
+            json[field] = value.clone();
+        }
+        serde_json::from_value(json).map_err(|error| error.to_string())
+    }
+    let source = || Source::<2>::simplex(1);
+    let fbm = source().fbm(2, 1.0, 2.0, 0.5);
+    let error = modified(&fbm, &[("octaves", json!(0)), ("frequency", json!(-3.0))]).unwrap_err();
+    assert_eq!(
+        error,
+        "invalid parameter octaves: must be at least 1, got 0"
+    );
+    let error = modified(&fbm, &[("frequency", json!(-3.0))]).unwrap_err();
+    assert!(error.starts_with("invalid parameter frequency"), "{error}");
+    let error = modified(&fbm, &[("persistence", json!(-0.5))]).unwrap_err();
+    assert!(
+        error.starts_with("invalid parameter persistence"),
+        "{error}"
+    );
+
+    let billow = source().billow(2, 1.0, 2.0, 0.5);
+    let error = modified(&billow, &[("lacunarity", json!(0.0))]).unwrap_err();
+    assert!(error.starts_with("invalid parameter lacunarity"), "{error}");
+    let ridgedmulti = source().ridgedmulti(2, 1.0, 2.0, 2.0);
+    let error = modified(&ridgedmulti, &[("attenuation", json!(0.0))]).unwrap_err();
+    assert!(
+        error.starts_with("invalid parameter attenuation"),
+        "{error}"
+    );
+    let fbm_lod = source().fbm_lod(2, 1.0, 2.0, 0.5);
+    let error = modified(&fbm_lod, &[("octaves", json!(0))]).unwrap_err();
+    assert!(error.starts_with("invalid parameter octaves"), "{error}");
+
+    // valid parameters still deserialize
+    let fbm = modified(&fbm, &[("frequency", json!(0.5))]).unwrap();
+    assert_eq!(
+        fbm.sample([0.3, 0.7]),
+        source().fbm(2, 0.5, 2.0, 0.5).sample([0.3, 0.7])
+    );
+}
+
+#[test]
+fn test_fractal_try_new_boundaries() {
+    let source = || Source::<2>::simplex(42);
+    let point = [0.3, 0.7];
+
+    // a single octave is the scaled underlying generator
+    let fbm = Fbm::try_new(source(), 1, 2.0, 2.0, 0.5).unwrap();
+    assert_eq!(fbm.sample(point), source().sample([0.6, 1.4]));
+    let billow = Billow::try_new(source(), 1, 2.0, 2.0, 0.5).unwrap();
+    assert_eq!(
+        billow.sample(point),
+        source().sample([0.6, 1.4]).abs() * 2.0 - 1.0
+    );
+    RidgedMulti::try_new(source(), 1, 2.0, 2.0, 2.0).unwrap();
+    FbmLod::try_new(source(), 1, 2.0, 2.0, 0.5).unwrap();
+
+    // equal amplitudes average the octaves
+    let fbm = Fbm::try_new(source(), 2, 1.0, 2.0, 1.0).unwrap();
+    let expected = (source().sample(point) + source().sample([0.6, 1.4])) / 2.0;
+    assert!((fbm.sample(point) - expected).abs() < 1e-15);
+    Billow::try_new(source(), 6, 1.0, 2.0, 1.0).unwrap();
+    FbmLod::try_new(source(), 6, 1.0, 2.0, 1.0).unwrap();
+
+    // the extremes of the documented persistence range
+    Fbm::try_new(source(), 6, 1.0, 2.0, 0.0).unwrap();
+    Fbm::try_new(source(), 6, 1.0, 2.0, 1e9).unwrap();
+    RidgedMulti::try_new(source(), 6, 1.0, 2.0, 1e-9).unwrap();
+
+    // try_new agrees with the convenience methods
+    let fbm = Fbm::try_new(source(), 4, 0.5, 2.0, 0.5).unwrap();
+    assert_eq!(
+        fbm.sample(point),
+        source().fbm(4, 0.5, 2.0, 0.5).sample(point)
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "invalid parameter octaves: must be at least 1, got 0")]
+fn test_fbm_invalid_octaves() {
+    Source::<2>::simplex(42).fbm(0, 1.0, 2.0, 0.5);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "invalid parameter attenuation: must be positive and finite, got -2")]
+fn test_ridgedmulti_invalid_attenuation() {
+    Source::<2>::simplex(42).ridgedmulti(4, 1.0, 2.0, -2.0);
+}
+
+// =================================================================
+// test adapter: fbm normalization
+// =================================================================
+#[test]
+fn test_fbm_normalized_range() {
+    for (octaves, persistence) in [(1, 0.5), (8, 0.5), (8, 0.25), (12, 0.8), (6, 1.0), (4, 1.5)] {
+        let generator = Source::<2>::simplex(42).fbm(octaves, 0.013, 2.0, persistence);
+        let max = (0..250_000)
+            .map(|i| {
+                generator
+                    .sample([(i % 500) as f64 * 3.7, (i / 500) as f64 * 3.7])
+                    .abs()
+            })
+            .fold(0.0, f64::max);
+        assert!(
+            max <= 1.0,
+            "max |value| {max} for {octaves} octaves and persistence {persistence}"
+        );
+        // the bound is tight for an underlying generator reaching its extremes in every octave
+        let generator = Source::<2>::constant(-1.0).fbm(octaves, 0.013, 2.0, persistence);
+        assert_eq!(generator.sample([1.0, 2.0]), -1.0);
+    }
+}
+
+#[test]
+fn test_fbm_normalized_mean() {
+    let point = [0.3, 0.7];
+    for octaves in [1, 2, 5, 8] {
+        let generator = Source::<2>::simplex(42).fbm(octaves, 1.0, 2.0, 1.0);
+        let sum: f64 = (0..octaves)
+            .map(|octave| {
+                let frequency = 2.0_f64.powi(octave as i32);
+                Source::<2>::simplex(42).sample(point.map(|x| x * frequency))
+            })
+            .sum();
+        let expected = sum / octaves as f64;
+        assert!(
+            (generator.sample(point) - expected).abs() < 1e-15,
+            "expected the mean {expected} of {octaves} octaves"
+        );
+    }
+}
+
+// =================================================================
+// test adapter: fbm octave views
+// =================================================================
+#[test]
+fn test_fbm_octave_views_sum() {
+    let fbm = Source::<3>::simplex(42).fbm(6, 0.013, 2.0, 0.6);
+    let views = fbm.octave_views();
+    assert_eq!(views.len(), 6);
+    for i in 0..1000 {
+        let point = [
+            i as f64 * 1.7,
+            (i % 37) as f64 * 5.3,
+            (i % 11) as f64 * -9.1,
+        ];
+        let sum: f64 = views.iter().map(|view| view.sample(point)).sum();
+        let value = fbm.sample(point);
+        assert!(
+            (sum - value).abs() < 1e-12,
+            "octaves sum up to {sum} instead of {value} at {point:?}"
+        );
+    }
+
+    // tweaked parameters are reflected in the views
+    let persistence = Param::new(0.5);
+    let fbm =
+        Source::<2>::simplex(42).fbm(4, Param::new(0.1), Param::new(2.0), persistence.clone());
+    persistence.set(0.9);
+    let point = [3.0, 7.0];
+    let sum: f64 = fbm
+        .octave_views()
+        .iter()
+        .map(|view| view.sample(point))
+        .sum();
+    assert!((sum - fbm.sample(point)).abs() < 1e-12);
+}
+
+#[test]
+fn test_fbm_octave_view() {
+    let fbm = Source::<2>::simplex(42).fbm(3, 0.5, 2.0, 0.5);
+    let view = fbm.octave_view(2).unwrap();
+    let point = [1.3, -0.4];
+    // the third octave has a frequency of 2 and an amplitude of 0.25 out of 1.75
+    let expected = 0.25 / 1.75 * Source::<2>::simplex(42).sample([2.6, -0.8]);
+    assert!((view.sample(point) - expected).abs() < 1e-15);
+    assert_interval_contains(&view, 20.0);
+    assert_eq!(
+        view.to_string(),
+        "\
+FbmOctave(octave=2, frequency=2, amplitude=0.14285714285714285)
+└─ Simplex(seed=42)"
+    );
+
+    match fbm.octave_view(3) {
+        Err(Error::InvalidParameter { name, reason }) => {
+            assert_eq!(name, "octave");
+            assert_eq!(reason, "must be less than the number of octaves 3, got 3");
+        }
+        other => panic!("expected an invalid octave, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_fbm_octave_view_dimension_specific_adapters() {
+    let fbm = Source::<1>::simplex(42).fbm(3, 0.5, 2.0, 0.5);
+    let view = fbm.octave_view(1).unwrap();
+    let displaced = view.clone().displace_x(Source::constant(0.5));
+    assert_eq!(displaced.sample([1.3]), view.sample([1.8]));
+
+    let fbm = Source::<2>::simplex(42).fbm(3, 0.5, 2.0, 0.5);
+    let view = fbm.octave_view(1).unwrap();
+    let displaced = view.clone().displace_y(Source::constant(0.5));
+    assert_eq!(displaced.sample([1.3, -0.4]), view.sample([1.3, 0.1]));
+
+    let fbm = Source::<3>::simplex(42).fbm(3, 0.5, 2.0, 0.5);
+    let view = fbm.octave_view(1).unwrap();
+    let displaced = view.clone().displace_z(Source::constant(0.5));
+    assert_eq!(
+        displaced.sample([1.3, -0.4, 2.0]),
+        view.sample([1.3, -0.4, 2.5])
+    );
+
+    let fbm = Source::<4>::simplex(42).fbm(3, 0.5, 2.0, 0.5);
+    let view = fbm.octave_view(1).unwrap();
+    let displaced = view.clone().displace_w(Source::constant(0.5));
+    assert_eq!(
+        displaced.sample([1.3, -0.4, 2.0, 0.25]),
+        view.sample([1.3, -0.4, 2.0, 0.75])
+    );
+}
+
+// =================================================================
+// test adapter: animated_2d
+// =================================================================
+#[test]
+fn test_animated_2d() {
+    let generator = Source::<3>::simplex(42).fbm(3, 0.05, 2.0, 0.5);
+    let mut animated = generator.clone().animated_2d(0.3);
+    for i in 0..200 {
+        let point = [i as f64 * 1.3, (i % 17) as f64 * -2.1];
+        let time = i as f64 * 0.7;
+        assert_eq!(
+            animated.sample_at(point, time),
+            generator.sample([point[0], point[1], time * 0.3])
+        );
+    }
+
+    // the stateful form samples the frame at the time set
+    animated.set_time(2.5);
+    assert_eq!(animated.time(), 2.5);
+    assert_same_samples(&animated, &generator.clone().animated_2d(0.3).at_time(2.5));
+    let point = [4.0, 9.0];
+    assert_eq!(animated.sample(point), animated.sample_at(point, 2.5));
+    assert_interval_contains(&animated, 20.0);
+
+    // successive frames differ
+    let frame = |time| NoiseBuffer::<2>::new([32, 32], &animated.clone().at_time(time));
+    let (a, b) = (frame(1.0), frame(2.0));
+    assert_ne!(a.buffer, b.buffer);
+    assert_eq!(frame(1.0).buffer, a.buffer);
+}
+
+#[test]
+#[should_panic(expected = "time_scale must be finite")]
+fn test_animated_2d_invalid_time_scale() {
+    Source::<3>::simplex(42).animated_2d(f64::NAN);
+}
+
+// =================================================================
+// test adapters: bundle, component, warp
+// =================================================================
+#[test]
+fn test_bundle() {
+    let scalars = || {
+        [
+            Source::<2>::simplex(42).fbm(3, 0.05, 2.0, 0.5),
+            Source::<2>::simplex(43).fbm(3, 0.05, 2.0, 0.5),
+            Source::<2>::simplex(44).fbm(3, 0.05, 2.0, 0.5),
+        ]
+    };
+    let bundle = Bundle::new(scalars());
+    let [a, b, c] = scalars();
+    for i in 0..200 {
+        let point = [i as f64 * 1.3 - 100.0, (i % 17) as f64 * -2.1];
+        assert_eq!(
+            bundle.sample(point),
+            [a.sample(point), b.sample(point), c.sample(point)]
+        );
+    }
+    assert_eq!(
+        VectorGenerator::sample_interval(&bundle, [0.0; 2], [10.0; 2]),
+        [&a, &b, &c].map(|g| g.sample_interval([0.0; 2], [10.0; 2]))
+    );
+    assert_eq!(bundle.to_string().matches("Simplex").count(), 3);
+
+    // extracted components match the bundled generators
+    let [x, y, z] = bundle.components();
+    assert_same_samples(&x, &a);
+    assert_same_samples(&y, &b);
+    assert_same_samples(&z, &c);
+    assert_interval_contains(&z, 20.0);
+    assert_eq!(x.to_string().lines().next(), Some("Component(index=0)"));
+}
+
+#[test]
+#[should_panic(expected = "index must be less than the number of components 2, got 2")]
+fn test_component_invalid_index() {
+    Bundle::new([Source::<2>::simplex(42), Source::simplex(43)]).component(2);
+}
+
+#[test]
+fn test_warp() {
+    let offsets = || {
+        Bundle::new([
+            Source::<2>::simplex(43).mul(3.0),
+            Source::simplex(44).mul(3.0),
+        ])
+    };
+    let generator = Source::<2>::simplex(42).scale([0.1; 2]);
+    let warped = generator.clone().warp(offsets());
+    for i in 0..200 {
+        let point = [i as f64 * 1.3 - 100.0, (i % 17) as f64 * -2.1];
+        let [dx, dy] = offsets().sample(point);
+        assert_eq!(
+            warped.sample(point),
+            generator.sample([point[0] + dx, point[1] + dy])
+        );
+    }
+    assert_interval_contains(&warped, 20.0);
+
+    // warping by bundled components is the same as displacing each axis in turn, as long as the
+    // displacement of the second axis does not depend on the first
+    let displaced = Source::<2>::simplex(42)
+        .displace_x(Source::simplex(43).mul(3.0))
+        .displace_y(Source::simplex(44).mul(3.0));
+    let offsets: [Box<dyn SampleGenerator<2>>; 2] = [
+        Box::new(
+            Source::<2>::simplex(43)
+                .mul(3.0)
+                .displace_y(Source::simplex(44).mul(3.0)),
+        ),
+        Box::new(Source::<2>::simplex(44).mul(3.0)),
+    ];
+    let flat = Source::<2>::simplex(42).warp(Bundle::new(offsets));
+    assert_same_samples(&displaced, &flat);
+}
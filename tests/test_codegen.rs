@@ -0,0 +1,754 @@
+use libnoise::codegen::{self, CodegenError, GlslOptions, GlslVersion};
+use libnoise::prelude::*;
+use libnoise::recipe::NoiseRecipe;
+
+// asserts that every function called with the given prefix is also defined
+fn assert_functions_defined(code: &str, prefix: &str) {
+    let mut rest = code;
+    while let Some(index) = rest.find(prefix) {
+        let tail = &rest[index..];
+        let end = tail
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(tail.len());
+        let identifier = &tail[..end];
+        if tail[end..].starts_with('(') {
+            let call = format!("{}(", identifier);
+            let defined = code.lines().any(|line| {
+                !line.starts_with(' ') && !line.starts_with("//") && line.contains(&call)
+            });
+            assert!(defined, "{} is called but never defined", identifier);
+        }
+        rest = &tail[end..];
+    }
+}
+
+// a generator represented by the given recipe, which is translated without being validated
+struct RawRecipe(NoiseRecipe);
+
+impl Generator<2> for RawRecipe {
+    fn sample(&self, _point: [f64; 2]) -> f64 {
+        unreachable!("only translated")
+    }
+
+    fn to_recipe(&self) -> Result<NoiseRecipe, libnoise::recipe::RecipeError> {
+        Ok(self.0.clone())
+    }
+}
+
+// translates a generator into all shading languages, asserting that they fail with the same error
+fn translate<const D: usize, G: Generator<D>>(generator: &G, name: &str) -> CodegenError {
+    let error = generator.to_wgsl(name).unwrap_err();
+    for version in [
+        GlslVersion::Glsl330,
+        GlslVersion::Glsl450,
+        GlslVersion::Es300,
+    ] {
+        assert_eq!(generator.to_glsl(version, name).unwrap_err(), error);
+        let options = GlslOptions::new(version).with_permutation_texture(0);
+        assert_eq!(generator.to_glsl(options, name).unwrap_err(), error);
+    }
+    error
+}
+
+// asserts that every function is declared before it is called, as required by glsl
+fn assert_functions_declared_before_use(code: &str, prefix: &str) {
+    for (index, line) in code.lines().enumerate() {
+        let Some(declaration) = line.strip_prefix("float ").or(line.strip_prefix("uint ")) else {
+            continue;
+        };
+        let Some(function) = declaration.split('(').next() else {
+            continue;
+        };
+        if !function.starts_with(prefix) || function.contains(' ') {
+            continue;
+        }
+        let call = format!("{}(", function);
+        let preceding = code
+            .lines()
+            .take(index)
+            .any(|line| !line.starts_with("//") && line.contains(&call));
+        assert!(!preceding, "{} is called before it is declared", function);
+    }
+}
+
+// =================================================================
+// test generated code
+// =================================================================
+#[test]
+fn test_codegen_wgsl_nodes() {
+    let generator = Source::<2>::simplex(42)
+        .fbm(2, 0.5, 2.0, 0.5)
+        .sum(Source::simplex(42).mul(0.5))
+        .clamp(-1.0, 1.0);
+    let code = generator.to_wgsl("terrain").unwrap();
+    assert!(code.starts_with("// terrain(p: vec2<f32>) -> f32, generated by libnoise\n"));
+    let expected = "
+fn terrain_n0(p: vec2<f32>) -> f32 {
+    return terrain_simplex(p, 0u);
+}
+
+fn terrain_n1(p: vec2<f32>) -> f32 {
+    var value = 0.0;
+    value += 1.0 * terrain_n0(p * 0.5);
+    value += 0.5 * terrain_n0(p * 1.0);
+    return value * 0.6666667;
+}
+
+fn terrain_n2(p: vec2<f32>) -> f32 {
+    return terrain_n0(p) * 0.5;
+}
+
+fn terrain_n3(p: vec2<f32>) -> f32 {
+    return terrain_n1(p) + terrain_n2(p);
+}
+
+fn terrain(p: vec2<f32>) -> f32 {
+    return clamp(terrain_n3(p), -1.0, 1.0);
+}
+";
+    assert!(code.ends_with(expected), "{}", code);
+    // both simplex sources share a seed, and thereby a single node and permutation table
+    assert!(code.contains("const terrain_PERM = array<u32, 512>("));
+    assert_functions_defined(&code, "terrain");
+}
+
+#[test]
+fn test_codegen_wgsl_all_nodes_3d() {
+    let generator = Source::<3>::simplex(42)
+        .fbm(3, 0.013, 2.0, 0.5)
+        .blend(
+            Source::perlin(43)
+                .scale([0.5, 0.5, 0.5])
+                .billow(2, 1.0, 2.0, 0.5),
+            Source::<3>::value(7).rotate([0.1, 0.2, 0.3]).exp(),
+        )
+        .ridgedmulti(2, 1.0, 2.0, 2.0)
+        .powi(3)
+        .select(
+            Source::checkerboard().translate([0.5, 0.0, 0.0]),
+            Source::constant(0.5).neg().abs(),
+            -0.5,
+            f64::INFINITY,
+        )
+        .displace_y(Source::constant(1.0).powf(2.0))
+        .power(Source::simplex(42).product(Source::value(7)))
+        .min(Source::perlin(43).checked(-1.0, 1.0))
+        .max(
+            Source::simplex(1)
+                .fbm_lod(3, 1.0, 2.0, 0.5)
+                .distance_lod([0.0; 3], 50.0),
+        )
+        .clamp(-1.0, f64::INFINITY);
+    let code = generator.to_wgsl("shape").unwrap();
+    assert!(code.contains("fn shape(p: vec3<f32>) -> f32 {"));
+    // the seeds 42, 43, 7, and 1 each get a table
+    assert!(code.contains("const shape_PERM = array<u32, 2048>("));
+    assert!(code.contains("fn shape_simplex(x: vec3<f32>, table: u32) -> f32 {"));
+    assert!(code.contains("fn shape_perlin(x: vec3<f32>, table: u32) -> f32 {"));
+    assert!(code.contains("fn shape_value(x: vec3<f32>, table: u32) -> f32 {"));
+    assert!(code.contains("return max(shape_n"));
+    assert_functions_defined(&code, "shape");
+}
+
+#[test]
+fn test_codegen_wgsl_omits_unused_library() {
+    let code = Source::<2>::constant(0.5).to_wgsl("flat").unwrap();
+    assert!(!code.contains("PERM"));
+    assert!(!code.contains("flat_simplex"));
+    assert!(code.contains("fn flat(p: vec2<f32>) -> f32 {\n    return 0.5;\n}"));
+}
+
+#[test]
+fn test_codegen_wgsl_deterministic() {
+    let generator = Source::<2>::perlin(3).ridgedmulti(4, 0.1, 2.0, 2.0);
+    assert_eq!(
+        generator.to_wgsl("a").unwrap(),
+        generator.clone().to_wgsl("a").unwrap()
+    );
+}
+
+#[test]
+fn test_codegen_wgsl_transform() {
+    let generator = Source::<2>::simplex(42)
+        .translate([1.0, 2.0])
+        .scale([0.5, 0.25])
+        .translate([3.0, 4.0]);
+    let recipe = generator.to_recipe().unwrap().optimize::<2>().unwrap();
+    assert!(matches!(recipe, NoiseRecipe::Transform { .. }));
+    assert_eq!(
+        recipe.build::<2>().unwrap().to_wgsl("terrain").unwrap(),
+        generator.to_wgsl("terrain").unwrap()
+    );
+}
+
+#[test]
+fn test_codegen_checkerboard() {
+    let generator = Source::<2>::checkerboard()
+        .with_period([2.0, 0.5])
+        .smooth(true)
+        .sum(Source::checkerboard());
+    let code = generator.to_wgsl("tiles").unwrap();
+    assert!(code.contains("fn tiles_checkerboard(x: vec2<f32>) -> f32 {"));
+    assert!(code.contains(
+        "fn tiles_checkerboard_smooth(x: vec2<f32>) -> f32 {
+    let s = sin(3.14159265 * x);
+    return -(s.x * s.y);
+}"
+    ));
+    assert!(code.contains("return tiles_checkerboard_smooth(p / vec2<f32>(2.0, 0.5));"));
+    assert!(code.contains("return tiles_checkerboard(p);"));
+    assert_functions_defined(&code, "tiles");
+    let code = Source::<3>::checkerboard()
+        .with_period([1.0, 3.0, 1.0])
+        .to_glsl(GlslVersion::Glsl330, "tiles")
+        .unwrap();
+    assert!(!code.contains("tiles_checkerboard_smooth"));
+    assert!(code.contains("return tiles_checkerboard(p / vec3(1.0, 3.0, 1.0));"));
+    assert_functions_defined(&code, "tiles");
+    assert_functions_declared_before_use(&code, "tiles");
+}
+
+#[test]
+fn test_codegen_glsl_nodes() {
+    let generator = Source::<2>::simplex(42)
+        .fbm(2, 0.5, 2.0, 0.5)
+        .sum(Source::simplex(42).mul(0.5))
+        .clamp(-1.0, 1.0);
+    let code = generator.to_glsl(GlslVersion::Glsl330, "terrain").unwrap();
+    assert!(code.starts_with("// float terrain(vec2 p), generated by libnoise\n"));
+    let expected = "
+float terrain_n0(vec2 p) {
+    return terrain_simplex(p, 0u);
+}
+
+float terrain_n1(vec2 p) {
+    float value = 0.0;
+    value += 1.0 * terrain_n0(p * 0.5);
+    value += 0.5 * terrain_n0(p * 1.0);
+    return value * 0.6666667;
+}
+
+float terrain_n2(vec2 p) {
+    return terrain_n0(p) * 0.5;
+}
+
+float terrain_n3(vec2 p) {
+    return terrain_n1(p) + terrain_n2(p);
+}
+
+float terrain(vec2 p) {
+    return clamp(terrain_n3(p), -1.0, 1.0);
+}
+";
+    assert!(code.ends_with(expected), "{}", code);
+    assert!(code.contains("const uint terrain_PERM[512] = uint[512](\n"));
+    assert!(code.contains(
+        "
+uint terrain_perm(uint table, uint i) {
+    return terrain_PERM[table * 512u + i];
+}
+"
+    ));
+    assert_functions_defined(&code, "terrain");
+    assert_functions_declared_before_use(&code, "terrain");
+}
+
+#[test]
+fn test_codegen_glsl_all_nodes_3d() {
+    let generator = Source::<3>::simplex(42)
+        .fbm(3, 0.013, 2.0, 0.5)
+        .blend(
+            Source::perlin(43)
+                .scale([0.5, 0.5, 0.5])
+                .billow(2, 1.0, 2.0, 0.5),
+            Source::<3>::value(7).rotate([0.1, 0.2, 0.3]).exp(),
+        )
+        .ridgedmulti(2, 1.0, 2.0, 2.0)
+        .powi(3)
+        .select(
+            Source::checkerboard().translate([0.5, 0.0, 0.0]),
+            Source::constant(0.5).neg().abs(),
+            -0.5,
+            f64::INFINITY,
+        )
+        .displace_y(Source::constant(1.0).powf(2.0))
+        .power(Source::simplex(42).product(Source::value(7)))
+        .min(Source::perlin(43).checked(-1.0, 1.0))
+        .max(
+            Source::simplex(1)
+                .fbm_lod(3, 1.0, 2.0, 0.5)
+                .distance_lod([0.0; 3], 50.0),
+        )
+        .clamp(-1.0, f64::INFINITY);
+    let code = generator.to_glsl(GlslVersion::Glsl450, "shape").unwrap();
+    assert!(code.contains("float shape(vec3 p) {"));
+    assert!(code.contains("const uint shape_PERM[2048] = uint[2048](\n"));
+    assert!(code.contains("\nfloat shape_simplex(vec3 x, uint table) {"));
+    assert!(code.contains("\nfloat shape_perlin(vec3 x, uint table) {"));
+    assert!(code.contains("\nfloat shape_value(vec3 x, uint table) {"));
+    assert!(code.contains("(mat3("));
+    // glsl does not allow trailing commas in array constructors
+    assert!(!code.contains(",\n)"));
+    assert_functions_defined(&code, "shape");
+    assert_functions_declared_before_use(&code, "shape");
+    // both languages translate the same nodes
+    let wgsl = generator.to_wgsl("shape").unwrap();
+    assert_eq!(
+        code.matches("\nfloat shape_n").count(),
+        wgsl.matches("\nfn shape_n").count()
+    );
+}
+
+#[test]
+fn test_codegen_glsl_permutation_texture() {
+    let generator = Source::<2>::simplex(42).blend(Source::perlin(42), Source::value(43).add(1.0));
+    let expected = "
+uint noise_perm(uint table, uint i) {
+    return texelFetch(noise_PERM, ivec2(int(i), int(table)), 0).r;
+}
+";
+    for (version, declaration) in [
+        (GlslVersion::Glsl330, "\nuniform usampler2D noise_PERM;\n"),
+        (
+            GlslVersion::Glsl450,
+            "\nlayout(binding = 3) uniform usampler2D noise_PERM;\n",
+        ),
+        (
+            GlslVersion::Es300,
+            "\nuniform highp usampler2D noise_PERM;\n",
+        ),
+    ] {
+        let options = GlslOptions::new(version).with_permutation_texture(3);
+        let code = generator.to_glsl(options, "noise").unwrap();
+        assert!(code.contains(declaration), "{}", code);
+        assert!(code.contains(expected), "{}", code);
+        assert!(!code.contains("const uint noise_PERM"));
+    }
+
+    // the texture holds the same tables as the constant array
+    let code = generator.to_glsl(GlslVersion::Glsl330, "noise").unwrap();
+    let start = code.find("uint[1024](").unwrap() + "uint[1024](".len();
+    let end = start + code[start..].find(");").unwrap();
+    let entries = code[start..end]
+        .split(',')
+        .map(|entry| entry.trim().trim_end_matches('u').parse::<u8>().unwrap())
+        .collect::<Vec<_>>();
+    let texture = codegen::permutation_texture(&generator).unwrap();
+    assert_eq!(texture.len(), 2 * 512);
+    assert_eq!(texture, entries);
+    assert!(codegen::permutation_texture(&Source::<2>::checkerboard())
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_codegen_glsl_version_directives() {
+    assert_eq!(GlslVersion::Glsl330.directive(), "#version 330 core");
+    assert_eq!(GlslVersion::Glsl450.directive(), "#version 450");
+    assert_eq!(GlslVersion::Es300.directive(), "#version 300 es");
+}
+
+// =================================================================
+// test errors shared by all shading languages
+// =================================================================
+#[test]
+fn test_codegen_unsupported_nodes() {
+    let generator = Source::<2>::simplex(1).blend(
+        Source::simplex(2),
+        Source::simplex(3).lambda(f64::sin).mul(2.0),
+    );
+    assert_eq!(
+        translate(&generator, "noise"),
+        CodegenError::Unsupported {
+            path: "control.source".to_string(),
+            node: "Lambda",
+        }
+    );
+    let generator = Source::<3>::simplex(1).sum(Source::worley(2));
+    let error = translate(&generator, "noise");
+    assert_eq!(
+        error,
+        CodegenError::Unsupported {
+            path: "b".to_string(),
+            node: "worley",
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "worley node at b cannot be translated into shader code"
+    );
+    assert!(matches!(
+        translate(&Source::<2>::improved_perlin(1), "noise"),
+        CodegenError::Unsupported {
+            node: "improved_perlin",
+            ..
+        }
+    ));
+    assert!(matches!(
+        codegen::permutation_texture(&Source::<2>::custom(|[x, _]| x)),
+        Err(CodegenError::Unsupported { node: "Custom", .. })
+    ));
+}
+
+#[test]
+fn test_codegen_unsupported_dimensions() {
+    assert_eq!(
+        translate(&Source::<1>::simplex(1), "noise"),
+        CodegenError::UnsupportedDimension { dimension: 1 }
+    );
+    assert_eq!(
+        translate(&Source::<4>::simplex(1), "noise"),
+        CodegenError::UnsupportedDimension { dimension: 4 }
+    );
+}
+
+#[test]
+fn test_codegen_invalid_names() {
+    for name in ["", "1noise", "my-noise", "_", "__noise", "nöise", "clamp"] {
+        assert_eq!(
+            translate(&Source::<2>::simplex(1), name),
+            CodegenError::InvalidName {
+                name: name.to_string()
+            }
+        );
+    }
+    assert!(Source::<2>::simplex(1).to_wgsl("_noise2").is_ok());
+    assert!(Source::<2>::simplex(1)
+        .to_glsl(GlslVersion::Es300, "_noise2")
+        .is_ok());
+}
+
+#[test]
+fn test_codegen_invalid_parameters() {
+    let error = translate(&Source::<2>::simplex(1).add(f64::MAX), "noise");
+    assert!(matches!(
+        error,
+        CodegenError::InvalidParameter {
+            node: "add",
+            parameter: "offset",
+            ..
+        }
+    ));
+    // debug builds already reject the invalid octaves when building the chain, so the recipe is
+    // built directly
+    let generator = RawRecipe(NoiseRecipe::Fbm {
+        source: Box::new(NoiseRecipe::Mul {
+            source: Box::new(NoiseRecipe::Simplex {
+                seed: 1,
+                large_coordinates: false,
+                r_squared: None,
+            }),
+            scale: 2.0,
+        }),
+        octaves: 0,
+        frequency: 1.0,
+        lacunarity: 2.0,
+        persistence: 0.5,
+    });
+    assert_eq!(
+        translate(&generator, "noise"),
+        CodegenError::InvalidParameter {
+            path: String::new(),
+            node: "fbm",
+            parameter: "octaves",
+            reason: "must be at least 1",
+        }
+    );
+    let generator = Source::<2>::simplex_with_radius(1, 0.3)
+        .unwrap()
+        .scale([0.1; 2]);
+    assert_eq!(
+        translate(&generator, "noise"),
+        CodegenError::InvalidParameter {
+            path: "source".to_string(),
+            node: "simplex",
+            parameter: "r_squared",
+            reason: "is only supported at its default of 0.5",
+        }
+    );
+}
+
+// =================================================================
+// test errors specific to a shading language
+// =================================================================
+#[test]
+fn test_codegen_reserved_names() {
+    let generator = Source::<2>::simplex(1);
+    for name in ["sample", "smooth", "input", "output", "uint", "gl_Position"] {
+        assert_eq!(
+            generator.to_glsl(GlslVersion::Glsl450, name),
+            Err(CodegenError::InvalidName {
+                name: name.to_string()
+            })
+        );
+    }
+    for name in ["fn", "let", "loop", "f32", "vec2"] {
+        assert_eq!(
+            generator.to_wgsl(name),
+            Err(CodegenError::InvalidName {
+                name: name.to_string()
+            })
+        );
+    }
+    // names reserved by only one language are allowed by the other
+    assert!(generator.to_wgsl("sample").is_ok());
+    assert!(generator.to_glsl(GlslVersion::Glsl450, "loop").is_ok());
+}
+
+// =================================================================
+// test agreement of the generated sources with the CPU
+// =================================================================
+// the following functions transcribe the sources as generated in WGSL into single precision
+// rust, line by line, such that the precision promised by the documentation can be verified
+// without a GPU. every constant used is asserted to appear in the generated code, and the
+// permutation table is read from it
+
+// the permutation table embedded into the generated code
+fn shader_permutation_table(code: &str) -> Vec<u32> {
+    let start = code.find("_PERM = array<u32, 512>(").unwrap();
+    let body = &code[start..];
+    let body = &body[body.find('(').unwrap() + 1..body.find(");").unwrap()];
+    let table = body
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| entry.trim_end_matches('u').parse().unwrap())
+        .collect::<Vec<u32>>();
+    assert_eq!(table.len(), 512);
+    table
+}
+
+fn shader_hash<const D: usize>(perm: &[u32], i: [u32; D]) -> u32 {
+    i.iter()
+        .fold(0, |hash, &component| perm[(component + hash) as usize])
+}
+
+fn shader_wrap<const D: usize>(x: [f32; D]) -> [u32; D] {
+    x.map(|x| (x as i32 & 255) as u32)
+}
+
+fn shader_lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + t * (b - a)
+}
+
+fn shader_smoothstep(t: f32) -> f32 {
+    t * t * (t * -2.0 + 3.0)
+}
+
+fn shader_dot<const D: usize>(a: [f32; D], b: [f32; D]) -> f32 {
+    (0..D).map(|k| a[k] * b[k]).sum()
+}
+
+fn shader_simplex_contribution<const D: usize>(x: [f32; D], gradient: [f32; D]) -> f32 {
+    let mut t = 0.5 - shader_dot(x, x);
+    if t <= 0.0 {
+        return 0.0;
+    }
+    t = t * t;
+    t * t * shader_dot(gradient, x)
+}
+
+const SHADER_SIMPLEX_GRADIENTS_2D: [[f32; 2]; 4] =
+    [[0.0, -1.0], [-1.0, 0.0], [0.0, 1.0], [1.0, 0.0]];
+
+fn shader_simplex2d(perm: &[u32], x: [f32; 2]) -> f32 {
+    let skew = (x[0] + x[1]) * 0.36602542;
+    let cell = x.map(|x| (x + skew).floor());
+    let unskew = (cell[0] + cell[1]) * 0.21132487;
+    let x0 = [x[0] - cell[0] + unskew, x[1] - cell[1] + unskew];
+    let i = shader_wrap(cell);
+    let i1 = if x0[0] < x0[1] { [0, 1] } else { [1, 0] };
+    let x1 = [0, 1].map(|k| x0[k] - i1[k] as f32 + 0.21132487);
+    let x2 = x0.map(|x| x - 1.0 + 2.0 * 0.21132487);
+    let corners = [i, [i[0] + i1[0], i[1] + i1[1]], i.map(|i| i + 1)];
+    let n = [x0, x1, x2]
+        .iter()
+        .zip(corners)
+        .map(|(&x, corner)| {
+            let gradient = SHADER_SIMPLEX_GRADIENTS_2D[(shader_hash(perm, corner) % 4) as usize];
+            shader_simplex_contribution(x, gradient)
+        })
+        .collect::<Vec<_>>();
+    (n[0] + n[1] + n[2]) * 99.83685
+}
+
+const SHADER_SIMPLEX_TRAVERSAL_3D: [[u32; 3]; 16] = [
+    [0, 0, 1],
+    [0, 1, 1],
+    [0, 0, 0],
+    [0, 0, 0],
+    [0, 1, 0],
+    [0, 1, 1],
+    [0, 1, 0],
+    [1, 1, 0],
+    [0, 0, 1],
+    [1, 0, 1],
+    [1, 0, 0],
+    [1, 0, 1],
+    [0, 0, 0],
+    [0, 0, 0],
+    [1, 0, 0],
+    [1, 1, 0],
+];
+
+const SHADER_SIMPLEX_GRADIENTS_3D: [[f32; 3]; 16] = [
+    [0.0, -1.0, -1.0],
+    [-1.0, 0.0, -1.0],
+    [-1.0, -1.0, 0.0],
+    [0.0, 1.0, -1.0],
+    [1.0, 0.0, -1.0],
+    [1.0, -1.0, 0.0],
+    [0.0, -1.0, 1.0],
+    [-1.0, 0.0, 1.0],
+    [-1.0, 1.0, 0.0],
+    [0.0, 1.0, 1.0],
+    [1.0, 0.0, 1.0],
+    [1.0, 1.0, 0.0],
+    [0.0, -1.0, -1.0],
+    [0.0, -1.0, 1.0],
+    [-1.0, 1.0, 0.0],
+    [1.0, 1.0, 0.0],
+];
+
+fn shader_simplex3d(perm: &[u32], x: [f32; 3]) -> f32 {
+    let skew = (x[0] + x[1] + x[2]) * 0.33333334;
+    let cell = x.map(|x| (x + skew).floor());
+    let unskew = (cell[0] + cell[1] + cell[2]) * 0.16666667;
+    let x0 = [0, 1, 2].map(|k| x[k] - cell[k] + unskew);
+    let i = shader_wrap(cell);
+    let traversal =
+        (x0[0] > x0[1]) as usize * 4 + (x0[1] > x0[2]) as usize * 2 + (x0[0] > x0[2]) as usize;
+    let i1 = SHADER_SIMPLEX_TRAVERSAL_3D[traversal * 2];
+    let i2 = SHADER_SIMPLEX_TRAVERSAL_3D[traversal * 2 + 1];
+    let x1 = [0, 1, 2].map(|k| x0[k] - i1[k] as f32 + 0.16666667);
+    let x2 = [0, 1, 2].map(|k| x0[k] - i2[k] as f32 + 2.0 * 0.16666667);
+    let x3 = x0.map(|x| x - 1.0 + 3.0 * 0.16666667);
+    let corners = [
+        i,
+        [0, 1, 2].map(|k| i[k] + i1[k]),
+        [0, 1, 2].map(|k| i[k] + i2[k]),
+        i.map(|i| i + 1),
+    ];
+    let n = [x0, x1, x2, x3]
+        .iter()
+        .zip(corners)
+        .map(|(&x, corner)| {
+            let gradient = SHADER_SIMPLEX_GRADIENTS_3D[(shader_hash(perm, corner) % 16) as usize];
+            shader_simplex_contribution(x, gradient)
+        })
+        .collect::<Vec<_>>();
+    (n[0] + n[1] + n[2] + n[3]) * 76.88376
+}
+
+// interpolates the values at the corners of the cell containing the point, where the corner
+// offset along axis k is given by bit D - 1 - k of the corner index
+fn shader_interpolate<const D: usize>(x: [f32; D], corner: impl Fn([u32; D]) -> f32) -> f32 {
+    let cell = x.map(f32::floor);
+    let dxs = core::array::from_fn::<_, D, _>(|k| shader_smoothstep(x[k] - cell[k]));
+    let mut values = (0..1 << D)
+        .map(|index: usize| corner(core::array::from_fn(|k| (index >> (D - 1 - k)) as u32 & 1)))
+        .collect::<Vec<_>>();
+    for t in dxs {
+        let half = values.len() / 2;
+        values = (0..half)
+            .map(|index| shader_lerp(values[index], values[index + half], t))
+            .collect();
+    }
+    values[0]
+}
+
+fn shader_perlin<const D: usize>(perm: &[u32], x: [f32; D]) -> f32 {
+    let cell = x.map(f32::floor);
+    let dx = core::array::from_fn::<_, D, _>(|k| x[k] - cell[k]);
+    let i = shader_wrap(cell);
+    shader_interpolate(x, |corner| {
+        let hash = shader_hash::<D>(perm, core::array::from_fn(|k| i[k] + corner[k]));
+        // the gradients are ordered such that bit D - 1 - k of their index gives the sign of
+        // component k
+        let index = hash % (1 << D);
+        let gradient: [f32; D] = core::array::from_fn(|k| {
+            if (index >> (D - 1 - k)) & 1 == 1 {
+                1.0
+            } else {
+                -1.0
+            }
+        });
+        shader_dot::<D>(gradient, core::array::from_fn(|k| dx[k] - corner[k] as f32))
+    })
+}
+
+fn shader_value<const D: usize>(perm: &[u32], x: [f32; D]) -> f32 {
+    let i = shader_wrap(x.map(f32::floor));
+    let value = shader_interpolate(x, |corner| {
+        shader_hash::<D>(perm, core::array::from_fn(|k| i[k] + corner[k])) as f32
+    });
+    2.0 / 256.0 * value - 1.0
+}
+
+// asserts that the transcribed source agrees with the generated code, and that both deviate
+// from the CPU by less than 1e-3 for coordinates of magnitude up to 1000
+fn assert_shader_matches_cpu<const D: usize, G: Generator<D>>(
+    generator: &G,
+    constants: &[&str],
+    shader: impl Fn(&[u32], [f32; D]) -> f32,
+) {
+    let code = generator.to_wgsl("noise").unwrap();
+    for constant in constants {
+        assert!(
+            code.contains(constant),
+            "{} missing from\n{}",
+            constant,
+            code
+        );
+    }
+    let perm = shader_permutation_table(&code);
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut random = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 11) as f64 / (1_u64 << 53) as f64 * 2.0 - 1.0
+    };
+    for magnitude in [1.0, 10.0, 100.0, 1000.0] {
+        for _ in 0..5000 {
+            let point = core::array::from_fn::<_, D, _>(|_| random() * magnitude);
+            let expected = generator.sample(point);
+            let actual = shader(&perm, point.map(|x| x as f32)) as f64;
+            assert!(
+                (actual - expected).abs() < 1e-3,
+                "shader returned {} instead of {} at {:?}",
+                actual,
+                expected,
+                point
+            );
+        }
+    }
+}
+
+#[test]
+fn test_codegen_sources_match_cpu() {
+    for seed in [0, 42, 1337] {
+        assert_shader_matches_cpu(
+            &Source::<2>::simplex(seed),
+            &["0.36602542", "0.21132487", "99.83685", "% 4u"],
+            shader_simplex2d,
+        );
+        assert_shader_matches_cpu(
+            &Source::<3>::simplex(seed),
+            &["0.33333334", "0.16666667", "76.88376", "% 16u"],
+            shader_simplex3d,
+        );
+        assert_shader_matches_cpu(
+            &Source::<2>::perlin(seed),
+            &["vec2<f32>(-1.0, 1.0),\n    vec2<f32>(1.0, -1.0)", "% 4u"],
+            shader_perlin,
+        );
+        assert_shader_matches_cpu(
+            &Source::<3>::perlin(seed),
+            &["* 0.6666666666666666", "% 8u"],
+            |perm, x| shader_perlin(perm, x) * 0.666_666_7,
+        );
+        assert_shader_matches_cpu(&Source::<2>::value(seed), &["2.0 / 256.0"], shader_value);
+        assert_shader_matches_cpu(&Source::<3>::value(seed), &["2.0 / 256.0"], shader_value);
+    }
+}
@@ -0,0 +1,376 @@
+use libnoise::prelude::*;
+use std::fmt::Write;
+
+// The golden vectors are stored as one `<generator> <seed> <point> <value>` entry per line, where
+// the point is a comma-separated list of coordinates and the value is given by its bit pattern.
+// After an intended change of the output, regenerate them using:
+//
+//     cargo test --test test_determinism -- --ignored
+const GOLDEN_VECTORS: &str = include_str!("data/golden_vectors.txt");
+const GOLDEN_VECTORS_PATH: &str = "tests/data/golden_vectors.txt";
+
+const GENERATORS: &[&str] = &[
+    "simplex",
+    "simplex_large",
+    "value",
+    "perlin",
+    "improved_perlin",
+    "worley",
+    "checkerboard",
+    "fbm",
+    "billow",
+    "ridgedmulti",
+    "chain",
+    "white",
+    "spot",
+    "worley_edge",
+    "simplex_radius",
+    "vector_simplex",
+];
+
+// generators which are only sampled in some dimensionalities, along with those
+const GENERATORS_BY_DIMENSION: &[(&str, &[usize])] = &[
+    ("island", &[2]),
+    ("blue", &[2]),
+    ("simplex", &[5, 6, 8]),
+    ("simplex_large", &[5, 6, 8]),
+    ("simplex_radius", &[5, 6, 8]),
+    ("vector_simplex", &[5, 6, 8]),
+];
+
+macro_rules! sample_golden {
+    ($dim:literal, $name:expr, $seed:expr, $point:expr) => {
+        match $name {
+            "simplex" => Source::<$dim>::simplex($seed).sample($point),
+            "simplex_large" => Simplex::<$dim>::new($seed)
+                .with_large_coordinates()
+                .sample($point),
+            "value" => Source::<$dim>::value($seed).sample($point),
+            "perlin" => Source::<$dim>::perlin($seed).sample($point),
+            "improved_perlin" => Source::<$dim>::improved_perlin($seed).sample($point),
+            "worley" => Source::<$dim>::worley($seed).sample($point),
+            "worley_edge" => Source::<$dim>::worley($seed)
+                .with_return_type(WorleyReturnType::EdgeDistance)
+                .sample($point),
+            "simplex_radius" => Source::<$dim>::simplex_with_radius($seed, 0.3)
+                .unwrap()
+                .sample($point),
+            "vector_simplex" => Source::<$dim>::vector_simplex::<2>($seed).sample($point)[1],
+            "checkerboard" => Source::<$dim>::checkerboard().sample($point),
+            "white" => Source::<$dim>::white($seed).sample($point),
+            "spot" => Source::<$dim>::spot($seed)
+                .with_kernel(SpotKernel::Ring)
+                .with_radius(0.9)
+                .with_amplitude_jitter(0.5)
+                .sample($point),
+            "fbm" => Source::<$dim>::simplex($seed)
+                .fbm(5, 0.3, 2.0, 0.5)
+                .sample($point),
+            "billow" => Source::<$dim>::perlin($seed)
+                .billow(4, 0.3, 2.0, 0.5)
+                .sample($point),
+            "ridgedmulti" => Source::<$dim>::value($seed)
+                .ridgedmulti(4, 0.3, 2.0, 2.0)
+                .sample($point),
+            "chain" => Source::<$dim>::simplex($seed)
+                .scale([0.7; $dim])
+                .translate([-3.5; $dim])
+                .abs()
+                .mul(2.0)
+                .add(-1.0)
+                .blend(
+                    Source::worley($seed + 1),
+                    Source::improved_perlin($seed + 2).clamp(-0.5, 0.5),
+                )
+                .sample($point),
+            name => panic!("unknown generator {}", name),
+        }
+    };
+}
+
+// the simplex noise beyond 4 dimensions, which uses a generic implementation
+macro_rules! sample_golden_nd {
+    ($dim:literal, $name:expr, $seed:expr, $point:expr) => {{
+        let point: [f64; $dim] = $point.try_into().unwrap();
+        match $name {
+            "simplex" => Source::<$dim>::simplex($seed).sample(point),
+            "simplex_large" => Simplex::<$dim>::new($seed)
+                .with_large_coordinates()
+                .sample(point),
+            "simplex_radius" => Source::<$dim>::simplex_with_radius($seed, 0.3)
+                .unwrap()
+                .sample(point),
+            "vector_simplex" => Source::<$dim>::vector_simplex::<2>($seed).sample(point)[1],
+            name => panic!("unknown generator {}", name),
+        }
+    }};
+}
+
+fn sample(name: &str, seed: u64, point: &[f64]) -> f64 {
+    match (name, point.len()) {
+        ("island", 2) => Source::island([3.0, -2.0], 40.0, FalloffCurve::SquareBump)
+            .unwrap()
+            .sample([point[0], point[1]]),
+        ("blue", 2) => Source::blue(seed, 16).sample([point[0], point[1]]),
+        (name, 1) => sample_golden!(1, name, seed, [point[0]]),
+        (name, 2) => sample_golden!(2, name, seed, [point[0], point[1]]),
+        (name, 3) => sample_golden!(3, name, seed, [point[0], point[1], point[2]]),
+        (name, 4) => sample_golden!(4, name, seed, [point[0], point[1], point[2], point[3]]),
+        (name, 5) => sample_golden_nd!(5, name, seed, point),
+        (name, 6) => sample_golden_nd!(6, name, seed, point),
+        (name, 8) => sample_golden_nd!(8, name, seed, point),
+        (_, dim) => panic!("unsupported dimensionality {}", dim),
+    }
+}
+
+fn parse_entry(line: &str) -> (&str, u64, Vec<f64>, u64) {
+    let mut fields = line.split_whitespace();
+    let mut next = || fields.next().expect("incomplete golden vector");
+    let name = next();
+    let seed = next().parse().unwrap();
+    let point = next().split(',').map(|x| x.parse().unwrap()).collect();
+    let bits = u64::from_str_radix(next().trim_start_matches("0x"), 16).unwrap();
+    (name, seed, point, bits)
+}
+
+#[test]
+fn test_golden_vectors() {
+    let mut count = 0;
+    for line in GOLDEN_VECTORS.lines() {
+        let (name, seed, point, bits) = parse_entry(line);
+        let value = sample(name, seed, &point);
+        assert_eq!(
+            value.to_bits(),
+            bits,
+            "{} (seed {}) at {:?} produced {:?}, expected {:?}",
+            name,
+            seed,
+            point,
+            value,
+            f64::from_bits(bits)
+        );
+        count += 1;
+    }
+    assert!(count >= 300, "only {} golden vectors found", count);
+}
+
+#[test]
+#[ignore = "regenerates the golden vectors"]
+fn regenerate_golden_vectors() {
+    // a fixed linear congruential generator yields reproducible points of varying magnitude
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut coordinate = |magnitude: f64| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 11) as f64 / (1_u64 << 53) as f64 * 2.0 - 1.0) * magnitude
+    };
+    let all_dimensions: &[usize] = &[1, 2, 3, 4];
+    let generators = GENERATORS
+        .iter()
+        .map(|&name| (name, all_dimensions))
+        .chain(GENERATORS_BY_DIMENSION.iter().copied());
+    let mut out = String::new();
+    for (i, (name, dimensions)) in generators.enumerate() {
+        for &dim in dimensions {
+            for magnitude in [1.0, 10.0, 1000.0, 1e6, 1e9, 1.0, 50.0, 5e4] {
+                let seed = (i * 4 + dim) as u64 * 7919;
+                let point = (0..dim).map(|_| coordinate(magnitude)).collect::<Vec<_>>();
+                let value = sample(name, seed, &point);
+                let point = point.iter().map(|x| format!("{:?}", x)).collect::<Vec<_>>();
+                writeln!(
+                    out,
+                    "{} {} {} {:#018x}",
+                    name,
+                    seed,
+                    point.join(","),
+                    value.to_bits()
+                )
+                .unwrap();
+            }
+        }
+    }
+    std::fs::write(GOLDEN_VECTORS_PATH, out).unwrap();
+}
+
+// fingerprints of the built-in sources seeded with 42 on [-50, 50]^D with 4096 samples each
+const GOLDEN_FINGERPRINTS: &[(&str, usize, u64)] = &[
+    ("simplex", 1, 0x05ec6d1545193c71),
+    ("simplex", 2, 0x3a9f375d7f26ffef),
+    ("simplex", 3, 0x6b8f76102b9bd3ca),
+    ("simplex", 4, 0xa034db9cbc201c52),
+    ("value", 1, 0xd195446c2ac8fcc5),
+    ("value", 2, 0x77224a47bfcde0fe),
+    ("value", 3, 0xd97e98d96abd4ec1),
+    ("value", 4, 0xd5c2d3ca5bed97d8),
+    ("perlin", 1, 0x8364d7319da9f166),
+    ("perlin", 2, 0xf68be0dcb2e1ebcc),
+    ("perlin", 3, 0x29f8aadcc96eb7fa),
+    ("perlin", 4, 0xbb2e713bf01c2f84),
+    ("improved_perlin", 1, 0x232720ee1e97f743),
+    ("improved_perlin", 2, 0x0d1d44c02a8493ea),
+    ("improved_perlin", 3, 0xf9c66ce8f67e8f50),
+    ("improved_perlin", 4, 0x43b29db12e1e2489),
+    ("worley", 1, 0xcfcffa0b650e315c),
+    ("worley", 2, 0x6a36ecc79f562607),
+    ("worley", 3, 0x686ef5e8838b2628),
+    ("worley", 4, 0xd52a438ea8fd6b09),
+    ("checkerboard", 1, 0xe6f36e7bd6ac9925),
+    ("checkerboard", 2, 0x1b72b96731c53b25),
+    ("checkerboard", 3, 0x351244ad5a96c325),
+    ("checkerboard", 4, 0xbb5de6b56d332325),
+];
+
+macro_rules! fingerprint_source {
+    ($dim:literal, $name:expr) => {{
+        let samples = [4096, 64, 16, 8][$dim - 1];
+        let (min, max) = ([-50.0; $dim], [50.0; $dim]);
+        match $name {
+            "simplex" => Source::<$dim>::simplex(42).fingerprint(min, max, samples),
+            "value" => Source::<$dim>::value(42).fingerprint(min, max, samples),
+            "perlin" => Source::<$dim>::perlin(42).fingerprint(min, max, samples),
+            "improved_perlin" => Source::<$dim>::improved_perlin(42).fingerprint(min, max, samples),
+            "worley" => Source::<$dim>::worley(42).fingerprint(min, max, samples),
+            "checkerboard" => Source::<$dim>::checkerboard().fingerprint(min, max, samples),
+            name => panic!("unknown source {}", name),
+        }
+    }};
+}
+
+fn fingerprint_source(name: &str, dim: usize) -> u64 {
+    match dim {
+        1 => fingerprint_source!(1, name),
+        2 => fingerprint_source!(2, name),
+        3 => fingerprint_source!(3, name),
+        4 => fingerprint_source!(4, name),
+        dim => panic!("unsupported dimensionality {}", dim),
+    }
+}
+
+#[test]
+fn test_golden_fingerprints() {
+    for &(name, dim, expected) in GOLDEN_FINGERPRINTS {
+        let fingerprint = fingerprint_source(name, dim);
+        assert_eq!(
+            fingerprint, expected,
+            "fingerprint of {} in {}D changed to {:#018x}",
+            name, dim, fingerprint
+        );
+    }
+}
+
+// fingerprints of the presets seeded with 42 on [0, 1000]^2 with 64 samples per axis, and on
+// [0, 100]^3 with 16 samples per axis
+const GOLDEN_PRESET_FINGERPRINTS: &[(&str, u64)] = &[
+    ("mountains", 0x8bc84a1bc6f945b0),
+    ("rolling_hills", 0x12c6adcc3c24ec09),
+    ("archipelago", 0x1c10d0e1122ecb18),
+    ("badlands", 0x7e95eaa7c8b92138),
+    ("caves3d", 0xe613262e26e57510),
+];
+
+fn fingerprint_preset(name: &str) -> u64 {
+    let (min, max) = ([0.0; 2], [1000.0; 2]);
+    match name {
+        "mountains" => presets::mountains(42).fingerprint(min, max, 64),
+        "rolling_hills" => presets::rolling_hills(42).fingerprint(min, max, 64),
+        "archipelago" => presets::archipelago(42).fingerprint(min, max, 64),
+        "badlands" => presets::badlands(42).fingerprint(min, max, 64),
+        "caves3d" => presets::caves3d(42).fingerprint([0.0; 3], [100.0; 3], 16),
+        name => panic!("unknown preset {}", name),
+    }
+}
+
+#[test]
+fn test_golden_preset_fingerprints() {
+    for &(name, expected) in GOLDEN_PRESET_FINGERPRINTS {
+        let fingerprint = fingerprint_preset(name);
+        assert_eq!(
+            fingerprint, expected,
+            "fingerprint of preset {} changed to {:#018x}",
+            name, fingerprint
+        );
+    }
+}
+
+#[test]
+fn test_fingerprint_diff() {
+    let generator = Source::<2>::simplex(42);
+    let diff = generator.fingerprint_diff(&Source::simplex(42), [-50.0; 2], [50.0; 2], 64);
+    assert!(diff.is_empty());
+    assert_eq!(diff.samples, 64 * 64);
+
+    // only results exceeding the clamping interval differ
+    let clamped = Source::simplex(42).clamp(-0.5, 0.5);
+    let diff = generator.fingerprint_diff(&clamped, [-50.0; 2], [50.0; 2], 64);
+    assert!(!diff.is_empty());
+    for mismatch in &diff.mismatches {
+        assert_eq!(mismatch.left, generator.sample(mismatch.point));
+        assert_eq!(mismatch.right, mismatch.left.clamp(-0.5, 0.5));
+        assert!(mismatch.left.abs() > 0.5);
+    }
+    let report = diff.to_string();
+    assert!(report.starts_with(&format!("{} of 4096 samples differ", diff.mismatches.len())));
+    assert_eq!(report.lines().count(), diff.mismatches.len() + 1);
+}
+
+#[test]
+fn test_compare() {
+    let generator = Source::<2>::simplex(42).fbm(3, 0.013, 2.0, 0.5);
+    let comparison = generator.compare(&generator.clone(), [-50.0; 2], [50.0; 2], 64);
+    assert_eq!(comparison.samples, 64 * 64);
+    assert_eq!(comparison.max_abs_diff, 0.0);
+    assert_eq!(comparison.mean_abs_diff, 0.0);
+    assert_eq!(comparison.rmse, 0.0);
+    assert_eq!(comparison.worst_point, [-50.0; 2]);
+
+    // a constant offset yields exact statistics
+    let constant = Source::<3>::constant(0.5);
+    let comparison = constant.compare(&constant.add(0.25), [-5.0; 3], [5.0; 3], 8);
+    assert_eq!(comparison.samples, 8 * 8 * 8);
+    assert_eq!(comparison.max_abs_diff, 0.25);
+    assert_eq!(comparison.mean_abs_diff, 0.25);
+    assert_eq!(comparison.rmse, 0.25);
+    let offset = generator.clone().add(0.25);
+    let comparison = generator.compare(&offset, [-50.0; 2], [50.0; 2], 64);
+    assert!((comparison.max_abs_diff - 0.25).abs() < 1e-12);
+    assert!((comparison.mean_abs_diff - 0.25).abs() < 1e-12);
+    assert!((comparison.rmse - 0.25).abs() < 1e-12);
+
+    // the worst point is where the results differ the most
+    let clamped = generator.clone().clamp(-0.25, 0.25);
+    let comparison = generator.compare(&clamped, [-50.0; 2], [50.0; 2], 64);
+    let worst = generator.sample(comparison.worst_point);
+    assert_eq!(comparison.max_abs_diff, worst.abs() - 0.25);
+    assert!(comparison.mean_abs_diff < comparison.rmse);
+    assert!(comparison.rmse < comparison.max_abs_diff);
+    assert!(comparison.to_string().starts_with("max |diff| "));
+}
+
+#[test]
+fn test_compare_boxed() {
+    let generator = Source::<2>::simplex(42).fbm(3, 0.013, 2.0, 0.5).mul(2.0);
+    let boxed: Box<dyn SampleGenerator<2>> = Box::new(generator.clone());
+    let comparison = generator.compare(&boxed, [-50.0; 2], [50.0; 2], 32);
+    assert_eq!(comparison.max_abs_diff, 0.0);
+    let rebuilt = generator.to_recipe().unwrap().build::<2>().unwrap();
+    let comparison = boxed.compare(&rebuilt, [-50.0; 2], [50.0; 2], 32);
+    assert_eq!(comparison.max_abs_diff, 0.0);
+}
+
+#[test]
+fn test_compare_nan() {
+    let nan = Source::<1>::constant(f64::NAN);
+    assert_eq!(nan.compare(&nan, [0.0], [1.0], 4).max_abs_diff, 0.0);
+    let comparison = nan.compare(&Source::constant(0.0), [0.0], [1.0], 4);
+    assert_eq!(comparison.max_abs_diff, f64::INFINITY);
+    assert_eq!(comparison.rmse, f64::INFINITY);
+    assert_eq!(comparison.worst_point, [0.0]);
+}
+
+#[test]
+#[should_panic(expected = "samples_per_axis must be at least 1, got 0")]
+fn test_compare_no_samples() {
+    let generator = Source::<2>::simplex(42);
+    generator.compare(&generator, [0.0; 2], [1.0; 2], 0);
+}
@@ -0,0 +1,223 @@
+use libnoise::pipeline::{Pipeline, PipelineOp};
+use libnoise::prelude::*;
+use libnoise::recipe::{NoiseRecipe, RecipeError};
+
+fn assert_equivalent<const D: usize, GA, GB>(generator_a: &GA, generator_b: &GB)
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    let diff = generator_a.fingerprint_diff(generator_b, [-200.0; D], [200.0; D], 64);
+    assert!(diff.is_empty(), "{}", diff);
+}
+
+fn simplex<const D: usize>(seed: u64) -> Pipeline<D> {
+    Pipeline::new(NoiseRecipe::Simplex {
+        seed,
+        large_coordinates: false,
+        r_squared: None,
+    })
+    .unwrap()
+}
+
+fn with<const D: usize>(mut pipeline: Pipeline<D>, op: PipelineOp<D>) -> Pipeline<D> {
+    pipeline.push(op).unwrap();
+    pipeline
+}
+
+fn fbm_op<const D: usize>() -> PipelineOp<D> {
+    PipelineOp::Fbm {
+        octaves: 4,
+        frequency: 0.013,
+        lacunarity: 2.0,
+        persistence: 0.5,
+    }
+}
+
+// =================================================================
+// test equivalence with static chains
+// =================================================================
+#[test]
+fn test_pipeline_source() {
+    assert_equivalent(&simplex::<3>(42), &Source::<3>::simplex(42));
+}
+
+#[test]
+fn test_pipeline_static_chain() {
+    let mut pipeline = simplex::<2>(42);
+    pipeline.push_scale([0.5, 2.0]).unwrap();
+    pipeline.push_fbm(4, 0.013, 2.0, 0.5).unwrap();
+    pipeline.push(PipelineOp::Abs).unwrap();
+    pipeline.push(PipelineOp::Mul(-2.0)).unwrap();
+    pipeline.push(PipelineOp::Add(1.0)).unwrap();
+    pipeline
+        .push(PipelineOp::Sum(with(simplex(43), PipelineOp::Mul(0.1))))
+        .unwrap();
+    pipeline
+        .push_blend(
+            with(
+                simplex(44),
+                PipelineOp::Billow {
+                    octaves: 3,
+                    frequency: 0.02,
+                    lacunarity: 2.0,
+                    persistence: 0.5,
+                },
+            ),
+            with(simplex(45), PipelineOp::Scale([0.05, 0.05])),
+        )
+        .unwrap();
+    pipeline
+        .push(PipelineOp::Clamp {
+            min: -0.5,
+            max: 0.5,
+        })
+        .unwrap();
+    let expected = Source::<2>::simplex(42)
+        .scale([0.5, 2.0])
+        .fbm(4, 0.013, 2.0, 0.5)
+        .abs()
+        .mul(-2.0)
+        .add(1.0)
+        .sum(Source::simplex(43).mul(0.1))
+        .blend(
+            Source::simplex(44).billow(3, 0.02, 2.0, 0.5),
+            Source::simplex(45).scale([0.05, 0.05]),
+        )
+        .clamp(-0.5, 0.5);
+    assert_equivalent(&pipeline, &expected);
+    assert_eq!(pipeline.output_bounds(), expected.output_bounds());
+}
+
+#[test]
+fn test_pipeline_recipe() {
+    let mut pipeline = simplex::<2>(42);
+    pipeline.push_translate([1.0, 2.0]).unwrap();
+    pipeline.push(fbm_op()).unwrap();
+    assert_equivalent(&pipeline.recipe().build::<2>().unwrap(), &pipeline);
+}
+
+// =================================================================
+// test pipeline mutation
+// =================================================================
+#[test]
+fn test_pipeline_insert() {
+    let mut pipeline = simplex::<2>(42);
+    pipeline.push(fbm_op()).unwrap();
+    pipeline.push(PipelineOp::Neg).unwrap();
+    pipeline.insert(0, PipelineOp::Scale([2.0, 0.5])).unwrap();
+    pipeline.insert(3, PipelineOp::Add(0.25)).unwrap();
+    let expected = Source::<2>::simplex(42)
+        .scale([2.0, 0.5])
+        .fbm(4, 0.013, 2.0, 0.5)
+        .neg()
+        .add(0.25);
+    assert_eq!(pipeline.ops().len(), 4);
+    assert_equivalent(&pipeline, &expected);
+}
+
+#[test]
+fn test_pipeline_remove() {
+    let mut pipeline = simplex::<2>(42);
+    pipeline.push_scale([2.0, 0.5]).unwrap();
+    pipeline.push(fbm_op()).unwrap();
+    pipeline.push(PipelineOp::Mul(3.0)).unwrap();
+    assert_eq!(pipeline.remove(0).unwrap(), PipelineOp::Scale([2.0, 0.5]));
+    assert_equivalent(
+        &pipeline,
+        &Source::<2>::simplex(42).fbm(4, 0.013, 2.0, 0.5).mul(3.0),
+    );
+    pipeline.remove(1).unwrap();
+    pipeline.remove(0).unwrap();
+    assert!(pipeline.ops().is_empty());
+    assert_equivalent(&pipeline, &Source::<2>::simplex(42));
+}
+
+#[test]
+fn test_pipeline_reorder() {
+    let mut pipeline = simplex::<2>(42);
+    pipeline.push(PipelineOp::Add(1.0)).unwrap();
+    pipeline.push(PipelineOp::Mul(2.0)).unwrap();
+    let point = [3.7, -1.2];
+    let value = Source::<2>::simplex(42).sample(point);
+    assert_eq!(pipeline.sample(point), (value + 1.0) * 2.0);
+    pipeline.swap(0, 1).unwrap();
+    assert_eq!(pipeline.sample(point), value * 2.0 + 1.0);
+    let op = pipeline.remove(1).unwrap();
+    pipeline.insert(0, op).unwrap();
+    assert_eq!(pipeline.sample(point), (value + 1.0) * 2.0);
+}
+
+#[test]
+fn test_pipeline_set_source() {
+    let mut pipeline = simplex::<2>(42);
+    pipeline.push(fbm_op()).unwrap();
+    pipeline
+        .set_source(NoiseRecipe::Perlin {
+            seed: 7,
+            large_coordinates: false,
+        })
+        .unwrap();
+    assert_equivalent(&pipeline, &Source::<2>::perlin(7).fbm(4, 0.013, 2.0, 0.5));
+}
+
+#[test]
+fn test_pipeline_clone() {
+    let mut pipeline = simplex::<2>(42);
+    pipeline.push(fbm_op()).unwrap();
+    let mut clone = pipeline.clone();
+    assert_eq!(clone, pipeline);
+    assert_equivalent(&clone, &pipeline);
+    clone.push(PipelineOp::Neg).unwrap();
+    assert_ne!(clone, pipeline);
+    assert_eq!(pipeline.ops().len(), 1);
+}
+
+// =================================================================
+// test invalid mutations
+// =================================================================
+#[test]
+fn test_pipeline_invalid_mutation() {
+    let mut pipeline = simplex::<2>(42);
+    pipeline.push(PipelineOp::Mul(2.0)).unwrap();
+    let before = pipeline.clone();
+    let error = pipeline
+        .insert(
+            0,
+            PipelineOp::Fbm {
+                octaves: 0,
+                frequency: 1.0,
+                lacunarity: 2.0,
+                persistence: 0.5,
+            },
+        )
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        RecipeError::InvalidParameter {
+            parameter: "octaves",
+            ..
+        }
+    ));
+    assert_eq!(pipeline, before);
+    assert_equivalent(&pipeline, &before);
+}
+
+#[test]
+fn test_pipeline_unsupported_dimension() {
+    let error = Pipeline::<5>::new(NoiseRecipe::Worley {
+        seed: 42,
+        large_coordinates: false,
+        return_type: WorleyReturnType::Distance,
+    })
+    .unwrap_err();
+    assert!(matches!(
+        error,
+        RecipeError::UnsupportedDimension { dimension: 5, .. }
+    ));
+    let mut pipeline = Pipeline::<5>::new(NoiseRecipe::Constant { value: 0.5 }).unwrap();
+    pipeline.push(PipelineOp::Mul(2.0)).unwrap();
+    assert!(pipeline.push(fbm_op()).is_err());
+    assert_eq!(pipeline.ops().len(), 1);
+    assert_eq!(pipeline.sample([0.0; 5]), 1.0);
+}
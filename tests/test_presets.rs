@@ -0,0 +1,187 @@
+use libnoise::prelude::*;
+use libnoise::presets::{self, Knobs};
+
+const SEEDS: [u64; 3] = [0, 42, u64::MAX];
+
+// asserts that the values of a generator on a grid over [0, extent]^D, as well as its interval
+// estimates on boxes of the given size, are within [-1, 1] and consistent with each other
+fn assert_range<const D: usize, G: Generator<D>>(generator: G, extent: f64, box_size: f64) {
+    let mut state = 0x853c_49e6_748f_ea9b_u64;
+    let mut random = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 11) as f64 / (1_u64 << 53) as f64
+    };
+    for _ in 0..100 {
+        let min: [f64; D] = std::array::from_fn(|_| random() * extent);
+        let max: [f64; D] = std::array::from_fn(|i| min[i] + random() * box_size);
+        let (lower, upper) = generator.sample_interval(min, max);
+        assert!(
+            -1.0 <= lower && lower <= upper && upper <= 1.0,
+            "interval ({lower}, {upper}) not within [-1, 1]"
+        );
+        for _ in 0..100 {
+            let point = std::array::from_fn(|i| min[i] + random() * (max[i] - min[i]));
+            let value = generator.sample(point);
+            assert!(
+                lower <= value && value <= upper,
+                "value {value} at {point:?} not within ({lower}, {upper})"
+            );
+        }
+    }
+}
+
+// asserts that a generator has a substantial spread of values on [0, extent]^D
+fn assert_spread<const D: usize, G: Generator<D>>(generator: G, extent: f64) {
+    let samples: usize = [4096, 64, 16][D - 1];
+    let mut values = Vec::new();
+    for index in 0..samples.pow(D as u32) {
+        let point = std::array::from_fn(|axis| {
+            let i = index / samples.pow(axis as u32) % samples;
+            i as f64 / samples as f64 * extent
+        });
+        values.push(generator.sample(point));
+    }
+    let lowest = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let highest = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    assert!(
+        highest - lowest > 0.5,
+        "values only span ({lowest}, {highest})"
+    );
+}
+
+// =================================================================
+// test presets
+// =================================================================
+#[test]
+fn test_presets_range() {
+    for seed in SEEDS {
+        assert_range(presets::mountains(seed), 2000.0, 200.0);
+        assert_range(presets::rolling_hills(seed), 2000.0, 200.0);
+        assert_range(presets::archipelago(seed), 2000.0, 200.0);
+        assert_range(presets::badlands(seed), 2000.0, 200.0);
+        assert_range(presets::caves3d(seed), 200.0, 20.0);
+    }
+}
+
+#[test]
+fn test_presets_spread() {
+    assert_spread(presets::mountains(42), 2000.0);
+    assert_spread(presets::rolling_hills(42), 2000.0);
+    assert_spread(presets::archipelago(42), 2000.0);
+    assert_spread(presets::badlands(42), 2000.0);
+    assert_spread(presets::caves3d(42), 200.0);
+}
+
+#[test]
+fn test_presets_sea_level() {
+    let knobs = Knobs {
+        sea_level: 0.1,
+        ..Knobs::MOUNTAINS
+    };
+    let generator = presets::mountains_with(42, knobs);
+    let flat = (0..4096)
+        .map(|i| generator.sample([(i % 64) as f64 * 30.0, (i / 64) as f64 * 30.0]))
+        .inspect(|value| assert!(*value >= 0.1, "value {value} below the sea level"))
+        .filter(|value| *value == 0.1)
+        .count();
+    assert!(flat > 0, "no point at the sea level");
+
+    // raising the threshold of the caves opens up more space
+    let open = |sea_level| {
+        let generator = presets::caves3d_with(
+            42,
+            Knobs {
+                sea_level,
+                ..Knobs::CAVES
+            },
+        );
+        (0..4096)
+            .filter(|i| {
+                let point = [i % 16, i / 16 % 16, i / 256].map(|x| x as f64 * 7.0);
+                generator.sample(point) < 0.0
+            })
+            .count()
+    };
+    assert!(open(-0.9) < open(-0.8) && open(-0.8) < open(-0.5));
+}
+
+#[test]
+fn test_presets_knobs() {
+    let knobs = Knobs {
+        scale: 100.0,
+        sea_level: -0.5,
+        roughness: 0.8,
+    };
+    assert_range(presets::mountains_with(7, knobs), 500.0, 50.0);
+    assert_range(presets::rolling_hills_with(7, knobs), 500.0, 50.0);
+    assert_range(presets::archipelago_with(7, knobs), 500.0, 50.0);
+    assert_range(presets::badlands_with(7, knobs), 500.0, 50.0);
+    assert_range(presets::caves3d_with(7, knobs), 200.0, 20.0);
+
+    // the defaults are the knobs of the respective preset
+    let default = presets::archipelago(7);
+    let explicit = presets::archipelago_with(7, Knobs::ARCHIPELAGO);
+    let diff = default.fingerprint_diff(&explicit, [0.0; 2], [1000.0; 2], 64);
+    assert!(diff.is_empty(), "{}", diff);
+}
+
+#[test]
+#[should_panic(expected = "scale must be positive and finite")]
+fn test_presets_invalid_scale() {
+    presets::mountains_with(
+        42,
+        Knobs {
+            scale: 0.0,
+            ..Knobs::MOUNTAINS
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "sea_level must be within [-1, 1]")]
+fn test_presets_invalid_sea_level() {
+    presets::archipelago_with(
+        42,
+        Knobs {
+            sea_level: f64::NAN,
+            ..Knobs::ARCHIPELAGO
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "roughness must be within (0, 1]")]
+fn test_presets_invalid_roughness() {
+    presets::caves3d_with(
+        42,
+        Knobs {
+            roughness: 1.5,
+            ..Knobs::CAVES
+        },
+    );
+}
+
+#[test]
+fn test_presets_recipe() {
+    let generator = presets::badlands(42);
+    let rebuilt = generator.to_recipe().unwrap().build::<2>().unwrap();
+    let diff = generator.fingerprint_diff(&rebuilt, [0.0; 2], [1000.0; 2], 64);
+    assert!(diff.is_empty(), "{}", diff);
+
+    let generator = presets::caves3d(42);
+    let rebuilt = generator.to_recipe().unwrap().build::<3>().unwrap();
+    let diff = generator.fingerprint_diff(&rebuilt, [0.0; 3], [100.0; 3], 16);
+    assert!(diff.is_empty(), "{}", diff);
+}
+
+#[test]
+fn test_presets_knobs_serde() {
+    let json = serde_json::to_string(&Knobs::MOUNTAINS).unwrap();
+    assert_eq!(json, r#"{"scale":500.0,"sea_level":-0.6,"roughness":0.5}"#);
+    assert_eq!(
+        serde_json::from_str::<Knobs>(&json).unwrap(),
+        Knobs::MOUNTAINS
+    );
+}
@@ -0,0 +1,782 @@
+use libnoise::prelude::*;
+use libnoise::recipe::{NoiseRecipe, RecipeError, TransformStep};
+
+fn load(json: &str) -> NoiseRecipe {
+    serde_json::from_str(json).unwrap()
+}
+
+fn assert_equivalent<const D: usize, GA, GB>(generator_a: &GA, generator_b: &GB)
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    let diff = generator_a.fingerprint_diff(generator_b, [-200.0; D], [200.0; D], 64);
+    assert!(diff.is_empty(), "{}", diff);
+}
+
+fn simplex(seed: u64) -> Box<NoiseRecipe> {
+    Box::new(NoiseRecipe::Simplex {
+        seed,
+        large_coordinates: false,
+        r_squared: None,
+    })
+}
+
+// =================================================================
+// test example recipes
+// =================================================================
+#[test]
+fn test_recipe_wood() {
+    let recipe = load(include_str!("../examples/recipes/wood.json"));
+    let expected = Source::<2>::perlin(7)
+        .scale([0.02, 0.25])
+        .fbm(3, 1.0, 2.0, 0.5)
+        .displace_x(Source::simplex(8).scale([0.05, 0.05]).mul(4.0))
+        .mul(8.0)
+        .clamp(-1.0, 1.0);
+    assert_equivalent(&recipe.build::<2>().unwrap(), &expected);
+}
+
+#[test]
+fn test_recipe_marble() {
+    let recipe = load(include_str!("../examples/recipes/marble.json"));
+    let expected = Source::<2>::simplex(3)
+        .fbm(6, 0.02, 2.0, 0.5)
+        .abs()
+        .mul(-2.0)
+        .add(1.0)
+        .blend(Source::constant(1.0), Source::worley(4).scale([0.05, 0.05]));
+    assert_equivalent(&recipe.build::<2>().unwrap(), &expected);
+}
+
+#[test]
+fn test_recipe_terrain() {
+    let recipe = load(include_str!("../examples/recipes/terrain.json"));
+    let expected = Source::<2>::simplex(43)
+        .ridgedmulti(6, 0.008, 2.0, 2.0)
+        .mul(0.6)
+        .add(0.3)
+        .select(
+            Source::simplex(44).billow(4, 0.01, 2.0, 0.5).mul(0.1),
+            Source::perlin(42).fbm(8, 0.004, 2.0, 0.5),
+            0.0,
+            1.0,
+        )
+        .rotate([0.3]);
+    assert_equivalent(&recipe.build::<2>().unwrap(), &expected);
+}
+
+// =================================================================
+// test recipe nodes
+// =================================================================
+#[test]
+fn test_recipe_sources() {
+    let recipe = load(r#"{"type": "improved_perlin", "seed": 5, "large_coordinates": true}"#);
+    assert_equivalent(
+        &recipe.build::<3>().unwrap(),
+        &ImprovedPerlin::<3>::new(5).with_large_coordinates(),
+    );
+    let recipe = load(r#"{"type": "open_simplex2", "seed": 5}"#);
+    assert_equivalent(&recipe.build::<2>().unwrap(), &OpenSimplex2::<2>::new(5));
+    assert_equivalent(&recipe.build::<3>().unwrap(), &OpenSimplex2::<3>::new(5));
+    let recipe = load(r#"{"type": "open_simplex2s", "seed": 5}"#);
+    assert_equivalent(&recipe.build::<2>().unwrap(), &OpenSimplex2S::<2>::new(5));
+    assert_equivalent(&recipe.build::<3>().unwrap(), &OpenSimplex2S::<3>::new(5));
+    let recipe = load(r#"{"type": "simplex", "seed": 5, "r_squared": 0.3}"#);
+    assert_equivalent(
+        &recipe.build::<4>().unwrap(),
+        &Source::<4>::simplex_with_radius(5, 0.3).unwrap(),
+    );
+    let recipe = load(r#"{"type": "simplex", "seed": 5, "large_coordinates": true}"#);
+    let (built, expected) = (
+        recipe.build::<5>().unwrap(),
+        Source::<5>::simplex(5).with_large_coordinates(),
+    );
+    for i in 0..100 {
+        let point = [i as f64 * 0.37, 1.5, -2.25, i as f64 * -0.61, 4.0];
+        assert_eq!(built.sample(point), expected.sample(point));
+    }
+    let recipe = load(r#"{"type": "value", "seed": 5}"#);
+    assert_equivalent(&recipe.build::<4>().unwrap(), &Source::<4>::value(5));
+    let recipe = load(r#"{"type": "checkerboard"}"#);
+    assert_equivalent(&recipe.build::<1>().unwrap(), &Source::<1>::checkerboard());
+    let recipe = load(r#"{"type": "checkerboard", "period": [2.0, 0.5, 1.0], "smooth": true}"#);
+    assert_equivalent(
+        &recipe.build::<3>().unwrap(),
+        &Source::<3>::checkerboard()
+            .with_period([2.0, 0.5, 1.0])
+            .smooth(true),
+    );
+    let recipe = load(r#"{"type": "white", "seed": 5}"#);
+    assert_equivalent(&recipe.build::<2>().unwrap(), &Source::<2>::white(5));
+    let recipe = load(
+        r#"{"type": "spot", "seed": 5, "kernel": "ring", "radius": 1.5, "density": 2,
+            "amplitude_jitter": 0.25}"#,
+    );
+    assert_equivalent(
+        &recipe.build::<3>().unwrap(),
+        &Source::<3>::spot(5)
+            .with_kernel(SpotKernel::Ring)
+            .with_radius(1.5)
+            .with_density(2)
+            .with_amplitude_jitter(0.25),
+    );
+    let recipe = load(r#"{"type": "worley", "seed": 5, "return_type": "edge_distance"}"#);
+    assert_equivalent(
+        &recipe.build::<3>().unwrap(),
+        &Source::<3>::worley(5).with_return_type(WorleyReturnType::EdgeDistance),
+    );
+    let recipe = load(
+        r#"{"type": "worley", "seed": 5, "return_type": {"weighted_distance": {"nearest": -1.0, "second": 1.0}}}"#,
+    );
+    assert_equivalent(
+        &recipe.build::<2>().unwrap(),
+        &Source::<2>::worley(5).with_return_type(WorleyReturnType::DistanceDifference),
+    );
+    let recipe = load(r#"{"type": "wavelet", "seed": 5, "tile_size": 8}"#);
+    assert_equivalent(&recipe.build::<3>().unwrap(), &Source::<3>::wavelet(5, 8));
+    let recipe = load(r#"{"type": "blue", "seed": 5, "resolution": 8, "interpolate": true}"#);
+    assert_equivalent(
+        &recipe.build::<2>().unwrap(),
+        &Blue::new(5, 8).with_interpolation(),
+    );
+    let recipe = load(
+        r#"{"type": "distance_field", "points": [[1.0, 2.0], [-3.0, 0.5]], "max_distance": 4.0}"#,
+    );
+    assert_equivalent(
+        &recipe.build::<2>().unwrap(),
+        &Source::distance_field(vec![[1.0, 2.0], [-3.0, 0.5]])
+            .unwrap()
+            .with_max_distance(4.0),
+    );
+    let recipe =
+        load(r#"{"type": "linear_gradient", "from": [1.0, 2.0, 0.0], "to": [-3.0, 0.5, 1.0]}"#);
+    assert_equivalent(
+        &recipe.build::<3>().unwrap(),
+        &Source::linear_gradient([1.0, 2.0, 0.0], [-3.0, 0.5, 1.0]).unwrap(),
+    );
+    let recipe = load(
+        r#"{"type": "radial_gradient", "center": [1.0, 2.0], "inner_radius": 0.5,
+            "outer_radius": 3.0}"#,
+    );
+    assert_equivalent(
+        &recipe.build::<2>().unwrap(),
+        &Source::radial_gradient([1.0, 2.0], 0.5, 3.0).unwrap(),
+    );
+    let recipe = load(
+        r#"{"type": "island", "center": [1.0, 2.0], "radius": 3.0,
+            "falloff": {"exponent": 1.5}}"#,
+    );
+    assert_equivalent(
+        &recipe.build::<2>().unwrap(),
+        &Source::island([1.0, 2.0], 3.0, FalloffCurve::Exponent(1.5)).unwrap(),
+    );
+    let recipe = load(
+        r#"{"type": "island_rect", "min": [1.0, 2.0], "max": [5.0, 3.0],
+            "falloff": "square_bump"}"#,
+    );
+    assert_equivalent(
+        &recipe.build::<2>().unwrap(),
+        &Source::island_rect([1.0, 2.0], [5.0, 3.0], FalloffCurve::SquareBump).unwrap(),
+    );
+    let recipe = load(r#"{"type": "constant", "value": 0.5}"#);
+    assert_eq!(recipe.build::<7>().unwrap().sample([0.0; 7]), 0.5);
+}
+
+#[test]
+fn test_recipe_adapters() {
+    let recipe = NoiseRecipe::Displace {
+        source: Box::new(NoiseRecipe::Rotate {
+            source: Box::new(NoiseRecipe::Translate {
+                source: Box::new(NoiseRecipe::Powf {
+                    source: Box::new(NoiseRecipe::Exp {
+                        source: Box::new(NoiseRecipe::Neg {
+                            source: Box::new(NoiseRecipe::Powi {
+                                source: simplex(1),
+                                exponent: 3,
+                            }),
+                        }),
+                    }),
+                    exponent: 0.5,
+                }),
+                translation: vec![1.0, 2.0, 3.0],
+            }),
+            rotation: vec![0.1, 0.2, 0.3],
+        }),
+        displacement: Box::new(NoiseRecipe::Max {
+            a: Box::new(NoiseRecipe::Product {
+                a: simplex(2),
+                b: simplex(3),
+            }),
+            b: Box::new(NoiseRecipe::Min {
+                a: Box::new(NoiseRecipe::Power {
+                    a: simplex(4),
+                    b: simplex(5),
+                }),
+                b: Box::new(NoiseRecipe::Checked {
+                    source: Box::new(NoiseRecipe::Sum {
+                        a: simplex(6),
+                        b: simplex(7),
+                    }),
+                    min: -2.0,
+                    max: 2.0,
+                }),
+            }),
+        }),
+        axis: 2,
+    };
+    let expected = Source::<3>::simplex(1)
+        .powi(3)
+        .neg()
+        .exp()
+        .powf(0.5)
+        .translate([1.0, 2.0, 3.0])
+        .rotate([0.1, 0.2, 0.3])
+        .displace_z(
+            Source::simplex(2).product(Source::simplex(3)).max(
+                Source::simplex(4).power(Source::simplex(5)).min(
+                    Source::simplex(6)
+                        .sum(Source::simplex(7))
+                        .checked(-2.0, 2.0),
+                ),
+            ),
+        );
+    assert_equivalent(&recipe.build::<3>().unwrap(), &expected);
+    let recipe = NoiseRecipe::DistanceLod {
+        source: simplex(1),
+        octaves: 4,
+        frequency: 0.05,
+        lacunarity: 2.0,
+        persistence: 0.5,
+        focus: vec![10.0, 20.0],
+        near: 30.0,
+    };
+    let expected = Source::<2>::simplex(1)
+        .fbm_lod(4, 0.05, 2.0, 0.5)
+        .distance_lod([10.0, 20.0], 30.0);
+    assert_equivalent(&recipe.build::<2>().unwrap(), &expected);
+}
+
+#[test]
+fn test_recipe_generator_can_be_adapted() {
+    let recipe = load(r#"{"type": "simplex", "seed": 42}"#);
+    let generator = recipe
+        .build::<2>()
+        .unwrap()
+        .fbm(3, 0.1, 2.0, 0.5)
+        .rotate([0.5]);
+    let expected = Source::<2>::simplex(42).fbm(3, 0.1, 2.0, 0.5).rotate([0.5]);
+    assert_equivalent(&generator, &expected);
+    assert_eq!(generator.output_bounds(), expected.output_bounds());
+}
+
+#[test]
+fn test_recipe_serialization_round_trip() {
+    let json = include_str!("../examples/recipes/terrain.json");
+    let recipe = load(json);
+    let serialized = serde_json::to_string(&recipe).unwrap();
+    assert_eq!(load(&serialized), recipe);
+}
+
+// =================================================================
+// test recipe validation
+// =================================================================
+#[test]
+fn test_recipe_zero_octaves() {
+    let recipe = NoiseRecipe::Clamp {
+        source: Box::new(NoiseRecipe::Sum {
+            a: simplex(1),
+            b: Box::new(NoiseRecipe::Fbm {
+                source: simplex(2),
+                octaves: 0,
+                frequency: 1.0,
+                lacunarity: 2.0,
+                persistence: 0.5,
+            }),
+        }),
+        min: -1.0,
+        max: 1.0,
+    };
+    let error = recipe.build::<2>().unwrap_err();
+    assert_eq!(
+        error,
+        RecipeError::InvalidParameter {
+            path: "source.b".to_string(),
+            node: "fbm",
+            parameter: "octaves",
+            reason: "must be at least 1",
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "invalid fbm node at source.b: octaves must be at least 1"
+    );
+}
+
+#[test]
+fn test_recipe_invalid_fractal_parameters() {
+    let cases = [
+        (
+            r#""type": "fbm", "octaves": 4, "frequency": -1.0, "lacunarity": 2.0, "persistence": 0.5"#,
+            "fbm",
+            "frequency",
+        ),
+        (
+            r#""type": "billow", "octaves": 4, "frequency": 1.0, "lacunarity": 0.0, "persistence": 0.5"#,
+            "billow",
+            "lacunarity",
+        ),
+        (
+            r#""type": "fbm_lod", "octaves": 4, "frequency": 1.0, "lacunarity": 2.0, "persistence": 1e10"#,
+            "fbm_lod",
+            "persistence",
+        ),
+        (
+            r#""type": "ridgedmulti", "octaves": 4, "frequency": 1.0, "lacunarity": 2.0, "attenuation": -2.0"#,
+            "ridgedmulti",
+            "attenuation",
+        ),
+    ];
+    for (fields, node, parameter) in cases {
+        let json = format!(r#"{{{fields}, "source": {{"type": "simplex", "seed": 42}}}}"#);
+        let error = load(&json).build::<2>().unwrap_err();
+        assert!(
+            matches!(error, RecipeError::InvalidParameter { node: n, parameter: p, .. } if n == node && p == parameter),
+            "unexpected error {error}"
+        );
+    }
+}
+
+#[test]
+fn test_recipe_wrong_dimensionality() {
+    let recipe = load(include_str!("../examples/recipes/wood.json"));
+    assert_eq!(
+        recipe.build::<3>().unwrap_err(),
+        RecipeError::LengthMismatch {
+            path: "source.source.source.source".to_string(),
+            node: "scale",
+            parameter: "scale",
+            expected: 3,
+            found: 2,
+        }
+    );
+    let recipe = load(include_str!("../examples/recipes/terrain.json"));
+    assert_eq!(
+        recipe.build::<1>().unwrap_err(),
+        RecipeError::UnsupportedDimension {
+            path: String::new(),
+            node: "rotate",
+            dimension: 1,
+        }
+    );
+    let recipe = load(r#"{"type": "blue", "seed": 42, "resolution": 8}"#);
+    assert_eq!(
+        recipe.build::<3>().unwrap_err(),
+        RecipeError::UnsupportedDimension {
+            path: String::new(),
+            node: "blue",
+            dimension: 3,
+        }
+    );
+    let recipe = load(r#"{"type": "open_simplex2", "seed": 42}"#);
+    assert_eq!(
+        recipe.build::<4>().unwrap_err(),
+        RecipeError::UnsupportedDimension {
+            path: String::new(),
+            node: "open_simplex2",
+            dimension: 4,
+        }
+    );
+    let recipe = load(r#"{"type": "open_simplex2s", "seed": 42}"#);
+    assert_eq!(
+        recipe.build::<1>().unwrap_err(),
+        RecipeError::UnsupportedDimension {
+            path: String::new(),
+            node: "open_simplex2s",
+            dimension: 1,
+        }
+    );
+    let recipe = NoiseRecipe::Worley {
+        seed: 42,
+        large_coordinates: false,
+        return_type: WorleyReturnType::WeightedDistance {
+            nearest: 1.0,
+            second: f64::INFINITY,
+        },
+    };
+    assert_eq!(
+        recipe.build::<2>().unwrap_err().to_string(),
+        "invalid worley node at root: return_type must have finite weights"
+    );
+    let recipe = load(r#"{"type": "blue", "seed": 42, "resolution": 0}"#);
+    assert!(matches!(
+        recipe.build::<2>(),
+        Err(RecipeError::InvalidParameter {
+            parameter: "resolution",
+            ..
+        })
+    ));
+    let recipe = load(r#"{"type": "distance_field", "points": [[1.0, 2.0]], "max_distance": 1.0}"#);
+    assert_eq!(
+        recipe.build::<3>().unwrap_err(),
+        RecipeError::LengthMismatch {
+            path: String::new(),
+            node: "distance_field",
+            parameter: "points",
+            expected: 3,
+            found: 2,
+        }
+    );
+    let recipe = load(r#"{"type": "distance_field", "points": [], "max_distance": 1.0}"#);
+    assert!(matches!(
+        recipe.build::<2>(),
+        Err(RecipeError::InvalidParameter {
+            parameter: "points",
+            ..
+        })
+    ));
+    let recipe = load(r#"{"type": "linear_gradient", "from": [1.0, 2.0], "to": [1.0, 2.0]}"#);
+    assert_eq!(
+        recipe.build::<2>().unwrap_err().to_string(),
+        "invalid linear_gradient node at root: to must differ from from"
+    );
+    let recipe = load(
+        r#"{"type": "radial_gradient", "center": [1.0, 2.0], "inner_radius": 2.0,
+            "outer_radius": 1.0}"#,
+    );
+    assert!(matches!(
+        recipe.build::<2>(),
+        Err(RecipeError::InvalidParameter {
+            parameter: "outer_radius",
+            ..
+        })
+    ));
+    let recipe =
+        load(r#"{"type": "island", "center": [1.0, 2.0], "radius": 3.0, "falloff": "linear"}"#);
+    assert_eq!(
+        recipe.build::<3>().unwrap_err(),
+        RecipeError::UnsupportedDimension {
+            path: String::new(),
+            node: "island",
+            dimension: 3,
+        }
+    );
+    let recipe = load(
+        r#"{"type": "island_rect", "min": [1.0, 2.0], "max": [5.0, 2.0], "falloff": "linear"}"#,
+    );
+    assert_eq!(
+        recipe.build::<2>().unwrap_err().to_string(),
+        "invalid island_rect node at root: max must exceed min by a finite amount along every \
+         axis"
+    );
+    let recipe = load(r#"{"type": "simplex", "seed": 5, "r_squared": 0.6}"#);
+    assert_eq!(
+        recipe.build::<2>().unwrap_err().to_string(),
+        "invalid simplex node at root: r_squared must be within [0.01, 0.5]"
+    );
+    let recipe = load(r#"{"type": "checkerboard", "period": [2.0, -1.0]}"#);
+    assert_eq!(
+        recipe.build::<2>().unwrap_err().to_string(),
+        "invalid checkerboard node at root: period must be positive and finite"
+    );
+    let recipe = load(r#"{"type": "checkerboard", "period": [2.0, 1.0]}"#);
+    assert!(matches!(
+        recipe.build::<3>(),
+        Err(RecipeError::LengthMismatch {
+            parameter: "period",
+            ..
+        })
+    ));
+    let recipe = NoiseRecipe::Clamp {
+        source: simplex(1),
+        min: 0.5,
+        max: -0.5,
+    };
+    assert_eq!(
+        recipe.build::<2>().unwrap_err().to_string(),
+        "invalid clamp node at root: max must not be less than min"
+    );
+    let recipe = load(r#"{"type": "worley", "seed": 42}"#);
+    let error = recipe.build::<5>().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid worley node at root: 5 dimensions are not supported"
+    );
+    let recipe = NoiseRecipe::Displace {
+        source: simplex(1),
+        displacement: simplex(2),
+        axis: 2,
+    };
+    assert!(matches!(
+        recipe.build::<2>(),
+        Err(RecipeError::InvalidParameter {
+            parameter: "axis",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_recipe_unknown_source() {
+    let error = serde_json::from_str::<NoiseRecipe>(
+        r#"{"type": "scale", "source": {"type": "plasma", "seed": 1}, "scale": [1.0, 1.0]}"#,
+    )
+    .unwrap_err();
+    assert!(
+        error.to_string().contains("unknown variant `plasma`"),
+        "{}",
+        error
+    );
+}
+
+// =================================================================
+// test conversion of generators into recipes
+// =================================================================
+#[test]
+fn test_recipe_from_generator_round_trip() {
+    let generator = Source::<3>::simplex(1)
+        .powi(3)
+        .neg()
+        .exp()
+        .powf(0.5)
+        .translate([1.0, 2.0, 3.0])
+        .rotate([0.1, 0.2, 0.3])
+        .displace_z(
+            Source::perlin(2).product(Source::value(3)).max(
+                Source::worley(4).power(Source::improved_perlin(5)).min(
+                    Source::checkerboard()
+                        .sum(Source::constant(0.25))
+                        .checked(-2.0, 2.0),
+                ),
+            ),
+        )
+        .blend(
+            Source::simplex(6).billow(3, 0.5, 2.0, 0.5),
+            Source::simplex(7).ridgedmulti(3, 0.5, 2.0, 2.0),
+        )
+        .select(
+            Source::simplex(8).fbm(3, 0.5, 2.0, 0.5).abs(),
+            Source::simplex(9)
+                .scale([0.5, 0.25, 0.125])
+                .add(1.0)
+                .mul(2.0),
+            -0.5,
+            0.5,
+        )
+        .clamp(-1.0, 1.0);
+    let recipe = generator.to_recipe().unwrap();
+    assert_equivalent(&recipe.build::<3>().unwrap(), &generator);
+    assert_eq!(recipe.build::<3>().unwrap().to_recipe().unwrap(), recipe);
+    let generator = Source::<2>::simplex(1)
+        .fbm_lod(4, 0.05, 2.0, 0.5)
+        .distance_lod([10.0, 20.0], 30.0);
+    let recipe = generator.to_recipe().unwrap();
+    assert_equivalent(&recipe.build::<2>().unwrap(), &generator);
+}
+
+#[test]
+fn test_recipe_from_generator_captures_params() {
+    let frequency = Param::new(0.5);
+    let generator =
+        Source::<2>::simplex(1).fbm(3, frequency.clone(), Param::new(2.0), Param::new(0.5));
+    frequency.set(0.25);
+    let recipe = generator.to_recipe().unwrap();
+    assert_eq!(
+        recipe,
+        NoiseRecipe::Fbm {
+            source: simplex(1),
+            octaves: 3,
+            frequency: 0.25,
+            lacunarity: 2.0,
+            persistence: 0.5,
+        }
+    );
+}
+
+#[test]
+fn test_recipe_from_generator_unrepresentable() {
+    let generator = Source::<2>::simplex(1).blend(
+        Source::simplex(2),
+        Source::simplex(3).lambda(f64::sin).mul(2.0),
+    );
+    let error = generator.to_recipe().unwrap_err();
+    assert_eq!(
+        error,
+        RecipeError::Unrepresentable {
+            path: "control.source".to_string(),
+            node: "Lambda",
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Lambda node at control.source cannot be represented by a recipe"
+    );
+    let generator = Source::<1>::custom(|[x]| x * 2.0);
+    assert_eq!(
+        generator.to_recipe().unwrap_err().to_string(),
+        "Custom node at root cannot be represented by a recipe"
+    );
+}
+
+// =================================================================
+// test recipe optimization
+// =================================================================
+fn assert_optimized_identical<const D: usize>(recipe: &NoiseRecipe) -> NoiseRecipe {
+    let optimized = recipe.optimize::<D>().unwrap();
+    let original = recipe.build::<D>().unwrap();
+    let generator = optimized.build::<D>().unwrap();
+    assert_eq!(
+        generator.fingerprint([-200.0; D], [200.0; D], 64),
+        original.fingerprint([-200.0; D], [200.0; D], 64)
+    );
+    assert_equivalent(&generator, &original);
+    optimized
+}
+
+#[test]
+fn test_recipe_optimize_bloated_chain() {
+    let generator = Source::<2>::simplex(1)
+        .scale([1.0, 1.0])
+        .translate([0.3, 0.7])
+        .scale([0.1, 0.2])
+        .translate([-0.0, -0.0])
+        .scale([3.0, 3.0])
+        .mul(1.0)
+        .neg()
+        .neg()
+        .sum(
+            Source::<2>::constant(0.5)
+                .fbm(3, 0.013, 2.0, 0.5)
+                .rotate([0.5]),
+        )
+        .product(Source::constant(2.0).scale([0.5, 0.5]).abs().add(-1.0));
+    let recipe = generator.to_recipe().unwrap();
+    let optimized = assert_optimized_identical::<2>(&recipe);
+    let NoiseRecipe::Add { source, .. } = optimized else {
+        panic!("expected add node, got {:?}", optimized);
+    };
+    assert_eq!(
+        *source,
+        NoiseRecipe::Transform {
+            source: simplex(1),
+            steps: vec![
+                TransformStep::Scale(vec![3.0, 3.0]),
+                TransformStep::Scale(vec![0.1, 0.2]),
+                TransformStep::Translate(vec![0.3, 0.7]),
+            ],
+        }
+    );
+}
+
+#[test]
+fn test_recipe_optimize_keeps_behavior() {
+    let generator = Source::<3>::simplex(1)
+        .translate([1.0, 2.0, 3.0])
+        .scale([0.5, 0.25, 0.125])
+        .rotate([0.1, 0.2, 0.3])
+        .translate([0.0, 0.0, 0.0])
+        .add(0.0)
+        .displace_z(Source::constant(0.25).mul(1.0).scale([2.0, 2.0, 2.0]))
+        .blend(
+            Source::worley(2).abs().add(0.0),
+            Source::constant(1.0).sum(Source::value(3).neg()),
+        )
+        .select(
+            Source::constant(0.5).powi(2).product(Source::perlin(4)),
+            Source::constant(-1.0).exp().min(Source::constant(0.0)),
+            -0.5,
+            0.5,
+        )
+        .checked(-10.0, 10.0)
+        .fbm_lod(3, 0.5, 2.0, 0.5);
+    let recipe = generator.to_recipe().unwrap();
+    let optimized = assert_optimized_identical::<3>(&recipe);
+    let json = serde_json::to_string(&optimized).unwrap();
+    assert!(!json.contains(r#""type":"sum""#), "{}", json);
+    assert!(!json.contains(r#""type":"product""#), "{}", json);
+    assert!(json.contains(r#""type":"checked""#), "{}", json);
+    assert!(json.contains(r#""type":"fbm_lod""#), "{}", json);
+    // re-optimizing an optimized recipe has no effect
+    assert_eq!(optimized.optimize::<3>().unwrap(), optimized);
+}
+
+#[test]
+fn test_recipe_optimize_signed_zero() {
+    // adding positive zero turns negative zero into positive zero, and is thus kept
+    let recipe = NoiseRecipe::Add {
+        source: Box::new(NoiseRecipe::Constant { value: -0.0 }),
+        offset: 0.0,
+    };
+    let optimized = assert_optimized_identical::<2>(&recipe);
+    assert_eq!(optimized, NoiseRecipe::Constant { value: 0.0 });
+    assert!(optimized
+        .build::<2>()
+        .unwrap()
+        .sample([0.0; 2])
+        .is_sign_positive());
+    let recipe = NoiseRecipe::Add {
+        source: simplex(1),
+        offset: 0.0,
+    };
+    assert_eq!(assert_optimized_identical::<2>(&recipe), recipe);
+    let recipe = NoiseRecipe::Add {
+        source: Box::new(NoiseRecipe::Abs { source: simplex(1) }),
+        offset: 0.0,
+    };
+    assert_eq!(
+        assert_optimized_identical::<2>(&recipe),
+        NoiseRecipe::Abs { source: simplex(1) }
+    );
+    let recipe = NoiseRecipe::Translate {
+        source: simplex(1),
+        translation: vec![0.0, -0.0],
+    };
+    assert_eq!(assert_optimized_identical::<2>(&recipe), recipe);
+}
+
+#[test]
+fn test_recipe_optimize_validates() {
+    // the invalid scale would be removed by optimizing, but is reported nonetheless
+    let recipe = NoiseRecipe::Scale {
+        source: Box::new(NoiseRecipe::Constant { value: 1.0 }),
+        scale: vec![1.0, 1.0, 1.0],
+    };
+    assert!(matches!(
+        recipe.optimize::<2>(),
+        Err(RecipeError::LengthMismatch { .. })
+    ));
+    assert_eq!(
+        recipe.optimize::<3>().unwrap(),
+        NoiseRecipe::Constant { value: 1.0 }
+    );
+}
+
+#[test]
+fn test_recipe_transform() {
+    let recipe = load(
+        r#"{
+            "type": "transform",
+            "source": {"type": "simplex", "seed": 1},
+            "steps": [{"translate": [1.0, 2.0]}, {"scale": [0.5, 0.25]}]
+        }"#,
+    );
+    let generator = recipe.build::<2>().unwrap();
+    assert_equivalent(
+        &generator,
+        &Source::<2>::simplex(1)
+            .scale([0.5, 0.25])
+            .translate([1.0, 2.0]),
+    );
+    assert_eq!(generator.to_recipe().unwrap(), recipe);
+    assert_eq!(
+        generator.describe().param("steps"),
+        Some("translate [1, 2], scale [0.5, 0.25]")
+    );
+    assert!(matches!(
+        recipe.build::<3>(),
+        Err(RecipeError::LengthMismatch {
+            parameter: "steps",
+            ..
+        })
+    ));
+}
@@ -0,0 +1,235 @@
+use libnoise::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+
+// serializes a generator to json and back, and asserts that the deserialized generator produces
+// bit-identical results on a grid
+fn assert_round_trip<const D: usize, G>(generator: &G)
+where
+    G: Generator<D> + Serialize + DeserializeOwned,
+{
+    let json = serde_json::to_string(generator).unwrap();
+    let deserialized: G = serde_json::from_str(&json).unwrap();
+    let diff = generator.fingerprint_diff(&deserialized, [-50.0; D], [50.0; D], 16);
+    assert!(diff.is_empty(), "{}", diff);
+    assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+}
+
+// =================================================================
+// test sources
+// =================================================================
+#[test]
+fn test_round_trip_sources() {
+    assert_round_trip(&Source::<1>::simplex(42));
+    assert_round_trip(&Source::<3>::simplex_with_radius(42, 0.3).unwrap());
+    assert_round_trip(&Source::<2>::value(42));
+    assert_round_trip(&Source::<3>::perlin(42));
+    assert_round_trip(&Source::<4>::improved_perlin(42));
+    assert_round_trip(&Source::<3>::open_simplex2(42));
+    assert_round_trip(&Source::<2>::open_simplex2s(42));
+    assert_round_trip(&Source::<2>::worley(42));
+    assert_round_trip(&Source::<3>::worley(42).with_return_type(WorleyReturnType::EdgeDistance));
+    assert_round_trip(&Source::<2>::worley(42).with_return_type(
+        WorleyReturnType::WeightedDistance {
+            nearest: 0.5,
+            second: 1.5,
+        },
+    ));
+    assert_round_trip(&Source::<3>::checkerboard());
+    assert_round_trip(
+        &Source::<2>::checkerboard()
+            .with_period([2.0, 0.5])
+            .smooth(true),
+    );
+    assert_round_trip(&Source::<4>::white(42));
+    assert_round_trip(&Source::<3>::wavelet(42, 8));
+    assert_round_trip(&Source::<2>::spot(42).with_kernel(SpotKernel::Cone));
+    assert_round_trip(&Blue::new(42, 8).with_interpolation());
+    assert_round_trip(
+        &Source::<3>::distance_field(vec![[0.5, 1.0, -2.0], [3.0, 0.0, 1.5]])
+            .unwrap()
+            .with_max_distance(2.0),
+    );
+    assert_round_trip(&Source::linear_gradient([0.5, 1.0], [-2.0, 3.0]).unwrap());
+    assert_round_trip(&Source::radial_gradient([0.5, 1.0, 2.0], 0.5, 4.0).unwrap());
+    assert_round_trip(&Source::island([0.5, 1.0], 4.0, FalloffCurve::Exponent(1.5)).unwrap());
+    assert_round_trip(
+        &Source::island_rect([0.5, 1.0], [4.0, 2.0], FalloffCurve::SquareBump).unwrap(),
+    );
+    assert_round_trip(&Source::<2>::constant(0.25));
+    assert_round_trip(&Simplex::<3>::new(42).with_large_coordinates());
+}
+
+#[test]
+fn test_source_format() {
+    let json = serde_json::to_string(&Source::<2>::simplex(42)).unwrap();
+    assert_eq!(json, r#"{"seed":42,"large_coordinates":false}"#);
+    let generator: Simplex<2> = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        generator.sample([0.3, 0.7]),
+        Source::simplex(42).sample([0.3, 0.7])
+    );
+    let json = serde_json::to_string(&Source::<2>::simplex_with_radius(42, 0.25).unwrap()).unwrap();
+    assert_eq!(
+        json,
+        r#"{"seed":42,"large_coordinates":false,"r_squared":0.25}"#
+    );
+    let error = serde_json::from_str::<Simplex<2>>(r#"{"seed":42,"r_squared":0.6}"#).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("r_squared must be within [0.01, 0.5]"));
+}
+
+#[test]
+fn test_invalid_gradients() {
+    let json = r#"{"from": [1.0, 2.0], "to": [1.0, 2.0]}"#;
+    let error = serde_json::from_str::<LinearGradient<2>>(json).unwrap_err();
+    assert!(error.to_string().contains("must differ from from"));
+    let json = r#"{"center": [0.0, 0.0], "inner_radius": 2.0, "outer_radius": 1.0}"#;
+    let error = serde_json::from_str::<RadialGradient<2>>(json).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("must be finite and exceed inner_radius"));
+    let json = r#"{"center": [0.0, 0.0], "inner_radius": -1.0, "outer_radius": 1.0}"#;
+    assert!(serde_json::from_str::<RadialGradient<2>>(json).is_err());
+}
+
+#[test]
+fn test_invalid_spot() {
+    let json = r#"{
+        "seed": 42,
+        "kernel": "cone",
+        "radius": 0.9,
+        "density": 3,
+        "amplitude_jitter": 7.0
+    }"#;
+    let error = serde_json::from_str::<Spot<2>>(json).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("amplitude jitter must be within [0, 1]"));
+    for radius in ["0.0", "-0.5"] {
+        let json = serde_json::to_string(&Spot::<2>::new(42).with_radius(0.9))
+            .unwrap()
+            .replace("0.9", radius);
+        let error = serde_json::from_str::<Spot<2>>(&json).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("radius must be positive and finite"));
+    }
+}
+
+#[test]
+fn test_invalid_islands() {
+    for radius in ["0.0", "-2.0"] {
+        let json = format!(
+            r#"{{"center": [0.0, 0.0], "radius": {}, "falloff": "linear"}}"#,
+            radius
+        );
+        let error = serde_json::from_str::<Island>(&json).unwrap_err();
+        assert!(error.to_string().contains("invalid parameter radius"));
+    }
+    let json = r#"{"center": [0.0, 0.0], "radius": 1.0, "falloff": {"exponent": -1.0}}"#;
+    assert!(serde_json::from_str::<Island>(json).is_err());
+    let json = r#"{"min": [0.0, 0.0], "max": [4.0, 0.0], "falloff": "square_bump"}"#;
+    let error = serde_json::from_str::<IslandRect>(json).unwrap_err();
+    assert!(error.to_string().contains("invalid parameter max"));
+}
+
+#[test]
+fn test_invalid_checkerboard() {
+    for period in ["[0.0, -1.0]", "[1.0, 0.0]", "[-2.0, 1.0]"] {
+        let json = format!(r#"{{"period": {}, "smooth": false}}"#, period);
+        let error = serde_json::from_str::<Checkerboard<2>>(&json).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("period must be positive and finite"));
+    }
+}
+
+// =================================================================
+// test adapters
+// =================================================================
+#[test]
+fn test_round_trip_chain() {
+    let generator = Source::<2>::simplex(42)
+        .fbm(6, 1.0, 2.0, 0.5)
+        .clamp(-1.0, 1.0);
+    assert_round_trip(&generator);
+}
+
+#[test]
+fn test_round_trip_adapters() {
+    let generator = Source::<2>::simplex(42)
+        .scale([0.05, 0.07])
+        .translate([3.0, -2.0])
+        .rotate([0.4])
+        .abs()
+        .add(0.5)
+        .mul(0.7)
+        .neg()
+        .exp()
+        .powi(2)
+        .powf(0.5)
+        .checked(-10.0, 10.0)
+        .billow(3, 1.0, 2.0, 0.5)
+        .ridgedmulti(3, 1.0, 2.0, 2.0)
+        .sum(Source::value(43).scale([0.1; 2]))
+        .product(Source::perlin(44).scale([0.1; 2]))
+        .min(Source::constant(0.9))
+        .max(Source::worley(45).scale([0.1; 2]))
+        .power(Source::constant(1.5))
+        .blend(Source::improved_perlin(46), Source::checkerboard())
+        .select(Source::simplex(47), Source::value(48), -0.2, 0.4)
+        .displace_y(Source::perlin(49).mul(2.0));
+    assert_round_trip(&generator);
+    let generator = Source::<3>::simplex(42).rotate([0.4, 1.1, -0.3]);
+    assert_round_trip(&generator);
+    let generator = Source::<4>::perlin(42).rotate([0.4, 1.1, -0.3, 0.9, 2.0, -1.2]);
+    assert_round_trip(&generator);
+    let generator = Source::<2>::simplex(42)
+        .fbm_lod(6, 0.013, 2.0, 0.5)
+        .distance_lod([10.0, 20.0], 30.0);
+    assert_round_trip(&generator);
+}
+
+#[test]
+fn test_fractal_normalization_is_derived() {
+    let json = r#"{
+        "generator": {"seed": 42, "large_coordinates": false},
+        "octaves": 4,
+        "frequency": 0.5,
+        "lacunarity": 2.0,
+        "persistence": 0.6
+    }"#;
+    let generator: Fbm<2, Simplex<2>> = serde_json::from_str(json).unwrap();
+    let expected = Source::<2>::simplex(42).fbm(4, 0.5, 2.0, 0.6);
+    assert!(generator
+        .fingerprint_diff(&expected, [-50.0; 2], [50.0; 2], 16)
+        .is_empty());
+    assert!(!serde_json::to_string(&generator)
+        .unwrap()
+        .contains("normalization"));
+}
+
+#[test]
+fn test_invalid_array_length() {
+    let json =
+        r#"{"generator": {"seed": 42, "large_coordinates": false}, "scale": [1.0, 2.0, 3.0]}"#;
+    let result = serde_json::from_str::<Scale<2, Simplex<2>>>(json);
+    assert!(result.is_err());
+    let json = r#"{"generator": {"seed": 42, "large_coordinates": false}, "scale": [1.0]}"#;
+    let result = serde_json::from_str::<Scale<2, Simplex<2>>>(json);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_params_serialize_as_values() {
+    let generator = Source::<2>::simplex(42)
+        .scale([Param::new(0.5), Param::new(2.0)])
+        .fbm(4, Param::new(0.5), Param::new(2.0), Param::new(0.6));
+    assert_round_trip(&generator);
+    let json = serde_json::to_string(&generator).unwrap();
+    let expected = Source::<2>::simplex(42)
+        .scale([0.5, 2.0])
+        .fbm(4, 0.5, 2.0, 0.6);
+    assert_eq!(serde_json::to_string(&expected).unwrap(), json);
+}
@@ -1,5 +1,8 @@
 use libnoise::prelude::*;
+use libnoise::recipe::NoiseRecipe;
+use libnoise::testing::{assert_tileable, assert_tileable_3d};
 use proptest::prelude::*;
+use tempdir::TempDir;
 
 macro_rules! strategy_float_numeric {
     () => {
@@ -134,6 +137,33 @@ proptest! {
         prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
     }
 
+    // =================================================================
+    // test source: open_simplex2
+    // =================================================================
+    #[test]
+    fn test_open_simplex2_2d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::open_simplex2(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n) || n.is_nan(), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_open_simplex2_3d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::open_simplex2(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n) || n.is_nan(), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_open_simplex2s_2d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::open_simplex2s(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n) || n.is_nan(), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_open_simplex2s_3d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::open_simplex2s(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n) || n.is_nan(), "value not in [-1, 1] range, instead: {}", n);
+    }
+
     // =================================================================
     // test source: perlin
     // =================================================================
@@ -188,6 +218,18 @@ proptest! {
         prop_assert!((-1.0..=1.0).contains(&n) || n.is_nan(), "value not in [-1, 1] range, instead: {}", n);
     }
 
+    #[test]
+    fn test_simplex_5d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<5>::simplex(seed).sample(point);
+        prop_assert!((-1.001..=1.001).contains(&n) || n.is_nan(), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_simplex_6d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<6>::simplex(seed).sample(point);
+        prop_assert!((-1.001..=1.001).contains(&n) || n.is_nan(), "value not in [-1, 1] range, instead: {}", n);
+    }
+
     // =================================================================
     // test source: value
     // =================================================================
@@ -241,4 +283,2350 @@ proptest! {
         let n = Source::<4>::worley(seed).sample(point);
         prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
     }
+
+    #[test]
+    fn test_worley_edge_2d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::worley(seed)
+            .with_return_type(WorleyReturnType::EdgeDistance)
+            .sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_worley_edge_3d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::worley(seed)
+            .with_return_type(WorleyReturnType::EdgeDistance)
+            .sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_worley_second_2d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::worley(seed)
+            .with_return_type(WorleyReturnType::SecondDistance)
+            .sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_worley_difference_3d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::worley(seed)
+            .with_return_type(WorleyReturnType::DistanceDifference)
+            .sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+}
+
+// =================================================================
+// test configurable checkerboard
+// =================================================================
+#[test]
+fn test_checkerboard_period() {
+    let generator = Source::<2>::checkerboard().with_period([2.0, 0.5]);
+    let cases = [
+        ([0.5, 0.25], -1.0),
+        ([2.5, 0.25], 1.0),
+        ([0.5, 0.75], 1.0),
+        ([2.5, 0.75], -1.0),
+        ([4.5, 1.25], -1.0),
+        ([1.99, 0.49], -1.0),
+        ([2.01, 0.49], 1.0),
+    ];
+    for (point, expected) in cases {
+        assert_eq!(generator.sample(point), expected, "at {:?}", point);
+    }
+    // the default period agrees with the unit checkerboard
+    let unit = Source::<3>::checkerboard();
+    let generator = Source::<3>::checkerboard().with_period([1.0; 3]);
+    for i in 0..1000 {
+        let t = i as f64 * 0.173 - 80.0;
+        let point = [t, 3.0 - t * 0.5, t * 0.25 + 7.0];
+        assert_eq!(generator.sample(point), unit.sample(point));
+    }
+    // the hard pattern only ever takes exactly -1 and 1
+    let generator = Source::<3>::checkerboard().with_period([0.3, 1.7, 2.9]);
+    for i in 0..1000 {
+        let t = i as f64 * 0.0731;
+        let value = generator.sample([t, t * 1.3, 40.0 - t]);
+        assert!(value == -1.0 || value == 1.0, "{}", value);
+    }
+}
+
+#[test]
+fn test_checkerboard_smooth() {
+    let hard = Source::<2>::checkerboard().with_period([2.0, 0.5]);
+    let smooth = Source::<2>::checkerboard()
+        .with_period([2.0, 0.5])
+        .smooth(true);
+    // the wave peaks at the centers of the cells, with the sign of the hard pattern
+    for i in 0..6 {
+        for j in 0..6 {
+            let center = [(i as f64 + 0.5) * 2.0, (j as f64 + 0.5) * 0.5];
+            assert!((smooth.sample(center) - hard.sample(center)).abs() < 1e-12);
+        }
+    }
+    // and vanishes at the edges between them
+    assert!(smooth.sample([4.0, 0.3]).abs() < 1e-12);
+    assert!(smooth.sample([0.7, 1.5]).abs() < 1e-12);
+    // it is periodic with twice the period, as is the hard pattern
+    for (point, shifted) in [([0.3, 0.1], [4.3, 0.1]), ([1.7, 0.2], [1.7, 1.2])] {
+        assert!((smooth.sample(point) - smooth.sample(shifted)).abs() < 1e-12);
+        assert_eq!(hard.sample(point), hard.sample(shifted));
+    }
+    // it is continuous, with a slope bounded by pi over the smallest period per axis
+    let step = 1e-4;
+    let lipschitz = core::f64::consts::PI / 0.5 * 2.0_f64.sqrt();
+    for i in 0..2000 {
+        let t = i as f64 * 0.0173;
+        let point = [t, 5.0 - t * 0.7];
+        let value = smooth.sample(point);
+        assert!((-1.0..=1.0).contains(&value), "{}", value);
+        let next = smooth.sample([point[0] + step, point[1] + step]);
+        assert!(
+            (next - value).abs() <= lipschitz * step,
+            "discontinuity at {:?}: {} vs {}",
+            point,
+            value,
+            next
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "period must be positive and finite")]
+fn test_checkerboard_invalid_period() {
+    Source::<2>::checkerboard().with_period([1.0, 0.0]);
+}
+
+// =================================================================
+// test simplex kernel radius
+// =================================================================
+const SIMPLEX_RADII: [f64; 5] = [0.01, 0.1, 0.25, 0.4, 0.5];
+
+fn assert_range_with_radius<const D: usize, G: Generator<D>>(generator: G) {
+    let (lower, upper) = generator.sample_interval([-1e3; D], [1e3; D]);
+    let mut largest = 0.0_f64;
+    for i in 0..50000 {
+        let t = i as f64 * 0.01237;
+        let point = core::array::from_fn(|axis| t * [1.0, 0.618, -0.3, 0.1][axis] + axis as f64);
+        let value = generator.sample(point);
+        assert!(
+            (lower..=upper).contains(&value),
+            "{} at {:?} outside of [{}, {}]",
+            value,
+            point,
+            lower,
+            upper
+        );
+        largest = largest.max(value.abs());
+    }
+    // the rescaling retains the range for small radii
+    assert!(largest > 0.5, "largest absolute value {}", largest);
+}
+
+#[test]
+fn test_simplex_radius_range() {
+    for r_squared in SIMPLEX_RADII {
+        assert_range_with_radius(Source::<1>::simplex_with_radius(42, r_squared).unwrap());
+        assert_range_with_radius(Source::<2>::simplex_with_radius(42, r_squared).unwrap());
+        assert_range_with_radius(Source::<3>::simplex_with_radius(42, r_squared).unwrap());
+        assert_range_with_radius(Source::<4>::simplex_with_radius(42, r_squared).unwrap());
+    }
+    // the default radius reproduces the default noise exactly
+    let generator = Source::<3>::simplex_with_radius(42, 0.5).unwrap();
+    let default = Source::<3>::simplex(42);
+    for i in 0..1000 {
+        let t = i as f64 * 0.173;
+        let point = [t, 300.0 - t * 0.5, t * 0.25 + 7.0];
+        assert_eq!(generator.sample(point), default.sample(point));
+    }
+}
+
+// samples the second difference along a line crossing the borders of the simplices, which are
+// the faces of the skewed unit cubes, and the faces within them where two skewed coordinates
+// are equal. for a continuously differentiable function, it vanishes with the square of the step.
+fn assert_c1_across_borders<const D: usize, G: Generator<D>>(generator: G, curvature: f64) {
+    let unskew = if D == 1 {
+        0.0
+    } else {
+        (1.0 - 1.0 / ((D + 1) as f64).sqrt()) / D as f64
+    };
+    let direction: [f64; D] = core::array::from_fn(|axis| [1.0, 0.37, -0.61, 0.23][axis]);
+    let step = 1e-4;
+    for i in 0..2000 {
+        // a point on a border in skewed space, mapped into input space
+        let mut skewed: [f64; D] =
+            core::array::from_fn(|axis| (i as f64 * [0.7371, 0.3119, 0.5813, 0.9137][axis]) % 9.0);
+        skewed[0] = skewed[0].floor();
+        if D > 1 && i % 2 == 1 {
+            skewed[0] = skewed[1];
+        }
+        let sum = skewed.iter().sum::<f64>();
+        let border: [f64; D] = core::array::from_fn(|axis| skewed[axis] - sum * unskew);
+        let at = |t: f64| {
+            generator.sample(core::array::from_fn(|axis| {
+                border[axis] + t * direction[axis]
+            }))
+        };
+        let (before, center, after) = (at(-step), at(0.0), at(step));
+        let second_difference = (after - 2.0 * center + before).abs() / (step * step);
+        assert!(
+            second_difference <= curvature,
+            "second difference {} at {:?}",
+            second_difference,
+            border
+        );
+    }
+}
+
+#[test]
+fn test_simplex_radius_continuity() {
+    for r_squared in SIMPLEX_RADII {
+        // the curvature grows as the kernel shrinks
+        let curvature = 100.0 / r_squared;
+        assert_c1_across_borders(
+            Source::<1>::simplex_with_radius(7, r_squared).unwrap(),
+            curvature,
+        );
+        assert_c1_across_borders(
+            Source::<2>::simplex_with_radius(7, r_squared).unwrap(),
+            curvature,
+        );
+        assert_c1_across_borders(
+            Source::<3>::simplex_with_radius(7, r_squared).unwrap(),
+            curvature,
+        );
+        assert_c1_across_borders(
+            Source::<4>::simplex_with_radius(7, r_squared).unwrap(),
+            curvature,
+        );
+    }
+}
+
+#[test]
+fn test_simplex_radius_invalid() {
+    for r_squared in [0.6, 0.0, -0.5, 0.001, f64::NAN, f64::INFINITY] {
+        let error = Source::<2>::simplex_with_radius(42, r_squared).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "invalid parameter r_squared: must be within [0.01, 0.5], got {}",
+                r_squared
+            )
+        );
+    }
+}
+
+// =================================================================
+// test large-coordinate mode
+// =================================================================
+fn window_statistics<G: Generator<2>>(generator: &G, origin: [f64; 2]) -> (f64, f64) {
+    // variance of the values and mean squared difference between neighbors in a small window
+    let n = 64;
+    let step = 0.1;
+    let samples = (0..n)
+        .flat_map(|i| (0..n).map(move |j| [i as f64 * step, j as f64 * step]))
+        .map(|[x, y]| generator.sample([origin[0] + x, origin[1] + y]))
+        .collect::<Vec<f64>>();
+    let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+    let variance = samples.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+    let roughness = samples
+        .windows(2)
+        .enumerate()
+        .filter(|(k, _)| (k + 1) % n != 0)
+        .map(|(_, w)| (w[1] - w[0]).powi(2))
+        .sum::<f64>()
+        / (n * (n - 1)) as f64;
+    (variance, roughness)
+}
+
+fn assert_large_coordinates_match<G: Generator<2>>(generator: G) {
+    let (variance_near, roughness_near) = window_statistics(&generator, [0.0, 0.0]);
+    for offset in [1e9, -1e9, 1e12] {
+        let (variance_far, roughness_far) = window_statistics(&generator, [offset, offset]);
+        assert!(
+            (variance_far / variance_near - 1.0).abs() < 0.5,
+            "variance at {offset} deviates: {variance_far} vs {variance_near}"
+        );
+        assert!(
+            (roughness_far / roughness_near - 1.0).abs() < 0.5,
+            "roughness at {offset} deviates: {roughness_far} vs {roughness_near}"
+        );
+    }
+}
+
+#[test]
+fn test_large_coordinates_statistics() {
+    assert_large_coordinates_match(Simplex::new(42).with_large_coordinates());
+    assert_large_coordinates_match(Perlin::new(42).with_large_coordinates());
+    assert_large_coordinates_match(ImprovedPerlin::new(42).with_large_coordinates());
+    assert_large_coordinates_match(Value::new(42).with_large_coordinates());
+    assert_large_coordinates_match(Worley::new(42).with_large_coordinates());
+}
+
+#[test]
+fn test_large_coordinates_consistency() {
+    let default = Simplex::<3>::new(42);
+    let large = Simplex::<3>::new(42).with_large_coordinates();
+    let grid_default = Perlin::<3>::new(42);
+    let grid_large = Perlin::<3>::new(42).with_large_coordinates();
+    for i in 0..1000 {
+        let t = i as f64 * 0.173;
+        let point = [t, 300.0 - t * 0.5, t * 0.25 + 7.0];
+        assert!((default.sample(point) - large.sample(point)).abs() < 1e-12);
+        assert!((grid_default.sample(point) - grid_large.sample(point)).abs() < 1e-12);
+    }
+}
+
+// =================================================================
+// test lattice wrapping for negative coordinates
+// =================================================================
+fn assert_continuous_across_origin<G: Generator<2>>(generator: G, lipschitz: f64) {
+    let step = 1e-3;
+    for row in 0..8 {
+        let y = row as f64 * 0.37 + 0.11;
+        let mut previous = generator.sample([-4.0, y]);
+        for i in 1..8000 {
+            let value = generator.sample([-4.0 + i as f64 * step, y]);
+            assert!(
+                (value - previous).abs() <= lipschitz * step,
+                "discontinuity at x = {}",
+                -4.0 + i as f64 * step
+            );
+            previous = value;
+        }
+    }
+}
+
+fn assert_periodic_across_origin<G: Generator<2>>(generator: G) {
+    for i in 0..2000 {
+        let point = [i as f64 * 0.1283 % 256.0, 100.0 - i as f64 * 0.0719];
+        let value = generator.sample(point);
+        assert!((value - generator.sample([point[0] - 256.0, point[1]])).abs() < 1e-9);
+        assert!((value - generator.sample([point[0], point[1] - 512.0])).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_negative_coordinates_continuity() {
+    assert_continuous_across_origin(Source::simplex(42), 10.0);
+    assert_continuous_across_origin(Source::perlin(42), 10.0);
+    assert_continuous_across_origin(Source::improved_perlin(42), 10.0);
+    assert_continuous_across_origin(Source::open_simplex2(42), 10.0);
+    assert_continuous_across_origin(Source::open_simplex2s(42), 10.0);
+    assert_continuous_across_origin(Source::value(42), 10.0);
+    assert_continuous_across_origin(Source::worley(42), 10.0);
+}
+
+#[test]
+fn test_negative_coordinates_periodicity() {
+    assert_periodic_across_origin(Source::perlin(42));
+    assert_periodic_across_origin(Source::improved_perlin(42));
+    assert_periodic_across_origin(Source::value(42));
+    assert_periodic_across_origin(Source::worley(42));
+}
+
+#[test]
+fn test_negative_coordinates_statistics() {
+    let generators: [Box<dyn Fn([f64; 3]) -> f64>; 5] = [
+        Box::new(|p| Source::simplex(42).sample(p)),
+        Box::new(|p| Source::perlin(42).sample(p)),
+        Box::new(|p| Source::improved_perlin(42).sample(p)),
+        Box::new(|p| Source::value(42).sample(p)),
+        Box::new(|p| Source::worley(42).sample(p)),
+    ];
+    for generator in generators {
+        let generator = Source::custom(|[x, y]| generator([x, y, 0.5]));
+        // average over several windows on either side of the origin
+        let statistics = |sign: f64| {
+            [[0.0, 0.0], [6.4, 0.0], [0.0, 6.4], [6.4, 6.4]]
+                .map(|[x, y]| window_statistics(&generator, [sign * (x + 6.4), sign * (y + 6.4)]))
+                .iter()
+                .fold((0.0, 0.0), |acc, stat| (acc.0 + stat.0, acc.1 + stat.1))
+        };
+        let (variance_neg, roughness_neg) = statistics(-1.0);
+        let (variance_pos, roughness_pos) = statistics(1.0);
+        assert!((variance_neg / variance_pos - 1.0).abs() < 0.35);
+        assert!((roughness_neg / roughness_pos - 1.0).abs() < 0.35);
+    }
+}
+
+// =================================================================
+// test smoothness of 1-dimensional simplex noise
+// =================================================================
+#[test]
+fn test_simplex_1d_continuity_at_cutoff() {
+    // the contribution of a lattice point vanishes at the radius 1/sqrt(2), which lies at this
+    // offset from the lattice point and from its neighbor
+    let generator = Source::<1>::simplex(42);
+    let lipschitz = 16.0;
+    let step = 1e-7;
+    for lattice in -8..8 {
+        for cutoff in [
+            std::f64::consts::FRAC_1_SQRT_2,
+            1.0 - std::f64::consts::FRAC_1_SQRT_2,
+        ] {
+            let center = lattice as f64 + cutoff;
+            let mut previous = generator.sample([center - 1000.0 * step]);
+            for i in -999..=1000 {
+                let value = generator.sample([center + i as f64 * step]);
+                assert!(
+                    (value - previous).abs() <= lipschitz * step,
+                    "discontinuity at x = {}",
+                    center + i as f64 * step
+                );
+                previous = value;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_simplex_1d_derivative_continuity() {
+    // bound on the second derivative of the normalized falloff polynomial with steepest gradient
+    let generator = Source::<1>::simplex(42);
+    let curvature = 100.0;
+    let step = 1e-3;
+    let derivative = |x: f64| (generator.sample([x + step]) - generator.sample([x])) / step;
+    let mut previous = derivative(-5.0);
+    for i in 1..5000 {
+        let x = -5.0 + i as f64 * 2e-3;
+        let current = derivative(x);
+        assert!(
+            (current - previous).abs() <= curvature * 2e-3,
+            "kink at x = {}",
+            x
+        );
+        previous = current;
+    }
+}
+
+// =================================================================
+// test non-finite inputs
+// =================================================================
+fn assert_non_finite_behavior<const D: usize, G: Generator<D>>(generator: G) {
+    for special in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        for axis in 0..D {
+            let mut point = [0.3; D];
+            point[axis] = special;
+            let value = generator.sample(point);
+            assert!(value.is_nan(), "{special} at axis {axis} produced {value}");
+        }
+    }
+    for special in [f64::MAX, f64::MIN, f64::MIN_POSITIVE, -f64::MIN_POSITIVE] {
+        let value = generator.sample([special; D]);
+        assert!(value.is_nan() || (-1.0..=1.0).contains(&value));
+    }
+}
+
+macro_rules! assert_non_finite_behavior_all_dims {
+    ($source:ident($($arg:expr),*)) => {
+        assert_non_finite_behavior(Source::<1>::$source($($arg),*));
+        assert_non_finite_behavior(Source::<2>::$source($($arg),*));
+        assert_non_finite_behavior(Source::<3>::$source($($arg),*));
+        assert_non_finite_behavior(Source::<4>::$source($($arg),*));
+    };
+}
+
+#[test]
+fn test_non_finite_inputs() {
+    assert_non_finite_behavior_all_dims!(simplex(42));
+    assert_non_finite_behavior(Source::<4>::simplex_with_radius(42, 0.3).unwrap());
+    assert_non_finite_behavior_all_dims!(value(42));
+    assert_non_finite_behavior_all_dims!(perlin(42));
+    assert_non_finite_behavior_all_dims!(improved_perlin(42));
+    assert_non_finite_behavior(Source::<2>::open_simplex2(42));
+    assert_non_finite_behavior(Source::<3>::open_simplex2(42));
+    assert_non_finite_behavior(Source::<2>::open_simplex2s(42));
+    assert_non_finite_behavior(Source::<3>::open_simplex2s(42));
+    assert_non_finite_behavior_all_dims!(worley(42));
+    assert_non_finite_behavior_all_dims!(checkerboard());
+    assert_non_finite_behavior(Source::<3>::checkerboard().smooth(true));
+    assert_non_finite_behavior_all_dims!(white(42));
+    assert_non_finite_behavior_all_dims!(wavelet(42, 8));
+    assert_non_finite_behavior_all_dims!(spot(42));
+    assert_non_finite_behavior(Source::blue(42, 8));
+    assert_non_finite_behavior(Source::distance_field(vec![[0.5], [-2.0]]).unwrap());
+    assert_non_finite_behavior(Source::linear_gradient([0.0, 1.0], [2.0, -1.0]).unwrap());
+    assert_non_finite_behavior(Source::linear_gradient([0.0; 4], [1.0; 4]).unwrap());
+    assert_non_finite_behavior(Source::radial_gradient([0.5], 0.0, 2.0).unwrap());
+    assert_non_finite_behavior(Source::radial_gradient([0.5; 3], 1.0, 2.0).unwrap());
+    assert_non_finite_behavior(Source::island([0.5, 1.0], 2.0, FalloffCurve::Smoothstep).unwrap());
+    assert_non_finite_behavior(
+        Source::island_rect([0.0, 1.0], [2.0, 3.0], FalloffCurve::SquareBump).unwrap(),
+    );
+    assert_non_finite_behavior(Source::distance_field(vec![[0.5, 1.0, -2.0]]).unwrap());
+    assert_non_finite_behavior(Blue::new(42, 8).with_interpolation());
+    assert_non_finite_behavior(Source::image(&image::DynamicImage::new_luma8(4, 4)));
+    let buf = NoiseBuffer::<3>::new([4, 4, 4], &Source::simplex(42));
+    assert_non_finite_behavior(Source::from_buffer(buf, [0.0; 3], [1.0; 3]).unwrap());
+    assert_non_finite_behavior(Simplex::<3>::new(42).with_large_coordinates());
+    assert_non_finite_behavior(Perlin::<2>::new(42).with_large_coordinates());
+    assert_non_finite_behavior(Worley::<2>::new(42).with_large_coordinates());
+    for return_type in [
+        WorleyReturnType::Distance,
+        WorleyReturnType::EdgeDistance,
+        WorleyReturnType::SecondDistance,
+        WorleyReturnType::DistanceDifference,
+        WorleyReturnType::WeightedDistance {
+            nearest: 0.0,
+            second: 0.5,
+        },
+    ] {
+        assert_non_finite_behavior(Worley::<1>::new(42).with_return_type(return_type));
+        assert_non_finite_behavior(Worley::<4>::new(42).with_return_type(return_type));
+    }
+    assert_eq!(Source::constant(0.5).sample([f64::NAN, f64::INFINITY]), 0.5);
+}
+
+// =================================================================
+// test sample_interval
+// =================================================================
+// samples random points within random boxes and asserts that the results lie within the
+// interval computed for the box
+fn assert_interval_contains<const D: usize, G: Generator<D>>(generator: G, extent: f64) {
+    let mut state = 0x853c_49e6_748f_ea9b_u64;
+    let mut random = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 11) as f64 / (1_u64 << 53) as f64
+    };
+    for _ in 0..500 {
+        let min: [f64; D] = std::array::from_fn(|_| (random() * 2.0 - 1.0) * 300.0);
+        let max: [f64; D] = std::array::from_fn(|i| min[i] + random() * extent);
+        let (lower, upper) = generator.sample_interval(min, max);
+        assert!(lower <= upper, "empty interval ({lower}, {upper})");
+        for _ in 0..200 {
+            let point = std::array::from_fn(|i| min[i] + random() * (max[i] - min[i]));
+            let value = generator.sample(point);
+            assert!(
+                value.is_nan() || (lower <= value && value <= upper),
+                "value {value} at {point:?} not within ({lower}, {upper}) computed for box \
+                 from {min:?} to {max:?}"
+            );
+        }
+    }
+}
+
+macro_rules! assert_interval_contains_all_dims {
+    ($source:ident($($arg:expr),*), $extent:expr) => {
+        assert_interval_contains(Source::<1>::$source($($arg),*), $extent);
+        assert_interval_contains(Source::<2>::$source($($arg),*), $extent);
+        assert_interval_contains(Source::<3>::$source($($arg),*), $extent);
+        assert_interval_contains(Source::<4>::$source($($arg),*), $extent);
+    };
+}
+
+#[test]
+fn test_sample_interval() {
+    assert_interval_contains_all_dims!(simplex(42), 10.0);
+    assert_interval_contains(Source::<3>::simplex_with_radius(42, 0.3).unwrap(), 10.0);
+    assert_interval_contains_all_dims!(value(42), 10.0);
+    assert_interval_contains_all_dims!(perlin(42), 10.0);
+    assert_interval_contains_all_dims!(improved_perlin(42), 10.0);
+    assert_interval_contains(Source::<2>::open_simplex2(42), 10.0);
+    assert_interval_contains(Source::<3>::open_simplex2(42), 10.0);
+    assert_interval_contains(Source::<2>::open_simplex2s(42), 10.0);
+    assert_interval_contains(Source::<3>::open_simplex2s(42), 10.0);
+    assert_interval_contains_all_dims!(worley(42), 10.0);
+    assert_interval_contains_all_dims!(checkerboard(), 0.5);
+    assert_interval_contains(Source::<2>::checkerboard().with_period([3.0, 0.5]), 2.0);
+    assert_interval_contains(Source::<1>::checkerboard().smooth(true), 0.4);
+    assert_interval_contains(
+        Source::<3>::checkerboard()
+            .with_period([2.0, 0.7, 1.5])
+            .smooth(true),
+        1.0,
+    );
+    assert_interval_contains_all_dims!(white(42), 10.0);
+    assert_interval_contains_all_dims!(wavelet(42, 8), 10.0);
+    assert_interval_contains_all_dims!(spot(42), 10.0);
+    assert_interval_contains(Blue::new(42, 8).with_interpolation(), 10.0);
+    let image = image::DynamicImage::ImageLuma8(image::GrayImage::from_fn(8, 8, |x, y| {
+        image::Luma([(x * 37 + y * 91) as u8])
+    }));
+    let buf = NoiseBuffer::<2>::new([16, 16], &Source::simplex(42).scale([0.3; 2]));
+    for interpolation in [Interpolation::Bilinear, Interpolation::Bicubic] {
+        assert_interval_contains(
+            Source::from_buffer(buf.clone(), [-50.0, -20.0], [2.0, 3.0])
+                .unwrap()
+                .with_interpolation(interpolation)
+                .with_boundary(Boundary::Wrap),
+            10.0,
+        );
+        assert_interval_contains(
+            Source::image(&image)
+                .with_interpolation(interpolation)
+                .with_boundary(ImageBoundary::Constant(-1.5)),
+            10.0,
+        );
+    }
+    assert_interval_contains(
+        Worley::<3>::new(42).with_return_type(WorleyReturnType::EdgeDistance),
+        10.0,
+    );
+    assert_interval_contains(
+        Source::linear_gradient([-100.0, 50.0, 0.0], [80.0, -20.0, 30.0]).unwrap(),
+        100.0,
+    );
+    assert_interval_contains(
+        Source::radial_gradient([30.0, -60.0], 40.0, 200.0).unwrap(),
+        100.0,
+    );
+    for falloff in [
+        FalloffCurve::Linear,
+        FalloffCurve::SquareBump,
+        FalloffCurve::Exponent(0.5),
+    ] {
+        assert_interval_contains(
+            Source::island([30.0, -60.0], 150.0, falloff).unwrap(),
+            100.0,
+        );
+        assert_interval_contains(
+            Source::island_rect([-100.0, -50.0], [120.0, 90.0], falloff).unwrap(),
+            100.0,
+        );
+    }
+    assert_interval_contains(
+        Source::distance_field(points::poisson_disk(42, [-300.0; 2], [310.0; 2], 25.0))
+            .unwrap()
+            .with_max_distance(20.0),
+        10.0,
+    );
+    assert_interval_contains_all_dims!(constant(0.5), 10.0);
+    assert_interval_contains(Simplex::<3>::new(42).with_large_coordinates(), 10.0);
+}
+
+#[test]
+fn test_sample_interval_exact() {
+    let generator = Source::<2>::checkerboard();
+    assert_eq!(
+        generator.sample_interval([2.1, 3.2], [2.9, 3.8]),
+        (1.0, 1.0)
+    );
+    assert_eq!(
+        generator.sample_interval([2.1, 3.2], [3.1, 3.8]),
+        (-1.0, 1.0)
+    );
+    let generator = Source::<3>::constant(0.5);
+    assert_eq!(generator.sample_interval([0.0; 3], [1e9; 3]), (0.5, 0.5));
+}
+
+// =================================================================
+// test stateful custom sources
+// =================================================================
+// nearest-neighbor lookup into a heightmap of the given width, wrapping around its borders
+fn lookup_heightmap(heights: &[f64], width: usize, [x, y]: [f64; 2]) -> f64 {
+    let height = heights.len() / width;
+    let x = (x.floor() as i64).rem_euclid(width as i64) as usize;
+    let y = (y.floor() as i64).rem_euclid(height as i64) as usize;
+    heights[y * width + x]
+}
+
+fn heightmap() -> Vec<f64> {
+    (0..64).map(|i| (i as f64 * 0.37).sin()).collect()
+}
+
+fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+#[test]
+fn test_custom_capturing() {
+    let heights = heightmap();
+    let expected = heights.clone();
+    let generator = Source::custom(move |point| lookup_heightmap(&heights, 8, point))
+        .scale([0.5, 0.5])
+        .mul(2.0)
+        .clamp(-1.5, 1.5);
+    assert_send_sync(&generator);
+    for point in [[0.0, 0.0], [3.7, 9.2], [-5.5, 14.0]] {
+        let scaled = [point[0] * 0.5, point[1] * 0.5];
+        let value = (lookup_heightmap(&expected, 8, scaled) * 2.0).clamp(-1.5, 1.5);
+        assert_eq!(generator.sample(point), value);
+    }
+    let sampled = std::thread::spawn({
+        let generator = generator.clone();
+        move || generator.sample([3.7, 9.2])
+    })
+    .join()
+    .unwrap();
+    assert_eq!(sampled, generator.sample([3.7, 9.2]));
+}
+
+#[test]
+fn test_custom_dyn() {
+    let heights = heightmap();
+    let behaviors: [std::sync::Arc<dyn Fn([f64; 2]) -> f64 + Send + Sync>; 2] = [
+        std::sync::Arc::new(move |point| lookup_heightmap(&heights, 8, point)),
+        std::sync::Arc::new(|[x, y]| x * y),
+    ];
+    let generators = behaviors.map(Source::custom_dyn);
+    assert_send_sync(&generators);
+    assert_eq!(
+        generators[0].sample([3.7, 9.2]),
+        lookup_heightmap(&heightmap(), 8, [3.7, 9.2])
+    );
+    let generator = generators[1].clone().add(1.0).neg();
+    assert_eq!(generator.sample([2.0, 3.0]), -7.0);
+    let sampled = std::thread::spawn(move || generator.sample([0.5, 4.0]))
+        .join()
+        .unwrap();
+    assert_eq!(sampled, -3.0);
+    assert_eq!(generators[1].to_string(), "CustomDyn(noise=<closure>)");
+}
+
+#[test]
+fn test_from_fn_with_state() {
+    let generator = Source::from_fn_with_state(heightmap(), |heights, point| {
+        lookup_heightmap(heights, 8, point)
+    });
+    assert_send_sync(&generator);
+    assert_eq!(generator.state().len(), 64);
+    let generator = generator.abs().add(0.5);
+    assert_eq!(
+        generator.sample([3.7, 9.2]),
+        lookup_heightmap(&heightmap(), 8, [3.7, 9.2]).abs() + 0.5
+    );
+    let sampled = std::thread::spawn({
+        let generator = generator.clone();
+        move || generator.sample([-5.5, 14.0])
+    })
+    .join()
+    .unwrap();
+    assert_eq!(sampled, generator.sample([-5.5, 14.0]));
+}
+
+// =================================================================
+// test source: worley edge distance
+// =================================================================
+// recovers the position of the feature point nearest to the given point from the distances
+// sampled around it, as the squared distance to a fixed point changes by 2 * h * (x - p) + h²
+// when moving by h along an axis
+fn nearest_feature_point(generator: &Worley<2>, point: [f64; 2]) -> [f64; 2] {
+    let dist_sq = |point: [f64; 2]| ((generator.sample(point) + 1.0) / 2.0).powi(2);
+    let h = 1e-3;
+    let base = dist_sq(point);
+    std::array::from_fn(|axis| {
+        let mut shifted = point;
+        shifted[axis] += h;
+        point[axis] - (dist_sq(shifted) - base - h * h) / (2.0 * h)
+    })
+}
+
+#[test]
+fn test_worley_edge_distance_bisector() {
+    let distance = Worley::<2>::new(42);
+    let edge = Worley::<2>::new(42).with_return_type(WorleyReturnType::EdgeDistance);
+    let difference = Worley::<2>::new(42).with_return_type(WorleyReturnType::DistanceDifference);
+    let mut checked = 0;
+    for cell in 0..20 {
+        // the feature points of two horizontally adjacent cells
+        let a = nearest_feature_point(&distance, [cell as f64 + 0.5, 0.5]);
+        let b = nearest_feature_point(&distance, [cell as f64 + 1.5, 0.5]);
+        let midpoint = [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0];
+        let half_separation = (b[0] - a[0]).hypot(b[1] - a[1]) / 2.0;
+        // skip pairs whose cells are not adjacent at their midpoint, as it lies in a third cell
+        if ((distance.sample(midpoint) + 1.0) / 2.0 - half_separation).abs() > 1e-6 {
+            continue;
+        }
+        checked += 1;
+        assert!(
+            (edge.sample(midpoint) + 1.0).abs() < 1e-6,
+            "{} at bisector midpoint {midpoint:?}",
+            edge.sample(midpoint)
+        );
+        // both feature points are equally near on the border
+        assert!(
+            (difference.sample(midpoint) + 1.0).abs() < 1e-6,
+            "{} at bisector midpoint {midpoint:?}",
+            difference.sample(midpoint)
+        );
+        // moving away from the border along the direction between both points increases the
+        // value linearly, as the distance is mapped from [0, 0.5] to [-1, 1]
+        let direction = [
+            (b[0] - a[0]) / half_separation,
+            (b[1] - a[1]) / half_separation,
+        ];
+        for t in [1e-3, 1e-2] {
+            for sign in [-1.0, 1.0] {
+                let point = [
+                    midpoint[0] + sign * t * direction[0] / 2.0,
+                    midpoint[1] + sign * t * direction[1] / 2.0,
+                ];
+                let value = edge.sample(point);
+                assert!(
+                    (value - (-1.0 + 4.0 * t)).abs() < 1e-6,
+                    "{value} at distance {t} from bisector midpoint {midpoint:?}"
+                );
+            }
+        }
+    }
+    assert!(
+        checked >= 10,
+        "only {checked} pairs of adjacent cells checked"
+    );
+}
+
+// asserts that the edge distance along the line from `origin` in the unit `direction` never
+// changes faster than the distance itself, which is mapped from [0, 0.5] to [-1, 1]
+fn assert_edge_continuous<const D: usize, G: Generator<D>>(
+    generator: &G,
+    origin: [f64; D],
+    direction: [f64; D],
+    length: f64,
+) {
+    const STEP: f64 = 1e-3;
+    let point = |t: f64| core::array::from_fn(|i| origin[i] + t * direction[i]);
+    let mut previous = generator.sample(point(0.0));
+    for i in 1..=(length / STEP) as usize {
+        let value = generator.sample(point(i as f64 * STEP));
+        assert!(
+            (value - previous).abs() <= 4.0 * STEP + 1e-9,
+            "jump from {previous} to {value} at {:?}",
+            point(i as f64 * STEP)
+        );
+        previous = value;
+    }
+}
+
+#[test]
+fn test_worley_edge_distance_continuity() {
+    // the distance to the nearest border is continuous, unlike that to the border between the
+    // nearest and second nearest feature point only. the feature points bounding the cell of the
+    // nearest one may lie outside the neighborhood of the cell of the input, which causes jumps
+    // when crossing the lines between cells if missed
+    for seed in 0..16 {
+        let generator = Worley::<2>::new(seed).with_return_type(WorleyReturnType::EdgeDistance);
+        for row in 0..6 {
+            let y = row as f64 * 1.37 + 0.11;
+            assert_edge_continuous(&generator, [-30.0, y], [1.0, 0.0], 60.0);
+        }
+        assert_edge_continuous(&generator, [0.3, -20.0], [0.6, 0.8], 40.0);
+    }
+    for seed in 0..4 {
+        let generator = Worley::<3>::new(seed).with_return_type(WorleyReturnType::EdgeDistance);
+        assert_edge_continuous(&generator, [-10.0, 0.4, -7.0], [0.48, 0.6, 0.64], 30.0);
+        assert_edge_continuous(&generator, [-10.0, 0.5, 1.5], [1.0, 0.0, 0.0], 20.0);
+    }
+}
+
+// =================================================================
+// test source: worley with density
+// =================================================================
+#[test]
+fn test_worley_density_determinism() {
+    let density = || Source::<2>::simplex(7).scale([0.05; 2]);
+    let generator = Source::worley(42).with_density(density(), 4);
+    let same = Source::worley(42).with_density(density(), 4);
+    let other = Source::worley(43).with_density(density(), 4);
+    let mut differing = 0;
+    for i in 0..1000 {
+        let point = [i as f64 * 0.731 - 300.0, (i % 29) as f64 * 1.37];
+        let value = generator.sample(point);
+        assert!((-1.0..=1.0).contains(&value), "{value} at {point:?}");
+        assert_eq!(value, same.sample(point));
+        assert_eq!(value, generator.clone().sample(point));
+        if value != other.sample(point) {
+            differing += 1;
+        }
+    }
+    assert!(
+        differing > 900,
+        "only {differing} values differ across seeds"
+    );
+    assert!(Source::worley(42)
+        .with_density(density(), 4)
+        .sample([f64::NAN, 0.0])
+        .is_nan());
+}
+
+#[test]
+fn test_worley_density_continuity() {
+    let density = Source::<2>::simplex(7).scale([0.2; 2]);
+    assert_continuous_across_origin(Source::worley(42).with_density(density, 4), 2.0);
+    // sparse points leave many cells empty, such that the nearest point is often found in a
+    // neighboring cell
+    let sparse = Source::<2>::constant(-0.7);
+    assert_continuous_across_origin(Source::worley(42).with_density(sparse, 2), 2.0);
+    let edge = Source::worley(42)
+        .with_return_type(WorleyReturnType::EdgeDistance)
+        .with_density(Source::<2>::simplex(7).scale([0.2; 2]), 3);
+    assert_interval_contains(edge, 10.0);
+
+    // without any points, every input is far from them
+    let empty = Source::worley(42).with_density(Source::constant(-1.0), 8);
+    assert_eq!(empty.sample([3.7, -1.2]), 1.0);
+}
+
+#[test]
+fn test_worley_density_edge_distance_continuity() {
+    // empty cells leave the feature points bounding the cell of the nearest one far away, such
+    // that missing them causes jumps when crossing the lines between cells
+    for seed in 0..6 {
+        let edge = Source::worley(seed).with_return_type(WorleyReturnType::EdgeDistance);
+        let varying = edge
+            .clone()
+            .with_density(Source::<2>::simplex(7).scale([0.2; 2]), 4);
+        let sparse = edge.with_density(Source::<2>::constant(-0.7), 2);
+        for row in 0..3 {
+            let y = row as f64 * 2.37 + 0.11;
+            assert_edge_continuous(&varying, [-30.0, y], [1.0, 0.0], 60.0);
+            assert_edge_continuous(&sparse, [-30.0, y], [1.0, 0.0], 60.0);
+        }
+        assert_edge_continuous(&sparse, [0.3, -20.0], [0.6, 0.8], 40.0);
+    }
+    // many points per cell overflow the points kept on the stack
+    let crowded = Source::worley(42)
+        .with_return_type(WorleyReturnType::EdgeDistance)
+        .with_density(Source::<2>::constant(1.0), 64);
+    assert_edge_continuous(&crowded, [-2.0, 0.4], [1.0, 0.0], 4.0);
+}
+
+#[test]
+fn test_worley_density_correlation() {
+    // the density rises from -1 at x = 0 to 1 at x = 200, and the number of points per area is
+    // estimated per strip from the mean distance to the nearest point, which is 1 / (2 * sqrt(n))
+    // for n uniformly distributed points per unit of area
+    let density = Source::linear_gradient([0.0, 0.0], [200.0, 0.0]).unwrap();
+    let generator = Source::worley(42).with_density(density, 6);
+    let strips = 10;
+    let estimates: Vec<f64> = (0..strips)
+        .map(|strip| {
+            let samples = 20_000;
+            let mean = (0..samples)
+                .map(|i| {
+                    let x = strip as f64 * 20.0 + (i % 100) as f64 * 0.2;
+                    let y = (i / 100) as f64 * 0.51;
+                    (generator.sample([x, y]) + 1.0) / 2.0
+                })
+                .sum::<f64>()
+                / samples as f64;
+            1.0 / (4.0 * mean * mean)
+        })
+        .collect();
+    // the expected number of points per cell is the fraction of kept candidates times 3.5
+    let expected: Vec<f64> = (0..strips)
+        .map(|strip| (strip as f64 + 0.5) / strips as f64 * 3.5)
+        .collect();
+    let correlation = pearson(&estimates, &expected);
+    assert!(
+        correlation > 0.95,
+        "correlation {correlation} of estimates {estimates:?}"
+    );
+    assert!(estimates[strips - 1] > 5.0 * estimates[1], "{estimates:?}");
+}
+
+fn pearson(a: &[f64], b: &[f64]) -> f64 {
+    let mean = |v: &[f64]| v.iter().sum::<f64>() / v.len() as f64;
+    let (mean_a, mean_b) = (mean(a), mean(b));
+    let covariance: f64 = a
+        .iter()
+        .zip(b)
+        .map(|(x, y)| (x - mean_a) * (y - mean_b))
+        .sum();
+    let variance = |v: &[f64], m: f64| v.iter().map(|x| (x - m) * (x - m)).sum::<f64>();
+    covariance / (variance(a, mean_a) * variance(b, mean_b)).sqrt()
+}
+
+#[test]
+#[should_panic(expected = "max_points_per_cell must be at least 1, got 0")]
+fn test_worley_density_invalid_max_points() {
+    Source::worley(42).with_density(Source::constant(0.0), 0);
+}
+
+// =================================================================
+// test worley return types
+// =================================================================
+#[test]
+fn test_worley_distance_combinations() {
+    // the distance or weighted sum of distances encoded by the value of the return type
+    let distance = |return_type, point| {
+        (Worley::<3>::new(42)
+            .with_return_type(return_type)
+            .sample(point)
+            + 1.0)
+            / 2.0
+    };
+    let weighted = |nearest, second| WorleyReturnType::WeightedDistance { nearest, second };
+    for i in 0..2000 {
+        let point = [
+            i as f64 * 0.173 - 50.0,
+            i as f64 * -0.091,
+            (i % 37) as f64 * 0.29,
+        ];
+        let nearest = distance(WorleyReturnType::Distance, point);
+        let second = distance(WorleyReturnType::SecondDistance, point);
+        assert!(nearest <= second, "{nearest} > {second} at {point:?}");
+        assert!((distance(weighted(1.0, 0.0), point) - nearest).abs() < 1e-12);
+        assert!(
+            (distance(WorleyReturnType::DistanceDifference, point) - (second - nearest)).abs()
+                < 1e-12
+        );
+        assert!(
+            (distance(weighted(0.5, -2.0), point) - (0.5 * nearest - 2.0 * second)).abs() < 1e-12
+        );
+    }
+}
+
+#[test]
+fn test_worley_distance_combinations_continuity() {
+    for return_type in [
+        WorleyReturnType::SecondDistance,
+        WorleyReturnType::DistanceDifference,
+    ] {
+        assert_continuous_across_origin(Source::worley(42).with_return_type(return_type), 10.0);
+        let density = Source::<2>::simplex(7).scale([0.2; 2]);
+        assert_continuous_across_origin(
+            Source::worley(42)
+                .with_return_type(return_type)
+                .with_density(density, 4),
+            10.0,
+        );
+    }
+    let weighted = WorleyReturnType::WeightedDistance {
+        nearest: 2.0,
+        second: -0.5,
+    };
+    assert_interval_contains(Worley::<2>::new(42).with_return_type(weighted), 10.0);
+    assert_interval_contains(
+        Worley::<2>::new(42)
+            .with_return_type(weighted)
+            .with_density(Source::constant(-0.7), 2),
+        10.0,
+    );
+    assert_eq!(
+        Worley::<3>::new(42)
+            .with_return_type(weighted)
+            .sample_interval([0.0; 3], [1.0; 3]),
+        (-2.0, 2.0)
+    );
+}
+
+#[test]
+#[should_panic(expected = "return_type must have finite weights")]
+fn test_worley_invalid_weights() {
+    Source::<2>::worley(42).with_return_type(WorleyReturnType::WeightedDistance {
+        nearest: f64::NAN,
+        second: 1.0,
+    });
+}
+
+// =================================================================
+// test source: simplex in more than 4 dimensions
+// =================================================================
+fn assert_simplex_nd<const D: usize>() {
+    let generator = Source::<D>::simplex(42);
+    let other = Source::<D>::simplex(43);
+    let large = Source::<D>::simplex(42).with_large_coordinates();
+    let step = 1e-4;
+    let (mut sum, mut sum_sq, mut differing, mut reached) = (0.0, 0.0, 0, 0);
+    let samples = 5000;
+    for i in 0..samples {
+        let point: [f64; D] = core::array::from_fn(|axis| {
+            ((i * (2 * axis + 3)) % 997) as f64 * 0.173 - 80.0 + axis as f64 * 0.31
+        });
+        let value = generator.sample(point);
+        assert!((-1.001..=1.001).contains(&value), "{value} at {point:?}");
+        assert_eq!(value, generator.sample(point));
+        assert!(
+            (value - large.sample(point)).abs() < 1e-9,
+            "large coordinates differ at {point:?}"
+        );
+        // the radius of the corner contributions grows with the simplices, such that no region
+        // is beyond the reach of every lattice point
+        if value != 0.0 {
+            reached += 1;
+            if value != other.sample(point) {
+                differing += 1;
+            }
+        }
+        let mut moved = point;
+        moved[i % D] += step;
+        let change = (generator.sample(moved) - value).abs();
+        assert!(change < 50.0 * step, "jump of {change} at {point:?}");
+        sum += value;
+        sum_sq += value * value;
+    }
+    let mean = sum / samples as f64;
+    let deviation = (sum_sq / samples as f64 - mean * mean).sqrt();
+    assert!(mean.abs() < 0.05, "mean {mean} in {D} dimensions");
+    assert!(deviation > 0.02, "deviation {deviation} in {D} dimensions");
+    assert!(
+        reached > samples * 99 / 100,
+        "only {reached} values are reached"
+    );
+    assert!(
+        differing > reached * 9 / 10,
+        "only {differing} of {reached} values differ"
+    );
+    assert!(generator.sample([f64::NAN; D]).is_nan());
+}
+
+#[test]
+fn test_simplex_nd() {
+    assert_simplex_nd::<5>();
+    assert_simplex_nd::<6>();
+    assert_simplex_nd::<8>();
+    assert_simplex_nd::<10>();
+    // beyond the tabulated normalization factors, the output is clamped
+    assert_simplex_nd::<12>();
+}
+
+#[test]
+fn test_simplex_nd_interval() {
+    assert_interval_contains(Source::<5>::simplex(42), 10.0);
+    assert_interval_contains(Source::<7>::simplex(42), 10.0);
+}
+
+// =================================================================
+// test source: vector simplex
+// =================================================================
+fn assert_vector_simplex_range<const D: usize>() {
+    let generator = Source::<D>::vector_simplex::<3>(42);
+    let large = Source::<D>::vector_simplex::<3>(42).with_large_coordinates();
+    let step = 1e-4;
+    let mut extremes = [0.0_f64; 3];
+    for i in 0..5000 {
+        let point: [f64; D] = core::array::from_fn(|axis| {
+            ((i * (2 * axis + 3)) % 997) as f64 * 0.173 - 80.0 + axis as f64 * 0.31
+        });
+        let values = generator.sample(point);
+        let mut moved = point;
+        moved[i % D] += step;
+        let moved_values = generator.sample(moved);
+        let large_values = large.sample(point);
+        for k in 0..3 {
+            assert!((-1.0..=1.0).contains(&values[k]), "{values:?} at {point:?}");
+            assert!((values[k] - large_values[k]).abs() < 1e-9);
+            let change = (moved_values[k] - values[k]).abs();
+            assert!(change < 50.0 * step, "jump of {change} at {point:?}");
+            extremes[k] = extremes[k].max(values[k].abs());
+        }
+    }
+    assert!(
+        extremes.iter().all(|&extreme| extreme > 0.3),
+        "extremes {extremes:?} in {D} dimensions"
+    );
+    assert!(generator.sample([f64::NAN; D]).iter().all(|v| v.is_nan()));
+}
+
+#[test]
+fn test_vector_simplex_range() {
+    assert_vector_simplex_range::<1>();
+    assert_vector_simplex_range::<2>();
+    assert_vector_simplex_range::<3>();
+    assert_vector_simplex_range::<4>();
+    assert_vector_simplex_range::<5>();
+}
+
+#[test]
+fn test_vector_simplex_components() {
+    let generator = Source::<2>::vector_simplex::<2>(42);
+    let [x, y] = generator.clone().components();
+    assert_continuous_across_origin(x.clone(), 10.0);
+    assert_continuous_across_origin(y.clone(), 10.0);
+    assert_interval_contains(y.clone(), 10.0);
+
+    // the components are based on different gradients and thus uncorrelated
+    let points: Vec<[f64; 2]> = (0..10_000)
+        .map(|i| [(i % 100) as f64 * 0.37, (i / 100) as f64 * 0.41])
+        .collect();
+    let xs: Vec<f64> = points.iter().map(|&p| x.sample(p)).collect();
+    let ys: Vec<f64> = points.iter().map(|&p| y.sample(p)).collect();
+    let correlation = pearson(&xs, &ys);
+    assert!(correlation.abs() < 0.1, "correlation {correlation}");
+
+    // the first component of vectors beyond 4 dimensions is the generic simplex noise
+    let generator = Source::<5>::vector_simplex::<2>(42);
+    let simplex = Source::<5>::simplex(42);
+    for i in 0..100 {
+        let point = [i as f64 * 0.37, 1.5, -2.25, i as f64 * -0.61, 4.0];
+        assert_eq!(generator.sample(point)[0], simplex.sample(point));
+    }
+}
+
+// =================================================================
+// test source: white
+// =================================================================
+#[test]
+fn test_white_deterministic() {
+    let generator = Source::<3>::white(42);
+    for point in [[0.0, 0.0, 0.0], [1.5, -2.25, 1e9], [-3.0, 7.0, 0.1]] {
+        let value = generator.sample(point);
+        assert!(
+            (-1.0..=1.0).contains(&value),
+            "value {value} not in [-1, 1]"
+        );
+        assert_eq!(value, generator.sample(point));
+        assert_eq!(value, Source::<3>::white(42).sample(point));
+    }
+    // both zeros denote the same point
+    assert_eq!(generator.sample([0.0; 3]), generator.sample([-0.0; 3]));
+    assert_ne!(
+        generator.sample([1.0; 3]),
+        Source::<3>::white(43).sample([1.0; 3])
+    );
+    // the coordinates are not interchangeable
+    assert_ne!(
+        generator.sample([1.0, 2.0, 3.0]),
+        generator.sample([3.0, 2.0, 1.0])
+    );
+}
+
+#[test]
+fn test_white_uniform() {
+    const BINS: usize = 20;
+    const SIDE: usize = 200;
+    let generator = Source::<2>::white(7);
+    let mut counts = [0_usize; BINS];
+    for x in 0..SIDE {
+        for y in 0..SIDE {
+            let value = generator.sample([x as f64, y as f64]);
+            let bin = ((value + 1.0) / 2.0 * BINS as f64) as usize;
+            counts[bin.min(BINS - 1)] += 1;
+        }
+    }
+    let expected = (SIDE * SIDE) as f64 / BINS as f64;
+    let chi_squared = counts
+        .iter()
+        .map(|&count| (count as f64 - expected).powi(2) / expected)
+        .sum::<f64>();
+    // critical value of the chi-squared distribution with 19 degrees of freedom at p = 0.001
+    assert!(chi_squared < 43.82, "chi-squared statistic {chi_squared}");
+}
+
+#[test]
+fn test_white_uncorrelated() {
+    const SIDE: usize = 200;
+    let generator = Source::<2>::white(7);
+    let buf = NoiseBuffer::<2>::new([SIDE, SIDE], &generator);
+    for [dx, dy] in [[1, 0], [0, 1], [1, 1]] {
+        let (mut sum, mut count) = (0.0, 0);
+        for x in 0..SIDE - dx {
+            for y in 0..SIDE - dy {
+                sum += buf[[x, y]] * buf[[x + dx, y + dy]];
+                count += 1;
+            }
+        }
+        // the variance of a uniform distribution on [-1, 1] is 1/3
+        let correlation = sum / count as f64 * 3.0;
+        assert!(
+            correlation.abs() < 0.02,
+            "correlation {correlation} at offset {:?}",
+            [dx, dy]
+        );
+    }
+}
+
+// =================================================================
+// test source: open_simplex2
+// =================================================================
+fn assert_continuous_along_lines<G: Generator<3>>(generator: G) {
+    let lipschitz = 20.0;
+    let step = 1e-4;
+    for line in 0..8 {
+        let origin = [line as f64 * 0.731 - 3.0, line as f64 * -0.419, 1.3];
+        let direction = [0.48, 0.6, 0.64];
+        let point = |t: f64| core::array::from_fn(|i| origin[i] + t * direction[i]);
+        let mut previous = generator.sample(point(0.0));
+        for i in 1..50000 {
+            let value = generator.sample(point(i as f64 * step));
+            assert!(
+                (value - previous).abs() <= lipschitz * step,
+                "discontinuity at {:?}",
+                point(i as f64 * step)
+            );
+            previous = value;
+        }
+    }
+}
+
+// the largest second difference along a line, which approximates the largest curvature
+fn max_curvature<G: Generator<2>>(generator: &G) -> f64 {
+    let step = 1e-2;
+    let value = |i: i32| generator.sample([-20.0 + i as f64 * step, 0.37 + i as f64 * step * 0.3]);
+    (1..4000)
+        .map(|i| (value(i - 1) - 2.0 * value(i) + value(i + 1)).abs() / (step * step))
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn test_open_simplex2_continuity() {
+    // the contributions of the vertices vanish before the set of evaluated vertices changes, such
+    // that lines crossing many cells do not jump
+    assert_continuous_along_lines(Source::open_simplex2(42));
+    assert_continuous_along_lines(Source::open_simplex2s(42));
+}
+
+#[test]
+fn test_open_simplex2s_smoothness() {
+    for seed in 0..4 {
+        let fast = max_curvature(&Source::open_simplex2(seed));
+        let smooth = max_curvature(&Source::open_simplex2s(seed));
+        assert!(
+            smooth < 0.75 * fast,
+            "curvature {smooth} of the smooth variant not below {fast} of the fast variant"
+        );
+    }
+}
+
+// =================================================================
+// test source: blue
+// =================================================================
+#[test]
+fn test_blue_tile_values() {
+    let resolution = 16;
+    let buf = NoiseBuffer::<2>::new([resolution; 2], &Source::blue(42, resolution));
+    // every rank occurs exactly once, such that the values are uniformly distributed
+    let count = resolution * resolution;
+    let mut ranks = buf
+        .buffer
+        .iter()
+        .map(|value| (value + 1.0) / 2.0 * count as f64 - 0.5)
+        .collect::<Vec<_>>();
+    ranks.sort_by(f64::total_cmp);
+    for (expected, rank) in ranks.into_iter().enumerate() {
+        assert_eq!(rank, expected as f64);
+    }
+    let other = NoiseBuffer::<2>::new([resolution; 2], &Source::blue(42, resolution));
+    assert_eq!(buf.buffer, other.buffer);
+    let other = NoiseBuffer::<2>::new([resolution; 2], &Source::blue(43, resolution));
+    assert_ne!(buf.buffer, other.buffer);
+    assert_eq!(Source::blue(7, 1).sample([3.5, -2.0]), 0.0);
+}
+
+#[test]
+fn test_blue_tiling() {
+    let resolution = 24;
+    let nearest = Source::blue(42, resolution);
+    let interpolated = Blue::new(42, resolution).with_interpolation();
+    let period = resolution as f64;
+    for point in [[0.0, 0.0], [3.25, 17.5], [23.75, 23.5], [11.0, 0.125]] {
+        for [kx, ky] in [[1.0, 0.0], [0.0, -1.0], [3.0, 2.0], [-5.0, 7.0]] {
+            let shifted = [point[0] + kx * period, point[1] + ky * period];
+            assert_eq!(nearest.sample(point), nearest.sample(shifted));
+            assert_eq!(interpolated.sample(point), interpolated.sample(shifted));
+        }
+    }
+    // the interpolated noise passes through the values of the tile, and wraps around its edges
+    assert_eq!(interpolated.sample([5.0, 9.0]), nearest.sample([5.0, 9.0]));
+    let midpoint = (nearest.sample([23.0, 4.0]) + nearest.sample([0.0, 4.0])) / 2.0;
+    assert!((interpolated.sample([23.5, 4.0]) - midpoint).abs() < 1e-15);
+    assert_eq!(nearest.sample([23.5, 4.0]), nearest.sample([23.0, 4.0]));
+}
+
+#[test]
+fn test_blue_spectrum() {
+    let resolution = 64;
+    let band_powers = |generator: &dyn Fn([f64; 2]) -> f64| {
+        let buf = NoiseBuffer::<2>::new([resolution; 2], &Source::custom(generator));
+        let spectrum = spectrum2d(&buf);
+        let band = |range: std::ops::Range<f64>| {
+            let powers = spectrum
+                .iter()
+                .filter(|(frequency, _)| range.contains(frequency))
+                .map(|(_, power)| power)
+                .collect::<Vec<_>>();
+            powers.iter().copied().sum::<f64>() / powers.len() as f64
+        };
+        (band(0.0..0.1), band(0.3..0.5))
+    };
+    let blue = Source::blue(42, resolution);
+    let white = Source::white(42);
+    let (blue_low, blue_high) = band_powers(&|point| blue.sample(point));
+    let (white_low, white_high) = band_powers(&|point| white.sample(point));
+    assert!(
+        blue_low < 0.1 * white_low,
+        "low frequency power of blue noise {blue_low} not suppressed relative to white noise \
+         {white_low}"
+    );
+    assert!(blue_low < 0.1 * blue_high);
+    assert!(white_low > 0.5 * white_high);
+}
+
+// =================================================================
+// test source: wavelet
+// =================================================================
+#[test]
+fn test_wavelet_tiling() {
+    let generator = Source::<2>::wavelet(42, 16);
+    for point in [[0.0, 0.0], [3.25, 9.5], [15.75, 0.125]] {
+        for [kx, ky] in [[1.0, 0.0], [0.0, -1.0], [-3.0, 2.0]] {
+            let shifted = [point[0] + kx * 16.0, point[1] + ky * 16.0];
+            assert_eq!(generator.sample(point), generator.sample(shifted));
+        }
+    }
+    let generator = Source::<3>::wavelet(42, 8);
+    let point = [1.5, 6.25, 7.75];
+    assert_eq!(
+        generator.sample(point),
+        generator.sample([point[0] - 8.0, point[1] + 16.0, point[2] + 8.0])
+    );
+    // the edges of a buffer spanning one period match up
+    let buf = NoiseBuffer::<2>::new([16, 16], &Source::wavelet(7, 16));
+    assert_eq!(Source::<2>::wavelet(7, 16).sample([16.0, 5.0]), buf[[0, 5]]);
+}
+
+#[test]
+fn test_wavelet_range() {
+    for seed in [1, 2, 3] {
+        let buf = NoiseBuffer::<2>::new([64, 64], &Source::wavelet(seed, 32).scale([0.7; 2]));
+        let stats = buf.stats();
+        assert!(-1.0 <= stats.min && stats.max <= 1.0, "{:?}", stats);
+        // the noise is not degenerate, and centered around zero
+        assert!(stats.std_dev > 0.05, "{:?}", stats);
+        assert!(stats.mean.abs() < 0.05, "{:?}", stats);
+        let buf = NoiseBuffer::<3>::new([24, 24, 24], &Source::wavelet(seed, 16));
+        assert!(buf.buffer.iter().all(|value| (-1.0..=1.0).contains(value)));
+    }
+    assert!(Source::<3>::wavelet(42, 8)
+        .sample([f64::NAN, 0.0, 0.0])
+        .is_nan());
+}
+
+#[test]
+fn test_wavelet_spectrum() {
+    let buf = NoiseBuffer::<2>::new([64, 64], &Source::wavelet(42, 64));
+    let spectrum = spectrum2d(&buf);
+    let total = spectrum.iter().map(|(_, power)| power).sum::<f64>();
+    let band = |range: std::ops::Range<f64>| {
+        spectrum
+            .iter()
+            .filter(|(frequency, _)| range.contains(frequency))
+            .map(|(_, power)| power)
+            .sum::<f64>()
+            / total
+    };
+    // the power lies mostly within the highest octave, and vanishes at low frequencies
+    let octave = band(0.25..0.51);
+    assert!(
+        octave > 0.85,
+        "fraction of power in the top octave {}",
+        octave
+    );
+    let low = band(0.0..0.125);
+    assert!(low < 1e-3, "fraction of power below an eighth {}", low);
+}
+
+#[test]
+#[should_panic(expected = "tile size must be a positive even number")]
+fn test_wavelet_odd_tile_size() {
+    Source::<2>::wavelet(42, 15);
+}
+
+// =================================================================
+// test source: spot
+// =================================================================
+#[test]
+fn test_spot_deterministic() {
+    let generator = Source::<3>::spot(42)
+        .with_kernel(SpotKernel::Cone)
+        .with_density(3)
+        .with_amplitude_jitter(0.5);
+    let buf = NoiseBuffer::<3>::new([12, 12, 12], &generator.scale([0.3; 3]));
+    let other = NoiseBuffer::<3>::new([12, 12, 12], &generator.scale([0.3; 3]));
+    assert_eq!(buf.buffer, other.buffer);
+    let other = NoiseBuffer::<3>::new(
+        [12, 12, 12],
+        &Source::spot(43)
+            .with_kernel(SpotKernel::Cone)
+            .with_density(3)
+            .with_amplitude_jitter(0.5)
+            .scale([0.3; 3]),
+    );
+    assert_ne!(buf.buffer, other.buffer);
+}
+
+#[test]
+fn test_spot_range() {
+    for kernel in [SpotKernel::Gaussian, SpotKernel::Cone, SpotKernel::Ring] {
+        let generator = Source::<2>::spot(42).with_kernel(kernel).scale([0.1; 2]);
+        let stats = NoiseBuffer::<2>::new([200, 200], &generator).stats();
+        // the background is untouched, and isolated spots come close to their full amplitude
+        assert_eq!(stats.min, -1.0);
+        assert!(stats.max <= 1.0 && stats.max > 0.8, "{:?}", stats);
+    }
+    let generator = Source::<2>::spot(42).with_radius(3.0).with_density(4);
+    let stats = NoiseBuffer::<2>::new([50, 50], &generator.scale([0.3; 2])).stats();
+    assert_eq!(stats.max, 1.0);
+}
+
+#[test]
+fn test_spot_continuity() {
+    // the kernels are continuous with a bounded slope, such that the noise changes only by a
+    // small amount between closely spaced points, unless kernels reaching a point are missed
+    // when scanning the cells around it
+    const STEP: f64 = 1e-4;
+    for kernel in [SpotKernel::Gaussian, SpotKernel::Cone, SpotKernel::Ring] {
+        for radius in [0.3, 1.0, 1.7, 2.5] {
+            let generator = Source::<2>::spot(7)
+                .with_kernel(kernel)
+                .with_radius(radius)
+                .with_density(3)
+                .with_amplitude_jitter(0.3);
+            for y in [0.1, 0.45, 2.3] {
+                let mut previous = generator.sample([-2.0, y]);
+                for i in 1..=(4.0 / STEP) as usize {
+                    let x = -2.0 + i as f64 * STEP;
+                    let value = generator.sample([x, y]);
+                    assert!(
+                        (value - previous).abs() < 1e-2,
+                        "{kernel:?} kernel with radius {radius} jumps from {previous} to {value} \
+                         at x = {x}, y = {y}"
+                    );
+                    previous = value;
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "radius must be positive and finite")]
+fn test_spot_invalid_radius() {
+    Source::<2>::spot(42).with_radius(0.0);
+}
+
+// =================================================================
+// test source: distance field
+// =================================================================
+fn random_points<const D: usize>(seed: u64, count: usize, extent: f64) -> Vec<[f64; D]> {
+    let mut state = seed;
+    let mut random = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 11) as f64 / (1_u64 << 53) as f64 * extent
+    };
+    (0..count)
+        .map(|_| std::array::from_fn(|_| random()))
+        .collect()
+}
+
+fn brute_force_distance<const D: usize>(points: &[[f64; D]], point: [f64; D]) -> f64 {
+    let mut nearest = f64::INFINITY;
+    for other in points {
+        let distance = (0..D).map(|i| (point[i] - other[i]).powi(2)).sum::<f64>();
+        if distance < nearest {
+            nearest = distance;
+        }
+    }
+    nearest.sqrt()
+}
+
+fn assert_matches_brute_force<const D: usize>(count: usize) {
+    let points = random_points::<D>(count as u64, count, 100.0);
+    let max_distance = 30.0;
+    let generator = Source::distance_field(points.clone())
+        .unwrap()
+        .with_max_distance(max_distance);
+    // queries partly lie outside of the bounding box of the points
+    for query in random_points::<D>(7, 2000, 140.0) {
+        let query = query.map(|x| x - 20.0);
+        let expected = (brute_force_distance(&points, query) / max_distance).min(1.0) * 2.0 - 1.0;
+        let value = generator.sample(query);
+        assert!(
+            (value - expected).abs() < 1e-12,
+            "{value} differs from {expected} at {query:?} for {count} points"
+        );
+    }
+}
+
+#[test]
+fn test_distance_field_brute_force() {
+    for count in [1, 2, 3, 10, 257] {
+        assert_matches_brute_force::<1>(count);
+        assert_matches_brute_force::<2>(count);
+        assert_matches_brute_force::<3>(count);
+        assert_matches_brute_force::<4>(count);
+    }
+    // duplicate points and points sharing coordinates
+    let points = vec![[1.0, 1.0], [1.0, 1.0], [1.0, 3.0], [2.0, 1.0], [1.0, -4.0]];
+    let generator = Source::distance_field(points.clone()).unwrap();
+    for query in random_points::<2>(3, 500, 8.0) {
+        let query = query.map(|x| x - 4.0);
+        let expected = brute_force_distance(&points, query).min(1.0) * 2.0 - 1.0;
+        assert_eq!(generator.sample(query), expected);
+    }
+}
+
+#[test]
+fn test_distance_field_normalization() {
+    let generator = Source::distance_field(vec![[0.0, 0.0], [10.0, 0.0]])
+        .unwrap()
+        .with_max_distance(4.0);
+    assert_eq!(generator.sample([10.0, 0.0]), -1.0);
+    assert_eq!(generator.sample([0.0, 2.0]), 0.0);
+    assert_eq!(generator.sample([9.0, 0.0]), -0.5);
+    assert_eq!(generator.sample([5.0, 0.0]), 1.0);
+    assert_eq!(generator.sample([-100.0, 50.0]), 1.0);
+}
+
+#[test]
+fn test_distance_field_invalid_points() {
+    assert_eq!(
+        Source::<2>::distance_field(Vec::new()).unwrap_err(),
+        Error::EmptyPointSet
+    );
+    let error = Source::distance_field(vec![[0.0, 1.0], [2.0, f64::INFINITY]]).unwrap_err();
+    assert_eq!(
+        error,
+        Error::NonFinitePoint {
+            index: 1,
+            point: vec![2.0, f64::INFINITY],
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "point [2.0, inf] at index 1 is not finite"
+    );
+}
+
+#[test]
+fn test_distance_field_scaling() {
+    // the number of nodes visited per query grows logarithmically with the number of points, and
+    // is far below that of visiting every point. counting nodes rather than timing the queries
+    // keeps the test independent of the machine
+    let queries = random_points::<2>(7, 2000, 100.0);
+    let visits = |points: &[[f64; 2]]| {
+        let generator = Source::distance_field(points.to_vec()).unwrap();
+        queries
+            .iter()
+            .map(|&query| devtools::counters::distance_field_visits(&generator, query))
+            .sum::<usize>()
+    };
+    let few = random_points::<2>(1, 1000, 100.0);
+    let many = random_points::<2>(2, 100_000, 100.0);
+    let (visits_few, visits_many) = (visits(&few), visits(&many));
+    assert!(
+        visits_many < visits_few * 3,
+        "{visits_many} visits for 100000 points, {visits_few} visits for 1000 points"
+    );
+    let visits_naive = queries.len() * many.len();
+    assert!(
+        visits_many * 20 < visits_naive,
+        "{visits_many} visits using the generator, {visits_naive} visiting every point"
+    );
+}
+
+#[test]
+#[should_panic(expected = "max distance must be positive and finite")]
+fn test_distance_field_invalid_max_distance() {
+    Source::distance_field(vec![[0.0, 1.0]])
+        .unwrap()
+        .with_max_distance(-1.0);
+}
+
+// =================================================================
+// test sources: linear and radial gradients
+// =================================================================
+#[test]
+fn test_linear_gradient() {
+    let generator = Source::linear_gradient([1.0, 2.0], [5.0, 2.0]).unwrap();
+    assert_eq!(generator.sample([1.0, 2.0]), -1.0);
+    assert_eq!(generator.sample([5.0, 2.0]), 1.0);
+    assert_eq!(generator.sample([3.0, 2.0]), 0.0);
+    assert_eq!(generator.sample([2.0, 2.0]), -0.5);
+    // points are projected onto the line through both endpoints
+    assert_eq!(generator.sample([3.0, -40.0]), 0.0);
+    // and clamped beyond the endpoints
+    assert_eq!(generator.sample([-7.0, 2.0]), -1.0);
+    assert_eq!(generator.sample([9.0, 6.0]), 1.0);
+    // along a diagonal direction
+    let generator = Source::linear_gradient([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]).unwrap();
+    assert_eq!(generator.sample([1.0, 1.0, 1.0]), 0.0);
+    assert_eq!(generator.sample([3.0, 0.0, 0.0]), 0.0);
+    assert_eq!(generator.sample([0.5, 0.5, 0.5]), -0.5);
+}
+
+#[test]
+fn test_radial_gradient() {
+    let generator = Source::radial_gradient([1.0, -1.0], 2.0, 6.0).unwrap();
+    assert_eq!(generator.sample([1.0, -1.0]), 1.0);
+    assert_eq!(generator.sample([2.0, -1.0]), 1.0);
+    assert_eq!(generator.sample([1.0, 1.0]), 1.0);
+    assert_eq!(generator.sample([1.0, 3.0]), 0.0);
+    assert_eq!(generator.sample([-2.0, -1.0]), 0.5);
+    assert_eq!(generator.sample([1.0, -7.0]), -1.0);
+    assert_eq!(generator.sample([4.0, 3.0]), -0.5);
+    assert_eq!(generator.sample([100.0, 100.0]), -1.0);
+    // an inner radius of zero peaks only at the center
+    let generator = Source::radial_gradient([0.0; 3], 0.0, 4.0).unwrap();
+    assert_eq!(generator.sample([0.0; 3]), 1.0);
+    assert_eq!(generator.sample([0.0, 0.0, 2.0]), 0.0);
+}
+
+#[test]
+fn test_gradient_masks() {
+    // a radial gradient selects between two generators by the distance to its center
+    let mask = Source::radial_gradient([0.0, 0.0], 1.0, 3.0).unwrap();
+    let generator = Source::constant(-0.5).blend(Source::constant(0.5), mask);
+    assert_eq!(generator.sample([0.5, 0.0]), 0.5);
+    assert_eq!(generator.sample([2.0, 0.0]), 0.0);
+    assert_eq!(generator.sample([0.0, 10.0]), -0.5);
+    let mask = Source::linear_gradient([0.0, 0.0], [0.0, 4.0]).unwrap();
+    let generator = Source::constant(-0.5).select(Source::constant(0.5), mask, 0.0, 1.0);
+    assert_eq!(generator.sample([7.0, 1.0]), 0.5);
+    assert_eq!(generator.sample([7.0, 3.0]), -0.5);
+}
+
+#[test]
+fn test_gradient_invalid_parameters() {
+    let error = Source::linear_gradient([1.0, 2.0], [1.0, 2.0]).unwrap_err();
+    assert!(matches!(error, Error::InvalidParameter { name: "to", .. }));
+    assert_eq!(
+        error.to_string(),
+        "invalid parameter to: must differ from from, got from [1.0, 2.0] and to [1.0, 2.0]"
+    );
+    assert!(matches!(
+        Source::linear_gradient([f64::NAN, 2.0], [1.0, 2.0]),
+        Err(Error::InvalidParameter { name: "from", .. })
+    ));
+    assert!(matches!(
+        Source::linear_gradient([-1e200], [1e200]),
+        Err(Error::InvalidParameter { name: "to", .. })
+    ));
+    assert!(matches!(
+        Source::radial_gradient([0.0, 0.0], 3.0, 3.0),
+        Err(Error::InvalidParameter {
+            name: "outer_radius",
+            ..
+        })
+    ));
+    assert!(matches!(
+        Source::radial_gradient([0.0, 0.0], 4.0, 3.0),
+        Err(Error::InvalidParameter {
+            name: "outer_radius",
+            ..
+        })
+    ));
+    assert!(matches!(
+        Source::radial_gradient([0.0, 0.0], -1.0, 3.0),
+        Err(Error::InvalidParameter {
+            name: "inner_radius",
+            ..
+        })
+    ));
+    assert!(matches!(
+        Source::radial_gradient([0.0, f64::INFINITY], 0.0, 3.0),
+        Err(Error::InvalidParameter { name: "center", .. })
+    ));
+}
+
+// =================================================================
+// test sources: round and rectangular islands
+// =================================================================
+const FALLOFF_CURVES: [FalloffCurve; 4] = [
+    FalloffCurve::Linear,
+    FalloffCurve::Smoothstep,
+    FalloffCurve::SquareBump,
+    FalloffCurve::Exponent(2.0),
+];
+
+#[test]
+fn test_island() {
+    for falloff in FALLOFF_CURVES {
+        let generator = Source::island([2.0, -1.0], 4.0, falloff).unwrap();
+        assert_eq!(generator.sample([2.0, -1.0]), 1.0, "{falloff:?}");
+        assert_eq!(generator.sample([6.0, -1.0]), -1.0, "{falloff:?}");
+        assert_eq!(generator.sample([2.0, -5.0]), -1.0, "{falloff:?}");
+        assert_eq!(generator.sample([9.0, 4.0]), -1.0, "{falloff:?}");
+        assert_eq!(generator.sample([-200.0, 0.0]), -1.0, "{falloff:?}");
+    }
+    let value_at = |falloff, point| {
+        Source::island([2.0, -1.0], 4.0, falloff)
+            .unwrap()
+            .sample(point)
+    };
+    assert_eq!(value_at(FalloffCurve::Linear, [4.0, -1.0]), 0.0);
+    assert_eq!(value_at(FalloffCurve::Linear, [2.0, -4.0]), -0.5);
+    assert_eq!(value_at(FalloffCurve::Smoothstep, [4.0, -1.0]), 0.0);
+    assert_eq!(value_at(FalloffCurve::Smoothstep, [3.0, -1.0]), 0.6875);
+    assert_eq!(value_at(FalloffCurve::Exponent(2.0), [4.0, -1.0]), 0.5);
+    assert_eq!(value_at(FalloffCurve::Exponent(0.5), [3.0, -1.0]), 0.0);
+    assert_eq!(value_at(FalloffCurve::SquareBump, [4.0, -1.0]), 0.5);
+    assert_eq!(value_at(FalloffCurve::SquareBump, [4.0, 1.0]), 0.125);
+    // the square bump is a square, and thus keeps land at the corners beyond the radius
+    assert!(value_at(FalloffCurve::SquareBump, [5.0, 2.0]) > -1.0);
+    assert_eq!(value_at(FalloffCurve::Linear, [5.0, 2.0]), -1.0);
+}
+
+#[test]
+fn test_island_rect() {
+    for falloff in FALLOFF_CURVES {
+        let generator = Source::island_rect([0.0, 0.0], [8.0, 4.0], falloff).unwrap();
+        assert_eq!(generator.sample([4.0, 2.0]), 1.0, "{falloff:?}");
+        assert_eq!(generator.sample([8.0, 1.0]), -1.0, "{falloff:?}");
+        assert_eq!(generator.sample([3.0, 0.0]), -1.0, "{falloff:?}");
+        assert_eq!(generator.sample([-1.0, 2.0]), -1.0, "{falloff:?}");
+        assert_eq!(generator.sample([10.0, 10.0]), -1.0, "{falloff:?}");
+    }
+    let value_at = |falloff, point| {
+        Source::island_rect([0.0, 0.0], [8.0, 4.0], falloff)
+            .unwrap()
+            .sample(point)
+    };
+    // the offsets are relative to the half-extent along each axis, and the largest one is used
+    assert_eq!(value_at(FalloffCurve::Linear, [6.0, 2.0]), 0.0);
+    assert_eq!(value_at(FalloffCurve::Linear, [4.0, 3.0]), 0.0);
+    assert_eq!(value_at(FalloffCurve::Linear, [6.0, 3.0]), 0.0);
+    assert_eq!(value_at(FalloffCurve::Linear, [5.0, 3.0]), 0.0);
+    assert_eq!(value_at(FalloffCurve::SquareBump, [6.0, 3.0]), 0.125);
+}
+
+#[test]
+fn test_island_terrain() {
+    // taking the minimum with terrain sinks it into the sea beyond the radius, and leaves the
+    // interior untouched where the terrain lies below the mask
+    let mask = Source::island([0.0, 0.0], 100.0, FalloffCurve::Exponent(4.0)).unwrap();
+    let terrain = Source::simplex(42).scale([0.05; 2]).mul(0.5);
+    let generator = mask.min(terrain.clone());
+    for point in [[0.0, 0.0], [10.0, -20.0], [-30.0, 35.0]] {
+        assert_eq!(generator.sample(point), terrain.sample(point));
+    }
+    for point in [[100.0, 0.0], [80.0, -90.0], [-500.0, 1.0]] {
+        assert_eq!(generator.sample(point), -1.0);
+    }
+}
+
+#[test]
+fn test_island_invalid_parameters() {
+    let error = Source::island([0.0, 0.0], 0.0, FalloffCurve::Linear).unwrap_err();
+    assert!(matches!(
+        error,
+        Error::InvalidParameter { name: "radius", .. }
+    ));
+    assert_eq!(
+        error.to_string(),
+        "invalid parameter radius: must be positive and finite, got center [0.0, 0.0], radius 0 \
+         and falloff Linear"
+    );
+    assert!(matches!(
+        Source::island([f64::NAN, 0.0], 1.0, FalloffCurve::Linear),
+        Err(Error::InvalidParameter { name: "center", .. })
+    ));
+    for exponent in [0.0, -1.0, f64::INFINITY, f64::NAN] {
+        assert!(matches!(
+            Source::island([0.0, 0.0], 1.0, FalloffCurve::Exponent(exponent)),
+            Err(Error::InvalidParameter {
+                name: "falloff",
+                ..
+            })
+        ));
+    }
+    assert!(matches!(
+        Source::island_rect([0.0, 0.0], [1.0, 0.0], FalloffCurve::Linear),
+        Err(Error::InvalidParameter { name: "max", .. })
+    ));
+    assert!(matches!(
+        Source::island_rect([0.0, -1e308], [1.0, 1e308], FalloffCurve::Linear),
+        Err(Error::InvalidParameter { name: "max", .. })
+    ));
+    assert!(matches!(
+        Source::island_rect([0.0, f64::NEG_INFINITY], [1.0, 1.0], FalloffCurve::Linear),
+        Err(Error::InvalidParameter { name: "min", .. })
+    ));
+    assert!(matches!(
+        Source::island_rect([0.0, 0.0], [1.0, 1.0], FalloffCurve::Exponent(0.0)),
+        Err(Error::InvalidParameter {
+            name: "falloff",
+            ..
+        })
+    ));
+}
+
+// =================================================================
+// test source: image
+// =================================================================
+fn gray_image(width: u32, height: u32, pixels: Vec<u8>) -> image::DynamicImage {
+    image::DynamicImage::ImageLuma8(image::GrayImage::from_raw(width, height, pixels).unwrap())
+}
+
+#[test]
+fn test_image_texel_centers() {
+    let pixels = vec![0, 51, 102, 153, 204, 255];
+    let image = gray_image(3, 2, pixels.clone());
+    for interpolation in [
+        Interpolation::Nearest,
+        Interpolation::Bilinear,
+        Interpolation::Bicubic,
+    ] {
+        let generator = Source::image(&image).with_interpolation(interpolation);
+        for (index, pixel) in pixels.iter().enumerate() {
+            let (row, column) = ((index / 3) as f64, (index % 3) as f64);
+            let expected = *pixel as f64 / 255.0 * 2.0 - 1.0;
+            let value = generator.sample([row + 0.5, column + 0.5]);
+            assert!(
+                (value - expected).abs() < 1e-12,
+                "{value} differs from {expected} at row {row}, column {column} with \
+                 {interpolation:?} interpolation"
+            );
+        }
+    }
+    // 16-bit images keep their full precision
+    let pixels = vec![0, 1, 32768, u16::MAX];
+    let image = image::DynamicImage::ImageLuma16(
+        image::ImageBuffer::from_raw(2, 2, pixels.clone()).unwrap(),
+    );
+    let generator = Source::image(&image);
+    assert_eq!(generator.sample([0.5, 0.5]), -1.0);
+    assert_eq!(generator.sample([0.5, 1.5]), 2.0 / 65535.0 - 1.0);
+    assert_eq!(generator.sample([1.5, 0.5]), 65536.0 / 65535.0 - 1.0);
+    assert_eq!(generator.sample([1.5, 1.5]), 1.0);
+}
+
+#[test]
+fn test_image_interpolation() {
+    let image = gray_image(2, 2, vec![0, 255, 255, 255]);
+    let generator = Source::image(&image);
+    // the midpoints between two pixels, and the midpoint between all four
+    assert_eq!(generator.sample([0.5, 1.0]), 0.0);
+    assert_eq!(generator.sample([1.0, 0.5]), 0.0);
+    assert_eq!(generator.sample([1.5, 1.0]), 1.0);
+    assert_eq!(generator.sample([1.0, 1.0]), 0.5);
+    assert_eq!(generator.sample([0.5, 0.75]), -0.5);
+    let generator = Source::image(&image).with_interpolation(Interpolation::Nearest);
+    assert_eq!(generator.sample([0.9, 0.9]), -1.0);
+    assert_eq!(generator.sample([0.9, 1.1]), 1.0);
+    // bicubic interpolation does not overshoot the range of the values
+    let image = gray_image(4, 1, vec![0, 0, 255, 255]);
+    let generator = Source::image(&image).with_interpolation(Interpolation::Bicubic);
+    for i in 0..=40 {
+        let value = generator.sample([0.5, i as f64 * 0.1]);
+        assert!((-1.0..=1.0).contains(&value), "{value}");
+    }
+}
+
+#[test]
+fn test_image_bounds_and_boundaries() {
+    let image = gray_image(2, 2, vec![0, 255, 255, 0]);
+    let generator = Source::image(&image).with_bounds([-10.0, 100.0], [10.0, 140.0]);
+    assert_eq!(generator.sample([-5.0, 110.0]), -1.0);
+    assert_eq!(generator.sample([-5.0, 130.0]), 1.0);
+    assert_eq!(generator.sample([5.0, 120.0]), 0.0);
+    // beyond the bounds
+    let generator = generator.with_interpolation(Interpolation::Nearest);
+    assert_eq!(generator.sample([-50.0, 300.0]), 1.0);
+    let wrapped = generator.clone().with_boundary(ImageBoundary::Wrap);
+    assert_eq!(
+        wrapped.sample([-45.0, 290.0]),
+        generator.sample([-5.0, 130.0])
+    );
+    assert_eq!(
+        wrapped.sample([15.0, 50.0]),
+        generator.sample([-5.0, 130.0])
+    );
+    let constant = generator.with_boundary(ImageBoundary::Constant(0.25));
+    assert_eq!(constant.sample([-50.0, 300.0]), 0.25);
+    assert_eq!(constant.sample([0.0, 99.0]), 0.25);
+    assert_eq!(constant.sample([-5.0, 110.0]), -1.0);
+    // the values of black and white
+    let generator = Source::image(&image)
+        .with_interpolation(Interpolation::Nearest)
+        .with_value_range(0.5, -0.5);
+    assert_eq!(generator.sample([0.5, 0.5]), 0.5);
+    assert_eq!(generator.sample([0.5, 1.5]), -0.5);
+    assert_eq!(generator.sample_interval([0.0; 2], [2.0; 2]), (-0.5, 0.5));
+}
+
+#[test]
+fn test_image_file() {
+    // an image written by the visualizer is read back with the same orientation
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let path = tmp_dir.path().join("mask.png");
+    let path = path.to_str().unwrap();
+    let visualizer = Visualizer::<2>::new([30, 20], &Source::simplex(42).scale([0.1; 2]));
+    visualizer.write_to_file(path).unwrap();
+    let generator = Source::image_file(path)
+        .unwrap()
+        .with_interpolation(Interpolation::Nearest);
+    let image = visualizer.to_image();
+    for i in 0..30 {
+        for j in 0..20 {
+            let expected = image.get_pixel(j, i).0[0] as f64 / 255.0 * 2.0 - 1.0;
+            assert_eq!(generator.sample([i as f64, j as f64]), expected);
+        }
+    }
+    assert!(Source::image_file(tmp_dir.path().join("missing.png").to_str().unwrap()).is_err());
+}
+
+#[test]
+fn test_image_adapters() {
+    let image = gray_image(2, 1, vec![0, 255]);
+    let mask = Source::image(&image).with_interpolation(Interpolation::Nearest);
+    let generator = Source::constant(-0.5).blend(Source::constant(0.5), mask.clone());
+    assert_eq!(generator.sample([0.5, 0.5]), -0.5);
+    assert_eq!(generator.sample([0.5, 1.5]), 0.5);
+    let generator = Source::constant(-0.5).select(Source::constant(0.5), mask.clone(), 0.0, 1.0);
+    assert_eq!(generator.sample([0.5, 0.5]), 0.5);
+    assert_eq!(generator.sample([0.5, 1.5]), -0.5);
+    let generator = mask.displace_y(Source::constant(1.0));
+    assert_eq!(generator.sample([0.5, 0.5]), 1.0);
+}
+
+#[test]
+#[should_panic(expected = "image must not be empty")]
+fn test_image_empty() {
+    Source::image(&gray_image(0, 3, vec![]));
+}
+
+// =================================================================
+// test source: buffer
+// =================================================================
+#[test]
+fn test_buffer_cell_centers() {
+    let buf = NoiseBuffer::<2>::new([7, 5], &Source::simplex(42).scale([0.3; 2]));
+    for interpolation in [
+        Interpolation::Nearest,
+        Interpolation::Bilinear,
+        Interpolation::Bicubic,
+    ] {
+        let generator = Source::from_buffer(buf.clone(), [-3.0, 10.0], [0.5, 2.0])
+            .unwrap()
+            .with_interpolation(interpolation);
+        for i in 0..7 {
+            for j in 0..5 {
+                let point = [-3.0 + i as f64 * 0.5, 10.0 + j as f64 * 2.0];
+                assert_eq!(generator.sample(point), buf[[i, j]], "{interpolation:?}");
+            }
+        }
+    }
+    // the inverse of filling a buffer
+    let generator = Source::simplex(42).scale([0.2; 3]);
+    let buf = NoiseBuffer::<3>::new([6, 5, 4], &generator);
+    let source = Source::from_buffer(buf, [0.0; 3], [1.0; 3]).unwrap();
+    assert_eq!(
+        source.sample([4.0, 2.0, 3.0]),
+        generator.sample([4.0, 2.0, 3.0])
+    );
+}
+
+#[test]
+fn test_buffer_interpolation() {
+    let buf = NoiseBuffer::<2>::new([2, 3], &Source::custom(|[x, y]| x * 4.0 + y * y));
+    let generator = Source::from_buffer(buf, [0.0, 0.0], [1.0, 1.0]).unwrap();
+    // the values are 0, 1, 4 in the first row and 4, 5, 8 in the second
+    assert_eq!(generator.sample([0.5, 0.0]), 2.0);
+    assert_eq!(generator.sample([0.0, 0.5]), 0.5);
+    assert_eq!(generator.sample([0.0, 1.5]), 2.5);
+    assert_eq!(generator.sample([0.5, 0.5]), 2.5);
+    assert_eq!(generator.sample([0.5, 1.5]), 4.5);
+    assert_eq!(generator.sample([0.25, 0.0]), 1.0);
+    let generator = generator.with_interpolation(Interpolation::Nearest);
+    assert_eq!(generator.sample([0.4, 1.6]), 4.0);
+    assert_eq!(generator.sample([0.6, 1.4]), 5.0);
+    // trilinear interpolation at the center of a cube of cells
+    let buf = NoiseBuffer::<3>::new(
+        [2, 2, 2],
+        &Source::custom(|[x, y, z]| x + 2.0 * y + 4.0 * z),
+    );
+    let generator = Source::from_buffer(buf, [0.0; 3], [1.0; 3]).unwrap();
+    assert_eq!(generator.sample([0.5, 0.5, 0.5]), 3.5);
+    assert_eq!(generator.sample([1.0, 0.5, 0.0]), 2.0);
+    // bicubic interpolation does not overshoot the range of the values
+    let buf = NoiseBuffer::<1>::new([6], &Source::custom(|[x]| if x < 3.0 { -1.0 } else { 1.0 }));
+    let generator = Source::from_buffer(buf, [0.0], [1.0])
+        .unwrap()
+        .with_interpolation(Interpolation::Bicubic);
+    for i in 0..=50 {
+        let value = generator.sample([i as f64 * 0.1]);
+        assert!((-1.0..=1.0).contains(&value), "{value}");
+    }
+}
+
+#[test]
+fn test_buffer_boundaries() {
+    let buf = NoiseBuffer::<1>::new([4], &Source::custom(|[x]| x));
+    let generator = Source::from_buffer(buf, [10.0], [1.0]).unwrap();
+    assert_eq!(generator.sample([5.0]), 0.0);
+    assert_eq!(generator.sample([20.0]), 3.0);
+    let generator = generator.with_boundary(Boundary::Wrap);
+    assert_eq!(generator.sample([14.0]), 0.0);
+    assert_eq!(generator.sample([9.0]), 3.0);
+    assert_eq!(generator.sample([13.5]), 1.5);
+    let generator = generator.with_boundary(Boundary::Mirror);
+    assert_eq!(generator.sample([9.0]), 1.0);
+    assert_eq!(generator.sample([14.0]), 2.0);
+    // wrapped buffers tile seamlessly, also with bicubic interpolation
+    let buf = NoiseBuffer::<2>::new([16, 12], &Source::simplex(42).scale([0.2; 2]));
+    for interpolation in [Interpolation::Bilinear, Interpolation::Bicubic] {
+        let generator = Source::from_buffer(buf.clone(), [3.0, -2.0], [0.5, 0.25])
+            .unwrap()
+            .with_interpolation(interpolation)
+            .with_boundary(Boundary::Wrap);
+        assert_tileable(&generator, [8.0, 3.0], 64, 1e-12);
+    }
+    let buf = NoiseBuffer::<3>::new([6, 5, 4], &Source::simplex(42).scale([0.3; 3]));
+    let generator = Source::from_buffer(buf, [0.0; 3], [1.0; 3])
+        .unwrap()
+        .with_boundary(Boundary::Wrap);
+    assert_tileable_3d(&generator, [6.0, 5.0, 4.0], 16, 1e-12);
+}
+
+#[test]
+fn test_buffer_shared() {
+    let buf = std::sync::Arc::new(NoiseBuffer::<2>::new([8, 8], &Source::simplex(42)));
+    let generator = Source::from_buffer(buf.clone(), [0.0; 2], [1.0; 2]).unwrap();
+    let scaled = Source::from_buffer(buf.clone(), [0.0; 2], [2.0; 2]).unwrap();
+    let clones = vec![generator.clone(); 4];
+    assert_eq!(std::sync::Arc::strong_count(&buf), 7);
+    assert_eq!(clones[3].sample([3.0, 5.0]), scaled.sample([6.0, 10.0]));
+}
+
+#[test]
+fn test_buffer_invalid_parameters() {
+    let error = Source::from_buffer(
+        NoiseBuffer::<2>::new([0, 4], &Source::simplex(42)),
+        [0.0; 2],
+        [1.0; 2],
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid parameter buffer: must not be empty, got shape [0, 4]"
+    );
+    let buf = NoiseBuffer::<2>::new([4, 4], &Source::simplex(42));
+    assert!(matches!(
+        Source::from_buffer(buf.clone(), [f64::NAN, 0.0], [1.0; 2]),
+        Err(Error::InvalidParameter { name: "origin", .. })
+    ));
+    for cell_size in [[0.0, 1.0], [1.0, -1.0], [f64::INFINITY, 1.0]] {
+        assert!(matches!(
+            Source::from_buffer(buf.clone(), [0.0; 2], cell_size),
+            Err(Error::InvalidParameter {
+                name: "cell_size",
+                ..
+            })
+        ));
+    }
+}
+
+// =================================================================
+// test raw noise functions
+// =================================================================
+const RAW_SEEDS: [u64; 3] = [0, 42, u64::MAX];
+
+// points covering both signs, lattice points, fractional offsets, and large coordinates
+fn raw_points<const D: usize>() -> Vec<[f64; D]> {
+    let coordinates = [-1234.567, -3.0, -0.25, 0.0, 0.5, 1.0, 7.89, 98765.4321];
+    (0..coordinates.len())
+        .map(|i| core::array::from_fn(|axis| coordinates[(i + 3 * axis) % coordinates.len()]))
+        .collect()
+}
+
+#[test]
+fn test_raw_matches_sources() {
+    for seed in RAW_SEEDS {
+        let table = raw::build_permutation_table(seed);
+        assert_eq!(table.seed(), seed);
+        for [x] in raw_points() {
+            let point = [x];
+            assert_eq!(
+                raw::simplex1d(seed, x).to_bits(),
+                Source::simplex(seed).sample(point).to_bits()
+            );
+            assert_eq!(
+                raw::perlin1d_with_table(&table, x),
+                Source::perlin(seed).sample(point)
+            );
+            assert_eq!(
+                raw::value1d_with_table(&table, x),
+                Source::value(seed).sample(point)
+            );
+            assert_eq!(
+                raw::worley1d_with_table(&table, x),
+                Source::worley(seed).sample(point)
+            );
+            assert_eq!(
+                raw::simplex1d_with_table(&table, x),
+                raw::simplex1d(seed, x)
+            );
+        }
+        for [x, y] in raw_points() {
+            let point = [x, y];
+            assert_eq!(
+                raw::simplex2d(seed, x, y).to_bits(),
+                Source::simplex(seed).sample(point).to_bits()
+            );
+            assert_eq!(
+                raw::perlin2d_with_table(&table, x, y),
+                Source::perlin(seed).sample(point)
+            );
+            assert_eq!(
+                raw::value2d_with_table(&table, x, y),
+                Source::value(seed).sample(point)
+            );
+            assert_eq!(
+                raw::worley2d_with_table(&table, x, y),
+                Source::worley(seed).sample(point)
+            );
+            assert_eq!(
+                raw::perlin2d(seed, x, y),
+                raw::perlin2d_with_table(&table, x, y)
+            );
+        }
+        for [x, y, z] in raw_points() {
+            let point = [x, y, z];
+            assert_eq!(
+                raw::simplex3d(seed, x, y, z).to_bits(),
+                Source::simplex(seed).sample(point).to_bits()
+            );
+            assert_eq!(
+                raw::perlin3d_with_table(&table, x, y, z),
+                Source::perlin(seed).sample(point)
+            );
+            assert_eq!(
+                raw::value3d_with_table(&table, x, y, z),
+                Source::value(seed).sample(point)
+            );
+            assert_eq!(
+                raw::worley3d_with_table(&table, x, y, z),
+                Source::worley(seed).sample(point)
+            );
+            assert_eq!(
+                raw::value3d(seed, x, y, z),
+                raw::value3d_with_table(&table, x, y, z)
+            );
+        }
+        for [x, y, z, w] in raw_points() {
+            let point = [x, y, z, w];
+            assert_eq!(
+                raw::simplex4d(seed, x, y, z, w).to_bits(),
+                Source::simplex(seed).sample(point).to_bits()
+            );
+            assert_eq!(
+                raw::perlin4d_with_table(&table, x, y, z, w),
+                Source::perlin(seed).sample(point)
+            );
+            assert_eq!(
+                raw::value4d_with_table(&table, x, y, z, w),
+                Source::value(seed).sample(point)
+            );
+            assert_eq!(
+                raw::worley4d_with_table(&table, x, y, z, w),
+                Source::worley(seed).sample(point)
+            );
+            assert_eq!(
+                raw::worley4d(seed, x, y, z, w),
+                raw::worley4d_with_table(&table, x, y, z, w)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_raw_simplex_generic() {
+    fn assert_matches<const D: usize>(seed: u64) {
+        let table = raw::build_permutation_table(seed);
+        for point in raw_points::<D>() {
+            let expected = Source::simplex(seed).sample(point);
+            assert_eq!(raw::simplex(seed, point).to_bits(), expected.to_bits());
+            assert_eq!(
+                raw::simplex_with_table(&table, point).to_bits(),
+                expected.to_bits()
+            );
+        }
+    }
+    for seed in RAW_SEEDS {
+        assert_matches::<1>(seed);
+        assert_matches::<2>(seed);
+        assert_matches::<3>(seed);
+        assert_matches::<4>(seed);
+        assert_matches::<5>(seed);
+        assert_matches::<6>(seed);
+        assert_matches::<8>(seed);
+    }
+    let [x, y, z, w] = [0.2, -1.5, 3.25, 7.0];
+    assert_eq!(raw::simplex(42, [x, y]), raw::simplex2d(42, x, y));
+    assert_eq!(
+        raw::simplex(42, [x, y, z, w]),
+        raw::simplex4d(42, x, y, z, w)
+    );
+}
+#[test]
+fn test_raw_table_shared() {
+    let table = std::sync::Arc::new(raw::build_permutation_table(42));
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let table = table.clone();
+            std::thread::spawn(move || raw::simplex2d_with_table(&table, i as f64 * 0.3, 0.7))
+        })
+        .collect();
+    for (i, handle) in handles.into_iter().enumerate() {
+        let value = handle.join().unwrap();
+        assert_eq!(value, Source::simplex(42).sample([i as f64 * 0.3, 0.7]));
+    }
+}
+
+// =================================================================
+// test integer lattice sampling
+// =================================================================
+// coordinates near the origin, beyond the period of the permutation table, around the limit of
+// exactly representable integers, and at the limits of i64
+const LATTICE_COORDINATES: [i64; 12] = [
+    0,
+    1,
+    -1,
+    255,
+    -256,
+    1_000_003,
+    (1 << 53) + 1,
+    -(1 << 53) - 3,
+    (1 << 62) + 77,
+    i64::MAX,
+    i64::MIN,
+    i64::MIN + 1,
+];
+
+fn assert_lattice_matches<const D: usize, G: Generator<D>>(generator: &G) {
+    for (k, &x) in LATTICE_COORDINATES.iter().enumerate() {
+        for &y in &LATTICE_COORDINATES[k % 3..] {
+            // spread the coordinates over all axes
+            let point: [i64; D] = std::array::from_fn(|i| {
+                if i % 2 == 0 {
+                    x
+                } else {
+                    y.wrapping_sub(i as i64)
+                }
+            });
+            let expected = generator.sample(point.map(|x| x as f64));
+            let value = generator.sample_lattice(point);
+            assert_eq!(value.to_bits(), expected.to_bits(), "at {:?}", point);
+        }
+    }
+}
+
+macro_rules! assert_lattice_matches_sources {
+    ($dim:literal) => {
+        assert_lattice_matches(&Value::<$dim>::new(42));
+        assert_lattice_matches(&Value::<$dim>::new(42).with_large_coordinates());
+        assert_lattice_matches(&White::<$dim>::new(42));
+        assert_lattice_matches(&Source::<$dim>::simplex(42));
+        assert_lattice_matches(&Source::<$dim>::value(7).add(1.0));
+    };
+}
+
+#[test]
+fn test_sample_lattice_matches_sample() {
+    assert_lattice_matches_sources!(1);
+    assert_lattice_matches_sources!(2);
+    assert_lattice_matches_sources!(3);
+    assert_lattice_matches_sources!(4);
+}
+
+#[test]
+fn test_sample_lattice_forwarded() {
+    let boxed: Box<dyn SampleGenerator<3>> = Box::new(Source::value(42));
+    let recipe = NoiseRecipe::Value {
+        seed: 42,
+        large_coordinates: true,
+    }
+    .build::<2>()
+    .unwrap();
+    assert_lattice_matches(&boxed);
+    assert_lattice_matches(&recipe);
+    assert_eq!(
+        boxed.sample_lattice([3, -4, 5]),
+        Source::<3>::value(42).sample_lattice([3, -4, 5])
+    );
 }
@@ -1,4 +1,5 @@
 use libnoise::prelude::*;
+use libnoise::testing::{assert_tileable, assert_tileable_3d};
 use proptest::prelude::*;
 use tempdir::TempDir;
 
@@ -74,3 +75,3114 @@ proptest! {
         Visualizer::<4>::new([10, 10, 10, 10], &generator).write_to_file(path).unwrap();
     }
 }
+
+#[test]
+fn test_visualizer_4d_frames() {
+    let generator = Source::<4>::simplex(42).scale([0.1; 4]);
+    let visualizer = Visualizer::<4>::new([12, 9, 7, 3], &generator);
+    let frames = visualizer.to_images();
+    assert_eq!(frames.len(), 3);
+    for (t, frame) in frames.iter().enumerate() {
+        let generator = generator.clone();
+        let slice = Source::custom(move |[x, y, z]| generator.sample([x, y, z, t as f64]));
+        assert_eq!(frame, &Visualizer::<3>::new([12, 9, 7], &slice).to_image());
+    }
+}
+
+#[test]
+fn test_visualizer_difference() {
+    let generator = Source::<2>::simplex(42).fbm(3, 0.013, 2.0, 0.5);
+    let image = Visualizer::<2>::difference_image([40, 30], &generator, &generator);
+    assert_eq!(image.dimensions(), (30, 40));
+    assert!(image.pixels().all(|pixel| pixel.0 == [255, 255, 255]));
+
+    // the largest positive and negative differences are saturated red and blue
+    let clamped = generator.clone().clamp(-0.25, 0.25);
+    let image = Visualizer::<2>::difference_image([40, 30], &generator, &clamped);
+    let buffer = NoiseBuffer::<2>::new([40, 30], &generator);
+    for (i, pixel) in image.pixels().enumerate() {
+        let value = buffer.buffer[i];
+        match pixel.0 {
+            // differences well below the scale are indistinguishable from no difference
+            [255, 255, 255] => assert!(value.abs() < 0.26),
+            [255, g, b] => assert!(value > 0.25 && g == b),
+            [r, g, 255] => assert!(value < -0.25 && r == g),
+            other => panic!("unexpected color {:?}", other),
+        }
+    }
+    assert!(image
+        .pixels()
+        .any(|pixel| pixel.0 == [255, 0, 0] || pixel.0 == [0, 0, 255]));
+    let image =
+        Visualizer::<2>::difference_image([1, 2], &Source::constant(1.0), &Source::constant(-1.0));
+    assert!(image.pixels().all(|pixel| pixel.0 == [255, 0, 0]));
+    let image = Visualizer::<2>::difference_image(
+        [1, 2],
+        &Source::constant(0.0),
+        &Source::constant(f64::NAN),
+    );
+    assert!(image.pixels().all(|pixel| pixel.0 == [0, 0, 0]));
+
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let path = &tmp_dir
+        .path()
+        .join("difference.png")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    Visualizer::<2>::write_difference_to_file([40, 30], &generator, &clamped, path).unwrap();
+    assert_eq!(
+        image::open(path).unwrap().to_rgb8(),
+        Visualizer::<2>::difference_image([40, 30], &generator, &clamped)
+    );
+    let result = Visualizer::<2>::write_difference_to_file([0, 30], &generator, &clamped, path);
+    assert!(matches!(
+        result,
+        Err(Error::InvalidParameter { name: "shape", .. })
+    ));
+}
+
+#[test]
+fn test_visualizer_identity_transfer() {
+    let generator = Source::<2>::simplex(42).fbm(3, 0.013, 2.0, 0.5).mul(1.5);
+    let mut buf = NoiseBuffer::<2>::new([40, 30], &generator);
+    buf.buffer[..4].copy_from_slice(&[f64::NAN, f64::INFINITY, -0.0, -1.0]);
+    let identity = Visualizer::from(buf.clone()).with_transfer(|x: f64| x);
+    assert_eq!(
+        identity.to_image(),
+        Visualizer::from(buf.clone()).to_image()
+    );
+    let identity = Visualizer::from_buffer_with_range(buf.clone(), (-0.5, 2.0));
+    assert_eq!(
+        identity.clone().with_transfer(|x: f64| x).to_image(),
+        identity.to_image()
+    );
+    assert_eq!(
+        Visualizer::from_buffer_with_transfer(buf.clone(), (-0.5, 2.0), |x: f64| x).to_image(),
+        identity.to_image()
+    );
+    let buf = NoiseBuffer::<3>::new([10, 12, 14], &Source::simplex(42));
+    assert_eq!(
+        Visualizer::from(buf.clone())
+            .with_transfer(|x: f64| x)
+            .to_image(),
+        Visualizer::from(buf).to_image()
+    );
+}
+
+#[test]
+fn test_visualizer_log_transfer() {
+    let buf = NoiseBuffer::<1>::new([256], &Source::custom(|[x]: [f64; 1]| x / 127.5 - 1.0));
+    let linear = Visualizer::from(buf.clone());
+    let log = Visualizer::from_buffer_with_transfer(buf, (-1.0, 1.0), Transfer::Log(0.01));
+    assert_eq!(log[&[0]], 0);
+    assert_eq!(log[&[255]], 255);
+    for x in 1..255 {
+        assert!(log[&[x]] >= log[&[x - 1]]);
+        assert!(log[&[x]] >= linear[&[x]]);
+    }
+    // a quarter of the range covers more than half of the grayscale values
+    assert!(log[&[64]] > 128);
+}
+
+#[test]
+#[should_panic(expected = "epsilon must be positive and finite, got 0")]
+fn test_visualizer_log_transfer_invalid() {
+    Visualizer::<2>::new([4, 4], &Source::simplex(42)).with_transfer(Transfer::Log(0.0));
+}
+
+#[test]
+fn test_visualizer_equalize_transfer() {
+    let generators: [Box<dyn SampleGenerator<2>>; 4] = [
+        Box::new(Source::simplex(42).fbm(5, 0.02, 2.0, 0.5)),
+        Box::new(Source::simplex(42).ridgedmulti(5, 0.02, 2.0, 0.5).powi(8)),
+        Box::new(Source::worley(43).scale([0.05; 2]).add(0.5).abs()),
+        Box::new(Source::custom(|[x, y]: [f64; 2]| (x * y / 12192.0).powi(4))),
+    ];
+    for generator in generators {
+        let buf = NoiseBuffer::<2>::new([128, 96], &generator);
+        let visualizer =
+            Visualizer::from_buffer_with_transfer(buf, (-1.0, 1.0), Transfer::Equalize);
+        // only a value of 1 is quantized to 255, so the values are spread over 255 levels
+        let mut histogram = [0usize; 15];
+        for pixel in visualizer.to_image().pixels() {
+            histogram[(pixel.0[0] as usize / 17).min(14)] += 1;
+        }
+        let expected = 128 * 96 / 15;
+        assert!(
+            histogram
+                .iter()
+                .all(|&count| count.abs_diff(expected) <= expected / 20),
+            "{:?}",
+            histogram
+        );
+    }
+    // equal values share the same result in the middle of their range
+    let visualizer =
+        Visualizer::<2>::new([8, 8], &Source::constant(0.7)).with_transfer(Transfer::Equalize);
+    assert!(visualizer.to_image().pixels().all(|pixel| pixel.0 == [127]));
+}
+
+#[test]
+fn test_visualizer_transfer_keeps_edits() {
+    let mut visualizer = Visualizer::<2>::new([8, 8], &Source::constant(-1.0));
+    visualizer[&[2, 3]] = 100;
+    let inverted = visualizer.with_transfer(|x: f64| 1.0 - x);
+    assert_eq!(inverted[&[2, 3]], 155);
+    assert_eq!(inverted[&[0, 0]], 255);
+    // transfer functions compose in order
+    let twice = inverted.with_transfer(|x: f64| 1.0 - x);
+    assert_eq!(twice[&[2, 3]], 100);
+    assert_eq!(twice[&[0, 0]], 0);
+}
+// =================================================================
+// test NoiseBuffer filling
+// =================================================================
+#[test]
+fn test_noise_buffer_refill() {
+    let mut buf = NoiseBuffer::<3>::new([7, 5, 3], &Source::simplex(42));
+    buf.refill(&Source::simplex(43));
+    let expected = NoiseBuffer::<3>::new([7, 5, 3], &Source::simplex(43));
+    assert_eq!(buf.buffer, expected.buffer);
+}
+
+#[test]
+fn test_noise_buffer_fill_slice() {
+    let generator = Source::simplex(42);
+    let expected = NoiseBuffer::<2>::new([13, 9], &generator);
+    let mut out = vec![0.0; 13 * 9];
+    NoiseBuffer::<2>::fill_slice(&generator, [13, 9], &mut out).unwrap();
+    assert_eq!(out, expected.buffer);
+    let mut out = vec![0.0; 13 * 9];
+    NoiseBuffer::<2>::fill_slice_f32(&generator, [13, 9], &mut out).unwrap();
+    assert!(out
+        .iter()
+        .zip(&expected.buffer)
+        .all(|(&a, &b)| a == b as f32));
+}
+
+#[test]
+fn test_noise_buffer_fill_slice_length_mismatch() {
+    let generator = Source::simplex(42);
+    let mut out = vec![0.0; 100];
+    let result = NoiseBuffer::<2>::fill_slice(&generator, [13, 9], &mut out);
+    assert_eq!(
+        result,
+        Err(Error::ShapeMismatch {
+            expected: vec![117],
+            found: vec![100]
+        })
+    );
+    let mut out = vec![0.0_f32; 118];
+    assert!(NoiseBuffer::<2>::fill_slice_f32(&generator, [13, 9], &mut out).is_err());
+}
+
+// =================================================================
+// test NoiseChunks
+// =================================================================
+macro_rules! assert_chunks_reassemble {
+    ($dim:literal, $shape:expr, $chunk_shape:expr) => {
+        let (shape, chunk_shape): ([usize; $dim], [usize; $dim]) = ($shape, $chunk_shape);
+        let generator = Source::simplex(42).scale([0.13; $dim]);
+        let full = NoiseBuffer::<$dim>::new(shape, &generator);
+        let mut reassembled = NoiseBuffer::<$dim>::new(shape, &Source::constant(f64::NAN));
+        let chunks = NoiseChunks::new(&generator, shape, chunk_shape);
+        let expected_len = (0..$dim)
+            .map(|i| shape[i].div_ceil(chunk_shape[i]))
+            .product::<usize>();
+        assert_eq!(chunks.len(), expected_len);
+        for (origin, chunk) in chunks {
+            for i in 0..$dim {
+                assert_eq!(chunk.shape[i], chunk_shape[i].min(shape[i] - origin[i]));
+            }
+            for (flat_index, &value) in chunk.buffer.iter().enumerate() {
+                let mut global = origin;
+                let mut rest = flat_index;
+                for i in 0..$dim {
+                    global[i] += rest / chunk.offsets[i];
+                    rest %= chunk.offsets[i];
+                }
+                reassembled[global] = value;
+            }
+        }
+        assert_eq!(reassembled.buffer, full.buffer);
+    };
+}
+
+#[test]
+fn test_noise_chunks_divisible() {
+    assert_chunks_reassemble!(1, [32], [8]);
+    assert_chunks_reassemble!(2, [16, 12], [4, 6]);
+    assert_chunks_reassemble!(3, [8, 8, 8], [4, 2, 8]);
+}
+
+#[test]
+fn test_noise_chunks_non_divisible() {
+    assert_chunks_reassemble!(1, [33], [8]);
+    assert_chunks_reassemble!(2, [17, 11], [4, 6]);
+    assert_chunks_reassemble!(3, [9, 7, 5], [4, 3, 8]);
+    assert_chunks_reassemble!(4, [5, 4, 3, 2], [2, 3, 2, 5]);
+}
+
+// =================================================================
+// test NoiseBuffer mapping
+// =================================================================
+#[test]
+fn test_noise_buffer_mapped() {
+    let generator = Source::simplex(42);
+    let origin = [-3.5, 100.0];
+    let step = [0.25, -0.125];
+    let buf = NoiseBuffer::<2>::new_mapped([20, 30], origin, step, &generator);
+    for i in 0..20 {
+        for j in 0..30 {
+            let point = [
+                origin[0] + i as f64 * step[0],
+                origin[1] + j as f64 * step[1],
+            ];
+            assert_eq!(buf[[i, j]], generator.sample(point));
+        }
+    }
+}
+
+#[test]
+fn test_noise_buffer_mapped_unit_step() {
+    let generator = Source::simplex(42);
+    let buf = NoiseBuffer::<3>::new_mapped([6, 5, 4], [0.0; 3], [1.0; 3], &generator);
+    assert_eq!(
+        buf.buffer,
+        NoiseBuffer::<3>::new([6, 5, 4], &generator).buffer
+    );
+}
+
+// =================================================================
+// test grid iteration
+// =================================================================
+#[test]
+fn test_grid_iter_matches_mapped() {
+    let generator = Source::simplex(42);
+    let (origin, step, shape) = ([-3.5, 100.0, 0.5], [0.25, -0.125, 2.0], [7, 5, 3]);
+    let buf = NoiseBuffer::<3>::new_mapped(shape, origin, step, &generator);
+    let samples = generator.grid_iter(origin, step, shape);
+    assert_eq!(samples.len(), buf.buffer.len());
+    let values = samples
+        .map(|(index, point, value)| {
+            assert_eq!(buf[index], value);
+            assert_eq!(generator.sample(point), value);
+            value
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(values, buf.buffer);
+}
+
+#[test]
+fn test_grid_iter_reverse() {
+    let generator = Source::simplex(42);
+    let buf = NoiseBuffer::<2>::new_mapped([6, 4], [1.0, 2.0], [0.5, 0.5], &generator);
+    let reversed = generator
+        .grid_iter([1.0, 2.0], [0.5, 0.5], [6, 4])
+        .rev()
+        .map(|(_, _, value)| value)
+        .collect::<Vec<_>>();
+    assert!(reversed.iter().eq(buf.buffer.iter().rev()));
+
+    // consume from both ends until the iterator is exhausted
+    let mut samples = generator.grid_iter([1.0, 2.0], [0.5, 0.5], [6, 4]);
+    assert_eq!(samples.next().unwrap().0, [0, 0]);
+    assert_eq!(samples.next_back().unwrap().0, [5, 3]);
+    assert_eq!(samples.nth(3).unwrap().0, [1, 0]);
+    assert_eq!(samples.nth_back(2).unwrap().0, [5, 0]);
+    assert_eq!(samples.len(), 15);
+    assert_eq!(samples.by_ref().count(), 15);
+    assert_eq!(samples.len(), 0);
+    assert!(samples.next().is_none() && samples.next_back().is_none());
+    assert_eq!(generator.grid_iter([0.0; 2], [1.0; 2], [6, 0]).len(), 0);
+}
+
+// =================================================================
+// test path sampling
+// =================================================================
+// the points at which sampling along a path evaluates the generator, recovered per axis
+fn path_positions(points: &[[f64; 2]], samples: usize) -> Vec<[f64; 2]> {
+    let xs = Source::custom(|[x, _]| x).sample_path(points, samples);
+    let ys = Source::custom(|[_, y]| y).sample_path(points, samples);
+    xs.into_iter().zip(ys).map(|(x, y)| [x, y]).collect()
+}
+
+#[test]
+fn test_sample_segment() {
+    let xs = Source::custom(|[x, _]| x).sample_segment([1.0, 2.0], [3.0, -2.0], 5);
+    let ys = Source::custom(|[_, y]| y).sample_segment([1.0, 2.0], [3.0, -2.0], 5);
+    assert_eq!(xs, [1.0, 1.5, 2.0, 2.5, 3.0]);
+    assert_eq!(ys, [2.0, 1.0, 0.0, -1.0, -2.0]);
+    let generator = Source::<3>::simplex(42);
+    assert_eq!(
+        generator.sample_segment([0.5; 3], [9.0; 3], 1),
+        [generator.sample([0.5; 3])]
+    );
+    assert!(generator.sample_segment([0.5; 3], [9.0; 3], 0).is_empty());
+}
+
+#[test]
+fn test_sample_path_l_shape() {
+    // legs of length 4 and 2, such that 7 samples are spaced 1 apart
+    let path = [[0.0, 0.0], [4.0, 0.0], [4.0, 2.0]];
+    assert_eq!(
+        path_positions(&path, 7),
+        [
+            [0.0, 0.0],
+            [1.0, 0.0],
+            [2.0, 0.0],
+            [3.0, 0.0],
+            [4.0, 0.0],
+            [4.0, 1.0],
+            [4.0, 2.0]
+        ]
+    );
+    assert_eq!(
+        path_positions(&path, 4),
+        [[0.0, 0.0], [2.0, 0.0], [4.0, 0.0], [4.0, 2.0]]
+    );
+    // duplicated points introduce segments of zero length, which must not change the result
+    let duplicated = [[0.0, 0.0], [0.0, 0.0], [4.0, 0.0], [4.0, 0.0], [4.0, 2.0]];
+    assert_eq!(path_positions(&duplicated, 7), path_positions(&path, 7));
+    let generator = Source::simplex(42).scale([0.3, 0.3]);
+    let values = generator.sample_path(&path, 7);
+    for (value, point) in values.iter().zip(path_positions(&path, 7)) {
+        assert_eq!(*value, generator.sample(point));
+    }
+}
+
+#[test]
+fn test_sample_path_degenerate() {
+    let generator = Source::simplex(42);
+    let expected = generator.sample([0.3, 0.7]);
+    // a single point
+    assert_eq!(generator.sample_path(&[[0.3, 0.7]], 4), [expected; 4]);
+    // a path of zero length
+    assert_eq!(
+        generator.sample_path(&[[0.3, 0.7], [0.3, 0.7], [0.3, 0.7]], 3),
+        [expected; 3]
+    );
+    // a single sample
+    assert_eq!(
+        generator.sample_path(&[[0.3, 0.7], [5.0, 5.0]], 1),
+        [expected]
+    );
+    assert!(generator.sample_path(&[[0.3, 0.7]], 0).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "path must contain at least one point")]
+fn test_sample_path_empty() {
+    Source::<2>::simplex(42).sample_path(&[], 4);
+}
+
+// =================================================================
+// test sphere mapping
+// =================================================================
+#[test]
+fn test_sphere_to_buffer_seamless() {
+    let generator = Source::simplex(42).fbm(4, 1.0, 2.0, 0.5);
+    let (width, height) = (65, 33);
+    let map = generator.sphere_to_buffer(width, height, 3.0);
+    assert_eq!(map.shape, [height, width]);
+    for row in 0..height {
+        assert_eq!(
+            map[[row, 0]],
+            map[[row, width - 1]],
+            "seam differs in row {}",
+            row
+        );
+    }
+    for row in [0, height - 1] {
+        for column in 0..width {
+            assert_eq!(
+                map[[row, column]],
+                map[[row, 0]],
+                "pole differs in row {}",
+                row
+            );
+        }
+    }
+    assert_eq!(map[[0, 0]], generator.sample([0.0, 0.0, 3.0]));
+    assert_eq!(map[[height - 1, 0]], generator.sample([0.0, 0.0, -3.0]));
+    // the equator at longitude zero lies on the positive x-axis
+    assert_eq!(map[[16, 32]], generator.sample([3.0, 0.0, 0.0]));
+}
+
+#[test]
+fn test_sample_sphere() {
+    let generator = Source::simplex(42);
+    for (lat, lon) in [(0.3, -2.0), (-1.2, 0.7), (1.5, 3.0)] {
+        let point = [
+            2.5 * f64::cos(lat) * f64::cos(lon),
+            2.5 * f64::cos(lat) * f64::sin(lon),
+            2.5 * f64::sin(lat),
+        ];
+        assert_eq!(
+            generator.sample_sphere(lat, lon, 2.5),
+            generator.sample(point)
+        );
+    }
+    // a larger radius yields smaller features relative to the sphere
+    let small = Source::simplex(42).sphere_to_buffer(64, 32, 1.0);
+    let large = Source::simplex(42).sphere_to_buffer(64, 32, 16.0);
+    let roughness = |map: &NoiseBuffer<2>| {
+        map.buffer
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]).abs())
+            .sum::<f64>()
+    };
+    assert!(roughness(&large) > 2.0 * roughness(&small));
+}
+
+// =================================================================
+// test cube sphere sampling
+// =================================================================
+// the point on the cube scaled by resolution - 1, such that it is integral, following the
+// orientation documented for CubeFace
+fn cube_lattice_point(
+    face: planet::CubeFace,
+    [row, column]: [usize; 2],
+    resolution: usize,
+) -> [i64; 3] {
+    let u = 2 * column as i64 - (resolution - 1) as i64;
+    let v = 2 * row as i64 - (resolution - 1) as i64;
+    let n = (resolution - 1) as i64;
+    match face {
+        planet::CubeFace::PositiveX => [n, -v, -u],
+        planet::CubeFace::NegativeX => [-n, -v, u],
+        planet::CubeFace::PositiveY => [u, n, v],
+        planet::CubeFace::NegativeY => [u, -n, -v],
+        planet::CubeFace::PositiveZ => [u, -v, n],
+        planet::CubeFace::NegativeZ => [-u, -v, -n],
+    }
+}
+
+#[test]
+fn test_cube_sphere_seamless() {
+    let generator = Source::simplex(42).fbm(5, 1.0, 2.0, 0.5);
+    for resolution in [2, 3, 16, 33] {
+        let faces = planet::all_faces(&generator, resolution, 3.0);
+        let mut samples =
+            std::collections::HashMap::<[i64; 3], Vec<(planet::CubeFace, f64)>>::new();
+        for (face, buf) in planet::CubeFace::ALL.into_iter().zip(&faces) {
+            assert_eq!(buf.shape, [resolution, resolution]);
+            for row in 0..resolution {
+                for column in 0..resolution {
+                    let point = cube_lattice_point(face, [row, column], resolution);
+                    samples
+                        .entry(point)
+                        .or_default()
+                        .push((face, buf[[row, column]]));
+                }
+            }
+        }
+        let inner = resolution - 2;
+        assert_eq!(samples.len(), 6 * inner * inner + 12 * inner + 8);
+        let n = (resolution - 1) as i64;
+        for (point, values) in &samples {
+            // points on an edge are shared by two faces, and corners by three faces
+            let on_faces = point.iter().filter(|x| x.abs() == n).count();
+            assert_eq!(values.len(), on_faces, "point {:?}", point);
+            for (face, value) in values {
+                assert_eq!(
+                    value.to_bits(),
+                    values[0].1.to_bits(),
+                    "{:?} differs from {:?} at {:?}",
+                    face,
+                    values[0].0,
+                    point
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_cube_sphere_corners() {
+    let generator = Source::simplex(42).fbm(5, 1.0, 2.0, 0.5);
+    let faces = planet::all_faces(&generator, 9, 2.0);
+    let face = |face: planet::CubeFace| &faces[face as usize];
+    // the corner at [1, 1, 1], shared by the positive faces
+    let corner = face(planet::CubeFace::PositiveX)[[0, 0]];
+    assert_eq!(face(planet::CubeFace::PositiveY)[[8, 8]], corner);
+    assert_eq!(face(planet::CubeFace::PositiveZ)[[0, 8]], corner);
+    // the corner at [-1, -1, -1], shared by the negative faces
+    let corner = face(planet::CubeFace::NegativeX)[[8, 0]];
+    assert_eq!(face(planet::CubeFace::NegativeY)[[8, 0]], corner);
+    assert_eq!(face(planet::CubeFace::NegativeZ)[[8, 8]], corner);
+    let expected = generator.sample([-2.0 / 3f64.sqrt(); 3]);
+    assert!((corner - expected).abs() < 1e-9);
+}
+
+#[test]
+fn test_cube_sphere_projection() {
+    let norm = Source::custom(|[x, y, z]: [f64; 3]| (x * x + y * y + z * z).sqrt());
+    for face in planet::all_faces(&norm, 17, 2.5) {
+        assert!(face.buffer.iter().all(|value| (value - 2.5).abs() < 1e-12));
+    }
+    // the angle between neighboring points along the center line of a face is constant
+    let latitude = Source::custom(|[x, y, z]: [f64; 3]| y.atan2((x * x + z * z).sqrt()));
+    let buf = planet::cube_sphere_face(&latitude, planet::CubeFace::NegativeZ, 17, 1.0);
+    for row in 1..17 {
+        let step = buf[[row - 1, 8]] - buf[[row, 8]];
+        assert!((step - std::f64::consts::FRAC_PI_2 / 16.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+#[should_panic(expected = "resolution must be at least 2, got 1")]
+fn test_cube_sphere_resolution() {
+    planet::cube_sphere_face(&Source::simplex(42), planet::CubeFace::PositiveX, 1, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "radius must be positive and finite, got 0")]
+fn test_cube_sphere_radius() {
+    planet::all_faces(&Source::simplex(42), 8, 0.0);
+}
+
+// =================================================================
+// test audio sample filling
+// =================================================================
+#[test]
+fn test_fill_samples() {
+    let generator = Source::simplex(42).fbm(4, 3.0, 2.0, 0.5);
+    let mut block = [0.0; 256];
+    generator.fill_samples(-0.75, 44100.0, &mut block);
+    for (n, sample) in block.iter().enumerate() {
+        assert_eq!(
+            *sample,
+            generator.sample([-0.75 + n as f64 / 44100.0]) as f32
+        );
+    }
+    // empty buffers are left untouched
+    generator.fill_samples(0.0, 44100.0, &mut []);
+}
+
+#[test]
+fn test_fill_samples_block_continuity() {
+    let generator = Source::simplex(42).fbm(6, 5.0, 2.0, 0.5);
+    let (sample_rate, block_size) = (48000.0, 128);
+    let mut signal = Vec::new();
+    let mut block = vec![0.0; block_size];
+    for index in 0..32 {
+        let start_time = 0.25 + (index * block_size) as f64 / sample_rate;
+        generator.fill_samples(start_time, sample_rate, &mut block);
+        signal.extend_from_slice(&block);
+    }
+    // consecutive blocks form the same signal as a single large block
+    let mut reference = vec![0.0; signal.len()];
+    generator.fill_samples(0.25, sample_rate, &mut reference);
+    for (a, b) in signal.iter().zip(&reference) {
+        assert!((a - b).abs() < 1e-6);
+    }
+    // the step across block boundaries is no larger than the steps within blocks
+    let steps = signal
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).abs())
+        .collect::<Vec<_>>();
+    let max_step = steps
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| (index + 1) % block_size != 0)
+        .map(|(_, step)| *step)
+        .fold(0.0, f32::max);
+    assert!(max_step > 0.0);
+    for boundary in (block_size..signal.len()).step_by(block_size) {
+        assert!(steps[boundary - 1] <= 1.5 * max_step);
+    }
+}
+
+#[test]
+#[should_panic(expected = "sample rate must be positive and finite")]
+fn test_fill_samples_invalid_sample_rate() {
+    Source::simplex(42).fill_samples(0.0, 0.0, &mut [0.0; 16]);
+}
+
+// =================================================================
+// test NoiseBuffer 4D support
+// =================================================================
+#[test]
+fn test_noise_buffer_4d_indexing() {
+    let mut buf = NoiseBuffer::<4>::new([3, 4, 5, 6], &Source::constant(0.0));
+    assert_eq!(buf.offsets, [120, 30, 6, 1]);
+    for (i, index) in (0..3)
+        .flat_map(|x| (0..4).map(move |y| [x, y]))
+        .flat_map(|[x, y]| (0..5).map(move |z| [x, y, z]))
+        .flat_map(|[x, y, z]| (0..6).map(move |w| [x, y, z, w]))
+        .enumerate()
+    {
+        buf[index] = i as f64;
+    }
+    assert!(buf.buffer.iter().enumerate().all(|(i, &v)| v == i as f64));
+}
+
+#[test]
+fn test_noise_buffer_4d_fill() {
+    let generator = Source::simplex(42).scale([0.3; 4]);
+    let buf = NoiseBuffer::<4>::new([3, 4, 5, 6], &generator);
+    for x in 0..3 {
+        for y in 0..4 {
+            for z in 0..5 {
+                for w in 0..6 {
+                    let point = [x as f64, y as f64, z as f64, w as f64];
+                    assert_eq!(buf[[x, y, z, w]], generator.sample(point));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_noise_buffer_size_overflow() {
+    let shape = [1 << 20, 1 << 20, 1 << 20, 1 << 20];
+    let result = NoiseBuffer::<4>::try_new(shape, &Source::constant(0.0));
+    assert_eq!(
+        result.unwrap_err(),
+        Error::InvalidShape {
+            shape: shape.to_vec()
+        }
+    );
+    let result = NoiseBuffer::<2>::try_new([usize::MAX / 4, 2], &Source::constant(0.0));
+    assert!(matches!(result, Err(Error::InvalidShape { .. })));
+}
+
+#[test]
+#[should_panic(expected = "buffer size overflows")]
+fn test_noise_buffer_size_overflow_panics() {
+    NoiseBuffer::<3>::new([1 << 30, 1 << 30, 1 << 30], &Source::constant(0.0));
+}
+
+// =================================================================
+// test NoiseBufferView
+// =================================================================
+#[test]
+fn test_noise_buffer_slice() {
+    let buf = NoiseBuffer::<3>::new([7, 5, 3], &Source::simplex(42).scale([0.3; 3]));
+    for axis in 0..3 {
+        for index in 0..buf.shape[axis] {
+            let view = buf.slice(axis, index);
+            for i in 0..view.shape[0] {
+                for j in 0..view.shape[1] {
+                    let parent = match axis {
+                        0 => [index, i, j],
+                        1 => [i, index, j],
+                        _ => [i, j, index],
+                    };
+                    let flat = parent[0] * 15 + parent[1] * 3 + parent[2];
+                    assert_eq!(view[[i, j]], buf.buffer[flat]);
+                }
+            }
+            let owned = view.to_buffer();
+            assert_eq!(owned.shape, view.shape);
+            assert!(owned.buffer.iter().copied().eq(view.iter()));
+        }
+    }
+}
+
+#[test]
+fn test_noise_buffer_view() {
+    let buf = NoiseBuffer::<2>::new([10, 8], &Source::simplex(42).scale([0.3; 2]));
+    let view = buf.view([3, 2], [5, 4]);
+    assert_eq!(view.shape, [5, 4]);
+    for i in 0..5 {
+        for j in 0..4 {
+            assert_eq!(view[[i, j]], buf.buffer[(3 + i) * 8 + 2 + j]);
+        }
+    }
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let path = &tmp_dir
+        .path()
+        .join("output.png")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    Visualizer::from(view).write_to_file(path).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "exceeds axis 1")]
+fn test_noise_buffer_view_out_of_bounds() {
+    let buf = NoiseBuffer::<2>::new([10, 8], &Source::constant(0.0));
+    buf.view([3, 5], [5, 4]);
+}
+
+#[test]
+#[should_panic(expected = "axis 3 out of range")]
+fn test_noise_buffer_slice_invalid_axis() {
+    let buf = NoiseBuffer::<3>::new([4, 4, 4], &Source::constant(0.0));
+    buf.slice(3, 0);
+}
+
+#[test]
+#[should_panic(expected = "index 1 out of bounds for axis 0 of view")]
+fn test_noise_buffer_view_index_out_of_bounds() {
+    let buf = NoiseBuffer::<2>::new([10, 8], &Source::constant(0.0));
+    let view = buf.view([9, 0], [1, 8]);
+    let _ = view[[1, 0]];
+}
+
+// =================================================================
+// test NoiseBuffer resampling
+// =================================================================
+#[test]
+fn test_noise_buffer_resample_bilinear_ramp() {
+    let ramp = Source::custom(|[x, y]| 0.5 * x - 0.25 * y + 1.0);
+    let buf = NoiseBuffer::<2>::new([8, 6], &ramp);
+    let upscaled = buf.resample([32, 18], Interpolation::Bilinear);
+    for i in 0..32 {
+        for j in 0..18 {
+            let x = (i as f64 + 0.5) * 8.0 / 32.0 - 0.5;
+            let y = (j as f64 + 0.5) * 6.0 / 18.0 - 0.5;
+            let expected = 0.5 * x.clamp(0.0, 7.0) - 0.25 * y.clamp(0.0, 5.0) + 1.0;
+            assert!((upscaled[[i, j]] - expected).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_noise_buffer_resample_bicubic_ramp() {
+    let ramp = Source::custom(|[x, y]| 0.5 * x - 0.25 * y + 1.0);
+    let buf = NoiseBuffer::<2>::new([8, 6], &ramp);
+    let upscaled = buf.resample([32, 18], Interpolation::Bicubic);
+    // away from the clamped edges, catmull-rom splines reproduce linear functions exactly
+    for i in 6..26 {
+        for j in 6..12 {
+            let x = (i as f64 + 0.5) * 8.0 / 32.0 - 0.5;
+            let y = (j as f64 + 0.5) * 6.0 / 18.0 - 0.5;
+            let expected = 0.5 * x - 0.25 * y + 1.0;
+            assert!((upscaled[[i, j]] - expected).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_noise_buffer_resample_nearest() {
+    let buf = NoiseBuffer::<2>::new([5, 4], &Source::simplex(42).scale([0.3; 2]));
+    let upscaled = buf.resample([15, 8], Interpolation::Nearest);
+    for i in 0..15 {
+        for j in 0..8 {
+            assert_eq!(upscaled[[i, j]], buf[[i / 3, j / 2]]);
+        }
+    }
+}
+
+#[test]
+fn test_noise_buffer_resample_trilinear() {
+    let ramp = Source::custom(|[x, y, z]| x + 2.0 * y - 3.0 * z);
+    let buf = NoiseBuffer::<3>::new([4, 4, 4], &ramp);
+    let same = buf.resample([4, 4, 4], Interpolation::Bilinear);
+    assert_eq!(same.buffer, buf.buffer);
+    let upscaled = buf.resample([8, 8, 8], Interpolation::Bilinear);
+    let expected = ramp.sample([1.25, 1.75, 2.25]);
+    assert!((upscaled[[3, 4, 5]] - expected).abs() < 1e-12);
+}
+
+// =================================================================
+// test NoiseBuffer downsampling
+// =================================================================
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+#[test]
+fn test_noise_buffer_downsample_conservation() {
+    let buf = NoiseBuffer::<2>::new([24, 18], &Source::simplex(42).scale([0.1; 2]));
+    for factor in [1, 2, 3, 6] {
+        let downsampled = buf.downsample(factor);
+        assert_eq!(downsampled.shape, [24 / factor, 18 / factor]);
+        assert!((mean(&downsampled.buffer) - mean(&buf.buffer)).abs() < 1e-12);
+    }
+    let downsampled = buf.downsample_by(1.5);
+    assert_eq!(downsampled.shape, [16, 12]);
+    assert!((mean(&downsampled.buffer) - mean(&buf.buffer)).abs() < 1e-12);
+}
+
+#[test]
+fn test_noise_buffer_downsample_non_divisible() {
+    let buf = NoiseBuffer::<2>::new([10, 7], &Source::simplex(42).scale([0.1; 2]));
+    let downsampled = buf.downsample(3);
+    assert_eq!(downsampled.shape, [4, 3]);
+    let expected = (buf[[6, 6]] + buf[[7, 6]] + buf[[8, 6]]) / 3.0;
+    assert!((downsampled[[2, 2]] - expected).abs() < 1e-12);
+    assert!((downsampled[[3, 2]] - buf[[9, 6]]).abs() < 1e-12);
+    let expected = (0..3).map(|j| buf[[9, 3 + j]]).sum::<f64>() / 3.0;
+    assert!((downsampled[[3, 1]] - expected).abs() < 1e-12);
+    assert_eq!(buf.downsample_by(2.5).shape, [4, 3]);
+    assert_eq!(buf.downsample_by(4.0).shape, [3, 2]);
+    let buf = NoiseBuffer::<3>::new([5, 5, 5], &Source::constant(0.3));
+    let downsampled = buf.downsample_by(2.2);
+    assert_eq!(downsampled.shape, [3, 3, 3]);
+    assert!(downsampled.buffer.iter().all(|&v| (v - 0.3).abs() < 1e-12));
+}
+
+// =================================================================
+// test NoiseBuffer gaussian blur
+// =================================================================
+fn impulse<const D: usize>(mut buf: NoiseBuffer<D>, index: [usize; D]) -> NoiseBuffer<D> {
+    let flat_index = index
+        .iter()
+        .zip(buf.offsets)
+        .map(|(i, offset)| i * offset)
+        .sum::<usize>();
+    buf.buffer.fill(0.0);
+    buf.buffer[flat_index] = 1.0;
+    buf
+}
+
+#[test]
+fn test_noise_buffer_gaussian_blur_impulse() {
+    for sigma in [0.5, 1.0, 2.3_f64] {
+        let radius = (3.0 * sigma).ceil() as isize;
+        let weight = |offset: isize| (-((offset * offset) as f64) / (2.0 * sigma * sigma)).exp();
+        let total = (-radius..=radius).map(weight).sum::<f64>();
+        let blurred = impulse(
+            NoiseBuffer::<2>::new([31, 31], &Source::constant(0.0)),
+            [15, 15],
+        )
+        .gaussian_blur(sigma, Boundary::Clamp);
+        for x in 0..31 {
+            for y in 0..31 {
+                let (dx, dy) = (x as isize - 15, y as isize - 15);
+                let expected = if dx.abs() <= radius && dy.abs() <= radius {
+                    weight(dx) * weight(dy) / (total * total)
+                } else {
+                    0.0
+                };
+                assert!((blurred[[x, y]] - expected).abs() < 1e-12);
+            }
+        }
+        // the blur is symmetric and peaks at the impulse
+        assert_eq!(blurred[[13, 15]], blurred[[17, 15]]);
+        assert_eq!(blurred[[15, 14]], blurred[[14, 15]]);
+        assert!(blurred
+            .buffer
+            .iter()
+            .all(|value| *value <= blurred[[15, 15]]));
+    }
+    // a sigma of zero leaves the buffer unchanged
+    let buf = NoiseBuffer::<2>::new([10, 12], &Source::simplex(42));
+    assert_eq!(buf.gaussian_blur(0.0, Boundary::Wrap).buffer, buf.buffer);
+}
+
+#[test]
+fn test_noise_buffer_gaussian_blur_sum() {
+    let sum = |buf: &NoiseBuffer<2>| buf.buffer.iter().sum::<f64>();
+    for boundary in [Boundary::Clamp, Boundary::Wrap, Boundary::Mirror] {
+        let blurred = impulse(
+            NoiseBuffer::<2>::new([40, 30], &Source::constant(0.0)),
+            [20, 12],
+        )
+        .gaussian_blur(1.7, boundary);
+        assert!((sum(&blurred) - 1.0).abs() < 1e-12);
+    }
+    // wrapping preserves the sum even for impulses at the edges
+    let blurred = impulse(
+        NoiseBuffer::<2>::new([20, 20], &Source::constant(0.0)),
+        [0, 19],
+    )
+    .gaussian_blur(3.0, Boundary::Wrap);
+    assert!((sum(&blurred) - 1.0).abs() < 1e-12);
+    assert_eq!(blurred[[1, 18]], blurred[[19, 0]]);
+    // clamping gathers the weight of the taps beyond the edge at the edge
+    let blurred = impulse(NoiseBuffer::<1>::new([20], &Source::constant(0.0)), [0])
+        .gaussian_blur(2.0, Boundary::Clamp);
+    assert!(blurred[[0]] > blurred[[1]]);
+    // mirroring reflects the taps beyond the edge without repeating the edge
+    let kernel = impulse(NoiseBuffer::<1>::new([20], &Source::constant(0.0)), [10])
+        .gaussian_blur(2.0, Boundary::Clamp);
+    let blurred = impulse(NoiseBuffer::<1>::new([20], &Source::constant(0.0)), [1])
+        .gaussian_blur(2.0, Boundary::Mirror);
+    assert!((blurred[[0]] - 2.0 * kernel[[11]]).abs() < 1e-15);
+    assert!((blurred[[2]] - kernel[[11]] - kernel[[13]]).abs() < 1e-15);
+}
+
+#[test]
+fn test_noise_buffer_gaussian_blur_tileable() {
+    // two periods of a tileable generator, such that wrapping around is seamless
+    let generator = Source::<4>::simplex(42)
+        .scale([0.15; 4])
+        .tileable_2d([32.0, 32.0]);
+    let buf = NoiseBuffer::<2>::new([64, 64], &generator);
+    let blurred = buf.gaussian_blur(2.5, Boundary::Wrap);
+    for x in 0..32 {
+        for y in 0..32 {
+            assert!((blurred[[x, y]] - blurred[[x + 32, y]]).abs() < 1e-12);
+            assert!((blurred[[x, y]] - blurred[[x, y + 32]]).abs() < 1e-12);
+        }
+    }
+    // interpolate the central part of the blurred buffer, such that it can be checked as a
+    // generator, which must tile with the same period
+    let lookup = Source::from_fn_with_state(blurred, |blurred, [x, y]| {
+        let (x, y) = (x + 16.0, y + 16.0);
+        let (ix, iy) = (x.floor() as usize, y.floor() as usize);
+        let (u, v) = (x - ix as f64, y - iy as f64);
+        blurred[[ix, iy]] * (1.0 - u) * (1.0 - v)
+            + blurred[[ix + 1, iy]] * u * (1.0 - v)
+            + blurred[[ix, iy + 1]] * (1.0 - u) * v
+            + blurred[[ix + 1, iy + 1]] * u * v
+    });
+    assert_tileable(&lookup, [32.0, 32.0], 32, 1e-9);
+}
+
+#[test]
+fn test_noise_buffer_gaussian_blur_3d() {
+    let blurred = impulse(
+        NoiseBuffer::<3>::new([9, 9, 9], &Source::constant(0.0)),
+        [4, 4, 4],
+    )
+    .gaussian_blur(1.0, Boundary::Mirror);
+    let sum = blurred.buffer.iter().sum::<f64>();
+    assert!((sum - 1.0).abs() < 1e-12);
+    assert_eq!(blurred[[3, 4, 4]], blurred[[4, 4, 5]]);
+    // the result is separable into the same kernel along every axis
+    let (a, b) = (
+        blurred[[4, 4, 4]] * blurred[[4, 3, 3]],
+        blurred[[4, 4, 3]] * blurred[[4, 3, 4]],
+    );
+    assert!((a - b).abs() < 1e-15);
+}
+
+#[test]
+#[should_panic(expected = "sigma must be non-negative and finite")]
+fn test_noise_buffer_gaussian_blur_invalid_sigma() {
+    NoiseBuffer::<2>::new([4, 4], &Source::simplex(42)).gaussian_blur(-1.0, Boundary::Clamp);
+}
+
+// =================================================================
+// test NoiseBuffer sobel filter
+// =================================================================
+#[test]
+fn test_noise_buffer_sobel_step() {
+    // a step from 0 to 2 between the indices 4 and 5 along the first axis
+    let step = NoiseBuffer::<2>::new(
+        [10, 8],
+        &Source::custom(|[x, _]| if x < 4.5 { 0.0 } else { 2.0 }),
+    );
+    let (raw, direction) = step.sobel_with_direction(false);
+    let normalized = step.sobel(true);
+    for x in 0..10 {
+        for y in 0..8 {
+            if x == 4 || x == 5 {
+                // replicated edges yield the full response along the boundary rows
+                assert_eq!(raw[[x, y]], 8.0);
+                assert_eq!(normalized[[x, y]], 1.0);
+                assert_eq!(direction[[x, y]], 0.0);
+            } else {
+                assert_eq!(raw[[x, y]], 0.0);
+                assert_eq!(normalized[[x, y]], 0.0);
+                assert!(direction[[x, y]].is_nan());
+            }
+        }
+    }
+    // the response is symmetric with respect to the orientation of the step
+    let transposed = NoiseBuffer::<2>::new(
+        [8, 10],
+        &Source::custom(|[_, y]| if y < 4.5 { 2.0 } else { 0.0 }),
+    );
+    let (raw, direction) = transposed.sobel_with_direction(false);
+    assert_eq!(raw[[3, 4]], 8.0);
+    assert_eq!(direction[[3, 5]], -std::f64::consts::FRAC_PI_2);
+    assert_eq!(raw[[3, 6]], 0.0);
+}
+
+#[test]
+fn test_noise_buffer_sobel_constant() {
+    let constant = NoiseBuffer::<2>::new([12, 9], &Source::constant(0.7));
+    for normalize in [false, true] {
+        assert!(constant
+            .sobel(normalize)
+            .buffer
+            .iter()
+            .all(|value| *value == 0.0));
+    }
+    let (_, direction) = constant.sobel_with_direction(false);
+    assert!(direction.buffer.iter().all(|value| value.is_nan()));
+    // normalization scales the raw magnitudes by their maximum
+    let terrain = NoiseBuffer::<2>::new([32, 32], &Source::simplex(42).scale([0.1; 2]));
+    let (raw, normalized) = (terrain.sobel(false), terrain.sobel(true));
+    let max = raw.buffer.iter().copied().fold(0.0, f64::max);
+    for (raw, normalized) in raw.buffer.iter().zip(&normalized.buffer) {
+        assert_eq!(*normalized, raw / max);
+    }
+}
+
+// =================================================================
+// test NoiseBuffer element-wise operations
+// =================================================================
+fn ramp_buffer() -> NoiseBuffer<2> {
+    NoiseBuffer::<2>::new([3, 2], &Source::custom(|[x, y]| x * 2.0 + y))
+}
+
+fn half_buffer() -> NoiseBuffer<2> {
+    NoiseBuffer::<2>::new([3, 2], &Source::constant(2.5))
+}
+
+#[test]
+fn test_noise_buffer_map_assign() {
+    let mut buf = ramp_buffer();
+    buf.map_assign(|value| value * value);
+    assert_eq!(buf.buffer, [0.0, 1.0, 4.0, 9.0, 16.0, 25.0]);
+}
+
+#[test]
+fn test_noise_buffer_elementwise_ops() {
+    let mut buf = ramp_buffer();
+    buf.add_assign(&half_buffer()).unwrap();
+    assert_eq!(buf.buffer, [2.5, 3.5, 4.5, 5.5, 6.5, 7.5]);
+
+    let mut buf = ramp_buffer();
+    buf.mul_assign(&half_buffer()).unwrap();
+    assert_eq!(buf.buffer, [0.0, 2.5, 5.0, 7.5, 10.0, 12.5]);
+
+    let mut buf = ramp_buffer();
+    buf.min_assign(&half_buffer()).unwrap();
+    assert_eq!(buf.buffer, [0.0, 1.0, 2.0, 2.5, 2.5, 2.5]);
+
+    let mut buf = ramp_buffer();
+    buf.max_assign(&half_buffer()).unwrap();
+    assert_eq!(buf.buffer, [2.5, 2.5, 2.5, 3.0, 4.0, 5.0]);
+
+    let mut buf = ramp_buffer();
+    buf.lerp_assign(&half_buffer(), 0.0).unwrap();
+    assert_eq!(buf.buffer, ramp_buffer().buffer);
+    buf.lerp_assign(&half_buffer(), 0.5).unwrap();
+    assert_eq!(buf.buffer, [1.25, 1.75, 2.25, 2.75, 3.25, 3.75]);
+    buf.lerp_assign(&half_buffer(), 1.0).unwrap();
+    assert_eq!(buf.buffer, half_buffer().buffer);
+}
+
+#[test]
+fn test_noise_buffer_elementwise_shape_mismatch() {
+    let mut buf = ramp_buffer();
+    let other = NoiseBuffer::<2>::new([2, 3], &Source::constant(1.0));
+    let expected = Err(Error::ShapeMismatch {
+        expected: vec![3, 2],
+        found: vec![2, 3],
+    });
+    assert_eq!(buf.add_assign(&other), expected);
+    assert_eq!(buf.mul_assign(&other), expected);
+    assert_eq!(buf.min_assign(&other), expected);
+    assert_eq!(buf.max_assign(&other), expected);
+    assert_eq!(buf.lerp_assign(&other, 0.5), expected);
+    assert_eq!(buf.buffer, ramp_buffer().buffer);
+}
+// =================================================================
+// test NoiseBuffer slope and aspect
+// =================================================================
+#[test]
+fn test_noise_buffer_slope_aspect_planes() {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+    // gradients of planes paired with the direction in which they fall
+    let planes = [
+        ([1.0, 0.0], PI),
+        ([-2.0, 0.0], 0.0),
+        ([0.0, 0.5], -FRAC_PI_2),
+        ([0.0, -3.0], FRAC_PI_2),
+        ([1.5, 1.5], -3.0 * FRAC_PI_4),
+        ([-0.25, 0.25], -FRAC_PI_4),
+        ([3.0, -4.0], (4.0_f64).atan2(-3.0)),
+    ];
+    for ([a, b], expected_aspect) in planes {
+        let buf = NoiseBuffer::<2>::new([7, 5], &Source::custom(move |[x, y]| a * x + b * y));
+        for cell_size in [1.0, 0.25, 4.0] {
+            let slope = buf.slope(cell_size);
+            let aspect = buf.aspect(cell_size);
+            assert_eq!(slope.shape, buf.shape);
+            assert_eq!(aspect.shape, buf.shape);
+            // planes have a constant gradient, so edges using one-sided differences agree
+            let expected_slope = (a * a + b * b).sqrt() / cell_size;
+            for (value, direction) in slope.buffer.iter().zip(&aspect.buffer) {
+                assert!((value - expected_slope).abs() < 1e-12);
+                assert!((direction - expected_aspect).abs() < 1e-12);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_noise_buffer_slope_aspect_edges() {
+    // a paraboloid has a known gradient in the interior, and one-sided differences at the edges
+    let buf = NoiseBuffer::<2>::new([5, 5], &Source::custom(|[x, y]| x * x + y * y));
+    let slope = buf.slope(1.0);
+    assert_eq!(slope[[2, 2]], (16.0_f64 + 16.0).sqrt());
+    assert_eq!(slope[[0, 2]], (1.0_f64 + 16.0).sqrt());
+    assert_eq!(slope[[4, 0]], (49.0_f64 + 1.0).sqrt());
+    // the paraboloid falls towards its vertex at the origin
+    let aspect = buf.aspect(1.0);
+    assert_eq!(aspect[[3, 3]], -3.0 * std::f64::consts::FRAC_PI_4);
+    // flat cells and axes of length 1 have no gradient
+    let line = NoiseBuffer::<2>::new([1, 6], &Source::custom(|[x, y]| x + 2.0 * y));
+    assert!(line.slope(1.0).buffer.iter().all(|value| *value == 2.0));
+    assert!(line
+        .aspect(1.0)
+        .buffer
+        .iter()
+        .all(|value| *value == -std::f64::consts::FRAC_PI_2));
+    let flat = NoiseBuffer::<2>::new([4, 4], &Source::constant(1.0));
+    assert!(flat.slope(1.0).buffer.iter().all(|value| *value == 0.0));
+    assert!(flat.aspect(1.0).buffer.iter().all(|value| value.is_nan()));
+}
+
+// =================================================================
+// test hydraulic erosion
+// =================================================================
+fn eroded_terrain(
+    shape: [usize; 2],
+    params: erosion::HydraulicParams,
+    seed: u64,
+) -> NoiseBuffer<2> {
+    let generator = Source::simplex(42).fbm(5, 0.05, 2.0, 0.5);
+    let mut buf = NoiseBuffer::<2>::new(shape, &generator);
+    erosion::hydraulic(&mut buf, params, seed);
+    buf
+}
+
+fn buffer_hash(buf: &NoiseBuffer<2>) -> u64 {
+    buf.buffer
+        .iter()
+        .flat_map(|value| value.to_bits().to_le_bytes())
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+}
+
+#[test]
+fn test_hydraulic_erosion_determinism() {
+    let params = erosion::HydraulicParams {
+        droplets: 2000,
+        ..Default::default()
+    };
+    let original = NoiseBuffer::<2>::new([48, 40], &Source::simplex(42).fbm(5, 0.05, 2.0, 0.5));
+    let eroded = eroded_terrain([48, 40], params, 7);
+    assert_eq!(eroded.buffer, eroded_terrain([48, 40], params, 7).buffer);
+    assert_ne!(eroded.buffer, eroded_terrain([48, 40], params, 8).buffer);
+    assert_ne!(eroded.buffer, original.buffer);
+    assert!(eroded.buffer.iter().all(|value| value.is_finite()));
+}
+
+#[test]
+fn test_hydraulic_erosion_mass_conservation() {
+    for (evaporate_speed, erosion_radius) in [(0.01, 3), (0.2, 1), (1.0, 4)] {
+        let params = erosion::HydraulicParams {
+            droplets: 3000,
+            evaporate_speed,
+            erosion_radius,
+            ..Default::default()
+        };
+        let original = NoiseBuffer::<2>::new([40, 40], &Source::simplex(42).fbm(5, 0.05, 2.0, 0.5));
+        let eroded = eroded_terrain([40, 40], params, 42);
+        let mass = |buf: &NoiseBuffer<2>| buf.buffer.iter().sum::<f64>();
+        assert!((mass(&eroded) - mass(&original)).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_hydraulic_erosion_golden() {
+    let params = erosion::HydraulicParams {
+        droplets: 500,
+        ..Default::default()
+    };
+    let eroded = eroded_terrain([32, 32], params, 42);
+    assert_eq!(buffer_hash(&eroded), 0xfb3f_ca3a_3c70_52b3);
+}
+
+#[test]
+fn test_hydraulic_erosion_degenerate_shapes() {
+    let params = erosion::HydraulicParams::default();
+    for shape in [[1, 16], [16, 1], [0, 0]] {
+        let original = NoiseBuffer::<2>::new(shape, &Source::simplex(42));
+        let mut buf = original.clone();
+        erosion::hydraulic(&mut buf, params, 42);
+        assert_eq!(buf.buffer, original.buffer);
+    }
+}
+
+// =================================================================
+// test thermal erosion
+// =================================================================
+fn max_talus_slope(
+    buf: &NoiseBuffer<2>,
+    cell_size: f64,
+    neighborhood: erosion::Neighborhood,
+) -> f64 {
+    let [width, height] = buf.shape;
+    let offsets: &[[isize; 2]] = match neighborhood {
+        erosion::Neighborhood::VonNeumann => &[[1, 0], [0, 1]],
+        erosion::Neighborhood::Moore => &[[1, 0], [0, 1], [1, 1], [1, -1]],
+    };
+    let mut max = 0.0_f64;
+    for x in 0..width as isize {
+        for y in 0..height as isize {
+            for [dx, dy] in offsets {
+                let (nx, ny) = (x + dx, y + dy);
+                if nx < 0 || ny < 0 || nx >= width as isize || ny >= height as isize {
+                    continue;
+                }
+                let distance = ((dx * dx + dy * dy) as f64).sqrt() * cell_size;
+                let difference = buf[[x as usize, y as usize]] - buf[[nx as usize, ny as usize]];
+                max = max.max(difference.abs() / distance);
+            }
+        }
+    }
+    max
+}
+
+#[test]
+fn test_thermal_erosion_mass_conservation() {
+    for neighborhood in [
+        erosion::Neighborhood::VonNeumann,
+        erosion::Neighborhood::Moore,
+    ] {
+        let original = NoiseBuffer::<2>::new([40, 30], &Source::simplex(42).fbm(5, 0.05, 2.0, 0.5));
+        let mut buf = original.clone();
+        erosion::thermal(&mut buf, 100, 0.3, 0.05, neighborhood);
+        assert_ne!(buf.buffer, original.buffer);
+        let mass = |buf: &NoiseBuffer<2>| buf.buffer.iter().sum::<f64>();
+        assert!((mass(&buf) - mass(&original)).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn test_thermal_erosion_convergence() {
+    for neighborhood in [
+        erosion::Neighborhood::VonNeumann,
+        erosion::Neighborhood::Moore,
+    ] {
+        for (talus_angle, cell_size) in [(0.5, 0.1), (1.0, 0.02), (0.0, 1.0)] {
+            let mut buf = NoiseBuffer::<2>::new([12, 10], &Source::simplex(42).scale([0.3; 2]));
+            let talus = f64::tan(talus_angle);
+            assert!(max_talus_slope(&buf, cell_size, neighborhood) > talus);
+            erosion::thermal(&mut buf, 10_000, talus_angle, cell_size, neighborhood);
+            assert!(max_talus_slope(&buf, cell_size, neighborhood) <= talus + 1e-6);
+        }
+    }
+}
+
+#[test]
+fn test_thermal_erosion_determinism() {
+    let original = NoiseBuffer::<2>::new([32, 32], &Source::simplex(42).fbm(5, 0.05, 2.0, 0.5));
+    let erode = |iterations| {
+        let mut buf = original.clone();
+        erosion::thermal(
+            &mut buf,
+            iterations,
+            0.6,
+            0.05,
+            erosion::Neighborhood::Moore,
+        );
+        buf
+    };
+    assert_eq!(erode(30).buffer, erode(30).buffer);
+    assert_ne!(erode(30).buffer, erode(31).buffer);
+    assert_eq!(erode(0).buffer, original.buffer);
+    // a pair of cells is leveled exactly to the talus angle in one iteration
+    let mut pair = NoiseBuffer::<2>::new([2, 1], &Source::custom(|[x, _]| 2.0 * x));
+    erosion::thermal(
+        &mut pair,
+        1,
+        std::f64::consts::FRAC_PI_4,
+        1.0,
+        erosion::Neighborhood::VonNeumann,
+    );
+    assert_eq!(pair.buffer, vec![0.5, 1.5]);
+    // stable slopes are left unchanged
+    let stable = pair.clone();
+    erosion::thermal(
+        &mut pair,
+        10,
+        std::f64::consts::FRAC_PI_4,
+        1.0,
+        erosion::Neighborhood::VonNeumann,
+    );
+    assert_eq!(pair.buffer, stable.buffer);
+}
+
+#[test]
+#[should_panic(expected = "talus angle must be within [0, pi/2)")]
+fn test_thermal_erosion_invalid_talus_angle() {
+    let mut buf = NoiseBuffer::<2>::new([4, 4], &Source::simplex(42));
+    erosion::thermal(
+        &mut buf,
+        1,
+        std::f64::consts::FRAC_PI_2,
+        1.0,
+        erosion::Neighborhood::Moore,
+    );
+}
+
+// =================================================================
+// test poisson-disk point sets
+// =================================================================
+fn assert_min_distance(points: &[[f64; 2]], min_distance: f64) {
+    for (i, [x1, y1]) in points.iter().enumerate() {
+        for [x2, y2] in &points[i + 1..] {
+            let distance = (x2 - x1).hypot(y2 - y1);
+            assert!(
+                distance >= min_distance,
+                "points [{x1}, {y1}] and [{x2}, {y2}] are only {distance} apart"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_poisson_disk() {
+    let points = points::poisson_disk(42, [-20.0, 10.0], [60.0, 50.0], 3.0);
+    assert_min_distance(&points, 3.0);
+    assert!(points
+        .iter()
+        .all(|[x, y]| (-20.0..60.0).contains(x) && (10.0..50.0).contains(y)));
+    // the region is filled, leaving no gaps in which further points would fit easily
+    let area = 80.0 * 40.0;
+    assert!(points.len() as f64 > area / 16.0, "{} points", points.len());
+    for x in 0..80 {
+        for y in 0..40 {
+            let [px, py] = [x as f64 - 19.5, y as f64 + 10.5];
+            let nearest = points
+                .iter()
+                .map(|[x, y]| (x - px).hypot(y - py))
+                .fold(f64::INFINITY, f64::min);
+            assert!(nearest < 6.0, "no point near [{px}, {py}]");
+        }
+    }
+}
+
+#[test]
+fn test_poisson_disk_determinism() {
+    let points = points::poisson_disk(42, [0.0; 2], [30.0; 2], 2.0);
+    assert_eq!(points, points::poisson_disk(42, [0.0; 2], [30.0; 2], 2.0));
+    assert_ne!(points, points::poisson_disk(43, [0.0; 2], [30.0; 2], 2.0));
+    assert!(points::poisson_disk(42, [5.0, 0.0], [5.0, 30.0], 2.0).is_empty());
+}
+
+#[test]
+fn test_poisson_disk_chunked() {
+    let (chunk_size, min_distance) = (16.0, 2.5);
+    let mut all = Vec::new();
+    for cx in -2..2 {
+        for cy in -2..2 {
+            let points = points::poisson_disk_chunked(7, [cx, cy], chunk_size, min_distance);
+            assert!(!points.is_empty());
+            // every point belongs to the chunk it is returned for
+            let (x0, y0) = (cx as f64 * chunk_size, cy as f64 * chunk_size);
+            assert!(points.iter().all(|[x, y]| {
+                (x0..x0 + chunk_size).contains(x) && (y0..y0 + chunk_size).contains(y)
+            }));
+            assert_eq!(
+                points,
+                points::poisson_disk_chunked(7, [cx, cy], chunk_size, min_distance)
+            );
+            all.extend(points);
+        }
+    }
+    // the minimum distance holds across the borders of all chunks
+    assert_min_distance(&all, min_distance);
+    let border_points = all
+        .iter()
+        .filter(|[x, y]| {
+            let distance_to_border = |v: f64| {
+                let offset = v.rem_euclid(chunk_size);
+                offset.min(chunk_size - offset)
+            };
+            distance_to_border(*x).min(distance_to_border(*y)) < min_distance
+        })
+        .count();
+    assert!(border_points > 0);
+    assert_ne!(
+        points::poisson_disk_chunked(7, [0, 0], chunk_size, min_distance),
+        points::poisson_disk_chunked(8, [0, 0], chunk_size, min_distance)
+    );
+}
+
+#[test]
+#[should_panic(expected = "must not exceed the finite chunk size")]
+fn test_poisson_disk_chunked_invalid_min_distance() {
+    points::poisson_disk_chunked(7, [0, 0], 4.0, 5.0);
+}
+
+// =================================================================
+// test NoiseBuffer indexed iteration
+// =================================================================
+fn radial_falloff([x, y]: [usize; 2], value: &mut f64) {
+    let dx = x as f64 - 10.0;
+    let dy = y as f64 - 10.0;
+    *value *= (1.0 - (dx * dx + dy * dy).sqrt() / 10.0).max(0.0);
+}
+
+#[test]
+fn test_noise_buffer_for_each_indexed() {
+    let mut buf = NoiseBuffer::<2>::new([21, 21], &Source::constant(0.8));
+    let mut visited = Vec::new();
+    buf.for_each_indexed(|index, value| {
+        visited.push(index);
+        radial_falloff(index, value);
+    });
+    assert_eq!(visited[..3], [[0, 0], [0, 1], [0, 2]]);
+    assert_eq!(visited.len(), 21 * 21);
+    assert_eq!(buf[[10, 10]], 0.8);
+    assert!((buf[[10, 15]] - 0.4).abs() < 1e-12);
+    assert_eq!(buf[[10, 20]], 0.0);
+    for corner in [[0, 0], [0, 20], [20, 0], [20, 20]] {
+        assert_eq!(buf[corner], 0.0);
+    }
+}
+
+#[test]
+fn test_noise_buffer_par_for_each_indexed() {
+    let generator = Source::simplex(42).scale([0.1; 2]);
+    let mut buf = NoiseBuffer::<2>::new([21, 21], &generator);
+    let mut par_buf = buf.clone();
+    buf.for_each_indexed(radial_falloff);
+    par_buf.par_for_each_indexed(radial_falloff);
+    assert_eq!(buf.buffer, par_buf.buffer);
+}
+
+// =================================================================
+// test MultiNoiseBuffer
+// =================================================================
+#[test]
+fn test_multi_noise_buffer_channels() {
+    let shape = [30, 20];
+    let red = Source::simplex(42).fbm(3, 0.013, 2.0, 0.5);
+    let green = Source::worley(43).scale([0.02; 2]);
+    let blue = Source::perlin(44).scale([0.05; 2]);
+    let alpha = Source::constant(0.5);
+    let buf =
+        MultiNoiseBuffer::<2, 4>::new(shape, &(red.clone(), green.clone(), blue.clone(), alpha));
+    assert_eq!(buf.buffer.len(), 30 * 20);
+    assert_eq!(
+        buf.channel(0).buffer,
+        NoiseBuffer::<2>::new(shape, &red).buffer
+    );
+    assert_eq!(
+        buf.channel(1).buffer,
+        NoiseBuffer::<2>::new(shape, &green).buffer
+    );
+    assert_eq!(
+        buf.channel(2).buffer,
+        NoiseBuffer::<2>::new(shape, &blue).buffer
+    );
+    assert_eq!(
+        buf.channel(3).buffer,
+        NoiseBuffer::<2>::new(shape, &alpha).buffer
+    );
+    assert_eq!(buf[[17, 9]][2], blue.sample([17.0, 9.0]));
+}
+
+#[test]
+fn test_multi_noise_buffer_array_channels() {
+    let generators = [Source::simplex(1), Source::simplex(2)];
+    let buf = MultiNoiseBuffer::<3, 2>::new([5, 6, 7], &generators);
+    for (channel, generator) in generators.iter().enumerate() {
+        let expected = NoiseBuffer::<3>::new([5, 6, 7], generator);
+        assert_eq!(buf.channel(channel).buffer, expected.buffer);
+    }
+}
+
+#[test]
+fn test_multi_noise_buffer_write_png() {
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let generator = Source::simplex(42).scale([0.05; 2]);
+    let path = &tmp_dir
+        .path()
+        .join("rgb.png")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    MultiNoiseBuffer::<2, 3>::new(
+        [30, 20],
+        &[generator.clone(), generator.clone(), generator.clone()],
+    )
+    .write_rgb_png(path)
+    .unwrap();
+    let path = &tmp_dir
+        .path()
+        .join("rgba.png")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    MultiNoiseBuffer::<2, 4>::new([30, 20], &std::array::from_fn(|_| generator.clone()))
+        .write_rgba_png(path)
+        .unwrap();
+}
+
+// =================================================================
+// test NoiseBuffer with f32 elements
+// =================================================================
+#[test]
+fn test_noise_buffer_f32_fill_and_index() {
+    let generator = Source::simplex(42).scale([0.1; 3]);
+    let buf = NoiseBuffer::<3>::new([10, 8, 6], &generator);
+    let mut buf_f32 = NoiseBuffer::<3, f32>::new([10, 8, 6], &generator);
+    assert_eq!(buf_f32.shape, buf.shape);
+    assert_eq!(buf_f32.offsets, buf.offsets);
+    for (&value_f32, &value) in buf_f32.buffer.iter().zip(&buf.buffer) {
+        assert_eq!(value_f32, value as f32);
+    }
+    assert_eq!(buf_f32[[7, 3, 5]], buf[[7, 3, 5]] as f32);
+    buf_f32[[7, 3, 5]] = 0.25;
+    assert_eq!(buf_f32[[7, 3, 5]], 0.25);
+    buf_f32.refill(&generator);
+    assert_eq!(buf_f32[[7, 3, 5]], buf[[7, 3, 5]] as f32);
+    let mapped =
+        NoiseBuffer::<2, f32>::new_mapped([4, 4], [1.0, 2.0], [0.5, 0.5], &Source::simplex(42));
+    let expected =
+        NoiseBuffer::<2>::new_mapped([4, 4], [1.0, 2.0], [0.5, 0.5], &Source::simplex(42));
+    assert_eq!(mapped[[3, 1]], expected[[3, 1]] as f32);
+}
+
+#[test]
+fn test_noise_buffer_f32_export() {
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let path = &tmp_dir
+        .path()
+        .join("output.png")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    let generator = Source::simplex(42).scale([0.05; 2]);
+    Visualizer::from(NoiseBuffer::<2, f32>::new([30, 20], &generator))
+        .write_to_file(path)
+        .unwrap();
+}
+
+// =================================================================
+// test NoiseBuffer with f16 elements
+// =================================================================
+#[test]
+fn test_noise_buffer_f16_fill_and_index() {
+    use half::f16;
+    let generator = Source::simplex(42).scale([0.1; 3]);
+    let buf = NoiseBuffer::<3>::new([10, 8, 6], &generator);
+    let mut buf_f16 = NoiseBuffer::<3, f16>::new([10, 8, 6], &generator);
+    assert_eq!(buf_f16.offsets, buf.offsets);
+    assert_eq!(
+        std::mem::size_of_val(buf_f16.buffer.as_slice()),
+        2 * 10 * 8 * 6
+    );
+    for (&value_f16, &value) in buf_f16.buffer.iter().zip(&buf.buffer) {
+        assert!((value_f16.to_f64() - value).abs() <= 2.0_f64.powi(-11));
+    }
+    assert_eq!(buf_f16[[7, 3, 5]], NoiseElement::from_f64(buf[[7, 3, 5]]));
+    buf_f16[[7, 3, 5]] = f16::from_f32(0.25);
+    assert_eq!(buf_f16[[7, 3, 5]].to_f64(), 0.25);
+    buf_f16.refill(&generator);
+    assert_eq!(buf_f16[[7, 3, 5]], NoiseElement::from_f64(buf[[7, 3, 5]]));
+    let stats = buf_f16.stats();
+    assert!(stats.min >= -1.0 && stats.max <= 1.0);
+}
+
+#[test]
+fn test_noise_buffer_f16_rounding() {
+    use half::f16;
+    let convert = |value: f64| <f16 as NoiseElement>::from_f64(value).to_bits();
+    let one = 0x3c00;
+    // ties round to even, but anything above a tie rounds up, even below the precision of f32
+    assert_eq!(convert(1.0 + 2.0_f64.powi(-11)), one);
+    assert_eq!(
+        convert(1.0 + 2.0_f64.powi(-11) + 2.0_f64.powi(-40)),
+        one + 1
+    );
+    assert_eq!(convert(1.0 + 3.0 * 2.0_f64.powi(-11)), one + 2);
+    assert_eq!(convert(1.0 - 2.0_f64.powi(-12)), one);
+    assert_eq!(convert(1.0 - 2.0_f64.powi(-12) - 2.0_f64.powi(-40)), 0x3bff);
+    assert_eq!(
+        convert(-1.0 - 2.0_f64.powi(-11) - 2.0_f64.powi(-40)),
+        0x8000 | (one + 1)
+    );
+    // the largest finite value, and overflow to infinity from the tie above it on
+    assert_eq!(convert(65504.0), 0x7bff);
+    assert_eq!(convert(65519.999), 0x7bff);
+    assert_eq!(convert(65520.0), 0x7c00);
+    assert_eq!(convert(-1e300), 0xfc00);
+    // subnormals, and underflow to zero from the tie below the smallest subnormal on
+    assert_eq!(convert(2.0_f64.powi(-24)), 0x0001);
+    assert_eq!(convert(3.0 * 2.0_f64.powi(-25)), 0x0002);
+    assert_eq!(convert(2.0_f64.powi(-25) + 2.0_f64.powi(-60)), 0x0001);
+    assert_eq!(convert(2.0_f64.powi(-25)), 0x0000);
+    assert_eq!(convert(-1e-300), 0x8000);
+    assert_eq!(convert(f64::MIN_POSITIVE / 2.0), 0x0000);
+    // the largest subnormal rounds up into the normal range
+    assert_eq!(convert(2.0_f64.powi(-14) - 2.0_f64.powi(-26)), 0x0400);
+    assert_eq!(convert(2.0_f64.powi(-14)), 0x0400);
+    // special values
+    assert_eq!(convert(0.0), 0x0000);
+    assert_eq!(convert(-0.0), 0x8000);
+    assert_eq!(convert(f64::INFINITY), 0x7c00);
+    assert_eq!(convert(f64::NEG_INFINITY), 0xfc00);
+    assert!(f16::from_bits(convert(f64::NAN)).is_nan());
+    // every value representable in half precision converts exactly
+    for bits in (0..0x7c00).chain(0x8000..0xfc00) {
+        let value = f16::from_bits(bits);
+        assert_eq!(convert(value.to_f64()), bits);
+    }
+}
+
+#[test]
+fn test_noise_buffer_f16_export() {
+    use half::f16;
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let path = &tmp_dir
+        .path()
+        .join("output.exr")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    let generator = Source::simplex(42).scale([0.05; 2]);
+    let buf = NoiseBuffer::<2, f16>::new([30, 20], &generator);
+    buf.write_exr(path).unwrap();
+    let image = exr::prelude::read_first_flat_layer_from_file(path).unwrap();
+    let layer = &image.layer_data;
+    assert_eq!(layer.size.width(), 20);
+    assert_eq!(layer.size.height(), 30);
+    assert_eq!(layer.channel_data.list.len(), 1);
+    let channel = &layer.channel_data.list[0];
+    assert_eq!(channel.name.to_string(), "Y");
+    match &channel.sample_data {
+        exr::prelude::FlatSamples::F16(samples) => assert_eq!(samples, &buf.buffer),
+        other => panic!("expected half samples, got {:?}", other),
+    }
+
+    // other element types are stored as single precision floats
+    let buf = NoiseBuffer::<2>::new([30, 20], &generator);
+    buf.write_exr(path).unwrap();
+    let image = exr::prelude::read_first_flat_layer_from_file(path).unwrap();
+    match &image.layer_data.channel_data.list[0].sample_data {
+        exr::prelude::FlatSamples::F32(samples) => {
+            assert!(samples
+                .iter()
+                .zip(&buf.buffer)
+                .all(|(&a, &b)| a == b as f32))
+        }
+        other => panic!("expected float samples, got {:?}", other),
+    }
+    let result = NoiseBuffer::<2, f32>::new([0, 20], &generator).write_exr(path);
+    assert!(matches!(
+        result,
+        Err(Error::InvalidParameter { name: "shape", .. })
+    ));
+}
+
+// =================================================================
+// test NoiseBuffer quantization
+// =================================================================
+#[test]
+fn test_noise_buffer_quantize_boundaries() {
+    let mut buf = NoiseBuffer::<1>::new([6], &Source::constant(0.0));
+    buf.buffer = vec![-1.0, 0.0, 1.0, 1.0000001, -1.5, f64::NAN];
+    // 0.0 maps to 127.5 and 32767.5 respectively, which are truncated
+    assert_eq!(buf.quantize_u8(), [0, 127, 255, 255, 0, 0]);
+    assert_eq!(buf.quantize_u16(), [0, 32767, 65535, 65535, 0, 0]);
+}
+
+#[test]
+fn test_noise_buffer_quantize_matches_visualizer() {
+    let generator = Source::simplex(42).scale([0.05; 2]);
+    let buf = NoiseBuffer::<2>::new([30, 20], &generator);
+    let quantized = buf.quantize_u8();
+    let visualizer = Visualizer::from(buf.clone());
+    for i in 0..30 {
+        for j in 0..20 {
+            assert_eq!(quantized[i * 20 + j], visualizer[&[i, j][..]]);
+        }
+    }
+    let quantized_f32 = NoiseBuffer::<2, f32>::new([30, 20], &generator).quantize_u8();
+    assert_eq!(quantized_f32.len(), quantized.len());
+}
+
+// =================================================================
+// test NoiseBuffer npy export
+// =================================================================
+fn parse_npy_header(bytes: &[u8]) -> (String, usize) {
+    assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+    let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+    let data_start = 10 + header_len;
+    assert_eq!(data_start % 64, 0);
+    assert_eq!(bytes[data_start - 1], b'\n');
+    let header = std::str::from_utf8(&bytes[10..data_start]).unwrap();
+    (header.trim_end().to_string(), data_start)
+}
+
+#[test]
+fn test_noise_buffer_write_npy() {
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let path = &tmp_dir
+        .path()
+        .join("noise.npy")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    let buf = NoiseBuffer::<3>::new([5, 4, 3], &Source::simplex(42).scale([0.1; 3]));
+    buf.write_npy(path).unwrap();
+    let bytes = std::fs::read(path).unwrap();
+    let (header, data_start) = parse_npy_header(&bytes);
+    assert_eq!(
+        header,
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (5, 4, 3), }"
+    );
+    assert_eq!(bytes.len(), data_start + 5 * 4 * 3 * 8);
+    let first = f64::from_le_bytes(bytes[data_start..data_start + 8].try_into().unwrap());
+    let last = f64::from_le_bytes(bytes[bytes.len() - 8..].try_into().unwrap());
+    assert_eq!(first, buf[[0, 0, 0]]);
+    assert_eq!(last, buf[[4, 3, 2]]);
+}
+
+#[test]
+fn test_noise_buffer_write_npy_f32() {
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let path = &tmp_dir
+        .path()
+        .join("noise.npy")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    let buf = NoiseBuffer::<1, f32>::new([7], &Source::simplex(42));
+    buf.write_npy(path).unwrap();
+    let bytes = std::fs::read(path).unwrap();
+    let (header, data_start) = parse_npy_header(&bytes);
+    assert_eq!(
+        header,
+        "{'descr': '<f4', 'fortran_order': False, 'shape': (7,), }"
+    );
+    assert_eq!(bytes.len(), data_start + 7 * 4);
+    let last = f32::from_le_bytes(bytes[bytes.len() - 4..].try_into().unwrap());
+    assert_eq!(last, buf[[6]]);
+}
+
+// =================================================================
+// test NoiseBuffer npy import
+// =================================================================
+fn write_npy_fixture(path: &str, header: &str, data: &[u8]) {
+    let mut header = header.to_string();
+    while !(10 + header.len() + 1).is_multiple_of(64) {
+        header.push(' ');
+    }
+    header.push('\n');
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend((header.len() as u16).to_le_bytes());
+    bytes.extend(header.as_bytes());
+    bytes.extend(data);
+    std::fs::write(path, bytes).unwrap();
+}
+
+#[test]
+fn test_noise_buffer_read_npy_round_trip() {
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let path = &tmp_dir
+        .path()
+        .join("noise.npy")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    let generator = Source::simplex(42).scale([0.1; 4]);
+    let buf = NoiseBuffer::<4>::new([3, 4, 5, 2], &generator);
+    buf.write_npy(path).unwrap();
+    let loaded = NoiseBuffer::<4>::read_npy(path).unwrap();
+    assert_eq!(loaded.shape, buf.shape);
+    assert_eq!(loaded.buffer, buf.buffer);
+    let buf = NoiseBuffer::<2, f32>::new([6, 7], &Source::simplex(42));
+    buf.write_npy(path).unwrap();
+    let loaded = NoiseBuffer::<2>::read_npy(path).unwrap();
+    assert_eq!(loaded[[5, 3]], buf[[5, 3]] as f64);
+}
+
+#[test]
+fn test_noise_buffer_read_npy_fortran_order() {
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let path = &tmp_dir
+        .path()
+        .join("mask.npy")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    // np.array([[1, 2, 3], [4, 5, 6]], dtype='<i2', order='F')
+    let data: Vec<u8> = [1_i16, 4, 2, 5, 3, 6]
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect();
+    write_npy_fixture(
+        path,
+        "{'descr': '<i2', 'fortran_order': True, 'shape': (2, 3), }",
+        &data,
+    );
+    let loaded = NoiseBuffer::<2>::read_npy(path).unwrap();
+    assert_eq!(loaded.shape, [2, 3]);
+    assert_eq!(loaded.buffer, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    write_npy_fixture(
+        path,
+        "{'descr': '|u1', 'fortran_order': False, 'shape': (4,), }",
+        &[0, 1, 128, 255],
+    );
+    let loaded = NoiseBuffer::<1>::read_npy(path).unwrap();
+    assert_eq!(loaded.buffer, [0.0, 1.0, 128.0, 255.0]);
+}
+
+#[test]
+fn test_noise_buffer_read_npy_errors() {
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let path = &tmp_dir
+        .path()
+        .join("bad.npy")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    let read_error = |header: &str, data: &[u8]| {
+        write_npy_fixture(path, header, data);
+        let err = NoiseBuffer::<2>::read_npy(path).unwrap_err();
+        assert!(matches!(err, Error::Encode { .. }), "{:?}", err);
+        err.to_string()
+    };
+    write_npy_fixture(
+        path,
+        "{'descr': '<c16', 'fortran_order': False, 'shape': (1, 1), }",
+        &[0; 16],
+    );
+    let err = NoiseBuffer::<2>::read_npy(path).unwrap_err();
+    assert!(matches!(err, Error::Unsupported { .. }), "{:?}", err);
+    assert!(err.to_string().contains("dtype '<c16'"));
+    let message = read_error(
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (2,), }",
+        &[0; 16],
+    );
+    assert!(message.contains("expected array with 2 dimensions"));
+    let message = read_error(
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 2), }",
+        &[0; 24],
+    );
+    assert!(message.contains("expected 32 bytes"));
+    let message = read_error("{'descr': '<f8', 'shape': (1, 1), }", &[0; 8]);
+    assert!(message.contains("missing key 'fortran_order'"));
+    std::fs::write(path, b"not a npy file").unwrap();
+    let err = NoiseBuffer::<2>::read_npy(path).unwrap_err();
+    assert!(matches!(err, Error::Encode { .. }), "{:?}", err);
+    std::fs::remove_file(path).unwrap();
+    let err = NoiseBuffer::<2>::read_npy(path).unwrap_err();
+    assert!(
+        matches!(
+            err,
+            Error::Io {
+                kind: std::io::ErrorKind::NotFound,
+                ..
+            }
+        ),
+        "{:?}",
+        err
+    );
+}
+
+// =================================================================
+// test NoiseBuffer obj export
+// =================================================================
+struct ObjMesh {
+    vertices: Vec<[f64; 3]>,
+    texture_coordinates: Vec<[f64; 2]>,
+    normals: Vec<[f64; 3]>,
+    faces: Vec<Vec<usize>>,
+}
+
+fn parse_obj(path: &str) -> ObjMesh {
+    let mut mesh = ObjMesh {
+        vertices: Vec::new(),
+        texture_coordinates: Vec::new(),
+        normals: Vec::new(),
+        faces: Vec::new(),
+    };
+    let parse = |fields: std::str::SplitWhitespace| {
+        fields
+            .map(|field| field.parse::<f64>().unwrap())
+            .collect::<Vec<_>>()
+    };
+    for line in std::fs::read_to_string(path).unwrap().lines() {
+        let mut fields = line.split_whitespace();
+        match fields.next() {
+            Some("v") => mesh.vertices.push(parse(fields).try_into().unwrap()),
+            Some("vt") => mesh
+                .texture_coordinates
+                .push(parse(fields).try_into().unwrap()),
+            Some("vn") => mesh.normals.push(parse(fields).try_into().unwrap()),
+            Some("f") => mesh.faces.push(
+                fields
+                    .map(|corner| {
+                        let indices = corner.split('/').collect::<Vec<_>>();
+                        assert!(indices.len() == 3 && indices.iter().all(|i| *i == indices[0]));
+                        indices[0].parse().unwrap()
+                    })
+                    .collect(),
+            ),
+            Some("#") | None => {}
+            Some(other) => panic!("unexpected statement {}", other),
+        }
+    }
+    mesh
+}
+
+#[test]
+fn test_noise_buffer_write_obj() {
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let path = &tmp_dir
+        .path()
+        .join("mesh.obj")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    let buf = NoiseBuffer::<2>::new([3, 3], &Source::custom(|[x, y]| x * x + 0.5 * y));
+    buf.write_obj(path, 1.5, 2.0).unwrap();
+    let mesh = parse_obj(path);
+    assert_eq!(mesh.vertices.len(), 9);
+    assert_eq!(mesh.texture_coordinates.len(), 9);
+    assert_eq!(mesh.normals.len(), 9);
+    assert_eq!(
+        mesh.faces,
+        vec![
+            vec![1, 2, 5, 4],
+            vec![2, 3, 6, 5],
+            vec![4, 5, 8, 7],
+            vec![5, 6, 9, 8]
+        ]
+    );
+    for x in 0..3 {
+        for y in 0..3 {
+            let index = x * 3 + y;
+            assert_eq!(
+                mesh.vertices[index],
+                [x as f64 * 1.5, buf[[x, y]] * 2.0, y as f64 * 1.5]
+            );
+            assert_eq!(
+                mesh.texture_coordinates[index],
+                [x as f64 / 2.0, y as f64 / 2.0]
+            );
+            let [nx, ny, nz] = mesh.normals[index];
+            assert!((nx * nx + ny * ny + nz * nz - 1.0).abs() < 1e-12);
+        }
+    }
+    // the height field at the center is 2x^2 + y, whose slopes per unit of distance are 8 / 3
+    // and 2 / 3 using central differences
+    let expected = [-8.0 / 3.0, 1.0, -2.0 / 3.0];
+    let norm = expected.iter().map(|c| c * c).sum::<f64>().sqrt();
+    for (actual, expected) in mesh.normals[4].iter().zip(expected) {
+        assert!((actual - expected / norm).abs() < 1e-12);
+    }
+    // faces wind counter-clockwise when viewed from above
+    for face in mesh.faces {
+        let [a, b, c] = [face[0], face[1], face[2]].map(|i| mesh.vertices[i - 1]);
+        let (u, v) = (
+            [b[0] - a[0], b[1] - a[1], b[2] - a[2]],
+            [c[0] - a[0], c[1] - a[1], c[2] - a[2]],
+        );
+        assert!(u[2] * v[0] - u[0] * v[2] > 0.0);
+    }
+}
+
+#[test]
+fn test_noise_buffer_write_obj_round_trip() {
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let path = &tmp_dir
+        .path()
+        .join("mesh.obj")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    let buf = NoiseBuffer::<2, f32>::new_mapped(
+        [20, 13],
+        [0.0; 2],
+        [0.1; 2],
+        &Source::simplex(42).fbm(3, 1.0, 2.0, 0.5),
+    );
+    buf.write_obj(path, 0.25, 10.0).unwrap();
+    let mesh = parse_obj(path);
+    assert_eq!(mesh.vertices.len(), 20 * 13);
+    assert_eq!(mesh.faces.len(), 19 * 12);
+    for (vertex, value) in mesh.vertices.iter().zip(&buf.buffer) {
+        assert_eq!(vertex[1], *value as f64 * 10.0);
+    }
+    assert!(mesh.normals.iter().all(|normal| normal[1] > 0.0));
+    assert_eq!(mesh.texture_coordinates.last(), Some(&[1.0, 1.0]));
+    // buffers too thin for faces still produce vertices
+    let line = NoiseBuffer::<2>::new([1, 4], &Source::constant(0.5));
+    line.write_obj(path, 1.0, 1.0).unwrap();
+    let mesh = parse_obj(path);
+    assert_eq!(mesh.vertices.len(), 4);
+    assert!(mesh.faces.is_empty());
+    assert!(mesh.normals.iter().all(|normal| *normal == [0.0, 1.0, 0.0]));
+}
+
+// =================================================================
+// test NoiseBuffer ndarray interoperability
+// =================================================================
+#[test]
+fn test_noise_buffer_ndarray_round_trip() {
+    let generator = Source::simplex(42).scale([0.1; 3]);
+    let buf = NoiseBuffer::<3>::new([5, 4, 3], &generator);
+    let array = ndarray::Array3::from(buf.clone());
+    assert_eq!(array.shape(), [5, 4, 3]);
+    assert_eq!(array[[4, 1, 2]], buf[[4, 1, 2]]);
+    let round_trip = NoiseBuffer::<3>::from(array);
+    assert_eq!(round_trip.shape, buf.shape);
+    assert_eq!(round_trip.offsets, buf.offsets);
+    assert_eq!(round_trip.buffer, buf.buffer);
+    let array = ndarray::ArrayD::from(buf.clone());
+    assert_eq!(array.shape(), [5, 4, 3]);
+    assert_eq!(array[[2, 3, 1].as_slice()], buf[[2, 3, 1]]);
+}
+
+#[test]
+fn test_noise_buffer_ndarray_views() {
+    let generator = Source::simplex(42).scale([0.1; 2]);
+    let buf = NoiseBuffer::<2>::new([6, 5], &generator);
+    let view = buf.as_array_view();
+    assert_eq!(view.shape(), [6, 5]);
+    assert_eq!(view[[5, 2]], buf[[5, 2]]);
+    // transposed arrays are not in standard layout and are copied in logical order
+    let transposed = view.t();
+    let from_view = NoiseBuffer::<2>::from_array(transposed);
+    assert_eq!(from_view.shape, [5, 6]);
+    assert_eq!(from_view[[2, 5]], buf[[5, 2]]);
+    let from_owned = NoiseBuffer::<2>::from(transposed.to_owned().reversed_axes());
+    assert_eq!(from_owned.buffer, buf.buffer);
+    // sliced arrays may be in standard layout, but only cover a part of their storage
+    let sliced = ndarray::Array2::from(buf.clone()).slice_move(ndarray::s![1..4, ..]);
+    let from_slice = NoiseBuffer::<2>::from(sliced);
+    assert_eq!(from_slice.shape, [3, 5]);
+    assert_eq!(from_slice.buffer, buf.buffer[5..20]);
+}
+
+// =================================================================
+// test Visualizer image interoperability
+// =================================================================
+#[test]
+fn test_visualizer_to_image_matches_png() {
+    let generator = Source::simplex(42).scale([0.05; 2]);
+    let visualizer = Visualizer::<2>::new([30, 20], &generator);
+    let image = visualizer.to_image();
+    assert_eq!((image.width(), image.height()), (20, 30));
+    let png = visualizer.write_to_vec().unwrap();
+    let decoded = image::load_from_memory(&png).unwrap().into_luma8();
+    assert_eq!(image.into_raw(), decoded.into_raw());
+    let generator = Source::simplex(42).scale([0.05; 3]);
+    let visualizer = Visualizer::<3>::new([20, 20, 20], &generator);
+    let png = visualizer.write_to_vec().unwrap();
+    let decoded = image::load_from_memory(&png).unwrap().into_luma8();
+    assert_eq!(visualizer.to_image().into_raw(), decoded.into_raw());
+}
+
+#[test]
+fn test_visualizer_from_buffer_with_range() {
+    let buf = NoiseBuffer::<1> {
+        shape: [4],
+        offsets: [1],
+        buffer: vec![2.0, 3.0, 4.0, 5.0],
+    };
+    let visualizer = Visualizer::from_buffer_with_range(buf.clone(), (2.0, 4.0));
+    let pixels: Vec<u8> = (0..4).map(|i| visualizer[&[i][..]]).collect();
+    assert_eq!(pixels, [0, 127, 255, 255]);
+    let visualizer = Visualizer::from_buffer_with_range(buf, (f64::NEG_INFINITY, 4.0));
+    assert_eq!(visualizer[&[0][..]], 255);
+}
+
+#[test]
+fn test_noise_buffer_to_luma16() {
+    let generator = Source::simplex(42).scale([0.05; 2]);
+    let buf = NoiseBuffer::<2>::new([30, 20], &generator);
+    let image = buf.to_luma16();
+    assert_eq!((image.width(), image.height()), (20, 30));
+    assert_eq!(image.get_pixel(7, 13).0[0], buf.quantize_u16()[13 * 20 + 7]);
+    assert_eq!(image.into_raw(), buf.quantize_u16());
+}
+
+// =================================================================
+// test memory-mapped NoiseBuffer
+// =================================================================
+#[test]
+fn test_mmap_noise_buffer_persistence() {
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let path = &tmp_dir
+        .path()
+        .join("noise.bin")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    let generator = Source::simplex(42).scale([0.1; 3]);
+    let expected = NoiseBuffer::<3, f32>::new([20, 15, 10], &generator);
+    let mut buf = NoiseBuffer::<3, f32>::new_mmapped(path, [20, 15, 10], &generator).unwrap();
+    assert_eq!(buf.as_slice(), expected.buffer.as_slice());
+    buf[[3, 4, 5]] = 0.5;
+    buf.flush().unwrap();
+    drop(buf);
+    let buf = NoiseBuffer::<3, f32>::open_mmapped(path, [20, 15, 10]).unwrap();
+    assert_eq!(buf[[3, 4, 5]], 0.5);
+    assert_eq!(buf[[19, 14, 9]], expected[[19, 14, 9]]);
+    assert_eq!(buf[[0, 0, 0]], expected[[0, 0, 0]]);
+    assert_eq!(buf.to_buffer().shape, [20, 15, 10]);
+}
+
+#[test]
+fn test_mmap_noise_buffer_par_refill() {
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let path = &tmp_dir
+        .path()
+        .join("noise.bin")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    let generator = Source::simplex(42).scale([0.1; 2]);
+    let buf = NoiseBuffer::<2>::par_new_mmapped(path, [40, 30], &generator).unwrap();
+    assert_eq!(
+        buf.as_slice(),
+        NoiseBuffer::<2>::new([40, 30], &generator).buffer
+    );
+}
+
+#[test]
+fn test_mmap_noise_buffer_errors() {
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let path = &tmp_dir
+        .path()
+        .join("noise.bin")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    let result = NoiseBuffer::<2>::open_mmapped(path, [10, 10]);
+    assert!(matches!(
+        result.unwrap_err(),
+        Error::Io {
+            kind: std::io::ErrorKind::NotFound,
+            ..
+        }
+    ));
+    NoiseBuffer::<2>::new_mmapped(path, [10, 10], &Source::simplex(42)).unwrap();
+    let result = NoiseBuffer::<2>::open_mmapped(path, [10, 11]);
+    assert_eq!(
+        result.unwrap_err(),
+        Error::ShapeMismatch {
+            expected: vec![880],
+            found: vec![800],
+        }
+    );
+    let result = NoiseBuffer::<2>::open_mmapped(path, [usize::MAX, 2]);
+    assert!(matches!(result.unwrap_err(), Error::InvalidShape { .. }));
+    let path = &tmp_dir
+        .path()
+        .join("missing")
+        .join("noise.bin")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    assert!(NoiseBuffer::<2>::new_mmapped(path, [10, 10], &Source::simplex(42)).is_err());
+}
+
+// =================================================================
+// test NoiseBuffer region refilling
+// =================================================================
+#[test]
+fn test_noise_buffer_refill_region_same_generator() {
+    let generator = Source::simplex(42).scale([0.1; 3]);
+    let buf = NoiseBuffer::<3>::new([12, 10, 8], &generator);
+    let mut refilled = buf.clone();
+    refilled.refill_region(&generator, [3, 2, 1], [5, 4, 3]);
+    assert_eq!(refilled.buffer, buf.buffer);
+
+    let generator = Source::simplex(42).fbm(2, 0.1, 2.0, 0.5);
+    let (grid_origin, step) = ([4.0, -2.0], [0.5, 0.25]);
+    let buf = NoiseBuffer::<2>::new_mapped([12, 10], grid_origin, step, &generator);
+    let mut refilled = buf.clone();
+    refilled.refill_region(&Source::constant(0.0), [2, 3], [6, 6]);
+    refilled.refill_region_mapped(&generator, [2, 3], [6, 6], grid_origin, step);
+    assert_eq!(refilled.buffer, buf.buffer);
+}
+
+#[test]
+fn test_noise_buffer_refill_region_different_generator() {
+    let generator = Source::simplex(42).scale([0.1; 2]);
+    let buf = NoiseBuffer::<2>::new([12, 10], &generator);
+    let mut refilled = buf.clone();
+    // the region exceeds the buffer along the first axis and is clipped
+    refilled.refill_region(&Source::constant(2.0), [8, 3], [10, 4]);
+    for i in 0..12 {
+        for j in 0..10 {
+            if (8..12).contains(&i) && (3..7).contains(&j) {
+                assert_eq!(refilled[[i, j]], 2.0);
+            } else {
+                assert_eq!(refilled[[i, j]], buf[[i, j]]);
+            }
+        }
+    }
+    refilled.refill_region(&Source::constant(3.0), [12, 0], [5, 5]);
+    refilled.refill_region(&Source::constant(3.0), [0, 0], [0, 5]);
+    assert!(!refilled.buffer.contains(&3.0));
+}
+
+// =================================================================
+// test ChunkCache
+// =================================================================
+#[test]
+fn test_chunk_cache_hits_and_eviction() {
+    let cache = ChunkCache::new(Source::simplex(42), [8, 8], 2);
+    let a = cache.get_or_generate([0, 0]);
+    cache.get_or_generate([0, 1]);
+    let a_again = cache.get_or_generate([0, 0]);
+    assert!(std::sync::Arc::ptr_eq(&a, &a_again));
+    assert_eq!((cache.hits(), cache.misses()), (1, 2));
+    // [0, 1] is the least recently used chunk and is evicted
+    cache.get_or_generate([1, 0]);
+    assert_eq!(cache.len(), 2);
+    assert!(cache.contains([0, 0]));
+    assert!(!cache.contains([0, 1]));
+    assert!(cache.contains([1, 0]));
+    cache.get_or_generate([0, 1]);
+    assert_eq!((cache.hits(), cache.misses()), (1, 4));
+    assert!(!cache.contains([0, 0]));
+    assert!(cache.invalidate([1, 0]));
+    assert!(!cache.invalidate([1, 0]));
+    assert_eq!(cache.len(), 1);
+    cache.clear();
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_chunk_cache_continuity() {
+    let generator = Source::simplex(42).scale([0.05; 2]);
+    let cache = ChunkCache::new(generator.clone(), [8, 6], 16);
+    let full = NoiseBuffer::<2>::new_mapped([16, 12], [-8.0, -6.0], [1.0, 1.0], &generator);
+    for cx in -1..=0 {
+        for cy in -1..=0 {
+            let chunk = cache.get_or_generate([cx, cy]);
+            for i in 0..8 {
+                for j in 0..6 {
+                    let index = [(cx + 1) as usize * 8 + i, (cy + 1) as usize * 6 + j];
+                    assert_eq!(chunk[[i, j]], full[index]);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_chunk_cache_index_overflow() {
+    let cache = ChunkCache::new(Source::simplex(42), [6, 6], 4);
+    let err = cache
+        .try_get_or_generate([i64::MAX / 6 + 1, 0])
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::InvalidParameter {
+            name: "chunk_coord",
+            ..
+        }
+    ));
+    // the last grid index of the chunk overflows, while its first one does not
+    assert!(cache.try_get_or_generate([i64::MAX / 6, 0]).is_err());
+    assert!(cache.try_get_or_generate([i64::MIN / 6, 0]).is_ok());
+    assert_eq!((cache.len(), cache.misses()), (1, 1));
+}
+
+#[test]
+fn test_chunk_cache_threads() {
+    let cache = ChunkCache::new(Source::simplex(42), [4, 4, 4], 64);
+    std::thread::scope(|scope| {
+        for t in 0..4 {
+            let cache = &cache;
+            scope.spawn(move || {
+                for c in 0..8 {
+                    cache.get_or_generate([c, t % 2, 0]);
+                }
+            });
+        }
+    });
+    assert_eq!(cache.len(), 16);
+    assert_eq!(cache.hits() + cache.misses(), 32);
+}
+
+// =================================================================
+// test NoiseBuffer chunk filling
+// =================================================================
+#[test]
+fn test_noise_buffer_fill_chunk_mosaic() {
+    let generator = Source::simplex(42).fbm(3, 0.1, 2.0, 0.5);
+    let cell_size = 0.37;
+    for full_coord in [-1, 0] {
+        let full = NoiseBuffer::<2>::fill_chunk(&generator, [full_coord; 2], [16, 24], cell_size);
+        for cx in 0..4 {
+            for cy in 0..4 {
+                let chunk_coord = [full_coord * 4 + cx, full_coord * 4 + cy];
+                let chunk =
+                    NoiseBuffer::<2>::fill_chunk(&generator, chunk_coord, [4, 6], cell_size);
+                for i in 0..4 {
+                    for j in 0..6 {
+                        let index = [cx as usize * 4 + i, cy as usize * 6 + j];
+                        assert_eq!(chunk[[i, j]], full[index]);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_noise_buffer_fill_chunk_points() {
+    let generator = Source::simplex(42);
+    let chunk = NoiseBuffer::<3>::fill_chunk(&generator, [-3, 0, 2], [5, 5, 5], 2.0);
+    assert_eq!(
+        chunk[[1, 2, 3]],
+        generator.sample([(-15.0 + 1.0) * 2.0, 2.0 * 2.0, (10.0 + 3.0) * 2.0])
+    );
+}
+
+#[test]
+fn test_noise_buffer_fill_chunk_index_overflow() {
+    let generator = Source::simplex(42);
+    // the last grid index of the chunk [0, i64::MAX / 6] overflows, while its first one does not
+    for chunk_coord in [
+        [0, i64::MAX / 6 + 1],
+        [0, i64::MIN / 6 - 1],
+        [0, i64::MAX / 6],
+    ] {
+        let result = NoiseBuffer::<2>::try_fill_chunk(&generator, chunk_coord, [4, 6], 1.0);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidParameter {
+                name: "chunk_coord",
+                ..
+            })
+        ));
+    }
+    let chunk = NoiseBuffer::<2>::try_fill_chunk(&generator, [i64::MIN / 4, 0], [4, 4], 1.0);
+    assert_eq!(
+        chunk.unwrap()[[1, 0]],
+        generator.sample([(i64::MIN + 1) as f64, 0.0])
+    );
+}
+
+#[test]
+#[should_panic(expected = "overflow i64")]
+fn test_noise_buffer_fill_chunk_index_overflow_panics() {
+    NoiseBuffer::<2>::fill_chunk(&Source::simplex(42), [i64::MAX, 0], [4, 4], 1.0);
+}
+
+#[test]
+fn test_noise_buffer_fill_lattice() {
+    let generator = Source::<3>::value(42);
+    let origin = [-7, 250, 3];
+    let buf = NoiseBuffer::<3, f32>::fill_lattice(&generator, origin, [9, 10, 11]);
+    let expected = NoiseBuffer::<3, f32>::new_mapped(
+        [9, 10, 11],
+        origin.map(|x| x as f64),
+        [1.0; 3],
+        &generator,
+    );
+    assert_eq!(buf.buffer, expected.buffer);
+    let generator = Source::<2>::simplex(42).fbm(3, 0.1, 2.0, 0.5);
+    let buf = NoiseBuffer::<2>::fill_lattice(&generator, [-3, 5], [4, 6]);
+    assert_eq!(buf[[1, 2]], generator.sample([-2.0, 7.0]));
+}
+
+// =================================================================
+// test density volumes
+// =================================================================
+fn sphere_sdf(radius: f64) -> impl Generator3D {
+    Source::custom(move |[x, y, z]| (x * x + y * y + z * z).sqrt() - radius)
+}
+
+#[test]
+fn test_density_volume_block_bounds() {
+    let generator = sphere_sdf(7.0);
+    let volume = generator.fill_density([-10, -12, -9], 0.75, [30, 33, 17]);
+    assert_eq!(volume.shape(), [30, 33, 17]);
+    assert_eq!(volume.block_shape(), [4, 4, 2]);
+    for block in [[0, 0, 0], [1, 2, 1], [3, 3, 1], [2, 1, 0]] {
+        // the last blocks are shrunk to the volume, and neighbouring blocks share a face
+        let range = |i: usize| {
+            let start = block[i] * DensityVolume::BLOCK_SIZE;
+            start..(start + DensityVolume::BLOCK_SIZE + 1).min(volume.shape()[i])
+        };
+        let (mut min, mut max) = (f32::INFINITY, f32::NEG_INFINITY);
+        for x in range(0) {
+            for y in range(1) {
+                for z in range(2) {
+                    let position = volume.position([x, y, z]);
+                    let density = volume.densities()[[x, y, z]];
+                    assert_eq!(density, generator.sample(position) as f32);
+                    min = min.min(density);
+                    max = max.max(density);
+                }
+            }
+        }
+        assert_eq!(volume.block_bounds(block), (min, max));
+    }
+}
+
+#[test]
+fn test_density_volume_classify() {
+    let radius = 20.0;
+    let volume = sphere_sdf(radius).fill_density([-32, -32, -32], 1.0, [65, 65, 65]);
+    assert_eq!(volume.block_shape(), [8, 8, 8]);
+    let mut counts = [0; 3];
+    for bx in 0..8 {
+        for by in 0..8 {
+            for bz in 0..8 {
+                let block = [bx, by, bz];
+                // nearest and farthest distance of the block to the center of the sphere
+                let (mut near, mut far) = (0.0_f64, 0.0_f64);
+                for component in block {
+                    let start = -32.0 + (component * 8) as f64;
+                    let end = start + 8.0;
+                    near += if start > 0.0 {
+                        start * start
+                    } else if end < 0.0 {
+                        end * end
+                    } else {
+                        0.0
+                    };
+                    far += start.abs().max(end.abs()).powi(2);
+                }
+                let class = volume.classify(block, 0.0);
+                if near.sqrt() > radius {
+                    assert_eq!(class, BlockClass::Above);
+                    counts[0] += 1;
+                } else if far.sqrt() < radius {
+                    assert_eq!(class, BlockClass::Below);
+                    counts[1] += 1;
+                } else {
+                    assert_ne!(class, BlockClass::Below);
+                    counts[2] += 1;
+                }
+            }
+        }
+    }
+    assert!(counts.iter().all(|&count| count > 0));
+    // shifting the threshold beyond all densities makes every block uniform
+    assert_eq!(
+        volume.classify([1, 3, 3], -radius as f32 - 1.0),
+        BlockClass::Above
+    );
+    assert_eq!(volume.classify([1, 3, 3], 100.0), BlockClass::Below);
+    assert_eq!(volume.classify([1, 3, 3], 0.0), BlockClass::Mixed);
+}
+
+#[test]
+fn test_density_volume_border_welding() {
+    let generator = Source::simplex(42).fbm(4, 0.05, 2.0, 0.5);
+    let cell_size = 0.3;
+    for origin in [[0, 0, 0], [-16, -32, 16]] {
+        let volume = generator.fill_density(origin, cell_size, [17, 17, 17]);
+        let neighbours = [
+            generator.fill_density(
+                [origin[0] + 16, origin[1], origin[2]],
+                cell_size,
+                [17, 17, 17],
+            ),
+            generator.fill_density(
+                [origin[0], origin[1] + 16, origin[2]],
+                cell_size,
+                [17, 17, 17],
+            ),
+            generator.fill_density(
+                [origin[0], origin[1], origin[2] + 16],
+                cell_size,
+                [17, 17, 17],
+            ),
+        ];
+        for i in 0..17 {
+            for j in 0..17 {
+                let densities = volume.densities();
+                assert_eq!(densities[[16, i, j]], neighbours[0].densities()[[0, i, j]]);
+                assert_eq!(densities[[i, 16, j]], neighbours[1].densities()[[i, 0, j]]);
+                assert_eq!(densities[[i, j, 16]], neighbours[2].densities()[[i, j, 0]]);
+            }
+        }
+        // the volume matches the chunk with the same global grid indices
+        let chunk_coord = origin.map(|component| component / 16);
+        let chunk = NoiseBuffer::<3, f32>::fill_chunk(&generator, chunk_coord, [16; 3], cell_size);
+        for i in 0..16 {
+            for j in 0..16 {
+                for k in 0..16 {
+                    assert_eq!(volume.densities()[[i, j, k]], chunk[[i, j, k]]);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_density_volume_degenerate_shapes() {
+    let generator = sphere_sdf(1.0);
+    assert_eq!(
+        generator
+            .fill_density([0; 3], 1.0, [1, 9, 10])
+            .block_shape(),
+        [0, 1, 2]
+    );
+    assert_eq!(
+        generator.fill_density([0; 3], 1.0, [0, 0, 0]).block_shape(),
+        [0, 0, 0]
+    );
+}
+
+#[test]
+#[should_panic(expected = "out of bounds for block grid")]
+fn test_density_volume_block_out_of_bounds() {
+    sphere_sdf(1.0)
+        .fill_density([0; 3], 1.0, [9, 9, 9])
+        .classify([1, 0, 0], 0.0);
+}
+
+// =================================================================
+// test NoiseBuffer statistics
+// =================================================================
+#[test]
+fn test_noise_buffer_stats_constant() {
+    let buf = NoiseBuffer::<3, f32>::new([7, 5, 3], &Source::constant(0.375));
+    let stats = buf.stats();
+    assert_eq!(stats.min, 0.375);
+    assert_eq!(stats.max, 0.375);
+    assert_eq!(stats.mean, 0.375);
+    assert_eq!(stats.std_dev, 0.0);
+    let histogram = buf.histogram(8);
+    assert_eq!(histogram, [0, 0, 0, 0, 0, 105, 0, 0]);
+}
+
+#[test]
+fn test_noise_buffer_stats_ramp() {
+    // 8 values evenly spaced from -1.0 to 0.75 in steps of 0.25
+    let buf = NoiseBuffer::<1>::new([8], &Source::custom(|[x]| -1.0 + x * 0.25));
+    let stats = buf.stats();
+    assert_eq!(stats.min, -1.0);
+    assert_eq!(stats.max, 0.75);
+    assert_eq!(stats.mean, -0.125);
+    assert_eq!(stats.std_dev, 0.328125_f64.sqrt());
+    assert_eq!(buf.histogram(8), [1; 8]);
+    assert_eq!(buf.histogram(4), [2; 4]);
+    assert_eq!(buf.histogram(1), [8]);
+}
+
+#[test]
+fn test_noise_buffer_histogram_out_of_range() {
+    let values = [-3.0, -1.0, f64::NAN, 1.0, 7.0];
+    let buf = NoiseBuffer::<1>::new([5], &Source::custom(|[x]| values[x as usize]));
+    assert_eq!(buf.histogram(4), [2, 0, 0, 2]);
+    let stats = buf.stats();
+    assert_eq!((stats.min, stats.max, stats.mean), (-3.0, 7.0, 1.0));
+    assert!(buf.histogram(0).is_empty());
+}
+
+#[test]
+fn test_analyze_matches_buffer() {
+    let generator = Source::simplex(42).fbm(4, 0.05, 2.0, 0.5);
+    let analysis = generator.analyze([0.0; 2], [63.0; 2], 64, 16);
+    let buf = NoiseBuffer::<2>::new([64, 64], &generator);
+    let stats = buf.stats();
+    assert_eq!(analysis.histogram, buf.histogram(16));
+    assert_eq!(analysis.stats.min, stats.min);
+    assert_eq!(analysis.stats.max, stats.max);
+    assert!((analysis.stats.mean - stats.mean).abs() < 1e-12);
+    assert!((analysis.stats.std_dev - stats.std_dev).abs() < 1e-12);
+    assert_eq!(analysis.to_string().lines().count(), 17);
+}
+
+// =================================================================
+// test power spectrum analysis
+// =================================================================
+fn white_noise_buffer(shape: [usize; 2]) -> NoiseBuffer<2> {
+    let mut state = 0x853c_49e6_748f_ea9b_u64;
+    let mut buf = NoiseBuffer::<2>::new(shape, &Source::constant(0.0));
+    for value in buf.buffer.iter_mut() {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        *value = (state >> 11) as f64 / (1_u64 << 53) as f64 * 2.0 - 1.0;
+    }
+    buf
+}
+
+#[test]
+fn test_spectrum_white_noise() {
+    for shape in [[128, 128], [48, 60]] {
+        let spectrum = spectrum2d(&white_noise_buffer(shape));
+        assert_eq!(spectrum.len(), shape[0].min(shape[1]) / 2);
+        let beta = fit_spectral_slope(&spectrum);
+        assert!(beta.abs() < 0.2, "white noise has spectral slope {}", beta);
+    }
+}
+
+#[test]
+fn test_spectrum_sinusoid_peak() {
+    let generator = Source::custom(|[x, _]| (2.0 * std::f64::consts::PI * x * 8.0 / 64.0).cos());
+    let spectrum = spectrum2d(&NoiseBuffer::<2>::new([64, 64], &generator));
+    let (frequency, _) = spectrum
+        .iter()
+        .copied()
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap();
+    assert_eq!(frequency, 8.0 / 64.0);
+}
+
+#[test]
+fn test_spectrum_sinusoid_peak_non_power_of_two() {
+    // 60 and 90 are not powers of two, such that both axes use the chirp based transform
+    let generator = Source::custom(|[x, y]| {
+        (2.0 * std::f64::consts::PI * (x * 6.0 / 60.0 + y * 9.0 / 90.0)).cos()
+    });
+    let spectrum = spectrum2d(&NoiseBuffer::<2>::new([60, 90], &generator));
+    let (frequency, _) = spectrum
+        .iter()
+        .copied()
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap();
+    let expected = ((6.0_f64 / 60.0).powi(2) + (9.0_f64 / 90.0).powi(2)).sqrt();
+    assert!((frequency - expected).abs() <= 0.5 / 60.0);
+}
+
+#[test]
+fn test_spectrum_power_law() {
+    // synthesize a field with power 1 / f^beta by summing sinusoids of every lattice frequency
+    let size = 64;
+    let beta = 2.0;
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut components = Vec::new();
+    for kx in -(size as i64) / 2..size as i64 / 2 {
+        for ky in 0..size as i64 / 2 {
+            let f = ((kx * kx + ky * ky) as f64).sqrt() / size as f64;
+            if f == 0.0 || f > 0.5 {
+                continue;
+            }
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let phase = (state >> 11) as f64 / (1_u64 << 53) as f64 * std::f64::consts::TAU;
+            components.push((kx as f64, ky as f64, f.powf(-beta / 2.0), phase));
+        }
+    }
+    let generator = Source::custom(|[x, y]| {
+        components
+            .iter()
+            .map(|(kx, ky, amplitude, phase)| {
+                let angle = std::f64::consts::TAU * (kx * x + ky * y) / size as f64;
+                amplitude * (angle + phase).cos()
+            })
+            .sum()
+    });
+    let spectrum = spectrum2d(&NoiseBuffer::<2>::new([size, size], &generator));
+    let fitted = fit_spectral_slope(&spectrum);
+    assert!(
+        (fitted - beta).abs() < 0.25,
+        "expected spectral slope {}, found {}",
+        beta,
+        fitted
+    );
+}
+
+#[test]
+fn test_fit_spectral_slope_degenerate() {
+    assert!(fit_spectral_slope(&[]).is_nan());
+    assert!(fit_spectral_slope(&[(0.1, 1.0)]).is_nan());
+    assert!(fit_spectral_slope(&[(0.1, 1.0), (0.1, 2.0)]).is_nan());
+    assert_eq!(
+        fit_spectral_slope(&[(0.1, 1.0), (0.0, 5.0), (0.2, 1.0)]),
+        0.0
+    );
+}
+
+// =================================================================
+// test tileability assertions
+// =================================================================
+#[test]
+fn test_assert_tileable_lattice_sources() {
+    assert_tileable(&Source::perlin(42), [256.0, 256.0], 64, 1e-9);
+    assert_tileable(&Source::improved_perlin(42), [256.0, 256.0], 64, 1e-9);
+    assert_tileable(&Source::value(42), [256.0, 256.0], 64, 1e-9);
+    assert_tileable(&Source::worley(42), [256.0, 256.0], 64, 1e-9);
+    assert_tileable_3d(&Source::perlin(42), [256.0; 3], 16, 1e-9);
+    assert_tileable_3d(
+        &Source::value(42).fbm(3, 1.0, 2.0, 0.5),
+        [256.0; 3],
+        16,
+        1e-9,
+    );
+}
+
+#[test]
+#[should_panic(expected = "generator does not tile")]
+fn test_assert_tileable_simplex() {
+    assert_tileable(&Source::simplex(42), [256.0, 256.0], 64, 1e-3);
+}
+
+#[test]
+#[should_panic(expected = "generator does not tile")]
+fn test_assert_tileable_3d_wrong_period() {
+    assert_tileable_3d(&Source::perlin(42), [256.0, 256.0, 128.0], 16, 1e-3);
+}
+
+#[test]
+#[should_panic(expected = "generator does not tile")]
+fn test_assert_tileable_matching_edges_only() {
+    // the values coincide on opposite edges, but the slopes across the seam do not
+    let generator =
+        Source::custom(|[x, y]: [f64; 2]| x * (256.0 - x) * y * (256.0 - y) / 256.0_f64.powi(4));
+    assert_tileable(&generator, [256.0, 256.0], 64, 1e-9);
+}
+
+#[test]
+#[should_panic(expected = "differ by NaN")]
+fn test_assert_tileable_nan() {
+    assert_tileable(&Source::constant(f64::NAN), [256.0, 256.0], 8, 1e-9);
+}
+
+// =================================================================
+// test generator descriptions
+// =================================================================
+#[test]
+fn test_describe_tree() {
+    let generator = Source::<3>::simplex(42)
+        .fbm(6, 1.0, 2.0, 0.5)
+        .select(
+            Source::perlin(43).with_large_coordinates(),
+            Source::worley(44).scale([0.5, 0.25, 1.0]),
+            -0.5,
+            0.5,
+        )
+        .displace_z(Source::value(45).mul(2.0))
+        .clamp(-1.0, 1.0);
+    let description = generator.describe();
+    assert_eq!(description.node_count(), 10);
+    assert_eq!(description.name(), "Clamp");
+    assert_eq!(description.param("max"), Some("1"));
+    let displace = description.child("source").unwrap();
+    assert_eq!(displace.param("axis"), Some("2"));
+    assert_eq!(
+        displace.child("displacement").unwrap().header(),
+        "Mul(scale=2)"
+    );
+    let select = displace.child("source").unwrap();
+    assert_eq!(
+        select.params(),
+        &[
+            ("selection_min", "-0.5".to_string()),
+            ("selection_max", "0.5".to_string())
+        ]
+    );
+    let roles = select
+        .children()
+        .iter()
+        .map(|(role, _)| *role)
+        .collect::<Vec<_>>();
+    assert_eq!(roles, ["a", "b", "control"]);
+    assert_eq!(
+        select.child("b").unwrap().header(),
+        "Perlin(seed=43, large_coordinates=true)"
+    );
+    assert_eq!(
+        select.child("control").unwrap().header(),
+        "Scale(scale=[0.5, 0.25, 1])"
+    );
+    assert_eq!(
+        select.child("a").unwrap().header(),
+        "Fbm(octaves=6, frequency=1, lacunarity=2, persistence=0.5)"
+    );
+}
+
+#[test]
+fn test_describe_display() {
+    let generator = Source::<2>::simplex(42)
+        .rotate([0.5])
+        .sum(Source::checkerboard().lambda(|x| x * 0.5))
+        .powi(2);
+    assert_eq!(
+        generator.to_string(),
+        "\
+Pow(exponent=2)
+└─ Sum
+   ├─ a: Rotate(rotation=[0.5])
+   │  └─ Simplex(seed=42)
+   └─ b: Lambda(lambda=<closure>)
+      └─ Checkerboard"
+    );
+    assert_eq!(generator.to_string(), generator.describe().to_string());
+}
+
+#[test]
+fn test_describe_custom() {
+    struct Plane;
+
+    impl Generator<2> for Plane {
+        fn sample(&self, point: [f64; 2]) -> f64 {
+            point[0]
+        }
+    }
+
+    let generator = Plane.add(1.0);
+    assert_eq!(generator.to_string(), "Add(offset=1)\n└─ Plane");
+    let generator = Source::custom(|[x, _]: [f64; 2]| x);
+    assert_eq!(generator.to_string(), "Custom(noise=<closure>)");
+}
+
+// =================================================================
+// test error reporting
+// =================================================================
+#[test]
+fn test_error_invalid_clamp_bounds() {
+    let result = Source::<2>::simplex(42).try_clamp(0.5, -0.5);
+    assert!(matches!(
+        result,
+        Err(Error::InvalidParameter { name: "max", .. })
+    ));
+    let result = Source::<2>::simplex(42).try_clamp(f64::NAN, 0.5);
+    assert!(matches!(
+        result,
+        Err(Error::InvalidParameter { name: "min", .. })
+    ));
+    let generator = Source::<2>::simplex(42).try_clamp(-0.5, 0.5).unwrap();
+    assert_eq!(
+        generator.sample([1.5, 2.5]),
+        Source::<2>::simplex(42).sample([1.5, 2.5]).clamp(-0.5, 0.5)
+    );
+
+    // recipe errors convert into the crate-wide error type
+    let recipe = Source::<2>::simplex(42)
+        .clamp(0.5, -0.5)
+        .to_recipe()
+        .unwrap();
+    let error = Error::from(recipe.build::<2>().unwrap_err());
+    assert_eq!(
+        error,
+        Error::InvalidParameter {
+            name: "max",
+            reason: "must not be less than min, in clamp node at root".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_error_zero_size_buffer() {
+    let visualizer = Visualizer::<2>::new([0, 10], &Source::simplex(42));
+    assert!(matches!(
+        visualizer.write_to_vec(),
+        Err(Error::InvalidParameter { name: "shape", .. })
+    ));
+    let buf =
+        MultiNoiseBuffer::<2, 3>::new([4, 0], &std::array::from_fn(|i| Source::simplex(i as u64)));
+    assert!(matches!(
+        buf.write_rgb_png("unused.png"),
+        Err(Error::InvalidParameter { name: "shape", .. })
+    ));
+    let buf = NoiseBuffer::<2>::new([0, 4], &Source::simplex(42));
+    assert!(matches!(
+        BufferSource::new(buf, [0.0; 2], [1.0; 2]),
+        Err(Error::InvalidParameter { name: "buffer", .. })
+    ));
+}
+
+#[test]
+fn test_error_unwritable_path() {
+    let tmp_dir = TempDir::new("libnoise").unwrap();
+    let path = &tmp_dir
+        .path()
+        .join("missing")
+        .join("noise.png")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    let is_not_found = |result: Result<(), Error>| {
+        matches!(
+            result,
+            Err(Error::Io {
+                kind: std::io::ErrorKind::NotFound,
+                ..
+            })
+        )
+    };
+    let generator = Source::simplex(42);
+    assert!(is_not_found(
+        Visualizer::<2>::new([8, 8], &generator).write_to_file(path)
+    ));
+    assert!(is_not_found(
+        Visualizer::<4>::new([8, 8, 8, 8], &Source::simplex(42)).write_to_file(path)
+    ));
+    let buf = NoiseBuffer::<2>::new([8, 8], &generator);
+    assert!(is_not_found(buf.write_npy(path)));
+    assert!(is_not_found(buf.write_obj(path, 1.0, 1.0)));
+    assert!(matches!(
+        Source::image_file(path),
+        Err(Error::Io {
+            kind: std::io::ErrorKind::NotFound,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_error_display() {
+    let error = Error::from(std::io::Error::new(
+        std::io::ErrorKind::PermissionDenied,
+        "access denied",
+    ));
+    assert_eq!(error.to_string(), "I/O error: access denied");
+    let error = Error::Encode {
+        reason: "truncated .npy header".to_string(),
+    };
+    assert_eq!(error.to_string(), "encoding error: truncated .npy header");
+    let error = Error::Unsupported {
+        reason: "dtype '<c16'".to_string(),
+    };
+    assert_eq!(error.to_string(), "unsupported: dtype '<c16'");
+    let error: Box<dyn std::error::Error> = Box::new(error);
+    assert_eq!(error.to_string(), "unsupported: dtype '<c16'");
+}
+
+// =================================================================
+// test compatibility with the noise crate
+// =================================================================
+#[test]
+fn test_as_noise_fn() {
+    use noise::NoiseFn;
+    let generator = Source::<2>::simplex(42).fbm(4, 0.05, 2.0, 0.5).abs();
+    let noise_fn = AsNoiseFn::new(generator.clone());
+    for i in 0..200 {
+        let point = [i as f64 * 1.3 - 100.0, (i % 17) as f64 * -2.1];
+        assert_eq!(noise_fn.get(point), generator.sample(point));
+    }
+
+    // modifiers of the noise crate accept wrapped generators
+    let scaled = noise::ScaleBias::new(AsNoiseFn::new(generator.clone()))
+        .set_scale(2.0)
+        .set_bias(0.5);
+    assert_eq!(
+        scaled.get([3.0, 4.0]),
+        generator.sample([3.0, 4.0]) * 2.0 + 0.5
+    );
+    assert_eq!(
+        AsNoiseFn::new(generator)
+            .into_inner()
+            .to_string()
+            .lines()
+            .count(),
+        3
+    );
+}
+
+#[test]
+fn test_as_noise_fn_map_builder() {
+    use noise::utils::{NoiseMapBuilder, PlaneMapBuilder};
+    let generator = Source::<3>::simplex(42).fbm(3, 0.5, 2.0, 0.5);
+    let map = PlaneMapBuilder::new(AsNoiseFn::new(generator.clone()))
+        .set_size(16, 8)
+        .set_x_bounds(-4.0, 4.0)
+        .set_y_bounds(0.0, 2.0)
+        .build();
+    assert_eq!(map.size(), (16, 8));
+    for y in 0..8 {
+        for x in 0..16 {
+            let point = [-4.0 + 0.5 * x as f64, 0.25 * y as f64, 0.0];
+            assert_eq!(map.get_value(x, y), generator.sample(point));
+        }
+    }
+}
+
+#[test]
+fn test_from_noise_fn() {
+    use noise::NoiseFn;
+    let perlin = noise::Perlin::new(42);
+    let generator = FromNoiseFn::new(perlin);
+    for i in 0..200 {
+        let point = [
+            i as f64 * 1.3 - 100.0,
+            (i % 17) as f64 * -2.1,
+            i as f64 * 0.01,
+        ];
+        assert_eq!(generator.sample(point), perlin.get(point));
+    }
+    assert_eq!(
+        generator.sample_interval([0.0; 3], [1.0; 3]),
+        (f64::NEG_INFINITY, f64::INFINITY)
+    );
+    assert!(generator.to_recipe().is_err());
+
+    // wrapped functions can be used with the adapters and buffers of this crate
+    let adapted = FromNoiseFn::<3, _>::new(perlin)
+        .fbm(3, 0.05, 2.0, 0.5)
+        .rotate([0.0, 0.0, 0.5]);
+    let buffer = NoiseBuffer::<3>::new([8, 8, 8], &adapted);
+    assert_eq!(buffer[[1, 2, 3]], adapted.sample([1.0, 2.0, 3.0]));
+    assert!(buffer.buffer.iter().any(|&value| value != 0.0));
+
+    // round trips through both wrappers preserve the results
+    let round_trip = AsNoiseFn::new(FromNoiseFn::new(perlin));
+    let point = [0.3, -1.7, 2.9, 4.1];
+    assert_eq!(round_trip.get(point), perlin.get(point));
+    let generator = Source::<4>::simplex(42).scale([0.3; 4]);
+    let round_trip = FromNoiseFn::new(AsNoiseFn::new(generator.clone()));
+    assert_eq!(round_trip.sample(point), generator.sample(point));
+    assert_eq!(
+        round_trip.into_inner().into_inner().to_string(),
+        generator.to_string()
+    );
+}
+
+// =================================================================
+// test random sampling of regions
+// =================================================================
+#[test]
+fn test_noise_distribution_deterministic() {
+    use rand::{distributions::Distribution, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+    let generator = Source::<2>::simplex(42).fbm(4, 0.05, 2.0, 0.5);
+    let distribution = NoiseDistribution::new(generator.clone(), [-50.0, 0.0], [50.0, 20.0]);
+    let draw = |seed| -> Vec<f64> {
+        (&distribution)
+            .sample_iter(ChaCha8Rng::seed_from_u64(seed))
+            .take(1000)
+            .collect()
+    };
+    let values = draw(7);
+    assert_eq!(values, draw(7));
+    assert_ne!(values, draw(8));
+
+    // the convenience method draws the same points
+    let mut rng = ChaCha8Rng::seed_from_u64(7);
+    let region = generator.sample_region_random(&mut rng, [-50.0, 0.0], [50.0, 20.0], 1000);
+    assert_eq!(region, values);
+    assert!(generator
+        .sample_region_random(&mut rng, [0.0; 2], [1.0; 2], 0)
+        .is_empty());
+
+    // a degenerate region always yields the same point
+    let mut rng = ChaCha8Rng::seed_from_u64(7);
+    let point = generator.sample_region_random(&mut rng, [3.0, 4.0], [3.0, 4.0], 10);
+    assert!(point
+        .iter()
+        .all(|&value| value == generator.sample([3.0, 4.0])));
+}
+
+#[test]
+fn test_noise_distribution_bounds() {
+    use rand::{distributions::Distribution, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+    let generator = Source::<3>::worley(42).abs().mul(0.5).add(0.25);
+    let (lower, upper) = generator.output_bounds();
+    let distribution = NoiseDistribution::new(generator.clone(), [-1e3; 3], [1e3; 3]);
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+    let values: Vec<f64> = (0..10_000).map(|_| distribution.sample(&mut rng)).collect();
+    assert!(values.iter().all(|value| (lower..=upper).contains(value)));
+    // the values are not uniformly distributed, but do cover much of the output range
+    let (min, max) = values
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+            (min.min(v), max.max(v))
+        });
+    assert!(min < 0.3 && max > 0.5, "values within [{min}, {max}]");
+}
+
+#[test]
+#[should_panic(expected = "region min [1.0, 0.0] must not exceed region max [0.0, 1.0]")]
+fn test_noise_distribution_invalid_region() {
+    NoiseDistribution::new(Source::<2>::simplex(42), [1.0, 0.0], [0.0, 1.0]);
+}
+
+#[test]
+#[should_panic(expected = "region bounds must be finite")]
+fn test_sample_region_random_invalid_region() {
+    use rand::SeedableRng;
+    let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(7);
+    Source::<2>::simplex(42).sample_region_random(&mut rng, [0.0; 2], [f64::INFINITY; 2], 1);
+}
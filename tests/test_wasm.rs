@@ -0,0 +1,84 @@
+// the bindings are exercised natively, as the wasm32 target and wasm-bindgen-test are not part
+// of the test environment. on wasm32, the same results are covered by the golden vectors
+use libnoise::pipeline::Pipeline;
+use libnoise::prelude::*;
+use libnoise::recipe::{NoiseRecipe, RecipeError};
+use libnoise::wasm::JsGenerator;
+use libnoise::Error;
+
+const RECIPE: &str = r#"{
+    "type": "scale",
+    "source": { "type": "simplex", "seed": 42, "large_coordinates": false, "r_squared": null },
+    "scale": [0.05, 0.05]
+}"#;
+
+#[test]
+fn test_js_generator_sample() {
+    let generator = JsGenerator::new(RECIPE).unwrap_or_else(|_| panic!("invalid recipe"));
+    let expected = Source::simplex(42).scale([0.05; 2]);
+    for point in [[0.0, 0.0], [0.2, 0.5], [-13.7, 42.1], [1e6, -3.5]] {
+        assert_eq!(
+            generator.sample2(point[0], point[1]),
+            expected.sample(point)
+        );
+    }
+}
+
+#[test]
+fn test_js_generator_from_pipeline() {
+    let mut pipeline = Pipeline::<2>::new(NoiseRecipe::Simplex {
+        seed: 42,
+        large_coordinates: false,
+        r_squared: None,
+    })
+    .unwrap();
+    pipeline.push_scale([0.05; 2]).unwrap();
+    let generator = JsGenerator::from_pipeline(&pipeline).unwrap();
+    assert_eq!(generator.sample2(3.5, -2.25), pipeline.sample([3.5, -2.25]));
+
+    let simplex = NoiseRecipe::Simplex {
+        seed: 42,
+        large_coordinates: false,
+        r_squared: None,
+    };
+    assert!(matches!(
+        JsGenerator::from_recipe(&NoiseRecipe::Scale {
+            source: Box::new(simplex),
+            scale: vec![1.0; 3],
+        }),
+        Err(RecipeError::LengthMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_js_generator_fill_image_data() {
+    let generator = JsGenerator::new(RECIPE).unwrap_or_else(|_| panic!("invalid recipe"));
+    let (width, height) = (17, 9);
+    let pixels = generator.try_fill_image_data(width, height).unwrap();
+    assert_eq!(pixels.len(), (width * height * 4) as usize);
+    // the image matches the quantized samples, with the first coordinate along the rows
+    let buf = NoiseBuffer::<2>::new(
+        [width as usize, height as usize],
+        &Source::simplex(42).scale([0.05; 2]),
+    );
+    let quantized = buf.quantize_u8();
+    for y in 0..height as usize {
+        for x in 0..width as usize {
+            let pixel = &pixels[(y * width as usize + x) * 4..][..4];
+            let value = quantized[x * height as usize + y];
+            assert_eq!(pixel, [value, value, value, u8::MAX]);
+        }
+    }
+    assert_eq!(
+        generator
+            .fill_image_data(width, height)
+            .unwrap_or_else(|_| panic!())
+            .0,
+        pixels
+    );
+    assert!(generator.try_fill_image_data(0, 5).unwrap().is_empty());
+    assert!(matches!(
+        generator.try_fill_image_data(u32::MAX, u32::MAX),
+        Err(Error::InvalidShape { .. })
+    ));
+}